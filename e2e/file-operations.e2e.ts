@@ -1,6 +1,9 @@
 import { test, expect, TEST_BUCKETS, getEndpoint } from './electron-fixtures';
 import { TEST_DATA, getLocalStackS3Client } from './fixtures/localstack-setup';
-import { PutObjectCommand, DeleteObjectCommand } from '@aws-sdk/client-s3';
+import { PutObjectCommand, DeleteObjectCommand, ListObjectsV2Command, HeadObjectCommand } from '@aws-sdk/client-s3';
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
 
 test.describe('File Operations', () => {
   test.describe('Upload Files', () => {
@@ -37,6 +40,35 @@ test.describe('File Operations', () => {
       await window.screenshot({ path: 'test-results/upload-ready.png' });
     });
 
+    test('should store the content type of an uploaded JSON file', async ({ window }) => {
+      const s3Client = getLocalStackS3Client();
+      const fileName = `temp-upload-${Date.now()}.json`;
+      const localPath = path.join(os.tmpdir(), fileName);
+      fs.writeFileSync(localPath, '{"uploaded": true}');
+
+      try {
+        // The native file dialog can't be driven, so upload through the same API it uses
+        const result = await window.evaluate(
+          ({ bucket, filePath }) => window.electronAPI.s3.uploadFile(bucket, 'documents/', filePath, 'e2e-upload'),
+          { bucket: TEST_BUCKETS.main, filePath: localPath }
+        );
+        expect(result.success).toBe(true);
+
+        const head = await s3Client.send(new HeadObjectCommand({
+          Bucket: TEST_BUCKETS.main,
+          Key: `documents/${fileName}`,
+        }));
+        expect(head.ContentType).toBe('application/json');
+      } finally {
+        fs.unlinkSync(localPath);
+        try {
+          await s3Client.send(new DeleteObjectCommand({ Bucket: TEST_BUCKETS.main, Key: `documents/${fileName}` }));
+        } catch {
+          // Ignore cleanup errors
+        }
+      }
+    });
+
     test('should have drop zone element in file list', async ({ window }) => {
       // Navigate into documents folder to have a more isolated test area (double-click to navigate)
       const documentsFolder = window.locator('.file-row.folder').filter({ hasText: 'documents' });
@@ -152,7 +184,7 @@ test.describe('File Operations', () => {
       await window.screenshot({ path: 'test-results/download-toast-action.png' });
     });
 
-    test('should disable download button for multiple file selection', async ({ window }) => {
+    test('should enable download button for multiple file selection', async ({ window }) => {
       // Select first file
       const file1 = window.locator('.file-row.file').filter({ hasText: 'file1.txt' });
       await file1.click();
@@ -161,12 +193,12 @@ test.describe('File Operations', () => {
       const file2 = window.locator('.file-row.file').filter({ hasText: 'file2.txt' });
       await file2.click({ modifiers: ['Control'] });
 
-      // Download button should be disabled for multi-select
-      const downloadButton = window.locator('button[title*="Download not available"]');
-      await expect(downloadButton).toBeDisabled();
+      // Download button downloads every selected file
+      const downloadButton = window.locator('button[title="Download 2 selected files"]');
+      await expect(downloadButton).toBeEnabled();
 
       // Screenshot
-      await window.screenshot({ path: 'test-results/download-multiselect-disabled.png' });
+      await window.screenshot({ path: 'test-results/download-multiselect.png' });
     });
   });
 
@@ -352,6 +384,13 @@ test.describe('File Operations', () => {
       await expect(file2).not.toBeVisible({ timeout: 5000 });
       await expect(file3).not.toBeVisible({ timeout: 5000 });
 
+      // The bulk DeleteObjects request should have removed them from the bucket
+      const remaining = await s3Client.send(new ListObjectsV2Command({
+        Bucket: TEST_BUCKETS.main,
+        Prefix: `documents/multi-del-${timestamp}-`,
+      }));
+      expect(remaining.KeyCount ?? 0).toBe(0);
+
       // Should see success toast
       const toast = window.locator('.toast').filter({ hasText: /delete/i });
       await expect(toast).toBeVisible({ timeout: 5000 });
@@ -520,7 +559,9 @@ test.describe('File Operations', () => {
         Bucket: TEST_BUCKETS.main,
         Key: tempFileKey,
         Body: 'Temporary file for rename test',
-        ContentType: 'text/plain',
+        // Not what the extension would suggest, so a guessed type would show up below
+        ContentType: 'text/plain; charset=utf-8',
+        Metadata: { owner: 'team-a' },
       }));
 
       // Refresh to see new file - use specific selector
@@ -562,6 +603,14 @@ test.describe('File Operations', () => {
       // Screenshot showing renamed file
       await window.screenshot({ path: 'test-results/rename-complete.png' });
 
+      // The renamed object keeps its content type and user metadata
+      const head = await s3Client.send(new HeadObjectCommand({
+        Bucket: TEST_BUCKETS.main,
+        Key: `documents/${renamedName}`,
+      }));
+      expect(head.ContentType).toBe('text/plain; charset=utf-8');
+      expect(head.Metadata).toEqual({ owner: 'team-a' });
+
       // Clean up - delete the renamed file
       try {
         await s3Client.send(new DeleteObjectCommand({
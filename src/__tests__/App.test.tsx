@@ -140,6 +140,290 @@ describe('App', () => {
         expect(screen.getByText('Selected:')).toBeInTheDocument();
       });
     });
+
+    it('keeps a newer selection when the file being opened resolves late', async () => {
+      mockElectronAPI.s3.listBuckets.mockResolvedValue({
+        success: true,
+        buckets: [{ name: 'my-bucket', creationDate: new Date() }],
+      });
+      mockElectronAPI.aws.getProfiles.mockResolvedValue({
+        profiles: [{ name: 'default', hasCredentials: true, isValid: true }],
+        currentProfile: 'default',
+        defaultRegion: 'us-east-1',
+      });
+      mockElectronAPI.s3.listObjects.mockResolvedValue({
+        success: true,
+        result: {
+          objects: [
+            { key: 'no-extension', size: 1024, isPrefix: false },
+            { key: 'notes.txt', size: 10, isPrefix: false },
+          ],
+          prefixes: [],
+          continuationToken: undefined,
+          isTruncated: false,
+          prefix: '',
+          keyCount: 2,
+        },
+      });
+      let resolveHead: (value: unknown) => void = () => {};
+      mockElectronAPI.s3.headObject.mockReturnValueOnce(new Promise(resolve => { resolveHead = resolve; }));
+
+      renderApp();
+
+      await waitFor(() => {
+        expect(screen.getByText('my-bucket')).toBeInTheDocument();
+      });
+      fireEvent.click(screen.getByText('my-bucket'));
+      await waitFor(() => {
+        expect(screen.getByText('no-extension')).toBeInTheDocument();
+      });
+
+      // The extensionless file needs its Content-Type, which is still loading
+      fireEvent.doubleClick(screen.getByText('no-extension'));
+      await waitFor(() => {
+        expect(mockElectronAPI.s3.headObject).toHaveBeenCalledWith('my-bucket', 'no-extension');
+      });
+      fireEvent.click(screen.getByText('notes.txt'));
+
+      await act(async () => {
+        resolveHead({ success: true, head: { contentLength: 1024, contentType: 'application/octet-stream' } });
+      });
+
+      expect(document.querySelector('.selected-name')).toHaveTextContent('notes.txt');
+      // An unknown Content-Type would otherwise go on to sniff the file and open a viewer
+      expect(mockElectronAPI.s3.downloadPartialContent).not.toHaveBeenCalled();
+    });
+
+    it('opens the version named by a pasted URL instead of the latest one', async () => {
+      mockElectronAPI.s3.listBuckets.mockResolvedValue({
+        success: true,
+        buckets: [{ name: 'my-bucket', creationDate: new Date() }],
+      });
+      mockElectronAPI.aws.getProfiles.mockResolvedValue({
+        profiles: [{ name: 'default', hasCredentials: true, isValid: true }],
+        currentProfile: 'default',
+        defaultRegion: 'us-east-1',
+      });
+      mockElectronAPI.s3.listObjects.mockResolvedValue({
+        success: true,
+        result: {
+          objects: [{ key: 'notes.txt', size: 10, isPrefix: false }],
+          prefixes: [],
+          continuationToken: undefined,
+          isTruncated: false,
+          prefix: '',
+          keyCount: 1,
+        },
+      });
+      mockElectronAPI.s3.parseUrl.mockResolvedValueOnce({
+        success: true,
+        bucket: 'my-bucket',
+        key: 'notes.txt',
+        versionId: 'v1',
+      });
+      mockElectronAPI.s3.headObject.mockResolvedValueOnce({
+        success: true,
+        head: { contentLength: 4, contentType: 'text/plain' },
+      });
+
+      renderApp();
+
+      const input = await screen.findByRole('textbox', { name: /s3 url/i });
+      fireEvent.change(input, { target: { value: 'https://my-bucket.s3.amazonaws.com/notes.txt?versionId=v1' } });
+      fireEvent.click(screen.getByRole('button', { name: /go/i }));
+      await waitFor(() => {
+        expect(document.querySelector('.file-row.selected')).toHaveTextContent('notes.txt');
+      });
+
+      fireEvent.doubleClick(document.querySelector('.file-row.selected')!);
+
+      await waitFor(() => {
+        expect(mockElectronAPI.s3.downloadContent).toHaveBeenCalledWith('my-bucket', 'notes.txt', 'v1');
+      });
+      expect(mockElectronAPI.s3.headObject).toHaveBeenCalledWith('my-bucket', 'notes.txt', 'v1');
+      expect(mockElectronAPI.s3.getFileSize).toHaveBeenCalledWith('my-bucket', 'notes.txt', 'v1');
+    });
+  });
+
+  describe('session restore', () => {
+    beforeEach(() => {
+      mockElectronAPI.aws.getProfiles.mockResolvedValue({
+        profiles: [{ name: 'default', hasCredentials: true, isValid: true }],
+        currentProfile: 'default',
+        defaultRegion: 'us-east-1',
+      });
+      mockElectronAPI.appState.load.mockResolvedValue({
+        lastProfile: 'default',
+        lastBucket: 'deleted-bucket',
+        lastPrefix: 'reports/',
+      });
+    });
+
+    afterEach(() => {
+      mockElectronAPI.appState.load.mockResolvedValue({
+        lastProfile: null,
+        lastBucket: null,
+        lastPrefix: '',
+        readOnlyProfiles: [],
+      });
+    });
+
+    it('falls back to the bucket list when the last bucket no longer exists', async () => {
+      mockElectronAPI.s3.listBuckets.mockResolvedValue({
+        success: true,
+        buckets: [{ name: 'other-bucket', creationDate: new Date() }],
+      });
+      mockElectronAPI.s3.listObjects.mockResolvedValueOnce({ success: false, error: 'The specified bucket does not exist' });
+
+      renderApp();
+
+      await waitFor(() => {
+        expect(
+          screen.getByText("Last location 's3://deleted-bucket/reports/' is no longer accessible")
+        ).toBeInTheDocument();
+      });
+
+      // Stale location is removed from settings, profile is kept
+      expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({ lastBucket: null, lastPrefix: '' });
+      expect(screen.getByText('Select a bucket to view files')).toBeInTheDocument();
+      // Only the check of the saved location was listed
+      expect(mockElectronAPI.s3.listObjects).toHaveBeenCalledTimes(1);
+      expect(mockElectronAPI.s3.listObjects).toHaveBeenCalledWith({
+        bucket: 'deleted-bucket',
+        prefix: 'reports/',
+        maxKeys: 1,
+      });
+    });
+
+    it('restores a location missing from the bucket list that can still be listed', async () => {
+      mockElectronAPI.s3.listBuckets.mockResolvedValue({
+        success: true,
+        buckets: [{ name: 'other-bucket', creationDate: new Date() }],
+      });
+
+      renderApp();
+
+      await waitFor(() => {
+        expect(mockElectronAPI.s3.listObjects).toHaveBeenCalledWith(
+          expect.objectContaining({ bucket: 'deleted-bucket', prefix: 'reports/' }),
+          expect.any(String)
+        );
+      });
+      expect(mockElectronAPI.appState.save).not.toHaveBeenCalledWith({ lastBucket: null, lastPrefix: '' });
+    });
+
+    it('restores the last bucket when it is still listed', async () => {
+      mockElectronAPI.s3.listBuckets.mockResolvedValue({
+        success: true,
+        buckets: [{ name: 'deleted-bucket', creationDate: new Date() }],
+      });
+
+      renderApp();
+
+      await waitFor(() => {
+        expect(mockElectronAPI.s3.listObjects).toHaveBeenCalledWith(
+          expect.objectContaining({ bucket: 'deleted-bucket', prefix: 'reports/' }),
+          expect.any(String)
+        );
+      });
+      expect(mockElectronAPI.appState.save).not.toHaveBeenCalledWith({ lastBucket: null, lastPrefix: '' });
+    });
+  });
+
+  describe('read-only mode', () => {
+    beforeEach(() => {
+      mockElectronAPI.aws.getProfiles.mockResolvedValue({
+        profiles: [{ name: 'prod', hasCredentials: true, isValid: true }],
+        currentProfile: 'prod',
+        defaultRegion: 'us-east-1',
+      });
+      mockElectronAPI.appState.load.mockResolvedValue({
+        lastProfile: null,
+        lastBucket: null,
+        lastPrefix: '',
+        readOnlyProfiles: ['prod'],
+      });
+      mockElectronAPI.s3.listBuckets.mockResolvedValue({
+        success: true,
+        buckets: [{ name: 'prod-bucket', creationDate: new Date() }],
+      });
+      mockElectronAPI.s3.listObjects.mockResolvedValue({
+        success: true,
+        result: {
+          objects: [{ key: 'report.csv', size: 10, isPrefix: false }],
+          prefixes: [],
+          continuationToken: undefined,
+          isTruncated: false,
+          prefix: '',
+          keyCount: 1,
+        },
+      });
+    });
+
+    afterEach(() => {
+      mockElectronAPI.appState.load.mockResolvedValue({
+        lastProfile: null,
+        lastBucket: null,
+        lastPrefix: '',
+        readOnlyProfiles: [],
+      });
+    });
+
+    async function openBucket() {
+      renderApp();
+      await waitFor(() => {
+        expect(screen.getByLabelText('Read-only')).toBeChecked();
+      });
+      fireEvent.click(screen.getByText('prod-bucket'));
+      await waitFor(() => {
+        expect(screen.getByText('report.csv')).toBeInTheDocument();
+      });
+    }
+
+    it('does not upload when read-only mode is enabled', async () => {
+      await openBucket();
+
+      fireEvent.click(screen.getByTitle('Upload files'));
+
+      expect(await screen.findByText('Read-only mode is enabled')).toBeInTheDocument();
+      expect(mockElectronAPI.s3.showOpenDialog).not.toHaveBeenCalled();
+    });
+
+    it('does not open the delete or rename dialogs when read-only mode is enabled', async () => {
+      await openBucket();
+      fireEvent.click(screen.getByText('report.csv'));
+
+      fireEvent.click(screen.getByTitle('Delete selected file'));
+      fireEvent.click(screen.getByTitle('Rename selected file'));
+
+      await waitFor(() => {
+        expect(screen.getAllByText('Read-only mode is enabled')).toHaveLength(2);
+      });
+      expect(screen.queryByRole('button', { name: 'Delete' })).not.toBeInTheDocument();
+      expect(screen.queryByRole('button', { name: 'Rename' })).not.toBeInTheDocument();
+    });
+
+    it('does not open the new folder dialog when read-only mode is enabled', async () => {
+      await openBucket();
+
+      fireEvent.click(screen.getByTitle('Create new folder'));
+
+      expect(await screen.findByText('Read-only mode is enabled')).toBeInTheDocument();
+      expect(mockElectronAPI.s3.createFolder).not.toHaveBeenCalled();
+      expect(screen.queryByRole('button', { name: 'Create' })).not.toBeInTheDocument();
+    });
+
+    it('persists the toggle per profile', async () => {
+      renderApp();
+      await waitFor(() => {
+        expect(screen.getByLabelText('Read-only')).toBeChecked();
+      });
+
+      fireEvent.click(screen.getByLabelText('Read-only'));
+
+      expect(screen.getByLabelText('Read-only')).not.toBeChecked();
+      expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({ readOnlyProfiles: [] });
+    });
   });
 
   describe('file operations', () => {
@@ -264,5 +548,46 @@ describe('App', () => {
       // Cleanup
       window.removeEventListener('s3-refresh-files', refreshHandler);
     });
+
+    it('copies the current location on Ctrl+Shift+C', async () => {
+      const writeText = vi.fn().mockResolvedValue(undefined);
+      Object.defineProperty(navigator, 'clipboard', { value: { writeText }, configurable: true });
+
+      renderApp();
+
+      await waitFor(() => {
+        expect(screen.getByText('test-bucket')).toBeInTheDocument();
+      });
+      fireEvent.click(screen.getByText('test-bucket'));
+
+      await waitFor(() => {
+        expect(screen.getByText('file.txt')).toBeInTheDocument();
+      });
+
+      await act(async () => {
+        fireEvent.keyDown(window, { key: 'C', ctrlKey: true, shiftKey: true });
+      });
+
+      expect(writeText).toHaveBeenCalledWith('s3://test-bucket/');
+    });
+
+    it('opens the current location in the AWS console', async () => {
+      const openSpy = vi.spyOn(window, 'open').mockImplementation(() => null);
+
+      renderApp();
+
+      await waitFor(() => {
+        expect(screen.getByText('test-bucket')).toBeInTheDocument();
+      });
+      fireEvent.click(screen.getByText('test-bucket'));
+
+      fireEvent.click(screen.getByTitle('Open current location in AWS Console'));
+
+      expect(openSpy).toHaveBeenCalledWith(
+        'https://s3.console.aws.amazon.com/s3/buckets/test-bucket?region=us-east-1',
+        '_blank'
+      );
+      openSpy.mockRestore();
+    });
   });
 });
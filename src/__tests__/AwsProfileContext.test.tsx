@@ -167,6 +167,35 @@ describe('AwsProfileContext', () => {
     });
   });
 
+  it('reloads profiles when the AWS config files change', async () => {
+    let notifyChanged: () => void = () => {};
+    mockElectronAPI.aws.onProfilesChanged.mockImplementation((callback: () => void) => {
+      notifyChanged = callback;
+      return () => {};
+    });
+    mockElectronAPI.aws.refreshProfiles.mockResolvedValue({
+      profiles: [{ name: 'new-profile', hasCredentials: true, isValid: true, profileType: 'static', profileTypeDescription: 'Access Key' }],
+      currentProfile: null,
+    });
+
+    render(
+      <AwsProfileProvider>
+        <TestProfileConsumer />
+      </AwsProfileProvider>
+    );
+
+    await waitFor(() => {
+      expect(screen.getByTestId('profile-dev')).toBeInTheDocument();
+    });
+
+    act(() => notifyChanged());
+
+    await waitFor(() => {
+      expect(screen.getByTestId('profile-new-profile')).toBeInTheDocument();
+    });
+    expect(mockElectronAPI.aws.refreshProfiles).toHaveBeenCalled();
+  });
+
   it('throws error when used outside provider', () => {
     // Suppress console.error for this test
     const consoleError = vi.spyOn(console, 'error').mockImplementation(() => {});
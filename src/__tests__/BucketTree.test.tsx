@@ -63,6 +63,29 @@ describe('BucketTree', () => {
       });
     });
 
+    it('shows the region of buckets whose region is known', async () => {
+      mockElectronAPI.s3.listBuckets.mockResolvedValue({
+        success: true,
+        buckets: [
+          { name: 'bucket-alpha', region: 'eu-central-1' },
+          { name: 'bucket-beta' },
+        ],
+      });
+
+      render(
+        <BucketTree
+          currentProfile="test-profile"
+          selectedBucket={null}
+          onSelectBucket={vi.fn()}
+        />
+      );
+
+      await waitFor(() => {
+        expect(screen.getByText('bucket-alpha')).toHaveAttribute('title', 'bucket-alpha (eu-central-1)');
+        expect(screen.getByText('bucket-beta')).toHaveAttribute('title', 'bucket-beta');
+      });
+    });
+
     it('shows empty message when no buckets', async () => {
       mockElectronAPI.s3.listBuckets.mockResolvedValue({
         success: true,
@@ -78,7 +101,32 @@ describe('BucketTree', () => {
       );
 
       await waitFor(() => {
-        expect(screen.getByText('No buckets found')).toBeInTheDocument();
+        expect(screen.getByText('No buckets accessible')).toBeInTheDocument();
+      });
+      expect(screen.getByText(/s3:ListAllMyBuckets/)).toBeInTheDocument();
+      expect(screen.queryByText('Loading buckets...')).not.toBeInTheDocument();
+    });
+
+    it('can retry after listing returned no buckets', async () => {
+      mockElectronAPI.s3.listBuckets
+        .mockResolvedValueOnce({ success: true, buckets: [] })
+        .mockResolvedValueOnce({ success: true, buckets: [{ name: 'granted-bucket' }] });
+
+      render(
+        <BucketTree
+          currentProfile="test-profile"
+          selectedBucket={null}
+          onSelectBucket={vi.fn()}
+        />
+      );
+
+      await waitFor(() => {
+        expect(screen.getByText('No buckets accessible')).toBeInTheDocument();
+      });
+      fireEvent.click(screen.getByText('Retry'));
+
+      await waitFor(() => {
+        expect(screen.getByText('granted-bucket')).toBeInTheDocument();
       });
     });
 
@@ -124,6 +172,32 @@ describe('BucketTree', () => {
       expect(onSelectBucket).toHaveBeenCalledWith('my-bucket');
     });
 
+    it('shows the public access status of the selected bucket', async () => {
+      mockElectronAPI.s3.listBuckets.mockResolvedValue({
+        success: true,
+        buckets: [{ name: 'website' }, { name: 'internal' }],
+      });
+      mockElectronAPI.s3.getBucketPublicStatus.mockResolvedValueOnce({ success: true, status: 'public-read' });
+
+      const { rerender } = render(
+        <BucketTree currentProfile="test-profile" selectedBucket="website" onSelectBucket={vi.fn()} />
+      );
+
+      expect(await screen.findByText('Public')).toHaveAttribute(
+        'title',
+        'The bucket policy or ACL grants public access'
+      );
+      expect(mockElectronAPI.s3.getBucketPublicStatus).toHaveBeenCalledWith('website');
+
+      mockElectronAPI.s3.getBucketPublicStatus.mockResolvedValueOnce({ success: false, error: 'Access Denied' });
+      rerender(<BucketTree currentProfile="test-profile" selectedBucket="internal" onSelectBucket={vi.fn()} />);
+
+      expect(await screen.findByText('?')).toBeInTheDocument();
+      // The first bucket keeps its badge without being checked again
+      expect(screen.getByText('Public')).toBeInTheDocument();
+      expect(mockElectronAPI.s3.getBucketPublicStatus).toHaveBeenCalledTimes(2);
+    });
+
     it('highlights selected bucket', async () => {
       mockElectronAPI.s3.listBuckets.mockResolvedValue({
         success: true,
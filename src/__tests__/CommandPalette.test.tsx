@@ -0,0 +1,64 @@
+import { describe, it, expect, vi } from 'vitest';
+import { render, screen, fireEvent, waitFor } from '@testing-library/react';
+import CommandPalette from '../renderer/components/CommandPalette';
+import type { PaletteCommand } from '../renderer/utils/commandPalette';
+
+const commands: PaletteCommand[] = [
+  { kind: 'location', id: 'location:logs/2024/', label: 's3://logs/2024/', bucket: 'logs', prefix: '2024/' },
+  { kind: 'action', id: 'action:refresh', label: 'Refresh file list', action: 'refresh' },
+  { kind: 'bucket', id: 'bucket:prod-logs', label: 'prod-logs', bucket: 'prod-logs' },
+];
+
+describe('CommandPalette', () => {
+  it('renders nothing when closed', () => {
+    const { container } = render(
+      <CommandPalette isOpen={false} commands={commands} onSelect={vi.fn()} onClose={vi.fn()} />
+    );
+
+    expect(container).toBeEmptyDOMElement();
+  });
+
+  it('focuses the input and lists all commands', async () => {
+    render(<CommandPalette isOpen={true} commands={commands} onSelect={vi.fn()} onClose={vi.fn()} />);
+
+    await waitFor(() => {
+      expect(screen.getByLabelText('Command palette')).toHaveFocus();
+    });
+    expect(screen.getAllByRole('option')).toHaveLength(3);
+  });
+
+  it('filters as the user types and highlights matched characters', () => {
+    const { container } = render(
+      <CommandPalette isOpen={true} commands={commands} onSelect={vi.fn()} onClose={vi.fn()} />
+    );
+
+    fireEvent.change(screen.getByLabelText('Command palette'), { target: { value: 'refr' } });
+
+    expect(screen.getAllByRole('option')).toHaveLength(1);
+    expect(Array.from(container.querySelectorAll('.command-palette-match')).map(m => m.textContent).join('')).toBe('Refr');
+  });
+
+  it('runs the highlighted command with arrow keys and Enter', () => {
+    const onSelect = vi.fn();
+    render(<CommandPalette isOpen={true} commands={commands} onSelect={onSelect} onClose={vi.fn()} />);
+
+    const input = screen.getByLabelText('Command palette');
+    fireEvent.keyDown(input, { key: 'ArrowDown' });
+    fireEvent.keyDown(input, { key: 'ArrowDown' });
+    fireEvent.keyDown(input, { key: 'Enter' });
+
+    expect(onSelect).toHaveBeenCalledWith(commands[2]);
+  });
+
+  it('shows a message when nothing matches and closes on Escape', () => {
+    const onClose = vi.fn();
+    render(<CommandPalette isOpen={true} commands={commands} onSelect={vi.fn()} onClose={onClose} />);
+
+    const input = screen.getByLabelText('Command palette');
+    fireEvent.change(input, { target: { value: 'zzz' } });
+    expect(screen.getByText('No matches')).toBeInTheDocument();
+
+    fireEvent.keyDown(input, { key: 'Escape' });
+    expect(onClose).toHaveBeenCalled();
+  });
+});
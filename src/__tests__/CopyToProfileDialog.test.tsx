@@ -0,0 +1,71 @@
+import React from 'react';
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, fireEvent } from '@testing-library/react';
+import CopyToProfileDialog from '../renderer/components/CopyToProfileDialog';
+
+describe('CopyToProfileDialog', () => {
+  const mockOnConfirm = vi.fn();
+  const mockOnCancel = vi.fn();
+
+  const defaultProps = {
+    isOpen: true,
+    profileNames: ['dev', 'prod', 'staging'],
+    currentProfile: 'dev',
+    sourceBucket: 'source-bucket',
+    sourceKey: 'data/report.csv',
+    onConfirm: mockOnConfirm,
+    onCancel: mockOnCancel,
+  };
+
+  beforeEach(() => {
+    mockOnConfirm.mockClear();
+    mockOnCancel.mockClear();
+  });
+
+  it('should not render anything when closed', () => {
+    const { container } = render(<CopyToProfileDialog {...defaultProps} isOpen={false} />);
+    expect(container).toBeEmptyDOMElement();
+  });
+
+  it('should show the source location', () => {
+    render(<CopyToProfileDialog {...defaultProps} />);
+    expect(screen.getByText('s3://source-bucket/data/report.csv')).toBeInTheDocument();
+  });
+
+  it('should default to the first profile other than the current one', () => {
+    render(<CopyToProfileDialog {...defaultProps} />);
+    expect(screen.getByLabelText('Destination profile:')).toHaveValue('prod');
+  });
+
+  it('should prefill destination bucket and key from the source', () => {
+    render(<CopyToProfileDialog {...defaultProps} />);
+    expect(screen.getByLabelText('Destination bucket:')).toHaveValue('source-bucket');
+    expect(screen.getByLabelText('Destination key:')).toHaveValue('data/report.csv');
+  });
+
+  it('should call onConfirm with destination values', () => {
+    render(<CopyToProfileDialog {...defaultProps} />);
+
+    fireEvent.change(screen.getByLabelText('Destination profile:'), { target: { value: 'staging' } });
+    fireEvent.change(screen.getByLabelText('Destination bucket:'), { target: { value: ' other-bucket ' } });
+    fireEvent.click(screen.getByRole('button', { name: 'Copy' }));
+
+    expect(mockOnConfirm).toHaveBeenCalledWith('staging', 'other-bucket', 'data/report.csv');
+  });
+
+  it('should disable Copy when bucket is empty', () => {
+    render(<CopyToProfileDialog {...defaultProps} />);
+
+    fireEvent.change(screen.getByLabelText('Destination bucket:'), { target: { value: '' } });
+
+    expect(screen.getByRole('button', { name: 'Copy' })).toBeDisabled();
+  });
+
+  it('should call onCancel on Escape', () => {
+    render(<CopyToProfileDialog {...defaultProps} />);
+
+    fireEvent.keyDown(screen.getByLabelText('Destination key:'), { key: 'Escape' });
+
+    expect(mockOnCancel).toHaveBeenCalled();
+  });
+});
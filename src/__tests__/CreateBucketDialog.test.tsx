@@ -0,0 +1,83 @@
+import React from 'react';
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, fireEvent, waitFor } from '@testing-library/react';
+import CreateBucketDialog from '../renderer/components/CreateBucketDialog';
+
+describe('CreateBucketDialog', () => {
+  const mockOnConfirm = vi.fn();
+  const mockOnCancel = vi.fn();
+
+  beforeEach(() => {
+    mockOnConfirm.mockReset();
+    mockOnCancel.mockClear();
+  });
+
+  const renderDialog = (defaultRegion = 'eu-west-1') =>
+    render(
+      <CreateBucketDialog
+        isOpen={true}
+        defaultRegion={defaultRegion}
+        onConfirm={mockOnConfirm}
+        onCancel={mockOnCancel}
+      />
+    );
+
+  it('does not render when closed', () => {
+    const { container } = render(
+      <CreateBucketDialog isOpen={false} defaultRegion="eu-west-1" onConfirm={mockOnConfirm} onCancel={mockOnCancel} />
+    );
+    expect(container).toBeEmptyDOMElement();
+  });
+
+  it('preselects the default region', () => {
+    renderDialog('ap-southeast-2');
+
+    expect(screen.getByLabelText('Region:')).toHaveValue('ap-southeast-2');
+  });
+
+  it('offers a default region that is not in the built-in list', () => {
+    renderDialog('il-central-1');
+
+    expect(screen.getByLabelText('Region:')).toHaveValue('il-central-1');
+  });
+
+  it('disables Create until a name is entered', () => {
+    renderDialog();
+
+    expect(screen.getByRole('button', { name: 'Create' })).toBeDisabled();
+    fireEvent.change(screen.getByLabelText('Bucket name:'), { target: { value: 'new-bucket' } });
+    expect(screen.getByRole('button', { name: 'Create' })).toBeEnabled();
+  });
+
+  it('creates the bucket in the chosen region', async () => {
+    mockOnConfirm.mockResolvedValue(null);
+    renderDialog();
+
+    fireEvent.change(screen.getByLabelText('Bucket name:'), { target: { value: ' new-bucket ' } });
+    fireEvent.change(screen.getByLabelText('Region:'), { target: { value: 'us-east-1' } });
+    fireEvent.click(screen.getByRole('button', { name: 'Create' }));
+
+    await waitFor(() => {
+      expect(mockOnConfirm).toHaveBeenCalledWith('new-bucket', 'us-east-1');
+    });
+  });
+
+  it('keeps the dialog open and shows the error when creation fails', async () => {
+    mockOnConfirm.mockResolvedValue('Bucket names must not contain two adjacent dots');
+    renderDialog();
+
+    fireEvent.change(screen.getByLabelText('Bucket name:'), { target: { value: 'my..bucket' } });
+    fireEvent.click(screen.getByRole('button', { name: 'Create' }));
+
+    expect(await screen.findByRole('alert')).toHaveTextContent('Bucket names must not contain two adjacent dots');
+    expect(screen.getByRole('button', { name: 'Create' })).toBeEnabled();
+  });
+
+  it('cancels on Escape', () => {
+    renderDialog();
+
+    fireEvent.keyDown(screen.getByLabelText('Bucket name:'), { key: 'Escape' });
+
+    expect(mockOnCancel).toHaveBeenCalled();
+  });
+});
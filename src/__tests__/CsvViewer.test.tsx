@@ -38,7 +38,8 @@ describe('CsvViewer', () => {
       await waitFor(() => {
         expect(mockElectronAPI.s3.downloadContent).toHaveBeenCalledWith(
           'test-bucket',
-          'path/to/data.csv'
+          'path/to/data.csv',
+          undefined
         );
       });
     });
@@ -165,6 +166,51 @@ describe('CsvViewer', () => {
     });
   });
 
+  describe('column visibility', () => {
+    beforeEach(() => {
+      mockElectronAPI.s3.downloadContent.mockResolvedValue({
+        success: true,
+        content: 'name,age,city\nAlice,30,NYC\nBob,25,LA',
+      });
+    });
+
+    it('restores the hidden columns saved for the object', async () => {
+      mockElectronAPI.appState.load.mockResolvedValueOnce({
+        ...(await mockElectronAPI.appState.load()),
+        viewerLayouts: { 'test-bucket/path/to/data.csv': { hiddenColumns: ['age'] } },
+      });
+
+      render(<CsvViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('2 of 3 columns')).toBeInTheDocument();
+      });
+      expect(screen.getByText('NYC')).toBeInTheDocument();
+      expect(screen.queryByText('30')).not.toBeInTheDocument();
+      expect(screen.getByText('Columns (2/3)')).toBeInTheDocument();
+    });
+
+    it('shows a note when every column is hidden, and can show them all again', async () => {
+      render(<CsvViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('Alice')).toBeInTheDocument();
+      });
+      fireEvent.click(screen.getByText('Columns'));
+      fireEvent.click(screen.getByLabelText('name'));
+      fireEvent.click(screen.getByLabelText('age'));
+      fireEvent.click(screen.getByLabelText('city'));
+
+      expect(screen.getByText('All columns are hidden')).toBeInTheDocument();
+      expect(screen.queryByText('Alice')).not.toBeInTheDocument();
+
+      fireEvent.click(screen.getByText('Show all'));
+
+      expect(screen.getByText('Alice')).toBeInTheDocument();
+      expect(mockElectronAPI.appState.save).toHaveBeenLastCalledWith({ viewerLayouts: {} });
+    });
+  });
+
   describe('table rendering', () => {
     it('displays column headers', async () => {
       mockElectronAPI.s3.downloadContent.mockResolvedValue({
@@ -402,6 +448,66 @@ describe('CsvViewer', () => {
     });
   });
 
+  describe('copy column', () => {
+    let writeText: ReturnType<typeof vi.fn>;
+
+    beforeEach(() => {
+      writeText = vi.fn().mockResolvedValue(undefined);
+      Object.defineProperty(navigator, 'clipboard', { value: { writeText }, configurable: true });
+    });
+
+    it('copies the chosen column, one value per line', async () => {
+      mockElectronAPI.s3.downloadContent.mockResolvedValue({
+        success: true,
+        content: 'name,city\nAlice,NYC\nBob,LA\nCarol,NYC',
+      });
+      render(<CsvViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('Alice')).toBeInTheDocument();
+      });
+      fireEvent.click(screen.getByText('Copy column'));
+      fireEvent.change(screen.getByLabelText('Column:'), { target: { value: '1' } });
+      fireEvent.click(screen.getByRole('button', { name: 'Copy' }));
+
+      expect(await screen.findByRole('status')).toHaveTextContent('Copied 3 values');
+      expect(writeText).toHaveBeenCalledWith('NYC\nLA\nNYC');
+
+      fireEvent.click(screen.getByLabelText('Unique values only'));
+      fireEvent.click(screen.getByRole('button', { name: 'Copy' }));
+
+      await waitFor(() => {
+        expect(writeText).toHaveBeenLastCalledWith('NYC\nLA');
+      });
+      expect(screen.getByRole('status')).toHaveTextContent('Copied 2 values');
+    });
+
+    it('copies only the loaded rows unless all rows are requested', async () => {
+      const lines = ['id'];
+      for (let i = 1; i <= 150; i++) {
+        lines.push(String(i));
+      }
+      mockElectronAPI.s3.downloadContent.mockResolvedValue({ success: true, content: lines.join('\n') });
+      render(<CsvViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('Showing 100 of 150 rows')).toBeInTheDocument();
+      });
+      fireEvent.click(screen.getByText('Copy column'));
+      fireEvent.click(screen.getByRole('button', { name: 'Copy' }));
+
+      expect(await screen.findByRole('status')).toHaveTextContent('Copied 100 values');
+
+      fireEvent.click(screen.getByLabelText('All rows'));
+      fireEvent.click(screen.getByRole('button', { name: 'Copy' }));
+
+      await waitFor(() => {
+        expect(screen.getByRole('status')).toHaveTextContent('Copied 150 values');
+      });
+      expect(writeText).toHaveBeenLastCalledWith(lines.slice(1).join('\n'));
+    });
+  });
+
   describe('lazy loading', () => {
     it('initially loads limited rows', async () => {
       // Create 150 rows to exceed initial load of 100
@@ -1,3 +1,4 @@
+import React from 'react';
 import { describe, it, expect, vi, beforeEach } from 'vitest';
 import { render, screen, waitFor, fireEvent } from '@testing-library/react';
 import FileList, { type FileListProps, type S3Object } from '../renderer/components/FileList';
@@ -118,6 +119,28 @@ describe('FileList', () => {
         expect(screen.getByText('1.5 MB')).toBeInTheDocument();
       });
     });
+
+    it('labels files stored outside the standard storage class', async () => {
+      mockElectronAPI.s3.listObjects.mockResolvedValue({
+        success: true,
+        result: {
+          objects: [
+            { key: 'hot.txt', size: 10, isPrefix: false, storageClass: 'STANDARD' },
+            { key: 'cold.txt', size: 10, isPrefix: false, storageClass: 'GLACIER_IR' },
+          ],
+          prefixes: [],
+          continuationToken: undefined,
+          isTruncated: false,
+          prefix: '',
+          keyCount: 2,
+        },
+      });
+
+      render(<FileList {...createDefaultProps({ currentProfile: 'test-profile', selectedBucket: 'my-bucket' })} />);
+
+      expect(await screen.findByText('GLACIER_IR')).toBeInTheDocument();
+      expect(screen.queryByText('STANDARD')).not.toBeInTheDocument();
+    });
   });
 
   describe('navigation', () => {
@@ -174,6 +197,32 @@ describe('FileList', () => {
       expect(onNavigate).toHaveBeenCalledWith('subfolder/');
     });
 
+    it('opens file on double click when onOpenFile is provided', async () => {
+      const onOpenFile = vi.fn();
+      const onDownloadFile = vi.fn();
+      mockElectronAPI.s3.listObjects.mockResolvedValue({
+        success: true,
+        result: {
+          objects: [{ key: 'data.parquet', size: 100, isPrefix: false }],
+          prefixes: [],
+          continuationToken: undefined,
+          isTruncated: false,
+          prefix: '',
+          keyCount: 1,
+        },
+      });
+
+      render(<FileList {...createDefaultProps({ currentProfile: 'test-profile', selectedBucket: 'my-bucket', onOpenFile, onDownloadFile })} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('data.parquet')).toBeInTheDocument();
+      });
+
+      fireEvent.doubleClick(screen.getByText('data.parquet'));
+      expect(onOpenFile).toHaveBeenCalledWith(expect.objectContaining({ key: 'data.parquet' }));
+      expect(onDownloadFile).not.toHaveBeenCalled();
+    });
+
     it('displays breadcrumb for current path', async () => {
       mockElectronAPI.s3.listObjects.mockResolvedValue({
         success: true,
@@ -220,6 +269,38 @@ describe('FileList', () => {
       expect(onNavigate).toHaveBeenCalledWith('folder1/');
     });
 
+    it('shows a collapsed folder chain as a single breadcrumb', async () => {
+      const onNavigate = vi.fn();
+      mockElectronAPI.s3.listObjects.mockResolvedValue({
+        success: true,
+        result: {
+          objects: [],
+          prefixes: [],
+          continuationToken: undefined,
+          isTruncated: false,
+          prefix: 'events/year=2024/month=01/day=05/',
+          keyCount: 0,
+        },
+      });
+
+      render(<FileList {...createDefaultProps({
+        currentProfile: 'test-profile',
+        selectedBucket: 'my-bucket',
+        currentPrefix: 'events/year=2024/month=01/day=05/',
+        collapsedPrefixes: new Set(['events/year=2024/', 'events/year=2024/month=01/']),
+        onNavigate,
+      })} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('year=2024/month=01/day=05')).toBeInTheDocument();
+      });
+      expect(screen.getByText('events')).toBeInTheDocument();
+      expect(screen.queryByText('year=2024')).not.toBeInTheDocument();
+
+      fireEvent.click(screen.getByText('year=2024/month=01/day=05'));
+      expect(onNavigate).toHaveBeenCalledWith('events/year=2024/month=01/day=05/');
+    });
+
     it('navigates to bucket root when bucket name clicked', async () => {
       const onNavigate = vi.fn();
       mockElectronAPI.s3.listObjects.mockResolvedValue({
@@ -267,6 +348,55 @@ describe('FileList', () => {
       fireEvent.click(screen.getByTitle('Go to parent folder'));
       expect(onNavigate).toHaveBeenCalledWith('a/b/');
     });
+
+    it('cancels a listing still in flight when navigating to another folder', async () => {
+      let resolveSlow: (value: unknown) => void = () => {};
+      mockElectronAPI.s3.listObjects
+        .mockReturnValueOnce(new Promise((resolve) => { resolveSlow = resolve; }))
+        .mockResolvedValueOnce({
+          success: true,
+          result: {
+            objects: [{ key: 'new/current.txt', size: 1, isPrefix: false }],
+            prefixes: [],
+            continuationToken: undefined,
+            isTruncated: false,
+            prefix: 'new/',
+            keyCount: 1,
+          },
+        });
+
+      const props = createDefaultProps({ currentProfile: 'test-profile', selectedBucket: 'my-bucket', currentPrefix: 'old/' });
+      const { rerender } = render(<FileList {...props} />);
+
+      await waitFor(() => {
+        expect(mockElectronAPI.s3.listObjects).toHaveBeenCalledTimes(1);
+      });
+      const slowOperationId = mockElectronAPI.s3.listObjects.mock.calls[0][1];
+
+      rerender(<FileList {...props} currentPrefix="new/" />);
+
+      await waitFor(() => {
+        expect(screen.getByText('current.txt')).toBeInTheDocument();
+      });
+      expect(mockElectronAPI.s3.cancelOperation).toHaveBeenCalledWith(slowOperationId);
+
+      // The cancelled listing's late result must not replace the new folder's contents
+      resolveSlow({
+        success: true,
+        result: {
+          objects: [{ key: 'old/stale.txt', size: 1, isPrefix: false }],
+          prefixes: [],
+          continuationToken: undefined,
+          isTruncated: false,
+          prefix: 'old/',
+          keyCount: 1,
+        },
+      });
+      await waitFor(() => {
+        expect(screen.getByText('current.txt')).toBeInTheDocument();
+      });
+      expect(screen.queryByText('stale.txt')).not.toBeInTheDocument();
+    });
   });
 
   describe('file selection', () => {
@@ -494,6 +624,99 @@ describe('FileList', () => {
     });
   });
 
+  describe('selection across pages', () => {
+    const firstPage = {
+      success: true,
+      result: {
+        objects: [
+          { key: 'file-b.txt', size: 100, isPrefix: false },
+          { key: 'file-d.txt', size: 100, isPrefix: false },
+        ],
+        prefixes: [],
+        continuationToken: 'page2-token',
+        isTruncated: true,
+        prefix: '',
+        keyCount: 2,
+      },
+    };
+    // Sorting puts these rows ahead of the first page's rows, shifting their positions
+    const secondPage = {
+      success: true,
+      result: {
+        objects: [{ key: 'file-a.txt', size: 100, isPrefix: false }],
+        prefixes: [{ key: 'folder/', size: 0, isPrefix: true }],
+        continuationToken: undefined,
+        isTruncated: false,
+        prefix: '',
+        keyCount: 2,
+      },
+    };
+
+    // Holds selection the way App does, so rows re-render with the current selection
+    function SelectionHarness({ onSelectFiles }: { onSelectFiles: (files: S3Object[]) => void }) {
+      const [selectedFile, setSelectedFile] = React.useState<S3Object | null>(null);
+      const [selectedFiles, setSelectedFiles] = React.useState<S3Object[]>([]);
+      // Stable callbacks, since FileList clears the selection whenever they change
+      const handleSelectFiles = React.useCallback((files: S3Object[]) => {
+        setSelectedFiles(files);
+        onSelectFiles(files);
+      }, [onSelectFiles]);
+      return (
+        <FileList
+          {...createDefaultProps({
+            currentProfile: 'test-profile',
+            selectedBucket: 'my-bucket',
+            selectedFile,
+            selectedFiles,
+          })}
+          onSelectFile={setSelectedFile}
+          onSelectFiles={handleSelectFiles}
+        />
+      );
+    }
+
+    it('keeps the same object selected after a page is appended', async () => {
+      mockElectronAPI.s3.listObjects.mockResolvedValueOnce(firstPage).mockResolvedValueOnce(secondPage);
+      const { container } = render(<SelectionHarness onSelectFiles={vi.fn()} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('file-d.txt')).toBeInTheDocument();
+      });
+      fireEvent.click(screen.getByText('file-d.txt'));
+
+      fireEvent.scroll(container.querySelector('.file-list-table-wrapper')!);
+      await waitFor(() => {
+        expect(screen.getByText('file-a.txt')).toBeInTheDocument();
+      });
+
+      expect(screen.getByText('file-d.txt').closest('tr')).toHaveClass('selected');
+      expect(screen.getByText('file-a.txt').closest('tr')).not.toHaveClass('selected');
+    });
+
+    it('anchors shift+click ranges to the clicked object, not its old row index', async () => {
+      const onSelectFiles = vi.fn();
+      mockElectronAPI.s3.listObjects.mockResolvedValueOnce(firstPage).mockResolvedValueOnce(secondPage);
+      const { container } = render(<SelectionHarness onSelectFiles={onSelectFiles} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('file-d.txt')).toBeInTheDocument();
+      });
+      fireEvent.click(screen.getByText('file-d.txt'));
+
+      fireEvent.scroll(container.querySelector('.file-list-table-wrapper')!);
+      await waitFor(() => {
+        expect(screen.getByText('file-a.txt')).toBeInTheDocument();
+      });
+
+      fireEvent.click(screen.getByText('file-b.txt'), { shiftKey: true });
+
+      expect(onSelectFiles).toHaveBeenLastCalledWith([
+        expect.objectContaining({ key: 'file-b.txt' }),
+        expect.objectContaining({ key: 'file-d.txt' }),
+      ]);
+    });
+  });
+
   describe('keyboard navigation', () => {
     it('handles Enter key on file row', async () => {
       const onSelectFile = vi.fn();
@@ -599,12 +822,60 @@ describe('FileList', () => {
       fireEvent.change(searchInput, { target: { value: 'alpha' } });
 
       await waitFor(() => {
-        expect(screen.getByText('alpha.txt')).toBeInTheDocument();
+        expect(screen.getByTitle('alpha.txt')).toHaveTextContent('alpha.txt');
         expect(screen.queryByText('beta.json')).not.toBeInTheDocument();
         expect(screen.queryByText('gamma.png')).not.toBeInTheDocument();
       });
     });
 
+    it('matches forgivingly and highlights the matched characters', async () => {
+      render(<FileList {...createDefaultProps({ currentProfile: 'test-profile', selectedBucket: 'my-bucket' })} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('alpha.txt')).toBeInTheDocument();
+      });
+
+      fireEvent.change(screen.getByPlaceholderText('Quick filter...'), { target: { value: 'atxt' } });
+
+      await waitFor(() => {
+        expect(screen.queryByText('beta.json')).not.toBeInTheDocument();
+      });
+      const name = screen.getByTitle('alpha.txt');
+      expect(Array.from(name.querySelectorAll('mark')).map((mark) => mark.textContent)).toEqual(['a', 'txt']);
+    });
+
+    it('keeps the selection of items the filter still shows', async () => {
+      const onSelectFiles = vi.fn();
+      const onSelectFile = vi.fn();
+      const alpha = { key: 'alpha.txt', size: 500, isPrefix: false };
+      const beta = { key: 'beta.json', size: 1000, isPrefix: false };
+      render(
+        <FileList
+          {...createDefaultProps({
+            currentProfile: 'test-profile',
+            selectedBucket: 'my-bucket',
+            selectedFile: beta,
+            selectedFiles: [alpha, beta],
+            onSelectFiles,
+            onSelectFile,
+          })}
+        />
+      );
+
+      await waitFor(() => {
+        expect(screen.getByText('alpha.txt')).toBeInTheDocument();
+      });
+      onSelectFiles.mockClear();
+      onSelectFile.mockClear();
+
+      fireEvent.change(screen.getByPlaceholderText('Quick filter...'), { target: { value: 'alpha' } });
+
+      await waitFor(() => {
+        expect(onSelectFiles).toHaveBeenCalledWith([alpha]);
+      });
+      expect(onSelectFile).toHaveBeenCalledWith(null);
+    });
+
     it('filters by file type', async () => {
       render(<FileList {...createDefaultProps({ currentProfile: 'test-profile', selectedBucket: 'my-bucket' })} />);
 
@@ -960,4 +1231,72 @@ describe('FileList', () => {
       expect(onSelectFile).not.toHaveBeenCalledWith(expect.objectContaining({ key: 'prefix/nonexistent.txt' }));
     });
   });
+
+  describe('thumbnails', () => {
+    it('only fetches thumbnails for the rows on screen', async () => {
+      const objects = Array.from({ length: 60 }, (_, i) => ({
+        key: `photo-${String(i).padStart(2, '0')}.png`,
+        size: 1024,
+        etag: `"etag-${i}"`,
+        isPrefix: false,
+      }));
+      mockElectronAPI.s3.listObjects.mockResolvedValue({
+        success: true,
+        result: {
+          objects,
+          prefixes: [],
+          continuationToken: undefined,
+          isTruncated: false,
+          prefix: '',
+          keyCount: objects.length,
+        },
+      });
+
+      render(<FileList {...createDefaultProps({ currentProfile: 'test-profile', selectedBucket: 'my-bucket' })} />);
+
+      await waitFor(() => {
+        expect(mockElectronAPI.s3.downloadBinaryContent).toHaveBeenCalledWith('my-bucket', 'photo-00.png');
+      });
+
+      const fetchedKeys = mockElectronAPI.s3.downloadBinaryContent.mock.calls.map(call => call[1]);
+      expect(fetchedKeys).not.toContain('photo-59.png');
+      expect(fetchedKeys.length).toBeLessThan(objects.length);
+    });
+  });
+
+  describe('metadata column', () => {
+    it('only fetches metadata for the rows on screen', async () => {
+      const objects = Array.from({ length: 60 }, (_, i) => ({
+        key: `data-${String(i).padStart(2, '0')}.json`,
+        size: 1024,
+        isPrefix: false,
+      }));
+      mockElectronAPI.s3.listObjects.mockResolvedValue({
+        success: true,
+        result: {
+          objects,
+          prefixes: [],
+          continuationToken: undefined,
+          isTruncated: false,
+          prefix: '',
+          keyCount: objects.length,
+        },
+      });
+
+      render(<FileList {...createDefaultProps({ currentProfile: 'test-profile', selectedBucket: 'my-bucket' })} />);
+      await waitFor(() => {
+        expect(screen.getByText('data-00.json')).toBeInTheDocument();
+      });
+
+      fireEvent.change(screen.getByLabelText('Metadata column key'), { target: { value: 'owner' } });
+
+      await waitFor(() => {
+        expect(mockElectronAPI.s3.getObjectMetadata).toHaveBeenCalledWith('my-bucket', 'data-09.json');
+      });
+
+      const fetchedKeys = mockElectronAPI.s3.getObjectMetadata.mock.calls.map(call => call[1]);
+      expect(fetchedKeys).not.toContain('data-59.json');
+      expect(new Set(fetchedKeys).size).toBe(fetchedKeys.length);
+    });
+  });
 });
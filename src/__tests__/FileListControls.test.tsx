@@ -4,6 +4,8 @@ import FileListControls, {
   sortItems,
   filterByType,
   filterBySearch,
+  quickFilter,
+  filterByMetadata,
   FILE_TYPE_FILTERS,
   SortConfig,
 } from '../renderer/components/FileListControls';
@@ -84,6 +86,23 @@ describe('FileListControls Component', () => {
       fireEvent.click(screen.getByTitle('Clear filter'));
       expect(onSearchChange).toHaveBeenCalledWith('');
     });
+
+    it('offers case-sensitive matching only for wildcard queries', () => {
+      const onGlobCaseSensitiveChange = vi.fn();
+      const { rerender } = render(
+        <FileListControls {...defaultProps} searchQuery="report" onGlobCaseSensitiveChange={onGlobCaseSensitiveChange} />
+      );
+      expect(screen.queryByRole('button', { name: 'Match case' })).not.toBeInTheDocument();
+
+      rerender(
+        <FileListControls {...defaultProps} searchQuery="*.CSV" onGlobCaseSensitiveChange={onGlobCaseSensitiveChange} />
+      );
+      const toggle = screen.getByRole('button', { name: 'Match case' });
+      expect(toggle).toHaveAttribute('aria-pressed', 'false');
+
+      fireEvent.click(toggle);
+      expect(onGlobCaseSensitiveChange).toHaveBeenCalledWith(true);
+    });
   });
 
   describe('filter functionality', () => {
@@ -98,6 +117,36 @@ describe('FileListControls Component', () => {
     });
   });
 
+  describe('metadata column', () => {
+    it('does not render metadata inputs without handlers', () => {
+      render(<FileListControls {...defaultProps} />);
+      expect(screen.queryByLabelText('Metadata column key')).not.toBeInTheDocument();
+    });
+
+    it('shows request cost warning and value filter once a key is entered', () => {
+      render(
+        <FileListControls
+          {...defaultProps}
+          metadataKey="env"
+          onMetadataKeyChange={vi.fn()}
+          metadataValue=""
+          onMetadataValueChange={vi.fn()}
+        />
+      );
+      expect(screen.getByLabelText('Metadata filter value')).toBeInTheDocument();
+      expect(screen.getByRole('note')).toHaveTextContent('extra request per visible file');
+    });
+
+    it('calls onMetadataKeyChange when typing a key', () => {
+      const onMetadataKeyChange = vi.fn();
+      render(
+        <FileListControls {...defaultProps} onMetadataKeyChange={onMetadataKeyChange} />
+      );
+      fireEvent.change(screen.getByLabelText('Metadata column key'), { target: { value: 'env' } });
+      expect(onMetadataKeyChange).toHaveBeenCalledWith('env');
+    });
+  });
+
   describe('disabled state', () => {
     it('disables search input when disabled', () => {
       render(<FileListControls {...defaultProps} disabled />);
@@ -216,6 +265,30 @@ describe('sortItems', () => {
     expect(sorted[0].isPrefix).toBe(true);
     expect(sorted[1].isPrefix).toBe(false);
   });
+
+  it('keeps folders first for every sort field and direction', () => {
+    const fields: SortConfig['field'][] = ['name', 'size', 'lastModified'];
+    for (const field of fields) {
+      for (const direction of ['asc', 'desc'] as const) {
+        const sorted = sortItems(items, { field, direction });
+        expect(sorted.map(item => item.isPrefix)).toEqual([true, true, false, false, false]);
+      }
+    }
+  });
+
+  it('orders items with equal sizes or dates by name in either direction', () => {
+    const date = new Date('2024-01-01');
+    const tied: S3Object[] = [
+      createItem('Charlie.txt', 100, date, false),
+      createItem('alpha.txt', 100, date, false),
+      createItem('bravo.txt', 100, date, false),
+    ];
+
+    for (const field of ['size', 'lastModified'] as const) {
+      expect(sortItems(tied, { field, direction: 'asc' }).map(item => item.key)).toEqual(['alpha.txt', 'bravo.txt', 'Charlie.txt']);
+      expect(sortItems(tied, { field, direction: 'desc' }).map(item => item.key)).toEqual(['alpha.txt', 'bravo.txt', 'Charlie.txt']);
+    }
+  });
 });
 
 describe('filterByType', () => {
@@ -341,3 +414,94 @@ describe('filterBySearch', () => {
     expect(filtered[0].key).toBe('data.json');
   });
 });
+
+describe('quickFilter', () => {
+  const createItem = (key: string, isPrefix = false): S3Object => ({ key, size: 0, isPrefix });
+
+  it('returns the indices of matching items with the matched character positions', () => {
+    const items = [createItem('logs/app-2024.log'), createItem('logs/archive/', true), createItem('logs/readme.md')];
+
+    expect(quickFilter(items, 'ap24', 'logs/')).toEqual([{ index: 0, positions: [0, 1, 4, 7] }]);
+  });
+
+  it('matches the display name without the prefix or trailing slash', () => {
+    const items = [createItem('data/archive/', true), createItem('data/notes.txt')];
+
+    // "data" is only in the prefix, so no item matches on it
+    expect(quickFilter(items, 'data', 'data/')).toEqual([]);
+    expect(quickFilter(items, 'arch', 'data/')).toEqual([{ index: 0, positions: [0, 1, 2, 3] }]);
+  });
+
+  it('keeps matching folders alongside files, in list order', () => {
+    const items = [createItem('reports/', true), createItem('summary.txt'), createItem('report.pdf')];
+
+    expect(quickFilter(items, 'rep', '').map((match) => match.index)).toEqual([0, 2]);
+  });
+
+  it('ignores case and spaces in the query', () => {
+    const items = [createItem('Final Report.PDF')];
+
+    expect(quickFilter(items, 'f rep', '')).toEqual([{ index: 0, positions: [0, 6, 7, 8] }]);
+  });
+
+  it('matches everything, with nothing highlighted, for an empty query', () => {
+    const items = [createItem('a.txt'), createItem('b/', true)];
+
+    expect(quickFilter(items, '  ', '')).toEqual([
+      { index: 0, positions: [] },
+      { index: 1, positions: [] },
+    ]);
+  });
+
+  it('matches queries with wildcards as globs, with nothing highlighted', () => {
+    const items = [createItem('data/part-0.parquet'), createItem('data/parquet-notes.txt'), createItem('data/raw/', true)];
+
+    expect(quickFilter(items, '*.parquet', 'data/')).toEqual([{ index: 0, positions: [] }]);
+    expect(quickFilter(items, ' r?w ', 'data/')).toEqual([{ index: 2, positions: [] }]);
+  });
+
+  it('keeps "*" within a folder and lets "**" cross folders', () => {
+    const items = [createItem('logs/app.LOG'), createItem('logs/2024/01/app.log'), createItem('logs/2024/summary.csv')];
+
+    expect(quickFilter(items, '*.log', 'logs/').map((match) => match.index)).toEqual([0]);
+    expect(quickFilter(items, '**/*.log', 'logs/').map((match) => match.index)).toEqual([0, 1]);
+    expect(quickFilter(items, '2024/*', 'logs/').map((match) => match.index)).toEqual([2]);
+  });
+
+  it('can match globs case-sensitively', () => {
+    const items = [createItem('logs/app.LOG'), createItem('logs/2024/01/app.log')];
+
+    expect(quickFilter(items, '**.log', 'logs/', { caseSensitive: true }).map((match) => match.index)).toEqual([1]);
+  });
+});
+});
+
+describe('filterByMetadata', () => {
+  const items: S3Object[] = [
+    { key: 'folder/', size: 0, isPrefix: true },
+    { key: 'prod.csv', size: 1, isPrefix: false, metadata: { env: 'prod', owner: 'team-a' } },
+    { key: 'dev.csv', size: 1, isPrefix: false, metadata: { env: 'dev' } },
+    { key: 'untagged.csv', size: 1, isPrefix: false, metadata: {} },
+    { key: 'pending.csv', size: 1, isPrefix: false },
+  ];
+
+  it('returns all items when key or value is empty', () => {
+    expect(filterByMetadata(items, '', 'prod')).toHaveLength(items.length);
+    expect(filterByMetadata(items, 'env', '  ')).toHaveLength(items.length);
+  });
+
+  it('keeps only files with matching key=value plus folders', () => {
+    const filtered = filterByMetadata(items, 'env', 'prod');
+    expect(filtered.map((i) => i.key)).toEqual(['folder/', 'prod.csv']);
+  });
+
+  it('matches keys case-insensitively but values exactly', () => {
+    expect(filterByMetadata(items, 'ENV', 'prod').map((i) => i.key)).toContain('prod.csv');
+    expect(filterByMetadata(items, 'env', 'PROD').map((i) => i.key)).not.toContain('prod.csv');
+  });
+
+  it('hides files whose metadata has not been fetched', () => {
+    const filtered = filterByMetadata(items, 'env', 'dev');
+    expect(filtered.map((i) => i.key)).not.toContain('pending.csv');
+  });
+});
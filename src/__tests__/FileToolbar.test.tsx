@@ -454,8 +454,78 @@ describe('FileToolbar', () => {
     });
   });
 
+  describe('Open with default app button', () => {
+    it('is only shown when a handler is provided', () => {
+      render(<FileToolbar {...defaultProps} selectedFile={{ key: 'report.xlsx', isPrefix: false }} selectedCount={1} />);
+
+      expect(screen.queryByTitle('Open with default app')).not.toBeInTheDocument();
+    });
+
+    it('opens the selected file with its default app', () => {
+      const onOpenExternally = vi.fn();
+      render(
+        <FileToolbar
+          {...defaultProps}
+          selectedFile={{ key: 'report.xlsx', isPrefix: false }}
+          selectedCount={1}
+          onOpenExternally={onOpenExternally}
+        />
+      );
+
+      fireEvent.click(getButtonByTitle('Open with default app'));
+      expect(onOpenExternally).toHaveBeenCalled();
+    });
+
+    it('is disabled for folders and multiple files', () => {
+      const { unmount } = render(
+        <FileToolbar
+          {...defaultProps}
+          selectedFile={{ key: 'folder/', isPrefix: true }}
+          selectedCount={1}
+          onOpenExternally={vi.fn()}
+        />
+      );
+      expect(getButtonByTitle('Select a file to open with its default app')).toBeDisabled();
+      unmount();
+
+      render(
+        <FileToolbar
+          {...defaultProps}
+          selectedFile={{ key: 'a.docx', isPrefix: false }}
+          selectedCount={2}
+          onOpenExternally={vi.fn()}
+        />
+      );
+      expect(getButtonByTitle('Open with default app not available for multiple files')).toBeDisabled();
+    });
+  });
+
+  describe('S3 Select query button', () => {
+    it('is only shown when a query handler is given', () => {
+      render(<FileToolbar {...defaultProps} selectedFile={{ key: 'data.csv', isPrefix: false }} />);
+      expect(screen.queryByTitle('Query with S3 Select')).not.toBeInTheDocument();
+    });
+
+    it('is enabled for CSV, JSON and JSON lines files', () => {
+      const onQuery = vi.fn();
+      for (const key of ['data.csv', 'logs/events.json.gz', 'logs/events.ndjson']) {
+        const { unmount } = render(
+          <FileToolbar {...defaultProps} onQuery={onQuery} selectedFile={{ key, isPrefix: false }} />
+        );
+        fireEvent.click(getButtonByTitle('Query with S3 Select'));
+        unmount();
+      }
+      expect(onQuery).toHaveBeenCalledTimes(3);
+    });
+
+    it('is disabled for other files', () => {
+      render(<FileToolbar {...defaultProps} onQuery={vi.fn()} selectedFile={{ key: 'photo.png', isPrefix: false }} />);
+      expect(getButtonByTitle('Select a CSV or JSON file to query')).toBeDisabled();
+    });
+  });
+
   describe('multiselect behavior', () => {
-    it('disables Download button when multiple files are selected', () => {
+    it('enables Download button for all selected files when multiple files are selected', () => {
       render(
         <FileToolbar
           {...defaultProps}
@@ -464,8 +534,8 @@ describe('FileToolbar', () => {
         />
       );
 
-      const downloadButton = getButtonByTitle(/Download not available for multiple files/);
-      expect(downloadButton).toBeDisabled();
+      const downloadButton = getButtonByTitle('Download 2 selected files');
+      expect(downloadButton).not.toBeDisabled();
     });
 
     it('disables Rename button when multiple files are selected', () => {
@@ -0,0 +1,77 @@
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, waitFor, fireEvent } from '@testing-library/react';
+import HexViewer, { formatHexRows } from '../renderer/components/HexViewer';
+import { mockElectronAPI } from './setup';
+
+describe('formatHexRows', () => {
+  it('formats offset, hex bytes and ascii', () => {
+    const rows = formatHexRows(new TextEncoder().encode('Hello\n'));
+
+    expect(rows).toEqual([{ offset: '00000000', hex: '48 65 6c 6c 6f 0a', ascii: 'Hello.' }]);
+  });
+
+  it('splits into rows of 16 bytes', () => {
+    const rows = formatHexRows(new Uint8Array(20));
+
+    expect(rows).toHaveLength(2);
+    expect(rows[1].offset).toBe('00000010');
+    expect(rows[1].hex).toBe('00 00 00 00');
+  });
+
+  it('returns no rows for empty data', () => {
+    expect(formatHexRows(new Uint8Array())).toEqual([]);
+  });
+});
+
+describe('HexViewer', () => {
+  const defaultProps = {
+    bucket: 'test-bucket',
+    fileKey: 'data/blob.bin',
+    fileName: 'blob.bin',
+    fileSize: 200000,
+    onClose: vi.fn(),
+  };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    mockElectronAPI.s3.downloadPartialContent.mockResolvedValue({
+      success: true,
+      data: new Uint8Array([0xde, 0xad, 0xbe, 0xef]),
+    });
+  });
+
+  it('loads the leading bytes of the file', async () => {
+    render(<HexViewer {...defaultProps} />);
+
+    await waitFor(() => {
+      expect(screen.getByText('de ad be ef', { exact: false })).toBeInTheDocument();
+    });
+    expect(mockElectronAPI.s3.downloadPartialContent).toHaveBeenCalledWith('test-bucket', 'data/blob.bin', 65536, 0, undefined);
+  });
+
+  it('indicates when only part of the file is shown', async () => {
+    render(<HexViewer {...defaultProps} />);
+
+    await waitFor(() => {
+      expect(screen.getByText(/Showing first 4 B of/)).toBeInTheDocument();
+    });
+  });
+
+  it('shows an error when the download fails', async () => {
+    mockElectronAPI.s3.downloadPartialContent.mockResolvedValueOnce({ success: false, error: 'Access Denied' });
+
+    render(<HexViewer {...defaultProps} />);
+
+    await waitFor(() => {
+      expect(screen.getByText('Access Denied')).toBeInTheDocument();
+    });
+  });
+
+  it('closes on Escape', async () => {
+    render(<HexViewer {...defaultProps} />);
+
+    fireEvent.keyDown(screen.getByText('blob.bin'), { key: 'Escape' });
+
+    expect(defaultProps.onClose).toHaveBeenCalled();
+  });
+});
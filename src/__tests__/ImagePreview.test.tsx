@@ -52,7 +52,8 @@ describe('ImagePreview', () => {
       await waitFor(() => {
         expect(mockElectronAPI.s3.downloadBinaryContent).toHaveBeenCalledWith(
           'test-bucket',
-          'path/to/image.png'
+          'path/to/image.png',
+          undefined
         );
       });
 
@@ -0,0 +1,83 @@
+import React from 'react';
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, fireEvent, waitFor } from '@testing-library/react';
+import IncompleteUploadsDialog, { formatUploadAge } from '../renderer/components/IncompleteUploadsDialog';
+import { mockElectronAPI } from './setup';
+
+describe('IncompleteUploadsDialog', () => {
+  const defaultProps = {
+    isOpen: true,
+    bucket: 'backups',
+    isReadOnly: false,
+    onClose: vi.fn(),
+  };
+
+  const uploads = [
+    { key: 'db/dump-1.tar', uploadId: 'u1', initiated: new Date(Date.now() - 30 * 24 * 60 * 60 * 1000) },
+    { key: 'db/dump-2.tar', uploadId: 'u2', initiated: new Date(Date.now() - 2 * 60 * 60 * 1000) },
+  ];
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it('should list the incomplete uploads with their age', async () => {
+    mockElectronAPI.s3.listMultipartUploads.mockResolvedValueOnce({ success: true, uploads });
+    render(<IncompleteUploadsDialog {...defaultProps} />);
+
+    expect(await screen.findByText('db/dump-1.tar')).toBeInTheDocument();
+    expect(screen.getByText('30 days ago')).toBeInTheDocument();
+    expect(screen.getByText('2 hours ago')).toBeInTheDocument();
+    expect(mockElectronAPI.s3.listMultipartUploads).toHaveBeenCalledWith('backups');
+  });
+
+  it('should say when there is nothing to clean up', async () => {
+    render(<IncompleteUploadsDialog {...defaultProps} />);
+
+    expect(await screen.findByText('No incomplete uploads')).toBeInTheDocument();
+  });
+
+  it('should abort one upload and drop it from the list', async () => {
+    mockElectronAPI.s3.listMultipartUploads.mockResolvedValueOnce({ success: true, uploads });
+    render(<IncompleteUploadsDialog {...defaultProps} />);
+
+    fireEvent.click(await screen.findByLabelText('Abort upload of db/dump-1.tar'));
+
+    await waitFor(() => {
+      expect(screen.queryByText('db/dump-1.tar')).not.toBeInTheDocument();
+    });
+    expect(screen.getByText('db/dump-2.tar')).toBeInTheDocument();
+    expect(mockElectronAPI.s3.abortMultipartUpload).toHaveBeenCalledWith('backups', 'db/dump-1.tar', 'u1');
+  });
+
+  it('should ask for confirmation before aborting everything', async () => {
+    mockElectronAPI.s3.listMultipartUploads.mockResolvedValueOnce({ success: true, uploads });
+    mockElectronAPI.s3.abortMultipartUpload
+      .mockResolvedValueOnce({ success: true })
+      .mockResolvedValueOnce({ success: false, error: 'Access Denied' });
+    render(<IncompleteUploadsDialog {...defaultProps} />);
+
+    fireEvent.click(await screen.findByText('Abort all'));
+    expect(mockElectronAPI.s3.abortMultipartUpload).not.toHaveBeenCalled();
+    fireEvent.click(screen.getByText('Confirm: abort 2'));
+
+    expect(await screen.findByRole('alert')).toHaveTextContent('db/dump-2.tar: Access Denied');
+    expect(screen.queryByText('db/dump-1.tar')).not.toBeInTheDocument();
+    expect(mockElectronAPI.s3.abortMultipartUpload).toHaveBeenCalledTimes(2);
+  });
+
+  it('should not allow aborting with a read-only profile', async () => {
+    mockElectronAPI.s3.listMultipartUploads.mockResolvedValueOnce({ success: true, uploads });
+    render(<IncompleteUploadsDialog {...defaultProps} isReadOnly={true} />);
+
+    expect(await screen.findByLabelText('Abort upload of db/dump-1.tar')).toBeDisabled();
+    expect(screen.getByText('Abort all')).toBeDisabled();
+  });
+
+  it('should format upload ages', () => {
+    const now = Date.parse('2024-06-10T12:00:00Z');
+    expect(formatUploadAge(undefined, now)).toBe('Unknown');
+    expect(formatUploadAge('2024-06-10T11:30:00Z', now)).toBe('Less than an hour ago');
+    expect(formatUploadAge('2024-06-09T11:00:00Z', now)).toBe('1 day ago');
+  });
+});
@@ -38,7 +38,8 @@ describe('JsonViewer', () => {
       await waitFor(() => {
         expect(mockElectronAPI.s3.downloadContent).toHaveBeenCalledWith(
           'test-bucket',
-          'path/to/config.json'
+          'path/to/config.json',
+          undefined
         );
       });
     });
@@ -180,6 +181,21 @@ describe('JsonViewer', () => {
 
       expect(screen.getByText('s3://test-bucket/path/to/config.json')).toBeInTheDocument();
     });
+
+    it('shows the codec of decompressed content in the footer', async () => {
+      mockElectronAPI.s3.downloadContent.mockResolvedValue({
+        success: true,
+        content: '{"name": "test"}',
+        compression: 'zstd',
+      });
+
+      render(<JsonViewer {...defaultProps} fileKey="path/to/config.json.zst" fileName="config.json.zst" />);
+
+      await waitFor(() => {
+        expect(screen.getByText('"test"')).toBeInTheDocument();
+      });
+      expect(screen.getByText('zstd')).toHaveAttribute('title', 'Decompressed for viewing');
+    });
   });
 
   describe('view mode toggle', () => {
@@ -267,6 +267,103 @@ describe('NavigationBar', () => {
         expect(mockOnNavigate).toHaveBeenCalledWith('my-bucket', 'folder/');
       });
     });
+
+    it('passes the versionId of a URL naming an object version', async () => {
+      mockElectronAPI.s3.parseUrl.mockResolvedValue({
+        success: true,
+        bucket: 'my-bucket',
+        key: 'data/file.json',
+        versionId: 'v1',
+      });
+
+      render(
+        <NavigationBar
+          currentBucket={null}
+          currentPrefix=""
+          onNavigate={mockOnNavigate}
+        />
+      );
+
+      const input = screen.getByRole('textbox', { name: /s3 url/i });
+      await userEvent.clear(input);
+      await userEvent.type(input, 'https://my-bucket.s3.amazonaws.com/data/file.json?versionId=v1');
+
+      fireEvent.click(screen.getByRole('button', { name: /go/i }));
+
+      await waitFor(() => {
+        expect(mockOnNavigate).toHaveBeenCalledWith('my-bucket', 'data/', 'data/file.json', 'v1');
+      });
+    });
+  });
+
+  describe('multi-line paste', () => {
+    function pasteText(text: string) {
+      const input = screen.getByRole('textbox', { name: /s3 url/i });
+      fireEvent.paste(input, {
+        clipboardData: { getData: () => text },
+      });
+    }
+
+    it('navigates to the folder and selects files pasted from the same folder', async () => {
+      mockElectronAPI.s3.parseUrlList.mockResolvedValue({
+        success: true,
+        urls: [
+          { bucket: 'test-bucket', key: 'data/a.csv' },
+          { bucket: 'test-bucket', key: 'data/b.csv' },
+        ],
+        commonPrefix: { bucket: 'test-bucket', prefix: 'data/' },
+      });
+
+      render(<NavigationBar currentBucket={null} currentPrefix="" onNavigate={mockOnNavigate} />);
+      pasteText('s3://test-bucket/data/a.csv\ns3://test-bucket/data/b.csv');
+
+      await waitFor(() => {
+        expect(mockOnNavigate).toHaveBeenCalledWith('test-bucket', 'data/', ['data/a.csv', 'data/b.csv']);
+      });
+    });
+
+    it('navigates to the common prefix when files are in different folders', async () => {
+      mockElectronAPI.s3.parseUrlList.mockResolvedValue({
+        success: true,
+        urls: [
+          { bucket: 'test-bucket', key: 'data/2024/a.csv' },
+          { bucket: 'test-bucket', key: 'data/2025/b.csv' },
+        ],
+        commonPrefix: { bucket: 'test-bucket', prefix: 'data/' },
+      });
+
+      render(<NavigationBar currentBucket={null} currentPrefix="" onNavigate={mockOnNavigate} />);
+      pasteText('s3://test-bucket/data/2024/a.csv\ns3://test-bucket/data/2025/b.csv');
+
+      await waitFor(() => {
+        expect(mockOnNavigate).toHaveBeenCalledWith('test-bucket', 'data/');
+      });
+    });
+
+    it('shows an error when pasted URLs span multiple buckets', async () => {
+      mockElectronAPI.s3.parseUrlList.mockResolvedValue({
+        success: true,
+        urls: [
+          { bucket: 'bucket-a', key: 'a.csv' },
+          { bucket: 'bucket-b', key: 'b.csv' },
+        ],
+      });
+
+      render(<NavigationBar currentBucket={null} currentPrefix="" onNavigate={mockOnNavigate} />);
+      pasteText('s3://bucket-a/a.csv\ns3://bucket-b/b.csv');
+
+      await waitFor(() => {
+        expect(screen.getByText(/span multiple buckets/)).toBeInTheDocument();
+      });
+      expect(mockOnNavigate).not.toHaveBeenCalled();
+    });
+
+    it('does not intercept single-line pastes', () => {
+      render(<NavigationBar currentBucket={null} currentPrefix="" onNavigate={mockOnNavigate} />);
+      pasteText('s3://test-bucket/data/a.csv');
+
+      expect(mockElectronAPI.s3.parseUrlList).not.toHaveBeenCalled();
+    });
   });
 
   describe('keyboard navigation', () => {
@@ -96,20 +96,40 @@ describe('ParquetViewer', () => {
   });
 
   describe('file size validation', () => {
-    it('shows error for files exceeding max size (100MB)', async () => {
+    it('shows only the footer schema of files exceeding max size (100MB)', async () => {
       const largeFileProps = {
         ...defaultProps,
         fileSize: 150 * 1024 * 1024, // 150MB
       };
+      mockElectronAPI.s3.downloadPartialContent.mockResolvedValue({ success: true, data: new Uint8Array(8) });
+      (parquetMetadataAsync as ReturnType<typeof vi.fn>).mockImplementation(
+        async (file: { byteLength: number; slice: (start: number) => Promise<ArrayBuffer> }) => {
+          await file.slice(file.byteLength - 8);
+          return { schema: [{ name: 'root' }, { name: 'id' }, { name: 'city' }], num_rows: 5000000n, row_groups: [] };
+        }
+      );
+      (parquetSchema as ReturnType<typeof vi.fn>).mockReturnValue(createSchemaTree(['id', 'city']));
 
       render(<ParquetViewer {...largeFileProps} />);
 
       await waitFor(() => {
-        expect(screen.getByText(/File is too large to preview/)).toBeInTheDocument();
+        expect(screen.getByText(/File is too large to preview rows/)).toBeInTheDocument();
       });
-
-      // Should not try to download content
+      expect(screen.getByText('id')).toBeInTheDocument();
+      expect(screen.getByText('city')).toBeInTheDocument();
+      expect(screen.getByText('5,000,000 rows')).toBeInTheDocument();
+      expect(screen.queryByText('Export CSV')).not.toBeInTheDocument();
+
+      // Only the footer is fetched, never the whole file or its rows
+      expect(mockElectronAPI.s3.downloadPartialContent).toHaveBeenCalledWith(
+        'test-bucket',
+        'path/to/data.parquet',
+        8,
+        150 * 1024 * 1024 - 8,
+        undefined
+      );
       expect(mockElectronAPI.s3.downloadBinaryContent).not.toHaveBeenCalled();
+      expect(parquetRead).not.toHaveBeenCalled();
     });
 
     it('allows files within size limit', async () => {
@@ -435,6 +455,83 @@ describe('ParquetViewer', () => {
     });
   });
 
+  describe('logical type handling', () => {
+    function mockAnnotatedColumn(element: Record<string, unknown>, values: unknown[]) {
+      mockElectronAPI.s3.downloadBinaryContent.mockResolvedValue({
+        success: true,
+        data: new Uint8Array([]),
+      });
+
+      (parquetMetadataAsync as ReturnType<typeof vi.fn>).mockResolvedValue({
+        schema: [{ name: 'root' }, { name: 'col', type: 'BYTE_ARRAY', ...element }],
+      });
+
+      (parquetSchema as ReturnType<typeof vi.fn>).mockReturnValue({
+        element: { name: 'root' },
+        children: [{ element: { name: 'col', type: 'BYTE_ARRAY', ...element } }],
+      });
+
+      (parquetRead as ReturnType<typeof vi.fn>).mockImplementation(
+        async ({ onComplete }: { onComplete: (data: Record<string, unknown>[]) => void }) => {
+          onComplete(columnDataToRows({ col: values }));
+        }
+      );
+    }
+
+    it('renders JSON-annotated byte arrays as compact JSON', async () => {
+      const bytes = new TextEncoder().encode('{ "env": "prod", "tags": [1, 2] }');
+      mockAnnotatedColumn({ converted_type: 'JSON' }, [bytes]);
+
+      render(<ParquetViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('{"env":"prod","tags":[1,2]}')).toBeInTheDocument();
+      });
+      expect(screen.getByText('json')).toBeInTheDocument();
+    });
+
+    it('shows pretty JSON in the cell tooltip', async () => {
+      mockAnnotatedColumn({ logical_type: { type: 'JSON' } }, ['{"a":1}']);
+
+      render(<ParquetViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('{"a":1}')).toHaveAttribute('title', '{\n  "a": 1\n}');
+      });
+    });
+
+    it('renders already-parsed JSON values', async () => {
+      mockAnnotatedColumn({ converted_type: 'JSON' }, [{ nested: { ok: true } }]);
+
+      render(<ParquetViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('{"nested":{"ok":true}}')).toBeInTheDocument();
+      });
+    });
+
+    it('renders ENUM byte arrays as their string value', async () => {
+      mockAnnotatedColumn({ converted_type: 'ENUM' }, [new TextEncoder().encode('ACTIVE')]);
+
+      render(<ParquetViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('ACTIVE')).toBeInTheDocument();
+      });
+      expect(screen.getByText('enum')).toBeInTheDocument();
+    });
+
+    it('renders BSON byte arrays as a size placeholder', async () => {
+      mockAnnotatedColumn({ converted_type: 'BSON' }, [new Uint8Array([5, 0, 0, 0, 0])]);
+
+      render(<ParquetViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('[BSON 5 bytes]')).toBeInTheDocument();
+      });
+    });
+  });
+
   describe('search functionality', () => {
     beforeEach(async () => {
       mockElectronAPI.s3.downloadBinaryContent.mockResolvedValue({
@@ -636,6 +733,87 @@ describe('ParquetViewer', () => {
         expect(screen.getByText('Loaded 3 of 3 rows')).toBeInTheDocument();
       });
     });
+
+    it('shows column statistics in the header tooltip', async () => {
+      mockElectronAPI.s3.downloadBinaryContent.mockResolvedValue({
+        success: true,
+        data: new Uint8Array([]),
+      });
+
+      (parquetMetadataAsync as ReturnType<typeof vi.fn>).mockResolvedValue({
+        schema: [{ name: 'root' }, { name: 'id' }],
+        row_groups: [
+          {
+            num_rows: 3n,
+            total_byte_size: 100n,
+            columns: [
+              {
+                file_offset: 0n,
+                meta_data: {
+                  path_in_schema: ['id'],
+                  statistics: { min_value: 1n, max_value: 3n, null_count: 0n },
+                },
+              },
+            ],
+          },
+        ],
+      });
+
+      (parquetSchema as ReturnType<typeof vi.fn>).mockReturnValue(
+        createSchemaTree(['id'])
+      );
+
+      (parquetRead as ReturnType<typeof vi.fn>).mockImplementation(
+        async ({ onComplete }: { onComplete: (data: Record<string, unknown>[]) => void }) => {
+          onComplete(columnDataToRows({ id: [1n, 2n, 3n] }));
+        }
+      );
+
+      render(<ParquetViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('Loaded 3 of 3 rows')).toBeInTheDocument();
+      });
+      expect(screen.getByTitle(/^id \(bigint\)/)).toHaveAttribute(
+        'title',
+        'id (bigint)\nMin: 1\nMax: 3\nNulls: 0'
+      );
+    });
+
+    it('shows the row group count in footer', async () => {
+      mockElectronAPI.s3.downloadBinaryContent.mockResolvedValue({
+        success: true,
+        data: new Uint8Array([]),
+      });
+
+      (parquetMetadataAsync as ReturnType<typeof vi.fn>).mockResolvedValue({
+        schema: [{ name: 'root' }, { name: 'col' }],
+        row_groups: [
+          { num_rows: 2n, total_byte_size: 2048n, total_compressed_size: 1024n, columns: [] },
+          { num_rows: 1n, total_byte_size: 1024n, total_compressed_size: 512n, columns: [] },
+        ],
+      });
+
+      (parquetSchema as ReturnType<typeof vi.fn>).mockReturnValue(
+        createSchemaTree(['col'])
+      );
+
+      (parquetRead as ReturnType<typeof vi.fn>).mockImplementation(
+        async ({ onComplete }: { onComplete: (data: Record<string, unknown>[]) => void }) => {
+          onComplete(columnDataToRows({ col: ['a', 'b', 'c'] }));
+        }
+      );
+
+      render(<ParquetViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('2 row groups')).toBeInTheDocument();
+      });
+      expect(screen.getByText('2 row groups')).toHaveAttribute(
+        'title',
+        'Row group 1: 2 rows, 1.0 KB stored, 2.0 KB uncompressed\nRow group 2: 1 rows, 512 B stored, 1.0 KB uncompressed'
+      );
+    });
   });
 
   describe('IPC data transfer handling', () => {
@@ -686,4 +864,173 @@ describe('ParquetViewer', () => {
       });
     });
   });
+
+  describe('CSV export', () => {
+    beforeEach(() => {
+      mockElectronAPI.s3.downloadBinaryContent.mockResolvedValue({
+        success: true,
+        data: new Uint8Array([]),
+      });
+      (parquetMetadataAsync as ReturnType<typeof vi.fn>).mockResolvedValue({
+        schema: [{ name: 'root' }, { name: 'name' }, { name: 'note' }],
+      });
+      (parquetSchema as ReturnType<typeof vi.fn>).mockReturnValue(createSchemaTree(['name', 'note']));
+      (parquetRead as ReturnType<typeof vi.fn>).mockImplementation(
+        async ({ onComplete }: { onComplete: (data: Record<string, unknown>[]) => void }) => {
+          onComplete(columnDataToRows({
+            name: ['Alice', '=HYPERLINK("http://x")'],
+            note: [null, 'a,b'],
+          }));
+        }
+      );
+    });
+
+    it('exports all rows with formula cells neutralized by default', async () => {
+      render(<ParquetViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('Alice')).toBeInTheDocument();
+      });
+      fireEvent.click(screen.getByText('Export CSV'));
+
+      await waitFor(() => {
+        expect(mockElectronAPI.s3.saveLocalFile).toHaveBeenCalledWith(
+          'data.csv',
+          'name,note\nAlice,\n"\'=HYPERLINK(""http://x"")","a,b"\n'
+        );
+      });
+      expect(screen.getByText('Exported 2 rows to /downloads/export.csv')).toBeInTheDocument();
+    });
+
+    it('exports cells unchanged when spreadsheet-safe is turned off', async () => {
+      render(<ParquetViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('Alice')).toBeInTheDocument();
+      });
+      fireEvent.click(screen.getByLabelText('Spreadsheet-safe'));
+      fireEvent.click(screen.getByText('Export CSV'));
+
+      await waitFor(() => {
+        expect(mockElectronAPI.s3.saveLocalFile).toHaveBeenCalledWith(
+          'data.csv',
+          expect.stringContaining('"=HYPERLINK(""http://x"")"')
+        );
+      });
+    });
+
+    it('exports with the chosen delimiter, line endings and quoting', async () => {
+      render(<ParquetViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('Alice')).toBeInTheDocument();
+      });
+      fireEvent.click(screen.getByText('CSV format'));
+      fireEvent.change(screen.getByLabelText('Delimiter:'), { target: { value: ';' } });
+      fireEvent.change(screen.getByLabelText('Line endings:'), { target: { value: '\r\n' } });
+      fireEvent.change(screen.getByLabelText('Quoting:'), { target: { value: 'all' } });
+      fireEvent.click(screen.getByText('Export CSV'));
+
+      await waitFor(() => {
+        expect(mockElectronAPI.s3.saveLocalFile).toHaveBeenCalledWith(
+          'data.csv',
+          '"name";"note"\r\n"Alice";""\r\n"\'=HYPERLINK(""http://x"")";"a,b"\r\n'
+        );
+      });
+    });
+
+    it('exports only the visible columns and remembers the choice for the object', async () => {
+      render(<ParquetViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getByText('Alice')).toBeInTheDocument();
+      });
+      fireEvent.click(screen.getByText('Columns'));
+      fireEvent.click(screen.getByLabelText('note'));
+
+      expect(screen.queryByText('a,b')).not.toBeInTheDocument();
+      expect(screen.getByText('1 of 2 columns')).toBeInTheDocument();
+      expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({
+        viewerLayouts: { 'test-bucket/path/to/data.parquet': { hiddenColumns: ['note'] } },
+      });
+
+      fireEvent.click(screen.getByText('Export CSV'));
+
+      await waitFor(() => {
+        expect(mockElectronAPI.s3.saveLocalFile).toHaveBeenCalledWith(
+          'data.csv',
+          'name\nAlice\n"\'=HYPERLINK(""http://x"")"\n'
+        );
+      });
+      expect(screen.getByText('Exported 2 rows (1 of 2 columns) to /downloads/export.csv')).toBeInTheDocument();
+    });
+  });
+
+  describe('column profile', () => {
+    beforeEach(() => {
+      mockElectronAPI.s3.downloadBinaryContent.mockResolvedValue({
+        success: true,
+        data: new Uint8Array([]),
+      });
+      (parquetMetadataAsync as ReturnType<typeof vi.fn>).mockResolvedValue({
+        schema: [{ name: 'root' }, { name: 'city' }, { name: 'score' }, { name: 'missing' }],
+      });
+      (parquetSchema as ReturnType<typeof vi.fn>).mockReturnValue(createSchemaTree(['city', 'score', 'missing']));
+      (parquetRead as ReturnType<typeof vi.fn>).mockImplementation(
+        async ({ onComplete }: { onComplete: (data: Record<string, unknown>[]) => void }) => {
+          onComplete(columnDataToRows({
+            city: ['NYC', 'LA', 'NYC'],
+            score: [1, 2, 2],
+            missing: [null, null, null],
+          }));
+        }
+      );
+    });
+
+    it('shows a histogram for each column when profiling is turned on', async () => {
+      render(<ParquetViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getAllByText('NYC')).toHaveLength(2);
+      });
+      expect(screen.queryByText('2 distinct')).not.toBeInTheDocument();
+
+      fireEvent.click(screen.getByRole('button', { name: 'Profile' }));
+
+      expect(screen.getByRole('img', { name: 'NYC: 2, LA: 1' })).toBeInTheDocument();
+      expect(screen.getByText('2 distinct')).toBeInTheDocument();
+      expect(screen.getByText('1 – 2')).toBeInTheDocument();
+      expect(screen.getByText('all null')).toBeInTheDocument();
+    });
+
+    it('copies a column with nulls as empty lines', async () => {
+      const writeText = vi.fn().mockResolvedValue(undefined);
+      Object.defineProperty(navigator, 'clipboard', { value: { writeText }, configurable: true });
+      render(<ParquetViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getAllByText('NYC')).toHaveLength(2);
+      });
+      fireEvent.click(screen.getByText('Copy column'));
+      fireEvent.change(screen.getByLabelText('Column:'), { target: { value: '2' } });
+      fireEvent.click(screen.getByRole('button', { name: 'Copy' }));
+
+      expect(await screen.findByRole('status')).toHaveTextContent('Copied 3 values');
+      expect(writeText).toHaveBeenCalledWith('\n\n');
+    });
+
+    it('only profiles the visible columns', async () => {
+      render(<ParquetViewer {...defaultProps} />);
+
+      await waitFor(() => {
+        expect(screen.getAllByText('NYC')).toHaveLength(2);
+      });
+      fireEvent.click(screen.getByText('Columns'));
+      fireEvent.click(screen.getByLabelText('city'));
+      fireEvent.click(screen.getByRole('button', { name: 'Profile' }));
+
+      expect(screen.queryByText('2 distinct')).not.toBeInTheDocument();
+      expect(screen.getByText('1 – 2')).toBeInTheDocument();
+    });
+  });
 });
@@ -0,0 +1,75 @@
+import React from 'react';
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, fireEvent, waitFor } from '@testing-library/react';
+import ProfileDialog from '../renderer/components/ProfileDialog';
+
+describe('ProfileDialog', () => {
+  const mockOnSave = vi.fn();
+  const mockOnCancel = vi.fn();
+
+  beforeEach(() => {
+    mockOnSave.mockReset();
+    mockOnCancel.mockClear();
+  });
+
+  it('does not render when closed', () => {
+    const { container } = render(<ProfileDialog isOpen={false} onSave={mockOnSave} onCancel={mockOnCancel} />);
+    expect(container).toBeEmptyDOMElement();
+  });
+
+  it('saves the name and the filled-in settings only', async () => {
+    mockOnSave.mockResolvedValue(null);
+    render(<ProfileDialog isOpen={true} onSave={mockOnSave} onCancel={mockOnCancel} />);
+
+    expect(screen.getByRole('button', { name: 'Save' })).toBeDisabled();
+    fireEvent.change(screen.getByLabelText('Profile name:'), { target: { value: ' admin ' } });
+    fireEvent.change(screen.getByLabelText('Role ARN:'), { target: { value: 'arn:aws:iam::123456789012:role/Admin' } });
+    fireEvent.change(screen.getByLabelText('Source profile:'), { target: { value: 'base' } });
+    fireEvent.click(screen.getByRole('button', { name: 'Save' }));
+
+    await waitFor(() => {
+      expect(mockOnSave).toHaveBeenCalledWith({
+        name: 'admin',
+        roleArn: 'arn:aws:iam::123456789012:role/Admin',
+        sourceProfile: 'base',
+      });
+    });
+  });
+
+  it('shows the error when saving fails', async () => {
+    mockOnSave.mockResolvedValue("Profile 'admin' already exists");
+    render(<ProfileDialog isOpen={true} onSave={mockOnSave} onCancel={mockOnCancel} />);
+
+    fireEvent.change(screen.getByLabelText('Profile name:'), { target: { value: 'admin' } });
+    fireEvent.click(screen.getByRole('button', { name: 'Save' }));
+
+    expect(await screen.findByRole('alert')).toHaveTextContent("Profile 'admin' already exists");
+  });
+
+  it('starts a new profile from the given defaults, keeping settings not shown', async () => {
+    mockOnSave.mockResolvedValue(null);
+    render(
+      <ProfileDialog
+        isOpen={true}
+        defaults={{ name: 'Staging-Developer', ssoSession: 'corp', ssoAccountId: '222222222222', ssoRoleName: 'Developer' }}
+        onSave={mockOnSave}
+        onCancel={mockOnCancel}
+      />
+    );
+
+    expect(screen.getByText('New Profile')).toBeInTheDocument();
+    expect(screen.getByLabelText('Profile name:')).toHaveValue('Staging-Developer');
+    fireEvent.change(screen.getByLabelText('Region:'), { target: { value: 'eu-west-1' } });
+    fireEvent.click(screen.getByRole('button', { name: 'Save' }));
+
+    await waitFor(() => {
+      expect(mockOnSave).toHaveBeenCalledWith({
+        name: 'Staging-Developer',
+        region: 'eu-west-1',
+        ssoSession: 'corp',
+        ssoAccountId: '222222222222',
+        ssoRoleName: 'Developer',
+      });
+    });
+  });
+});
@@ -264,6 +264,24 @@ describe('PropertiesDialog', () => {
       });
     });
 
+    it('shows the part count of multipart uploads', async () => {
+      window.electronAPI.getObjectMetadata = vi.fn().mockResolvedValue({
+        success: true,
+        metadata: { ...mockMetadata, etag: 'abc123def456abc123def456abc123de-5', etagPartCount: 5 },
+      });
+
+      render(<PropertiesDialog {...defaultProps} />);
+
+      expect(await screen.findByText('Multipart (5 parts)')).toBeInTheDocument();
+    });
+
+    it('does not show an upload row for single-part objects', async () => {
+      render(<PropertiesDialog {...defaultProps} />);
+
+      await screen.findByText('abc123def456');
+      expect(screen.queryByText(/Multipart/)).not.toBeInTheDocument();
+    });
+
     it('shows version ID when present', async () => {
       render(<PropertiesDialog {...defaultProps} />);
 
@@ -281,6 +299,271 @@ describe('PropertiesDialog', () => {
     });
   });
 
+  describe('website redirect', () => {
+    const redirectMetadata = {
+      ...mockMetadata,
+      websiteRedirectLocation: '/docs/current.html',
+      redirectTarget: { bucket: 'test-bucket', key: 'docs/current.html' },
+    };
+
+    it('shows the redirect location and navigates to the target', async () => {
+      window.electronAPI.getObjectMetadata = vi.fn().mockResolvedValue({ success: true, metadata: redirectMetadata });
+      const onNavigateToObject = vi.fn();
+
+      render(<PropertiesDialog {...defaultProps} onNavigateToObject={onNavigateToObject} />);
+
+      expect(await screen.findByText('/docs/current.html')).toBeInTheDocument();
+      fireEvent.click(screen.getByRole('button', { name: 'Go to' }));
+      expect(onNavigateToObject).toHaveBeenCalledWith('test-bucket', 'docs/current.html');
+    });
+
+    it('does not offer navigation when the redirect points outside S3', async () => {
+      window.electronAPI.getObjectMetadata = vi.fn().mockResolvedValue({
+        success: true,
+        metadata: { ...mockMetadata, websiteRedirectLocation: 'https://example.com/' },
+      });
+
+      render(<PropertiesDialog {...defaultProps} onNavigateToObject={vi.fn()} />);
+
+      expect(await screen.findByText('https://example.com/')).toBeInTheDocument();
+      expect(screen.queryByRole('button', { name: 'Go to' })).not.toBeInTheDocument();
+    });
+  });
+
+  describe('shareable link', () => {
+    it('copies a presigned link with the chosen expiry', async () => {
+      const writeText = vi.fn().mockResolvedValue(undefined);
+      Object.defineProperty(navigator, 'clipboard', { value: { writeText }, configurable: true });
+      const presignGetUrl = vi.fn().mockResolvedValue({ success: true, url: 'https://signed.example/file?sig=1' });
+      window.electronAPI.s3 = { presignGetUrl } as unknown as typeof window.electronAPI.s3;
+
+      render(<PropertiesDialog {...defaultProps} />);
+      await waitFor(() => expect(screen.getByText('Shareable link:')).toBeInTheDocument());
+
+      fireEvent.change(screen.getByLabelText('Link expiry'), { target: { value: String(24 * 60 * 60) } });
+      fireEvent.click(screen.getByRole('button', { name: 'Copy link' }));
+
+      await waitFor(() => expect(screen.getByRole('status')).toHaveTextContent('Link copied, valid for 1 day'));
+      expect(presignGetUrl).toHaveBeenCalledWith('test-bucket', 'test-folder/test-file.txt', 86400);
+      expect(writeText).toHaveBeenCalledWith('https://signed.example/file?sig=1');
+    });
+
+    it('copies an upload link signed with the file content type', async () => {
+      const writeText = vi.fn().mockResolvedValue(undefined);
+      Object.defineProperty(navigator, 'clipboard', { value: { writeText }, configurable: true });
+      const presignPutUrl = vi.fn().mockResolvedValue({ success: true, url: 'https://signed.example/put?sig=2' });
+      window.electronAPI.s3 = { presignPutUrl } as unknown as typeof window.electronAPI.s3;
+
+      render(<PropertiesDialog {...defaultProps} />);
+      await waitFor(() => expect(screen.getByText('Shareable link:')).toBeInTheDocument());
+      fireEvent.click(screen.getByRole('button', { name: 'Copy upload link' }));
+
+      await waitFor(() => expect(screen.getByRole('status')).toHaveTextContent('Upload link copied, valid for 1 hour'));
+      expect(presignPutUrl).toHaveBeenCalledWith('test-bucket', 'test-folder/test-file.txt', 3600, 'text/plain');
+      expect(writeText).toHaveBeenCalledWith('https://signed.example/put?sig=2');
+    });
+
+    it('shows the error when the link cannot be created', async () => {
+      window.electronAPI.s3 = {
+        presignGetUrl: vi.fn().mockResolvedValue({ success: false, error: 'No credentials' }),
+      } as unknown as typeof window.electronAPI.s3;
+
+      render(<PropertiesDialog {...defaultProps} />);
+      await waitFor(() => expect(screen.getByText('Shareable link:')).toBeInTheDocument());
+      fireEvent.click(screen.getByRole('button', { name: 'Copy link' }));
+
+      await waitFor(() => expect(screen.getByRole('status')).toHaveTextContent('No credentials'));
+    });
+
+    it('is not offered for folders', () => {
+      render(<PropertiesDialog {...defaultProps} isFolder={true} fileKey="parent/folder-name/" />);
+      expect(screen.queryByText('Shareable link:')).not.toBeInTheDocument();
+    });
+  });
+
+  describe('tag editing', () => {
+    it('saves edited tags and shows them', async () => {
+      const putObjectTags = vi.fn().mockResolvedValue({ success: true });
+      window.electronAPI.s3 = { putObjectTags } as unknown as typeof window.electronAPI.s3;
+
+      render(<PropertiesDialog {...defaultProps} />);
+      fireEvent.click(await screen.findByRole('button', { name: 'Edit tags' }));
+
+      fireEvent.change(screen.getByLabelText('Tag value 1'), { target: { value: 'staging' } });
+      fireEvent.click(screen.getByRole('button', { name: 'Remove tag 2' }));
+      fireEvent.click(screen.getByRole('button', { name: 'Add tag' }));
+      fireEvent.change(screen.getByLabelText('Tag key 2'), { target: { value: 'cost-center' } });
+      fireEvent.change(screen.getByLabelText('Tag value 2'), { target: { value: '42' } });
+      fireEvent.click(screen.getByRole('button', { name: 'Save tags' }));
+
+      expect(await screen.findByText('cost-center:')).toBeInTheDocument();
+      expect(putObjectTags).toHaveBeenCalledWith('test-bucket', 'test-folder/test-file.txt', {
+        environment: 'staging',
+        'cost-center': '42',
+      });
+      expect(screen.getByText('staging')).toBeInTheDocument();
+      expect(screen.queryByText('owner:')).not.toBeInTheDocument();
+    });
+
+    it('offers adding tags to an untagged object', async () => {
+      window.electronAPI.getObjectMetadata = vi.fn().mockResolvedValue({
+        success: true,
+        metadata: { ...mockMetadata, tags: {} },
+      });
+
+      render(<PropertiesDialog {...defaultProps} />);
+      fireEvent.click(await screen.findByRole('button', { name: 'Add tags' }));
+
+      expect(screen.getByLabelText('Tag key 1')).toHaveValue('');
+    });
+
+    it('rejects duplicate keys before saving', async () => {
+      const putObjectTags = vi.fn();
+      window.electronAPI.s3 = { putObjectTags } as unknown as typeof window.electronAPI.s3;
+
+      render(<PropertiesDialog {...defaultProps} />);
+      fireEvent.click(await screen.findByRole('button', { name: 'Edit tags' }));
+      fireEvent.change(screen.getByLabelText('Tag key 2'), { target: { value: 'environment' } });
+      fireEvent.click(screen.getByRole('button', { name: 'Save tags' }));
+
+      expect(await screen.findByRole('alert')).toHaveTextContent('Duplicate tag key: environment');
+      expect(putObjectTags).not.toHaveBeenCalled();
+    });
+
+    it('keeps the editor open and shows the error when saving fails', async () => {
+      window.electronAPI.s3 = {
+        putObjectTags: vi.fn().mockResolvedValue({ success: false, error: 'Objects can have at most 10 tags' }),
+      } as unknown as typeof window.electronAPI.s3;
+
+      render(<PropertiesDialog {...defaultProps} />);
+      fireEvent.click(await screen.findByRole('button', { name: 'Edit tags' }));
+      fireEvent.click(screen.getByRole('button', { name: 'Save tags' }));
+
+      expect(await screen.findByRole('alert')).toHaveTextContent('Objects can have at most 10 tags');
+      expect(screen.getByLabelText('Tag key 1')).toBeInTheDocument();
+    });
+  });
+
+  describe('archive restore', () => {
+    it('is not offered for objects readable directly', async () => {
+      render(<PropertiesDialog {...defaultProps} />);
+      await waitFor(() => expect(screen.getByText('STANDARD')).toBeInTheDocument());
+      expect(screen.queryByText('Restore:')).not.toBeInTheDocument();
+    });
+
+    it('requests a restore with the chosen days and tier', async () => {
+      window.electronAPI.getObjectMetadata = vi.fn().mockResolvedValue({
+        success: true,
+        metadata: { ...mockMetadata, storageClass: 'GLACIER' },
+      });
+      const restoreObject = vi.fn().mockResolvedValue({ success: true });
+      window.electronAPI.s3 = { restoreObject } as unknown as typeof window.electronAPI.s3;
+
+      render(<PropertiesDialog {...defaultProps} />);
+      expect(await screen.findByText('Not restored (download needs a restore first)')).toBeInTheDocument();
+
+      fireEvent.change(screen.getByLabelText('Restore days'), { target: { value: '3' } });
+      fireEvent.change(screen.getByLabelText('Restore tier'), { target: { value: 'Bulk' } });
+      fireEvent.click(screen.getByRole('button', { name: 'Restore' }));
+
+      await waitFor(() => expect(screen.getByRole('status')).toHaveTextContent('Restore requested for 3 days'));
+      expect(restoreObject).toHaveBeenCalledWith('test-bucket', 'test-folder/test-file.txt', 3, 'Bulk');
+      expect(screen.getByText('In progress')).toBeInTheDocument();
+    });
+
+    it('shows when a restored copy expires', async () => {
+      window.electronAPI.getObjectMetadata = vi.fn().mockResolvedValue({
+        success: true,
+        metadata: {
+          ...mockMetadata,
+          storageClass: 'DEEP_ARCHIVE',
+          restore: { ongoing: false, expiry: new Date('2024-02-01T00:00:00Z') },
+        },
+      });
+
+      render(<PropertiesDialog {...defaultProps} />);
+
+      expect(await screen.findByText(/^Restored until/)).toBeInTheDocument();
+      expect(screen.getByRole('button', { name: 'Extend' })).toBeInTheDocument();
+    });
+
+    it('hides the restore controls while a restore is running', async () => {
+      window.electronAPI.getObjectMetadata = vi.fn().mockResolvedValue({
+        success: true,
+        metadata: { ...mockMetadata, storageClass: 'GLACIER', restore: { ongoing: true } },
+      });
+
+      render(<PropertiesDialog {...defaultProps} />);
+
+      expect(await screen.findByText('In progress')).toBeInTheDocument();
+      expect(screen.queryByRole('button', { name: 'Restore' })).not.toBeInTheDocument();
+    });
+  });
+
+  describe('checksum verification', () => {
+    it('compares the content with the stored SHA-256', async () => {
+      window.electronAPI.getObjectMetadata = vi.fn().mockResolvedValue({
+        success: true,
+        metadata: { ...mockMetadata, checksums: { SHA256: 'pZGm1Av0IEBKARczz7exkNYsZb8LzaMrV7J32a2fFG4=' } },
+      });
+      const verifyChecksum = vi.fn().mockResolvedValue({ success: true, verified: true });
+      window.electronAPI.s3 = { verifyChecksum } as unknown as typeof window.electronAPI.s3;
+
+      render(<PropertiesDialog {...defaultProps} />);
+      fireEvent.click(await screen.findByRole('button', { name: 'Verify' }));
+
+      await waitFor(() =>
+        expect(screen.getByRole('status')).toHaveTextContent('Content matches the SHA-256 checksum')
+      );
+      expect(verifyChecksum).toHaveBeenCalledWith('test-bucket', 'test-folder/test-file.txt');
+    });
+
+    it('shows a mismatch', async () => {
+      window.electronAPI.getObjectMetadata = vi.fn().mockResolvedValue({
+        success: true,
+        metadata: { ...mockMetadata, checksums: { SHA256: 'pZGm1Av0IEBKARczz7exkNYsZb8LzaMrV7J32a2fFG4=' } },
+      });
+      window.electronAPI.s3 = {
+        verifyChecksum: vi.fn().mockResolvedValue({
+          success: false,
+          checksumMismatch: true,
+          error: 'Checksum mismatch: S3 stored SHA-256 a, downloaded content has b',
+        }),
+      } as unknown as typeof window.electronAPI.s3;
+
+      render(<PropertiesDialog {...defaultProps} />);
+      fireEvent.click(await screen.findByRole('button', { name: 'Verify' }));
+
+      await waitFor(() => expect(screen.getByRole('status')).toHaveTextContent('Checksum mismatch'));
+    });
+
+    it('compares the content with a single-part ETag when there is no SHA-256', async () => {
+      window.electronAPI.getObjectMetadata = vi.fn().mockResolvedValue({
+        success: true,
+        metadata: { ...mockMetadata, etag: '"b10a8db164e0754105b7a99be72e3fe5"', checksums: {} },
+      });
+      const verifyChecksum = vi.fn().mockResolvedValue({ success: true, verified: true, verifiedAgainst: 'ETag' });
+      window.electronAPI.s3 = { verifyChecksum } as unknown as typeof window.electronAPI.s3;
+
+      render(<PropertiesDialog {...defaultProps} />);
+      fireEvent.click(await screen.findByRole('button', { name: 'Verify' }));
+
+      await waitFor(() => expect(screen.getByRole('status')).toHaveTextContent('Content matches the MD5 ETag'));
+    });
+
+    it('is not offered for composite multipart checksums', async () => {
+      window.electronAPI.getObjectMetadata = vi.fn().mockResolvedValue({
+        success: true,
+        metadata: { ...mockMetadata, checksums: { SHA256: 'abc123=-3', CRC32: 'AAAAAA==' } },
+      });
+
+      render(<PropertiesDialog {...defaultProps} />);
+
+      expect(await screen.findByText('abc123=-3')).toBeInTheDocument();
+      expect(screen.queryByRole('button', { name: 'Verify' })).not.toBeInTheDocument();
+    });
+  });
+
   describe('file name extraction', () => {
     it('extracts file name from full key', async () => {
       render(<PropertiesDialog {...defaultProps} fileKey="deep/nested/path/myfile.txt" />);
@@ -0,0 +1,82 @@
+import React from 'react';
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, fireEvent } from '@testing-library/react';
+import SelectQueryDialog, {
+  DEFAULT_SELECT_EXPRESSION,
+  getSelectInputFormat,
+} from '../renderer/components/SelectQueryDialog';
+import { mockElectronAPI } from './setup';
+
+describe('SelectQueryDialog', () => {
+  const defaultProps = {
+    isOpen: true,
+    bucket: 'data-bucket',
+    fileKey: 'exports/users.csv',
+    onClose: vi.fn(),
+  };
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it('should not render anything when closed', () => {
+    const { container } = render(<SelectQueryDialog {...defaultProps} isOpen={false} />);
+    expect(container).toBeEmptyDOMElement();
+  });
+
+  it('should run the query and show the matching records', async () => {
+    mockElectronAPI.s3.selectObjectContent.mockResolvedValueOnce({
+      success: true,
+      records: 'alice,42\nbob,7\n',
+      truncated: false,
+      bytesScanned: 2048,
+    });
+    render(<SelectQueryDialog {...defaultProps} />);
+
+    expect(screen.getByLabelText(/SQL expression/)).toHaveValue(DEFAULT_SELECT_EXPRESSION);
+    fireEvent.change(screen.getByLabelText(/SQL expression/), {
+      target: { value: "SELECT s.name, s.age FROM s3object s WHERE s.active = 'true'" },
+    });
+    fireEvent.click(screen.getByText('Run'));
+
+    expect(await screen.findByRole('status')).toHaveTextContent('2 records · 2.0 KB scanned');
+    expect(screen.getByText(/alice,42/)).toBeInTheDocument();
+    expect(mockElectronAPI.s3.selectObjectContent).toHaveBeenCalledWith(
+      'data-bucket',
+      'exports/users.csv',
+      "SELECT s.name, s.age FROM s3object s WHERE s.active = 'true'",
+      'csv',
+      'csv',
+      expect.any(String)
+    );
+  });
+
+  it('should show query errors', async () => {
+    mockElectronAPI.s3.selectObjectContent.mockResolvedValueOnce({
+      success: false,
+      error: 'Syntax error at line 1',
+    });
+    render(<SelectQueryDialog {...defaultProps} />);
+
+    fireEvent.click(screen.getByText('Run'));
+
+    expect(await screen.findByRole('alert')).toHaveTextContent('Syntax error at line 1');
+  });
+
+  it('should say when results were cut off', async () => {
+    mockElectronAPI.s3.selectObjectContent.mockResolvedValueOnce({ success: true, records: '{}\n', truncated: true });
+    render(<SelectQueryDialog {...defaultProps} fileKey="logs/events.jsonl" />);
+
+    expect(screen.getByLabelText('Results as:')).toHaveValue('json');
+    fireEvent.click(screen.getByText('Run'));
+
+    expect(await screen.findByRole('status')).toHaveTextContent('results cut off');
+  });
+
+  it('should read CSV and TSV files as CSV and everything else as JSON', () => {
+    expect(getSelectInputFormat('a/b.csv')).toBe('csv');
+    expect(getSelectInputFormat('a/b.TSV.gz')).toBe('csv');
+    expect(getSelectInputFormat('a/b.json')).toBe('json');
+    expect(getSelectInputFormat('a/b.ndjson.bz2')).toBe('json');
+  });
+});
@@ -0,0 +1,253 @@
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { render, screen, waitFor, fireEvent } from '@testing-library/react';
+import SettingsDialog from '../renderer/components/SettingsDialog';
+import { mockElectronAPI } from './setup';
+
+describe('SettingsDialog', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it('renders nothing when closed', () => {
+    const { container } = render(<SettingsDialog isOpen={false} onClose={vi.fn()} />);
+
+    expect(container).toBeEmptyDOMElement();
+  });
+
+  it('shows the saved download conflict policy', async () => {
+    mockElectronAPI.appState.load.mockResolvedValueOnce({
+      lastProfile: null,
+      lastBucket: null,
+      lastPrefix: '',
+      readOnlyProfiles: [],
+      downloadConflictPolicy: 'skip',
+    });
+
+    render(<SettingsDialog isOpen={true} onClose={vi.fn()} />);
+
+    await waitFor(() => {
+      expect(screen.getByLabelText('When a downloaded file already exists:')).toHaveValue('skip');
+    });
+  });
+
+  it('saves the conflict policy when changed', async () => {
+    render(<SettingsDialog isOpen={true} onClose={vi.fn()} />);
+
+    await waitFor(() => {
+      expect(mockElectronAPI.appState.load).toHaveBeenCalled();
+    });
+    fireEvent.change(screen.getByLabelText('When a downloaded file already exists:'), {
+      target: { value: 'overwrite' },
+    });
+
+    expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({ downloadConflictPolicy: 'overwrite' });
+  });
+
+  it('shows and saves the folder collapsing option', async () => {
+    mockElectronAPI.appState.load.mockResolvedValueOnce({
+      lastProfile: null,
+      lastBucket: null,
+      lastPrefix: '',
+      readOnlyProfiles: [],
+      downloadConflictPolicy: 'rename',
+      collapseSingleChildFolders: true,
+    });
+
+    render(<SettingsDialog isOpen={true} onClose={vi.fn()} />);
+
+    const checkbox = screen.getByLabelText('Skip through folders that only contain a single subfolder');
+    await waitFor(() => {
+      expect(checkbox).toBeChecked();
+    });
+    fireEvent.click(checkbox);
+
+    expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({ collapseSingleChildFolders: false });
+  });
+
+  it('shows and saves the delete confirmation option', async () => {
+    render(<SettingsDialog isOpen={true} onClose={vi.fn()} />);
+
+    const checkbox = screen.getByLabelText('Ask for confirmation before deleting files (folders are always confirmed)');
+    await waitFor(() => {
+      expect(mockElectronAPI.appState.load).toHaveBeenCalled();
+    });
+    expect(checkbox).toBeChecked();
+    fireEvent.click(checkbox);
+
+    expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({ confirmBeforeDelete: false });
+  });
+
+  it('shows and saves the theme', async () => {
+    mockElectronAPI.appState.load.mockResolvedValueOnce({
+      lastProfile: null,
+      lastBucket: null,
+      lastPrefix: '',
+      readOnlyProfiles: [],
+      downloadConflictPolicy: 'rename',
+      theme: 'dark',
+    });
+
+    render(<SettingsDialog isOpen={true} onClose={vi.fn()} />);
+
+    await waitFor(() => {
+      expect(screen.getByLabelText('Theme:')).toHaveValue('dark');
+    });
+    fireEvent.change(screen.getByLabelText('Theme:'), { target: { value: 'light' } });
+
+    expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({ theme: 'light' });
+  });
+
+  it('saves the request logging option', async () => {
+    render(<SettingsDialog isOpen={true} onClose={vi.fn()} />);
+
+    const checkbox = screen.getByLabelText('Log raw S3 requests and responses (for debugging endpoints)');
+    await waitFor(() => {
+      expect(mockElectronAPI.appState.load).toHaveBeenCalled();
+    });
+    expect(checkbox).not.toBeChecked();
+    fireEvent.click(checkbox);
+
+    expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({ requestLogging: true });
+  });
+
+  it('saves the Requester Pays option', async () => {
+    render(<SettingsDialog isOpen={true} onClose={vi.fn()} />);
+
+    const checkbox = screen.getByLabelText('Pay for requests to Requester Pays buckets');
+    await waitFor(() => {
+      expect(mockElectronAPI.appState.load).toHaveBeenCalled();
+    });
+    expect(checkbox).not.toBeChecked();
+    fireEvent.click(checkbox);
+
+    expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({ requesterPays: true });
+  });
+
+  it('saves the retry count', async () => {
+    mockElectronAPI.appState.load.mockResolvedValueOnce({
+      lastProfile: null,
+      lastBucket: null,
+      lastPrefix: '',
+      readOnlyProfiles: [],
+      downloadConflictPolicy: 'rename',
+      maxRetries: 3,
+    });
+    render(<SettingsDialog isOpen={true} onClose={vi.fn()} />);
+
+    const select = screen.getByLabelText('Retry failed S3 requests:');
+    await waitFor(() => {
+      expect(select).toHaveValue('3');
+    });
+    fireEvent.change(select, { target: { value: '5' } });
+    expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({ maxRetries: 5 });
+
+    fireEvent.change(select, { target: { value: '' } });
+    expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({ maxRetries: null });
+  });
+
+  it('saves the connection and request timeouts', async () => {
+    render(<SettingsDialog isOpen={true} onClose={vi.fn()} />);
+    await waitFor(() => {
+      expect(mockElectronAPI.appState.load).toHaveBeenCalled();
+    });
+
+    const connect = screen.getByLabelText('Connection timeout:');
+    expect(connect).toHaveValue('');
+    fireEvent.change(connect, { target: { value: '10' } });
+    expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({ connectTimeoutSeconds: 10 });
+
+    fireEvent.change(screen.getByLabelText('Request timeout:'), { target: { value: '120' } });
+    expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({ requestTimeoutSeconds: 120 });
+  });
+
+  it('saves a valid proxy URL and clears it when emptied', async () => {
+    render(<SettingsDialog isOpen={true} onClose={vi.fn()} />);
+    await waitFor(() => {
+      expect(mockElectronAPI.appState.load).toHaveBeenCalled();
+    });
+
+    const input = screen.getByLabelText('HTTP(S) proxy:');
+    fireEvent.change(input, { target: { value: 'http://proxy.corp.example:3128' } });
+    fireEvent.blur(input);
+    expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({ proxyUrl: 'http://proxy.corp.example:3128' });
+
+    fireEvent.change(input, { target: { value: '' } });
+    fireEvent.blur(input);
+    expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({ proxyUrl: null });
+  });
+
+  it('refuses to save a proxy URL without an http or https scheme', async () => {
+    render(<SettingsDialog isOpen={true} onClose={vi.fn()} />);
+    await waitFor(() => {
+      expect(mockElectronAPI.appState.load).toHaveBeenCalled();
+    });
+
+    const input = screen.getByLabelText('HTTP(S) proxy:');
+    fireEvent.change(input, { target: { value: 'proxy.corp.example:3128' } });
+    fireEvent.blur(input);
+
+    expect(screen.getByRole('alert')).toHaveTextContent('Enter an http:// or https:// proxy URL');
+    expect(mockElectronAPI.appState.save).not.toHaveBeenCalledWith(expect.objectContaining({ proxyUrl: expect.anything() }));
+  });
+
+  it('saves the storage class for uploads', async () => {
+    render(<SettingsDialog isOpen={true} onClose={vi.fn()} />);
+    await waitFor(() => {
+      expect(mockElectronAPI.appState.load).toHaveBeenCalled();
+    });
+
+    const select = screen.getByLabelText('Storage class for uploads:');
+    expect(select).toHaveValue('');
+    fireEvent.change(select, { target: { value: 'INTELLIGENT_TIERING' } });
+    expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({ uploadStorageClass: 'INTELLIGENT_TIERING' });
+
+    fireEvent.change(select, { target: { value: '' } });
+    expect(mockElectronAPI.appState.save).toHaveBeenCalledWith({ uploadStorageClass: null });
+  });
+
+  it('imports shared locations and reports how many were new', async () => {
+    mockElectronAPI.appState.importShareable.mockResolvedValueOnce({ success: true, added: 3 });
+    const onImported = vi.fn();
+    render(<SettingsDialog isOpen={true} onClose={vi.fn()} onImported={onImported} />);
+
+    fireEvent.click(screen.getByText('Import...'));
+
+    expect(await screen.findByRole('status')).toHaveTextContent('Imported 3 new entries');
+    expect(onImported).toHaveBeenCalled();
+  });
+
+  it('shows why an import failed', async () => {
+    mockElectronAPI.appState.importShareable.mockResolvedValueOnce({
+      success: false,
+      error: 'The file is not an S3 Browser settings export',
+    });
+    const onImported = vi.fn();
+    render(<SettingsDialog isOpen={true} onClose={vi.fn()} onImported={onImported} />);
+
+    fireEvent.click(screen.getByText('Import...'));
+
+    expect(await screen.findByRole('status')).toHaveTextContent(
+      'Import failed: The file is not an S3 Browser settings export'
+    );
+    expect(onImported).not.toHaveBeenCalled();
+  });
+
+  it('exports shared locations to the chosen file', async () => {
+    render(<SettingsDialog isOpen={true} onClose={vi.fn()} />);
+
+    fireEvent.click(screen.getByText('Export...'));
+
+    expect(await screen.findByRole('status')).toHaveTextContent(
+      'Exported to /downloads/s3-browser-locations.json'
+    );
+  });
+
+  it('closes with the Done button', () => {
+    const onClose = vi.fn();
+    render(<SettingsDialog isOpen={true} onClose={onClose} />);
+
+    fireEvent.click(screen.getByText('Done'));
+
+    expect(onClose).toHaveBeenCalled();
+  });
+});
@@ -0,0 +1,61 @@
+import React from 'react';
+import { describe, it, expect, vi, beforeEach, type Mock } from 'vitest';
+import { render, screen, fireEvent, waitFor } from '@testing-library/react';
+import SsoRolePicker from '../renderer/components/SsoRolePicker';
+
+describe('SsoRolePicker', () => {
+  const mockOnPick = vi.fn();
+  const mockOnCancel = vi.fn();
+  const listSsoRoles = window.electronAPI.aws.listSsoRoles as Mock;
+
+  beforeEach(() => {
+    mockOnPick.mockClear();
+    mockOnCancel.mockClear();
+    listSsoRoles.mockReset();
+  });
+
+  it('lists the roles and picks one', async () => {
+    listSsoRoles.mockResolvedValue({
+      success: true,
+      roles: [
+        { accountId: '111111111111', accountName: 'Production', roleName: 'Admin' },
+        { accountId: '222222222222', accountName: 'Staging', roleName: 'Developer' },
+      ],
+    });
+    render(<SsoRolePicker isOpen={true} profileName="sso-dev" onPick={mockOnPick} onCancel={mockOnCancel} />);
+
+    fireEvent.click(await screen.findByText('Developer'));
+
+    expect(listSsoRoles).toHaveBeenCalledWith('sso-dev');
+    expect(mockOnPick).toHaveBeenCalledWith({ accountId: '222222222222', accountName: 'Staging', roleName: 'Developer' });
+  });
+
+  it('filters by account or role', async () => {
+    listSsoRoles.mockResolvedValue({
+      success: true,
+      roles: [
+        { accountId: '111111111111', accountName: 'Production', roleName: 'Admin' },
+        { accountId: '222222222222', accountName: 'Staging', roleName: 'Developer' },
+      ],
+    });
+    render(<SsoRolePicker isOpen={true} profileName="sso-dev" onPick={mockOnPick} onCancel={mockOnCancel} />);
+
+    fireEvent.change(await screen.findByLabelText('Filter accounts'), { target: { value: 'prod' } });
+
+    expect(screen.getByText('Admin')).toBeInTheDocument();
+    expect(screen.queryByText('Developer')).not.toBeInTheDocument();
+  });
+
+  it('shows why the accounts could not be listed', async () => {
+    listSsoRoles.mockResolvedValue({
+      success: false,
+      needsLogin: true,
+      error: 'The SSO sign-in has expired; sign in again',
+    });
+    render(<SsoRolePicker isOpen={true} profileName="sso-dev" onPick={mockOnPick} onCancel={mockOnCancel} />);
+
+    await waitFor(() => {
+      expect(screen.getByRole('alert')).toHaveTextContent('The SSO sign-in has expired; sign in again');
+    });
+  });
+});
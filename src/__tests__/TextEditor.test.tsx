@@ -53,8 +53,8 @@ describe('TextEditor', () => {
       render(<TextEditor {...defaultProps} />);
 
       await waitFor(() => {
-        expect(mockElectronAPI.s3.getFileSize).toHaveBeenCalledWith('test-bucket', 'path/to/file.json');
-        expect(mockElectronAPI.s3.downloadContent).toHaveBeenCalledWith('test-bucket', 'path/to/file.json');
+        expect(mockElectronAPI.s3.getFileSize).toHaveBeenCalledWith('test-bucket', 'path/to/file.json', undefined);
+        expect(mockElectronAPI.s3.downloadContent).toHaveBeenCalledWith('test-bucket', 'path/to/file.json', undefined);
       });
 
       await waitFor(() => {
@@ -408,4 +408,52 @@ describe('TextEditor', () => {
       });
     });
   });
+
+  describe('long lines', () => {
+    it('shows a file with a huge line wrapped and read-only', async () => {
+      const line = 'a,'.repeat(15000);
+      mockElectronAPI.s3.getFileSize.mockResolvedValue({ success: true, size: line.length });
+      mockElectronAPI.s3.downloadContent.mockResolvedValue({ success: true, content: line });
+
+      render(<TextEditor {...defaultProps} fileKey="path/to/data.csv" fileName="data.csv" />);
+
+      expect(await screen.findByText(/The longest line has 30,000 characters/)).toBeInTheDocument();
+      const editor = screen.getByTestId('monaco-editor') as HTMLTextAreaElement;
+      expect(editor.value.split('\n')).toHaveLength(30);
+      expect(screen.getByText('Read-only (wrapped)')).toBeInTheDocument();
+      expect(screen.queryByText('Pretty-print JSON')).not.toBeInTheDocument();
+
+      fireEvent.click(screen.getByText('Edit anyway'));
+
+      expect((screen.getByTestId('monaco-editor') as HTMLTextAreaElement).value).toBe(line);
+      expect(screen.getByText('Saved')).toBeInTheDocument();
+    });
+
+    it('offers to pretty-print minified JSON', async () => {
+      const minified = JSON.stringify(Array.from({ length: 3000 }, (_, id) => ({ id })));
+      mockElectronAPI.s3.getFileSize.mockResolvedValue({ success: true, size: minified.length });
+      mockElectronAPI.s3.downloadContent.mockResolvedValue({ success: true, content: minified });
+
+      render(<TextEditor {...defaultProps} />);
+
+      fireEvent.click(await screen.findByText('Pretty-print JSON'));
+
+      const editor = screen.getByTestId('monaco-editor') as HTMLTextAreaElement;
+      expect(editor.value).toBe(JSON.stringify(JSON.parse(minified), null, 2));
+      expect(screen.queryByText(/The longest line has/)).not.toBeInTheDocument();
+      expect(screen.getByText('Modified')).toBeInTheDocument();
+    });
+
+    it('opens files with ordinary lines normally', async () => {
+      mockElectronAPI.s3.getFileSize.mockResolvedValue({ success: true, size: 100 });
+      mockElectronAPI.s3.downloadContent.mockResolvedValue({ success: true, content: '{"a": 1}' });
+
+      render(<TextEditor {...defaultProps} />);
+
+      await waitFor(() => {
+        expect((screen.getByTestId('monaco-editor') as HTMLTextAreaElement).value).toBe('{"a": 1}');
+      });
+      expect(screen.queryByText(/The longest line has/)).not.toBeInTheDocument();
+    });
+  });
 });
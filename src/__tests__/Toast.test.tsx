@@ -1,7 +1,7 @@
 import React from 'react';
 import { render, screen, fireEvent, act, waitFor } from '@testing-library/react';
 import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
-import { ToastContainer, useToasts, type ToastMessage } from '../renderer/components/Toast';
+import { ToastContainer, useToasts, MAX_VISIBLE_TOASTS, type ToastMessage } from '../renderer/components/Toast';
 import { renderHook } from '@testing-library/react';
 
 describe('ToastContainer', () => {
@@ -153,6 +153,58 @@ describe('ToastContainer', () => {
     expect(screen.getByRole('button', { name: /dismiss/i })).toBeInTheDocument();
   });
 
+  describe('queue', () => {
+    const manyToasts = (count: number, duration = 3000): ToastMessage[] =>
+      Array.from({ length: count }, (_, i) =>
+        createTestToast({ id: `toast-${i + 1}`, title: `Toast ${i + 1}`, duration })
+      );
+
+    it('shows the first toasts and counts the rest as queued', () => {
+      const { container } = render(
+        <ToastContainer toasts={manyToasts(MAX_VISIBLE_TOASTS + 2)} onDismiss={mockOnDismiss} />
+      );
+
+      expect(container.querySelectorAll('.toast')).toHaveLength(MAX_VISIBLE_TOASTS);
+      expect(screen.getByText('Toast 1')).toBeInTheDocument();
+      expect(screen.queryByText(`Toast ${MAX_VISIBLE_TOASTS + 1}`)).not.toBeInTheDocument();
+      expect(screen.getByText('+2 more')).toBeInTheDocument();
+    });
+
+    it('shows queued toasts in order as earlier ones are dismissed', () => {
+      const toasts = manyToasts(MAX_VISIBLE_TOASTS + 2);
+      const { rerender } = render(<ToastContainer toasts={toasts} onDismiss={mockOnDismiss} />);
+
+      rerender(<ToastContainer toasts={toasts.slice(1)} onDismiss={mockOnDismiss} />);
+
+      expect(screen.queryByText('Toast 1')).not.toBeInTheDocument();
+      expect(screen.getByText(`Toast ${MAX_VISIBLE_TOASTS + 1}`)).toBeInTheDocument();
+      expect(screen.queryByText(`Toast ${MAX_VISIBLE_TOASTS + 2}`)).not.toBeInTheDocument();
+      expect(screen.getByText('+1 more')).toBeInTheDocument();
+    });
+
+    it('starts the timer of a queued toast only once it is shown', () => {
+      const toasts = manyToasts(MAX_VISIBLE_TOASTS + 1);
+      const queuedId = `toast-${MAX_VISIBLE_TOASTS + 1}`;
+      const { rerender } = render(<ToastContainer toasts={toasts} onDismiss={mockOnDismiss} />);
+
+      // The queued toast is not dismissed while it waits
+      act(() => {
+        vi.advanceTimersByTime(2000);
+      });
+      rerender(<ToastContainer toasts={toasts.slice(1)} onDismiss={mockOnDismiss} />);
+      act(() => {
+        vi.advanceTimersByTime(1500);
+      });
+      expect(mockOnDismiss).not.toHaveBeenCalledWith(queuedId);
+
+      // ...and gets its full duration after it appears, dismissed exactly once
+      act(() => {
+        vi.advanceTimersByTime(1700);
+      });
+      expect(mockOnDismiss.mock.calls.filter(([id]) => id === queuedId)).toHaveLength(1);
+    });
+  });
+
   it('renders dismiss button with × character (not raw unicode escape)', () => {
     const toast = createTestToast();
     render(<ToastContainer toasts={[toast]} onDismiss={mockOnDismiss} />);
@@ -38,7 +38,8 @@ describe('YamlViewer', () => {
       await waitFor(() => {
         expect(mockElectronAPI.s3.downloadContent).toHaveBeenCalledWith(
           'test-bucket',
-          'path/to/config.yaml'
+          'path/to/config.yaml',
+          undefined
         );
       });
     });
@@ -482,6 +483,54 @@ describe('YamlViewer', () => {
     });
   });
 
+  describe('tree view', () => {
+    const openTree = async (content: string) => {
+      mockElectronAPI.s3.downloadContent.mockResolvedValue({ success: true, content });
+      render(<YamlViewer {...defaultProps} />);
+      await waitFor(() => {
+        expect(screen.getByText('Tree')).toBeInTheDocument();
+      });
+      fireEvent.click(screen.getByText('Tree'));
+    };
+
+    it('shows multi-document files as a list of documents', async () => {
+      await openTree('kind: ConfigMap\n---\nkind: Service\n');
+
+      expect(screen.getByText('Document 1')).toBeInTheDocument();
+      expect(screen.getByText('Document 2')).toBeInTheDocument();
+      expect(screen.getByText('"Service"')).toBeInTheDocument();
+      expect(screen.getByText('2 documents')).toBeInTheDocument();
+    });
+
+    it('shows a single document as the root node', async () => {
+      await openTree('name: test\n');
+
+      expect(screen.getByText('root')).toBeInTheDocument();
+      expect(screen.getByText('"test"')).toBeInTheDocument();
+    });
+
+    it('expands aliases and marks them', async () => {
+      await openTree('defaults: &defaults\n  timeout: 30\nproduction: *defaults\n');
+
+      const aliasMarker = document.querySelector('.yaml-tree-annotation.yaml-alias');
+      expect(aliasMarker?.textContent).toBe('*defaults');
+      expect(aliasMarker).toHaveAttribute('title', 'Resolved from alias *defaults');
+      expect(screen.getAllByText('timeout')).toHaveLength(2);
+    });
+
+    it('shows tags as type annotations', async () => {
+      await openTree('bucket: !Ref MyBucket\n');
+
+      expect(document.querySelector('.yaml-tree-annotation.yaml-tag')?.textContent).toBe('!Ref');
+    });
+
+    it('shows parse errors instead of the tree', async () => {
+      await openTree('a: *missing\n');
+
+      expect(screen.getByText(/Cannot show tree: unidentified alias "missing"/)).toBeInTheDocument();
+    });
+  });
+
   describe('yml file extension', () => {
     it('works with .yml files', async () => {
       mockElectronAPI.s3.downloadContent.mockResolvedValue({
@@ -500,7 +549,8 @@ describe('YamlViewer', () => {
       await waitFor(() => {
         expect(mockElectronAPI.s3.downloadContent).toHaveBeenCalledWith(
           'test-bucket',
-          'path/to/config.yml'
+          'path/to/config.yml',
+          undefined
         );
       });
     });
@@ -15,7 +15,25 @@ import {
   saveAppState,
   clearAppState,
   getAppStatePath,
+  getCorruptStateBackupPath,
   createDefaultState,
+  isProfileReadOnly,
+  exportShareable,
+  parseShareable,
+  mergeShareable,
+  MAX_RECENT_LOCATIONS,
+  addBookmark,
+  removeBookmark,
+  listBookmarks,
+  getWindowState,
+  getTheme,
+  setTheme,
+  setWindowState,
+  setColumnWidths,
+  pruneColumnWidths,
+  MAX_COLUMN_WIDTH_ENTRIES,
+  MAX_RETRIES_LIMIT,
+  MAX_TIMEOUT_SECONDS,
   type AppState,
 } from '../main/services/appState';
 
@@ -31,10 +49,16 @@ describe('appState service', () => {
   });
 
   afterEach(() => {
+    vi.restoreAllMocks();
     // Clean up after each test
     if (fs.existsSync(testStatePath)) {
       fs.unlinkSync(testStatePath);
     }
+    for (const name of fs.existsSync(testDataDir) ? fs.readdirSync(testDataDir) : []) {
+      if (name.startsWith('app-state.json.corrupt')) {
+        fs.rmSync(path.join(testDataDir, name), { force: true });
+      }
+    }
   });
 
   describe('getAppStatePath', () => {
@@ -51,6 +75,23 @@ describe('appState service', () => {
         lastProfile: null,
         lastBucket: null,
         lastPrefix: '',
+        readOnlyProfiles: [],
+        downloadConflictPolicy: 'rename',
+        collapseSingleChildFolders: false,
+        confirmBeforeDelete: true,
+        recentLocations: [],
+        bookmarks: [],
+        viewerLayouts: {},
+        columnWidths: {},
+        requestLogging: false,
+        requesterPays: false,
+        maxRetries: null,
+        connectTimeoutSeconds: null,
+        requestTimeoutSeconds: null,
+        uploadStorageClass: null,
+        proxyUrl: null,
+        theme: 'system',
+        window: null,
         version: 1,
       });
     });
@@ -74,6 +115,18 @@ describe('appState service', () => {
         lastProfile: 'test-profile',
         lastBucket: 'test-bucket',
         lastPrefix: 'test/prefix/',
+        readOnlyProfiles: ['prod'],
+        downloadConflictPolicy: 'skip',
+        collapseSingleChildFolders: true,
+        recentLocations: [{ profile: 'prod', bucket: 'logs', prefix: '2024/' }],
+        viewerLayouts: { 'data/events.parquet': { hiddenColumns: ['payload'] } },
+        requestLogging: true,
+        requesterPays: true,
+        maxRetries: 5,
+        connectTimeoutSeconds: 10,
+        requestTimeoutSeconds: 120,
+        uploadStorageClass: 'STANDARD_IA',
+        proxyUrl: 'http://proxy.corp.example:3128/',
         version: 1,
       };
       fs.writeFileSync(testStatePath, JSON.stringify(testState), 'utf-8');
@@ -82,9 +135,67 @@ describe('appState service', () => {
       expect(state.lastProfile).toBe('test-profile');
       expect(state.lastBucket).toBe('test-bucket');
       expect(state.lastPrefix).toBe('test/prefix/');
+      expect(state.readOnlyProfiles).toEqual(['prod']);
+      expect(state.downloadConflictPolicy).toBe('skip');
+      expect(state.collapseSingleChildFolders).toBe(true);
+      expect(state.recentLocations).toEqual([{ profile: 'prod', bucket: 'logs', prefix: '2024/' }]);
+      expect(state.viewerLayouts).toEqual({ 'data/events.parquet': { hiddenColumns: ['payload'] } });
+      expect(state.requestLogging).toBe(true);
+      expect(state.requesterPays).toBe(true);
+      expect(state.maxRetries).toBe(5);
+      expect(state.connectTimeoutSeconds).toBe(10);
+      expect(state.requestTimeoutSeconds).toBe(120);
+      expect(state.uploadStorageClass).toBe('STANDARD_IA');
+      expect(state.proxyUrl).toBe('http://proxy.corp.example:3128/');
       expect(state.version).toBe(1);
     });
 
+    it('should ignore timeouts that are not a positive number of seconds', () => {
+      if (!fs.existsSync(testDataDir)) {
+        fs.mkdirSync(testDataDir, { recursive: true });
+      }
+      for (const timeout of [0, -5, 1.5, MAX_TIMEOUT_SECONDS + 1, '30']) {
+        fs.writeFileSync(
+          testStatePath,
+          JSON.stringify({ version: 1, connectTimeoutSeconds: timeout, requestTimeoutSeconds: timeout }),
+          'utf-8'
+        );
+        const state = loadAppState();
+        expect(state.connectTimeoutSeconds).toBeNull();
+        expect(state.requestTimeoutSeconds).toBeNull();
+      }
+    });
+
+    it('should ignore storage classes that cannot be uploaded into', () => {
+      if (!fs.existsSync(testDataDir)) {
+        fs.mkdirSync(testDataDir, { recursive: true });
+      }
+      for (const uploadStorageClass of ['standard', 'REDUCED_REDUNDANCY', 42]) {
+        fs.writeFileSync(testStatePath, JSON.stringify({ version: 1, uploadStorageClass }), 'utf-8');
+        expect(loadAppState().uploadStorageClass).toBeNull();
+      }
+    });
+
+    it('should ignore proxy URLs that are not http or https', () => {
+      if (!fs.existsSync(testDataDir)) {
+        fs.mkdirSync(testDataDir, { recursive: true });
+      }
+      for (const proxyUrl of ['socks5://proxy:1080', 'proxy:3128', '', 42]) {
+        fs.writeFileSync(testStatePath, JSON.stringify({ version: 1, proxyUrl }), 'utf-8');
+        expect(loadAppState().proxyUrl).toBeNull();
+      }
+    });
+
+    it('should ignore retry counts that are out of range', () => {
+      if (!fs.existsSync(testDataDir)) {
+        fs.mkdirSync(testDataDir, { recursive: true });
+      }
+      for (const maxRetries of [-1, 2.5, MAX_RETRIES_LIMIT + 1, '3']) {
+        fs.writeFileSync(testStatePath, JSON.stringify({ version: 1, maxRetries }), 'utf-8');
+        expect(loadAppState().maxRetries).toBeNull();
+      }
+    });
+
     it('should return default state for invalid JSON', () => {
       if (!fs.existsSync(testDataDir)) {
         fs.mkdirSync(testDataDir, { recursive: true });
@@ -96,6 +207,25 @@ describe('appState service', () => {
       expect(state.version).toBe(1);
     });
 
+    it('should move a file that is not valid JSON aside before using the default state', () => {
+      if (!fs.existsSync(testDataDir)) {
+        fs.mkdirSync(testDataDir, { recursive: true });
+      }
+      fs.writeFileSync(testStatePath, '{"lastProfile": "dev", "recentLoc', 'utf-8');
+      vi.spyOn(console, 'error').mockImplementation(() => {});
+      vi.useFakeTimers({ now: new Date('2024-05-01T12:30:45.678Z') });
+
+      try {
+        expect(loadAppState()).toEqual(createDefaultState());
+      } finally {
+        vi.useRealTimers();
+      }
+      expect(fs.existsSync(testStatePath)).toBe(false);
+      const backupPath = getCorruptStateBackupPath(new Date('2024-05-01T12:30:45.678Z'));
+      expect(backupPath).toBe(`${testStatePath}.corrupt-2024-05-01T12-30-45-678Z`);
+      expect(fs.readFileSync(backupPath, 'utf-8')).toBe('{"lastProfile": "dev", "recentLoc');
+    });
+
     it('should return default state if version is missing', () => {
       if (!fs.existsSync(testDataDir)) {
         fs.mkdirSync(testDataDir, { recursive: true });
@@ -127,6 +257,93 @@ describe('appState service', () => {
       expect(state.lastBucket).toBeNull();
       expect(state.lastPrefix).toBe('');
     });
+
+    it('should default readOnlyProfiles for older state files and drop invalid entries', () => {
+      if (!fs.existsSync(testDataDir)) {
+        fs.mkdirSync(testDataDir, { recursive: true });
+      }
+      fs.writeFileSync(testStatePath, JSON.stringify({ lastProfile: 'a', version: 1 }), 'utf-8');
+      expect(loadAppState().readOnlyProfiles).toEqual([]);
+
+      fs.writeFileSync(
+        testStatePath,
+        JSON.stringify({ readOnlyProfiles: ['prod', 42, null], version: 1 }),
+        'utf-8'
+      );
+      expect(loadAppState().readOnlyProfiles).toEqual(['prod']);
+    });
+
+    it('should default downloadConflictPolicy to rename for missing or unknown values', () => {
+      if (!fs.existsSync(testDataDir)) {
+        fs.mkdirSync(testDataDir, { recursive: true });
+      }
+      fs.writeFileSync(testStatePath, JSON.stringify({ version: 1 }), 'utf-8');
+      expect(loadAppState().downloadConflictPolicy).toBe('rename');
+
+      fs.writeFileSync(testStatePath, JSON.stringify({ downloadConflictPolicy: 'clobber', version: 1 }), 'utf-8');
+      expect(loadAppState().downloadConflictPolicy).toBe('rename');
+
+      fs.writeFileSync(testStatePath, JSON.stringify({ downloadConflictPolicy: 'overwrite', version: 1 }), 'utf-8');
+      expect(loadAppState().downloadConflictPolicy).toBe('overwrite');
+    });
+
+    it('should drop malformed recent locations', () => {
+      if (!fs.existsSync(testDataDir)) {
+        fs.mkdirSync(testDataDir, { recursive: true });
+      }
+      fs.writeFileSync(
+        testStatePath,
+        JSON.stringify({
+          recentLocations: [{ profile: 'dev', bucket: 'data', prefix: '' }, { bucket: 'no-profile' }, 'junk', null],
+          version: 1,
+        }),
+        'utf-8'
+      );
+
+      expect(loadAppState().recentLocations).toEqual([{ profile: 'dev', bucket: 'data', prefix: '' }]);
+    });
+
+    it('should default bookmarks for older state files and drop malformed ones', () => {
+      if (!fs.existsSync(testDataDir)) {
+        fs.mkdirSync(testDataDir, { recursive: true });
+      }
+      fs.writeFileSync(testStatePath, JSON.stringify({ version: 1 }), 'utf-8');
+      expect(loadAppState().bookmarks).toEqual([]);
+
+      fs.writeFileSync(
+        testStatePath,
+        JSON.stringify({
+          bookmarks: [
+            { label: 'Logs', profile: 'dev', bucket: 'data', prefix: 'logs/' },
+            { profile: 'dev', bucket: 'no-label', prefix: '' },
+            null,
+          ],
+          version: 1,
+        }),
+        'utf-8'
+      );
+      expect(loadAppState().bookmarks).toEqual([{ label: 'Logs', profile: 'dev', bucket: 'data', prefix: 'logs/' }]);
+    });
+
+    it('should drop malformed viewer layouts', () => {
+      if (!fs.existsSync(testDataDir)) {
+        fs.mkdirSync(testDataDir, { recursive: true });
+      }
+      fs.writeFileSync(
+        testStatePath,
+        JSON.stringify({
+          viewerLayouts: {
+            'data/a.csv': { hiddenColumns: ['id', 7, 'name'] },
+            'data/b.csv': { hiddenColumns: 'id' },
+            'data/c.csv': null,
+          },
+          version: 1,
+        }),
+        'utf-8'
+      );
+
+      expect(loadAppState().viewerLayouts).toEqual({ 'data/a.csv': { hiddenColumns: ['id', 'name'] } });
+    });
   });
 
   describe('saveAppState', () => {
@@ -179,6 +396,34 @@ describe('appState service', () => {
       expect(fs.existsSync(testStatePath)).toBe(true);
     });
 
+    it('should write a temporary file and rename it over the state file', () => {
+      saveAppState({ lastProfile: 'before' });
+      const renameSpy = vi.spyOn(fs, 'renameSync');
+
+      saveAppState({ lastProfile: 'after' });
+
+      expect(renameSpy).toHaveBeenCalledTimes(1);
+      const [from, to] = renameSpy.mock.calls[0];
+      expect(path.dirname(String(from))).toBe(testDataDir);
+      expect(to).toBe(testStatePath);
+      expect(fs.existsSync(String(from))).toBe(false);
+      expect(loadAppState().lastProfile).toBe('after');
+    });
+
+    it('should keep the previous state and no temporary file when the rename fails', () => {
+      saveAppState({ lastProfile: 'before' });
+      vi.spyOn(fs, 'renameSync').mockImplementation(() => {
+        throw new Error('disk full');
+      });
+      vi.spyOn(console, 'error').mockImplementation(() => {});
+
+      expect(saveAppState({ lastProfile: 'after' })).toBe(false);
+
+      vi.restoreAllMocks();
+      expect(loadAppState().lastProfile).toBe('before');
+      expect(fs.readdirSync(testDataDir).filter(name => name.endsWith('.tmp'))).toEqual([]);
+    });
+
     it('should handle null values correctly', () => {
       saveAppState({
         lastProfile: null,
@@ -193,6 +438,344 @@ describe('appState service', () => {
     });
   });
 
+  describe('confirmBeforeDelete', () => {
+    it('should default to asking before deleting', () => {
+      expect(loadAppState().confirmBeforeDelete).toBe(true);
+    });
+
+    it('should round-trip the setting', () => {
+      expect(saveAppState({ confirmBeforeDelete: false })).toBe(true);
+      expect(loadAppState().confirmBeforeDelete).toBe(false);
+      expect(JSON.parse(fs.readFileSync(testStatePath, 'utf-8')).confirmBeforeDelete).toBe(false);
+
+      saveAppState({ confirmBeforeDelete: true });
+      expect(loadAppState()).toEqual(createDefaultState());
+    });
+
+    it('should only turn confirmation off for an explicit false', () => {
+      if (!fs.existsSync(testDataDir)) {
+        fs.mkdirSync(testDataDir, { recursive: true });
+      }
+      for (const value of [undefined, 'no', 0, null]) {
+        fs.writeFileSync(testStatePath, JSON.stringify({ confirmBeforeDelete: value, version: 1 }), 'utf-8');
+        expect(loadAppState().confirmBeforeDelete).toBe(true);
+      }
+    });
+  });
+
+  describe('theme', () => {
+    it('should default to following the system', () => {
+      expect(createDefaultState().theme).toBe('system');
+      expect(getTheme()).toBe('system');
+    });
+
+    it('should round-trip the theme and keep it out of other fields', () => {
+      expect(setTheme('dark')).toBe(true);
+      expect(getTheme()).toBe('dark');
+      expect(JSON.parse(fs.readFileSync(testStatePath, 'utf-8')).theme).toBe('dark');
+
+      setTheme('light');
+      expect(loadAppState()).toEqual({ ...createDefaultState(), theme: 'light' });
+    });
+
+    it('should fall back to the system theme for unknown values', () => {
+      if (!fs.existsSync(testDataDir)) {
+        fs.mkdirSync(testDataDir, { recursive: true });
+      }
+      fs.writeFileSync(testStatePath, JSON.stringify({ theme: 'solarized', version: 1 }), 'utf-8');
+      expect(loadAppState().theme).toBe('system');
+    });
+  });
+
+  describe('window state', () => {
+    it('should round-trip the window geometry', () => {
+      expect(getWindowState()).toBeNull();
+
+      const geometry = { width: 1400, height: 900, x: -1600, y: 40, maximized: true };
+      expect(setWindowState(geometry)).toBe(true);
+      expect(getWindowState()).toEqual(geometry);
+    });
+
+    it('should ignore malformed window geometry', () => {
+      if (!fs.existsSync(testDataDir)) {
+        fs.mkdirSync(testDataDir, { recursive: true });
+      }
+      for (const window of [{ width: 0, height: 600, x: 0, y: 0 }, { width: 800, height: '600', x: 0, y: 0 }, 'big']) {
+        fs.writeFileSync(testStatePath, JSON.stringify({ window, version: 1 }), 'utf-8');
+        expect(loadAppState().window).toBeNull();
+      }
+
+      fs.writeFileSync(testStatePath, JSON.stringify({ window: { width: 800, height: 600, x: 10, y: 20 }, version: 1 }), 'utf-8');
+      expect(loadAppState().window).toEqual({ width: 800, height: 600, x: 10, y: 20, maximized: false });
+    });
+  });
+
+  describe('column widths', () => {
+    const signature = 'data/events.parquet#1a2b3c4d';
+
+    it('should round-trip the widths of a signature', () => {
+      expect(loadAppState().columnWidths[signature]).toBeUndefined();
+
+      expect(setColumnWidths(signature, { id: 80, payload: 420.5 })).toBe(true);
+      expect(loadAppState().columnWidths[signature]).toEqual({ id: 80, payload: 420.5 });
+      expect(JSON.parse(fs.readFileSync(testStatePath, 'utf-8')).columnWidths).toEqual({
+        [signature]: { id: 80, payload: 420.5 },
+      });
+    });
+
+    it('should replace the widths saved before and forget empty ones', () => {
+      setColumnWidths(signature, { id: 80, payload: 420 });
+      setColumnWidths(signature, { payload: 200 });
+      expect(loadAppState().columnWidths[signature]).toEqual({ payload: 200 });
+
+      setColumnWidths(signature, {});
+      expect(loadAppState().columnWidths).toEqual({});
+    });
+
+    it('should keep the most recently set signatures past the cap', () => {
+      for (let i = 0; i < MAX_COLUMN_WIDTH_ENTRIES + 2; i++) {
+        setColumnWidths(`file-${i}`, { id: 100 });
+      }
+      setColumnWidths('file-2', { id: 150 });
+
+      const signatures = Object.keys(loadAppState().columnWidths);
+      expect(signatures).toHaveLength(MAX_COLUMN_WIDTH_ENTRIES);
+      expect(signatures).not.toContain('file-0');
+      expect(signatures).not.toContain('file-1');
+      expect(signatures[signatures.length - 1]).toBe('file-2');
+    });
+
+    it('should prune the oldest entries', () => {
+      const widths = { a: { x: 1 }, b: { x: 2 }, c: { x: 3 } };
+      expect(pruneColumnWidths(widths, 2)).toEqual({ b: { x: 2 }, c: { x: 3 } });
+      expect(pruneColumnWidths(widths, 5)).toEqual(widths);
+      expect(pruneColumnWidths(widths, 0)).toEqual({});
+    });
+
+    it('should drop malformed widths when loading', () => {
+      if (!fs.existsSync(testDataDir)) {
+        fs.mkdirSync(testDataDir, { recursive: true });
+      }
+      fs.writeFileSync(
+        testStatePath,
+        JSON.stringify({
+          columnWidths: {
+            'a.parquet#1': { id: 90, name: '120', size: -4, note: null },
+            'b.parquet#2': [100],
+            'c.parquet#3': { id: 0 },
+            'd.parquet#4': null,
+          },
+          version: 1,
+        }),
+        'utf-8'
+      );
+
+      expect(loadAppState().columnWidths).toEqual({ 'a.parquet#1': { id: 90 } });
+    });
+  });
+
+  describe('bookmarks', () => {
+    const logs = { label: 'Logs', profile: 'dev', bucket: 'data', prefix: 'logs/' };
+    const reports = { label: 'Reports', profile: 'dev', bucket: 'data', prefix: 'reports/2024/' };
+
+    it('should save bookmarks in the order they were added', () => {
+      expect(addBookmark(logs)).toEqual([logs]);
+      expect(addBookmark(reports)).toEqual([logs, reports]);
+
+      expect(listBookmarks()).toEqual([logs, reports]);
+      expect(JSON.parse(fs.readFileSync(testStatePath, 'utf-8')).bookmarks).toEqual([logs, reports]);
+    });
+
+    it('should relabel a location bookmarked again in place', () => {
+      addBookmark(logs);
+      addBookmark(reports);
+
+      expect(addBookmark({ ...logs, label: 'App logs' })).toEqual([{ ...logs, label: 'App logs' }, reports]);
+    });
+
+    it('should keep bookmarks of the same folder under different profiles apart', () => {
+      addBookmark(logs);
+      addBookmark({ ...logs, profile: 'prod' });
+
+      expect(removeBookmark({ profile: 'dev', bucket: 'data', prefix: 'logs/' })).toEqual([{ ...logs, profile: 'prod' }]);
+      expect(listBookmarks()).toEqual([{ ...logs, profile: 'prod' }]);
+    });
+
+    it('should leave other state untouched', () => {
+      saveAppState({ lastProfile: 'dev', recentLocations: [{ profile: 'dev', bucket: 'data', prefix: '' }] });
+      addBookmark(logs);
+
+      const state = loadAppState();
+      expect(state.lastProfile).toBe('dev');
+      expect(state.recentLocations).toHaveLength(1);
+    });
+  });
+
+  describe('isProfileReadOnly', () => {
+    it('should only report profiles saved as read-only', () => {
+      saveAppState({ readOnlyProfiles: ['prod'] });
+
+      expect(isProfileReadOnly('prod')).toBe(true);
+      expect(isProfileReadOnly('sandbox')).toBe(false);
+    });
+
+    it('should return false when no state is saved', () => {
+      expect(isProfileReadOnly('prod')).toBe(false);
+    });
+  });
+
+  describe('shareable settings', () => {
+    const teamState: AppState = {
+      ...createDefaultState(),
+      lastProfile: 'prod',
+      lastBucket: 'logs',
+      lastPrefix: '2024/',
+      readOnlyProfiles: ['prod'],
+      recentLocations: [
+        { profile: 'prod', bucket: 'logs', prefix: '2024/' },
+        { profile: 'dev', bucket: 'scratch', prefix: '' },
+      ],
+      bookmarks: [{ label: 'Prod logs', profile: 'prod', bucket: 'logs', prefix: '2024/' }],
+      viewerLayouts: { 'data/events.parquet': { hiddenColumns: ['payload'] } },
+      requestLogging: true,
+    };
+
+    it('should round-trip locations, bookmarks and read-only profiles only', () => {
+      const exported = exportShareable(teamState);
+
+      expect(parseShareable(exported)).toEqual({
+        recentLocations: teamState.recentLocations,
+        readOnlyProfiles: ['prod'],
+        bookmarks: teamState.bookmarks,
+      });
+      expect(exported).not.toContain('lastBucket');
+      expect(exported).not.toContain('viewerLayouts');
+      expect(exported).not.toContain('requestLogging');
+    });
+
+    it('should merge without duplicating existing entries', () => {
+      const current: AppState = {
+        ...createDefaultState(),
+        readOnlyProfiles: ['staging', 'prod'],
+        recentLocations: [{ profile: 'prod', bucket: 'logs', prefix: '2024/' }],
+      };
+
+      const { update, added } = mergeShareable(current, parseShareable(exportShareable(teamState)));
+
+      expect(update.recentLocations).toEqual([
+        { profile: 'prod', bucket: 'logs', prefix: '2024/' },
+        { profile: 'dev', bucket: 'scratch', prefix: '' },
+      ]);
+      expect(update.readOnlyProfiles).toEqual(['staging', 'prod']);
+      expect(update.bookmarks).toEqual(teamState.bookmarks);
+      expect(added).toBe(2);
+    });
+
+    it('should keep the label of a location that is already bookmarked', () => {
+      const current: AppState = {
+        ...createDefaultState(),
+        bookmarks: [{ label: 'My logs', profile: 'prod', bucket: 'logs', prefix: '2024/' }],
+      };
+
+      const { update, added } = mergeShareable(current, {
+        recentLocations: [],
+        readOnlyProfiles: [],
+        bookmarks: [
+          { label: 'Prod logs', profile: 'prod', bucket: 'logs', prefix: '2024/' },
+          { label: 'Reports', profile: 'prod', bucket: 'reports', prefix: '' },
+          { label: 'Reports again', profile: 'prod', bucket: 'reports', prefix: '' },
+        ],
+      });
+
+      expect(update.bookmarks).toEqual([
+        { label: 'My logs', profile: 'prod', bucket: 'logs', prefix: '2024/' },
+        { label: 'Reports', profile: 'prod', bucket: 'reports', prefix: '' },
+      ]);
+      expect(added).toBe(1);
+    });
+
+    it('should put imported locations first so the limit keeps them', () => {
+      const current: AppState = {
+        ...createDefaultState(),
+        recentLocations: Array.from({ length: MAX_RECENT_LOCATIONS }, (_, i) => ({
+          profile: 'dev',
+          bucket: `bucket-${i}`,
+          prefix: '',
+        })),
+      };
+
+      const { update, added } = mergeShareable(current, {
+        recentLocations: [
+          { profile: 'prod', bucket: 'logs', prefix: '2024/' },
+          { profile: 'dev', bucket: 'bucket-3', prefix: '' },
+        ],
+        readOnlyProfiles: [],
+        bookmarks: [],
+      });
+
+      expect(update.recentLocations).toHaveLength(MAX_RECENT_LOCATIONS);
+      expect(update.recentLocations.slice(0, 3)).toEqual([
+        { profile: 'prod', bucket: 'logs', prefix: '2024/' },
+        { profile: 'dev', bucket: 'bucket-3', prefix: '' },
+        { profile: 'dev', bucket: 'bucket-0', prefix: '' },
+      ]);
+      expect(update.recentLocations).not.toContainEqual({ profile: 'dev', bucket: 'bucket-9', prefix: '' });
+      expect(added).toBe(1);
+    });
+
+    it('should de-duplicate entries repeated within the import', () => {
+      const { update, added } = mergeShareable(createDefaultState(), {
+        recentLocations: [
+          { profile: 'p', bucket: 'b', prefix: 'x/' },
+          { profile: 'p', bucket: 'b', prefix: 'x/' },
+        ],
+        readOnlyProfiles: ['p', 'p'],
+        bookmarks: [],
+      });
+
+      expect(update.recentLocations).toHaveLength(1);
+      expect(update.readOnlyProfiles).toEqual(['p']);
+      expect(added).toBe(2);
+    });
+
+    it('should skip malformed entries', () => {
+      const shared = parseShareable(
+        JSON.stringify({
+          format: 's3-browser-shared-settings',
+          version: 1,
+          recentLocations: [{ profile: 'p', bucket: 'b', prefix: '' }, { bucket: 'no-profile' }, 'junk'],
+          readOnlyProfiles: ['p', 42],
+          bookmarks: [{ label: 'b', profile: 'p', bucket: 'b', prefix: '' }, { label: 'no-location' }],
+        })
+      );
+
+      expect(shared).toEqual({
+        recentLocations: [{ profile: 'p', bucket: 'b', prefix: '' }],
+        readOnlyProfiles: ['p'],
+        bookmarks: [{ label: 'b', profile: 'p', bucket: 'b', prefix: '' }],
+      });
+    });
+
+    it('should import exports made before bookmarks were shared', () => {
+      const shared = parseShareable(
+        JSON.stringify({ format: 's3-browser-shared-settings', version: 1, recentLocations: [], readOnlyProfiles: [] })
+      );
+
+      expect(shared.bookmarks).toEqual([]);
+    });
+
+    it('should reject files that are not settings exports', () => {
+      expect(() => parseShareable('not json')).toThrow('The file is not valid JSON');
+      expect(() => parseShareable(JSON.stringify({ lastProfile: 'x', version: 1 }))).toThrow(
+        'The file is not an S3 Browser settings export'
+      );
+      expect(() => parseShareable('null')).toThrow('The file is not an S3 Browser settings export');
+      expect(() =>
+        parseShareable(JSON.stringify({ format: 's3-browser-shared-settings', version: 99 }))
+      ).toThrow('newer version');
+    });
+  });
+
   describe('clearAppState', () => {
     it('should delete state file', () => {
       // Create state first
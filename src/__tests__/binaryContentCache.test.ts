@@ -0,0 +1,85 @@
+import { describe, it, expect, beforeEach } from 'vitest';
+import { LruCache } from '../renderer/utils/thumbnailCache';
+import { loadBinaryContent, BINARY_CACHE_MAX_BYTES, type CachedBinary } from '../renderer/utils/binaryContentCache';
+import { mockElectronAPI } from './setup';
+
+describe('loadBinaryContent', () => {
+  let cache: LruCache<CachedBinary>;
+  const lastModified = new Date('2024-06-01T00:00:00Z');
+
+  beforeEach(() => {
+    cache = new LruCache<CachedBinary>(4);
+    mockElectronAPI.s3.downloadBinaryContent.mockReset();
+    mockElectronAPI.s3.downloadBinaryContentIfModified.mockReset();
+  });
+
+  it('downloads on first open and caches the response with its validators', async () => {
+    const data = new Uint8Array([1, 2, 3]);
+    mockElectronAPI.s3.downloadBinaryContent.mockResolvedValue({ success: true, data, etag: '"v1"', lastModified });
+
+    const result = await loadBinaryContent('bucket', 'data.parquet', cache);
+
+    expect(result).toEqual({ success: true, data });
+    expect(cache.get('bucket/data.parquet')).toEqual({ data, etag: '"v1"', lastModified });
+    expect(mockElectronAPI.s3.downloadBinaryContentIfModified).not.toHaveBeenCalled();
+  });
+
+  it('reuses the cached copy when S3 reports it unchanged', async () => {
+    const data = new Uint8Array([1, 2, 3]);
+    cache.set('bucket/data.parquet', { data, etag: '"v1"', lastModified });
+    mockElectronAPI.s3.downloadBinaryContentIfModified.mockResolvedValue({ success: true, notModified: true });
+
+    const result = await loadBinaryContent('bucket', 'data.parquet', cache);
+
+    expect(mockElectronAPI.s3.downloadBinaryContentIfModified).toHaveBeenCalledWith('bucket', 'data.parquet', {
+      etag: '"v1"',
+      modifiedSince: lastModified,
+    });
+    expect(mockElectronAPI.s3.downloadBinaryContent).not.toHaveBeenCalled();
+    expect(result).toEqual({ success: true, data, fromCache: true });
+  });
+
+  it('replaces the cached copy when the object changed', async () => {
+    const fresh = new Uint8Array([4, 5]);
+    cache.set('bucket/data.parquet', { data: new Uint8Array([1]), etag: '"v1"' });
+    mockElectronAPI.s3.downloadBinaryContentIfModified.mockResolvedValue({ success: true, data: fresh, etag: '"v2"' });
+
+    const result = await loadBinaryContent('bucket', 'data.parquet', cache);
+
+    expect(result).toEqual({ success: true, data: fresh });
+    expect(cache.get('bucket/data.parquet')).toMatchObject({ data: fresh, etag: '"v2"' });
+  });
+
+  it('does not cache responses without validators or above the size limit', async () => {
+    mockElectronAPI.s3.downloadBinaryContent.mockResolvedValueOnce({ success: true, data: new Uint8Array([1]) });
+    await loadBinaryContent('bucket', 'a.parquet', cache);
+
+    mockElectronAPI.s3.downloadBinaryContent.mockResolvedValueOnce({
+      success: true,
+      data: new Uint8Array(BINARY_CACHE_MAX_BYTES + 1),
+      etag: '"v1"',
+    });
+    await loadBinaryContent('bucket', 'b.parquet', cache);
+
+    expect(cache.size).toBe(0);
+  });
+
+  it('downloads a specific version once and keeps it apart from the latest one', async () => {
+    const data = new Uint8Array([9]);
+    cache.set('bucket/data.parquet', { data: new Uint8Array([1]), etag: '"latest"' });
+    mockElectronAPI.s3.downloadBinaryContent.mockResolvedValue({ success: true, data, etag: '"old"' });
+
+    expect(await loadBinaryContent('bucket', 'data.parquet', cache, 'v1')).toEqual({ success: true, data });
+    expect(await loadBinaryContent('bucket', 'data.parquet', cache, 'v1')).toEqual({ success: true, data, fromCache: true });
+
+    expect(mockElectronAPI.s3.downloadBinaryContent).toHaveBeenCalledTimes(1);
+    expect(mockElectronAPI.s3.downloadBinaryContent).toHaveBeenCalledWith('bucket', 'data.parquet', 'v1');
+    expect(mockElectronAPI.s3.downloadBinaryContentIfModified).not.toHaveBeenCalled();
+  });
+
+  it('reports download errors', async () => {
+    mockElectronAPI.s3.downloadBinaryContent.mockResolvedValue({ success: false, error: 'Access Denied' });
+
+    expect(await loadBinaryContent('bucket', 'data.parquet', cache)).toEqual({ success: false, error: 'Access Denied' });
+  });
+});
@@ -0,0 +1,57 @@
+import { describe, it, expect } from 'vitest';
+import { crc32c, computeChecksum, getEtagPartCount, md5MatchesEtag } from '../main/services/checksum';
+
+describe('checksum', () => {
+  describe('crc32c', () => {
+    it('matches the standard check value', () => {
+      expect(crc32c(Buffer.from('123456789'))).toBe(0xe3069283);
+    });
+
+    it('returns 0 for empty input', () => {
+      expect(crc32c(new Uint8Array(0))).toBe(0);
+    });
+  });
+
+  describe('computeChecksum', () => {
+    it('encodes CRC32C as base64 of the big-endian digest', () => {
+      expect(computeChecksum('123456789', 'CRC32C')).toBe('4waSgw==');
+    });
+
+    it('encodes SHA256 as base64', () => {
+      expect(computeChecksum('hello', 'SHA256')).toBe('LPJNul+wow4m6DsqxbninhsWHlwfp0JecwQzYpOLmCQ=');
+    });
+
+    it('gives the same result for strings and buffers', () => {
+      expect(computeChecksum('data', 'SHA256')).toBe(computeChecksum(Buffer.from('data'), 'SHA256'));
+    });
+  });
+
+  describe('getEtagPartCount', () => {
+    it('returns the part count of a multipart ETag', () => {
+      expect(getEtagPartCount('d41d8cd98f00b204e9800998ecf8427e-12')).toBe(12);
+      expect(getEtagPartCount('"D41D8CD98F00B204E9800998ECF8427E-3"')).toBe(3);
+    });
+
+    it('returns null for plain MD5 ETags', () => {
+      expect(getEtagPartCount('5d41402abc4b2a76b9719d911017c592')).toBeNull();
+      expect(getEtagPartCount('"5d41402abc4b2a76b9719d911017c592"')).toBeNull();
+    });
+
+    it('returns null for malformed ETags', () => {
+      expect(getEtagPartCount('')).toBeNull();
+      expect(getEtagPartCount('abc-2')).toBeNull();
+      expect(getEtagPartCount('d41d8cd98f00b204e9800998ecf8427e-0')).toBeNull();
+    });
+  });
+
+  describe('md5MatchesEtag', () => {
+    it('compares single-part ETags with the MD5 of the content', () => {
+      expect(md5MatchesEtag('"5d41402abc4b2a76b9719d911017c592"', 'hello')).toBe(true);
+      expect(md5MatchesEtag('5d41402abc4b2a76b9719d911017c592', Buffer.from('hello!'))).toBe(false);
+    });
+
+    it('skips multipart ETags', () => {
+      expect(md5MatchesEtag('5d41402abc4b2a76b9719d911017c592-2', 'hello')).toBeNull();
+    });
+  });
+});
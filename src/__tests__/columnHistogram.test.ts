@@ -0,0 +1,101 @@
+import { describe, it, expect } from 'vitest';
+import { computeHistogram } from '../renderer/utils/columnHistogram';
+
+describe('computeHistogram', () => {
+  describe('numeric columns', () => {
+    it('bins values into equal-width bins from min to max', () => {
+      const histogram = computeHistogram([0, 1, 2, 3, 4, 5, 6, 7, 8, 10], { bins: 5 });
+
+      expect(histogram).toEqual({
+        kind: 'numeric',
+        min: 0,
+        max: 10,
+        nullCount: 0,
+        bins: [
+          { start: 0, end: 2, count: 2 },
+          { start: 2, end: 4, count: 2 },
+          { start: 4, end: 6, count: 2 },
+          { start: 6, end: 8, count: 2 },
+          { start: 8, end: 10, count: 2 },
+        ],
+      });
+    });
+
+    it('puts the maximum in the last bin', () => {
+      const histogram = computeHistogram([1, 2, 2, 3], { bins: 2 });
+
+      expect(histogram.kind === 'numeric' && histogram.bins.map(bin => bin.count)).toEqual([1, 3]);
+    });
+
+    it('counts nulls separately', () => {
+      const histogram = computeHistogram([1, null, 5, undefined], { bins: 2 });
+
+      expect(histogram.nullCount).toBe(2);
+      expect(histogram.kind === 'numeric' && histogram.bins.map(bin => bin.count)).toEqual([1, 1]);
+    });
+
+    it('treats bigints as numbers', () => {
+      const histogram = computeHistogram([BigInt(1), BigInt(3)], { bins: 2 });
+
+      expect(histogram).toMatchObject({ kind: 'numeric', min: 1, max: 3 });
+    });
+
+    it('uses one bin for a single distinct value', () => {
+      expect(computeHistogram([7, 7, null, 7])).toEqual({
+        kind: 'numeric',
+        min: 7,
+        max: 7,
+        nullCount: 1,
+        bins: [{ start: 7, end: 7, count: 3 }],
+      });
+    });
+  });
+
+  describe('categorical columns', () => {
+    it('returns the most frequent values first, with the rest counted as other', () => {
+      const values = ['b', 'a', 'c', 'a', 'b', 'a', 'd', null];
+      const histogram = computeHistogram(values, { topCategories: 2 });
+
+      expect(histogram).toEqual({
+        kind: 'categorical',
+        categories: [
+          { value: 'a', count: 3 },
+          { value: 'b', count: 2 },
+        ],
+        otherCount: 2,
+        distinctCount: 4,
+        nullCount: 1,
+      });
+    });
+
+    it('keeps first-seen order for ties', () => {
+      const histogram = computeHistogram(['x', 'y', 'y', 'x', 'z']);
+
+      expect(histogram.kind === 'categorical' && histogram.categories.map(c => c.value)).toEqual(['x', 'y', 'z']);
+    });
+
+    it('treats mixed numbers and strings as categories', () => {
+      expect(computeHistogram([1, 'one', 1]).kind).toBe('categorical');
+    });
+
+    it('labels values with the given formatter', () => {
+      const histogram = computeHistogram([{ a: 1 }, { a: 1 }], { formatValue: value => JSON.stringify(value) });
+
+      expect(histogram.kind === 'categorical' && histogram.categories).toEqual([{ value: '{"a":1}', count: 2 }]);
+    });
+
+    it('handles a single distinct value', () => {
+      expect(computeHistogram([true, true])).toMatchObject({
+        kind: 'categorical',
+        categories: [{ value: 'true', count: 2 }],
+        otherCount: 0,
+        distinctCount: 1,
+      });
+    });
+  });
+
+  it('reports all-null columns as empty', () => {
+    expect(computeHistogram([null, undefined, null])).toEqual({ kind: 'empty', nullCount: 3 });
+    expect(computeHistogram([])).toEqual({ kind: 'empty', nullCount: 0 });
+  });
+});
@@ -0,0 +1,41 @@
+import { describe, it, expect } from 'vitest';
+import { columnValues, uniqueValues } from '../renderer/utils/columnValues';
+
+describe('columnValues', () => {
+  const rows = [
+    ['1', 'Alice', 'NYC'],
+    ['2', 'Bob', 'LA'],
+    ['3', 'Carol', 'NYC'],
+  ];
+
+  it('extracts one column in row order', () => {
+    expect(columnValues(rows, 2)).toEqual(['NYC', 'LA', 'NYC']);
+  });
+
+  it('formats each cell with the given formatter', () => {
+    const typed: unknown[][] = [[1n, null], [2n, true]];
+    expect(columnValues(typed, 0)).toEqual(['1', '2']);
+    expect(columnValues(typed, 1, value => (value === null ? '' : String(value)))).toEqual(['', 'true']);
+  });
+
+  it('gives an empty string for rows too short to have the column', () => {
+    expect(columnValues([['a', 'b'], ['c'], ['d', 'e']], 1)).toEqual(['b', '', 'e']);
+  });
+
+  it('returns no values for a column index outside every row', () => {
+    expect(columnValues(rows, 3)).toEqual([]);
+    expect(columnValues(rows, -1)).toEqual([]);
+    expect(columnValues(rows, 1.5)).toEqual([]);
+    expect(columnValues([], 0)).toEqual([]);
+  });
+});
+
+describe('uniqueValues', () => {
+  it('drops repeats and keeps first-seen order', () => {
+    expect(uniqueValues(['b', 'a', 'b', 'c', 'a'])).toEqual(['b', 'a', 'c']);
+  });
+
+  it('keeps empty strings as a value of their own', () => {
+    expect(uniqueValues(['', 'x', ''])).toEqual(['', 'x']);
+  });
+});
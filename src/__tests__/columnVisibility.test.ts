@@ -0,0 +1,90 @@
+import { describe, it, expect } from 'vitest';
+import {
+  MAX_VIEWER_LAYOUTS,
+  projectVisible,
+  updateViewerLayouts,
+  viewerLayoutKey,
+  visibleColumns,
+} from '../renderer/utils/columnVisibility';
+
+describe('projectVisible', () => {
+  const columns = ['id', 'name', 'city'];
+  const rows = [
+    ['1', 'Alice', 'NYC'],
+    ['2', 'Bob', 'LA'],
+  ];
+
+  it('keeps only the visible columns and their cells', () => {
+    expect(projectVisible(columns, rows, [true, false, true])).toEqual({
+      columns: ['id', 'city'],
+      rows: [
+        ['1', 'NYC'],
+        ['2', 'LA'],
+      ],
+    });
+  });
+
+  it('returns the data untouched when every column is visible', () => {
+    const projected = projectVisible(columns, rows, [true, true, true]);
+    expect(projected.columns).toBe(columns);
+    expect(projected.rows).toBe(rows);
+  });
+
+  it('returns no columns and empty rows when every column is hidden', () => {
+    expect(projectVisible(columns, rows, [false, false, false])).toEqual({
+      columns: [],
+      rows: [[], []],
+    });
+  });
+
+  it('shows columns that have no visibility flag', () => {
+    expect(projectVisible(columns, rows, [false]).columns).toEqual(['name', 'city']);
+  });
+
+  it('does not pad short rows', () => {
+    expect(projectVisible(columns, [['1', 'Alice']], [true, false, true]).rows).toEqual([['1']]);
+  });
+
+  it('does not modify the input', () => {
+    projectVisible(columns, rows, [false, true, false]);
+    expect(columns).toEqual(['id', 'name', 'city']);
+    expect(rows[0]).toEqual(['1', 'Alice', 'NYC']);
+  });
+});
+
+describe('visibleColumns', () => {
+  it('hides columns by name', () => {
+    expect(visibleColumns(['id', 'name', 'city'], ['name'])).toEqual([true, false, true]);
+  });
+
+  it('ignores hidden names that are no longer in the file', () => {
+    expect(visibleColumns(['id'], ['dropped'])).toEqual([true]);
+  });
+});
+
+describe('updateViewerLayouts', () => {
+  it('stores hidden columns under the object key', () => {
+    const key = viewerLayoutKey('data', 'events/2024.parquet');
+    expect(updateViewerLayouts({}, key, ['payload'])).toEqual({
+      'data/events/2024.parquet': { hiddenColumns: ['payload'] },
+    });
+  });
+
+  it('drops the entry when nothing is hidden', () => {
+    expect(updateViewerLayouts({ 'b/a.csv': { hiddenColumns: ['x'] } }, 'b/a.csv', [])).toEqual({});
+  });
+
+  it('moves an updated object to the end and trims the oldest', () => {
+    const layouts: Record<string, { hiddenColumns: string[] }> = {};
+    for (let i = 0; i < MAX_VIEWER_LAYOUTS; i++) {
+      layouts[`b/${i}.csv`] = { hiddenColumns: ['x'] };
+    }
+
+    const updated = updateViewerLayouts(updateViewerLayouts(layouts, 'b/0.csv', ['y']), 'b/new.csv', ['z']);
+    const ids = Object.keys(updated);
+
+    expect(ids).toHaveLength(MAX_VIEWER_LAYOUTS);
+    expect(ids).not.toContain('b/1.csv');
+    expect(ids.slice(-2)).toEqual(['b/0.csv', 'b/new.csv']);
+  });
+});
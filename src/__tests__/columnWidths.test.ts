@@ -0,0 +1,46 @@
+import { describe, it, expect } from 'vitest';
+import {
+  MAX_COLUMN_WIDTH,
+  MIN_COLUMN_WIDTH,
+  clampColumnWidth,
+  columnWidthsKey,
+} from '../renderer/utils/columnWidths';
+
+describe('columnWidthsKey', () => {
+  const columns = [
+    { name: 'id', type: 'INT64' },
+    { name: 'payload', type: 'BYTE_ARRAY' },
+  ];
+
+  it('identifies the object and a hash of its schema', () => {
+    expect(columnWidthsKey('bucket', 'data/events.parquet', columns)).toMatch(
+      /^bucket\/data\/events\.parquet#[0-9a-f]{8}$/
+    );
+  });
+
+  it('is stable for the same schema', () => {
+    expect(columnWidthsKey('bucket', 'a.parquet', columns)).toBe(
+      columnWidthsKey('bucket', 'a.parquet', columns.map(column => ({ ...column })))
+    );
+  });
+
+  it('changes when a column is renamed, retyped, added or reordered', () => {
+    const key = columnWidthsKey('bucket', 'a.parquet', columns);
+    expect(columnWidthsKey('bucket', 'a.parquet', [columns[0], { name: 'body', type: 'BYTE_ARRAY' }])).not.toBe(key);
+    expect(columnWidthsKey('bucket', 'a.parquet', [{ name: 'id', type: 'INT32' }, columns[1]])).not.toBe(key);
+    expect(columnWidthsKey('bucket', 'a.parquet', [...columns, { name: 'ts', type: 'INT64' }])).not.toBe(key);
+    expect(columnWidthsKey('bucket', 'a.parquet', [columns[1], columns[0]])).not.toBe(key);
+  });
+});
+
+describe('clampColumnWidth', () => {
+  it('rounds widths within the limits', () => {
+    expect(clampColumnWidth(123.6)).toBe(124);
+  });
+
+  it('keeps widths within the limits', () => {
+    expect(clampColumnWidth(5)).toBe(MIN_COLUMN_WIDTH);
+    expect(clampColumnWidth(-40)).toBe(MIN_COLUMN_WIDTH);
+    expect(clampColumnWidth(10_000)).toBe(MAX_COLUMN_WIDTH);
+  });
+});
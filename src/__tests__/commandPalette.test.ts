@@ -0,0 +1,158 @@
+import { describe, it, expect } from 'vitest';
+import {
+  commandPaletteCandidates,
+  rankCommands,
+  addRecentLocation,
+  bookmarkLabel,
+  MAX_RECENT_LOCATIONS,
+  type PaletteState,
+} from '../renderer/utils/commandPalette';
+
+function createState(overrides: Partial<PaletteState> = {}): PaletteState {
+  return {
+    buckets: ['prod-logs', 'product-catalog', 'staging-assets'],
+    recentLocations: [
+      { profile: 'dev', bucket: 'prod-logs', prefix: '2024/01/' },
+      { profile: 'other', bucket: 'secret', prefix: '' },
+    ],
+    bookmarks: [],
+    currentProfile: 'dev',
+    selectedBucket: 'prod-logs',
+    isBookmarked: false,
+    hasFileSelected: false,
+    isReadOnly: false,
+    ...overrides,
+  };
+}
+
+describe('commandPaletteCandidates', () => {
+  it('lists recent locations, then actions, then buckets', () => {
+    const commands = commandPaletteCandidates(createState());
+
+    expect(commands[0]).toEqual({
+      kind: 'location',
+      id: 'location:prod-logs/2024/01/',
+      label: 's3://prod-logs/2024/01/',
+      bucket: 'prod-logs',
+      prefix: '2024/01/',
+    });
+    expect(commands.map(c => c.kind)).toEqual([
+      'location',
+      ...Array(commands.length - 4).fill('action'),
+      'bucket',
+      'bucket',
+      'bucket',
+    ]);
+  });
+
+  it('only offers recent locations of the current profile', () => {
+    const commands = commandPaletteCandidates(createState());
+
+    expect(commands.some(c => c.kind === 'location' && c.bucket === 'secret')).toBe(false);
+  });
+
+  it('only offers actions that apply to the current view', () => {
+    const actions = (state: PaletteState) =>
+      commandPaletteCandidates(state).flatMap(c => (c.kind === 'action' ? [c.action] : []));
+
+    expect(actions(createState({ selectedBucket: null }))).toEqual(['settings']);
+    expect(actions(createState())).toEqual([
+      'refresh',
+      'upload',
+      'new-file',
+      'new-folder',
+      'copy-path',
+      'open-console',
+      'incomplete-uploads',
+      'add-bookmark',
+      'settings',
+    ]);
+    expect(actions(createState({ hasFileSelected: true }))).toContain('copy-url');
+    expect(actions(createState({ isReadOnly: true }))).toEqual([
+      'refresh',
+      'copy-path',
+      'open-console',
+      'incomplete-uploads',
+      'add-bookmark',
+      'settings',
+    ]);
+    expect(actions(createState({ isBookmarked: true }))).toContain('remove-bookmark');
+    expect(actions(createState({ isBookmarked: true }))).not.toContain('add-bookmark');
+  });
+
+  it('lists bookmarks of the current profile before recent locations', () => {
+    const commands = commandPaletteCandidates(
+      createState({
+        bookmarks: [
+          { label: 'January', profile: 'dev', bucket: 'prod-logs', prefix: '2024/01/' },
+          { label: 'Secret', profile: 'other', bucket: 'secret', prefix: '' },
+        ],
+      })
+    );
+
+    expect(commands[0]).toEqual({
+      kind: 'location',
+      id: 'bookmark:prod-logs/2024/01/',
+      label: 'January (s3://prod-logs/2024/01/)',
+      bucket: 'prod-logs',
+      prefix: '2024/01/',
+    });
+    expect(commands[1].id).toBe('location:prod-logs/2024/01/');
+    expect(commands.some(c => c.label.startsWith('Secret'))).toBe(false);
+  });
+});
+
+describe('bookmarkLabel', () => {
+  it('uses the folder name, or the bucket at its root', () => {
+    expect(bookmarkLabel('prod-logs', '2024/01/')).toBe('01');
+    expect(bookmarkLabel('prod-logs', '')).toBe('prod-logs');
+  });
+});
+
+describe('rankCommands', () => {
+  it('keeps candidate order for an empty query', () => {
+    const commands = commandPaletteCandidates(createState());
+
+    expect(rankCommands(commands, '').map(r => r.command)).toEqual(commands);
+  });
+
+  it('drops non-matching commands and puts the best match first', () => {
+    const ranked = rankCommands(commandPaletteCandidates(createState()), 'prdlog');
+
+    expect(ranked[0].command.label).toBe('prod-logs');
+    expect(ranked[0].positions).toEqual([0, 1, 3, 5, 6, 7]);
+    expect(ranked.map(r => r.command.label)).toContain('product-catalog');
+    expect(ranked.map(r => r.command.label)).not.toContain('staging-assets');
+  });
+
+  it('finds actions by typing part of their name', () => {
+    const ranked = rankCommands(commandPaletteCandidates(createState()), 'upl');
+
+    expect(ranked[0].command).toMatchObject({ kind: 'action', action: 'upload' });
+  });
+
+  it('limits the number of results', () => {
+    const buckets = Array.from({ length: 80 }, (_, i) => `bucket-${i}`);
+
+    expect(rankCommands(commandPaletteCandidates(createState({ buckets })), 'bucket', 10)).toHaveLength(10);
+  });
+});
+
+describe('addRecentLocation', () => {
+  it('moves a revisited location to the front without duplicating it', () => {
+    const a = { profile: 'dev', bucket: 'a', prefix: '' };
+    const b = { profile: 'dev', bucket: 'b', prefix: 'x/' };
+
+    expect(addRecentLocation([a, b], { ...b })).toEqual([b, a]);
+  });
+
+  it('keeps at most MAX_RECENT_LOCATIONS entries', () => {
+    let recent: ReturnType<typeof addRecentLocation> = [];
+    for (let i = 0; i < MAX_RECENT_LOCATIONS + 5; i++) {
+      recent = addRecentLocation(recent, { profile: 'dev', bucket: 'b', prefix: `${i}/` });
+    }
+
+    expect(recent).toHaveLength(MAX_RECENT_LOCATIONS);
+    expect(recent[0].prefix).toBe(`${MAX_RECENT_LOCATIONS + 4}/`);
+  });
+});
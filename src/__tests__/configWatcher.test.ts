@@ -0,0 +1,61 @@
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import fs from 'fs';
+import path from 'path';
+import { watchAwsConfigFiles } from '../main/services/configWatcher';
+
+describe('watchAwsConfigFiles', () => {
+  let tmpDir: string;
+  let stop: () => void = () => {};
+
+  beforeEach(() => {
+    tmpDir = '/tmp/aws-watch-' + Date.now();
+    fs.mkdirSync(path.join(tmpDir, 'sso', 'cache'), { recursive: true });
+  });
+
+  afterEach(() => {
+    stop();
+    fs.rmSync(tmpDir, { recursive: true, force: true });
+  });
+
+  const waitFor = (condition: () => boolean) =>
+    vi.waitFor(() => expect(condition()).toBe(true), { timeout: 2000, interval: 20 });
+
+  it('reloads once after a burst of writes to a watched file', async () => {
+    const onChange = vi.fn();
+    const configPath = path.join(tmpDir, 'config');
+    stop = watchAwsConfigFiles(onChange, { files: [configPath], directories: [], debounceMs: 100 });
+
+    fs.writeFileSync(configPath, '[default]\n');
+    fs.appendFileSync(configPath, 'region = eu-west-1\n');
+    fs.appendFileSync(configPath, 'output = json\n');
+
+    await waitFor(() => onChange.mock.calls.length > 0);
+    await new Promise(resolve => setTimeout(resolve, 200));
+    expect(onChange).toHaveBeenCalledTimes(1);
+  });
+
+  it('ignores other files in the same directory', async () => {
+    const onChange = vi.fn();
+    stop = watchAwsConfigFiles(onChange, { files: [path.join(tmpDir, 'config')], directories: [], debounceMs: 20 });
+
+    fs.writeFileSync(path.join(tmpDir, 'cli-history.log'), 'aws s3 ls\n');
+
+    await new Promise(resolve => setTimeout(resolve, 150));
+    expect(onChange).not.toHaveBeenCalled();
+  });
+
+  it('reloads on any change in a watched directory, such as the SSO cache', async () => {
+    const onChange = vi.fn();
+    stop = watchAwsConfigFiles(onChange, { files: [], directories: [path.join(tmpDir, 'sso', 'cache')], debounceMs: 20 });
+
+    fs.writeFileSync(path.join(tmpDir, 'sso', 'cache', 'token.json'), '{}');
+
+    await waitFor(() => onChange.mock.calls.length === 1);
+  });
+
+  it('skips directories that do not exist', () => {
+    expect(() => {
+      stop = watchAwsConfigFiles(vi.fn(), { files: [path.join(tmpDir, 'missing', 'config')], directories: [] });
+    }).not.toThrow();
+  });
+});
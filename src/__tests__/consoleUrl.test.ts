@@ -0,0 +1,49 @@
+import { describe, it, expect } from 'vitest';
+import { consoleUrl, getConsoleHost } from '../renderer/utils/consoleUrl';
+
+describe('getConsoleHost', () => {
+  it('uses the S3 console host for standard regions', () => {
+    expect(getConsoleHost('us-east-1')).toBe('https://s3.console.aws.amazon.com');
+    expect(getConsoleHost('eu-west-1')).toBe('https://s3.console.aws.amazon.com');
+  });
+
+  it('uses the China console host for cn regions', () => {
+    expect(getConsoleHost('cn-north-1')).toBe('https://console.amazonaws.cn');
+  });
+
+  it('uses the GovCloud console host for us-gov regions', () => {
+    expect(getConsoleHost('us-gov-west-1')).toBe('https://console.amazonaws-us-gov.com');
+  });
+});
+
+describe('consoleUrl', () => {
+  it('links to the bucket root when prefix is empty', () => {
+    expect(consoleUrl('us-east-1', 'my-bucket', '')).toBe(
+      'https://s3.console.aws.amazon.com/s3/buckets/my-bucket?region=us-east-1'
+    );
+  });
+
+  it('encodes the prefix', () => {
+    expect(consoleUrl('eu-west-1', 'my-bucket', 'data/2024 reports/')).toBe(
+      'https://s3.console.aws.amazon.com/s3/buckets/my-bucket?region=eu-west-1&prefix=data%2F2024%20reports%2F&showversions=false'
+    );
+  });
+
+  it('encodes reserved characters in the prefix', () => {
+    const url = consoleUrl('ap-southeast-2', 'my-bucket', 'a&b=c/');
+    expect(url).toContain('&prefix=a%26b%3Dc%2F');
+    expect(url).toContain('region=ap-southeast-2');
+  });
+
+  it('builds China partition URLs', () => {
+    expect(consoleUrl('cn-northwest-1', 'cn-bucket', 'logs/')).toBe(
+      'https://console.amazonaws.cn/s3/buckets/cn-bucket?region=cn-northwest-1&prefix=logs%2F&showversions=false'
+    );
+  });
+
+  it('builds GovCloud partition URLs', () => {
+    expect(consoleUrl('us-gov-east-1', 'gov-bucket', '')).toBe(
+      'https://console.amazonaws-us-gov.com/s3/buckets/gov-bucket?region=us-gov-east-1'
+    );
+  });
+});
@@ -0,0 +1,46 @@
+import { describe, it, expect, vi } from 'vitest';
+import { computeContentMd5, createContentMd5Middleware } from '../main/services/contentMd5';
+
+const deleteBody =
+  '<?xml version="1.0" encoding="UTF-8"?><Delete xmlns="http://s3.amazonaws.com/doc/2006-03-01/">' +
+  '<Object><Key>file1.txt</Key></Object><Quiet>true</Quiet></Delete>';
+
+describe('contentMd5', () => {
+  describe('computeContentMd5', () => {
+    it('returns the base64 MD5 digest', () => {
+      // md5("") = d41d8cd98f00b204e9800998ecf8427e
+      expect(computeContentMd5('')).toBe('1B2M2Y8AsgTpgAmY7PhCfg==');
+      expect(computeContentMd5(new Uint8Array())).toBe('1B2M2Y8AsgTpgAmY7PhCfg==');
+    });
+  });
+
+  describe('createContentMd5Middleware', () => {
+    it('adds a Content-MD5 header for the serialized body', async () => {
+      const next = vi.fn().mockResolvedValue({ output: {} });
+      const request = { headers: { 'content-type': 'application/xml' }, body: deleteBody };
+
+      await createContentMd5Middleware()(next)({ request });
+
+      expect(request.headers).toHaveProperty('content-md5', computeContentMd5(deleteBody));
+      expect(next).toHaveBeenCalledWith({ request });
+    });
+
+    it('keeps a Content-MD5 header that is already set', async () => {
+      const next = vi.fn().mockResolvedValue({ output: {} });
+      const request = { headers: { 'Content-MD5': 'existing' } as Record<string, string>, body: deleteBody };
+
+      await createContentMd5Middleware()(next)({ request });
+
+      expect(request.headers).toEqual({ 'Content-MD5': 'existing' });
+    });
+
+    it('leaves streaming bodies alone', async () => {
+      const next = vi.fn().mockResolvedValue({ output: {} });
+      const request = { headers: {} as Record<string, string>, body: { pipe: vi.fn() } };
+
+      await createContentMd5Middleware()(next)({ request });
+
+      expect(request.headers).toEqual({});
+    });
+  });
+});
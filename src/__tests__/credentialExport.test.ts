@@ -0,0 +1,111 @@
+import { describe, it, expect, vi, beforeEach, type Mock } from 'vitest';
+
+const mockResolveCredentials = vi.fn();
+
+vi.mock('@aws-sdk/credential-providers', () => ({
+  fromIni: vi.fn(() => mockResolveCredentials),
+}));
+
+vi.mock('../main/services/s3Service', () => ({
+  getCredentialProviderOptions: vi.fn(() => ({})),
+}));
+
+vi.mock('../main/services/awsCredentials', () => ({
+  ANONYMOUS_PROFILE_NAME: '(anonymous)',
+  getProfile: vi.fn(),
+}));
+
+vi.mock('../main/services/roleChain', () => ({
+  createRoleChainProvider: vi.fn(() => mockResolveCredentials),
+}));
+
+import { fromIni } from '@aws-sdk/credential-providers';
+import { exportCredentialsAsEnv, formatEnvExports } from '../main/services/credentialExport';
+import { getCredentialProviderOptions } from '../main/services/s3Service';
+import { getProfile } from '../main/services/awsCredentials';
+import { createRoleChainProvider } from '../main/services/roleChain';
+
+describe('credentialExport', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    mockResolveCredentials.mockReset();
+  });
+
+  describe('exportCredentialsAsEnv', () => {
+    it('returns the keys of long-term credentials', async () => {
+      mockResolveCredentials.mockResolvedValue({ accessKeyId: 'AKIAEXAMPLE', secretAccessKey: 'secret' });
+
+      const result = await exportCredentialsAsEnv('default');
+
+      expect(fromIni).toHaveBeenCalledWith({ profile: 'default' });
+      expect(result).toEqual({
+        success: true,
+        variables: [
+          ['AWS_ACCESS_KEY_ID', 'AKIAEXAMPLE'],
+          ['AWS_SECRET_ACCESS_KEY', 'secret'],
+        ],
+        expiration: undefined,
+      });
+    });
+
+    it('includes the session token and expiry of temporary credentials', async () => {
+      const expiration = new Date('2024-06-01T13:00:00Z');
+      const mfaCodeProvider = vi.fn();
+      (getCredentialProviderOptions as Mock).mockReturnValue({ mfaCodeProvider });
+      mockResolveCredentials.mockResolvedValue({
+        accessKeyId: 'ASIAEXAMPLE',
+        secretAccessKey: 'secret',
+        sessionToken: 'token',
+        expiration,
+      });
+
+      const result = await exportCredentialsAsEnv('admin');
+
+      expect(fromIni).toHaveBeenCalledWith({ profile: 'admin', mfaCodeProvider });
+      expect(result.variables).toContainEqual(['AWS_SESSION_TOKEN', 'token']);
+      expect(result.expiration).toBe(expiration);
+    });
+
+    it('reports resolution failures', async () => {
+      mockResolveCredentials.mockRejectedValue(new Error('The SSO session has expired'));
+
+      expect(await exportCredentialsAsEnv('sso-dev')).toEqual({
+        success: false,
+        error: "Could not resolve credentials for 'sso-dev': The SSO session has expired",
+      });
+    });
+
+    it('assumes the role chain of role profiles', async () => {
+      (getProfile as Mock).mockReturnValueOnce({ name: 'admin', profileType: 'role', hasCredentials: true });
+      (getCredentialProviderOptions as Mock).mockReturnValue({});
+      mockResolveCredentials.mockResolvedValue({ accessKeyId: 'ASIAEXAMPLE', secretAccessKey: 'secret', sessionToken: 'token' });
+
+      const result = await exportCredentialsAsEnv('admin');
+
+      expect(createRoleChainProvider).toHaveBeenCalledWith('admin', {});
+      expect(fromIni).not.toHaveBeenCalled();
+      expect(result.variables).toContainEqual(['AWS_SESSION_TOKEN', 'token']);
+    });
+
+    it('refuses the anonymous profile', async () => {
+      const result = await exportCredentialsAsEnv('(anonymous)');
+
+      expect(result.success).toBe(false);
+      expect(fromIni).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('formatEnvExports', () => {
+    it('formats quoted export lines', () => {
+      expect(formatEnvExports([['AWS_ACCESS_KEY_ID', 'AKIAEXAMPLE'], ['AWS_SECRET_ACCESS_KEY', "it's/secret"]])).toBe(
+        "export AWS_ACCESS_KEY_ID='AKIAEXAMPLE'\nexport AWS_SECRET_ACCESS_KEY='it'\\''s/secret'\n"
+      );
+    });
+
+    it('notes when temporary credentials expire', () => {
+      const text = formatEnvExports([['AWS_SESSION_TOKEN', 'token']], new Date('2024-06-01T13:00:00Z'));
+
+      expect(text.split('\n')[0]).toBe('# Temporary credentials, expire at 2024-06-01T13:00:00.000Z');
+    });
+  });
+});
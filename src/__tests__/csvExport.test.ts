@@ -0,0 +1,102 @@
+import { describe, it, expect } from 'vitest';
+import { sanitizeCsvCell, escapeCsvField, toCsv } from '../renderer/utils/csvExport';
+
+describe('sanitizeCsvCell', () => {
+  it.each(['=', '+', '-', '@'])('prefixes cells starting with %s with a single quote', (char) => {
+    expect(sanitizeCsvCell(`${char}SUM(A1:A2)`, 'apostrophe')).toBe(`'${char}SUM(A1:A2)`);
+  });
+
+  it.each(['=', '+', '-', '@'])('prefixes cells starting with %s with a space', (char) => {
+    expect(sanitizeCsvCell(`${char}cmd`, 'space')).toBe(` ${char}cmd`);
+  });
+
+  it('neutralizes leading tab and carriage return', () => {
+    expect(sanitizeCsvCell('\t=1+1', 'apostrophe')).toBe("'\t=1+1");
+    expect(sanitizeCsvCell('\r=1+1', 'apostrophe')).toBe("'\r=1+1");
+  });
+
+  it('leaves normal values unchanged', () => {
+    expect(sanitizeCsvCell('hello', 'apostrophe')).toBe('hello');
+    expect(sanitizeCsvCell('42', 'apostrophe')).toBe('42');
+    expect(sanitizeCsvCell('a=b', 'apostrophe')).toBe('a=b');
+    expect(sanitizeCsvCell('user@example.com', 'space')).toBe('user@example.com');
+    expect(sanitizeCsvCell('', 'apostrophe')).toBe('');
+  });
+
+  it('leaves signed numbers unchanged', () => {
+    expect(sanitizeCsvCell('-5', 'apostrophe')).toBe('-5');
+    expect(sanitizeCsvCell('-0.25', 'space')).toBe('-0.25');
+    expect(sanitizeCsvCell('+1.2e3', 'apostrophe')).toBe('+1.2e3');
+    expect(sanitizeCsvCell('-.5E-2', 'apostrophe')).toBe('-.5E-2');
+  });
+
+  it('still neutralizes formulas that start like a number', () => {
+    expect(sanitizeCsvCell('-1+1', 'apostrophe')).toBe("'-1+1");
+    expect(sanitizeCsvCell('+1,2', 'apostrophe')).toBe("'+1,2");
+    expect(sanitizeCsvCell('-', 'apostrophe')).toBe("'-");
+  });
+
+  it('leaves dangerous values unchanged when mode is none', () => {
+    expect(sanitizeCsvCell('=1+1', 'none')).toBe('=1+1');
+  });
+});
+
+describe('escapeCsvField', () => {
+  it('quotes fields containing the delimiter, quotes or line breaks', () => {
+    expect(escapeCsvField('a,b')).toBe('"a,b"');
+    expect(escapeCsvField('say "hi"')).toBe('"say ""hi"""');
+    expect(escapeCsvField('line1\nline2')).toBe('"line1\nline2"');
+  });
+
+  it('respects a custom delimiter', () => {
+    expect(escapeCsvField('a,b', '\t')).toBe('a,b');
+    expect(escapeCsvField('a\tb', '\t')).toBe('"a\tb"');
+  });
+
+  it('quotes every field with the all style', () => {
+    expect(escapeCsvField('plain', ',', 'all')).toBe('"plain"');
+    expect(escapeCsvField('', ',', 'all')).toBe('""');
+  });
+});
+
+describe('toCsv', () => {
+  it('writes header and rows with LF line endings', () => {
+    expect(toCsv(['a', 'b'], [['1', '2'], ['3', '4']])).toBe('a,b\n1,2\n3,4\n');
+  });
+
+  it('neutralizes formula cells by default', () => {
+    expect(toCsv(['value'], [['=cmd|calc']])).toBe("value\n'=cmd|calc\n");
+  });
+
+  it('applies the requested safety mode and delimiter', () => {
+    expect(toCsv(['x'], [['+1']], { safetyMode: 'none', delimiter: ';' })).toBe('x\n+1\n');
+  });
+
+  describe('format options', () => {
+    const headers = ['name', 'city'];
+    const rows = [
+      ['Alice', 'Paris, FR'],
+      ['Bob', 'Berlin; DE'],
+    ];
+
+    it('writes the same rows with comma and semicolon delimiters', () => {
+      expect(toCsv(headers, rows, { delimiter: ',' })).toBe('name,city\nAlice,"Paris, FR"\nBob,Berlin; DE\n');
+      expect(toCsv(headers, rows, { delimiter: ';' })).toBe('name;city\nAlice;Paris, FR\nBob;"Berlin; DE"\n');
+    });
+
+    it('writes the same rows with LF and CRLF line endings', () => {
+      expect(toCsv(headers, rows, { lineTerminator: '\n' })).toBe('name,city\nAlice,"Paris, FR"\nBob,Berlin; DE\n');
+      expect(toCsv(headers, rows, { lineTerminator: '\r\n' })).toBe(
+        'name,city\r\nAlice,"Paris, FR"\r\nBob,Berlin; DE\r\n'
+      );
+    });
+
+    it('quotes every field when asked', () => {
+      expect(toCsv(['a'], [['1']], { quoteStyle: 'all', lineTerminator: '\n' })).toBe('"a"\n"1"\n');
+    });
+
+    it('still quotes line breaks inside fields with LF endings', () => {
+      expect(toCsv(['note'], [['line1\nline2']], { lineTerminator: '\n' })).toBe('note\n"line1\nline2"\n');
+    });
+  });
+});
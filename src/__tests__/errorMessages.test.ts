@@ -30,6 +30,26 @@ describe('parseError', () => {
     expect(result.isRetryable).toBe(false);
   });
 
+  it('handles RequestTimeTooSkewed errors as clock skew', () => {
+    const result = parseError(
+      new Error('RequestTimeTooSkewed: The difference between the request time and the current time is too large.')
+    );
+
+    expect(result.title).toBe('Clock Skew');
+    expect(result.message).toBe('Your system clock appears to be off — S3 rejected the request signature.');
+    expect(result.suggestion).toContain('Sync your system clock');
+    expect(result.isRetryable).toBe(false);
+  });
+
+  it('includes the measured skew in the suggestion', () => {
+    const result = parseError(
+      new Error('RequestTimeTooSkewed: Too large. (clock skew: system clock is 42 minutes behind S3)')
+    );
+
+    expect(result.title).toBe('Clock Skew');
+    expect(result.suggestion).toBe('The system clock is 42 minutes behind S3. Sync your system clock and try again.');
+  });
+
   it('handles Invalid Access Key errors', () => {
     const result = parseError(new Error('InvalidAccessKeyId: The access key ID does not exist'));
 
@@ -0,0 +1,23 @@
+import { describe, it, expect } from 'vitest';
+import { getExplorerState } from '../renderer/utils/explorerState';
+
+describe('getExplorerState', () => {
+  it('asks for a profile when none is selected, whatever the outcome', () => {
+    expect(getExplorerState(null, { status: 'pending' })).toBe('no-profile');
+    expect(getExplorerState(null, { status: 'failed' })).toBe('no-profile');
+    expect(getExplorerState(null, { status: 'loaded', bucketCount: 3 })).toBe('no-profile');
+  });
+
+  it('is loading while the bucket list is pending', () => {
+    expect(getExplorerState('dev', { status: 'pending' })).toBe('loading');
+  });
+
+  it('reports an error when listing failed', () => {
+    expect(getExplorerState('dev', { status: 'failed' })).toBe('error');
+  });
+
+  it('tells an empty bucket list apart from a populated one', () => {
+    expect(getExplorerState('dev', { status: 'loaded', bucketCount: 0 })).toBe('empty');
+    expect(getExplorerState('dev', { status: 'loaded', bucketCount: 1 })).toBe('ready');
+  });
+});
@@ -0,0 +1,72 @@
+import { describe, it, expect } from 'vitest';
+import { getBaseExtension, getExtension, guessMimeType } from '../shared/fileTypes';
+
+describe('getExtension', () => {
+  it('returns the lowercase extension of the file name', () => {
+    expect(getExtension('data/part-0000.parquet')).toBe('parquet');
+    expect(getExtension('photos/IMG_0001.JPG')).toBe('jpg');
+  });
+
+  it('returns only the last extension of names with multiple dots', () => {
+    expect(getExtension('logs/app.2024-01-01.log.gz')).toBe('gz');
+    expect(getExtension('archive.tar.gz')).toBe('gz');
+  });
+
+  it('ignores dots in folder names', () => {
+    expect(getExtension('v1.2/README')).toBeNull();
+    expect(getExtension('site.example.com/index.html')).toBe('html');
+  });
+
+  it('returns null for names without an extension', () => {
+    expect(getExtension('Makefile')).toBeNull();
+    expect(getExtension('data/no-extension')).toBeNull();
+    expect(getExtension('trailing-dot.')).toBeNull();
+    expect(getExtension('config/.env')).toBeNull();
+  });
+
+  it('returns null for folder keys with a trailing slash', () => {
+    expect(getExtension('reports.2024/')).toBeNull();
+    expect(getExtension('data/file.csv/')).toBeNull();
+  });
+});
+
+describe('getBaseExtension', () => {
+  it('looks past compression extensions', () => {
+    expect(getBaseExtension('logs/events.JSON.gz')).toBe('json');
+    expect(getBaseExtension('data.csv.zst')).toBe('csv');
+    expect(getBaseExtension('part-0000.jsonl.snappy')).toBe('jsonl');
+  });
+
+  it('returns the extension of uncompressed files', () => {
+    expect(getBaseExtension('config.yml')).toBe('yml');
+    expect(getBaseExtension('archive.tar')).toBe('tar');
+  });
+
+  it('returns null when the name has no extension before the compression one', () => {
+    expect(getBaseExtension('dump.gz')).toBeNull();
+    expect(getBaseExtension('v1.2/dump.gz')).toBeNull();
+    expect(getBaseExtension('Makefile')).toBeNull();
+  });
+});
+
+describe('guessMimeType', () => {
+  it('maps known extensions to their MIME type', () => {
+    expect(guessMimeType('report.csv')).toBe('text/csv');
+    expect(guessMimeType('config.JSON')).toBe('application/json');
+    expect(guessMimeType('data/part-0000.parquet')).toBe('application/vnd.apache.parquet');
+    expect(guessMimeType('icon.svg')).toBe('image/svg+xml');
+    expect(guessMimeType('site/index.html')).toBe('text/html');
+    expect(guessMimeType('docs/manual.pdf')).toBe('application/pdf');
+    expect(guessMimeType('events.jsonl')).toBe('application/x-ndjson');
+  });
+
+  it('uses the last extension of compressed files', () => {
+    expect(guessMimeType('events.json.gz')).toBe('application/gzip');
+  });
+
+  it('falls back to application/octet-stream', () => {
+    expect(guessMimeType('blob.bin')).toBe('application/octet-stream');
+    expect(guessMimeType('Makefile')).toBe('application/octet-stream');
+    expect(guessMimeType('folder/')).toBe('application/octet-stream');
+  });
+});
@@ -0,0 +1,28 @@
+import { describe, it, expect } from 'vitest';
+import { fuzzyMatch } from '../renderer/utils/fuzzyMatch';
+
+describe('fuzzyMatch', () => {
+  it('matches characters in order, ignoring case', () => {
+    expect(fuzzyMatch('Prod-Logs', 'plg')?.positions).toEqual([0, 5, 7]);
+    expect(fuzzyMatch('prod-logs', 'gol')).toBeNull();
+  });
+
+  it('matches everything with an empty query', () => {
+    expect(fuzzyMatch('anything', '  ')).toEqual({ score: 0, positions: [] });
+  });
+
+  it('ignores spaces in the query', () => {
+    expect(fuzzyMatch('new-folder', 'new f')?.positions).toEqual([0, 1, 2, 4]);
+  });
+
+  it('ranks consecutive and word-start matches higher', () => {
+    const prodLogs = fuzzyMatch('prod-logs', 'prdlog')!;
+    const productCatalog = fuzzyMatch('product-catalog', 'prdlog')!;
+
+    expect(prodLogs.score).toBeGreaterThan(productCatalog.score);
+  });
+
+  it('prefers the shorter text for an otherwise equal match', () => {
+    expect(fuzzyMatch('logs', 'logs')!.score).toBeGreaterThan(fuzzyMatch('logs-archive', 'logs')!.score);
+  });
+});
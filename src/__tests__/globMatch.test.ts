@@ -0,0 +1,45 @@
+import { describe, it, expect } from 'vitest';
+import { isGlobPattern, matchesGlob } from '../renderer/utils/globMatch';
+
+describe('matchesGlob', () => {
+  it('matches "*" within a single path segment', () => {
+    expect(matchesGlob('part-0000.parquet', '*.parquet')).toBe(true);
+    expect(matchesGlob('2024/part-0000.parquet', '*.parquet')).toBe(false);
+    expect(matchesGlob('2024/part-0000.parquet', '*/*.parquet')).toBe(true);
+  });
+
+  it('matches "?" as exactly one character other than a slash', () => {
+    expect(matchesGlob('log-1.txt', 'log-?.txt')).toBe(true);
+    expect(matchesGlob('log-10.txt', 'log-?.txt')).toBe(false);
+    expect(matchesGlob('a/b', 'a?b')).toBe(false);
+  });
+
+  it('matches "**" across folders, including none for a "**/" segment', () => {
+    expect(matchesGlob('a/b/c/data.csv', '**/*.csv')).toBe(true);
+    expect(matchesGlob('data.csv', '**/*.csv')).toBe(true);
+    expect(matchesGlob('a/b/c/data.csv', 'a/**/data.csv')).toBe(true);
+    expect(matchesGlob('a/data.csv', 'a/**/data.csv')).toBe(true);
+    expect(matchesGlob('a/b/data.csv', 'a/**')).toBe(true);
+  });
+
+  it('matches the whole key, treating other characters literally', () => {
+    expect(matchesGlob('report.csv.bak', '*.csv')).toBe(false);
+    expect(matchesGlob('reportXcsv', 'report.csv')).toBe(false);
+    expect(matchesGlob('a+b (1).txt', 'a+b (?).txt')).toBe(true);
+    expect(matchesGlob('[draft]$.md', '[draft]$.*')).toBe(true);
+  });
+
+  it('ignores case unless asked not to', () => {
+    expect(matchesGlob('DATA.CSV', '*.csv')).toBe(true);
+    expect(matchesGlob('DATA.CSV', '*.csv', { caseSensitive: true })).toBe(false);
+    expect(matchesGlob('data.csv', '*.csv', { caseSensitive: true })).toBe(true);
+  });
+});
+
+describe('isGlobPattern', () => {
+  it('detects wildcards', () => {
+    expect(isGlobPattern('*.parquet')).toBe(true);
+    expect(isGlobPattern('log-?')).toBe(true);
+    expect(isGlobPattern('report')).toBe(false);
+  });
+});
@@ -1,9 +1,23 @@
-import { isGzipFile, getBaseExtension, compressGzip, decompressGzip, tryDecompressGzip } from '../main/services/gzipUtils';
+import {
+  isGzipFile,
+  getBaseExtension,
+  compressGzip,
+  decompressGzip,
+  tryDecompressGzip,
+  getCompressionFromKey,
+  detectCompression,
+  compressContent,
+  decompressContent,
+} from '../main/services/gzipUtils';
+import { compressSnappyFramed } from '../main/services/snappy';
 import * as zlib from 'zlib';
 import { promisify } from 'util';
 
 const gzipAsync = promisify(zlib.gzip);
 
+// zstd is built into zlib from Node 22.15 (and the Electron runtime)
+const hasZstd = typeof zlib.zstdCompressSync === 'function';
+
 describe('gzipUtils', () => {
   describe('isGzipFile', () => {
     it('returns true for .gz files', () => {
@@ -48,6 +62,38 @@ describe('gzipUtils', () => {
       expect(getBaseExtension('file.gz')).toBe('file');
       expect(getBaseExtension('')).toBe('');
     });
+
+    it('returns extension before .zst and .snappy', () => {
+      expect(getBaseExtension('events.json.zst')).toBe('json');
+      expect(getBaseExtension('part-0000.CSV.SNAPPY')).toBe('csv');
+    });
+  });
+
+  describe('getCompressionFromKey', () => {
+    it('maps compression extensions to codecs', () => {
+      expect(getCompressionFromKey('a.json.gz')).toBe('gzip');
+      expect(getCompressionFromKey('a.json.ZST')).toBe('zstd');
+      expect(getCompressionFromKey('a.csv.snappy')).toBe('snappy');
+    });
+
+    it('returns null for other files', () => {
+      expect(getCompressionFromKey('a.json')).toBeNull();
+      expect(getCompressionFromKey('a.zstd')).toBeNull();
+      expect(getCompressionFromKey('snappy')).toBeNull();
+    });
+  });
+
+  describe('detectCompression', () => {
+    it('recognizes each codec by its magic bytes', async () => {
+      expect(detectCompression(await gzipAsync(Buffer.from('x')))).toBe('gzip');
+      expect(detectCompression(Buffer.from([0x28, 0xb5, 0x2f, 0xfd, 0x00]))).toBe('zstd');
+      expect(detectCompression(compressSnappyFramed(Buffer.from('x')))).toBe('snappy');
+    });
+
+    it('returns null for uncompressed data', () => {
+      expect(detectCompression(Buffer.from('{"a": 1}'))).toBeNull();
+      expect(detectCompression(Buffer.from([0x1f]))).toBeNull();
+    });
   });
 
   describe('compressGzip', () => {
@@ -203,4 +249,65 @@ enabled: true
       expect(decompressed).toBe(yamlContent);
     });
   });
+
+  describe('decompressContent', () => {
+    const json = JSON.stringify({ events: [{ id: 1, type: 'click' }, { id: 2, type: 'view' }] });
+
+    it('round-trips JSON through gzip', async () => {
+      const compressed = await compressContent('events.json.gz', json);
+      expect(await decompressContent('events.json.gz', compressed as Buffer)).toEqual({
+        content: json,
+        compression: 'gzip',
+      });
+    });
+
+    it.skipIf(!hasZstd)('round-trips JSON through zstd', async () => {
+      const compressed = await compressContent('events.json.zst', json);
+      expect(detectCompression(compressed as Buffer)).toBe('zstd');
+      expect(await decompressContent('events.json.zst', compressed as Buffer)).toEqual({
+        content: json,
+        compression: 'zstd',
+      });
+    });
+
+    it('round-trips JSON through framed snappy', async () => {
+      const compressed = await compressContent('events.json.snappy', json);
+      expect(await decompressContent('events.json.snappy', compressed as Buffer)).toEqual({
+        content: json,
+        compression: 'snappy',
+      });
+    });
+
+    it('leaves files without a compression extension alone', async () => {
+      const gzipped = await gzipAsync(Buffer.from(json));
+      expect(await decompressContent('events.json', Buffer.from(json))).toEqual({ content: json });
+      expect(await compressContent('events.json', json)).toBe(json);
+      expect((await decompressContent('events.bin', gzipped)).compression).toBeUndefined();
+    });
+
+    it('trusts the magic bytes over a mislabelled extension', async () => {
+      const gzipped = await gzipAsync(Buffer.from(json));
+      expect(await decompressContent('events.json.snappy', gzipped)).toEqual({
+        content: json,
+        compression: 'gzip',
+      });
+    });
+
+    it('reports the codec when decompression fails', async () => {
+      await expect(decompressContent('events.json.gz', Buffer.from('not gzip'))).rejects.toThrow(
+        /^Gzip decompression failed/
+      );
+      await expect(decompressContent('events.json.snappy', Buffer.from('not snappy'))).rejects.toThrow(
+        /^Snappy decompression failed/
+      );
+    });
+
+    it('refuses content that decompresses past the size limit', async () => {
+      const bomb = 'x'.repeat(100000);
+      for (const key of ['bomb.txt.gz', 'bomb.txt.snappy', ...(hasZstd ? ['bomb.txt.zst'] : [])]) {
+        const compressed = (await compressContent(key, bomb)) as Buffer;
+        await expect(decompressContent(key, compressed, 1000)).rejects.toThrow(/larger than/);
+      }
+    });
+  });
 });
@@ -0,0 +1,165 @@
+import { describe, it, expect, beforeEach, afterEach } from 'vitest';
+import * as fs from 'fs';
+import * as path from 'path';
+import {
+  resolveConflict,
+  fileExists,
+  buildExternalOpenPath,
+  getExecutableExtension,
+  cleanupExternalOpenDir,
+  getExternalOpenDir,
+  getRevealAction,
+} from '../main/services/localFiles';
+
+describe('localFiles', () => {
+  let tmpDir: string;
+
+  beforeEach(() => {
+    tmpDir = '/tmp/local-files-test-' + Date.now();
+    fs.mkdirSync(tmpDir, { recursive: true });
+  });
+
+  afterEach(() => {
+    fs.rmSync(tmpDir, { recursive: true, force: true });
+  });
+
+  describe('fileExists', () => {
+    it('reports whether a file exists', async () => {
+      fs.writeFileSync(path.join(tmpDir, 'a.txt'), 'a');
+
+      expect(await fileExists(path.join(tmpDir, 'a.txt'))).toBe(true);
+      expect(await fileExists(path.join(tmpDir, 'b.txt'))).toBe(false);
+    });
+  });
+
+  describe('resolveConflict', () => {
+    it('returns the path unchanged when nothing exists there, for every policy', async () => {
+      const target = path.join(tmpDir, 'report.csv');
+
+      expect(await resolveConflict(target, 'overwrite')).toBe(target);
+      expect(await resolveConflict(target, 'skip')).toBe(target);
+      expect(await resolveConflict(target, 'rename')).toBe(target);
+    });
+
+    it('keeps the existing path for overwrite', async () => {
+      const target = path.join(tmpDir, 'report.csv');
+      fs.writeFileSync(target, 'old');
+
+      expect(await resolveConflict(target, 'overwrite')).toBe(target);
+    });
+
+    it('returns null for skip', async () => {
+      const target = path.join(tmpDir, 'report.csv');
+      fs.writeFileSync(target, 'old');
+
+      expect(await resolveConflict(target, 'skip')).toBeNull();
+    });
+
+    it('appends a counter for rename', async () => {
+      const target = path.join(tmpDir, 'report.csv');
+      fs.writeFileSync(target, 'old');
+
+      expect(await resolveConflict(target, 'rename')).toBe(path.join(tmpDir, 'report (1).csv'));
+    });
+
+    it('increments the rename counter past existing duplicates', async () => {
+      const target = path.join(tmpDir, 'report.csv');
+      fs.writeFileSync(target, 'old');
+      fs.writeFileSync(path.join(tmpDir, 'report (1).csv'), 'old');
+      fs.writeFileSync(path.join(tmpDir, 'report (2).csv'), 'old');
+
+      expect(await resolveConflict(target, 'rename')).toBe(path.join(tmpDir, 'report (3).csv'));
+    });
+
+    it('never hands out a path reserved by another write, whatever the policy', async () => {
+      const target = path.join(tmpDir, 'report.csv');
+      const reserved = new Set([target, path.join(tmpDir, 'report (1).csv')]);
+
+      expect(await resolveConflict(target, 'overwrite', reserved)).toBe(path.join(tmpDir, 'report (2).csv'));
+      expect(await resolveConflict(target, 'skip', reserved)).toBe(path.join(tmpDir, 'report (2).csv'));
+    });
+
+    it('renames files without an extension', async () => {
+      const target = path.join(tmpDir, 'Makefile');
+      fs.writeFileSync(target, 'old');
+
+      expect(await resolveConflict(target, 'rename')).toBe(path.join(tmpDir, 'Makefile (1)'));
+    });
+  });
+
+  describe('buildExternalOpenPath', () => {
+    it('keeps the file name and extension inside a unique subfolder', () => {
+      const base = path.join(tmpDir, 'open');
+
+      expect(buildExternalOpenPath('report.xlsx', base, 'abc123')).toBe(path.join(base, 'abc123', 'report.xlsx'));
+      expect(path.extname(buildExternalOpenPath('archive.tar.gz', base))).toBe('.gz');
+      expect(path.basename(buildExternalOpenPath('archive.tar.gz', base))).toBe('archive.tar.gz');
+    });
+
+    it('gives every download its own folder', () => {
+      const first = buildExternalOpenPath('report.xlsx');
+      const second = buildExternalOpenPath('report.xlsx');
+
+      expect(first).not.toBe(second);
+      expect(path.dirname(path.dirname(first))).toBe(getExternalOpenDir());
+    });
+
+    it('replaces characters that are not valid in local file names', () => {
+      expect(path.basename(buildExternalOpenPath('q1: "draft"?.docx', tmpDir, 'id'))).toBe('q1_ _draft__.docx');
+    });
+  });
+
+  describe('getExecutableExtension', () => {
+    it('blocks programs and scripts', () => {
+      expect(getExecutableExtension('setup.exe')).toBe('exe');
+      expect(getExecutableExtension('install.BAT')).toBe('bat');
+      expect(getExecutableExtension('run.cmd')).toBe('cmd');
+      expect(getExecutableExtension('deploy.sh')).toBe('sh');
+      expect(getExecutableExtension('Report.pdf.lnk')).toBe('lnk');
+      expect(getExecutableExtension('Tool.app')).toBe('app');
+    });
+
+    it('sees through trailing dots and spaces that Windows ignores', () => {
+      expect(getExecutableExtension('invoice.exe.')).toBe('exe');
+      expect(getExecutableExtension('invoice.exe . ')).toBe('exe');
+    });
+
+    it('allows documents and names without an extension', () => {
+      expect(getExecutableExtension('report.xlsx')).toBeNull();
+      expect(getExecutableExtension('archive.tar.gz')).toBeNull();
+      expect(getExecutableExtension('exe')).toBeNull();
+      expect(getExecutableExtension('Makefile')).toBeNull();
+    });
+  });
+
+  describe('cleanupExternalOpenDir', () => {
+    it('removes downloaded files and tolerates a missing folder', () => {
+      const base = path.join(tmpDir, 'open');
+      const file = buildExternalOpenPath('a.pdf', base, 'one');
+      fs.mkdirSync(path.dirname(file), { recursive: true });
+      fs.writeFileSync(file, 'pdf');
+
+      cleanupExternalOpenDir(base);
+
+      expect(fs.existsSync(base)).toBe(false);
+      expect(() => cleanupExternalOpenDir(base)).not.toThrow();
+    });
+  });
+
+  describe('getRevealAction', () => {
+    it('selects the file when it exists', async () => {
+      const file = path.join(tmpDir, 'report.csv');
+      fs.writeFileSync(file, 'a,b');
+
+      expect(await getRevealAction(file)).toEqual({ action: 'select', path: file });
+    });
+
+    it('opens the containing folder when the file is gone', async () => {
+      expect(await getRevealAction(path.join(tmpDir, 'moved.csv'))).toEqual({ action: 'open', path: tmpDir });
+    });
+
+    it('returns null when the folder is gone too', async () => {
+      expect(await getRevealAction(path.join(tmpDir, 'missing', 'file.csv'))).toBeNull();
+    });
+  });
+});
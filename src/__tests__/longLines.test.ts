@@ -0,0 +1,45 @@
+import { describe, it, expect } from 'vitest';
+import {
+  LONG_LINE_WRAP_WIDTH,
+  getLongestLineLength,
+  wrapLongLines,
+} from '../renderer/utils/longLines';
+
+describe('getLongestLineLength', () => {
+  it('measures the longest line', () => {
+    expect(getLongestLineLength('ab\nabcd\nabc')).toBe(4);
+  });
+
+  it('ignores the carriage return of CRLF endings', () => {
+    expect(getLongestLineLength('abc\r\nab\r\n')).toBe(3);
+  });
+
+  it('handles empty content', () => {
+    expect(getLongestLineLength('')).toBe(0);
+  });
+});
+
+describe('wrapLongLines', () => {
+  it('wraps a 5 MB single line into bounded segments', () => {
+    const line = '{"id":12345,"name":"value"},'.repeat(Math.ceil((5 * 1024 * 1024) / 28));
+    const segments = wrapLongLines(line, LONG_LINE_WRAP_WIDTH);
+
+    expect(segments.length).toBe(Math.ceil(line.length / LONG_LINE_WRAP_WIDTH));
+    expect(segments.every(segment => segment.length <= LONG_LINE_WRAP_WIDTH)).toBe(true);
+    expect(segments.join('')).toBe(line);
+  });
+
+  it('keeps short lines whole', () => {
+    expect(wrapLongLines('one\ntwo\r\nthree', 10)).toEqual(['one', 'two', 'three']);
+  });
+
+  it('wraps only the long lines', () => {
+    expect(wrapLongLines('abcdefgh\nxy', 3)).toEqual(['abc', 'def', 'gh', 'xy']);
+  });
+
+  it('does not split surrogate pairs', () => {
+    const segments = wrapLongLines('ab\u{1F600}cd', 3);
+
+    expect(segments).toEqual(['ab', '\u{1F600}c', 'd']);
+  });
+});
@@ -0,0 +1,69 @@
+import { describe, it, expect, vi, afterEach } from 'vitest';
+import { createMfaCodeRequests } from '../main/services/mfaPrompt';
+
+describe('createMfaCodeRequests', () => {
+  afterEach(() => {
+    vi.useRealTimers();
+  });
+
+  it('sends the request to the UI and resolves with the submitted code', async () => {
+    const send = vi.fn(() => true);
+    const requests = createMfaCodeRequests(send);
+
+    const code = requests.request('arn:aws:iam::123456789012:mfa/user');
+    expect(send).toHaveBeenCalledWith({ requestId: 1, mfaSerial: 'arn:aws:iam::123456789012:mfa/user' });
+
+    expect(requests.submit(1, ' 123456 ')).toBe(true);
+    await expect(code).resolves.toBe('123456');
+  });
+
+  it('rejects when the user cancels', async () => {
+    const requests = createMfaCodeRequests(() => true);
+
+    const code = requests.request('arn:aws:iam::123456789012:mfa/user');
+    requests.submit(1, null);
+
+    await expect(code).rejects.toThrow('MFA code entry cancelled');
+  });
+
+  it('rejects when there is no window to ask in', async () => {
+    const requests = createMfaCodeRequests(() => false);
+
+    await expect(requests.request('arn:aws:iam::123456789012:mfa/user')).rejects.toThrow(
+      'there is no window to ask in'
+    );
+  });
+
+  it('rejects requests nobody answers in time', async () => {
+    vi.useFakeTimers();
+    const requests = createMfaCodeRequests(() => true, 1000);
+
+    const code = requests.request('arn:aws:iam::123456789012:mfa/user');
+    const assertion = expect(code).rejects.toThrow('Timed out waiting for an MFA code');
+    await vi.advanceTimersByTimeAsync(1000);
+
+    await assertion;
+    expect(requests.submit(1, '123456')).toBe(false);
+  });
+
+  it('rejects every pending request when cancelled', async () => {
+    const requests = createMfaCodeRequests(() => true);
+
+    const first = requests.request('arn:aws:iam::123456789012:mfa/one');
+    const second = requests.request('arn:aws:iam::123456789012:mfa/two');
+    requests.cancelAll('window closed');
+
+    await expect(first).rejects.toThrow('window closed');
+    await expect(second).rejects.toThrow('window closed');
+    expect(requests.submit(1, '123456')).toBe(false);
+  });
+
+  it('ignores answers to unknown or already answered requests', () => {
+    const requests = createMfaCodeRequests(() => true);
+
+    requests.request('arn:aws:iam::123456789012:mfa/user');
+    expect(requests.submit(1, '123456')).toBe(true);
+    expect(requests.submit(1, '654321')).toBe(false);
+    expect(requests.submit(42, '123456')).toBe(false);
+  });
+});
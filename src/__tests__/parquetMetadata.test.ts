@@ -0,0 +1,229 @@
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+// Keep the real schema parsing, but stub reading so no bytes need decoding
+vi.mock('hyparquet', async (importOriginal) => ({
+  ...(await importOriginal<typeof import('hyparquet')>()),
+  parquetMetadataAsync: vi.fn(),
+  parquetRead: vi.fn(),
+}));
+
+import { parquetMetadataAsync, parquetRead, type FileMetaData } from 'hyparquet';
+import {
+  getLogicalAnnotation,
+  parquetColumnStats,
+  parquetRowGroups,
+  parquetSchemaInfo,
+  readParquetSchema,
+  s3RangeAsyncBuffer,
+  toAsyncBuffer,
+} from '../renderer/utils/parquetMetadata';
+import { mockElectronAPI } from './setup';
+
+const metadata = {
+  version: 2,
+  schema: [
+    { name: 'root', num_children: 3 },
+    { name: 'id', type: 'INT64', repetition_type: 'REQUIRED' },
+    { name: 'payload', type: 'BYTE_ARRAY', repetition_type: 'OPTIONAL', converted_type: 'JSON' },
+    { name: 'tags', repetition_type: 'OPTIONAL', converted_type: 'LIST', num_children: 1 },
+    { name: 'list', repetition_type: 'REPEATED', num_children: 1 },
+    { name: 'element', type: 'BYTE_ARRAY', repetition_type: 'OPTIONAL' },
+  ],
+  num_rows: 1234n,
+  row_groups: [],
+  metadata_length: 0,
+} as unknown as FileMetaData;
+
+describe('parquetSchemaInfo', () => {
+  it('describes the top-level columns and the row count', () => {
+    expect(parquetSchemaInfo(metadata)).toEqual({
+      columns: [
+        { name: 'id', physicalType: 'INT64', logicalType: undefined, nullable: false },
+        { name: 'payload', physicalType: 'BYTE_ARRAY', logicalType: 'JSON', nullable: true },
+        { name: 'tags', physicalType: 'GROUP', logicalType: undefined, nullable: true },
+      ],
+      totalRows: 1234,
+    });
+  });
+});
+
+describe('readParquetSchema', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it('reads columns and total rows from the metadata without reading rows', async () => {
+    (parquetMetadataAsync as ReturnType<typeof vi.fn>).mockResolvedValue(metadata);
+    const file = toAsyncBuffer(new Uint8Array([0x50, 0x41, 0x52, 0x31]));
+
+    const schema = await readParquetSchema(file);
+
+    expect(parquetMetadataAsync).toHaveBeenCalledWith(file);
+    expect(parquetRead).not.toHaveBeenCalled();
+    expect(schema.columns.map(c => c.name)).toEqual(['id', 'payload', 'tags']);
+    expect(schema.totalRows).toBe(1234);
+  });
+});
+
+describe('parquetRowGroups', () => {
+  const multiGroup = {
+    ...metadata,
+    row_groups: [
+      { num_rows: 1000n, total_byte_size: 64000n, total_compressed_size: 20000n, columns: [] },
+      { num_rows: 1000n, total_byte_size: 62000n, total_compressed_size: 19000n, columns: [] },
+      {
+        num_rows: 234n,
+        total_byte_size: 9000n,
+        columns: [
+          { file_offset: 0n, meta_data: { total_compressed_size: 1500n } },
+          { file_offset: 0n, meta_data: { total_compressed_size: 1200n } },
+        ],
+      },
+    ],
+  } as unknown as FileMetaData;
+
+  it('describes each row group in file order', () => {
+    expect(parquetRowGroups(multiGroup)).toEqual([
+      { numRows: 1000, totalByteSize: 64000, compressedSize: 20000 },
+      { numRows: 1000, totalByteSize: 62000, compressedSize: 19000 },
+      { numRows: 234, totalByteSize: 9000, compressedSize: 2700 },
+    ]);
+  });
+
+  it('returns no row groups for a file without rows', () => {
+    expect(parquetRowGroups({ ...metadata, row_groups: [] })).toEqual([]);
+  });
+});
+
+describe('parquetColumnStats', () => {
+  function chunk(path: string, statistics?: Record<string, unknown>) {
+    return { file_offset: 0n, meta_data: { path_in_schema: path.split('.'), statistics } };
+  }
+
+  function withRowGroups(...groups: ReturnType<typeof chunk>[][]): FileMetaData {
+    return {
+      ...metadata,
+      row_groups: groups.map(columns => ({ num_rows: 10n, total_byte_size: 0n, columns })),
+    } as unknown as FileMetaData;
+  }
+
+  it('aggregates min, max and null counts across row groups', () => {
+    const stats = parquetColumnStats(
+      withRowGroups(
+        [
+          chunk('id', { min_value: 5n, max_value: 90n, null_count: 0n }),
+          chunk('city', { min_value: 'Lisbon', max_value: 'Oslo', null_count: 2n }),
+        ],
+        [
+          chunk('id', { min_value: 1n, max_value: 40n, null_count: 0n }),
+          chunk('city', { min_value: 'Berlin', max_value: 'Madrid', null_count: 1n }),
+        ]
+      )
+    );
+
+    expect(stats).toEqual([
+      { name: 'id', min: 1n, max: 90n, nullCount: 0, distinctCount: null },
+      { name: 'city', min: 'Berlin', max: 'Oslo', nullCount: 3, distinctCount: null },
+    ]);
+  });
+
+  it('reports the distinct count of a single row group', () => {
+    expect(parquetColumnStats(withRowGroups([chunk('id', { distinct_count: 7n })]))).toEqual([
+      { name: 'id', min: null, max: null, nullCount: null, distinctCount: 7 },
+    ]);
+  });
+
+  it('falls back to the deprecated min and max', () => {
+    const [stats] = parquetColumnStats(withRowGroups([chunk('score', { min: 0.5, max: 9.5 })]));
+    expect(stats.min).toBe(0.5);
+    expect(stats.max).toBe(9.5);
+  });
+
+  it('returns null for statistics missing from any row group', () => {
+    const stats = parquetColumnStats(
+      withRowGroups(
+        [chunk('id', { min_value: 1, max_value: 2, null_count: 0n }), chunk('tags.list.element')],
+        [chunk('id', { null_count: 0n }), chunk('tags.list.element')]
+      )
+    );
+
+    expect(stats).toEqual([
+      { name: 'id', min: null, max: null, nullCount: 0, distinctCount: null },
+      { name: 'tags.list.element', min: null, max: null, nullCount: null, distinctCount: null },
+    ]);
+  });
+
+  it('returns null min and max for values that cannot be ordered', () => {
+    const [stats] = parquetColumnStats(
+      withRowGroups(
+        [chunk('blob', { min_value: new Uint8Array([1]), max_value: new Uint8Array([9]) })],
+        [chunk('blob', { min_value: new Uint8Array([0]), max_value: new Uint8Array([8]) })]
+      )
+    );
+    expect(stats.min).toBeNull();
+    expect(stats.max).toBeNull();
+  });
+
+  it('orders dates by time', () => {
+    const [stats] = parquetColumnStats(
+      withRowGroups(
+        [chunk('day', { min_value: new Date('2024-03-01'), max_value: new Date('2024-03-31') })],
+        [chunk('day', { min_value: new Date('2024-01-15'), max_value: new Date('2024-02-01') })]
+      )
+    );
+    expect(stats.min).toEqual(new Date('2024-01-15'));
+    expect(stats.max).toEqual(new Date('2024-03-31'));
+  });
+});
+
+describe('getLogicalAnnotation', () => {
+  it('prefers the logical type over the converted type', () => {
+    expect(getLogicalAnnotation({ logical_type: { type: 'BSON' }, converted_type: 'JSON' })).toBe('BSON');
+    expect(getLogicalAnnotation({ converted_type: 'ENUM' })).toBe('ENUM');
+  });
+
+  it('ignores annotations that need no special rendering', () => {
+    expect(getLogicalAnnotation({ logical_type: { type: 'STRING' } })).toBeUndefined();
+    expect(getLogicalAnnotation({})).toBeUndefined();
+  });
+});
+
+describe('s3RangeAsyncBuffer', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it('fetches each slice with a ranged read', async () => {
+    mockElectronAPI.s3.downloadPartialContent.mockResolvedValue({ success: true, data: new Uint8Array([7, 8]) });
+    const file = s3RangeAsyncBuffer('bucket', 'big.parquet', 1000);
+
+    const footer = await file.slice(998);
+
+    expect(file.byteLength).toBe(1000);
+    expect(mockElectronAPI.s3.downloadPartialContent).toHaveBeenCalledWith('bucket', 'big.parquet', 2, 998, undefined);
+    expect(footer).toBeInstanceOf(ArrayBuffer);
+    expect(Array.from(new Uint8Array(footer))).toEqual([7, 8]);
+
+    await file.slice(10, 20);
+    expect(mockElectronAPI.s3.downloadPartialContent).toHaveBeenLastCalledWith('bucket', 'big.parquet', 10, 10, undefined);
+  });
+
+  it('rejects when a range cannot be read', async () => {
+    mockElectronAPI.s3.downloadPartialContent.mockResolvedValue({ success: false, error: 'Access Denied' });
+
+    await expect(s3RangeAsyncBuffer('bucket', 'big.parquet', 1000).slice(0, 8)).rejects.toThrow('Access Denied');
+  });
+});
+
+describe('toAsyncBuffer', () => {
+  it('slices copies of the data as ArrayBuffers', async () => {
+    const data = new Uint8Array([1, 2, 3, 4]);
+    const file = toAsyncBuffer(data);
+    data[0] = 9;
+
+    const slice = await file.slice(0, 2);
+    expect(file.byteLength).toBe(4);
+    expect(slice).toBeInstanceOf(ArrayBuffer);
+    expect(Array.from(new Uint8Array(slice))).toEqual([1, 2]);
+  });
+});
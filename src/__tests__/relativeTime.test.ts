@@ -0,0 +1,30 @@
+import { describe, it, expect } from 'vitest';
+import { formatAge } from '../renderer/utils/relativeTime';
+
+describe('formatAge', () => {
+  const now = new Date('2024-06-15T12:00:00Z').getTime();
+
+  it('reports times under a minute as just now', () => {
+    expect(formatAge(new Date(now), now)).toBe('just now');
+    expect(formatAge(new Date(now - 59 * 1000), now)).toBe('just now');
+  });
+
+  it('uses the largest whole unit', () => {
+    expect(formatAge('2024-06-15T11:59:00Z', now)).toBe('1 minute ago');
+    expect(formatAge('2024-06-15T09:30:00Z', now)).toBe('2 hours ago');
+    expect(formatAge('2024-06-12T12:00:00Z', now)).toBe('3 days ago');
+    expect(formatAge('2024-06-01T12:00:00Z', now)).toBe('2 weeks ago');
+    expect(formatAge('2024-03-01T12:00:00Z', now)).toBe('3 months ago');
+    expect(formatAge('2022-01-01T00:00:00Z', now)).toBe('2 years ago');
+  });
+
+  it('treats timestamps ahead of the clock as just now', () => {
+    expect(formatAge(new Date(now + 30 * 1000), now)).toBe('just now');
+    expect(formatAge(new Date(now + 2 * 60 * 60 * 1000), now)).toBe('just now');
+  });
+
+  it('returns "--" for missing or invalid timestamps', () => {
+    expect(formatAge(undefined, now)).toBe('--');
+    expect(formatAge('not a date', now)).toBe('--');
+  });
+});
@@ -0,0 +1,102 @@
+import { describe, it, expect, vi } from 'vitest';
+import {
+  REDACTED,
+  createRequestLoggingMiddleware,
+  describeRequest,
+  redactHeaders,
+} from '../main/services/requestLogging';
+
+const signedRequest = {
+  method: 'GET',
+  protocol: 'https:',
+  hostname: 'minio.local',
+  port: 9000,
+  path: '/bucket/file.txt',
+  query: { 'x-id': 'GetObject' },
+  headers: {
+    authorization:
+      'AWS4-HMAC-SHA256 Credential=AKIAEXAMPLE/20240101/us-east-1/s3/aws4_request, SignedHeaders=host, Signature=abcdef0123',
+    'x-amz-security-token': 'session-token',
+    'x-amz-date': '20240101T000000Z',
+  },
+};
+
+describe('requestLogging', () => {
+  describe('redactHeaders', () => {
+    it('hides the Authorization value but keeps the signing algorithm', () => {
+      const redacted = redactHeaders(signedRequest.headers);
+
+      expect(redacted.authorization).toBe(`AWS4-HMAC-SHA256 ${REDACTED}`);
+      expect(redacted.authorization).not.toContain('AKIAEXAMPLE');
+      expect(redacted.authorization).not.toContain('abcdef0123');
+    });
+
+    it('matches header names case-insensitively', () => {
+      expect(redactHeaders({ Authorization: 'secret' })).toEqual({ Authorization: REDACTED });
+    });
+
+    it('hides session tokens and customer encryption keys', () => {
+      const redacted = redactHeaders({
+        'X-Amz-Security-Token': 'token',
+        'x-amz-server-side-encryption-customer-key': 'key',
+      });
+
+      expect(Object.values(redacted)).toEqual([REDACTED, REDACTED]);
+    });
+
+    it('keeps other headers unchanged', () => {
+      expect(redactHeaders({ 'x-amz-date': '20240101T000000Z', host: 'minio.local' })).toEqual({
+        'x-amz-date': '20240101T000000Z',
+        host: 'minio.local',
+      });
+    });
+  });
+
+  describe('describeRequest', () => {
+    it('includes the method, URL and redacted headers', () => {
+      const line = describeRequest(signedRequest);
+
+      expect(line).toContain('GET https://minio.local:9000/bucket/file.txt?x-id=GetObject');
+      expect(line).toContain('"x-amz-date":"20240101T000000Z"');
+      expect(line).not.toContain('session-token');
+      expect(line).not.toContain('Signature=');
+    });
+
+    it('redacts signatures and credentials in presigned query strings', () => {
+      const line = describeRequest({
+        ...signedRequest,
+        headers: {},
+        query: { 'X-Amz-Credential': 'AKIAEXAMPLE/20240101', 'X-Amz-Signature': 'abcdef', uploads: null },
+      });
+
+      expect(line).toContain(`X-Amz-Credential=${REDACTED}&X-Amz-Signature=${REDACTED}&uploads`);
+      expect(line).not.toContain('AKIAEXAMPLE');
+    });
+  });
+
+  describe('createRequestLoggingMiddleware', () => {
+    it('logs the request and response without the Authorization value', async () => {
+      const log = vi.fn();
+      const next = vi.fn().mockResolvedValue({ response: { statusCode: 200, headers: { etag: '"abc"' } } });
+
+      const result = await createRequestLoggingMiddleware(log)(next)({ request: signedRequest });
+
+      expect(next).toHaveBeenCalledWith({ request: signedRequest });
+      expect(result.response).toEqual({ statusCode: 200, headers: { etag: '"abc"' } });
+      expect(log).toHaveBeenCalledTimes(2);
+      expect(log.mock.calls[0][0]).toMatch(/^\[S3 request\] GET /);
+      expect(log.mock.calls[1][0]).toMatch(/^\[S3 response\] 200 \(\d+ ms\)/);
+      expect(log.mock.calls.flat().join('\n')).not.toContain('Signature=abcdef0123');
+    });
+
+    it('logs and rethrows failures', async () => {
+      const log = vi.fn();
+      const next = vi.fn().mockRejectedValue(new Error('connect ECONNREFUSED'));
+
+      await expect(createRequestLoggingMiddleware(log)(next)({ request: signedRequest })).rejects.toThrow(
+        'connect ECONNREFUSED'
+      );
+      expect(log).toHaveBeenLastCalledWith('[S3 error] GET /bucket/file.txt: connect ECONNREFUSED');
+    });
+  });
+});
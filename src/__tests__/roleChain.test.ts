@@ -0,0 +1,215 @@
+import { describe, it, expect, vi, beforeEach, type Mock } from 'vitest';
+
+const mockSend = vi.fn();
+const mockSourceCredentials = vi.fn();
+
+vi.mock('@aws-sdk/client-sts', () => {
+  class MockSTSClient {
+    constructor(readonly config: unknown) {}
+    send = (command: unknown) => mockSend(command, this.config);
+    destroy = vi.fn();
+  }
+
+  return {
+    STSClient: MockSTSClient,
+    AssumeRoleCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'AssumeRole' };
+    }),
+  };
+});
+
+vi.mock('@aws-sdk/credential-providers', () => ({
+  fromIni: vi.fn(() => mockSourceCredentials),
+  fromEnv: vi.fn(() => mockSourceCredentials),
+  fromInstanceMetadata: vi.fn(() => mockSourceCredentials),
+  fromContainerMetadata: vi.fn(() => mockSourceCredentials),
+}));
+
+vi.mock('../main/services/awsCredentials', () => ({
+  loadAwsProfiles: vi.fn(() => ({ profiles: [] })),
+  resolveRoleChain: vi.fn(),
+}));
+
+import { fromIni, fromEnv } from '@aws-sdk/credential-providers';
+import { resolveRoleChain, type RoleChain } from '../main/services/awsCredentials';
+import { assumeRoleChain, createRoleChainProvider, RoleChainError } from '../main/services/roleChain';
+
+const baseCredentials = { accessKeyId: 'AKIABASE', secretAccessKey: 'base-secret' };
+
+function stsCredentials(name: string, expiration = new Date(Date.now() + 60 * 60 * 1000)) {
+  return {
+    Credentials: {
+      AccessKeyId: `ASIA${name}`,
+      SecretAccessKey: `${name}-secret`,
+      SessionToken: `${name}-token`,
+      Expiration: expiration,
+    },
+  };
+}
+
+const twoHopChain: RoleChain = {
+  source: { profileName: 'base' },
+  hops: [
+    { profileName: 'ops', roleArn: 'arn:aws:iam::111111111111:role/Ops' },
+    {
+      profileName: 'prod',
+      roleArn: 'arn:aws:iam::222222222222:role/Deploy',
+      externalId: 'partner-42',
+      roleSessionName: 'deploy-session',
+      durationSeconds: 1800,
+      region: 'eu-west-1',
+    },
+  ],
+};
+
+describe('roleChain', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    mockSend.mockReset();
+    mockSourceCredentials.mockReset();
+    mockSourceCredentials.mockResolvedValue(baseCredentials);
+  });
+
+  describe('assumeRoleChain', () => {
+    it('assumes each role with the credentials of the one before', async () => {
+      mockSend.mockResolvedValueOnce(stsCredentials('OPS')).mockResolvedValueOnce(stsCredentials('PROD'));
+
+      const credentials = await assumeRoleChain(twoHopChain);
+
+      expect(fromIni).toHaveBeenCalledWith({ profile: 'base' });
+      expect(mockSend).toHaveBeenCalledTimes(2);
+
+      const [first, firstConfig] = mockSend.mock.calls[0];
+      expect(first.input.RoleArn).toBe('arn:aws:iam::111111111111:role/Ops');
+      expect(first.input.RoleSessionName).toMatch(/^s3-browser-\d+$/);
+      expect(firstConfig).toEqual({ region: 'us-east-1', credentials: baseCredentials });
+
+      const [second, secondConfig] = mockSend.mock.calls[1];
+      expect(second.input).toEqual({
+        RoleArn: 'arn:aws:iam::222222222222:role/Deploy',
+        RoleSessionName: 'deploy-session',
+        ExternalId: 'partner-42',
+        DurationSeconds: 1800,
+        SerialNumber: undefined,
+        TokenCode: undefined,
+      });
+      expect(secondConfig.region).toBe('eu-west-1');
+      expect(secondConfig.credentials.accessKeyId).toBe('ASIAOPS');
+
+      expect(credentials.accessKeyId).toBe('ASIAPROD');
+      expect(credentials.sessionToken).toBe('PROD-token');
+    });
+
+    it('asks for the MFA code of a role with mfa_serial', async () => {
+      mockSend.mockResolvedValueOnce(stsCredentials('OPS'));
+      const mfaCodeProvider = vi.fn().mockResolvedValue('123456');
+
+      await assumeRoleChain(
+        {
+          source: { credentialSource: 'Environment' },
+          hops: [
+            {
+              profileName: 'ops',
+              roleArn: 'arn:aws:iam::111111111111:role/Ops',
+              mfaSerial: 'arn:aws:iam::000000000000:mfa/me',
+            },
+          ],
+        },
+        { mfaCodeProvider }
+      );
+
+      expect(fromEnv).toHaveBeenCalled();
+      expect(mfaCodeProvider).toHaveBeenCalledWith('arn:aws:iam::000000000000:mfa/me');
+      expect(mockSend.mock.calls[0][0].input).toMatchObject({
+        SerialNumber: 'arn:aws:iam::000000000000:mfa/me',
+        TokenCode: '123456',
+      });
+    });
+
+    it('sends the source and STS requests through the given request handler', async () => {
+      mockSend.mockResolvedValueOnce(stsCredentials('OPS')).mockResolvedValueOnce(stsCredentials('PROD'));
+      const requestHandler = { connectionTimeout: 5000 };
+
+      await assumeRoleChain(twoHopChain, { clientConfig: { requestHandler } });
+
+      expect(fromIni).toHaveBeenCalledWith({ profile: 'base', clientConfig: { requestHandler } });
+      expect(mockSend.mock.calls[0][1].requestHandler).toBe(requestHandler);
+      expect(mockSend.mock.calls[1][1].requestHandler).toBe(requestHandler);
+    });
+
+    it('names the role that could not be assumed', async () => {
+      mockSend
+        .mockResolvedValueOnce(stsCredentials('OPS'))
+        .mockRejectedValueOnce(new Error('User is not authorized to perform: sts:AssumeRole'));
+
+      const error = await assumeRoleChain(twoHopChain).catch(e => e);
+
+      expect(error).toBeInstanceOf(RoleChainError);
+      expect(error.message).toBe(
+        "Could not assume arn:aws:iam::222222222222:role/Deploy for profile 'prod' (role 2 of 2): " +
+          'User is not authorized to perform: sts:AssumeRole'
+      );
+      expect(error.profileName).toBe('prod');
+      expect(error.roleArn).toBe('arn:aws:iam::222222222222:role/Deploy');
+    });
+
+    it('names the source profile when its credentials fail', async () => {
+      mockSourceCredentials.mockRejectedValue(new Error('The security token included in the request is expired'));
+
+      const error = await assumeRoleChain(twoHopChain).catch(e => e);
+
+      expect(error).toBeInstanceOf(RoleChainError);
+      expect(error.message).toBe(
+        "Could not get credentials from source profile 'base': The security token included in the request is expired"
+      );
+      expect(error.roleArn).toBeUndefined();
+      expect(mockSend).not.toHaveBeenCalled();
+    });
+
+    it('fails a role with mfa_serial when there is no way to ask for the code', async () => {
+      const error = await assumeRoleChain({
+        source: { profileName: 'base' },
+        hops: [{ profileName: 'ops', roleArn: 'arn:aws:iam::111111111111:role/Ops', mfaSerial: 'arn:mfa' }],
+      }).catch(e => e);
+
+      expect(error.message).toContain('an MFA code for arn:mfa is required');
+      expect(mockSend).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('createRoleChainProvider', () => {
+    it('reuses credentials until shortly before they expire', async () => {
+      (resolveRoleChain as Mock).mockReturnValue({ chain: twoHopChain });
+      mockSend
+        .mockResolvedValueOnce(stsCredentials('OPS'))
+        .mockResolvedValueOnce(stsCredentials('PROD', new Date(Date.now() + 2 * 60 * 1000)))
+        .mockResolvedValueOnce(stsCredentials('OPS'))
+        .mockResolvedValueOnce(stsCredentials('PROD2'));
+
+      const provider = createRoleChainProvider('prod');
+
+      expect((await provider()).accessKeyId).toBe('ASIAPROD');
+      // The first credentials expire within the refresh margin, so the chain is assumed again
+      expect((await provider()).accessKeyId).toBe('ASIAPROD2');
+      expect((await provider()).accessKeyId).toBe('ASIAPROD2');
+      expect(mockSend).toHaveBeenCalledTimes(4);
+    });
+
+    it('shares one assumption between concurrent requests', async () => {
+      (resolveRoleChain as Mock).mockReturnValue({ chain: twoHopChain });
+      mockSend.mockResolvedValueOnce(stsCredentials('OPS')).mockResolvedValueOnce(stsCredentials('PROD'));
+
+      const provider = createRoleChainProvider('prod');
+      const [a, b] = await Promise.all([provider(), provider()]);
+
+      expect(a).toBe(b);
+      expect(mockSend).toHaveBeenCalledTimes(2);
+    });
+
+    it('reports why the chain cannot be followed', async () => {
+      (resolveRoleChain as Mock).mockReturnValue({ error: "Source profile 'gone' not found" });
+
+      await expect(createRoleChainProvider('top')()).rejects.toThrow("Source profile 'gone' not found");
+    });
+  });
+});
@@ -323,11 +323,11 @@ describe.skipIf(!AWS_CREDENTIALS_AVAILABLE)('S3 Integration Tests', () => {
         },
         {
           url: 'https://my-bucket.s3.us-west-2.amazonaws.com/folder/file.txt',
-          expected: { bucket: 'my-bucket', key: 'folder/file.txt' },
+          expected: { bucket: 'my-bucket', key: 'folder/file.txt', region: 'us-west-2' },
         },
         {
           url: 'https://my-bucket.s3.eu-west-1.amazonaws.com/',
-          expected: { bucket: 'my-bucket', key: '' },
+          expected: { bucket: 'my-bucket', key: '', region: 'eu-west-1' },
         },
       ];
 
@@ -346,7 +346,7 @@ describe.skipIf(!AWS_CREDENTIALS_AVAILABLE)('S3 Integration Tests', () => {
         },
         {
           url: 'https://s3.eu-west-1.amazonaws.com/my-bucket/folder/file.txt',
-          expected: { bucket: 'my-bucket', key: 'folder/file.txt' },
+          expected: { bucket: 'my-bucket', key: 'folder/file.txt', region: 'eu-west-1' },
         },
       ];
 
@@ -13,6 +13,7 @@ vi.mock('@aws-sdk/client-s3', () => {
     }
     // Return a function that calls the mockSend we defined above
     send = (command: unknown) => mockSend(command);
+    middlewareStack = { add: vi.fn() };
   }
 
   return {
@@ -32,6 +33,9 @@ vi.mock('@aws-sdk/client-s3', () => {
     DeleteObjectCommand: vi.fn().mockImplementation(function (input: unknown) {
       return { input, type: 'DeleteObject' };
     }),
+    DeleteObjectsCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'DeleteObjects', middlewareStack: { add: vi.fn() } };
+    }),
     CopyObjectCommand: vi.fn().mockImplementation(function (input: unknown) {
       return { input, type: 'CopyObject' };
     }),
@@ -41,10 +45,47 @@ vi.mock('@aws-sdk/client-s3', () => {
     GetObjectTaggingCommand: vi.fn().mockImplementation(function (input: unknown) {
       return { input, type: 'GetObjectTagging' };
     }),
+    PutObjectTaggingCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'PutObjectTagging' };
+    }),
+    CreateBucketCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'CreateBucket' };
+    }),
+    HeadBucketCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'HeadBucket' };
+    }),
+    GetBucketLocationCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'GetBucketLocation' };
+    }),
+    RestoreObjectCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'RestoreObject' };
+    }),
+    SelectObjectContentCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'SelectObjectContent' };
+    }),
+    ListMultipartUploadsCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'ListMultipartUploads' };
+    }),
+    AbortMultipartUploadCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'AbortMultipartUpload' };
+    }),
+    GetPublicAccessBlockCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'GetPublicAccessBlock' };
+    }),
+    GetBucketPolicyStatusCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'GetBucketPolicyStatus' };
+    }),
+    GetBucketAclCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'GetBucketAcl' };
+    }),
   };
 });
 
 // Mock fs module - need to mock the specific imports used by s3Service
+vi.mock('@aws-sdk/s3-request-presigner', () => ({
+  getSignedUrl: vi.fn(),
+}));
+
 vi.mock('fs', async (importOriginal) => {
   const actual = await importOriginal() as object;
   return {
@@ -55,6 +96,7 @@ vi.mock('fs', async (importOriginal) => {
         stat: vi.fn().mockResolvedValue({ size: 1024 }),
         readFile: vi.fn().mockResolvedValue(Buffer.from('test content')),
         unlink: vi.fn().mockResolvedValue(undefined),
+        rename: vi.fn().mockResolvedValue(undefined),
         access: vi.fn().mockRejectedValue(new Error('ENOENT')),
       },
       createWriteStream: vi.fn().mockReturnValue({
@@ -68,6 +110,7 @@ vi.mock('fs', async (importOriginal) => {
       stat: vi.fn().mockResolvedValue({ size: 1024 }),
       readFile: vi.fn().mockResolvedValue(Buffer.from('test content')),
       unlink: vi.fn().mockResolvedValue(undefined),
+      rename: vi.fn().mockResolvedValue(undefined),
       access: vi.fn().mockRejectedValue(new Error('ENOENT')),
     },
     createWriteStream: vi.fn().mockReturnValue({
@@ -86,6 +129,14 @@ vi.mock('stream/promises', () => ({
   pipeline: vi.fn().mockResolvedValue(undefined),
 }));
 
+vi.mock('@aws-sdk/credential-providers', () => ({
+  fromIni: vi.fn((init: object) => ({ fromIni: init })),
+}));
+
+vi.mock('../main/services/roleChain', () => ({
+  createRoleChainProvider: vi.fn((profileName: string, options: object) => ({ roleChain: profileName, options })),
+}));
+
 // Mock the awsCredentials module
 vi.mock('../main/services/awsCredentials', () => ({
   getProfile: vi.fn(),
@@ -98,16 +149,72 @@ import {
   listBuckets,
   listObjects,
   listAllObjects,
+  iterateObjects,
+  resolvePagination,
+  collapseSingleChildChain,
   parseS3Url,
+  parseS3UrlList,
+  getRedirectTarget,
+  getCommonPrefix,
   getParentPrefix,
   getKeyName,
   uploadContent,
+  uploadFile,
   downloadContent,
+  downloadFile,
+  downloadFiles,
+  downloadPartialContent,
+  downloadBinaryContentIfModified,
+  downloadBinaryContentVerified,
+  movePrefix,
+  formatByteRange,
   deleteFile,
   renameFile,
   copyFile,
   getFileSize,
+  headObject,
+  isNotFoundError,
+  presignGetUrl,
+  presignPutUrl,
+  MAX_PRESIGN_EXPIRY_SECONDS,
   getObjectMetadata,
+  describeS3Error,
+  getClockSkewMs,
+  validateBucketName,
+  buildCreateBucketConfig,
+  createBucket,
+  getPublicAccessBlock,
+  getBucketPublicStatus,
+  getBucketRegion,
+  normalizeLocationConstraint,
+  checkBucketAccess,
+  describeBucketStatus,
+  isRegionMismatchError,
+  selectObjectContent,
+  buildSelectInputSerialization,
+  MAX_SELECT_RESULT_BYTES,
+  listMultipartUploads,
+  abortMultipartUpload,
+  combinePublicStatus,
+  setRequestLogging,
+  setRequesterPays,
+  setProxyUrl,
+  getProxyUrl,
+  setMfaCodeProvider,
+  resolveProfileRegion,
+  isRequestLoggingEnabled,
+  setMaxRetries,
+  getMaxRetries,
+  setRequestTimeouts,
+  getRequestTimeouts,
+  isTimeoutError,
+  restoreObject,
+  getRestoreStatus,
+  parseRestoreHeader,
+  isArchivedStorageClass,
+  getObjectTags,
+  putObjectTags,
+  validateObjectTags,
 } from '../main/services/s3Service';
 import {
   S3Client,
@@ -119,8 +226,12 @@ import {
   CopyObjectCommand,
   HeadObjectCommand,
 } from '@aws-sdk/client-s3';
+import { getSignedUrl } from '@aws-sdk/s3-request-presigner';
 import { getProfile } from '../main/services/awsCredentials';
-import { Readable } from 'stream';
+import { ProxyAgent } from '../main/services/proxy';
+import { Readable, Writable } from 'stream';
+import * as fs from 'fs';
+import { pipeline } from 'stream/promises';
 
 describe('s3Service', () => {
   beforeEach(() => {
@@ -149,6 +260,22 @@ describe('s3Service', () => {
       expect(client).toBeInstanceOf(S3Client);
     });
 
+    it('should send unsigned requests for the anonymous profile', async () => {
+      (getProfile as Mock).mockReturnValue({
+        name: '(anonymous)',
+        profileType: 'anonymous',
+        hasCredentials: true,
+      });
+
+      const client = getS3Client('(anonymous)');
+      const config = (client as any).config;
+      const request = { method: 'GET', headers: {} };
+
+      expect(config.region).toBe('eu-west-1');
+      expect(config.followRegionRedirects).toBe(true);
+      expect(await config.signer.sign(request)).toBe(request);
+    });
+
     it('should use default region (eu-west-1) if profile has no region', () => {
       (getProfile as Mock).mockReturnValue({
         name: 'default',
@@ -164,6 +291,45 @@ describe('s3Service', () => {
       expect((client as any).config.region).toBe('eu-west-1');
     });
 
+    it("should sign for the profile's region", () => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'frankfurt',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        region: 'eu-central-1',
+        profileType: 'static',
+        hasCredentials: true,
+      });
+
+      expect((getS3Client('frankfurt') as any).config.region).toBe('eu-central-1');
+    });
+
+    it('should use AWS_REGION, then AWS_DEFAULT_REGION, then the profile region', () => {
+      const profile = { name: 'default', profileType: 'static' as const, hasCredentials: true };
+      const saved = { AWS_REGION: process.env.AWS_REGION, AWS_DEFAULT_REGION: process.env.AWS_DEFAULT_REGION };
+      try {
+        process.env.AWS_DEFAULT_REGION = 'ap-southeast-2';
+        delete process.env.AWS_REGION;
+        expect(resolveProfileRegion(profile)).toBe('ap-southeast-2');
+
+        process.env.AWS_REGION = 'ca-central-1';
+        expect(resolveProfileRegion(profile)).toBe('ca-central-1');
+        (getProfile as Mock).mockReturnValue(profile);
+        expect((getS3Client('default') as any).config.region).toBe('ca-central-1');
+
+        // The environment wins over the profile's own setting, as in the CLI
+        expect(resolveProfileRegion({ ...profile, region: 'eu-west-1' })).toBe('ca-central-1');
+        delete process.env.AWS_REGION;
+        delete process.env.AWS_DEFAULT_REGION;
+        expect(resolveProfileRegion({ ...profile, region: 'eu-west-1' })).toBe('eu-west-1');
+      } finally {
+        for (const [name, value] of Object.entries(saved)) {
+          if (value === undefined) delete process.env[name];
+          else process.env[name] = value;
+        }
+      }
+    });
+
     it('should configure S3 client with followRegionRedirects for cross-region bucket access', () => {
       (getProfile as Mock).mockReturnValue({
         name: 'default',
@@ -227,6 +393,205 @@ describe('s3Service', () => {
       expect(client1).not.toBe(client2);
     });
 
+    it('should rebuild the client with request logging when it is turned on', () => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+
+      const plainClient = getS3Client('default');
+      expect((plainClient as any).middlewareStack.add).not.toHaveBeenCalled();
+
+      try {
+        setRequestLogging(true);
+        const loggingClient = getS3Client('default');
+
+        expect(isRequestLoggingEnabled()).toBe(true);
+        expect(loggingClient).not.toBe(plainClient);
+        expect((loggingClient as any).middlewareStack.add).toHaveBeenCalledWith(
+          expect.any(Function),
+          expect.objectContaining({ step: 'deserialize', name: 'requestLoggingMiddleware' })
+        );
+      } finally {
+        setRequestLogging(false);
+      }
+    });
+
+    it('should mark reads as requester-paid when Requester Pays is on', async () => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+
+      try {
+        setRequesterPays(true);
+        const client = getS3Client('default');
+        const [middleware, options] = (client as any).middlewareStack.add.mock.calls[0];
+        expect(options).toEqual({ step: 'initialize', name: 'requesterPaysMiddleware' });
+
+        const next = vi.fn().mockResolvedValue({});
+        await middleware(next, { commandName: 'GetObjectCommand' })({ input: { Bucket: 'b', Key: 'k' } });
+        await middleware(next, { commandName: 'PutObjectCommand' })({ input: { Bucket: 'b', Key: 'k' } });
+
+        expect(next.mock.calls[0][0].input).toEqual({ Bucket: 'b', Key: 'k', RequestPayer: 'requester' });
+        expect(next.mock.calls[1][0].input).toEqual({ Bucket: 'b', Key: 'k' });
+      } finally {
+        setRequesterPays(false);
+      }
+    });
+
+    it('should rebuild the client with the configured retry count', () => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+
+      const defaultClient = getS3Client('default');
+      expect((defaultClient as any).config).not.toHaveProperty('maxAttempts');
+
+      try {
+        setMaxRetries(5);
+        const retryingClient = getS3Client('default');
+
+        expect(getMaxRetries()).toBe(5);
+        expect(retryingClient).not.toBe(defaultClient);
+        // The first attempt counts towards maxAttempts
+        expect((retryingClient as any).config.maxAttempts).toBe(6);
+
+        setMaxRetries(0);
+        expect((getS3Client('default') as any).config.maxAttempts).toBe(1);
+      } finally {
+        setMaxRetries(null);
+      }
+      expect((getS3Client('default') as any).config).not.toHaveProperty('maxAttempts');
+    });
+
+    it('should pass configured timeouts to the request handler', () => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+
+      expect((getS3Client('default') as any).config).not.toHaveProperty('requestHandler');
+
+      try {
+        setRequestTimeouts({ connectTimeoutMs: 5000, requestTimeoutMs: null });
+        expect((getS3Client('default') as any).config.requestHandler).toEqual({ connectionTimeout: 5000 });
+
+        setRequestTimeouts({ connectTimeoutMs: 5000, requestTimeoutMs: 60000 });
+        expect((getS3Client('default') as any).config.requestHandler).toEqual({
+          connectionTimeout: 5000,
+          requestTimeout: 60000,
+        });
+        expect(getRequestTimeouts()).toEqual({ connectTimeoutMs: 5000, requestTimeoutMs: 60000 });
+      } finally {
+        setRequestTimeouts({ connectTimeoutMs: null, requestTimeoutMs: null });
+      }
+      expect((getS3Client('default') as any).config).not.toHaveProperty('requestHandler');
+    });
+
+    it('should route requests through the configured proxy', () => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+
+      try {
+        setProxyUrl('http://proxy.corp.example:3128');
+        const { requestHandler } = (getS3Client('default') as any).config;
+
+        expect(getProxyUrl()).toBe('http://proxy.corp.example:3128');
+        expect(requestHandler.httpsAgent).toBeInstanceOf(ProxyAgent);
+        expect(requestHandler.httpAgent).toBe(requestHandler.httpsAgent);
+        expect(requestHandler.httpsAgent.proxy.href).toBe('http://proxy.corp.example:3128/');
+      } finally {
+        setProxyUrl(null);
+      }
+    });
+
+    it('should send credential requests through the configured proxy', () => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'sso-dev',
+        profileType: 'sso',
+        hasCredentials: true,
+      });
+
+      try {
+        setProxyUrl('http://proxy.corp.example:3128');
+        const { credentials } = (getS3Client('sso-dev') as any).config;
+
+        expect(credentials.fromIni.clientConfig.requestHandler.httpsAgent).toBeInstanceOf(ProxyAgent);
+      } finally {
+        setProxyUrl(null);
+      }
+    });
+
+    it('should pass the MFA code provider to the profile credentials', async () => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'mfa-static',
+        accessKeyId: 'AKIA',
+        secretAccessKey: 'secret',
+        profileType: 'static',
+        hasCredentials: true,
+      });
+      const provider = vi.fn().mockResolvedValue('123456');
+
+      try {
+        const { credentials: withoutProvider } = (getS3Client('mfa-static') as any).config;
+        expect(withoutProvider.fromIni).toEqual({ profile: 'mfa-static' });
+
+        setMfaCodeProvider(provider);
+        const { credentials } = (getS3Client('mfa-static') as any).config;
+        expect(credentials.fromIni.profile).toBe('mfa-static');
+        expect(await credentials.fromIni.mfaCodeProvider('arn:aws:iam::123456789012:mfa/user')).toBe('123456');
+        expect(provider).toHaveBeenCalledWith('arn:aws:iam::123456789012:mfa/user');
+      } finally {
+        setMfaCodeProvider(null);
+      }
+    });
+
+    it('should assume the role chain of role profiles, with the MFA code provider', async () => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'mfa-role',
+        roleArn: 'arn:aws:iam::123456789012:role/Admin',
+        sourceProfile: 'base',
+        mfaSerial: 'arn:aws:iam::123456789012:mfa/user',
+        profileType: 'role',
+        hasCredentials: true,
+      });
+      const provider = vi.fn().mockResolvedValue('123456');
+
+      try {
+        const { credentials: withoutProvider } = (getS3Client('mfa-role') as any).config;
+        expect(withoutProvider).toEqual({ roleChain: 'mfa-role', options: {} });
+
+        setMfaCodeProvider(provider);
+        const { credentials } = (getS3Client('mfa-role') as any).config;
+        expect(credentials.roleChain).toBe('mfa-role');
+        expect(await credentials.options.mfaCodeProvider('arn:aws:iam::123456789012:mfa/user')).toBe('123456');
+        expect(provider).toHaveBeenCalledWith('arn:aws:iam::123456789012:mfa/user');
+      } finally {
+        setMfaCodeProvider(null);
+      }
+    });
+
+    it('should ignore invalid retry counts', () => {
+      setMaxRetries(-1);
+      expect(getMaxRetries()).toBeNull();
+      setMaxRetries(1.5);
+      expect(getMaxRetries()).toBeNull();
+    });
+
     it('should throw error for non-existent profile', () => {
       (getProfile as Mock).mockReturnValue(null);
 
@@ -349,6 +714,158 @@ describe('s3Service', () => {
     });
   });
 
+  describe('bucket regions', () => {
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+      clearS3Client();
+    });
+
+    it('should include regions reported by the bucket listing', async () => {
+      mockSend.mockResolvedValue({
+        Buckets: [
+          { Name: 'eu-bucket', BucketRegion: 'eu-central-1' },
+          { Name: 'old-bucket' },
+        ],
+      });
+
+      const buckets = await listBuckets('default');
+
+      expect(buckets).toEqual([
+        { name: 'eu-bucket', creationDate: undefined, region: 'eu-central-1' },
+        { name: 'old-bucket', creationDate: undefined, region: undefined },
+      ]);
+      // The listed region is remembered
+      mockSend.mockClear();
+      expect(await getBucketRegion('default', 'eu-bucket')).toEqual({ success: true, region: 'eu-central-1' });
+      expect(mockSend).not.toHaveBeenCalled();
+    });
+
+    it('should look up the region with HeadBucket and cache it', async () => {
+      mockSend.mockResolvedValueOnce({ BucketRegion: 'ap-southeast-2' });
+
+      expect(await getBucketRegion('default', 'sydney-bucket')).toEqual({ success: true, region: 'ap-southeast-2' });
+      expect(await getBucketRegion('default', 'sydney-bucket')).toEqual({ success: true, region: 'ap-southeast-2' });
+      expect(mockSend).toHaveBeenCalledTimes(1);
+      expect(mockSend.mock.calls[0][0]).toMatchObject({ type: 'HeadBucket', input: { Bucket: 'sydney-bucket' } });
+    });
+
+    it('should read the region from a refused HeadBucket', async () => {
+      mockSend.mockRejectedValueOnce(
+        Object.assign(new Error('Forbidden'), {
+          name: 'Forbidden',
+          $response: { headers: { 'x-amz-bucket-region': 'us-west-2' } },
+        })
+      );
+
+      expect(await getBucketRegion('default', 'other-account-bucket')).toEqual({ success: true, region: 'us-west-2' });
+    });
+
+    it('should fall back to GetBucketLocation', async () => {
+      mockSend.mockRejectedValueOnce(new Error('Not supported')).mockResolvedValueOnce({ LocationConstraint: '' });
+
+      const result = await getBucketRegion('default', 'virginia-bucket');
+
+      expect(result).toEqual({ success: true, region: 'us-east-1' });
+      expect(mockSend.mock.calls[1][0]).toMatchObject({ type: 'GetBucketLocation' });
+    });
+
+    it('should report failure when no lookup succeeds', async () => {
+      mockSend.mockRejectedValue(new Error('Access Denied'));
+
+      const result = await getBucketRegion('default', 'secret-bucket');
+
+      expect(result).toEqual({ success: false, error: 'Access Denied' });
+    });
+
+    it('should report an accessible bucket in the profile region as ok', async () => {
+      mockSend.mockResolvedValueOnce({ BucketRegion: 'eu-west-1' });
+
+      expect(await checkBucketAccess('default', 'home-bucket')).toEqual({
+        success: true,
+        status: { access: 'ok', region: 'eu-west-1' },
+      });
+    });
+
+    it('should tell missing, refused and misplaced buckets apart', async () => {
+      const headError = (name: string, httpStatusCode: number, region?: string) =>
+        Object.assign(new Error(name), {
+          name,
+          $metadata: { httpStatusCode },
+          $response: { headers: region ? { 'x-amz-bucket-region': region } : {} },
+        });
+      mockSend
+        .mockRejectedValueOnce(headError('NotFound', 404))
+        .mockRejectedValueOnce(headError('Forbidden', 403, 'us-west-2'))
+        .mockRejectedValueOnce(headError('PermanentRedirect', 301, 'ap-south-1'));
+
+      expect((await checkBucketAccess('default', 'gone')).status).toEqual({ access: 'not-found' });
+      expect((await checkBucketAccess('default', 'private')).status).toEqual({
+        access: 'access-denied',
+        region: 'us-west-2',
+      });
+      expect((await checkBucketAccess('default', 'mumbai')).status).toEqual({
+        access: 'wrong-region',
+        region: 'ap-south-1',
+      });
+
+      // The reported region is remembered for later requests
+      mockSend.mockClear();
+      expect(await getBucketRegion('default', 'mumbai')).toEqual({ success: true, region: 'ap-south-1' });
+      expect(mockSend).not.toHaveBeenCalled();
+    });
+
+    it('should report a bucket reached through a redirect as in the wrong region', async () => {
+      mockSend.mockResolvedValueOnce({ BucketRegion: 'us-east-2' });
+
+      expect((await checkBucketAccess('default', 'ohio-bucket')).status).toEqual({
+        access: 'wrong-region',
+        region: 'us-east-2',
+      });
+    });
+
+    it('should fail when HeadBucket fails for another reason', async () => {
+      mockSend.mockRejectedValueOnce(new Error('socket hang up'));
+
+      expect(await checkBucketAccess('default', 'flaky')).toEqual({ success: false, error: 'socket hang up' });
+    });
+
+    it('should explain bucket statuses', () => {
+      expect(describeBucketStatus('b', { access: 'ok' })).toBeNull();
+      expect(describeBucketStatus('b', { access: 'not-found' })).toBe('Bucket "b" does not exist');
+      expect(describeBucketStatus('b', { access: 'access-denied' })).toBe(
+        'Access to bucket "b" is denied for this profile'
+      );
+      expect(describeBucketStatus('b', { access: 'wrong-region', region: 'us-east-2' })).toBe(
+        "Bucket \"b\" is in region us-east-2, not the profile's region"
+      );
+    });
+
+    it('should recognize errors caused by a request to the wrong region', () => {
+      expect(isRegionMismatchError({ name: 'PermanentRedirect' })).toBe(true);
+      expect(isRegionMismatchError({ name: 'AuthorizationHeaderMalformed' })).toBe(true);
+      expect(isRegionMismatchError({ name: 'Unknown', $metadata: { httpStatusCode: 301 } })).toBe(true);
+    });
+
+    it('should not blame the region for other errors', () => {
+      expect(isRegionMismatchError({ name: 'SlowDown', $metadata: { httpStatusCode: 503 } })).toBe(false);
+      expect(isRegionMismatchError({ name: 'AccessDenied', $metadata: { httpStatusCode: 403 } })).toBe(false);
+      expect(isRegionMismatchError(new Error('socket hang up'))).toBe(false);
+      expect(isRegionMismatchError(null)).toBe(false);
+    });
+
+    it('should normalize location constraints', () => {
+      expect(normalizeLocationConstraint(undefined)).toBe('us-east-1');
+      expect(normalizeLocationConstraint(null)).toBe('us-east-1');
+      expect(normalizeLocationConstraint('EU')).toBe('eu-west-1');
+      expect(normalizeLocationConstraint('eu-north-1')).toBe('eu-north-1');
+    });
+  });
+
   describe('listObjects', () => {
     beforeEach(() => {
       (getProfile as Mock).mockReturnValue({
@@ -413,6 +930,34 @@ describe('s3Service', () => {
       );
     });
 
+    it('should omit the delimiter when listing recursively', async () => {
+      mockSend.mockResolvedValue({
+        Contents: [
+          { Key: 'logs/2024/01/a.log', Size: 10 },
+          { Key: 'logs/2024/02/b.log', Size: 20 },
+        ],
+        IsTruncated: true,
+        NextContinuationToken: 'next-page',
+        KeyCount: 2,
+      });
+
+      const result = await listObjects('default', {
+        bucket: 'test-bucket',
+        prefix: 'logs/',
+        delimiter: '/',
+        recursive: true,
+        continuationToken: 'this-page',
+      });
+
+      expect(ListObjectsV2Command).toHaveBeenCalledWith(
+        expect.objectContaining({ Prefix: 'logs/', Delimiter: undefined, ContinuationToken: 'this-page' })
+      );
+      expect(result.objects.map(obj => obj.key)).toEqual(['logs/2024/01/a.log', 'logs/2024/02/b.log']);
+      expect(result.prefixes).toEqual([]);
+      expect(result.isTruncated).toBe(true);
+      expect(result.continuationToken).toBe('next-page');
+    });
+
     it('should handle pagination with continuation token', async () => {
       mockSend.mockResolvedValue({
         Contents: [{ Key: 'file1.txt', Size: 100 }],
@@ -428,6 +973,36 @@ describe('s3Service', () => {
       expect(result.continuationToken).toBe('token123');
     });
 
+    it('should end the listing when a truncated page has no continuation token', async () => {
+      const warn = vi.spyOn(console, 'warn').mockImplementation(() => {});
+      mockSend.mockResolvedValue({
+        Contents: [{ Key: 'file1.txt', Size: 100 }],
+        IsTruncated: true,
+        KeyCount: 1,
+      });
+
+      const result = await listObjects('default', { bucket: 'test-bucket' });
+
+      expect(result.isTruncated).toBe(false);
+      expect(result.continuationToken).toBeUndefined();
+      expect(warn).toHaveBeenCalled();
+      warn.mockRestore();
+    });
+
+    it('should still follow a continuation token on a page that claims not to be truncated', async () => {
+      mockSend.mockResolvedValue({
+        Contents: [{ Key: 'file1.txt', Size: 100 }],
+        IsTruncated: false,
+        NextContinuationToken: 'token123',
+        KeyCount: 1,
+      });
+
+      const result = await listObjects('default', { bucket: 'test-bucket' });
+
+      expect(result.isTruncated).toBe(true);
+      expect(result.continuationToken).toBe('token123');
+    });
+
     it('should pass continuation token in request', async () => {
       mockSend.mockResolvedValue({
         Contents: [],
@@ -565,6 +1140,28 @@ describe('s3Service', () => {
       expect(result.continuationToken).toBeUndefined();
     });
 
+    it('should not loop when a page returns the token it was requested with', async () => {
+      const warn = vi.spyOn(console, 'warn').mockImplementation(() => {});
+      mockSend.mockResolvedValueOnce({
+        Contents: [{ Key: 'file1.txt', Size: 100 }],
+        IsTruncated: true,
+        NextContinuationToken: 'stuck',
+        KeyCount: 1,
+      });
+      mockSend.mockResolvedValue({
+        Contents: [{ Key: 'file2.txt', Size: 100 }],
+        IsTruncated: true,
+        NextContinuationToken: 'stuck',
+        KeyCount: 1,
+      });
+
+      const result = await listAllObjects('default', { bucket: 'test-bucket' });
+
+      expect(result.objects.map(o => o.key)).toEqual(['file1.txt', 'file2.txt']);
+      expect(mockSend).toHaveBeenCalledTimes(2);
+      warn.mockRestore();
+    });
+
     it('should call progress callback', async () => {
       mockSend.mockResolvedValueOnce({
         Contents: [{ Key: 'file1.txt', Size: 100 }],
@@ -605,25 +1202,239 @@ describe('s3Service', () => {
         listAllObjects('default', { bucket: 'test-bucket' }, undefined, abortController.signal)
       ).rejects.toThrow('Operation aborted');
     });
-  });
-
-  describe('parseS3Url', () => {
-    it('should parse s3:// URL with key', () => {
-      const result = parseS3Url('s3://my-bucket/path/to/file.txt');
 
-      expect(result).toEqual({
-        bucket: 'my-bucket',
-        key: 'path/to/file.txt',
+    it('reports the page request aborted mid-flight as an aborted operation', async () => {
+      const abortController = new AbortController();
+      mockSend.mockImplementationOnce(() => {
+        abortController.abort();
+        const error = new Error('The operation was aborted');
+        error.name = 'AbortError';
+        return Promise.reject(error);
       });
+
+      await expect(
+        listAllObjects('default', { bucket: 'test-bucket' }, undefined, abortController.signal)
+      ).rejects.toThrow('Operation aborted');
+      expect(mockSend).toHaveBeenCalledTimes(1);
     });
 
-    it('should parse s3:// URL without key', () => {
-      const result = parseS3Url('s3://my-bucket');
+    it('does not send a page request once the signal has fired', async () => {
+      const abortController = new AbortController();
+      abortController.abort();
 
-      expect(result).toEqual({
-        bucket: 'my-bucket',
-        key: '',
-      });
+      await expect(
+        listObjects('default', { bucket: 'test-bucket' }, abortController.signal)
+      ).rejects.toThrow('Operation aborted');
+      expect(mockSend).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('iterateObjects', () => {
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+    });
+
+    it('should yield folders and objects page by page', async () => {
+      mockSend
+        .mockResolvedValueOnce({
+          Contents: [{ Key: 'a.txt', Size: 1 }],
+          CommonPrefixes: [{ Prefix: 'logs/' }],
+          IsTruncated: true,
+          NextContinuationToken: 'page2',
+          KeyCount: 2,
+        })
+        .mockResolvedValueOnce({ Contents: [{ Key: 'b.txt', Size: 2 }], IsTruncated: false, KeyCount: 1 });
+
+      const keys: string[] = [];
+      for await (const object of iterateObjects('default', { bucket: 'test-bucket', delimiter: '/' })) {
+        keys.push(object.key);
+      }
+
+      expect(keys).toEqual(['logs/', 'a.txt', 'b.txt']);
+      expect(mockSend).toHaveBeenCalledTimes(2);
+      expect(mockSend.mock.calls[1][0].input).toMatchObject({ ContinuationToken: 'page2' });
+    });
+
+    it('should not request further pages once the caller stops', async () => {
+      mockSend.mockResolvedValue({
+        Contents: [{ Key: 'a.txt', Size: 1 }, { Key: 'b.txt', Size: 1 }],
+        IsTruncated: true,
+        NextContinuationToken: 'more',
+        KeyCount: 2,
+      });
+
+      for await (const object of iterateObjects('default', { bucket: 'test-bucket' })) {
+        if (object.key === 'a.txt') break;
+      }
+
+      expect(mockSend).toHaveBeenCalledTimes(1);
+    });
+
+    it('should stop with an abort error when the signal aborts between pages', async () => {
+      const controller = new AbortController();
+      mockSend.mockImplementationOnce(async () => {
+        controller.abort();
+        return { Contents: [{ Key: 'a.txt', Size: 1 }], IsTruncated: true, NextContinuationToken: 'more', KeyCount: 1 };
+      });
+
+      const seen: string[] = [];
+      await expect(async () => {
+        for await (const object of iterateObjects('default', { bucket: 'test-bucket' }, controller.signal)) {
+          seen.push(object.key);
+        }
+      }).rejects.toThrow('Operation aborted');
+      expect(seen).toEqual(['a.txt']);
+    });
+  });
+
+  describe('resolvePagination', () => {
+    beforeEach(() => {
+      vi.spyOn(console, 'warn').mockImplementation(() => {});
+    });
+
+    afterEach(() => {
+      vi.restoreAllMocks();
+    });
+
+    it('passes consistent pages through', () => {
+      expect(resolvePagination(true, 'next')).toEqual({ isTruncated: true, continuationToken: 'next' });
+      expect(resolvePagination(false, undefined)).toEqual({ isTruncated: false });
+      expect(resolvePagination(undefined, undefined)).toEqual({ isTruncated: false });
+    });
+
+    it('treats a truncated page without a token as the end of the listing', () => {
+      expect(resolvePagination(true, undefined)).toEqual({ isTruncated: false });
+      expect(resolvePagination(true, '')).toEqual({ isTruncated: false });
+      expect(console.warn).toHaveBeenCalledTimes(2);
+    });
+
+    it('follows a token even when the page is not marked truncated', () => {
+      expect(resolvePagination(false, 'next')).toEqual({ isTruncated: true, continuationToken: 'next' });
+      expect(resolvePagination(undefined, 'next', 'previous')).toEqual({
+        isTruncated: true,
+        continuationToken: 'next',
+      });
+    });
+
+    it('ends the listing when the token repeats the one requested', () => {
+      expect(resolvePagination(true, 'same', 'same')).toEqual({ isTruncated: false });
+      expect(console.warn).toHaveBeenCalledTimes(1);
+    });
+  });
+
+  describe('collapseSingleChildChain', () => {
+    // Hive-style partitioned layout: each level has a single partition until day=
+    const listings: Record<string, { Contents?: Array<{ Key: string; Size: number }>; CommonPrefixes?: Array<{ Prefix: string }> }> = {
+      'events/': { CommonPrefixes: [{ Prefix: 'events/year=2024/' }] },
+      'events/year=2024/': { CommonPrefixes: [{ Prefix: 'events/year=2024/month=01/' }] },
+      'events/year=2024/month=01/': {
+        // Console-created folder placeholder does not count as a child
+        Contents: [{ Key: 'events/year=2024/month=01/', Size: 0 }],
+        CommonPrefixes: [{ Prefix: 'events/year=2024/month=01/day=05/' }],
+      },
+      'events/year=2024/month=01/day=05/': {
+        CommonPrefixes: [
+          { Prefix: 'events/year=2024/month=01/day=05/hour=00/' },
+          { Prefix: 'events/year=2024/month=01/day=05/hour=01/' },
+        ],
+      },
+      'logs/': { CommonPrefixes: [{ Prefix: 'logs/2024/' }] },
+      'logs/2024/': {
+        Contents: [{ Key: 'logs/2024/summary.txt', Size: 10 }],
+        CommonPrefixes: [{ Prefix: 'logs/2024/01/' }],
+      },
+    };
+
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+      mockSend.mockImplementation((command: { input: { Prefix?: string } }) =>
+        Promise.resolve({ ...(listings[command.input.Prefix ?? ''] ?? {}), IsTruncated: false })
+      );
+    });
+
+    it('should follow single subfolders down to the first folder with several children', async () => {
+      const result = await collapseSingleChildChain('default', 'test-bucket', 'events/');
+
+      expect(result).toBe('events/year=2024/month=01/day=05/');
+      expect(mockSend).toHaveBeenCalledTimes(4);
+      expect(mockSend.mock.calls[0][0].input).toMatchObject({ Delimiter: '/', MaxKeys: 2 });
+    });
+
+    it('should stop at a folder that also contains files', async () => {
+      const result = await collapseSingleChildChain('default', 'test-bucket', 'logs/');
+
+      expect(result).toBe('logs/2024/');
+    });
+
+    it('should return the prefix unchanged when it has several children', async () => {
+      const result = await collapseSingleChildChain(
+        'default',
+        'test-bucket',
+        'events/year=2024/month=01/day=05/'
+      );
+
+      expect(result).toBe('events/year=2024/month=01/day=05/');
+      expect(mockSend).toHaveBeenCalledTimes(1);
+    });
+
+    it('should not descend further than maxDepth', async () => {
+      const result = await collapseSingleChildChain('default', 'test-bucket', 'events/', 2);
+
+      expect(result).toBe('events/year=2024/month=01/');
+      expect(mockSend).toHaveBeenCalledTimes(2);
+    });
+
+    it('should stop when a listing is truncated', async () => {
+      mockSend.mockReset();
+      mockSend.mockResolvedValueOnce({
+        CommonPrefixes: [{ Prefix: 'big/a/' }],
+        IsTruncated: true,
+        NextContinuationToken: 'more',
+      });
+
+      const result = await collapseSingleChildChain('default', 'test-bucket', 'big/');
+
+      expect(result).toBe('big/');
+    });
+
+    it('should handle abort signal', async () => {
+      const abortController = new AbortController();
+      abortController.abort();
+
+      await expect(
+        collapseSingleChildChain('default', 'test-bucket', 'events/', undefined, abortController.signal)
+      ).rejects.toThrow('Operation aborted');
+      expect(mockSend).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('parseS3Url', () => {
+    it('should parse s3:// URL with key', () => {
+      const result = parseS3Url('s3://my-bucket/path/to/file.txt');
+
+      expect(result).toEqual({
+        bucket: 'my-bucket',
+        key: 'path/to/file.txt',
+      });
+    });
+
+    it('should parse s3:// URL without key', () => {
+      const result = parseS3Url('s3://my-bucket');
+
+      expect(result).toEqual({
+        bucket: 'my-bucket',
+        key: '',
+      });
     });
 
     it('should parse s3:// URL with trailing slash', () => {
@@ -687,6 +1498,116 @@ describe('s3Service', () => {
     });
   });
 
+  describe('parseS3UrlList', () => {
+    it('should parse one URL per line and skip blank or invalid lines', () => {
+      const result = parseS3UrlList(
+        's3://my-bucket/a.txt\n\n  s3://my-bucket/b.txt  \r\nnot a url\nhttps://my-bucket.s3.amazonaws.com/c.txt'
+      );
+
+      expect(result).toEqual([
+        { bucket: 'my-bucket', key: 'a.txt' },
+        { bucket: 'my-bucket', key: 'b.txt' },
+        { bucket: 'my-bucket', key: 'c.txt' },
+      ]);
+    });
+
+    it('should drop duplicate URLs', () => {
+      const result = parseS3UrlList('s3://my-bucket/a.txt\ns3://my-bucket/a.txt');
+
+      expect(result).toEqual([{ bucket: 'my-bucket', key: 'a.txt' }]);
+    });
+
+    it('should return empty array for text without URLs', () => {
+      expect(parseS3UrlList('')).toEqual([]);
+      expect(parseS3UrlList('hello\nworld')).toEqual([]);
+    });
+  });
+
+  describe('getRedirectTarget', () => {
+    it('should resolve a relative location to a key in the same bucket', () => {
+      expect(getRedirectTarget('my-bucket', '/docs/new%20page.html')).toEqual({
+        bucket: 'my-bucket',
+        key: 'docs/new page.html',
+      });
+    });
+
+    it('should ignore the query string and fragment', () => {
+      expect(getRedirectTarget('my-bucket', '/index.html?lang=en#top')).toEqual({
+        bucket: 'my-bucket',
+        key: 'index.html',
+      });
+    });
+
+    it('should resolve website endpoint URLs, including dotted bucket names', () => {
+      expect(getRedirectTarget('my-bucket', 'http://www.example.com.s3-website-us-east-1.amazonaws.com/a/b.html')).toEqual({
+        bucket: 'www.example.com',
+        key: 'a/b.html',
+      });
+      expect(getRedirectTarget('my-bucket', 'https://site.s3-website.eu-west-1.amazonaws.com')).toEqual({
+        bucket: 'site',
+        key: '',
+      });
+    });
+
+    it('should resolve S3 REST and s3:// URLs', () => {
+      expect(getRedirectTarget('my-bucket', 'https://other.s3.us-west-2.amazonaws.com/x%2By.txt')).toEqual({
+        bucket: 'other',
+        key: 'x+y.txt',
+      });
+      expect(getRedirectTarget('my-bucket', 's3://other/file.txt')).toEqual({ bucket: 'other', key: 'file.txt' });
+    });
+
+    it('should return null for locations outside S3', () => {
+      expect(getRedirectTarget('my-bucket', 'https://example.com/page')).toBeNull();
+      expect(getRedirectTarget('my-bucket', 'docs/page.html')).toBeNull();
+      expect(getRedirectTarget('my-bucket', '')).toBeNull();
+      expect(getRedirectTarget('my-bucket', undefined)).toBeNull();
+    });
+  });
+
+  describe('getCommonPrefix', () => {
+    it('should return the shared folder for files in the same prefix', () => {
+      const result = getCommonPrefix([
+        { bucket: 'my-bucket', key: 'data/2024/report-a.csv' },
+        { bucket: 'my-bucket', key: 'data/2024/report-b.csv' },
+      ]);
+
+      // "report-" is a shared name fragment, not a folder
+      expect(result).toEqual({ bucket: 'my-bucket', prefix: 'data/2024/' });
+    });
+
+    it('should return the deepest shared folder for nested keys', () => {
+      const result = getCommonPrefix([
+        { bucket: 'my-bucket', key: 'data/2024/a.csv' },
+        { bucket: 'my-bucket', key: 'data/2025/b.csv' },
+      ]);
+
+      expect(result).toEqual({ bucket: 'my-bucket', prefix: 'data/' });
+    });
+
+    it('should return root prefix when keys share no folder', () => {
+      const result = getCommonPrefix([
+        { bucket: 'my-bucket', key: 'a.csv' },
+        { bucket: 'my-bucket', key: 'logs/b.csv' },
+      ]);
+
+      expect(result).toEqual({ bucket: 'my-bucket', prefix: '' });
+    });
+
+    it('should return null for mixed buckets', () => {
+      const result = getCommonPrefix([
+        { bucket: 'bucket-a', key: 'data/a.csv' },
+        { bucket: 'bucket-b', key: 'data/a.csv' },
+      ]);
+
+      expect(result).toBeNull();
+    });
+
+    it('should return null for an empty list', () => {
+      expect(getCommonPrefix([])).toBeNull();
+    });
+  });
+
   describe('getParentPrefix', () => {
     it('should return parent prefix for nested key', () => {
       expect(getParentPrefix('folder/subfolder/file.txt')).toBe('folder/subfolder/');
@@ -783,6 +1704,111 @@ describe('s3Service', () => {
       expect(result.success).toBe(false);
       expect(result.error).toBe('Upload failed');
     });
+
+    it('should store an explicit content type instead of the guessed one', async () => {
+      mockSend.mockResolvedValue({});
+
+      await uploadContent('default', 'test-bucket', 'data.json', '{}', { contentType: 'application/schema+json' });
+
+      expect(PutObjectCommand).toHaveBeenCalledWith(
+        expect.objectContaining({ ContentType: 'application/schema+json' })
+      );
+    });
+
+    it('should send a SHA-256 checksum when asked to', async () => {
+      mockSend.mockResolvedValue({});
+
+      await uploadContent('default', 'test-bucket', 'file.txt', 'Hello World', { checksumSha256: true });
+
+      expect(PutObjectCommand).toHaveBeenCalledWith(
+        expect.objectContaining({ ChecksumAlgorithm: 'SHA256', ChecksumSHA256: 'pZGm1Av0IEBKARczz7exkNYsZb8LzaMrV7J32a2fFG4=' })
+      );
+    });
+
+    it('should not send a checksum by default', async () => {
+      mockSend.mockResolvedValue({});
+
+      await uploadContent('default', 'test-bucket', 'file.txt', 'Hello World');
+
+      expect(mockSend.mock.calls[0][0].input).not.toHaveProperty('ChecksumAlgorithm');
+    });
+  });
+
+  describe('uploadFile', () => {
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+    });
+
+    it('should set the content type from the extension', async () => {
+      mockSend.mockResolvedValue({});
+
+      const result = await uploadFile('default', 'test-bucket', 'images/photo.PNG', '/tmp/photo.PNG');
+
+      expect(result.success).toBe(true);
+      expect(PutObjectCommand).toHaveBeenCalledWith(
+        expect.objectContaining({ Key: 'images/photo.PNG', ContentType: 'image/png' })
+      );
+    });
+
+    it('should prefer an explicit content type', async () => {
+      mockSend.mockResolvedValue({});
+
+      await uploadFile('default', 'test-bucket', 'report', '/tmp/report', undefined, undefined, {
+        contentType: 'text/html; charset=utf-8',
+      });
+
+      expect(PutObjectCommand).toHaveBeenCalledWith(
+        expect.objectContaining({ ContentType: 'text/html; charset=utf-8' })
+      );
+    });
+
+    it('should write the object in the requested storage class', async () => {
+      mockSend.mockResolvedValue({});
+
+      await uploadFile('default', 'test-bucket', 'archive.zip', '/tmp/archive.zip', undefined, undefined, {
+        storageClass: 'GLACIER_IR',
+      });
+
+      expect(PutObjectCommand).toHaveBeenCalledWith(expect.objectContaining({ StorageClass: 'GLACIER_IR' }));
+    });
+
+    it('should store user metadata with the object', async () => {
+      mockSend.mockResolvedValue({});
+
+      await uploadFile('default', 'test-bucket', 'a.txt', '/tmp/a.txt', undefined, undefined, {
+        metadata: { 'original-name': 'A.TXT', sha256: 'abc123' },
+      });
+
+      expect(PutObjectCommand).toHaveBeenCalledWith(
+        expect.objectContaining({ Metadata: { 'original-name': 'A.TXT', sha256: 'abc123' } })
+      );
+    });
+
+    it('should reject metadata S3 would mangle without uploading', async () => {
+      const upload = (metadata: Record<string, string>) =>
+        uploadFile('default', 'test-bucket', 'a.txt', '/tmp/a.txt', undefined, undefined, { metadata });
+
+      expect((await upload({ 'caf\u00e9': 'x' })).error).toBe('Metadata key "caf\u00e9" must be printable ASCII without spaces');
+      expect((await upload({ 'my key': 'x' })).error).toBe('Metadata key "my key" must be printable ASCII without spaces');
+      expect((await upload({ note: 'line one\nline two' })).error).toBe(
+        'Metadata value for "note" cannot contain line breaks'
+      );
+      expect(mockSend).not.toHaveBeenCalled();
+    });
+
+    it('should reject an unknown storage class without uploading', async () => {
+      const result = await uploadFile('default', 'test-bucket', 'a.txt', '/tmp/a.txt', undefined, undefined, {
+        storageClass: 'COLD' as never,
+      });
+
+      expect(result).toEqual({ success: false, error: 'Unknown storage class: COLD' });
+      expect(mockSend).not.toHaveBeenCalled();
+    });
   });
 
   describe('downloadContent', () => {
@@ -824,41 +1850,93 @@ describe('s3Service', () => {
     });
   });
 
-  describe('deleteFile', () => {
-    beforeEach(() => {
+  describe('downloadFile', () => {
+    let written: Buffer[];
+
+    beforeEach(async () => {
       (getProfile as Mock).mockReturnValue({
         name: 'default',
         accessKeyId: 'AKIATEST',
         secretAccessKey: 'secretkey',
         hasCredentials: true,
       });
+      // Stream into memory through the real pipeline
+      const actual = await vi.importActual<typeof import('stream/promises')>('stream/promises');
+      (pipeline as Mock).mockImplementationOnce(actual.pipeline);
+      written = [];
+      (fs.createWriteStream as Mock).mockReturnValueOnce(
+        new Writable({
+          write(chunk: Buffer, _encoding, callback) {
+            written.push(chunk);
+            callback();
+          },
+        })
+      );
     });
 
-    it('should delete file from S3', async () => {
-      mockSend.mockResolvedValue({});
+    it('streams the body to a partial file and renames it into place', async () => {
+      mockSend
+        .mockResolvedValueOnce({ ContentLength: 11 })
+        .mockResolvedValueOnce({ ContentLength: 11, Body: Readable.from([Buffer.from('Hello '), Buffer.from('World')]) });
+      const onProgress = vi.fn();
 
-      const result = await deleteFile('default', 'test-bucket', 'file.txt');
+      const result = await downloadFile('default', 'test-bucket', 'file.txt', '/downloads/file.txt', onProgress);
 
-      expect(result.success).toBe(true);
-      expect(DeleteObjectCommand).toHaveBeenCalledWith(
-        expect.objectContaining({
-          Bucket: 'test-bucket',
-          Key: 'file.txt',
-        })
-      );
+      expect(result).toEqual({ success: true, bytesWritten: 11 });
+      expect(Buffer.concat(written).toString()).toBe('Hello World');
+      expect(fs.createWriteStream).toHaveBeenCalledWith('/downloads/file.txt.part');
+      expect(fs.promises.rename).toHaveBeenCalledWith('/downloads/file.txt.part', '/downloads/file.txt');
+      expect(onProgress).toHaveBeenLastCalledWith({ loaded: 11, total: 11, percentage: 100 });
     });
 
-    it('should return error on failure', async () => {
-      mockSend.mockRejectedValue(new Error('Access denied'));
+    it('removes the partial file when the stream fails midway', async () => {
+      const body = new Readable({ read() {} });
+      mockSend.mockResolvedValueOnce({ ContentLength: 100 }).mockResolvedValueOnce({ ContentLength: 100, Body: body });
+      body.push(Buffer.from('partial'));
+      setTimeout(() => body.destroy(new Error('socket hang up')), 0);
 
-      const result = await deleteFile('default', 'test-bucket', 'file.txt');
+      const result = await downloadFile('default', 'test-bucket', 'big.bin', '/downloads/big.bin');
 
-      expect(result.success).toBe(false);
-      expect(result.error).toBe('Access denied');
+      expect(result).toEqual({ success: false, error: 'socket hang up' });
+      expect(fs.promises.unlink).toHaveBeenCalledWith('/downloads/big.bin.part');
+      expect(fs.promises.rename).not.toHaveBeenCalled();
+    });
+
+    it('fails when the body ends before the expected length', async () => {
+      mockSend
+        .mockResolvedValueOnce({ ContentLength: 20 })
+        .mockResolvedValueOnce({ ContentLength: 20, Body: Readable.from([Buffer.from('only ten b')]) });
+
+      const result = await downloadFile('default', 'test-bucket', 'file.txt', '/downloads/file.txt');
+
+      expect(result).toEqual({ success: false, error: 'Download incomplete: received 10 of 20 bytes' });
+      expect(fs.promises.unlink).toHaveBeenCalledWith('/downloads/file.txt.part');
+      expect(fs.promises.rename).not.toHaveBeenCalled();
+    });
+
+    it('stops a body already streaming when the signal fires', async () => {
+      const body = new Readable({ read() {} });
+      mockSend.mockResolvedValueOnce({ ContentLength: 100 }).mockResolvedValueOnce({ ContentLength: 100, Body: body });
+      body.push(Buffer.from('partial'));
+      const abortController = new AbortController();
+      setTimeout(() => abortController.abort(), 0);
+
+      const result = await downloadFile(
+        'default',
+        'test-bucket',
+        'big.bin',
+        '/downloads/big.bin',
+        undefined,
+        abortController.signal
+      );
+
+      expect(result).toEqual({ success: false, error: 'Operation aborted' });
+      expect(fs.promises.unlink).toHaveBeenCalledWith('/downloads/big.bin.part');
+      expect(fs.promises.rename).not.toHaveBeenCalled();
     });
   });
 
-  describe('renameFile', () => {
+  describe('downloadFiles', () => {
     beforeEach(() => {
       (getProfile as Mock).mockReturnValue({
         name: 'default',
@@ -868,10 +1946,178 @@ describe('s3Service', () => {
       });
     });
 
-    it('should copy and delete the original file', async () => {
-      mockSend.mockResolvedValue({});
+    it('keeps no more than the concurrency limit in flight and reports every key in order', async () => {
+      let inFlight = 0;
+      let maxInFlight = 0;
+      mockSend.mockImplementation(async (command: { input: { Key: string } }) => {
+        inFlight++;
+        maxInFlight = Math.max(maxInFlight, inFlight);
+        await new Promise(resolve => setTimeout(resolve, 1));
+        inFlight--;
+        throw new Error(`missing ${command.input.Key}`);
+      });
+      const downloads = Array.from({ length: 10 }, (_, i) => ({ key: `f${i}.txt`, destinationPath: `/downloads/f${i}.txt` }));
 
-      const result = await renameFile('default', 'test-bucket', 'old.txt', 'new.txt');
+      const results = await downloadFiles('default', 'test-bucket', downloads, 3);
+
+      expect(maxInFlight).toBe(3);
+      expect(results.map(r => r.key)).toEqual(downloads.map(d => d.key));
+      expect(results.every(r => !r.success)).toBe(true);
+      expect(results[4].error).toContain('missing f4.txt');
+    });
+
+    it('does not start downloads once the signal has aborted', async () => {
+      const controller = new AbortController();
+      controller.abort();
+
+      const results = await downloadFiles(
+        'default',
+        'test-bucket',
+        [{ key: 'a.txt', destinationPath: '/downloads/a.txt' }],
+        8,
+        controller.signal
+      );
+
+      expect(results).toEqual([{ key: 'a.txt', success: false, error: 'Operation aborted' }]);
+      expect(mockSend).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('downloadPartialContent', () => {
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+    });
+
+    it('formats closed and open-ended ranges', () => {
+      expect(formatByteRange(0, 1023)).toBe('bytes=0-1023');
+      expect(formatByteRange(4096)).toBe('bytes=4096-');
+      expect(() => formatByteRange(-1)).toThrow('Invalid range start');
+      expect(() => formatByteRange(10, 5)).toThrow('Invalid range end');
+    });
+
+    it('requests the leading bytes by default', async () => {
+      mockSend.mockResolvedValue({ ContentRange: 'bytes 0-4/11', Body: Readable.from([Buffer.from('Hello')]) });
+
+      const result = await downloadPartialContent('default', 'test-bucket', 'file.txt', 5);
+
+      expect(GetObjectCommand).toHaveBeenCalledWith({ Bucket: 'test-bucket', Key: 'file.txt', Range: 'bytes=0-4' });
+      expect(result.data?.toString()).toBe('Hello');
+    });
+
+    it('requests the range starting at the given offset', async () => {
+      mockSend.mockResolvedValue({ ContentRange: 'bytes 6-10/11', Body: Readable.from([Buffer.from('World')]) });
+
+      const result = await downloadPartialContent('default', 'test-bucket', 'file.txt', 5, 6);
+
+      expect(GetObjectCommand).toHaveBeenCalledWith({ Bucket: 'test-bucket', Key: 'file.txt', Range: 'bytes=6-10' });
+      expect(result.data?.toString()).toBe('World');
+    });
+
+    it('requests the rest of the object when no length is given', async () => {
+      mockSend.mockResolvedValue({ ContentRange: 'bytes 6-10/11', Body: Readable.from([Buffer.from('World')]) });
+
+      const result = await downloadPartialContent('default', 'test-bucket', 'file.txt', undefined, 6);
+
+      expect(GetObjectCommand).toHaveBeenCalledWith({ Bucket: 'test-bucket', Key: 'file.txt', Range: 'bytes=6-' });
+      expect(result.data?.toString()).toBe('World');
+    });
+
+    it('slices the range out of servers that ignore the Range header', async () => {
+      mockSend.mockResolvedValue({ Body: Readable.from([Buffer.from('Hello World')]) });
+
+      const result = await downloadPartialContent('default', 'test-bucket', 'file.txt', 3, 6);
+
+      expect(result.data?.toString()).toBe('Wor');
+    });
+
+    it('returns no bytes for an empty object', async () => {
+      mockSend.mockRejectedValue(
+        Object.assign(new Error('The requested range is not satisfiable'), { name: 'InvalidRange' })
+      );
+
+      const result = await downloadPartialContent('default', 'test-bucket', 'empty.txt', 512);
+
+      expect(result.success).toBe(true);
+      expect(result.data?.length).toBe(0);
+    });
+
+    it('reports a range past the end of the object clearly', async () => {
+      mockSend.mockRejectedValue(
+        Object.assign(new Error('The requested range is not satisfiable'), {
+          name: 'InvalidRange',
+          $metadata: { httpStatusCode: 416 },
+        })
+      );
+
+      const result = await downloadPartialContent('default', 'test-bucket', 'small.bin', 8, 5000);
+
+      expect(result).toEqual({
+        success: false,
+        error: 'Range not satisfiable: bytes=5000-5007 starts past the end of small.bin',
+      });
+    });
+
+    it('rejects invalid offsets without calling S3', async () => {
+      const result = await downloadPartialContent('default', 'test-bucket', 'file.txt', 8, 1.5);
+
+      expect(result).toEqual({ success: false, error: 'Invalid range start: 1.5' });
+      expect(mockSend).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('deleteFile', () => {
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+    });
+
+    it('should delete file from S3', async () => {
+      mockSend.mockResolvedValue({});
+
+      const result = await deleteFile('default', 'test-bucket', 'file.txt');
+
+      expect(result.success).toBe(true);
+      expect(DeleteObjectCommand).toHaveBeenCalledWith(
+        expect.objectContaining({
+          Bucket: 'test-bucket',
+          Key: 'file.txt',
+        })
+      );
+    });
+
+    it('should return error on failure', async () => {
+      mockSend.mockRejectedValue(new Error('Access denied'));
+
+      const result = await deleteFile('default', 'test-bucket', 'file.txt');
+
+      expect(result.success).toBe(false);
+      expect(result.error).toBe('Access denied');
+    });
+  });
+
+  describe('renameFile', () => {
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+    });
+
+    it('should copy and delete the original file', async () => {
+      mockSend.mockResolvedValue({});
+
+      const result = await renameFile('default', 'test-bucket', 'old.txt', 'new.txt');
 
       expect(result.success).toBe(true);
       expect(CopyObjectCommand).toHaveBeenCalledWith(
@@ -909,6 +2155,121 @@ describe('s3Service', () => {
       expect(result.success).toBe(false);
       expect(result.error).toBe('Copy failed');
     });
+
+    it('should carry the storage class over to the renamed object', async () => {
+      mockSend.mockImplementation((command: { type: string }) =>
+        Promise.resolve(command.type === 'HeadObject' ? { StorageClass: 'GLACIER_IR' } : {})
+      );
+
+      await renameFile('default', 'test-bucket', 'old.txt', 'new.txt');
+
+      expect(CopyObjectCommand).toHaveBeenCalledWith(
+        expect.objectContaining({ Key: 'new.txt', MetadataDirective: 'COPY', StorageClass: 'GLACIER_IR' })
+      );
+    });
+  });
+
+  describe('timeout errors', () => {
+    function timeoutError(message: string): Error {
+      return Object.assign(new Error(message), { name: 'TimeoutError' });
+    }
+
+    it('should recognise timeouts from the HTTP handler', () => {
+      expect(isTimeoutError(timeoutError('Connection timed out after 60000 ms'))).toBe(true);
+      expect(isTimeoutError(new Error('socket hang up'))).toBe(false);
+    });
+
+    it('should describe timeouts distinctly from other failures', () => {
+      const message = describeS3Error(
+        timeoutError('Socket timed out without establishing a connection within 5000 ms')
+      );
+
+      expect(message).toBe('Request timed out: Socket timed out without establishing a connection within 5000 ms');
+    });
+
+    it('should surface timeouts through operation results', async () => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+      mockSend.mockRejectedValue(timeoutError('Connection timed out after 60000 ms'));
+
+      const result = await getFileSize('default', 'test-bucket', 'file.txt');
+
+      expect(result.error).toBe('Request timed out: Connection timed out after 60000 ms');
+    });
+  });
+
+  describe('clock skew errors', () => {
+    const now = Date.parse('2024-06-01T12:30:00Z');
+
+    function skewError(name: string, fields: Record<string, unknown> = {}): Error {
+      const error = new Error('The difference between the request time and the current time is too large.');
+      error.name = name;
+      return Object.assign(error, fields);
+    }
+
+    it('should measure skew from the ServerTime field', () => {
+      const error = skewError('RequestTimeTooSkewed', { ServerTime: '2024-06-01T12:00:00Z' });
+
+      expect(getClockSkewMs(error, now)).toBe(30 * 60 * 1000);
+    });
+
+    it('should fall back to the response Date header', () => {
+      const error = skewError('SignatureDoesNotMatch', {
+        $response: { headers: { date: 'Sat, 01 Jun 2024 13:00:00 GMT' } },
+      });
+
+      expect(getClockSkewMs(error, now)).toBe(-30 * 60 * 1000);
+    });
+
+    it('should return null without a server time', () => {
+      expect(getClockSkewMs(skewError('RequestTimeTooSkewed'), now)).toBeNull();
+      expect(getClockSkewMs('oops', now)).toBeNull();
+    });
+
+    it('should report the skew magnitude for RequestTimeTooSkewed', () => {
+      const error = skewError('RequestTimeTooSkewed', { ServerTime: '2024-06-01T12:00:00Z' });
+
+      const message = describeS3Error(error, now);
+
+      expect(message).toMatch(/^RequestTimeTooSkewed: /);
+      expect(message).toContain('(clock skew: system clock is 30 minutes ahead of S3)');
+    });
+
+    it('should treat a signature mismatch with a skewed Date header as clock skew', () => {
+      const error = skewError('SignatureDoesNotMatch', {
+        $response: { headers: { date: 'Sat, 01 Jun 2024 13:00:00 GMT' } },
+      });
+
+      expect(describeS3Error(error, now)).toContain('system clock is 30 minutes behind S3');
+    });
+
+    it('should keep a signature mismatch without skew as a plain error', () => {
+      const error = skewError('SignatureDoesNotMatch', {
+        $response: { headers: { date: 'Sat, 01 Jun 2024 12:30:05 GMT' } },
+      });
+      error.message = 'The request signature we calculated does not match the signature you provided.';
+
+      expect(describeS3Error(error, now)).toBe(error.message);
+    });
+
+    it('should surface skew through operation results', async () => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+      mockSend.mockRejectedValue(skewError('RequestTimeTooSkewed', { ServerTime: '2000-01-01T00:00:00Z' }));
+
+      const result = await copyFile('default', 'test-bucket', 'a.txt', 'test-bucket', 'b.txt');
+
+      expect(result.success).toBe(false);
+      expect(result.error).toMatch(/^RequestTimeTooSkewed: .*\(clock skew: system clock is \d+ minutes ahead of S3\)$/);
+    });
   });
 
   describe('copyFile', () => {
@@ -976,6 +2337,38 @@ describe('s3Service', () => {
       expect(result.success).toBe(false);
       expect(result.error).toBe('Bucket not found');
     });
+
+    it('should keep the metadata, tags and storage class of the source', async () => {
+      mockSend.mockImplementation((command: { type: string }) =>
+        Promise.resolve(command.type === 'HeadObject' ? { StorageClass: 'STANDARD_IA' } : {})
+      );
+
+      const result = await copyFile('default', 'test-bucket', 'report.csv', 'test-bucket', 'copy.csv');
+
+      expect(result.success).toBe(true);
+      expect(CopyObjectCommand).toHaveBeenCalledWith(
+        expect.objectContaining({
+          MetadataDirective: 'COPY',
+          TaggingDirective: 'COPY',
+          StorageClass: 'STANDARD_IA',
+        })
+      );
+    });
+
+    it('should copy only the content when metadata is not preserved', async () => {
+      mockSend.mockResolvedValue({});
+
+      await copyFile('default', 'test-bucket', 'report.bin', 'test-bucket', 'copy.csv', { preserveMetadata: false });
+
+      expect(CopyObjectCommand).toHaveBeenCalledWith(
+        expect.objectContaining({
+          MetadataDirective: 'REPLACE',
+          TaggingDirective: 'REPLACE',
+          ContentType: 'text/csv',
+        })
+      );
+      expect(HeadObjectCommand).not.toHaveBeenCalled();
+    });
   });
 
   describe('getFileSize', () => {
@@ -1022,7 +2415,7 @@ describe('s3Service', () => {
     });
   });
 
-  describe('getObjectMetadata', () => {
+  describe('headObject', () => {
     beforeEach(() => {
       (getProfile as Mock).mockReturnValue({
         name: 'default',
@@ -1032,87 +2425,381 @@ describe('s3Service', () => {
       });
     });
 
-    it('should return metadata for an object', async () => {
-      // First call: HeadObject
-      mockSend.mockResolvedValueOnce({
-        ContentLength: 1234567,
-        ContentType: 'text/plain',
-        LastModified: new Date('2024-01-15T10:30:00Z'),
-        ETag: '"abc123def456"',
-        StorageClass: 'STANDARD',
-        VersionId: 'v123',
-        ServerSideEncryption: 'AES256',
-        Metadata: { 'custom-key': 'custom-value' },
-      });
-      // Second call: GetObjectTagging
-      mockSend.mockResolvedValueOnce({
-        TagSet: [
-          { Key: 'environment', Value: 'production' },
-          { Key: 'owner', Value: 'team-a' },
-        ],
+    it('should return the object headers without fetching tags', async () => {
+      mockSend.mockResolvedValue({
+        ContentLength: 2048,
+        ContentType: 'application/json',
+        ETag: '"abc123"',
+        LastModified: new Date('2024-03-01T12:00:00Z'),
+        StorageClass: 'STANDARD_IA',
       });
 
-      const result = await getObjectMetadata('default', 'test-bucket', 'folder/file.txt');
+      const result = await headObject('default', 'test-bucket', 'data.json');
 
-      expect(result.success).toBe(true);
-      expect(result.metadata).toBeDefined();
-      expect(result.metadata!.key).toBe('folder/file.txt');
-      expect(result.metadata!.bucket).toBe('test-bucket');
-      expect(result.metadata!.s3Url).toBe('s3://test-bucket/folder/file.txt');
-      expect(result.metadata!.contentLength).toBe(1234567);
-      expect(result.metadata!.contentType).toBe('text/plain');
-      expect(result.metadata!.etag).toBe('abc123def456');
-      expect(result.metadata!.storageClass).toBe('STANDARD');
-      expect(result.metadata!.versionId).toBe('v123');
-      expect(result.metadata!.serverSideEncryption).toBe('AES256');
-      expect(result.metadata!.tags).toEqual({ environment: 'production', owner: 'team-a' });
-      expect(result.metadata!.customMetadata).toEqual({ 'custom-key': 'custom-value' });
+      expect(result).toEqual({
+        success: true,
+        head: {
+          contentLength: 2048,
+          contentType: 'application/json',
+          etag: 'abc123',
+          lastModified: new Date('2024-03-01T12:00:00Z'),
+          storageClass: 'STANDARD_IA',
+        },
+      });
+      expect(mockSend).toHaveBeenCalledTimes(1);
     });
 
-    it('should handle missing tags gracefully', async () => {
-      // First call: HeadObject
-      mockSend.mockResolvedValueOnce({
-        ContentLength: 1000,
-        ContentType: 'application/json',
+    it('should report a missing object as not found', async () => {
+      const error = Object.assign(new Error('UnknownError'), {
+        name: 'NotFound',
+        $metadata: { httpStatusCode: 404 },
       });
-      // Second call: GetObjectTagging fails (access denied)
-      mockSend.mockRejectedValueOnce(new Error('Access denied'));
+      mockSend.mockRejectedValue(error);
 
-      const result = await getObjectMetadata('default', 'test-bucket', 'file.json');
+      const result = await headObject('default', 'test-bucket', 'missing.txt');
 
-      expect(result.success).toBe(true);
-      expect(result.metadata!.tags).toEqual({});
-      expect(result.metadata!.contentLength).toBe(1000);
+      expect(result.success).toBe(false);
+      expect(result.notFound).toBe(true);
+      expect(result.error).toBe('Object not found: s3://test-bucket/missing.txt');
     });
 
-    it('should generate correct URLs', async () => {
-      mockSend.mockResolvedValueOnce({ ContentLength: 100 });
-      mockSend.mockResolvedValueOnce({ TagSet: [] });
+    it('should not report other errors as not found', async () => {
+      mockSend.mockRejectedValue(
+        Object.assign(new Error('Access Denied'), { name: 'AccessDenied', $metadata: { httpStatusCode: 403 } })
+      );
 
-      const result = await getObjectMetadata('default', 'my-bucket', 'path/to/file.txt');
+      const result = await headObject('default', 'test-bucket', 'secret.txt');
 
-      expect(result.metadata!.s3Url).toBe('s3://my-bucket/path/to/file.txt');
-      expect(result.metadata!.httpUrl).toBe('https://my-bucket.s3.amazonaws.com/path%2Fto%2Ffile.txt');
+      expect(result.success).toBe(false);
+      expect(result.notFound).toBeUndefined();
     });
 
-    it('should return error on HeadObject failure', async () => {
-      mockSend.mockRejectedValue(new Error('Object not found'));
+    it('should pass not found through getFileSize', async () => {
+      mockSend.mockRejectedValue(Object.assign(new Error('UnknownError'), { name: 'NotFound' }));
 
-      const result = await getObjectMetadata('default', 'test-bucket', 'nonexistent.txt');
+      const result = await getFileSize('default', 'test-bucket', 'missing.txt');
 
       expect(result.success).toBe(false);
-      expect(result.error).toBe('Object not found');
+      expect(result.notFound).toBe(true);
     });
+  });
 
-    it('should handle empty TagSet', async () => {
-      mockSend.mockResolvedValueOnce({ ContentLength: 500 });
-      mockSend.mockResolvedValueOnce({ TagSet: [] });
-
-      const result = await getObjectMetadata('default', 'test-bucket', 'file.txt');
-
-      expect(result.success).toBe(true);
-      expect(result.metadata!.tags).toEqual({});
-    });
+  describe('restoreObject', () => {
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+    });
+
+    it('requests a restore for the given days and tier', async () => {
+      mockSend.mockResolvedValue({});
+
+      const result = await restoreObject('default', 'test-bucket', 'archive/2019.tar', 5, 'Bulk');
+
+      expect(result).toEqual({ success: true });
+      expect(mockSend).toHaveBeenCalledWith({
+        type: 'RestoreObject',
+        input: {
+          Bucket: 'test-bucket',
+          Key: 'archive/2019.tar',
+          RestoreRequest: { Days: 5, GlacierJobParameters: { Tier: 'Bulk' } },
+        },
+      });
+    });
+
+    it('rejects invalid days and tiers without a request', async () => {
+      expect(await restoreObject('default', 'b', 'k', 0, 'Standard')).toEqual({
+        success: false,
+        error: 'Restore days must be a positive whole number',
+      });
+      expect(await restoreObject('default', 'b', 'k', 1.5, 'Standard')).toMatchObject({ success: false });
+      expect(await restoreObject('default', 'b', 'k', 1, 'Fast' as never)).toEqual({
+        success: false,
+        error: 'Unknown restore tier: Fast',
+      });
+      expect(mockSend).not.toHaveBeenCalled();
+    });
+
+    it('explains a restore that is already running', async () => {
+      mockSend.mockRejectedValue(
+        Object.assign(new Error('Object restore is already in progress'), { name: 'RestoreAlreadyInProgress' })
+      );
+
+      const result = await restoreObject('default', 'test-bucket', 'archive/2019.tar', 1, 'Standard');
+
+      expect(result).toEqual({ success: false, error: 'A restore of this object is already in progress' });
+    });
+
+    it('reads the restore state from the HEAD response', async () => {
+      mockSend.mockResolvedValue({
+        ContentLength: 10,
+        StorageClass: 'GLACIER',
+        Restore: 'ongoing-request="false", expiry-date="Fri, 21 Dec 2012 00:00:00 GMT"',
+      });
+
+      const result = await getRestoreStatus('default', 'test-bucket', 'archive/2019.tar');
+
+      expect(result).toEqual({
+        success: true,
+        storageClass: 'GLACIER',
+        restore: { ongoing: false, expiry: new Date('2012-12-21T00:00:00Z') },
+      });
+    });
+
+    it('parses in-progress and completed restore headers', () => {
+      expect(parseRestoreHeader('ongoing-request="true"')).toEqual({ ongoing: true });
+      expect(parseRestoreHeader('ongoing-request="false", expiry-date="Fri, 21 Dec 2012 00:00:00 GMT"')).toEqual({
+        ongoing: false,
+        expiry: new Date('2012-12-21T00:00:00Z'),
+      });
+      expect(parseRestoreHeader(undefined)).toBeNull();
+      expect(parseRestoreHeader('garbage')).toBeNull();
+    });
+
+    it('only treats Glacier Flexible Retrieval and Deep Archive as archived', () => {
+      expect(isArchivedStorageClass('GLACIER')).toBe(true);
+      expect(isArchivedStorageClass('DEEP_ARCHIVE')).toBe(true);
+      expect(isArchivedStorageClass('GLACIER_IR')).toBe(false);
+      expect(isArchivedStorageClass('STANDARD')).toBe(false);
+      expect(isArchivedStorageClass(undefined)).toBe(false);
+    });
+
+    it('tells downloads of archived objects that a restore is needed', () => {
+      const error = Object.assign(new Error('The operation is not valid for the object\'s storage class'), {
+        name: 'InvalidObjectState',
+      });
+      expect(describeS3Error(error)).toBe(
+        "The operation is not valid for the object's storage class (the object is archived; restore it before downloading)"
+      );
+    });
+  });
+
+  describe('presignGetUrl', () => {
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+    });
+
+    it('should sign a GetObject request with the requested expiry', async () => {
+      (getSignedUrl as Mock).mockResolvedValue('https://test-bucket.s3.amazonaws.com/file.txt?X-Amz-Expires=3600');
+
+      const result = await presignGetUrl('default', 'test-bucket', 'file.txt', 3600);
+
+      expect(result).toEqual({ success: true, url: 'https://test-bucket.s3.amazonaws.com/file.txt?X-Amz-Expires=3600' });
+      expect(GetObjectCommand).toHaveBeenCalledWith({ Bucket: 'test-bucket', Key: 'file.txt' });
+      expect(getSignedUrl).toHaveBeenCalledWith(expect.anything(), expect.anything(), { expiresIn: 3600 });
+      expect(mockSend).not.toHaveBeenCalled();
+    });
+
+    it('should accept the 7-day maximum', async () => {
+      (getSignedUrl as Mock).mockResolvedValue('https://signed');
+
+      const result = await presignGetUrl('default', 'test-bucket', 'file.txt', MAX_PRESIGN_EXPIRY_SECONDS);
+
+      expect(result.success).toBe(true);
+    });
+
+    it('should reject expiries beyond 7 days without signing', async () => {
+      const result = await presignGetUrl('default', 'test-bucket', 'file.txt', MAX_PRESIGN_EXPIRY_SECONDS + 1);
+
+      expect(result.success).toBe(false);
+      expect(result.error).toBe('Presigned links can be valid for at most 7 days');
+      expect(getSignedUrl).not.toHaveBeenCalled();
+    });
+
+    it('should reject non-positive expiries', async () => {
+      expect((await presignGetUrl('default', 'test-bucket', 'file.txt', 0)).success).toBe(false);
+      expect((await presignGetUrl('default', 'test-bucket', 'file.txt', 1.5)).success).toBe(false);
+      expect(getSignedUrl).not.toHaveBeenCalled();
+    });
+
+    it('should return signing errors', async () => {
+      (getSignedUrl as Mock).mockRejectedValue(new Error('Could not load credentials'));
+
+      const result = await presignGetUrl('default', 'test-bucket', 'file.txt', 60);
+
+      expect(result).toEqual({ success: false, error: 'Could not load credentials' });
+    });
+  });
+
+  describe('presignPutUrl', () => {
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+      (getSignedUrl as Mock).mockResolvedValue('https://signed-put');
+    });
+
+    it('should sign a PutObject request with the requested expiry', async () => {
+      const result = await presignPutUrl('default', 'test-bucket', 'incoming/data.csv', 900);
+
+      expect(result).toEqual({ success: true, url: 'https://signed-put' });
+      expect(PutObjectCommand).toHaveBeenCalledWith({ Bucket: 'test-bucket', Key: 'incoming/data.csv' });
+      expect(getSignedUrl).toHaveBeenCalledWith(expect.anything(), expect.anything(), { expiresIn: 900 });
+    });
+
+    it('should sign the content type into the request', async () => {
+      await presignPutUrl('default', 'test-bucket', 'incoming/data.csv', 900, 'text/csv');
+
+      expect(PutObjectCommand).toHaveBeenCalledWith({
+        Bucket: 'test-bucket',
+        Key: 'incoming/data.csv',
+        ContentType: 'text/csv',
+      });
+    });
+
+    it('should pass keys with spaces and unicode to the signer unencoded', async () => {
+      // The signer encodes the path itself; encoding here would sign a different key
+      await presignPutUrl('default', 'test-bucket', 'reports/Q1 résumé 📄.pdf', 900);
+
+      expect(PutObjectCommand).toHaveBeenCalledWith({ Bucket: 'test-bucket', Key: 'reports/Q1 résumé 📄.pdf' });
+    });
+
+    it('should share the 7-day limit with download links', async () => {
+      const result = await presignPutUrl('default', 'test-bucket', 'file.txt', MAX_PRESIGN_EXPIRY_SECONDS + 1);
+
+      expect(result.success).toBe(false);
+      expect(result.error).toBe('Presigned links can be valid for at most 7 days');
+      expect(getSignedUrl).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('isNotFoundError', () => {
+    it('should recognise NotFound, NoSuchKey and 404 errors', () => {
+      expect(isNotFoundError({ name: 'NotFound' })).toBe(true);
+      expect(isNotFoundError({ name: 'NoSuchKey' })).toBe(true);
+      expect(isNotFoundError({ Code: 'NoSuchKey' })).toBe(true);
+      expect(isNotFoundError({ name: 'Unknown', $metadata: { httpStatusCode: 404 } })).toBe(true);
+    });
+
+    it('should not treat other errors as not found', () => {
+      expect(isNotFoundError({ name: 'AccessDenied', $metadata: { httpStatusCode: 403 } })).toBe(false);
+      expect(isNotFoundError(new Error('Network failure'))).toBe(false);
+      expect(isNotFoundError(null)).toBe(false);
+    });
+  });
+
+  describe('getObjectMetadata', () => {
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+    });
+
+    it('should return metadata for an object', async () => {
+      // First call: HeadObject
+      mockSend.mockResolvedValueOnce({
+        ContentLength: 1234567,
+        ContentType: 'text/plain',
+        LastModified: new Date('2024-01-15T10:30:00Z'),
+        ETag: '"abc123def456"',
+        StorageClass: 'STANDARD',
+        VersionId: 'v123',
+        ServerSideEncryption: 'AES256',
+        Metadata: { 'custom-key': 'custom-value' },
+      });
+      // Second call: GetObjectTagging
+      mockSend.mockResolvedValueOnce({
+        TagSet: [
+          { Key: 'environment', Value: 'production' },
+          { Key: 'owner', Value: 'team-a' },
+        ],
+      });
+
+      const result = await getObjectMetadata('default', 'test-bucket', 'folder/file.txt');
+
+      expect(result.success).toBe(true);
+      expect(result.metadata).toBeDefined();
+      expect(result.metadata!.key).toBe('folder/file.txt');
+      expect(result.metadata!.bucket).toBe('test-bucket');
+      expect(result.metadata!.s3Url).toBe('s3://test-bucket/folder/file.txt');
+      expect(result.metadata!.contentLength).toBe(1234567);
+      expect(result.metadata!.contentType).toBe('text/plain');
+      expect(result.metadata!.etag).toBe('abc123def456');
+      expect(result.metadata!.storageClass).toBe('STANDARD');
+      expect(result.metadata!.versionId).toBe('v123');
+      expect(result.metadata!.serverSideEncryption).toBe('AES256');
+      expect(result.metadata!.tags).toEqual({ environment: 'production', owner: 'team-a' });
+      expect(result.metadata!.customMetadata).toEqual({ 'custom-key': 'custom-value' });
+    });
+
+    it('should handle missing tags gracefully', async () => {
+      // First call: HeadObject
+      mockSend.mockResolvedValueOnce({
+        ContentLength: 1000,
+        ContentType: 'application/json',
+      });
+      // Second call: GetObjectTagging fails (access denied)
+      mockSend.mockRejectedValueOnce(new Error('Access denied'));
+
+      const result = await getObjectMetadata('default', 'test-bucket', 'file.json');
+
+      expect(result.success).toBe(true);
+      expect(result.metadata!.tags).toEqual({});
+      expect(result.metadata!.contentLength).toBe(1000);
+    });
+
+    it('should generate correct URLs', async () => {
+      mockSend.mockResolvedValueOnce({ ContentLength: 100 });
+      mockSend.mockResolvedValueOnce({ TagSet: [] });
+
+      const result = await getObjectMetadata('default', 'my-bucket', 'path/to/file.txt');
+
+      expect(result.metadata!.s3Url).toBe('s3://my-bucket/path/to/file.txt');
+      expect(result.metadata!.httpUrl).toBe('https://my-bucket.s3.amazonaws.com/path/to/file.txt');
+    });
+
+    it('should report the part count of multipart ETags', async () => {
+      mockSend.mockResolvedValueOnce({ ContentLength: 100, ETag: '"d41d8cd98f00b204e9800998ecf8427e-4"' });
+      mockSend.mockResolvedValueOnce({ TagSet: [] });
+
+      const result = await getObjectMetadata('default', 'my-bucket', 'big.bin');
+
+      expect(result.metadata!.etag).toBe('d41d8cd98f00b204e9800998ecf8427e-4');
+      expect(result.metadata!.etagPartCount).toBe(4);
+    });
+
+    it('should include the website redirect and its target', async () => {
+      mockSend.mockResolvedValueOnce({ ContentLength: 0, WebsiteRedirectLocation: '/docs/current.html' });
+      mockSend.mockResolvedValueOnce({ TagSet: [] });
+
+      const result = await getObjectMetadata('default', 'my-bucket', 'docs/latest.html');
+
+      expect(result.metadata!.websiteRedirectLocation).toBe('/docs/current.html');
+      expect(result.metadata!.redirectTarget).toEqual({ bucket: 'my-bucket', key: 'docs/current.html' });
+    });
+
+    it('should return error on HeadObject failure', async () => {
+      mockSend.mockRejectedValue(new Error('Object not found'));
+
+      const result = await getObjectMetadata('default', 'test-bucket', 'nonexistent.txt');
+
+      expect(result.success).toBe(false);
+      expect(result.error).toBe('Object not found');
+    });
+
+    it('should handle empty TagSet', async () => {
+      mockSend.mockResolvedValueOnce({ ContentLength: 500 });
+      mockSend.mockResolvedValueOnce({ TagSet: [] });
+
+      const result = await getObjectMetadata('default', 'test-bucket', 'file.txt');
+
+      expect(result.success).toBe(true);
+      expect(result.metadata!.tags).toEqual({});
+    });
 
     it('should handle undefined TagSet', async () => {
       mockSend.mockResolvedValueOnce({ ContentLength: 500 });
@@ -1257,4 +2944,812 @@ describe('s3Service', () => {
       expect(result.error).toBe('Permission denied');
     });
   });
+
+  describe('validateBucketName', () => {
+    it('accepts valid names', () => {
+      for (const name of ['abc', 'my-bucket', 'logs.example.com', 'a1-b2.c3', 'x'.repeat(63)]) {
+        expect(validateBucketName(name)).toEqual({ valid: true });
+      }
+    });
+
+    it.each([
+      ['ab', 'between 3 and 63 characters'],
+      ['x'.repeat(64), 'between 3 and 63 characters'],
+      ['My-Bucket', 'lowercase letters, numbers'],
+      ['my_bucket', 'lowercase letters, numbers'],
+      ['my bucket', 'lowercase letters, numbers'],
+      ['-bucket', 'begin and end with a letter or number'],
+      ['bucket.', 'begin and end with a letter or number'],
+      ['my..bucket', 'two adjacent dots'],
+      ['192.168.5.4', 'IP address'],
+      ['xn--bucket', 'must not start with "xn--"'],
+      ['sthree-bucket', 'must not start with "sthree-"'],
+      ['bucket-s3alias', 'must not end with "-s3alias"'],
+      ['bucket--ol-s3', 'must not end with "--ol-s3"'],
+    ])('rejects %s', (name, reason) => {
+      const result = validateBucketName(name);
+      expect(result.valid).toBe(false);
+      expect(result.reason).toContain(reason);
+    });
+  });
+
+  describe('buildCreateBucketConfig', () => {
+    it('omits the location constraint for us-east-1', () => {
+      expect(buildCreateBucketConfig('us-east-1')).toBeUndefined();
+    });
+
+    it('omits the location constraint when no region is given', () => {
+      expect(buildCreateBucketConfig('')).toBeUndefined();
+    });
+
+    it('sets the location constraint for other regions', () => {
+      expect(buildCreateBucketConfig('eu-west-1')).toEqual({ LocationConstraint: 'eu-west-1' });
+      expect(buildCreateBucketConfig('ap-southeast-2')).toEqual({ LocationConstraint: 'ap-southeast-2' });
+    });
+  });
+
+  describe('createBucket', () => {
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        region: 'eu-west-1',
+        hasCredentials: true,
+      });
+    });
+
+    it('creates a bucket outside us-east-1 with a location constraint', async () => {
+      mockSend.mockResolvedValueOnce({});
+
+      const result = await createBucket('default', 'new-bucket', 'eu-central-1');
+
+      expect(result).toEqual({ success: true });
+      expect(mockSend).toHaveBeenCalledWith({
+        type: 'CreateBucket',
+        input: {
+          Bucket: 'new-bucket',
+          CreateBucketConfiguration: { LocationConstraint: 'eu-central-1' },
+        },
+      });
+    });
+
+    it('creates a bucket in us-east-1 without a configuration', async () => {
+      mockSend.mockResolvedValueOnce({});
+
+      await createBucket('default', 'new-bucket', 'us-east-1');
+
+      expect(mockSend).toHaveBeenCalledWith({
+        type: 'CreateBucket',
+        input: { Bucket: 'new-bucket', CreateBucketConfiguration: undefined },
+      });
+    });
+
+    it('rejects an invalid name without calling S3', async () => {
+      const result = await createBucket('default', 'Bad_Name', 'eu-west-1');
+
+      expect(result.success).toBe(false);
+      expect(result.error).toContain('lowercase letters');
+      expect(mockSend).not.toHaveBeenCalled();
+    });
+
+    it('returns the S3 error on failure', async () => {
+      mockSend.mockRejectedValueOnce(new Error('The requested bucket name is not available'));
+
+      const result = await createBucket('default', 'taken-bucket', 'eu-west-1');
+
+      expect(result).toEqual({ success: false, error: 'The requested bucket name is not available' });
+    });
+
+    it('tells a name taken by another account from one already owned', async () => {
+      mockSend.mockRejectedValueOnce(Object.assign(new Error('The requested bucket name is not available'), { name: 'BucketAlreadyExists' }));
+      const taken = await createBucket('default', 'taken-bucket', 'eu-west-1');
+      expect(taken.success).toBe(false);
+      expect(taken.failure).toBe('name-taken');
+      expect(taken.error).toContain('already taken');
+
+      mockSend.mockRejectedValueOnce(Object.assign(new Error('Your previous request succeeded'), { name: 'BucketAlreadyOwnedByYou' }));
+      const owned = await createBucket('default', 'my-bucket', 'eu-west-1');
+      expect(owned).toEqual({ success: false, failure: 'already-owned', error: 'You already own a bucket named "my-bucket"' });
+    });
+
+    it('reports invalid names as such, whether caught locally or by S3', async () => {
+      expect((await createBucket('default', 'ab', 'eu-west-1')).failure).toBe('invalid-name');
+
+      mockSend.mockRejectedValueOnce(Object.assign(new Error('The specified bucket is not valid.'), { name: 'InvalidBucketName' }));
+      const result = await createBucket('default', 'odd.bucket.name', 'eu-west-1');
+      expect(result).toEqual({ success: false, failure: 'invalid-name', error: 'The specified bucket is not valid.' });
+    });
+
+    it('creates the bucket in us-east-1 when no region is given', async () => {
+      mockSend.mockResolvedValueOnce({});
+
+      await createBucket('default', 'new-bucket');
+
+      expect(mockSend).toHaveBeenCalledWith({
+        type: 'CreateBucket',
+        input: { Bucket: 'new-bucket', CreateBucketConfiguration: undefined },
+      });
+    });
+
+    it('does not replace the cached client for the profile', async () => {
+      const cached = getS3Client('default');
+      mockSend.mockResolvedValueOnce({});
+
+      await createBucket('default', 'new-bucket', 'us-west-2');
+
+      expect(getS3Client('default')).toBe(cached);
+    });
+  });
+
+  describe('bucket public access', () => {
+    const awsError = (name: string) => Object.assign(new Error(name), { name });
+    const allBlocked = {
+      blockPublicAcls: true,
+      ignorePublicAcls: true,
+      blockPublicPolicy: true,
+      restrictPublicBuckets: true,
+    };
+    const noneBlocked = {
+      blockPublicAcls: false,
+      ignorePublicAcls: false,
+      blockPublicPolicy: false,
+      restrictPublicBuckets: false,
+    };
+
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        region: 'eu-west-1',
+        hasCredentials: true,
+      });
+    });
+
+    it('reads the Block Public Access configuration', async () => {
+      mockSend.mockResolvedValueOnce({
+        PublicAccessBlockConfiguration: { BlockPublicAcls: true, IgnorePublicAcls: true, BlockPublicPolicy: false },
+      });
+
+      const block = await getPublicAccessBlock('default', 'my-bucket');
+
+      expect(mockSend).toHaveBeenCalledWith({ type: 'GetPublicAccessBlock', input: { Bucket: 'my-bucket' } });
+      expect(block).toEqual({
+        blockPublicAcls: true,
+        ignorePublicAcls: true,
+        blockPublicPolicy: false,
+        restrictPublicBuckets: false,
+      });
+    });
+
+    it('treats a missing Block Public Access configuration as every setting off', async () => {
+      mockSend.mockRejectedValueOnce(awsError('NoSuchPublicAccessBlockConfiguration'));
+
+      await expect(getPublicAccessBlock('default', 'my-bucket')).resolves.toEqual(noneBlocked);
+    });
+
+    it('passes other errors through', async () => {
+      mockSend.mockRejectedValueOnce(awsError('AccessDenied'));
+
+      await expect(getPublicAccessBlock('default', 'my-bucket')).rejects.toThrow('AccessDenied');
+    });
+
+    describe('combinePublicStatus', () => {
+      it('is private when every Block Public Access setting is on, whatever else is known', () => {
+        expect(combinePublicStatus({ publicAccessBlock: allBlocked, policyPublic: true, aclPublic: null })).toBe(
+          'private'
+        );
+      });
+
+      it('is public when the policy or ACL grants public access', () => {
+        expect(combinePublicStatus({ publicAccessBlock: noneBlocked, policyPublic: true, aclPublic: false })).toBe(
+          'public-read'
+        );
+        expect(combinePublicStatus({ publicAccessBlock: noneBlocked, policyPublic: false, aclPublic: true })).toBe(
+          'public-read'
+        );
+        // A public grant is public even if another signal couldn't be read
+        expect(combinePublicStatus({ publicAccessBlock: null, policyPublic: true, aclPublic: null })).toBe(
+          'public-read'
+        );
+      });
+
+      it('ignores public grants that Block Public Access neutralizes', () => {
+        expect(
+          combinePublicStatus({
+            publicAccessBlock: { ...noneBlocked, ignorePublicAcls: true, restrictPublicBuckets: true },
+            policyPublic: true,
+            aclPublic: true,
+          })
+        ).toBe('private');
+      });
+
+      it('is mixed when object ACLs can still make objects public', () => {
+        expect(combinePublicStatus({ publicAccessBlock: noneBlocked, policyPublic: false, aclPublic: false })).toBe(
+          'mixed'
+        );
+      });
+
+      it('is unknown when a signal is missing and nothing is public', () => {
+        expect(combinePublicStatus({ publicAccessBlock: null, policyPublic: false, aclPublic: false })).toBe(
+          'unknown'
+        );
+        expect(combinePublicStatus({ publicAccessBlock: noneBlocked, policyPublic: null, aclPublic: false })).toBe(
+          'unknown'
+        );
+      });
+    });
+
+    it('combines the bucket settings into a status', async () => {
+      mockSend.mockImplementation((command: { type: string }) => {
+        switch (command.type) {
+          case 'GetPublicAccessBlock':
+            return Promise.reject(awsError('NoSuchPublicAccessBlockConfiguration'));
+          case 'GetBucketPolicyStatus':
+            return Promise.reject(awsError('NoSuchBucketPolicy'));
+          case 'GetBucketAcl':
+            return Promise.resolve({
+              Grants: [
+                { Grantee: { Type: 'CanonicalUser', ID: 'owner' }, Permission: 'FULL_CONTROL' },
+                { Grantee: { Type: 'Group', URI: 'http://acs.amazonaws.com/groups/global/AllUsers' }, Permission: 'READ' },
+              ],
+            });
+          default:
+            return Promise.reject(new Error(`Unexpected ${command.type}`));
+        }
+      });
+
+      const result = await getBucketPublicStatus('default', 'website-bucket');
+
+      expect(result).toEqual({
+        status: 'public-read',
+        publicAccessBlock: noneBlocked,
+        policyPublic: false,
+        aclPublic: true,
+      });
+    });
+
+    it('reports unknown when the settings cannot be read', async () => {
+      mockSend.mockRejectedValue(awsError('AccessDenied'));
+
+      const result = await getBucketPublicStatus('default', 'locked-bucket');
+
+      expect(result).toEqual({ status: 'unknown', publicAccessBlock: null, policyPublic: null, aclPublic: null });
+    });
+  });
+
+  describe('object tags', () => {
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+    });
+
+    it('reads the tag set as key/value pairs', async () => {
+      mockSend.mockResolvedValue({
+        TagSet: [
+          { Key: 'cost-center', Value: 'data-eng' },
+          { Key: 'retention', Value: '' },
+        ],
+      });
+
+      const result = await getObjectTags('default', 'test-bucket', 'data.parquet');
+
+      expect(result).toEqual({ success: true, tags: { 'cost-center': 'data-eng', retention: '' } });
+      expect(mockSend).toHaveBeenCalledWith({
+        type: 'GetObjectTagging',
+        input: { Bucket: 'test-bucket', Key: 'data.parquet' },
+      });
+    });
+
+    it('replaces the whole tag set', async () => {
+      mockSend.mockResolvedValue({});
+
+      const result = await putObjectTags('default', 'test-bucket', 'data.parquet', { team: 'a', tier: 'cold' });
+
+      expect(result).toEqual({ success: true });
+      expect(mockSend).toHaveBeenCalledWith({
+        type: 'PutObjectTagging',
+        input: {
+          Bucket: 'test-bucket',
+          Key: 'data.parquet',
+          Tagging: { TagSet: [{ Key: 'team', Value: 'a' }, { Key: 'tier', Value: 'cold' }] },
+        },
+      });
+    });
+
+    it('rejects tag sets S3 would refuse without a request', async () => {
+      const eleven = Object.fromEntries(Array.from({ length: 11 }, (_, i) => [`k${i}`, 'v']));
+
+      expect(await putObjectTags('default', 'b', 'k', eleven)).toEqual({
+        success: false,
+        error: 'Objects can have at most 10 tags',
+      });
+      expect(mockSend).not.toHaveBeenCalled();
+    });
+
+    it('validates keys and value lengths', () => {
+      expect(validateObjectTags({ a: 'b' })).toBeNull();
+      expect(validateObjectTags({ ' ': 'b' })).toBe('Tag keys must not be empty');
+      expect(validateObjectTags({ ['k'.repeat(129)]: 'v' })).toMatch(/longer than 128 characters/);
+      expect(validateObjectTags({ k: 'v'.repeat(257) })).toBe('The value of tag "k" is longer than 256 characters');
+      expect(validateObjectTags({ 'aws:createdBy': 'me' })).toBe('Tag "aws:createdBy" uses the reserved aws: prefix');
+      // Lengths are in characters, so 128 emoji (256 UTF-16 code units) still fit
+      expect(validateObjectTags({ ['\u{1F600}'.repeat(128)]: 'v' })).toBeNull();
+    });
+
+    it('returns the S3 error when tagging is denied', async () => {
+      mockSend.mockRejectedValue(new Error('Access Denied'));
+
+      expect(await putObjectTags('default', 'b', 'k', { a: 'b' })).toEqual({ success: false, error: 'Access Denied' });
+      expect(await getObjectTags('default', 'b', 'k')).toEqual({ success: false, error: 'Access Denied' });
+    });
+  });
+
+  describe('selectObjectContent', () => {
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+    });
+
+    async function* events(...items: object[]) {
+      yield* items;
+    }
+
+    it('should join the record events and report the bytes scanned', async () => {
+      mockSend.mockResolvedValueOnce({
+        Payload: events(
+          { Records: { Payload: Buffer.from('a,1\n') } },
+          { Records: { Payload: Buffer.from('b,2\n') } },
+          { Stats: { Details: { BytesScanned: 2048, BytesProcessed: 2048, BytesReturned: 8 } } },
+          { End: {} }
+        ),
+      });
+
+      const result = await selectObjectContent(
+        'default',
+        'test-bucket',
+        'data/events.csv',
+        'SELECT s.name, s.count FROM s3object s',
+        'csv',
+        'csv'
+      );
+
+      expect(result).toEqual({ success: true, records: 'a,1\nb,2\n', truncated: false, bytesScanned: 2048 });
+      expect(mockSend.mock.calls[0][0]).toMatchObject({
+        type: 'SelectObjectContent',
+        input: {
+          Bucket: 'test-bucket',
+          Key: 'data/events.csv',
+          Expression: 'SELECT s.name, s.count FROM s3object s',
+          ExpressionType: 'SQL',
+          InputSerialization: { CompressionType: 'NONE', CSV: { FileHeaderInfo: 'USE', FieldDelimiter: ',' } },
+          OutputSerialization: { CSV: {} },
+        },
+      });
+    });
+
+    it('should cut oversized results off at the last whole record', async () => {
+      const line = 'x'.repeat(1023) + '\n';
+      const chunk = Buffer.from(line.repeat(1024));
+      mockSend.mockResolvedValueOnce({
+        Payload: events(...Array.from({ length: 11 }, () => ({ Records: { Payload: chunk } }))),
+      });
+
+      const result = await selectObjectContent('default', 'test-bucket', 'big.json', 'SELECT * FROM s3object s', 'json', 'json');
+
+      expect(result.truncated).toBe(true);
+      expect(result.records!.length).toBe(MAX_SELECT_RESULT_BYTES);
+      expect(result.records!.endsWith('\n')).toBe(true);
+    });
+
+    it('should reject an empty expression without sending it', async () => {
+      const result = await selectObjectContent('default', 'test-bucket', 'a.csv', '  ', 'csv', 'json');
+
+      expect(result).toEqual({ success: false, error: 'Enter a SQL expression to run' });
+      expect(mockSend).not.toHaveBeenCalled();
+    });
+
+    it('should report query errors', async () => {
+      mockSend.mockRejectedValueOnce(new Error('Syntax error at line 1'));
+
+      const result = await selectObjectContent('default', 'test-bucket', 'a.csv', 'SELEC *', 'csv', 'csv');
+
+      expect(result).toEqual({ success: false, error: 'Syntax error at line 1' });
+    });
+
+    it('should read the format details from the key', () => {
+      expect(buildSelectInputSerialization('logs/a.tsv.gz', 'csv')).toEqual({
+        CompressionType: 'GZIP',
+        CSV: { FileHeaderInfo: 'USE', FieldDelimiter: '\t' },
+      });
+      expect(buildSelectInputSerialization('events.ndjson.bz2', 'json')).toEqual({
+        CompressionType: 'BZIP2',
+        JSON: { Type: 'LINES' },
+      });
+      expect(buildSelectInputSerialization('config.json', 'json')).toEqual({
+        CompressionType: 'NONE',
+        JSON: { Type: 'DOCUMENT' },
+      });
+    });
+  });
+
+  describe('multipart upload cleanup', () => {
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+    });
+
+    it('should list uploads across pages, oldest first', async () => {
+      mockSend
+        .mockResolvedValueOnce({
+          Uploads: [{ Key: 'new.bin', UploadId: 'u2', Initiated: new Date('2024-06-02T00:00:00Z') }],
+          IsTruncated: true,
+          NextKeyMarker: 'new.bin',
+          NextUploadIdMarker: 'u2',
+        })
+        .mockResolvedValueOnce({
+          Uploads: [
+            { Key: 'old.bin', UploadId: 'u1', Initiated: new Date('2024-01-01T00:00:00Z'), StorageClass: 'STANDARD' },
+          ],
+          IsTruncated: false,
+        });
+
+      const result = await listMultipartUploads('default', 'test-bucket', 'backups/');
+
+      expect(result).toEqual({
+        success: true,
+        uploads: [
+          { key: 'old.bin', uploadId: 'u1', initiated: new Date('2024-01-01T00:00:00Z'), storageClass: 'STANDARD' },
+          { key: 'new.bin', uploadId: 'u2', initiated: new Date('2024-06-02T00:00:00Z'), storageClass: undefined },
+        ],
+      });
+      expect(mockSend.mock.calls[0][0]).toMatchObject({
+        type: 'ListMultipartUploads',
+        input: { Bucket: 'test-bucket', Prefix: 'backups/' },
+      });
+      expect(mockSend.mock.calls[1][0].input).toMatchObject({ KeyMarker: 'new.bin', UploadIdMarker: 'u2' });
+    });
+
+    it('should stop when a truncated page repeats the markers it was asked for', async () => {
+      mockSend.mockResolvedValue({
+        Uploads: [{ Key: 'stuck.bin', UploadId: 'u1' }],
+        IsTruncated: true,
+        NextKeyMarker: 'stuck.bin',
+        NextUploadIdMarker: 'u1',
+      });
+
+      const result = await listMultipartUploads('default', 'test-bucket');
+
+      expect(mockSend).toHaveBeenCalledTimes(2);
+      expect(result.success).toBe(true);
+    });
+
+    it('should report listing errors', async () => {
+      mockSend.mockRejectedValueOnce(new Error('Access Denied'));
+
+      expect(await listMultipartUploads('default', 'test-bucket')).toEqual({ success: false, error: 'Access Denied' });
+    });
+
+    it('should abort an upload by key and upload ID', async () => {
+      mockSend.mockResolvedValueOnce({});
+
+      expect(await abortMultipartUpload('default', 'test-bucket', 'old.bin', 'u1')).toEqual({ success: true });
+      expect(mockSend.mock.calls[0][0]).toMatchObject({
+        type: 'AbortMultipartUpload',
+        input: { Bucket: 'test-bucket', Key: 'old.bin', UploadId: 'u1' },
+      });
+    });
+
+    it('should treat an upload that is already gone as aborted', async () => {
+      mockSend.mockRejectedValueOnce(Object.assign(new Error('The specified upload does not exist'), { name: 'NoSuchUpload' }));
+
+      expect(await abortMultipartUpload('default', 'test-bucket', 'old.bin', 'u1')).toEqual({ success: true });
+    });
+  });
+
+  describe('downloadBinaryContentIfModified', () => {
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+    });
+
+    it('should send the ETag as If-None-Match and return a changed object', async () => {
+      const lastModified = new Date('2024-06-01T00:00:00Z');
+      mockSend.mockResolvedValueOnce({ ETag: '"v2"', LastModified: lastModified, Body: Readable.from([Buffer.from('PAR1')]) });
+
+      const result = await downloadBinaryContentIfModified('default', 'test-bucket', 'data.parquet', {
+        etag: '"v1"',
+        modifiedSince: new Date('2024-01-01T00:00:00Z'),
+      });
+
+      expect(GetObjectCommand).toHaveBeenCalledWith({
+        Bucket: 'test-bucket',
+        Key: 'data.parquet',
+        IfNoneMatch: '"v1"',
+        IfModifiedSince: undefined,
+      });
+      expect(result).toMatchObject({ success: true, etag: '"v2"', lastModified });
+      expect(result.data?.toString()).toBe('PAR1');
+    });
+
+    it('should fall back to If-Modified-Since without an ETag', async () => {
+      const since = new Date('2024-01-01T00:00:00Z');
+      mockSend.mockResolvedValueOnce({ Body: Readable.from([Buffer.from('PAR1')]) });
+
+      await downloadBinaryContentIfModified('default', 'test-bucket', 'data.parquet', { modifiedSince: since });
+
+      expect(GetObjectCommand).toHaveBeenCalledWith(expect.objectContaining({ IfNoneMatch: undefined, IfModifiedSince: since }));
+    });
+
+    it('should report a 304 response as not modified', async () => {
+      mockSend.mockRejectedValueOnce(
+        Object.assign(new Error('UnknownError'), { name: 'NotModified', $metadata: { httpStatusCode: 304 } })
+      );
+
+      const result = await downloadBinaryContentIfModified('default', 'test-bucket', 'data.parquet', { etag: '"v1"' });
+
+      expect(result).toEqual({ success: true, notModified: true });
+    });
+
+    it('should download unconditionally without validators', async () => {
+      mockSend.mockResolvedValueOnce({ ETag: '"v1"', Body: Readable.from([Buffer.from('PAR1')]) });
+
+      const result = await downloadBinaryContentIfModified('default', 'test-bucket', 'data.parquet', {});
+
+      expect(GetObjectCommand).toHaveBeenCalledWith({ Bucket: 'test-bucket', Key: 'data.parquet' });
+      expect(result).toMatchObject({ success: true, etag: '"v1"' });
+    });
+
+    it('should report other errors', async () => {
+      mockSend.mockRejectedValueOnce(new Error('Access Denied'));
+
+      const result = await downloadBinaryContentIfModified('default', 'test-bucket', 'data.parquet', { etag: '"v1"' });
+
+      expect(result).toEqual({ success: false, error: 'Access Denied' });
+    });
+  });
+
+  describe('downloadBinaryContentVerified', () => {
+    const helloSha256 = 'pZGm1Av0IEBKARczz7exkNYsZb8LzaMrV7J32a2fFG4=';
+
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+    });
+
+    it('should verify the body against the stored SHA-256 checksum', async () => {
+      mockSend.mockResolvedValueOnce({
+        Body: Readable.from([Buffer.from('Hello World')]),
+        ChecksumSHA256: helloSha256,
+      });
+
+      const result = await downloadBinaryContentVerified('default', 'test-bucket', 'file.txt');
+
+      expect(mockSend).toHaveBeenCalledTimes(1);
+      expect(mockSend.mock.calls[0][0]).toMatchObject({
+        type: 'GetObject',
+        input: { Bucket: 'test-bucket', Key: 'file.txt', ChecksumMode: 'ENABLED' },
+      });
+      expect(result.success).toBe(true);
+      expect(result.verified).toBe(true);
+      expect(result.verifiedAgainst).toBe('SHA-256');
+      expect(result.data?.toString()).toBe('Hello World');
+    });
+
+    it('should report a checksum mismatch', async () => {
+      mockSend.mockResolvedValueOnce({
+        Body: Readable.from([Buffer.from('Hello Worle')]),
+        ChecksumSHA256: helloSha256,
+      });
+
+      const result = await downloadBinaryContentVerified('default', 'test-bucket', 'file.txt');
+
+      expect(result.success).toBe(false);
+      expect(result.checksumMismatch).toBe(true);
+      expect(result.data).toBeUndefined();
+      expect(result.error).toContain('Checksum mismatch');
+    });
+
+    it('should skip verification for objects without a stored checksum or MD5 ETag', async () => {
+      mockSend.mockResolvedValueOnce({ Body: Readable.from([Buffer.from('Hello World')]) });
+
+      const result = await downloadBinaryContentVerified('default', 'test-bucket', 'file.txt');
+
+      expect(result).toMatchObject({ success: true, verified: false });
+    });
+
+    it('should fall back to the MD5 ETag without a stored checksum', async () => {
+      mockSend.mockResolvedValueOnce({
+        Body: Readable.from([Buffer.from('Hello World')]),
+        ETag: '"b10a8db164e0754105b7a99be72e3fe5"',
+      });
+
+      const result = await downloadBinaryContentVerified('default', 'test-bucket', 'file.txt');
+
+      expect(result).toMatchObject({ success: true, verified: true, verifiedAgainst: 'ETag' });
+    });
+
+    it('should report content that does not match the MD5 ETag', async () => {
+      mockSend.mockResolvedValueOnce({
+        Body: Readable.from([Buffer.from('Hello Worle')]),
+        ETag: '"b10a8db164e0754105b7a99be72e3fe5"',
+      });
+
+      const result = await downloadBinaryContentVerified('default', 'test-bucket', 'file.txt');
+
+      expect(result).toMatchObject({ success: false, checksumMismatch: true });
+      expect(result.error).toContain('MD5 ETag');
+    });
+
+    it('should not compare multipart or SSE-KMS ETags with the content', async () => {
+      mockSend
+        .mockResolvedValueOnce({
+          Body: Readable.from([Buffer.from('Hello World')]),
+          ETag: '"9b2cf535f27731c974343645a3985328-2"',
+        })
+        .mockResolvedValueOnce({
+          Body: Readable.from([Buffer.from('Hello World')]),
+          ETag: '"0123456789abcdef0123456789abcdef"',
+          ServerSideEncryption: 'aws:kms',
+        });
+
+      expect(await downloadBinaryContentVerified('default', 'test-bucket', 'file.txt')).toMatchObject({
+        success: true,
+        verified: false,
+      });
+      expect(await downloadBinaryContentVerified('default', 'test-bucket', 'file.txt')).toMatchObject({
+        success: true,
+        verified: false,
+      });
+    });
+
+    it('should skip verification for composite multipart checksums', async () => {
+      mockSend.mockResolvedValueOnce({
+        Body: Readable.from([Buffer.from('Hello World')]),
+        ChecksumSHA256: 'abc123=-3',
+      });
+
+      const result = await downloadBinaryContentVerified('default', 'test-bucket', 'file.txt');
+
+      expect(result).toMatchObject({ success: true, verified: false });
+    });
+
+    it('should flag a mismatch the SDK found while reading the body', async () => {
+      mockSend.mockRejectedValueOnce(
+        new Error('Checksum mismatch: expected "abc=" but received "def=" in response header "x-amz-checksum-sha256".')
+      );
+
+      const result = await downloadBinaryContentVerified('default', 'test-bucket', 'file.txt');
+
+      expect(result).toMatchObject({ success: false, checksumMismatch: true });
+    });
+  });
+
+  describe('movePrefix', () => {
+    const copiedTo = () =>
+      mockSend.mock.calls.filter(call => call[0].type === 'CopyObject').map(call => call[0].input.Key);
+
+    beforeEach(() => {
+      (getProfile as Mock).mockReturnValue({
+        name: 'default',
+        accessKeyId: 'AKIATEST',
+        secretAccessKey: 'secretkey',
+        hasCredentials: true,
+      });
+    });
+
+    function mockFolder(
+      options: {
+        marker?: boolean;
+        failCopy?: string;
+        deleteErrors?: Array<{ Key: string; Message: string }>;
+        destinationExists?: boolean;
+      } = {}
+    ) {
+      mockSend.mockImplementation(async (command: { type: string; input: Record<string, unknown> }) => {
+        switch (command.type) {
+          case 'ListObjectsV2':
+            if (command.input.Prefix === 'new/') {
+              return options.destinationExists ? { KeyCount: 1, Contents: [{ Key: 'new/c.txt', Size: 1 }] } : { KeyCount: 0 };
+            }
+            return { Contents: [{ Key: 'old/a.txt', Size: 1 }, { Key: 'old/sub/b.txt', Size: 2 }] };
+          case 'HeadObject':
+            if (command.input.Key === 'old/' && !options.marker) {
+              throw Object.assign(new Error('NotFound'), { name: 'NotFound', $metadata: { httpStatusCode: 404 } });
+            }
+            return {};
+          case 'CopyObject':
+            if (command.input.CopySource === encodeURIComponent(`test-bucket/${options.failCopy}`)) {
+              throw new Error('Access Denied');
+            }
+            return {};
+          case 'DeleteObjects':
+            return { Errors: options.deleteErrors ?? [] };
+          default:
+            return {};
+        }
+      });
+    }
+
+    it('should copy every object under the prefix and delete the originals', async () => {
+      mockFolder({ marker: true });
+
+      const result = await movePrefix('default', 'test-bucket', 'old/', 'new/');
+
+      expect(result).toEqual({ success: true, movedCount: 3, failures: [] });
+      expect(copiedTo()).toEqual(['new/a.txt', 'new/sub/b.txt', 'new/']);
+      const deleteCall = mockSend.mock.calls.find(call => call[0].type === 'DeleteObjects');
+      expect(deleteCall?.[0].input.Delete.Objects).toEqual([{ Key: 'old/a.txt' }, { Key: 'old/sub/b.txt' }, { Key: 'old/' }]);
+    });
+
+    it('should keep going when one object fails to copy, leaving it in place', async () => {
+      mockFolder({ failCopy: 'old/a.txt' });
+
+      const result = await movePrefix('default', 'test-bucket', 'old/', 'new/');
+
+      expect(result).toEqual({ success: false, movedCount: 1, failures: [{ key: 'old/a.txt', error: 'Access Denied' }] });
+      const deleteCall = mockSend.mock.calls.find(call => call[0].type === 'DeleteObjects');
+      expect(deleteCall?.[0].input.Delete.Objects).toEqual([{ Key: 'old/sub/b.txt' }]);
+    });
+
+    it('should report originals that were copied but could not be deleted', async () => {
+      mockFolder({ deleteErrors: [{ Key: 'old/sub/b.txt', Message: 'Access Denied' }] });
+
+      const result = await movePrefix('default', 'test-bucket', 'old/', 'new/');
+
+      expect(result.movedCount).toBe(1);
+      expect(result.failures).toEqual([{ key: 'old/sub/b.txt', error: 'Copied but not deleted: Access Denied' }]);
+    });
+
+    it('should refuse to move into a folder that already has objects', async () => {
+      mockFolder({ destinationExists: true });
+
+      const result = await movePrefix('default', 'test-bucket', 'old/', 'new/');
+
+      expect(result).toEqual({ success: false, movedCount: 0, failures: [], error: 'A folder named new/ already exists' });
+      expect(copiedTo()).toEqual([]);
+    });
+
+    it('should reject moving a folder into itself or onto its own name', async () => {
+      expect((await movePrefix('default', 'test-bucket', 'old/', 'old/inner/')).error).toBe(
+        'A folder cannot be moved into itself'
+      );
+      expect((await movePrefix('default', 'test-bucket', 'old/', 'old/')).success).toBe(false);
+      expect((await movePrefix('default', 'test-bucket', 'old', 'new/')).success).toBe(false);
+      expect(mockSend).not.toHaveBeenCalled();
+    });
+
+    it('should stop copying when aborted and still delete what was already copied', async () => {
+      mockFolder();
+      const controller = new AbortController();
+
+      const result = await movePrefix('default', 'test-bucket', 'old/', 'new/', copied => {
+        if (copied === 1) controller.abort();
+      }, controller.signal);
+
+      expect(result).toMatchObject({ success: false, movedCount: 1, error: 'Operation aborted' });
+      expect(copiedTo()).toEqual(['new/a.txt']);
+    });
+  });
 });
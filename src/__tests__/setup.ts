@@ -13,9 +13,30 @@ const mockElectronAPI = {
         lastProfile: null,
         lastBucket: null,
         lastPrefix: '',
+        readOnlyProfiles: [],
+        downloadConflictPolicy: 'rename',
+        collapseSingleChildFolders: false,
+        confirmBeforeDelete: true,
+        recentLocations: [],
+        bookmarks: [],
+        viewerLayouts: {},
+        columnWidths: {},
+        requestLogging: false,
+        requesterPays: false,
+        maxRetries: null,
+        connectTimeoutSeconds: null,
+        requestTimeoutSeconds: null,
+        uploadStorageClass: null,
+        proxyUrl: null,
+        theme: 'system',
       })
     ),
     save: vi.fn(() => Promise.resolve({ success: true })),
+    exportShareable: vi.fn(() => Promise.resolve({ success: true, filePath: '/downloads/s3-browser-locations.json' })),
+    importShareable: vi.fn(() => Promise.resolve({ success: true, added: 0 })),
+    addBookmark: vi.fn(() => Promise.resolve({ success: true, bookmarks: [] })),
+    removeBookmark: vi.fn(() => Promise.resolve({ success: true, bookmarks: [] })),
+    setColumnWidths: vi.fn(() => Promise.resolve({ success: true })),
   },
   aws: {
     getProfiles: vi.fn(() =>
@@ -37,11 +58,27 @@ const mockElectronAPI = {
         defaultRegion: undefined,
       })
     ),
+    getDiagnosticReport: vi.fn(() => Promise.resolve('')),
+    onProfilesChanged: vi.fn(() => () => {}),
+    exportEnv: vi.fn(() => Promise.resolve({ success: true, text: '' })),
+    ssoLogin: vi.fn(() => Promise.resolve({ success: true })),
+    cancelSsoLogin: vi.fn(() => Promise.resolve(false)),
+    listSsoRoles: vi.fn(() => Promise.resolve({ success: true, roles: [] })),
+    onMfaCodeRequest: vi.fn(() => () => {}),
+    submitMfaCode: vi.fn(() => Promise.resolve(true)),
+    addProfile: vi.fn(() => Promise.resolve({ success: true })),
+    getProfileConfig: vi.fn(() => Promise.resolve(null)),
+    updateProfile: vi.fn(() => Promise.resolve({ success: true })),
+    removeProfile: vi.fn(() => Promise.resolve({ success: true })),
+    onSsoAuthorization: vi.fn(() => () => {}),
   },
   s3: {
     listBuckets: vi.fn(() =>
       Promise.resolve({ success: true, buckets: [] })
     ),
+    createBucket: vi.fn(() => Promise.resolve({ success: true })),
+    getBucketPublicStatus: vi.fn(() => Promise.resolve({ success: true, status: 'private' })),
+    getBucketRegion: vi.fn(() => Promise.resolve({ success: true, region: 'us-east-1' })),
     listObjects: vi.fn(() =>
       Promise.resolve({
         success: true,
@@ -68,29 +105,60 @@ const mockElectronAPI = {
         },
       })
     ),
+    collapsePrefixChain: vi.fn((_bucket: string, prefix: string) =>
+      Promise.resolve({ success: true, prefix })
+    ),
     cancelOperation: vi.fn(() => Promise.resolve(true)),
+    onTransferProgress: vi.fn(() => () => {}),
     parseUrl: vi.fn((url: string) =>
       Promise.resolve({ success: false, error: 'Not implemented' })
     ),
+    parseUrlList: vi.fn((text: string) =>
+      Promise.resolve({ success: false, error: 'Not implemented' })
+    ),
     getParentPrefix: vi.fn((key: string) => Promise.resolve('')),
     getKeyName: vi.fn((key: string) => Promise.resolve(key)),
     clearClient: vi.fn(() => Promise.resolve()),
     // File operations
     downloadFile: vi.fn(() => Promise.resolve({ success: true, localPath: '/downloads/file.txt' })),
+    downloadFiles: vi.fn(() => Promise.resolve({ success: true, results: [] })),
+    openExternally: vi.fn(() => Promise.resolve({ success: true, localPath: '/tmp/s3-browser-open/file.txt' })),
     uploadFile: vi.fn(() => Promise.resolve({ success: true })),
     uploadFiles: vi.fn(() => Promise.resolve({ success: true, results: [] })),
     deleteFile: vi.fn(() => Promise.resolve({ success: true })),
     deleteFiles: vi.fn(() => Promise.resolve({ success: true, results: [], deletedCount: 0, failedCount: 0 })),
     deletePrefix: vi.fn(() => Promise.resolve({ success: true, deletedCount: 0, failedCount: 0 })),
     renameFile: vi.fn(() => Promise.resolve({ success: true })),
+    renamePrefix: vi.fn(() => Promise.resolve({ success: true, movedCount: 0, failures: [] })),
     copyFile: vi.fn(() => Promise.resolve({ success: true })),
+    transferObject: vi.fn(() => Promise.resolve({ success: true, method: 'server-side' })),
     uploadContent: vi.fn(() => Promise.resolve({ success: true })),
     downloadContent: vi.fn(() => Promise.resolve({ success: true, content: '' })),
     getFileSize: vi.fn(() => Promise.resolve({ success: true, size: 0 })),
+    headObject: vi.fn(() => Promise.resolve({ success: true, head: { contentLength: 0 } })),
+    presignGetUrl: vi.fn(() =>
+      Promise.resolve({ success: true, url: 'https://test-bucket.s3.amazonaws.com/file.txt?X-Amz-Signature=abc' })
+    ),
+    presignPutUrl: vi.fn(() =>
+      Promise.resolve({ success: true, url: 'https://test-bucket.s3.amazonaws.com/file.txt?X-Amz-Signature=def' })
+    ),
+    restoreObject: vi.fn(() => Promise.resolve({ success: true })),
+    verifyChecksum: vi.fn(() => Promise.resolve({ success: true, verified: true })),
+    getObjectMetadata: vi.fn(() =>
+      Promise.resolve({ success: true, metadata: { tags: {}, customMetadata: {} } })
+    ),
+    getObjectTags: vi.fn(() => Promise.resolve({ success: true, tags: {} })),
+    putObjectTags: vi.fn(() => Promise.resolve({ success: true })),
+    listMultipartUploads: vi.fn(() => Promise.resolve({ success: true, uploads: [] })),
+    abortMultipartUpload: vi.fn(() => Promise.resolve({ success: true })),
+    selectObjectContent: vi.fn(() => Promise.resolve({ success: true, records: '', truncated: false })),
     downloadBinaryContent: vi.fn(() => Promise.resolve({ success: true, data: new Uint8Array() })),
+    downloadBinaryContentIfModified: vi.fn(() => Promise.resolve({ success: true, notModified: true })),
+    downloadPartialContent: vi.fn(() => Promise.resolve({ success: true, data: new Uint8Array() })),
     showOpenDialog: vi.fn(() => Promise.resolve(null)),
+    saveLocalFile: vi.fn(() => Promise.resolve({ success: true, filePath: '/downloads/export.csv' })),
     openDownloadsFolder: vi.fn(() => Promise.resolve()),
-    showFileInFolder: vi.fn(() => Promise.resolve()),
+    showFileInFolder: vi.fn(() => Promise.resolve({ success: true })),
   },
 };
 
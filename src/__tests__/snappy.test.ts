@@ -0,0 +1,82 @@
+import { describe, it, expect } from 'vitest';
+import { randomBytes } from 'crypto';
+import {
+  compressSnappyFramed,
+  decompressSnappyFramed,
+  isSnappyFramed,
+} from '../main/services/snappy';
+
+const STREAM_IDENTIFIER = [0xff, 0x06, 0x00, 0x00, 0x73, 0x4e, 0x61, 0x50, 0x70, 0x59];
+
+describe('snappy', () => {
+  describe('decompressSnappyFramed', () => {
+    it('decodes a stream with literal and copy elements', () => {
+      const stream = Buffer.from([
+        ...STREAM_IDENTIFIER,
+        // Compressed chunk: 12 bytes, masked CRC, then "abcd" and a copy of 8 bytes at offset 4
+        0x00, 0x0c, 0x00, 0x00, 0xa8, 0x8d, 0x5f, 0x03,
+        0x0c, 0x0c, 0x61, 0x62, 0x63, 0x64, 0x11, 0x04,
+      ]);
+
+      expect(decompressSnappyFramed(stream, 100).toString()).toBe('abcdabcdabcd');
+    });
+
+    it('skips padding chunks', () => {
+      const stream = Buffer.concat([
+        compressSnappyFramed(Buffer.from('hello')),
+        Buffer.from([0xfe, 0x02, 0x00, 0x00, 0x00, 0x00]),
+      ]);
+
+      expect(decompressSnappyFramed(stream, 100).toString()).toBe('hello');
+    });
+
+    it('rejects data without the stream identifier', () => {
+      expect(() => decompressSnappyFramed(Buffer.from('plain text'), 100)).toThrow('Not a framed snappy stream');
+    });
+
+    it('rejects a chunk whose checksum does not match', () => {
+      const stream = compressSnappyFramed(Buffer.from('hello world'));
+      stream[stream.length - 1] ^= 0xff;
+
+      expect(() => decompressSnappyFramed(stream, 100)).toThrow();
+    });
+
+    it('rejects truncated streams', () => {
+      const stream = compressSnappyFramed(Buffer.from('hello world'));
+
+      expect(() => decompressSnappyFramed(stream.subarray(0, stream.length - 3), 100)).toThrow(
+        'Truncated snappy stream'
+      );
+    });
+
+    it('stops once the output passes the size limit', () => {
+      const stream = compressSnappyFramed(Buffer.alloc(200000, 'a'));
+
+      expect(() => decompressSnappyFramed(stream, 100000)).toThrow(RangeError);
+    });
+  });
+
+  describe('compressSnappyFramed', () => {
+    it('starts with the stream identifier', () => {
+      expect(isSnappyFramed(compressSnappyFramed(Buffer.from('x')))).toBe(true);
+    });
+
+    it('round-trips repetitive data in several chunks, smaller than the input', () => {
+      const data = Buffer.from('{"id":1,"type":"click"}\n'.repeat(10000));
+      const compressed = compressSnappyFramed(data);
+
+      expect(compressed.length).toBeLessThan(data.length / 4);
+      expect(decompressSnappyFramed(compressed, data.length).equals(data)).toBe(true);
+    });
+
+    it('round-trips incompressible data', () => {
+      const data = randomBytes(100000);
+
+      expect(decompressSnappyFramed(compressSnappyFramed(data), data.length).equals(data)).toBe(true);
+    });
+
+    it('round-trips empty input', () => {
+      expect(decompressSnappyFramed(compressSnappyFramed(Buffer.alloc(0)), 0).length).toBe(0);
+    });
+  });
+});
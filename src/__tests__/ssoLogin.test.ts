@@ -0,0 +1,369 @@
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import fs from 'fs';
+import path from 'path';
+import crypto from 'crypto';
+
+const mockSend = vi.fn();
+const mockSsoSend = vi.fn();
+
+vi.mock('@aws-sdk/client-sso-oidc', () => {
+  class MockSSOOIDCClient {
+    send = (command: unknown) => mockSend(command);
+    destroy = vi.fn();
+  }
+
+  return {
+    SSOOIDCClient: MockSSOOIDCClient,
+    RegisterClientCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'RegisterClient' };
+    }),
+    StartDeviceAuthorizationCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'StartDeviceAuthorization' };
+    }),
+    CreateTokenCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'CreateToken' };
+    }),
+  };
+});
+
+vi.mock('@aws-sdk/client-sso', () => {
+  class MockSSOClient {
+    send = (command: unknown) => mockSsoSend(command);
+    destroy = vi.fn();
+  }
+
+  return {
+    SSOClient: MockSSOClient,
+    ListAccountsCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'ListAccounts' };
+    }),
+    ListAccountRolesCommand: vi.fn().mockImplementation(function (input: unknown) {
+      return { input, type: 'ListAccountRoles' };
+    }),
+  };
+});
+
+import { ssoLogin, getSsoTokenCachePath, getSsoTokenStatus, listSsoRoles } from '../main/services/ssoLogin';
+import type { AwsProfile } from '../main/services/awsCredentials';
+
+function namedError(name: string): Error {
+  const error = new Error(name);
+  error.name = name;
+  return error;
+}
+
+describe('ssoLogin', () => {
+  let cacheDir: string;
+  const sleep = vi.fn(() => Promise.resolve());
+  const profile: AwsProfile = {
+    name: 'sso-dev',
+    ssoStartUrl: 'https://my-sso.awsapps.com/start',
+    ssoRegion: 'us-east-1',
+    ssoAccountId: '123456789012',
+    ssoRoleName: 'Developer',
+    profileType: 'sso',
+    hasCredentials: true,
+  };
+
+  beforeEach(() => {
+    cacheDir = '/tmp/aws-sso-cache-' + Date.now();
+    mockSend.mockReset();
+    sleep.mockClear();
+  });
+
+  afterEach(() => {
+    fs.rmSync(cacheDir, { recursive: true, force: true });
+  });
+
+  function mockDeviceFlow() {
+    mockSend.mockImplementation((command: { type: string }) => {
+      if (command.type === 'RegisterClient') {
+        return Promise.resolve({ clientId: 'client-id', clientSecret: 'client-secret', clientSecretExpiresAt: 1900000000 });
+      }
+      if (command.type === 'StartDeviceAuthorization') {
+        return Promise.resolve({
+          deviceCode: 'device-code',
+          userCode: 'ABCD-EFGH',
+          verificationUri: 'https://device.sso.us-east-1.amazonaws.com/',
+          verificationUriComplete: 'https://device.sso.us-east-1.amazonaws.com/?user_code=ABCD-EFGH',
+          expiresIn: 600,
+          interval: 1,
+        });
+      }
+      return Promise.reject(new Error(`Unexpected ${command.type}`));
+    });
+  }
+
+  it('names the cache file after the session, or the start URL without one', () => {
+    const sha1 = (value: string) => crypto.createHash('sha1').update(value).digest('hex');
+
+    expect(getSsoTokenCachePath(profile, cacheDir)).toBe(
+      path.join(cacheDir, `${sha1('https://my-sso.awsapps.com/start')}.json`)
+    );
+    expect(getSsoTokenCachePath({ ...profile, ssoSession: 'my-session' }, cacheDir)).toBe(
+      path.join(cacheDir, `${sha1('my-session')}.json`)
+    );
+  });
+
+  it('reports the user code, polls until approved and caches the token', async () => {
+    mockDeviceFlow();
+    const base = mockSend.getMockImplementation()!;
+    let polls = 0;
+    mockSend.mockImplementation((command: { type: string }) => {
+      if (command.type !== 'CreateToken') return base(command);
+      polls++;
+      if (polls === 1) return Promise.reject(namedError('AuthorizationPendingException'));
+      return Promise.resolve({ accessToken: 'access-token', expiresIn: 3600, refreshToken: 'refresh-token' });
+    });
+    const onAuthorization = vi.fn();
+
+    const result = await ssoLogin(profile, { onAuthorization, cacheDir, sleep });
+
+    expect(result).toEqual({ success: true });
+    expect(onAuthorization).toHaveBeenCalledWith(
+      expect.objectContaining({
+        userCode: 'ABCD-EFGH',
+        verificationUri: 'https://device.sso.us-east-1.amazonaws.com/',
+      })
+    );
+    expect(polls).toBe(2);
+    expect(sleep).toHaveBeenCalledWith(1000, undefined);
+
+    const cached = JSON.parse(fs.readFileSync(getSsoTokenCachePath(profile, cacheDir), 'utf-8'));
+    expect(cached).toMatchObject({
+      startUrl: 'https://my-sso.awsapps.com/start',
+      region: 'us-east-1',
+      accessToken: 'access-token',
+      refreshToken: 'refresh-token',
+      clientId: 'client-id',
+    });
+    expect(new Date(cached.expiresAt).getTime()).toBeGreaterThan(Date.now());
+  });
+
+  it('backs off when asked to slow down', async () => {
+    mockDeviceFlow();
+    const base = mockSend.getMockImplementation()!;
+    let polls = 0;
+    mockSend.mockImplementation((command: { type: string }) => {
+      if (command.type !== 'CreateToken') return base(command);
+      polls++;
+      if (polls === 1) return Promise.reject(namedError('SlowDownException'));
+      return Promise.resolve({ accessToken: 'access-token', expiresIn: 3600 });
+    });
+
+    await ssoLogin(profile, { onAuthorization: vi.fn(), cacheDir, sleep });
+
+    expect(sleep).toHaveBeenNthCalledWith(1, 1000, undefined);
+    expect(sleep).toHaveBeenNthCalledWith(2, 6000, undefined);
+  });
+
+  it('fails when the user denies the request', async () => {
+    mockDeviceFlow();
+    const base = mockSend.getMockImplementation()!;
+    mockSend.mockImplementation((command: { type: string }) =>
+      command.type === 'CreateToken' ? Promise.reject(namedError('AccessDeniedException')) : base(command)
+    );
+
+    const result = await ssoLogin(profile, { onAuthorization: vi.fn(), cacheDir, sleep });
+
+    expect(result).toEqual({ success: false, error: 'The sign-in request was denied' });
+    expect(fs.existsSync(cacheDir)).toBe(false);
+  });
+
+  it('stops polling when cancelled', async () => {
+    mockDeviceFlow();
+    const controller = new AbortController();
+    const cancellingSleep = vi.fn(() => {
+      controller.abort();
+      return Promise.reject(namedError('AbortError'));
+    });
+
+    const result = await ssoLogin(profile, {
+      onAuthorization: vi.fn(),
+      abortSignal: controller.signal,
+      cacheDir,
+      sleep: cancellingSleep,
+    });
+
+    expect(result).toEqual({ success: false, error: 'SSO login cancelled' });
+  });
+
+  it('requires the start URL and region', async () => {
+    const result = await ssoLogin(
+      { ...profile, ssoRegion: undefined },
+      { onAuthorization: vi.fn(), cacheDir, sleep }
+    );
+
+    expect(result).toEqual({ success: false, error: 'SSO login requires sso_start_url and sso_region' });
+    expect(mockSend).not.toHaveBeenCalled();
+  });
+});
+
+describe('getSsoTokenStatus', () => {
+  let cacheDir: string;
+  const now = new Date('2024-06-01T12:00:00Z');
+  const profile: AwsProfile = {
+    name: 'sso-dev',
+    ssoStartUrl: 'https://my-sso.awsapps.com/start',
+    ssoRegion: 'us-east-1',
+    profileType: 'sso',
+    hasCredentials: true,
+  };
+
+  beforeEach(() => {
+    cacheDir = '/tmp/aws-sso-status-' + Date.now();
+    fs.mkdirSync(cacheDir, { recursive: true });
+  });
+
+  afterEach(() => {
+    fs.rmSync(cacheDir, { recursive: true, force: true });
+  });
+
+  function writeCache(fileName: string, content: object | string) {
+    fs.writeFileSync(path.join(cacheDir, fileName), typeof content === 'string' ? content : JSON.stringify(content));
+  }
+
+  it('reports a valid token with its expiry', () => {
+    writeCache(path.basename(getSsoTokenCachePath(profile, cacheDir)), {
+      accessToken: 'token',
+      expiresAt: '2024-06-01T20:00:00Z',
+    });
+
+    expect(getSsoTokenStatus(profile, cacheDir, now)).toEqual({
+      state: 'valid',
+      expiresAt: new Date('2024-06-01T20:00:00Z'),
+    });
+  });
+
+  it('reports an expired token', () => {
+    writeCache(path.basename(getSsoTokenCachePath(profile, cacheDir)), {
+      accessToken: 'token',
+      expiresAt: '2024-06-01T08:00:00Z',
+    });
+
+    expect(getSsoTokenStatus(profile, cacheDir, now).state).toBe('expired');
+  });
+
+  it('reports a missing token when the cache has nothing for the profile', () => {
+    writeCache('other.json', { accessToken: 'token', startUrl: 'https://other.awsapps.com/start', expiresAt: '2024-06-02T00:00:00Z' });
+    writeCache('registration.json', { clientId: 'id', clientSecret: 'secret', expiresAt: '2024-09-01T00:00:00Z' });
+    writeCache('broken.json', '{not json');
+
+    expect(getSsoTokenStatus(profile, cacheDir, now)).toEqual({ state: 'missing' });
+    expect(getSsoTokenStatus(profile, path.join(cacheDir, 'nope'), now)).toEqual({ state: 'missing' });
+  });
+
+  it('matches tokens for a session by file name and picks the latest of several', () => {
+    const sessionProfile = { ...profile, ssoSession: 'my-session' };
+    writeCache(path.basename(getSsoTokenCachePath(sessionProfile, cacheDir)), {
+      accessToken: 'old',
+      expiresAt: '2024-06-01T10:00:00Z',
+    });
+    writeCache('legacy.json', {
+      accessToken: 'new',
+      startUrl: 'https://my-sso.awsapps.com/start',
+      expiresAt: '2024-06-01T18:00:00Z',
+    });
+
+    expect(getSsoTokenStatus(sessionProfile, cacheDir, now)).toEqual({
+      state: 'valid',
+      expiresAt: new Date('2024-06-01T18:00:00Z'),
+    });
+  });
+});
+
+describe('listSsoRoles', () => {
+  let cacheDir: string;
+  const profile: AwsProfile = {
+    name: 'sso-dev',
+    ssoStartUrl: 'https://my-sso.awsapps.com/start',
+    ssoRegion: 'eu-west-1',
+    profileType: 'sso',
+    hasCredentials: true,
+  };
+
+  beforeEach(() => {
+    cacheDir = '/tmp/aws-sso-roles-' + Date.now();
+    fs.mkdirSync(cacheDir, { recursive: true });
+    mockSsoSend.mockReset();
+  });
+
+  afterEach(() => {
+    fs.rmSync(cacheDir, { recursive: true, force: true });
+  });
+
+  function writeToken(expiresAt: Date) {
+    fs.writeFileSync(
+      getSsoTokenCachePath(profile, cacheDir),
+      JSON.stringify({ accessToken: 'access-token', expiresAt: expiresAt.toISOString() })
+    );
+  }
+
+  it('lists the roles of every account, following pages', async () => {
+    writeToken(new Date(Date.now() + 60 * 60 * 1000));
+    mockSsoSend.mockImplementation((command: { type: string; input: Record<string, string | undefined> }) => {
+      if (command.type === 'ListAccounts') {
+        return Promise.resolve(
+          command.input.nextToken
+            ? { accountList: [{ accountId: '111111111111', accountName: 'Production' }] }
+            : { accountList: [{ accountId: '222222222222', accountName: 'Staging' }], nextToken: 'page-2' }
+        );
+      }
+      if (command.input.accountId === '111111111111') {
+        return Promise.resolve(
+          command.input.nextToken
+            ? { roleList: [{ roleName: 'ReadOnly' }] }
+            : { roleList: [{ roleName: 'Admin' }], nextToken: 'roles-2' }
+        );
+      }
+      return Promise.resolve({ roleList: [{ roleName: 'Developer' }] });
+    });
+
+    const result = await listSsoRoles(profile, cacheDir);
+
+    expect(result).toEqual({
+      success: true,
+      roles: [
+        { accountId: '111111111111', accountName: 'Production', roleName: 'Admin' },
+        { accountId: '111111111111', accountName: 'Production', roleName: 'ReadOnly' },
+        { accountId: '222222222222', accountName: 'Staging', roleName: 'Developer' },
+      ],
+    });
+    expect(mockSsoSend.mock.calls[0][0].input.accessToken).toBe('access-token');
+  });
+
+  it('asks to sign in when there is no token', async () => {
+    const result = await listSsoRoles(profile, cacheDir);
+
+    expect(result).toEqual({ success: false, needsLogin: true, error: 'Not signed in to SSO; sign in first' });
+    expect(mockSsoSend).not.toHaveBeenCalled();
+  });
+
+  it('asks to sign in again when the token has expired', async () => {
+    writeToken(new Date(Date.now() - 1000));
+
+    const result = await listSsoRoles(profile, cacheDir);
+
+    expect(result.needsLogin).toBe(true);
+    expect(result.error).toBe('The SSO sign-in has expired; sign in again');
+  });
+
+  it('asks to sign in again when the service rejects the token', async () => {
+    writeToken(new Date(Date.now() + 60 * 60 * 1000));
+    mockSsoSend.mockRejectedValue(namedError('UnauthorizedException'));
+
+    const result = await listSsoRoles(profile, cacheDir);
+
+    expect(result).toEqual({
+      success: false,
+      needsLogin: true,
+      error: 'The SSO sign-in is no longer valid; sign in again',
+    });
+  });
+
+  it('refuses profiles that are not SSO profiles', async () => {
+    const result = await listSsoRoles({ name: 'static', profileType: 'static', hasCredentials: true }, cacheDir);
+
+    expect(result).toEqual({ success: false, error: "Profile 'static' is not an SSO profile" });
+  });
+});
@@ -0,0 +1,169 @@
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import {
+  LruCache,
+  thumbnailCacheKey,
+  isThumbnailCandidate,
+  prefetchThumbnails,
+  THUMBNAIL_MAX_BYTES,
+  THUMBNAIL_PREFETCH_CONCURRENCY,
+} from '../renderer/utils/thumbnailCache';
+import { mockElectronAPI } from './setup';
+
+describe('LruCache', () => {
+  it('evicts the least recently inserted entry past capacity', () => {
+    const onEvict = vi.fn();
+    const cache = new LruCache<string>(2, onEvict);
+
+    cache.set('a', 'A');
+    cache.set('b', 'B');
+    cache.set('c', 'C');
+
+    expect(cache.has('a')).toBe(false);
+    expect(cache.has('b')).toBe(true);
+    expect(cache.has('c')).toBe(true);
+    expect(onEvict).toHaveBeenCalledWith('A', 'a');
+  });
+
+  it('treats reads as recent use', () => {
+    const cache = new LruCache<string>(2);
+
+    cache.set('a', 'A');
+    cache.set('b', 'B');
+    cache.get('a');
+    cache.set('c', 'C');
+
+    expect(cache.has('a')).toBe(true);
+    expect(cache.has('b')).toBe(false);
+  });
+
+  it('releases the old value when an entry is replaced', () => {
+    const onEvict = vi.fn();
+    const cache = new LruCache<string>(2, onEvict);
+
+    cache.set('a', 'A1');
+    cache.set('a', 'A2');
+
+    expect(cache.get('a')).toBe('A2');
+    expect(cache.size).toBe(1);
+    expect(onEvict).toHaveBeenCalledWith('A1', 'a');
+  });
+
+  it('releases every value on clear', () => {
+    const onEvict = vi.fn();
+    const cache = new LruCache<string>(5, onEvict);
+
+    cache.set('a', 'A');
+    cache.set('b', 'B');
+    cache.clear();
+
+    expect(cache.size).toBe(0);
+    expect(onEvict).toHaveBeenCalledTimes(2);
+  });
+});
+
+describe('thumbnailCacheKey', () => {
+  it('misses the cache when the ETag changes', () => {
+    const cache = new LruCache<string>(10);
+    cache.set(thumbnailCacheKey('bucket', 'img/cat.png', '"v1"'), 'blob:v1');
+
+    expect(cache.get(thumbnailCacheKey('bucket', 'img/cat.png', '"v1"'))).toBe('blob:v1');
+    expect(cache.get(thumbnailCacheKey('bucket', 'img/cat.png', '"v2"'))).toBeUndefined();
+  });
+
+  it('separates buckets', () => {
+    expect(thumbnailCacheKey('a', 'k', 'e')).not.toBe(thumbnailCacheKey('b', 'k', 'e'));
+  });
+});
+
+describe('isThumbnailCandidate', () => {
+  it('accepts small images', () => {
+    expect(isThumbnailCandidate({ key: 'a.png', size: 1000, isPrefix: false })).toBe(true);
+    expect(isThumbnailCandidate({ key: 'a.JPEG', size: 1000, isPrefix: false })).toBe(true);
+  });
+
+  it('rejects folders, empty, large and non-image files', () => {
+    expect(isThumbnailCandidate({ key: 'img/', size: 0, isPrefix: true })).toBe(false);
+    expect(isThumbnailCandidate({ key: 'a.png', size: 0, isPrefix: false })).toBe(false);
+    expect(isThumbnailCandidate({ key: 'a.png', size: THUMBNAIL_MAX_BYTES + 1, isPrefix: false })).toBe(false);
+    expect(isThumbnailCandidate({ key: 'a.csv', size: 1000, isPrefix: false })).toBe(false);
+  });
+});
+
+describe('prefetchThumbnails', () => {
+  const originalCreateObjectURL = URL.createObjectURL;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    let counter = 0;
+    URL.createObjectURL = vi.fn(() => `blob:thumb-${++counter}`);
+    mockElectronAPI.s3.downloadBinaryContent.mockResolvedValue({
+      success: true,
+      data: new Uint8Array([1, 2, 3]),
+    });
+  });
+
+  afterEach(() => {
+    URL.createObjectURL = originalCreateObjectURL;
+    mockElectronAPI.s3.downloadBinaryContent.mockResolvedValue({ success: true, data: new Uint8Array() });
+  });
+
+  const image = (key: string, etag = '"e"') => ({ key, size: 100, isPrefix: false, etag });
+
+  it('caches thumbnails for image files only', async () => {
+    const cache = new LruCache<string>(10);
+
+    await prefetchThumbnails(
+      'bucket',
+      [image('a.png'), { key: 'notes.txt', size: 10, isPrefix: false }, { key: 'dir/', size: 0, isPrefix: true }],
+      cache,
+      new AbortController().signal
+    );
+
+    expect(mockElectronAPI.s3.downloadBinaryContent).toHaveBeenCalledTimes(1);
+    expect(cache.get(thumbnailCacheKey('bucket', 'a.png', '"e"'))).toBe('blob:thumb-1');
+  });
+
+  it('skips files that are already cached for the same ETag', async () => {
+    const cache = new LruCache<string>(10);
+    cache.set(thumbnailCacheKey('bucket', 'a.png', '"e"'), 'blob:cached');
+
+    await prefetchThumbnails('bucket', [image('a.png'), image('b.png')], cache, new AbortController().signal);
+
+    expect(mockElectronAPI.s3.downloadBinaryContent).toHaveBeenCalledTimes(1);
+    expect(mockElectronAPI.s3.downloadBinaryContent).toHaveBeenCalledWith('bucket', 'b.png');
+  });
+
+  it('limits the number of parallel downloads', async () => {
+    let active = 0;
+    let maxActive = 0;
+    mockElectronAPI.s3.downloadBinaryContent.mockImplementation(async () => {
+      active++;
+      maxActive = Math.max(maxActive, active);
+      await new Promise(resolve => setTimeout(resolve, 1));
+      active--;
+      return { success: true, data: new Uint8Array([1]) };
+    });
+
+    const items = Array.from({ length: 10 }, (_, i) => image(`img-${i}.png`));
+    await prefetchThumbnails('bucket', items, new LruCache<string>(20), new AbortController().signal);
+
+    expect(mockElectronAPI.s3.downloadBinaryContent).toHaveBeenCalledTimes(10);
+    expect(maxActive).toBe(THUMBNAIL_PREFETCH_CONCURRENCY);
+  });
+
+  it('stops and discards results once aborted', async () => {
+    const controller = new AbortController();
+    const cache = new LruCache<string>(20);
+    mockElectronAPI.s3.downloadBinaryContent.mockImplementation(async () => {
+      controller.abort();
+      return { success: true, data: new Uint8Array([1]) };
+    });
+
+    const items = Array.from({ length: 10 }, (_, i) => image(`img-${i}.png`));
+    await prefetchThumbnails('bucket', items, cache, controller.signal);
+
+    // The first download aborts before the other workers pick up their first file
+    expect(mockElectronAPI.s3.downloadBinaryContent).toHaveBeenCalledTimes(1);
+    expect(cache.size).toBe(0);
+  });
+});
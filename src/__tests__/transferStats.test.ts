@@ -0,0 +1,89 @@
+import { describe, it, expect } from 'vitest';
+import { TransferStats, formatSpeed, formatEta, describeTransfer } from '../renderer/utils/transferStats';
+
+const MB = 1024 * 1024;
+
+describe('TransferStats', () => {
+  it('computes speed and ETA from a steady transfer', () => {
+    const stats = new TransferStats(100 * MB, 0);
+
+    stats.update(10 * MB, 1000);
+    const estimate = stats.update(20 * MB, 2000);
+
+    expect(estimate.speedBps).toBe(10 * MB);
+    expect(estimate.averageBps).toBe(10 * MB);
+    expect(estimate.etaSeconds).toBe(8);
+  });
+
+  it('smooths speed over the trailing window only', () => {
+    const stats = new TransferStats(null, 0, 3000);
+
+    // Slow start, then 10 MB/s for the last five seconds
+    stats.update(1 * MB, 5000);
+    for (let t = 6000; t <= 10000; t += 1000) {
+      stats.update(1 * MB + ((t - 5000) / 1000) * 10 * MB, t);
+    }
+    const estimate = stats.update(61 * MB, 11000);
+
+    expect(estimate.speedBps).toBeCloseTo(10 * MB);
+    expect(estimate.averageBps).toBeCloseTo((61 * MB) / 11);
+  });
+
+  it('has no ETA when the total is unknown', () => {
+    const stats = new TransferStats(0, 0);
+
+    const estimate = stats.update(5 * MB, 1000);
+
+    expect(estimate.speedBps).toBe(5 * MB);
+    expect(estimate.etaSeconds).toBeNull();
+  });
+
+  it('handles completion with no elapsed time without dividing by zero', () => {
+    const stats = new TransferStats(1024, 500);
+
+    const estimate = stats.update(1024, 500);
+
+    expect(estimate.speedBps).toBe(0);
+    expect(estimate.averageBps).toBe(0);
+    expect(estimate.etaSeconds).toBe(0);
+  });
+
+  it('has no ETA while nothing has been transferred', () => {
+    const stats = new TransferStats(1024, 0);
+
+    expect(stats.update(0, 1000).etaSeconds).toBeNull();
+  });
+});
+
+describe('formatSpeed', () => {
+  it('formats bytes per second with units', () => {
+    expect(formatSpeed(0)).toBe('0 B/s');
+    expect(formatSpeed(512)).toBe('512 B/s');
+    expect(formatSpeed(45.2 * MB)).toBe('45.2 MB/s');
+  });
+});
+
+describe('formatEta', () => {
+  it('formats minutes and seconds', () => {
+    expect(formatEta(38)).toBe('0:38');
+    expect(formatEta(61.2)).toBe('1:02');
+  });
+
+  it('includes hours for long transfers', () => {
+    expect(formatEta(3 * 3600 + 5 * 60 + 9)).toBe('3:05:09');
+  });
+});
+
+describe('describeTransfer', () => {
+  it('includes speed and ETA', () => {
+    expect(
+      describeTransfer({ type: 'download', fileName: 'large.bin', speedBps: 45.2 * MB, etaSeconds: 38 })
+    ).toBe('Downloading large.bin — 45.2 MB/s, ETA 0:38');
+  });
+
+  it('omits the ETA when unknown', () => {
+    expect(describeTransfer({ type: 'upload', fileName: 'a.txt', speedBps: 2048, etaSeconds: null })).toBe(
+      'Uploading a.txt — 2.0 KB/s'
+    );
+  });
+});
@@ -0,0 +1,53 @@
+import { renderHook, act } from '@testing-library/react';
+import { describe, it, expect, vi, afterEach } from 'vitest';
+import { useColorScheme } from '../renderer/hooks/useColorScheme';
+
+function mockMatchMedia(matches: boolean) {
+  const query = {
+    matches,
+    listeners: new Set<() => void>(),
+    addEventListener: vi.fn((_: string, listener: () => void) => query.listeners.add(listener)),
+    removeEventListener: vi.fn((_: string, listener: () => void) => query.listeners.delete(listener)),
+  };
+  window.matchMedia = vi.fn(() => query as unknown as MediaQueryList);
+  return query;
+}
+
+describe('useColorScheme', () => {
+  const originalMatchMedia = window.matchMedia;
+
+  afterEach(() => {
+    window.matchMedia = originalMatchMedia;
+  });
+
+  it('follows the light color scheme', () => {
+    mockMatchMedia(true);
+
+    const { result } = renderHook(() => useColorScheme());
+
+    expect(result.current).toBe('light');
+    expect(window.matchMedia).toHaveBeenCalledWith('(prefers-color-scheme: light)');
+  });
+
+  it('updates when the theme changes', () => {
+    const query = mockMatchMedia(false);
+    const { result } = renderHook(() => useColorScheme());
+    expect(result.current).toBe('dark');
+
+    act(() => {
+      query.matches = true;
+      query.listeners.forEach(listener => listener());
+    });
+
+    expect(result.current).toBe('light');
+  });
+
+  it('stops listening on unmount', () => {
+    const query = mockMatchMedia(false);
+    const { unmount } = renderHook(() => useColorScheme());
+
+    unmount();
+
+    expect(query.listeners.size).toBe(0);
+  });
+});
@@ -0,0 +1,84 @@
+import { renderHook, act } from '@testing-library/react';
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { useElapsedSeconds } from '../renderer/hooks/useElapsedSeconds';
+
+describe('useElapsedSeconds', () => {
+  beforeEach(() => {
+    vi.useFakeTimers();
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+  });
+
+  it('stays at 0 while inactive', () => {
+    const { result } = renderHook(() => useElapsedSeconds(false));
+
+    act(() => {
+      vi.advanceTimersByTime(5000);
+    });
+
+    expect(result.current).toBe(0);
+  });
+
+  it('ticks every second while active', () => {
+    const { result } = renderHook(() => useElapsedSeconds(true));
+
+    act(() => {
+      vi.advanceTimersByTime(1000);
+    });
+    expect(result.current).toBe(1);
+
+    act(() => {
+      vi.advanceTimersByTime(11000);
+    });
+    expect(result.current).toBe(12);
+  });
+
+  it('resets and stops when loading finishes', () => {
+    const { result, rerender } = renderHook(({ active }) => useElapsedSeconds(active), {
+      initialProps: { active: true },
+    });
+
+    act(() => {
+      vi.advanceTimersByTime(3000);
+    });
+    expect(result.current).toBe(3);
+
+    rerender({ active: false });
+    expect(result.current).toBe(0);
+    expect(vi.getTimerCount()).toBe(0);
+
+    act(() => {
+      vi.advanceTimersByTime(3000);
+    });
+    expect(result.current).toBe(0);
+  });
+
+  it('restarts from 0 for a new load with only one ticker running', () => {
+    const { result, rerender } = renderHook(({ active }) => useElapsedSeconds(active), {
+      initialProps: { active: true },
+    });
+
+    act(() => {
+      vi.advanceTimersByTime(5000);
+    });
+    rerender({ active: false });
+    rerender({ active: true });
+
+    expect(vi.getTimerCount()).toBe(1);
+
+    act(() => {
+      vi.advanceTimersByTime(2000);
+    });
+    expect(result.current).toBe(2);
+  });
+
+  it('clears the ticker on unmount', () => {
+    const { unmount } = renderHook(() => useElapsedSeconds(true));
+
+    unmount();
+
+    expect(vi.getTimerCount()).toBe(0);
+  });
+});
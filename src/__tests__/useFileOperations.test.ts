@@ -6,12 +6,19 @@ import { useFileOperations } from '../renderer/hooks/useFileOperations';
 const mockElectronAPI = {
   s3: {
     downloadFile: vi.fn(),
+    downloadFiles: vi.fn(),
+    openExternally: vi.fn(),
     uploadFiles: vi.fn(),
     deleteFile: vi.fn(),
     deleteFiles: vi.fn(),
     deletePrefix: vi.fn(),
     renameFile: vi.fn(),
+    renamePrefix: vi.fn(),
+    cancelOperation: vi.fn(),
     showOpenDialog: vi.fn(),
+    onTransferProgress: vi.fn(
+      (_callback: (progress: { operationId: string; loaded: number; total: number }) => void) => () => {}
+    ),
   },
 };
 
@@ -93,6 +100,52 @@ describe('useFileOperations', () => {
       });
     });
 
+    it('should mark skipped downloads without calling onDownloadComplete', async () => {
+      mockElectronAPI.s3.downloadFile.mockResolvedValue({ success: true, skipped: true });
+
+      const onDownloadComplete = vi.fn();
+      const { result } = renderHook(() => useFileOperations({ onDownloadComplete }));
+
+      await act(async () => {
+        await result.current.downloadFile('test-bucket', 'folder/my-file.txt');
+      });
+
+      expect(result.current.operations[0].status).toBe('completed');
+      expect(result.current.operations[0].note).toBe('Skipped: file already exists');
+      expect(onDownloadComplete).not.toHaveBeenCalled();
+    });
+
+    it('should track speed and ETA from progress events', async () => {
+      let emitProgress: (progress: { operationId: string; loaded: number; total: number }) => void = () => {};
+      mockElectronAPI.s3.onTransferProgress.mockImplementationOnce((callback) => {
+        emitProgress = callback;
+        return () => {};
+      });
+      let finishDownload: (value: { success: boolean; localPath: string }) => void = () => {};
+      mockElectronAPI.s3.downloadFile.mockReturnValue(new Promise(resolve => { finishDownload = resolve; }));
+
+      const { result } = renderHook(() => useFileOperations());
+      let download: Promise<void>;
+      act(() => {
+        download = result.current.downloadFile('test-bucket', 'large.bin');
+      });
+      const opId = result.current.operations[0].id;
+
+      act(() => {
+        emitProgress({ operationId: opId, loaded: 1024, total: 4096 });
+      });
+
+      expect(result.current.operations[0].loaded).toBe(1024);
+      expect(result.current.operations[0].total).toBe(4096);
+      expect(result.current.operations[0].speedBps).toBeDefined();
+
+      await act(async () => {
+        finishDownload({ success: true, localPath: '/downloads/large.bin' });
+        await download;
+      });
+      expect(result.current.operations[0].status).toBe('completed');
+    });
+
     it('should not call onDownloadComplete callback on error', async () => {
       mockElectronAPI.s3.downloadFile.mockResolvedValue({ success: false, error: 'Failed' });
 
@@ -120,6 +173,76 @@ describe('useFileOperations', () => {
     });
   });
 
+  describe('downloadFiles', () => {
+    it('should track each file and notify once for the whole batch', async () => {
+      mockElectronAPI.s3.downloadFiles.mockResolvedValue({
+        success: false,
+        results: [
+          { key: 'a.txt', success: true, localPath: '/downloads/a.txt' },
+          { key: 'logs/b.txt', success: false, error: 'Access denied' },
+          { key: 'c.txt', success: true, localPath: '/downloads/c.txt' },
+        ],
+      });
+
+      const onDownloadComplete = vi.fn();
+      const { result } = renderHook(() => useFileOperations({ onDownloadComplete }));
+
+      await act(async () => {
+        await result.current.downloadFiles('test-bucket', ['a.txt', 'logs/b.txt', 'c.txt']);
+      });
+
+      expect(mockElectronAPI.s3.downloadFiles).toHaveBeenCalledWith(
+        'test-bucket',
+        ['a.txt', 'logs/b.txt', 'c.txt'],
+        expect.any(String)
+      );
+      expect(result.current.operations.map(op => [op.fileName, op.status])).toEqual([
+        ['a.txt', 'completed'],
+        ['b.txt', 'error'],
+        ['c.txt', 'completed'],
+      ]);
+      expect(result.current.operations[1].error).toBe('Access denied');
+      expect(onDownloadComplete).toHaveBeenCalledTimes(1);
+      expect(onDownloadComplete).toHaveBeenCalledWith({ fileName: '2 files', localPath: '/downloads/a.txt' });
+    });
+  });
+
+  describe('openExternally', () => {
+    it('should track the download and note the file was opened', async () => {
+      mockElectronAPI.s3.openExternally.mockResolvedValue({ success: true, localPath: '/tmp/s3-browser-open/x/report.xlsx' });
+
+      const { result } = renderHook(() => useFileOperations());
+
+      await act(async () => {
+        await result.current.openExternally('test-bucket', 'docs/report.xlsx');
+      });
+
+      expect(mockElectronAPI.s3.openExternally).toHaveBeenCalledWith('test-bucket', 'docs/report.xlsx', expect.any(String));
+      expect(result.current.operations[0]).toMatchObject({
+        type: 'download',
+        fileName: 'report.xlsx',
+        status: 'completed',
+        note: 'Opened with default app',
+      });
+    });
+
+    it('should report when no app can open the file', async () => {
+      mockElectronAPI.s3.openExternally.mockResolvedValue({
+        success: false,
+        error: 'No application is available to open data.xyz: Failed to open path',
+      });
+
+      const { result } = renderHook(() => useFileOperations());
+
+      await act(async () => {
+        await result.current.openExternally('test-bucket', 'data.xyz');
+      });
+
+      expect(result.current.operations[0].status).toBe('error');
+      expect(result.current.operations[0].error).toContain('No application is available');
+    });
+  });
+
   describe('uploadFiles', () => {
     it('should show file dialog when no paths provided', async () => {
       mockElectronAPI.s3.showOpenDialog.mockResolvedValue(['/path/to/file1.txt', '/path/to/file2.txt']);
@@ -498,6 +621,63 @@ describe('useFileOperations', () => {
     });
   });
 
+  describe('renamePrefix', () => {
+    it('should track the move as a cancellable operation counting objects', async () => {
+      let emitProgress: (progress: { operationId: string; loaded: number; total: number }) => void = () => {};
+      mockElectronAPI.s3.onTransferProgress.mockImplementationOnce((callback) => {
+        emitProgress = callback;
+        return () => {};
+      });
+      let finishMove: (value: { success: boolean; movedCount: number; failures: [] }) => void = () => {};
+      mockElectronAPI.s3.renamePrefix.mockReturnValue(new Promise(resolve => { finishMove = resolve; }));
+
+      const { result } = renderHook(() => useFileOperations());
+      let move: Promise<unknown>;
+      act(() => {
+        move = result.current.renamePrefix('test-bucket', 'data/old/', 'new');
+      });
+      const op = result.current.operations[0];
+      expect(op).toMatchObject({ type: 'rename', fileName: 'old/', status: 'in-progress', cancellable: true });
+      expect(mockElectronAPI.s3.renamePrefix).toHaveBeenCalledWith('test-bucket', 'data/old/', 'new', op.id);
+
+      act(() => {
+        emitProgress({ operationId: op.id, loaded: 3, total: 10 });
+      });
+      expect(result.current.operations[0].note).toBe('3 of 10 objects');
+      expect(result.current.operations[0].speedBps).toBeUndefined();
+
+      act(() => {
+        result.current.cancelOperation(op.id);
+      });
+      expect(mockElectronAPI.s3.cancelOperation).toHaveBeenCalledWith(op.id);
+
+      await act(async () => {
+        finishMove({ success: true, movedCount: 10, failures: [] });
+        await move;
+      });
+      expect(result.current.operations[0].status).toBe('completed');
+    });
+
+    it('should show why the move failed', async () => {
+      mockElectronAPI.s3.renamePrefix.mockResolvedValue({
+        success: false,
+        movedCount: 0,
+        failures: [],
+        error: 'A folder named data/new/ already exists',
+      });
+
+      const { result } = renderHook(() => useFileOperations());
+      await act(async () => {
+        await result.current.renamePrefix('test-bucket', 'data/old/', 'new');
+      });
+
+      expect(result.current.operations[0]).toMatchObject({
+        status: 'error',
+        error: 'A folder named data/new/ already exists',
+      });
+    });
+  });
+
   describe('dismissOperation', () => {
     it('should remove operation from list', async () => {
       mockElectronAPI.s3.downloadFile.mockResolvedValue({ success: false, error: 'Error' });
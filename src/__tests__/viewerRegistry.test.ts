@@ -0,0 +1,160 @@
+import { describe, it, expect } from 'vitest';
+import {
+  resolveViewer,
+  looksLikeText,
+  ViewerRegistry,
+  defaultViewerRegistry,
+} from '../renderer/utils/viewerRegistry';
+
+const bytes = (...values: number[]) => new Uint8Array(values);
+const text = (value: string) => new TextEncoder().encode(value);
+
+describe('resolveViewer', () => {
+  describe('by extension', () => {
+    it('resolves parquet files', () => {
+      expect(resolveViewer('data/part-0000.parquet')).toBe('parquet');
+    });
+
+    it('resolves csv and tsv files, including gzipped', () => {
+      expect(resolveViewer('report.csv')).toBe('csv');
+      expect(resolveViewer('report.TSV')).toBe('csv');
+      expect(resolveViewer('report.csv.gz')).toBe('csv');
+    });
+
+    it('resolves json files, including gzipped', () => {
+      expect(resolveViewer('config.json')).toBe('json');
+      expect(resolveViewer('events.json.gz')).toBe('json');
+    });
+
+    it('resolves yaml files', () => {
+      expect(resolveViewer('deploy.yaml')).toBe('yaml');
+      expect(resolveViewer('deploy.yml')).toBe('yaml');
+    });
+
+    it('resolves image files', () => {
+      expect(resolveViewer('photo.JPG')).toBe('image');
+      expect(resolveViewer('icon.svg')).toBe('image');
+    });
+
+    it('resolves text files', () => {
+      expect(resolveViewer('README.md')).toBe('text');
+      expect(resolveViewer('app.log.gz')).toBe('text');
+    });
+
+    it('looks through zstd and snappy compression', () => {
+      expect(resolveViewer('events.json.zst')).toBe('json');
+      expect(resolveViewer('part-0001.csv.snappy')).toBe('csv');
+      expect(resolveViewer('app.LOG.ZST')).toBe('text');
+      expect(resolveViewer('data.parquet.zst')).toBe('hex');
+    });
+
+    it('does not look through .gz for viewers without gzip support', () => {
+      expect(resolveViewer('data.parquet.gz')).toBe('hex');
+      expect(resolveViewer('photo.png.gz')).toBe('hex');
+    });
+
+    it('prefers the extension over the content', () => {
+      expect(resolveViewer('data.csv', text('{"a": 1}'))).toBe('csv');
+    });
+  });
+
+  describe('by content', () => {
+    it('detects parquet magic bytes', () => {
+      expect(resolveViewer('part-0000', text('PAR1\u0015\u0004'))).toBe('parquet');
+    });
+
+    it('detects PNG, JPEG and GIF signatures', () => {
+      expect(resolveViewer('thumb', bytes(0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00))).toBe('image');
+      expect(resolveViewer('thumb', bytes(0xff, 0xd8, 0xff, 0xe0))).toBe('image');
+      expect(resolveViewer('thumb', text('GIF89a'))).toBe('image');
+    });
+
+    it('detects WebP signatures', () => {
+      expect(resolveViewer('thumb', text('RIFF\u0000\u0000\u0000\u0000WEBPVP8 '))).toBe('image');
+    });
+
+    it('detects JSON content', () => {
+      expect(resolveViewer('payload', text('  \n{"id": 1}'))).toBe('json');
+      expect(resolveViewer('payload', text('[1, 2, 3]'))).toBe('json');
+    });
+
+    it('detects plain text content', () => {
+      expect(resolveViewer('Makefile', text('build:\n\tcargo build\n'))).toBe('text');
+    });
+  });
+
+  describe('fallback', () => {
+    it('falls back to hex for unknown extensions without content', () => {
+      expect(resolveViewer('archive.bin')).toBe('hex');
+      expect(resolveViewer('no-extension')).toBe('hex');
+    });
+
+    it('falls back to hex for binary content', () => {
+      expect(resolveViewer('blob', bytes(0x00, 0x01, 0x02, 0xff))).toBe('hex');
+      expect(resolveViewer('blob.gz', bytes(0x1f, 0x8b, 0x08, 0x00))).toBe('hex');
+    });
+
+    it('falls back to hex for empty content', () => {
+      expect(resolveViewer('empty', new Uint8Array())).toBe('hex');
+    });
+  });
+});
+
+describe('looksLikeText', () => {
+  it('accepts UTF-8 text', () => {
+    expect(looksLikeText(text('héllo wörld'))).toBe(true);
+  });
+
+  it('accepts text with a multi-byte character cut off at the end', () => {
+    const encoded = text('naïve');
+    expect(looksLikeText(encoded.subarray(0, 3))).toBe(true);
+  });
+
+  it('rejects content containing NUL bytes', () => {
+    expect(looksLikeText(bytes(0x61, 0x00, 0x62))).toBe(false);
+  });
+
+  it('rejects invalid UTF-8', () => {
+    expect(looksLikeText(bytes(0xc3, 0x28, 0x61))).toBe(false);
+  });
+});
+
+describe('ViewerRegistry', () => {
+  it('returns null from resolveByExtension for unknown extensions', () => {
+    expect(defaultViewerRegistry.resolveByExtension('file.xyz')).toBeNull();
+  });
+
+  it('resolves viewers from the Content-Type, ignoring parameters', () => {
+    expect(defaultViewerRegistry.resolveByContentType('application/json; charset=utf-8')).toBe('json');
+    expect(defaultViewerRegistry.resolveByContentType('Image/PNG')).toBe('image');
+    expect(defaultViewerRegistry.resolveByContentType('text/csv')).toBe('csv');
+    expect(defaultViewerRegistry.resolveByContentType('text/yaml')).toBe('yaml');
+    expect(defaultViewerRegistry.resolveByContentType('text/x-markdown')).toBe('text');
+    expect(defaultViewerRegistry.resolveByContentType('application/vnd.apache.parquet')).toBe('parquet');
+  });
+
+  it('returns null from resolveByContentType for missing or unclaimed types', () => {
+    expect(defaultViewerRegistry.resolveByContentType(undefined)).toBeNull();
+    expect(defaultViewerRegistry.resolveByContentType('')).toBeNull();
+    expect(defaultViewerRegistry.resolveByContentType('application/octet-stream')).toBeNull();
+  });
+
+  it('only opens image types the image preview can render', () => {
+    expect(defaultViewerRegistry.resolveByContentType('image/webp')).toBe('image');
+    expect(defaultViewerRegistry.resolveByContentType('image/svg+xml')).toBe('image');
+    expect(defaultViewerRegistry.resolveByContentType('image/tiff')).toBeNull();
+    expect(defaultViewerRegistry.resolveByContentType('image/heic')).toBeNull();
+  });
+
+  it('uses the first registration that claims an extension', () => {
+    const registry = new ViewerRegistry()
+      .register({ kind: 'text', extensions: ['dat'] })
+      .register({ kind: 'csv', extensions: ['dat'] });
+
+    expect(registry.resolve('values.dat')).toBe('text');
+  });
+
+  it('falls back to hex when nothing is registered', () => {
+    expect(new ViewerRegistry().resolve('file.json', text('{}'))).toBe('hex');
+  });
+});
@@ -0,0 +1,24 @@
+import { describe, it, expect } from 'vitest';
+import { DEFAULT_ROW_HEIGHT, getVisibleRowRange } from '../renderer/utils/visibleRows';
+
+describe('getVisibleRowRange', () => {
+  it('covers the rows in the viewport plus the overscan below', () => {
+    expect(getVisibleRowRange(0, 300, 30, 5)).toEqual({ start: 0, end: 15 });
+  });
+
+  it('extends the overscan above once scrolled down', () => {
+    expect(getVisibleRowRange(3000, 300, 30, 5)).toEqual({ start: 95, end: 115 });
+  });
+
+  it('includes partially visible rows at both edges', () => {
+    expect(getVisibleRowRange(45, 60, 30, 0)).toEqual({ start: 1, end: 4 });
+  });
+
+  it('falls back to the default row height before rows are measured', () => {
+    expect(getVisibleRowRange(0, DEFAULT_ROW_HEIGHT * 4, 0, 0)).toEqual({ start: 0, end: 4 });
+  });
+
+  it('still covers the overscan when the list has no height yet', () => {
+    expect(getVisibleRowRange(0, 0, 30, 5)).toEqual({ start: 0, end: 5 });
+  });
+});
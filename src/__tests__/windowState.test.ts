@@ -0,0 +1,60 @@
+import { describe, it, expect, vi } from 'vitest';
+
+vi.mock('electron', () => ({
+  app: { getPath: vi.fn(() => '/tmp/test-app-data') },
+  screen: {},
+}));
+
+import { restoreWindowBounds, DEFAULT_WINDOW_SIZE, MIN_WINDOW_SIZE } from '../main/services/windowState';
+
+const primary = { x: 0, y: 0, width: 1920, height: 1040 };
+const leftMonitor = { x: -1600, y: 0, width: 1600, height: 900 };
+
+describe('restoreWindowBounds', () => {
+  it('uses the default size when nothing was saved', () => {
+    expect(restoreWindowBounds(null, [primary])).toEqual(DEFAULT_WINDOW_SIZE);
+  });
+
+  it('restores a position that is on a connected display', () => {
+    const saved = { width: 1000, height: 700, x: 200, y: 100, maximized: false };
+
+    expect(restoreWindowBounds(saved, [primary])).toEqual({ width: 1000, height: 700, x: 200, y: 100 });
+    expect(restoreWindowBounds({ ...saved, x: -1400 }, [primary, leftMonitor])).toEqual({
+      width: 1000,
+      height: 700,
+      x: -1400,
+      y: 100,
+    });
+  });
+
+  it('centres a window last seen on a disconnected display', () => {
+    const saved = { width: 1000, height: 700, x: -1400, y: 100, maximized: false };
+
+    expect(restoreWindowBounds(saved, [primary])).toEqual({ width: 1000, height: 700 });
+  });
+
+  it('centres a window whose title bar would be above or beside every display', () => {
+    expect(restoreWindowBounds({ width: 1000, height: 700, x: 100, y: -500, maximized: false }, [primary])).toEqual({
+      width: 1000,
+      height: 700,
+    });
+    expect(restoreWindowBounds({ width: 1000, height: 700, x: 1880, y: 100, maximized: false }, [primary])).toEqual({
+      width: 1000,
+      height: 700,
+    });
+  });
+
+  it('keeps the size within the display and above the minimum', () => {
+    expect(restoreWindowBounds({ width: 3000, height: 2000, x: 0, y: 0, maximized: false }, [primary])).toEqual({
+      width: 1920,
+      height: 1040,
+      x: 0,
+      y: 0,
+    });
+    expect(restoreWindowBounds({ width: 300, height: 200, x: 0, y: 0, maximized: false }, [primary])).toEqual({
+      ...MIN_WINDOW_SIZE,
+      x: 0,
+      y: 0,
+    });
+  });
+});
@@ -0,0 +1,164 @@
+import { describe, it, expect } from 'vitest';
+import {
+  parseYamlDocuments,
+  yamlNodeToValue,
+  YamlParseError,
+  type YamlMapNode,
+  type YamlSeqNode,
+} from '../renderer/utils/yamlParser';
+
+describe('parseYamlDocuments', () => {
+  it('parses a two-document file into separate documents', () => {
+    const docs = parseYamlDocuments(
+      [
+        'apiVersion: v1',
+        'kind: ConfigMap',
+        'metadata:',
+        '  name: app-config',
+        '---',
+        'apiVersion: v1',
+        'kind: Service',
+        'spec:',
+        '  ports:',
+        '    - port: 80',
+        '      targetPort: 8080',
+      ].join('\n')
+    );
+
+    expect(docs).toHaveLength(2);
+    expect(yamlNodeToValue(docs[0])).toEqual({
+      apiVersion: 'v1',
+      kind: 'ConfigMap',
+      metadata: { name: 'app-config' },
+    });
+    expect(yamlNodeToValue(docs[1])).toEqual({
+      apiVersion: 'v1',
+      kind: 'Service',
+      spec: { ports: [{ port: 80, targetPort: 8080 }] },
+    });
+  });
+
+  it('ignores directives, document end markers and empty documents', () => {
+    const docs = parseYamlDocuments('%YAML 1.2\n---\na: 1\n...\n---\n# nothing here\n--- hello\n');
+
+    expect(docs.map(yamlNodeToValue)).toEqual([{ a: 1 }, 'hello']);
+  });
+
+  it('resolves aliases to the anchored content and marks them', () => {
+    const [doc] = parseYamlDocuments(
+      'defaults: &defaults\n  adapter: postgres\n  timeout: 30\ndevelopment:\n  <<: *defaults\n  database: dev\nproduction: *defaults\n'
+    );
+    const root = doc as YamlMapNode;
+
+    expect(root.entries[0].value.anchor).toBe('defaults');
+    const production = root.entries[2].value;
+    expect(production.alias).toBe('defaults');
+    expect(production.anchor).toBeUndefined();
+    expect(yamlNodeToValue(production)).toEqual({ adapter: 'postgres', timeout: 30 });
+    expect(yamlNodeToValue(doc)).toEqual({
+      defaults: { adapter: 'postgres', timeout: 30 },
+      development: { '<<': { adapter: 'postgres', timeout: 30 }, database: 'dev' },
+      production: { adapter: 'postgres', timeout: 30 },
+    });
+  });
+
+  it('scopes anchors to their document', () => {
+    expect(() => parseYamlDocuments('a: &x 1\n---\nb: *x\n')).toThrow('unidentified alias "x" (line 3)');
+  });
+
+  it('marks only the alias itself, not the collections inside the aliased content', () => {
+    const [doc] = parseYamlDocuments('base: &base\n  ports: &ports [80]\nother: *base\nmore: *ports\n');
+    const root = doc as YamlMapNode;
+
+    expect(root.entries[0].value.anchor).toBe('base');
+    expect((root.entries[0].value as YamlMapNode).entries[0].value.anchor).toBe('ports');
+    const other = root.entries[1].value as YamlMapNode;
+    expect(other.alias).toBe('base');
+    expect(other.entries[0].value.alias).toBeUndefined();
+    expect(other.entries[0].value.anchor).toBeUndefined();
+    expect(root.entries[2].value.alias).toBe('ports');
+  });
+
+  it('keeps explicit tags as annotations', () => {
+    const [doc] = parseYamlDocuments(
+      'bucket: !Ref MyBucket\ndata: !!binary aGVsbG8=\nitems: !Custom\n  - a\nport: !!str 8080\n'
+    );
+    const root = doc as YamlMapNode;
+
+    expect(root.entries[0].value).toEqual({ kind: 'scalar', value: 'MyBucket', tag: '!Ref' });
+    expect(root.entries[1].value).toEqual({ kind: 'scalar', value: 'aGVsbG8=', tag: '!!binary' });
+    expect(root.entries[2].value.tag).toBe('!Custom');
+    expect((root.entries[2].value as YamlSeqNode).items).toEqual([{ kind: 'scalar', value: 'a' }]);
+    // Tags of the core schema just set the type
+    expect(root.entries[3].value).toEqual({ kind: 'scalar', value: '8080' });
+  });
+
+  it('parses flow collections, quoted strings and block scalars', () => {
+    const [doc] = parseYamlDocuments(
+      [
+        'flow: [1, two, {k: v}]',
+        'double: "say \\"hi\\"\\n"',
+        "single: 'it''s'",
+        'url: http://example.com/a#frag # comment',
+        'literal: |',
+        '  line 1',
+        '   line 2',
+        'folded: >-',
+        '  a',
+        '  b',
+        '',
+        '  c',
+        'empty:',
+      ].join('\n')
+    );
+
+    expect(yamlNodeToValue(doc)).toEqual({
+      flow: [1, 'two', { k: 'v' }],
+      double: 'say "hi"\n',
+      single: "it's",
+      url: 'http://example.com/a#frag',
+      literal: 'line 1\n line 2\n',
+      folded: 'a b\nc',
+      empty: null,
+    });
+  });
+
+  it('parses compact nested sequences and sequences under a key', () => {
+    const [doc] = parseYamlDocuments('steps:\n- - a\n  - b\n- name: build\n  run: make\n');
+
+    expect(yamlNodeToValue(doc)).toEqual({ steps: [['a', 'b'], { name: 'build', run: 'make' }] });
+  });
+
+  it('reports the line of indentation errors', () => {
+    try {
+      parseYamlDocuments('a:\n  b: 1\n c: 2\n');
+      expect.unreachable();
+    } catch (err) {
+      expect(err).toBeInstanceOf(YamlParseError);
+      expect((err as YamlParseError).line).toBe(3);
+    }
+  });
+
+  it('returns no documents for comment-only input', () => {
+    expect(parseYamlDocuments('# just a comment\n')).toEqual([]);
+  });
+
+  it('resolves plain scalars with the core schema', () => {
+    const [doc] = parseYamlDocuments('a: ~\nb: True\nc: 42\nd: 0x1F\ne: 1.5e3\nf: -.inf\ng: yes\nh: 2024-01-01\n');
+
+    expect(yamlNodeToValue(doc)).toEqual({
+      a: null,
+      b: true,
+      c: 42,
+      d: 31,
+      e: 1500,
+      f: -Infinity,
+      g: 'yes',
+      h: '2024-01-01',
+    });
+  });
+
+  it('reports malformed escapes as parse errors', () => {
+    expect(() => parseYamlDocuments('a: "\\U0011ZZZZ"\n')).toThrow(YamlParseError);
+  });
+});
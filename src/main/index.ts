@@ -1,19 +1,22 @@
 import { app, BrowserWindow, shell, Menu } from 'electron';
 import path from 'path';
-import { registerCredentialsIpc } from './ipc/credentials';
+import { registerCredentialsIpc, enableProfileAutoReload } from './ipc/credentials';
 import { registerS3Ipc } from './ipc/s3';
 import { registerAppStateIpc } from './ipc/appState';
+import { cleanupExternalOpenDir } from './services/localFiles';
+import { initialWindowBounds, trackWindowState, MIN_WINDOW_SIZE } from './services/windowState';
 
 let mainWindow: BrowserWindow | null = null;
+let stopProfileAutoReload: (() => void) | null = null;
 
 const isDev = process.env.NODE_ENV === 'development';
 
 function createWindow(): void {
+  const { maximized, ...bounds } = initialWindowBounds();
   mainWindow = new BrowserWindow({
-    width: 1200,
-    height: 800,
-    minWidth: 800,
-    minHeight: 600,
+    ...bounds,
+    minWidth: MIN_WINDOW_SIZE.width,
+    minHeight: MIN_WINDOW_SIZE.height,
     webPreferences: {
       preload: path.join(__dirname, '../preload/index.js'),
       contextIsolation: true,
@@ -30,9 +33,15 @@ function createWindow(): void {
 
   // Show window when ready to prevent visual flash
   mainWindow.once('ready-to-show', () => {
+    if (maximized) {
+      mainWindow?.maximize();
+    }
     mainWindow?.show();
   });
 
+  // Remember the size and position for the next launch
+  trackWindowState(mainWindow);
+
   // Open external links in default browser
   mainWindow.webContents.setWindowOpenHandler(({ url }) => {
     shell.openExternal(url);
@@ -71,6 +80,7 @@ app.whenReady().then(() => {
   registerCredentialsIpc();
   registerS3Ipc();
   registerAppStateIpc();
+  stopProfileAutoReload = enableProfileAutoReload();
   createWindow();
 });
 
@@ -80,6 +90,12 @@ app.on('window-all-closed', () => {
   }
 });
 
+// Remove files downloaded for "Open with default app"
+app.on('will-quit', () => {
+  stopProfileAutoReload?.();
+  cleanupExternalOpenDir();
+});
+
 app.on('activate', () => {
   if (BrowserWindow.getAllWindows().length === 0) {
     createWindow();
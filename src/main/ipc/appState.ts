@@ -1,5 +1,31 @@
-import { ipcMain } from 'electron';
-import { loadAppState, saveAppState, type AppState } from '../services/appState';
+import { app, dialog, ipcMain, nativeTheme } from 'electron';
+import fs from 'fs';
+import path from 'path';
+import {
+  loadAppState,
+  saveAppState,
+  exportShareable,
+  parseShareable,
+  mergeShareable,
+  addBookmark,
+  removeBookmark,
+  setColumnWidths,
+  getTheme,
+  type AppState,
+  type Bookmark,
+  type RecentLocation,
+  type Theme,
+  type ViewerLayout,
+} from '../services/appState';
+import type { ConflictPolicy } from '../services/localFiles';
+import type { UploadStorageClass } from '../services/storageClasses';
+import {
+  setRequestLogging,
+  setRequesterPays,
+  setMaxRetries,
+  setRequestTimeouts,
+  setProxyUrl,
+} from '../services/s3Service';
 
 /**
  * Interface for the state data exposed to renderer
@@ -9,12 +35,47 @@ export interface AppStateData {
   lastProfile: string | null;
   lastBucket: string | null;
   lastPrefix: string;
+  readOnlyProfiles: string[];
+  downloadConflictPolicy: ConflictPolicy;
+  collapseSingleChildFolders: boolean;
+  confirmBeforeDelete: boolean;
+  recentLocations: RecentLocation[];
+  bookmarks: Bookmark[];
+  viewerLayouts: Record<string, ViewerLayout>;
+  columnWidths: Record<string, Record<string, number>>;
+  requestLogging: boolean;
+  requesterPays: boolean;
+  maxRetries: number | null;
+  connectTimeoutSeconds: number | null;
+  requestTimeoutSeconds: number | null;
+  uploadStorageClass: UploadStorageClass | null;
+  proxyUrl: string | null;
+  theme: Theme;
+}
+
+/**
+ * Applies the stored timeouts to the S3 clients
+ */
+function applyRequestTimeouts(state: AppState): void {
+  setRequestTimeouts({
+    connectTimeoutMs: state.connectTimeoutSeconds === null ? null : state.connectTimeoutSeconds * 1000,
+    requestTimeoutMs: state.requestTimeoutSeconds === null ? null : state.requestTimeoutSeconds * 1000,
+  });
 }
 
 /**
  * Register IPC handlers for app state persistence
  */
 export function registerAppStateIpc(): void {
+  const initialState = loadAppState();
+  setRequestLogging(initialState.requestLogging);
+  setRequesterPays(initialState.requesterPays);
+  setMaxRetries(initialState.maxRetries);
+  applyRequestTimeouts(initialState);
+  setProxyUrl(initialState.proxyUrl);
+  // The renderer's prefers-color-scheme follows the native theme source
+  nativeTheme.themeSource = initialState.theme;
+
   // Load app state
   ipcMain.handle('app-state:load', async (): Promise<AppStateData> => {
     const state = loadAppState();
@@ -22,6 +83,22 @@ export function registerAppStateIpc(): void {
       lastProfile: state.lastProfile,
       lastBucket: state.lastBucket,
       lastPrefix: state.lastPrefix,
+      readOnlyProfiles: state.readOnlyProfiles,
+      downloadConflictPolicy: state.downloadConflictPolicy,
+      collapseSingleChildFolders: state.collapseSingleChildFolders,
+      confirmBeforeDelete: state.confirmBeforeDelete,
+      recentLocations: state.recentLocations,
+      bookmarks: state.bookmarks,
+      viewerLayouts: state.viewerLayouts,
+      columnWidths: state.columnWidths,
+      requestLogging: state.requestLogging,
+      requesterPays: state.requesterPays,
+      maxRetries: state.maxRetries,
+      connectTimeoutSeconds: state.connectTimeoutSeconds,
+      requestTimeoutSeconds: state.requestTimeoutSeconds,
+      uploadStorageClass: state.uploadStorageClass,
+      proxyUrl: state.proxyUrl,
+      theme: state.theme,
     };
   });
 
@@ -37,6 +114,26 @@ export function registerAppStateIpc(): void {
         if (!success) {
           return { success: false, error: 'Failed to write state file' };
         }
+        if (data.requestLogging !== undefined) {
+          setRequestLogging(data.requestLogging);
+        }
+        if (data.requesterPays !== undefined) {
+          setRequesterPays(data.requesterPays);
+        }
+        if (data.maxRetries !== undefined) {
+          // Apply the stored value, which loading has validated
+          setMaxRetries(loadAppState().maxRetries);
+        }
+        if (data.connectTimeoutSeconds !== undefined || data.requestTimeoutSeconds !== undefined) {
+          applyRequestTimeouts(loadAppState());
+        }
+        if (data.proxyUrl !== undefined) {
+          // Apply the stored value, which loading has validated
+          setProxyUrl(loadAppState().proxyUrl);
+        }
+        if (data.theme !== undefined) {
+          nativeTheme.themeSource = getTheme();
+        }
         return { success: true };
       } catch (error) {
         return {
@@ -46,4 +143,85 @@ export function registerAppStateIpc(): void {
       }
     }
   );
+
+  // Bookmark a location, or relabel an existing bookmark
+  ipcMain.handle(
+    'app-state:add-bookmark',
+    async (_event, bookmark: Bookmark): Promise<{ success: boolean; bookmarks?: Bookmark[]; error?: string }> => {
+      const bookmarks = addBookmark(bookmark);
+      return bookmarks ? { success: true, bookmarks } : { success: false, error: 'Failed to write state file' };
+    }
+  );
+
+  // Remember the column widths the user set for a file/schema signature
+  ipcMain.handle(
+    'app-state:set-column-widths',
+    async (
+      _event,
+      signature: string,
+      widths: Record<string, number>
+    ): Promise<{ success: boolean; error?: string }> => {
+      return setColumnWidths(signature, widths)
+        ? { success: true }
+        : { success: false, error: 'Failed to write state file' };
+    }
+  );
+
+  // Remove the bookmark of a location
+  ipcMain.handle(
+    'app-state:remove-bookmark',
+    async (
+      _event,
+      location: RecentLocation
+    ): Promise<{ success: boolean; bookmarks?: Bookmark[]; error?: string }> => {
+      const bookmarks = removeBookmark(location);
+      return bookmarks ? { success: true, bookmarks } : { success: false, error: 'Failed to write state file' };
+    }
+  );
+
+  // Export recent locations and read-only profiles to a file for sharing with a team
+  ipcMain.handle(
+    'app-state:export-shareable',
+    async (): Promise<{ success: boolean; filePath?: string; cancelled?: boolean; error?: string }> => {
+      try {
+        const result = await dialog.showSaveDialog({
+          title: 'Export Locations',
+          defaultPath: path.join(app.getPath('downloads'), 's3-browser-locations.json'),
+          filters: [{ name: 'JSON', extensions: ['json'] }],
+        });
+        if (result.canceled || !result.filePath) {
+          return { success: false, cancelled: true };
+        }
+        await fs.promises.writeFile(result.filePath, exportShareable(loadAppState()), 'utf-8');
+        return { success: true, filePath: result.filePath };
+      } catch (error) {
+        return { success: false, error: error instanceof Error ? error.message : 'Unknown error exporting' };
+      }
+    }
+  );
+
+  // Merge a shared export into the current state
+  ipcMain.handle(
+    'app-state:import-shareable',
+    async (): Promise<{ success: boolean; added?: number; cancelled?: boolean; error?: string }> => {
+      try {
+        const result = await dialog.showOpenDialog({
+          title: 'Import Locations',
+          properties: ['openFile'],
+          filters: [{ name: 'JSON', extensions: ['json'] }],
+        });
+        if (result.canceled || result.filePaths.length === 0) {
+          return { success: false, cancelled: true };
+        }
+        const shared = parseShareable(await fs.promises.readFile(result.filePaths[0], 'utf-8'));
+        const { update, added } = mergeShareable(loadAppState(), shared);
+        if (!saveAppState(update)) {
+          return { success: false, error: 'Failed to write state file' };
+        }
+        return { success: true, added };
+      } catch (error) {
+        return { success: false, error: error instanceof Error ? error.message : 'Unknown error importing' };
+      }
+    }
+  );
 }
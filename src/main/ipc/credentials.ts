@@ -1,24 +1,79 @@
-import { ipcMain } from 'electron';
+import { ipcMain, shell, BrowserWindow, type WebContents } from 'electron';
 import {
   loadAwsProfiles,
   getProfile,
+  createAnonymousProfile,
   validateProfile,
   getProfileTypeDescription,
+  buildProfileDiagnosticReport,
+  addProfile,
+  updateProfile,
+  removeProfile,
+  getProfileConfig,
   type AwsProfile,
+  type ProfileConfig,
+  type ProfileWriteResult,
   type ProfileType,
 } from '../services/awsCredentials';
+import {
+  ssoLogin,
+  getSsoTokenStatus,
+  listSsoRoles,
+  type SsoLoginResult,
+  type SsoTokenStatus,
+  type SsoRoleListResult,
+} from '../services/ssoLogin';
+import { createMfaCodeRequests } from '../services/mfaPrompt';
+import { setMfaCodeProvider, resolveProfileRegion } from '../services/s3Service';
+import { exportCredentialsAsEnv, formatEnvExports } from '../services/credentialExport';
+import { watchAwsConfigFiles } from '../services/configWatcher';
 
 // Store the currently selected profile
 let currentProfile: string | null = null;
 
+// The SSO sign-in in progress, so it can be cancelled
+let ssoLoginController: AbortController | null = null;
+
+// MFA codes are asked for in the app window and answered through aws:submit-mfa-code
+const mfaCodeRequests = createMfaCodeRequests(request => {
+  const window = BrowserWindow.getFocusedWindow() ?? BrowserWindow.getAllWindows()[0];
+  if (!window || window.webContents.isDestroyed()) {
+    return false;
+  }
+  rejectMfaRequestsWhenGone(window.webContents);
+  window.webContents.send('aws:mfa-code-request', { ...request, profileName: currentProfile });
+  return true;
+});
+
+// Windows whose MFA dialogs are already tied to the pending requests
+const mfaAskingContents = new WeakSet<WebContents>();
+
+/**
+ * A reloaded, crashed or closed window can no longer answer, so its requests are rejected
+ */
+function rejectMfaRequestsWhenGone(contents: WebContents): void {
+  if (mfaAskingContents.has(contents)) {
+    return;
+  }
+  mfaAskingContents.add(contents);
+  const cancel = () => mfaCodeRequests.cancelAll('The window asking for the MFA code was closed or reloaded');
+  contents.on('did-navigate', cancel);
+  contents.on('render-process-gone', cancel);
+  contents.once('destroyed', cancel);
+}
+
 export interface ProfileInfo {
   name: string;
   region?: string;
+  /** The region requests are made in: the environment's, else the profile's or the app's default */
+  resolvedRegion: string;
   hasCredentials: boolean;
   isValid: boolean;
   validationMessage?: string;
   profileType: ProfileType;
   profileTypeDescription: string;
+  /** For SSO profiles, whether the cached token is still valid */
+  ssoTokenStatus?: SsoTokenStatus;
 }
 
 export interface CredentialsState {
@@ -27,26 +82,43 @@ export interface CredentialsState {
   defaultRegion?: string;
 }
 
+/**
+ * Profiles from the AWS config files, followed by the built-in anonymous profile
+ */
+function listProfiles(): { profiles: AwsProfile[]; defaultRegion?: string } {
+  const { profiles, defaultRegion } = loadAwsProfiles();
+  return { profiles: [...profiles, createAnonymousProfile()], defaultRegion };
+}
+
+/**
+ * Summary of a profile for the profile selector
+ */
+function toProfileInfo(profile: AwsProfile): ProfileInfo {
+  const validation = validateProfile(profile);
+  return {
+    name: profile.name,
+    region: profile.region,
+    resolvedRegion: resolveProfileRegion(profile),
+    hasCredentials: profile.hasCredentials,
+    isValid: validation.valid,
+    validationMessage: validation.reason,
+    profileType: profile.profileType,
+    profileTypeDescription: getProfileTypeDescription(profile.profileType),
+    ssoTokenStatus: profile.profileType === 'sso' ? getSsoTokenStatus(profile) : undefined,
+  };
+}
+
 /**
  * Register IPC handlers for AWS credentials operations
  */
 export function registerCredentialsIpc(): void {
+  setMfaCodeProvider(mfaCodeRequests.request);
+
   // Get list of available AWS profiles
   ipcMain.handle('aws:get-profiles', async (): Promise<CredentialsState> => {
-    const { profiles, defaultRegion } = loadAwsProfiles();
-
-    const profileInfos: ProfileInfo[] = profiles.map(profile => {
-      const validation = validateProfile(profile);
-      return {
-        name: profile.name,
-        region: profile.region,
-        hasCredentials: profile.hasCredentials,
-        isValid: validation.valid,
-        validationMessage: validation.reason,
-        profileType: profile.profileType,
-        profileTypeDescription: getProfileTypeDescription(profile.profileType),
-      };
-    });
+    const { profiles, defaultRegion } = listProfiles();
+
+    const profileInfos: ProfileInfo[] = profiles.map(toProfileInfo);
 
     return {
       profiles: profileInfos,
@@ -94,6 +166,7 @@ export function registerCredentialsIpc(): void {
       output: profile.output,
       sourceProfile: profile.sourceProfile,
       roleArn: profile.roleArn,
+      mfaSerial: profile.mfaSerial,
       credentialSource: profile.credentialSource,
       ssoStartUrl: profile.ssoStartUrl,
       ssoRegion: profile.ssoRegion,
@@ -107,23 +180,115 @@ export function registerCredentialsIpc(): void {
     };
   });
 
+  // Resolve a profile's credentials as `export AWS_...=...` lines for a terminal
+  ipcMain.handle(
+    'aws:export-env',
+    async (_event, profileName: string): Promise<{ success: boolean; text?: string; error?: string }> => {
+      const result = await exportCredentialsAsEnv(profileName);
+      if (!result.success || !result.variables) {
+        return { success: false, error: result.error };
+      }
+      return { success: true, text: formatEnvExports(result.variables, result.expiration) };
+    }
+  );
+
+  // Get a redacted report of all profiles for troubleshooting
+  ipcMain.handle('aws:get-diagnostic-report', async (): Promise<string> => {
+    return buildProfileDiagnosticReport();
+  });
+
+  // Sign in to SSO with the device authorization flow. The user code is sent to the
+  // renderer and the verification page opened in the browser; resolves once approved
+  ipcMain.handle('aws:sso-login', async (event, profileName: string): Promise<SsoLoginResult> => {
+    const profile = getProfile(profileName);
+    if (!profile) {
+      return { success: false, error: `Profile '${profileName}' not found` };
+    }
+
+    ssoLoginController?.abort();
+    const controller = new AbortController();
+    ssoLoginController = controller;
+
+    try {
+      return await ssoLogin(profile, {
+        abortSignal: controller.signal,
+        onAuthorization: (authorization) => {
+          if (!event.sender.isDestroyed()) {
+            event.sender.send('aws:sso-authorization', { profileName, ...authorization });
+          }
+          shell.openExternal(authorization.verificationUriComplete ?? authorization.verificationUri);
+        },
+      });
+    } finally {
+      if (ssoLoginController === controller) {
+        ssoLoginController = null;
+      }
+    }
+  });
+
+  // List the accounts and roles an SSO profile's sign-in gives access to
+  ipcMain.handle('aws:list-sso-roles', async (_event, profileName: string): Promise<SsoRoleListResult> => {
+    const profile = getProfile(profileName);
+    if (!profile) {
+      return { success: false, error: `Profile '${profileName}' not found` };
+    }
+    return listSsoRoles(profile);
+  });
+
+  // Answer an MFA code request; a null code cancels it
+  ipcMain.handle('aws:submit-mfa-code', async (_event, requestId: number, code: string | null): Promise<boolean> => {
+    return mfaCodeRequests.submit(requestId, code);
+  });
+
+  // Add a profile to ~/.aws/config; an existing one is only replaced with overwrite
+  ipcMain.handle(
+    'aws:add-profile',
+    async (_event, config: ProfileConfig, overwrite = false): Promise<ProfileWriteResult> => {
+      return addProfile({ ...config, profileType: 'config-only', hasCredentials: false }, { overwrite });
+    }
+  );
+
+  // Get the settings of a profile's section of ~/.aws/config, for editing
+  ipcMain.handle('aws:get-profile-config', async (_event, name: string): Promise<ProfileConfig | null> => {
+    return getProfileConfig(name);
+  });
+
+  // Rewrite a profile's section of ~/.aws/config (renaming it when the name changes)
+  ipcMain.handle(
+    'aws:update-profile',
+    async (_event, name: string, config: ProfileConfig): Promise<ProfileWriteResult> => {
+      const result = updateProfile(name, { ...config, profileType: 'config-only', hasCredentials: false });
+      if (result.success && currentProfile === name) {
+        currentProfile = config.name;
+      }
+      return result;
+    }
+  );
+
+  // Remove a profile's sections from ~/.aws/config and ~/.aws/credentials
+  ipcMain.handle('aws:remove-profile', async (_event, name: string): Promise<ProfileWriteResult> => {
+    const result = removeProfile(name);
+    if (result.success && currentProfile === name) {
+      currentProfile = null;
+    }
+    return result;
+  });
+
+  // Cancel the SSO sign-in in progress
+  ipcMain.handle('aws:cancel-sso-login', async (): Promise<boolean> => {
+    if (!ssoLoginController) {
+      return false;
+    }
+    ssoLoginController.abort();
+    return true;
+  });
+
   // Refresh profiles (re-read from disk)
   ipcMain.handle('aws:refresh-profiles', async (): Promise<CredentialsState> => {
     // Force re-read from disk by calling loadAwsProfiles again
-    const { profiles, defaultRegion } = loadAwsProfiles();
-
-    const profileInfos: ProfileInfo[] = profiles.map(profile => {
-      const validation = validateProfile(profile);
-      return {
-        name: profile.name,
-        region: profile.region,
-        hasCredentials: profile.hasCredentials,
-        isValid: validation.valid,
-        validationMessage: validation.reason,
-        profileType: profile.profileType,
-        profileTypeDescription: getProfileTypeDescription(profile.profileType),
-      };
-    });
+    const { profiles, defaultRegion } = listProfiles();
+
+    const profileInfos: ProfileInfo[] = profiles.map(toProfileInfo);
 
     // Check if current profile still exists
     if (currentProfile && !profiles.find(p => p.name === currentProfile)) {
@@ -138,6 +303,21 @@ export function registerCredentialsIpc(): void {
   });
 }
 
+/**
+ * Tells the renderer to reload profiles whenever the AWS config files (or the
+ * SSO token cache) change on disk
+ * @returns Stops watching
+ */
+export function enableProfileAutoReload(): () => void {
+  return watchAwsConfigFiles(() => {
+    for (const window of BrowserWindow.getAllWindows()) {
+      if (!window.webContents.isDestroyed()) {
+        window.webContents.send('aws:profiles-changed');
+      }
+    }
+  });
+}
+
 /**
  * Masks a credential string, showing only first 4 and last 4 characters
  */
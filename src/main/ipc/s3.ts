@@ -1,25 +1,54 @@
-import { ipcMain, app, dialog, shell } from 'electron';
+import { ipcMain, app, dialog, shell, type WebContents } from 'electron';
 import * as path from 'path';
+import * as fs from 'fs';
 import {
   listBuckets,
+  createBucket,
+  getBucketPublicStatus,
+  getBucketRegion,
   listObjects,
   listAllObjects,
+  collapseSingleChildChain,
   parseS3Url,
+  parseS3UrlList,
+  rememberBucketRegion,
+  getCommonPrefix,
   getParentPrefix,
   getKeyName,
   clearS3Client,
   downloadFile,
+  downloadFiles,
+  DEFAULT_DOWNLOAD_CONCURRENCY,
   uploadFile,
   uploadContent,
   downloadContent,
   downloadBinaryContent,
+  downloadBinaryContentVerified,
+  downloadBinaryContentIfModified,
+  downloadPartialContent,
   deleteFile,
   deleteFiles,
   deletePrefix,
   renameFile,
+  movePrefix,
   copyFile,
+  transferObject,
   getFileSize,
+  headObject,
+  presignGetUrl,
+  presignPutUrl,
+  restoreObject,
+  describeS3Error,
+  isAbortedError,
+  checkBucketAccess,
+  describeBucketStatus,
+  isRegionMismatchError,
   getObjectMetadata,
+  getObjectTags,
+  selectObjectContent,
+  listMultipartUploads,
+  abortMultipartUpload,
+  putObjectTags,
   createEmptyFile,
   createFolder,
   type S3Bucket,
@@ -27,32 +56,116 @@ import {
   type ListObjectsResult,
   type ListObjectsOptions,
   type FileOperationResult,
+  type UploadOptions,
+  type FileDownload,
+  type CreateBucketResult,
+  type TransferResult,
   type DeleteFilesResult,
   type DeletePrefixResult,
+  type MovePrefixResult,
   type ObjectMetadata,
+  type ObjectHead,
+  type RestoreTier,
+  type SelectFormat,
+  type SelectResult,
+  type MultipartUpload,
+  type PublicAccessSignals,
+  type PublicStatus,
 } from '../services/s3Service';
 import { getCurrentProfileCredentials } from './credentials';
-import { isGzipFile, decompressGzip, compressGzip } from '../services/gzipUtils';
+import { ANONYMOUS_PROFILE_NAME } from '../services/awsCredentials';
+import { isProfileReadOnly, loadAppState } from '../services/appState';
+import {
+  resolveConflict,
+  buildExternalOpenPath,
+  getExecutableExtension,
+  getRevealAction,
+} from '../services/localFiles';
+import {
+  compressContent,
+  decompressContent,
+  getCompressionFromKey,
+  type CompressionCodec,
+} from '../services/gzipUtils';
 
 // Abort controllers for cancellable operations
 const abortControllers = new Map<string, AbortController>();
 
+export interface DownloadFilesEntry extends FileOperationResult {
+  key: string;
+  localPath?: string;
+  skipped?: boolean;
+}
+
+/**
+ * Describes a failed listing, checking the bucket itself first so a missing,
+ * refused or misplaced bucket is named as such rather than as a generic error.
+ * A bucket in another region is only blamed when S3 refused the listing for it;
+ * region redirects are followed, so other failures there have other causes.
+ */
+async function explainListingError(bucket: string, error: unknown): Promise<string> {
+  const message = describeS3Error(error);
+  if (isAbortedError(error)) {
+    return message;
+  }
+  try {
+    const check = await checkBucketAccess(getCurrentProfile(), bucket);
+    if (!check.status || (check.status.access === 'wrong-region' && !isRegionMismatchError(error))) {
+      return message;
+    }
+    return describeBucketStatus(bucket, check.status) ?? message;
+  } catch {
+    return message;
+  }
+}
+
+/**
+ * Fills in upload options the user has configured in the settings
+ */
+function withUploadDefaults(options: UploadOptions = {}): UploadOptions {
+  const storageClass = options.storageClass ?? loadAppState().uploadStorageClass ?? undefined;
+  return { ...options, storageClass };
+}
+
 export interface S3ListBucketsResult {
   success: boolean;
   buckets?: S3Bucket[];
   error?: string;
 }
 
+export interface BucketPublicStatusResult {
+  success: boolean;
+  status?: PublicStatus;
+  signals?: PublicAccessSignals;
+  error?: string;
+}
+
 export interface S3ListObjectsResult {
   success: boolean;
   result?: ListObjectsResult;
   error?: string;
 }
 
+export interface S3CollapsePrefixResult {
+  success: boolean;
+  prefix?: string;
+  error?: string;
+}
+
 export interface S3ParseUrlResult {
   success: boolean;
   bucket?: string;
   key?: string;
+  region?: string;
+  versionId?: string;
+  error?: string;
+}
+
+export interface S3ParseUrlListResult {
+  success: boolean;
+  urls?: Array<{ bucket: string; key: string }>;
+  // Shared bucket and folder prefix, absent when URLs span multiple buckets
+  commonPrefix?: { bucket: string; prefix: string };
   error?: string;
 }
 
@@ -67,6 +180,57 @@ function getCurrentProfile(): string {
   return profile.name;
 }
 
+export const READ_ONLY_ERROR = 'Read-only mode is enabled';
+
+export const ANONYMOUS_WRITE_ERROR =
+  'Anonymous access can only read public buckets. Select a profile with credentials to make changes.';
+
+/**
+ * Throws if read-only mode is enabled for the profile, or it is the anonymous profile
+ */
+function assertWritable(profileName: string): void {
+  if (profileName === ANONYMOUS_PROFILE_NAME) {
+    throw new Error(ANONYMOUS_WRITE_ERROR);
+  }
+  if (isProfileReadOnly(profileName)) {
+    throw new Error(READ_ONLY_ERROR);
+  }
+}
+
+/**
+ * Gets the current profile name for a mutating operation.
+ * Throws if no profile is selected or the profile is in read-only mode.
+ */
+function getWritableProfile(): string {
+  const profileName = getCurrentProfile();
+  assertWritable(profileName);
+  return profileName;
+}
+
+// Minimum interval between progress events sent to the renderer
+const PROGRESS_EVENT_INTERVAL_MS = 200;
+
+/**
+ * Returns a progress callback that forwards throttled progress events for an
+ * operation to the renderer. The final update is always sent.
+ */
+function createProgressReporter(
+  sender: WebContents,
+  operationId: string
+): (progress: { loaded: number; total: number }) => void {
+  let lastSent = 0;
+  return ({ loaded, total }) => {
+    const now = Date.now();
+    if (now - lastSent < PROGRESS_EVENT_INTERVAL_MS && loaded < total) {
+      return;
+    }
+    lastSent = now;
+    if (!sender.isDestroyed()) {
+      sender.send('s3:transfer-progress', { operationId, loaded, total });
+    }
+  };
+}
+
 /**
  * Register IPC handlers for S3 operations
  */
@@ -78,19 +242,33 @@ export function registerS3Ipc(): void {
       const buckets = await listBuckets(profileName);
       return { success: true, buckets };
     } catch (error) {
-      const message = error instanceof Error ? error.message : 'Unknown error occurred';
+      const message = describeS3Error(error);
       return { success: false, error: message };
     }
   });
 
-  // List objects in a bucket with pagination support
+  // Create a bucket in the chosen region
   ipcMain.handle(
-    's3:list-objects',
-    async (_event, options: ListObjectsOptions): Promise<S3ListObjectsResult> => {
+    's3:create-bucket',
+    async (_event, bucket: string, region?: string): Promise<CreateBucketResult> => {
+      try {
+        const profileName = getWritableProfile();
+        return await createBucket(profileName, bucket, region);
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
+  // Check whether a bucket is publicly accessible (read-only)
+  ipcMain.handle(
+    's3:get-bucket-public-status',
+    async (_event, bucket: string): Promise<BucketPublicStatusResult> => {
       try {
         const profileName = getCurrentProfile();
-        const result = await listObjects(profileName, options);
-        return { success: true, result };
+        const { status, ...signals } = await getBucketPublicStatus(profileName, bucket);
+        return { success: true, status, signals };
       } catch (error) {
         const message = error instanceof Error ? error.message : 'Unknown error occurred';
         return { success: false, error: message };
@@ -98,6 +276,46 @@ export function registerS3Ipc(): void {
     }
   );
 
+  // Find the region a bucket lives in
+  ipcMain.handle(
+    's3:get-bucket-region',
+    async (_event, bucket: string): Promise<{ success: boolean; region?: string; error?: string }> => {
+      try {
+        const profileName = getCurrentProfile();
+        return await getBucketRegion(profileName, bucket);
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
+  // List objects in a bucket with pagination support - cancellable when given an operation id
+  ipcMain.handle(
+    's3:list-objects',
+    async (_event, options: ListObjectsOptions, operationId?: string): Promise<S3ListObjectsResult> => {
+      try {
+        const profileName = getCurrentProfile();
+        if (!operationId) {
+          const result = await listObjects(profileName, options);
+          return { success: true, result };
+        }
+
+        const abortController = new AbortController();
+        abortControllers.set(operationId, abortController);
+
+        try {
+          const result = await listObjects(profileName, options, abortController.signal);
+          return { success: true, result };
+        } finally {
+          abortControllers.delete(operationId);
+        }
+      } catch (error) {
+        return { success: false, error: await explainListingError(options.bucket, error) };
+      }
+    }
+  );
+
   // List all objects (with pagination handled internally) - supports cancellation
   ipcMain.handle(
     's3:list-all-objects',
@@ -136,6 +354,35 @@ export function registerS3Ipc(): void {
     }
   );
 
+  // Follow single-subfolder chains from a prefix - supports cancellation
+  ipcMain.handle(
+    's3:collapse-prefix-chain',
+    async (_event, bucket: string, prefix: string, operationId: string): Promise<S3CollapsePrefixResult> => {
+      try {
+        const profileName = getCurrentProfile();
+
+        const abortController = new AbortController();
+        abortControllers.set(operationId, abortController);
+
+        try {
+          const deepest = await collapseSingleChildChain(
+            profileName,
+            bucket,
+            prefix,
+            undefined,
+            abortController.signal
+          );
+          return { success: true, prefix: deepest };
+        } finally {
+          abortControllers.delete(operationId);
+        }
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
   // Cancel a running operation
   ipcMain.handle('s3:cancel-operation', async (_event, operationId: string): Promise<boolean> => {
     const controller = abortControllers.get(operationId);
@@ -154,10 +401,35 @@ export function registerS3Ipc(): void {
       if (!parsed) {
         return { success: false, error: 'Invalid S3 URL format' };
       }
+      // A regional URL says where the bucket is, so the first request can go there
+      const profile = getCurrentProfileCredentials();
+      if (parsed.region && profile) {
+        rememberBucketRegion(profile.name, parsed.bucket, parsed.region);
+      }
       return {
         success: true,
         bucket: parsed.bucket,
         key: parsed.key,
+        region: parsed.region,
+        versionId: parsed.versionId,
+      };
+    } catch (error) {
+      const message = error instanceof Error ? error.message : 'Unknown error occurred';
+      return { success: false, error: message };
+    }
+  });
+
+  // Parse a multi-line list of S3 URLs
+  ipcMain.handle('s3:parse-url-list', async (_event, text: string): Promise<S3ParseUrlListResult> => {
+    try {
+      const urls = parseS3UrlList(text);
+      if (urls.length === 0) {
+        return { success: false, error: 'No valid S3 URLs found' };
+      }
+      return {
+        success: true,
+        urls,
+        commonPrefix: getCommonPrefix(urls) ?? undefined,
       };
     } catch (error) {
       const message = error instanceof Error ? error.message : 'Unknown error occurred';
@@ -184,26 +456,22 @@ export function registerS3Ipc(): void {
   ipcMain.handle(
     's3:download-file',
     async (
-      _event,
+      event,
       bucket: string,
       key: string,
       operationId: string
-    ): Promise<FileOperationResult & { localPath?: string }> => {
+    ): Promise<FileOperationResult & { localPath?: string; skipped?: boolean }> => {
       try {
         const profileName = getCurrentProfile();
 
-        // Get the downloads folder path
+        // Get the downloads folder path and apply the conflict policy for existing files
         const downloadsPath = app.getPath('downloads');
-        const fileName = getKeyName(key);
-        let destinationPath = path.join(downloadsPath, fileName);
-
-        // If file exists, add a number suffix
-        let counter = 1;
-        const ext = path.extname(fileName);
-        const baseName = path.basename(fileName, ext);
-        while (await fileExists(destinationPath)) {
-          destinationPath = path.join(downloadsPath, `${baseName} (${counter})${ext}`);
-          counter++;
+        const destinationPath = await resolveConflict(
+          path.join(downloadsPath, getKeyName(key)),
+          loadAppState().downloadConflictPolicy
+        );
+        if (!destinationPath) {
+          return { success: true, skipped: true };
         }
 
         // Create abort controller for this operation
@@ -216,7 +484,7 @@ export function registerS3Ipc(): void {
             bucket,
             key,
             destinationPath,
-            undefined, // Progress callback could be added with IPC events
+            createProgressReporter(event.sender, operationId),
             abortController.signal
           );
 
@@ -234,18 +502,137 @@ export function registerS3Ipc(): void {
     }
   );
 
+  // Download several files to the downloads folder, a few at a time
+  ipcMain.handle(
+    's3:download-files',
+    async (
+      _event,
+      bucket: string,
+      keys: string[],
+      operationId: string
+    ): Promise<{ success: boolean; results: DownloadFilesEntry[] }> => {
+      try {
+        const profileName = getCurrentProfile();
+        const downloadsPath = app.getPath('downloads');
+        const policy = loadAppState().downloadConflictPolicy;
+
+        // Destinations are resolved up front so files with the same name don't share one
+        const reserved = new Set<string>();
+        const downloads: FileDownload[] = [];
+        const resolved = new Map<string, string | null>();
+        for (const key of keys) {
+          const destinationPath = await resolveConflict(path.join(downloadsPath, getKeyName(key)), policy, reserved);
+          resolved.set(key, destinationPath);
+          if (destinationPath) {
+            reserved.add(destinationPath);
+            downloads.push({ key, destinationPath });
+          }
+        }
+
+        const abortController = new AbortController();
+        abortControllers.set(operationId, abortController);
+
+        try {
+          const downloaded = await downloadFiles(
+            profileName,
+            bucket,
+            downloads,
+            DEFAULT_DOWNLOAD_CONCURRENCY,
+            abortController.signal
+          );
+          const byKey = new Map(downloaded.map(result => [result.key, result]));
+          const results = keys.map((key): DownloadFilesEntry => {
+            const destinationPath = resolved.get(key);
+            if (!destinationPath) {
+              return { key, success: true, skipped: true };
+            }
+            const result = byKey.get(key)!;
+            return result.success ? { ...result, localPath: destinationPath } : result;
+          });
+          return { success: results.every(r => r.success), results };
+        } finally {
+          abortControllers.delete(operationId);
+        }
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, results: keys.map(key => ({ key, success: false, error: message })) };
+      }
+    }
+  );
+
+  // Download a file to a temp folder and open it with the OS default app
+  ipcMain.handle(
+    's3:open-externally',
+    async (
+      event,
+      bucket: string,
+      key: string,
+      operationId: string
+    ): Promise<FileOperationResult & { localPath?: string }> => {
+      try {
+        const profileName = getCurrentProfile();
+
+        const fileName = getKeyName(key);
+        // Opening a program or script would run it, with content anyone who can write to the bucket chose
+        const executableExt = getExecutableExtension(fileName);
+        if (executableExt) {
+          return {
+            success: false,
+            error: `${fileName} is a .${executableExt} file, which would run as a program. Download it instead if you trust it.`,
+          };
+        }
+        const tempPath = buildExternalOpenPath(fileName);
+        await fs.promises.mkdir(path.dirname(tempPath), { recursive: true });
+
+        const abortController = new AbortController();
+        abortControllers.set(operationId, abortController);
+
+        try {
+          const result = await downloadFile(
+            profileName,
+            bucket,
+            key,
+            tempPath,
+            createProgressReporter(event.sender, operationId),
+            abortController.signal
+          );
+          if (!result.success) {
+            return result;
+          }
+        } finally {
+          abortControllers.delete(operationId);
+        }
+
+        // openPath resolves to an error message (empty on success), e.g. when no app handles the type
+        const openError = await shell.openPath(tempPath);
+        if (openError) {
+          return {
+            success: false,
+            error: `No application is available to open ${fileName}: ${openError}`,
+            localPath: tempPath,
+          };
+        }
+        return { success: true, localPath: tempPath };
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
   // Upload a file to S3
   ipcMain.handle(
     's3:upload-file',
     async (
-      _event,
+      event,
       bucket: string,
       prefix: string,
       filePath: string,
-      operationId: string
+      operationId: string,
+      options?: UploadOptions
     ): Promise<FileOperationResult> => {
       try {
-        const profileName = getCurrentProfile();
+        const profileName = getWritableProfile();
 
         // Build the S3 key from prefix + filename
         const fileName = path.basename(filePath);
@@ -256,7 +643,15 @@ export function registerS3Ipc(): void {
         abortControllers.set(operationId, abortController);
 
         try {
-          return await uploadFile(profileName, bucket, key, filePath, undefined, abortController.signal);
+          return await uploadFile(
+            profileName,
+            bucket,
+            key,
+            filePath,
+            createProgressReporter(event.sender, operationId),
+            abortController.signal,
+            withUploadDefaults(options)
+          );
         } finally {
           abortControllers.delete(operationId);
         }
@@ -275,10 +670,11 @@ export function registerS3Ipc(): void {
       bucket: string,
       prefix: string,
       filePaths: string[],
-      operationId: string
+      operationId: string,
+      options?: UploadOptions
     ): Promise<{ success: boolean; results: Array<{ path: string; success: boolean; error?: string }> }> => {
       try {
-        const profileName = getCurrentProfile();
+        const profileName = getWritableProfile();
         const results: Array<{ path: string; success: boolean; error?: string }> = [];
 
         // Create abort controller for this operation
@@ -294,7 +690,15 @@ export function registerS3Ipc(): void {
 
             const fileName = path.basename(filePath);
             const key = prefix ? `${prefix}${fileName}` : fileName;
-            const result = await uploadFile(profileName, bucket, key, filePath, undefined, abortController.signal);
+            const result = await uploadFile(
+              profileName,
+              bucket,
+              key,
+              filePath,
+              undefined,
+              abortController.signal,
+              withUploadDefaults(options)
+            );
             results.push({ path: filePath, ...result });
           }
 
@@ -304,7 +708,7 @@ export function registerS3Ipc(): void {
         }
       } catch (error) {
         const message = error instanceof Error ? error.message : 'Unknown error occurred';
-        return { success: false, results: [] };
+        return { success: false, results: filePaths.map(filePath => ({ path: filePath, success: false, error: message })) };
       }
     }
   );
@@ -314,7 +718,7 @@ export function registerS3Ipc(): void {
     's3:delete-file',
     async (_event, bucket: string, key: string): Promise<FileOperationResult> => {
       try {
-        const profileName = getCurrentProfile();
+        const profileName = getWritableProfile();
         return await deleteFile(profileName, bucket, key);
       } catch (error) {
         const message = error instanceof Error ? error.message : 'Unknown error occurred';
@@ -328,11 +732,16 @@ export function registerS3Ipc(): void {
     's3:delete-files',
     async (_event, bucket: string, keys: string[]): Promise<DeleteFilesResult> => {
       try {
-        const profileName = getCurrentProfile();
+        const profileName = getWritableProfile();
         return await deleteFiles(profileName, bucket, keys);
       } catch (error) {
         const message = error instanceof Error ? error.message : 'Unknown error occurred';
-        return { success: false, results: [], deletedCount: 0, failedCount: keys.length };
+        return {
+          success: false,
+          results: keys.map(key => ({ key, success: false, error: message })),
+          deletedCount: 0,
+          failedCount: keys.length,
+        };
       }
     }
   );
@@ -342,7 +751,7 @@ export function registerS3Ipc(): void {
     's3:delete-prefix',
     async (_event, bucket: string, prefix: string): Promise<DeletePrefixResult> => {
       try {
-        const profileName = getCurrentProfile();
+        const profileName = getWritableProfile();
         return await deletePrefix(profileName, bucket, prefix);
       } catch (error) {
         const message = error instanceof Error ? error.message : 'Unknown error occurred';
@@ -356,7 +765,7 @@ export function registerS3Ipc(): void {
     's3:rename-file',
     async (_event, bucket: string, sourceKey: string, newName: string): Promise<FileOperationResult> => {
       try {
-        const profileName = getCurrentProfile();
+        const profileName = getWritableProfile();
 
         // Build the new key with the same prefix but different name
         const parentPrefix = getParentPrefix(sourceKey);
@@ -370,6 +779,43 @@ export function registerS3Ipc(): void {
     }
   );
 
+  // Rename a prefix (folder) by moving everything under it - reports progress and supports cancellation
+  ipcMain.handle(
+    's3:rename-prefix',
+    async (
+      event,
+      bucket: string,
+      prefix: string,
+      newName: string,
+      operationId: string
+    ): Promise<MovePrefixResult> => {
+      try {
+        const profileName = getWritableProfile();
+        const destinationPrefix = `${getParentPrefix(prefix)}${newName.replace(/\/+$/, '')}/`;
+
+        const abortController = new AbortController();
+        abortControllers.set(operationId, abortController);
+        const reportProgress = createProgressReporter(event.sender, operationId);
+
+        try {
+          return await movePrefix(
+            profileName,
+            bucket,
+            prefix,
+            destinationPrefix,
+            (copied, total) => reportProgress({ loaded: copied, total }),
+            abortController.signal
+          );
+        } finally {
+          abortControllers.delete(operationId);
+        }
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, movedCount: 0, failures: [], error: message };
+      }
+    }
+  );
+
   // Copy a file in S3
   ipcMain.handle(
     's3:copy-file',
@@ -381,7 +827,7 @@ export function registerS3Ipc(): void {
       destinationKey: string
     ): Promise<FileOperationResult> => {
       try {
-        const profileName = getCurrentProfile();
+        const profileName = getWritableProfile();
         return await copyFile(profileName, sourceBucket, sourceKey, destinationBucket, destinationKey);
       } catch (error) {
         const message = error instanceof Error ? error.message : 'Unknown error occurred';
@@ -390,29 +836,44 @@ export function registerS3Ipc(): void {
     }
   );
 
-  // Upload content directly (for editor save)
-  // Automatically compresses content for .gz files
+  // Copy a file from the current profile to another profile (possibly another account)
   ipcMain.handle(
-    's3:upload-content',
-    async (_event, bucket: string, key: string, content: string): Promise<FileOperationResult> => {
+    's3:transfer-object',
+    async (
+      _event,
+      sourceBucket: string,
+      sourceKey: string,
+      destinationProfile: string,
+      destinationBucket: string,
+      destinationKey: string
+    ): Promise<TransferResult> => {
       try {
         const profileName = getCurrentProfile();
+        assertWritable(destinationProfile);
+        return await transferObject(
+          profileName,
+          sourceBucket,
+          sourceKey,
+          destinationProfile,
+          destinationBucket,
+          destinationKey
+        );
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
 
-        // For .gz files, compress content before upload
-        if (isGzipFile(key)) {
-          try {
-            const compressedBuffer = await compressGzip(content);
-            return await uploadContent(profileName, bucket, key, compressedBuffer);
-          } catch (compressError) {
-            const message = compressError instanceof Error
-              ? compressError.message
-              : 'Failed to compress file';
-            return { success: false, error: `Gzip compression failed: ${message}` };
-          }
-        }
-
-        // For non-gz files, use regular upload
-        return await uploadContent(profileName, bucket, key, content);
+  // Upload content directly (for editor save), in the storage class chosen in the settings
+  // Automatically compresses content for .gz, .zst and .snappy files
+  ipcMain.handle(
+    's3:upload-content',
+    async (_event, bucket: string, key: string, content: string): Promise<FileOperationResult> => {
+      try {
+        const profileName = getWritableProfile();
+        const body = await compressContent(key, content);
+        return await uploadContent(profileName, bucket, key, body, withUploadDefaults());
       } catch (error) {
         const message = error instanceof Error ? error.message : 'Unknown error occurred';
         return { success: false, error: message };
@@ -421,33 +882,31 @@ export function registerS3Ipc(): void {
   );
 
   // Download content directly (for editor load)
-  // Automatically decompresses .gz files
+  // Automatically decompresses .gz, .zst and .snappy files
   ipcMain.handle(
     's3:download-content',
-    async (_event, bucket: string, key: string): Promise<{ success: boolean; content?: string; error?: string }> => {
+    async (
+      _event,
+      bucket: string,
+      key: string,
+      versionId?: string
+    ): Promise<{ success: boolean; content?: string; compression?: CompressionCodec; error?: string }> => {
       try {
         const profileName = getCurrentProfile();
 
-        // For .gz files, download as binary and decompress
-        if (isGzipFile(key)) {
-          const result = await downloadBinaryContent(profileName, bucket, key);
+        // For compressed files, download as binary and decompress
+        if (getCompressionFromKey(key)) {
+          const result = await downloadBinaryContent(profileName, bucket, key, versionId);
           if (!result.success || !result.data) {
             return { success: false, error: result.error || 'Failed to download file' };
           }
 
-          try {
-            const content = await decompressGzip(result.data);
-            return { success: true, content };
-          } catch (decompressError) {
-            const message = decompressError instanceof Error
-              ? decompressError.message
-              : 'Failed to decompress file';
-            return { success: false, error: `Gzip decompression failed: ${message}` };
-          }
+          const { content, compression } = await decompressContent(key, result.data);
+          return { success: true, content, compression };
         }
 
-        // For non-gz files, use the regular download
-        return await downloadContent(profileName, bucket, key);
+        // For uncompressed files, use the regular download
+        return await downloadContent(profileName, bucket, key, versionId);
       } catch (error) {
         const message = error instanceof Error ? error.message : 'Unknown error occurred';
         return { success: false, error: message };
@@ -458,10 +917,123 @@ export function registerS3Ipc(): void {
   // Get file size
   ipcMain.handle(
     's3:get-file-size',
-    async (_event, bucket: string, key: string): Promise<{ success: boolean; size?: number; error?: string }> => {
+    async (
+      _event,
+      bucket: string,
+      key: string,
+      versionId?: string
+    ): Promise<{ success: boolean; size?: number; notFound?: boolean; error?: string }> => {
+      try {
+        const profileName = getCurrentProfile();
+        return await getFileSize(profileName, bucket, key, versionId);
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
+  // Head object (size, type, ETag, modification time and storage class)
+  ipcMain.handle(
+    's3:head-object',
+    async (
+      _event,
+      bucket: string,
+      key: string,
+      versionId?: string
+    ): Promise<{ success: boolean; head?: ObjectHead; notFound?: boolean; error?: string }> => {
+      try {
+        const profileName = getCurrentProfile();
+        return await headObject(profileName, bucket, key, versionId);
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
+  // Create a time-limited shareable link
+  ipcMain.handle(
+    's3:presign-get-url',
+    async (
+      _event,
+      bucket: string,
+      key: string,
+      expiresInSeconds: number
+    ): Promise<{ success: boolean; url?: string; error?: string }> => {
+      try {
+        const profileName = getCurrentProfile();
+        return await presignGetUrl(profileName, bucket, key, expiresInSeconds);
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
+  // Create a time-limited upload link
+  ipcMain.handle(
+    's3:presign-put-url',
+    async (
+      _event,
+      bucket: string,
+      key: string,
+      expiresInSeconds: number,
+      contentType?: string
+    ): Promise<{ success: boolean; url?: string; error?: string }> => {
+      try {
+        const profileName = getWritableProfile();
+        return await presignPutUrl(profileName, bucket, key, expiresInSeconds, contentType);
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
+  // Restore an archived (Glacier / Deep Archive) object for a number of days
+  ipcMain.handle(
+    's3:restore-object',
+    async (
+      _event,
+      bucket: string,
+      key: string,
+      days: number,
+      tier: RestoreTier
+    ): Promise<FileOperationResult> => {
+      try {
+        const profileName = getWritableProfile();
+        return await restoreObject(profileName, bucket, key, days, tier);
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
+  // Download an object and compare it with the SHA-256 checksum S3 stored for it, or its MD5 ETag
+  ipcMain.handle(
+    's3:verify-checksum',
+    async (
+      _event,
+      bucket: string,
+      key: string
+    ): Promise<{
+      success: boolean;
+      verified?: boolean;
+      verifiedAgainst?: 'SHA-256' | 'ETag';
+      checksumMismatch?: boolean;
+      error?: string;
+    }> => {
       try {
         const profileName = getCurrentProfile();
-        return await getFileSize(profileName, bucket, key);
+        // Only the outcome goes back; the content isn't needed
+        const { success, verified, verifiedAgainst, checksumMismatch, error } = await downloadBinaryContentVerified(
+          profileName,
+          bucket,
+          key
+        );
+        return { success, verified, verifiedAgainst, checksumMismatch, error };
       } catch (error) {
         const message = error instanceof Error ? error.message : 'Unknown error occurred';
         return { success: false, error: message };
@@ -472,12 +1044,80 @@ export function registerS3Ipc(): void {
   // Download binary content (for parquet files)
   ipcMain.handle(
     's3:download-binary-content',
-    async (_event, bucket: string, key: string): Promise<{ success: boolean; data?: Uint8Array; error?: string }> => {
+    async (
+      _event,
+      bucket: string,
+      key: string,
+      versionId?: string
+    ): Promise<{ success: boolean; data?: Uint8Array; etag?: string; lastModified?: Date; error?: string }> => {
       try {
         const profileName = getCurrentProfile();
-        const result = await downloadBinaryContent(profileName, bucket, key);
+        const result = await downloadBinaryContent(profileName, bucket, key, versionId);
         if (result.success && result.data) {
           // Convert Buffer to Uint8Array for IPC transfer
+          return { success: true, data: new Uint8Array(result.data), etag: result.etag, lastModified: result.lastModified };
+        }
+        return { success: false, error: result.error };
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
+  // Download binary content unless it is unchanged since an earlier download
+  ipcMain.handle(
+    's3:download-binary-content-if-modified',
+    async (
+      _event,
+      bucket: string,
+      key: string,
+      conditions: { etag?: string; modifiedSince?: Date }
+    ): Promise<{
+      success: boolean;
+      notModified?: boolean;
+      data?: Uint8Array;
+      etag?: string;
+      lastModified?: Date;
+      error?: string;
+    }> => {
+      try {
+        const profileName = getCurrentProfile();
+        const result = await downloadBinaryContentIfModified(profileName, bucket, key, conditions);
+        if (!result.success) {
+          return { success: false, error: result.error };
+        }
+        if (result.notModified) {
+          return { success: true, notModified: true };
+        }
+        return {
+          success: true,
+          data: result.data ? new Uint8Array(result.data) : undefined,
+          etag: result.etag,
+          lastModified: result.lastModified,
+        };
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
+  // Download part of a file (for content sniffing, hex preview and Parquet footers)
+  ipcMain.handle(
+    's3:download-partial-content',
+    async (
+      _event,
+      bucket: string,
+      key: string,
+      length?: number,
+      start?: number,
+      versionId?: string
+    ): Promise<{ success: boolean; data?: Uint8Array; error?: string }> => {
+      try {
+        const profileName = getCurrentProfile();
+        const result = await downloadPartialContent(profileName, bucket, key, length, start, versionId);
+        if (result.success && result.data) {
           return { success: true, data: new Uint8Array(result.data) };
         }
         return { success: false, error: result.error };
@@ -502,21 +1142,66 @@ export function registerS3Ipc(): void {
     return result.filePaths;
   });
 
+  // Save exported text (e.g. CSV) to a local file chosen by the user
+  ipcMain.handle(
+    's3:save-local-file',
+    async (
+      _event,
+      defaultFileName: string,
+      content: string
+    ): Promise<{ success: boolean; filePath?: string; cancelled?: boolean; error?: string }> => {
+      try {
+        const result = await dialog.showSaveDialog({
+          title: 'Export',
+          defaultPath: path.join(app.getPath('downloads'), defaultFileName),
+        });
+
+        if (result.canceled || !result.filePath) {
+          return { success: false, cancelled: true };
+        }
+
+        await fs.promises.writeFile(result.filePath, content, 'utf-8');
+        return { success: true, filePath: result.filePath };
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
   // Open the downloads folder in system explorer
   ipcMain.handle('s3:open-downloads-folder', async (): Promise<void> => {
     const downloadsPath = app.getPath('downloads');
     await shell.openPath(downloadsPath);
   });
 
-  // Show file in folder
-  ipcMain.handle('s3:show-file-in-folder', async (_event, filePath: string): Promise<void> => {
-    shell.showItemInFolder(filePath);
-  });
+  // Show file in folder: selects it in Explorer/Finder (Linux file managers without
+  // selection support just open the folder), or opens the folder if the file is gone
+  ipcMain.handle(
+    's3:show-file-in-folder',
+    async (_event, filePath: string): Promise<{ success: boolean; error?: string }> => {
+      const reveal = await getRevealAction(filePath);
+      if (!reveal) {
+        return { success: false, error: 'The file and its folder no longer exist' };
+      }
+      if (reveal.action === 'select') {
+        shell.showItemInFolder(reveal.path);
+        return { success: true };
+      }
+      // openPath resolves to an error message, empty on success
+      const openError = await shell.openPath(reveal.path);
+      return openError ? { success: false, error: openError } : { success: true };
+    }
+  );
 
   // Get object metadata
   ipcMain.handle(
     's3:get-object-metadata',
-    async (_event, bucket: string, key: string): Promise<{ success: boolean; metadata?: ObjectMetadata; error?: string }> => {
+    async (
+      _event,
+      bucket: string,
+      key: string
+    ): Promise<{ success: boolean; metadata?: ObjectMetadata; notFound?: boolean; error?: string }> => {
       try {
         const profileName = getCurrentProfile();
         return await getObjectMetadata(profileName, bucket, key);
@@ -527,12 +1212,106 @@ export function registerS3Ipc(): void {
     }
   );
 
+  // Read an object's tags
+  ipcMain.handle(
+    's3:get-object-tags',
+    async (
+      _event,
+      bucket: string,
+      key: string
+    ): Promise<{ success: boolean; tags?: Record<string, string>; error?: string }> => {
+      try {
+        const profileName = getCurrentProfile();
+        return await getObjectTags(profileName, bucket, key);
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
+  // Replace an object's tags
+  ipcMain.handle(
+    's3:put-object-tags',
+    async (_event, bucket: string, key: string, tags: Record<string, string>): Promise<FileOperationResult> => {
+      try {
+        const profileName = getWritableProfile();
+        return await putObjectTags(profileName, bucket, key, tags);
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
+  // Run an S3 Select query against a CSV or JSON object - supports cancellation
+  ipcMain.handle(
+    's3:select-object-content',
+    async (
+      _event,
+      bucket: string,
+      key: string,
+      expression: string,
+      input: SelectFormat,
+      output: SelectFormat,
+      operationId: string
+    ): Promise<SelectResult> => {
+      try {
+        const profileName = getCurrentProfile();
+
+        const abortController = new AbortController();
+        abortControllers.set(operationId, abortController);
+
+        try {
+          return await selectObjectContent(profileName, bucket, key, expression, input, output, abortController.signal);
+        } finally {
+          abortControllers.delete(operationId);
+        }
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
+  // List the incomplete multipart uploads in a bucket
+  ipcMain.handle(
+    's3:list-multipart-uploads',
+    async (
+      _event,
+      bucket: string,
+      prefix?: string
+    ): Promise<{ success: boolean; uploads?: MultipartUpload[]; error?: string }> => {
+      try {
+        const profileName = getCurrentProfile();
+        return await listMultipartUploads(profileName, bucket, prefix);
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
+  // Abort an incomplete multipart upload, deleting its parts
+  ipcMain.handle(
+    's3:abort-multipart-upload',
+    async (_event, bucket: string, key: string, uploadId: string): Promise<FileOperationResult> => {
+      try {
+        const profileName = getWritableProfile();
+        return await abortMultipartUpload(profileName, bucket, key, uploadId);
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Unknown error occurred';
+        return { success: false, error: message };
+      }
+    }
+  );
+
   // Create an empty file in S3
   ipcMain.handle(
     's3:create-file',
     async (_event, bucket: string, key: string): Promise<FileOperationResult> => {
       try {
-        const profileName = getCurrentProfile();
+        const profileName = getWritableProfile();
         return await createEmptyFile(profileName, bucket, key);
       } catch (error) {
         const message = error instanceof Error ? error.message : 'Unknown error occurred';
@@ -546,7 +1325,7 @@ export function registerS3Ipc(): void {
     's3:create-folder',
     async (_event, bucket: string, prefix: string): Promise<FileOperationResult> => {
       try {
-        const profileName = getCurrentProfile();
+        const profileName = getWritableProfile();
         return await createFolder(profileName, bucket, prefix);
       } catch (error) {
         const message = error instanceof Error ? error.message : 'Unknown error occurred';
@@ -555,15 +1334,3 @@ export function registerS3Ipc(): void {
     }
   );
 }
-
-/**
- * Helper to check if a file exists
- */
-async function fileExists(filePath: string): Promise<boolean> {
-  try {
-    await require('fs').promises.access(filePath);
-    return true;
-  } catch {
-    return false;
-  }
-}
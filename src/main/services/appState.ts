@@ -1,6 +1,59 @@
 import { app } from 'electron';
 import path from 'path';
 import fs from 'fs';
+import { CONFLICT_POLICIES, type ConflictPolicy } from './localFiles';
+import { writeFileAtomic } from './atomicWrite';
+import { isUploadStorageClass, type UploadStorageClass } from './storageClasses';
+import { parseProxyUrl } from './proxy';
+
+/**
+ * A previously visited location, most recent first in the app state
+ */
+export interface RecentLocation {
+  profile: string;
+  bucket: string;
+  prefix: string;
+}
+
+/**
+ * A location saved by the user for one-click navigation
+ */
+export interface Bookmark {
+  label: string;
+  profile: string;
+  bucket: string;
+  prefix: string;
+}
+
+/**
+ * Colour theme of the app; 'system' follows the OS light/dark setting
+ */
+export type Theme = 'system' | 'light' | 'dark';
+
+export const THEMES: Theme[] = ['system', 'light', 'dark'];
+
+/**
+ * Size and position of the main window when it was last closed. x, y, width
+ * and height are the normal (not maximized) bounds.
+ */
+export interface WindowState {
+  width: number;
+  height: number;
+  x: number;
+  y: number;
+  maximized: boolean;
+}
+
+/**
+ * Per-object viewer preferences, keyed by "bucket/key" in the app state
+ */
+export interface ViewerLayout {
+  // Column names hidden in the CSV/Parquet viewers
+  hiddenColumns: string[];
+}
+
+/** Number of files whose column widths are remembered */
+export const MAX_COLUMN_WIDTH_ENTRIES = 200;
 
 /**
  * Interface for persisted app state
@@ -9,6 +62,40 @@ export interface AppState {
   lastProfile: string | null;
   lastBucket: string | null;
   lastPrefix: string;
+  // Profiles in read-only mode (mutating operations are refused)
+  readOnlyProfiles: string[];
+  // What to do when a downloaded file already exists locally
+  downloadConflictPolicy: ConflictPolicy;
+  // Skip straight through folders that contain only a single subfolder
+  collapseSingleChildFolders: boolean;
+  // Ask before deleting objects and folders
+  confirmBeforeDelete: boolean;
+  // Recently visited locations, offered by the command palette
+  recentLocations: RecentLocation[];
+  // Locations bookmarked by the user, in the order they were added
+  bookmarks: Bookmark[];
+  // Viewer layouts for individual objects
+  viewerLayouts: Record<string, ViewerLayout>;
+  // Column widths in pixels set by the user in the Parquet viewer, by column
+  // name, keyed by a file/schema signature and most recently set last
+  columnWidths: Record<string, Record<string, number>>;
+  // Log raw S3 requests and responses for debugging
+  requestLogging: boolean;
+  // Accept the request charges of Requester Pays buckets when reading them
+  requesterPays: boolean;
+  // Times a failed S3 request is retried (null for the SDK default)
+  maxRetries: number | null;
+  // Seconds to wait for a connection, and for data on a request (null for no limit)
+  connectTimeoutSeconds: number | null;
+  requestTimeoutSeconds: number | null;
+  // Storage class new uploads are written in (null for the bucket's default, STANDARD)
+  uploadStorageClass: UploadStorageClass | null;
+  // HTTP(S) proxy for S3 requests (null to follow the proxy environment variables)
+  proxyUrl: string | null;
+  // Colour theme
+  theme: Theme;
+  // Main window geometry to restore on launch (null for the default size)
+  window: WindowState | null;
   // Version for future migration support
   version: number;
 }
@@ -16,6 +103,16 @@ export interface AppState {
 const STATE_FILE_NAME = 'app-state.json';
 const CURRENT_VERSION = 1;
 
+/**
+ * Get the path an unreadable state file is moved to, so a corrupted file can
+ * still be inspected or recovered after the app falls back to defaults.
+ * Each backup is named by when it was made, so an earlier one isn't overwritten.
+ * @param date - When the file was found unreadable
+ */
+export function getCorruptStateBackupPath(date: Date = new Date()): string {
+  return `${getAppStatePath()}.corrupt-${date.toISOString().replace(/[:.]/g, '-')}`;
+}
+
 /**
  * Get the path to the app state file
  * Uses Electron's app.getPath('userData') for platform-agnostic storage
@@ -36,13 +133,122 @@ export function createDefaultState(): AppState {
     lastProfile: null,
     lastBucket: null,
     lastPrefix: '',
+    readOnlyProfiles: [],
+    downloadConflictPolicy: 'rename',
+    collapseSingleChildFolders: false,
+    confirmBeforeDelete: true,
+    recentLocations: [],
+    bookmarks: [],
+    viewerLayouts: {},
+    columnWidths: {},
+    requestLogging: false,
+    requesterPays: false,
+    maxRetries: null,
+    connectTimeoutSeconds: null,
+    requestTimeoutSeconds: null,
+    uploadStorageClass: null,
+    proxyUrl: null,
+    theme: 'system',
+    window: null,
     version: CURRENT_VERSION,
   };
 }
 
+function isRecentLocation(value: unknown): value is RecentLocation {
+  const location = value as RecentLocation;
+  return (
+    typeof location === 'object' &&
+    location !== null &&
+    typeof location.profile === 'string' &&
+    typeof location.bucket === 'string' &&
+    typeof location.prefix === 'string'
+  );
+}
+
+function isBookmark(value: unknown): value is Bookmark {
+  return isRecentLocation(value) && typeof (value as Bookmark).label === 'string';
+}
+
+function isSameLocation(a: RecentLocation, b: RecentLocation): boolean {
+  return a.profile === b.profile && a.bucket === b.bucket && a.prefix === b.prefix;
+}
+
+function sanitizeViewerLayouts(value: unknown): Record<string, ViewerLayout> {
+  if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+    return {};
+  }
+  const layouts: Record<string, ViewerLayout> = {};
+  for (const [objectId, layout] of Object.entries(value as Record<string, unknown>)) {
+    const hiddenColumns = (layout as ViewerLayout | null)?.hiddenColumns;
+    if (Array.isArray(hiddenColumns)) {
+      layouts[objectId] = {
+        hiddenColumns: hiddenColumns.filter((name): name is string => typeof name === 'string'),
+      };
+    }
+  }
+  return layouts;
+}
+
+function sanitizeWidths(value: unknown): Record<string, number> {
+  const widths: Record<string, number> = {};
+  for (const [name, width] of Object.entries(value as Record<string, unknown>)) {
+    if (typeof width === 'number' && Number.isFinite(width) && width > 0) {
+      widths[name] = width;
+    }
+  }
+  return widths;
+}
+
+function sanitizeColumnWidths(value: unknown): Record<string, Record<string, number>> {
+  if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+    return {};
+  }
+  const entries: Array<[string, Record<string, number>]> = [];
+  for (const [signature, widths] of Object.entries(value as Record<string, unknown>)) {
+    if (typeof widths === 'object' && widths !== null && !Array.isArray(widths)) {
+      const entry = sanitizeWidths(widths);
+      if (Object.keys(entry).length > 0) {
+        entries.push([signature, entry]);
+      }
+    }
+  }
+  return Object.fromEntries(entries.slice(-MAX_COLUMN_WIDTH_ENTRIES));
+}
+
+/** Most retries the settings allow, so a failing request can't keep the UI waiting for long */
+export const MAX_RETRIES_LIMIT = 10;
+
+function sanitizeMaxRetries(value: unknown): number | null {
+  return Number.isInteger(value) && (value as number) >= 0 && (value as number) <= MAX_RETRIES_LIMIT
+    ? (value as number)
+    : null;
+}
+
+/** Longest timeout the settings allow, in seconds */
+export const MAX_TIMEOUT_SECONDS = 3600;
+
+function sanitizeTimeout(value: unknown): number | null {
+  return Number.isInteger(value) && (value as number) > 0 && (value as number) <= MAX_TIMEOUT_SECONDS
+    ? (value as number)
+    : null;
+}
+
+function sanitizeWindowState(value: unknown): WindowState | null {
+  const geometry = value as WindowState | null;
+  if (typeof geometry !== 'object' || geometry === null) {
+    return null;
+  }
+  const { width, height, x, y } = geometry;
+  if (![width, height, x, y].every(Number.isInteger) || width <= 0 || height <= 0) {
+    return null;
+  }
+  return { width, height, x, y, maximized: geometry.maximized === true };
+}
+
 /**
  * Load app state from disk
- * Returns default state if file doesn't exist or is invalid
+ * Returns default state if file doesn't exist or is invalid. A file that is not
+ * valid JSON is moved aside to getCorruptStateBackupPath() first.
  */
 export function loadAppState(): AppState {
   const statePath = getAppStatePath();
@@ -53,7 +259,15 @@ export function loadAppState(): AppState {
     }
 
     const content = fs.readFileSync(statePath, 'utf-8');
-    const state = JSON.parse(content) as AppState;
+    let state: AppState;
+    try {
+      state = JSON.parse(content) as AppState;
+    } catch (error) {
+      const backupPath = getCorruptStateBackupPath();
+      console.error(`App state file is not valid JSON, moving it to ${backupPath}:`, error);
+      fs.renameSync(statePath, backupPath);
+      return createDefaultState();
+    }
 
     // Validate required fields
     if (typeof state.version !== 'number') {
@@ -72,6 +286,29 @@ export function loadAppState(): AppState {
       lastProfile: typeof state.lastProfile === 'string' ? state.lastProfile : null,
       lastBucket: typeof state.lastBucket === 'string' ? state.lastBucket : null,
       lastPrefix: typeof state.lastPrefix === 'string' ? state.lastPrefix : '',
+      readOnlyProfiles: Array.isArray(state.readOnlyProfiles)
+        ? state.readOnlyProfiles.filter((name): name is string => typeof name === 'string')
+        : [],
+      downloadConflictPolicy: CONFLICT_POLICIES.includes(state.downloadConflictPolicy)
+        ? state.downloadConflictPolicy
+        : 'rename',
+      collapseSingleChildFolders: state.collapseSingleChildFolders === true,
+      confirmBeforeDelete: state.confirmBeforeDelete !== false,
+      recentLocations: Array.isArray(state.recentLocations)
+        ? state.recentLocations.filter(isRecentLocation)
+        : [],
+      bookmarks: Array.isArray(state.bookmarks) ? state.bookmarks.filter(isBookmark) : [],
+      viewerLayouts: sanitizeViewerLayouts(state.viewerLayouts),
+      columnWidths: sanitizeColumnWidths(state.columnWidths),
+      requestLogging: state.requestLogging === true,
+      requesterPays: state.requesterPays === true,
+      maxRetries: sanitizeMaxRetries(state.maxRetries),
+      connectTimeoutSeconds: sanitizeTimeout(state.connectTimeoutSeconds),
+      requestTimeoutSeconds: sanitizeTimeout(state.requestTimeoutSeconds),
+      uploadStorageClass: isUploadStorageClass(state.uploadStorageClass) ? state.uploadStorageClass : null,
+      proxyUrl: parseProxyUrl(state.proxyUrl),
+      theme: THEMES.includes(state.theme) ? state.theme : 'system',
+      window: sanitizeWindowState(state.window),
       version: CURRENT_VERSION,
     };
   } catch (error) {
@@ -103,7 +340,7 @@ export function saveAppState(state: Partial<Omit<AppState, 'version'>>): boolean
     }
 
     // Write state file
-    fs.writeFileSync(statePath, JSON.stringify(newState, null, 2), 'utf-8');
+    writeFileAtomic(statePath, JSON.stringify(newState, null, 2));
     return true;
   } catch (error) {
     console.error('Failed to save app state:', error);
@@ -111,6 +348,208 @@ export function saveAppState(state: Partial<Omit<AppState, 'version'>>): boolean
   }
 }
 
+/**
+ * Check whether read-only mode is enabled for a profile
+ */
+export function isProfileReadOnly(profileName: string): boolean {
+  return loadAppState().readOnlyProfiles.includes(profileName);
+}
+
+/**
+ * Get the colour theme preference
+ */
+export function getTheme(): Theme {
+  return loadAppState().theme;
+}
+
+/**
+ * Save the colour theme preference
+ */
+export function setTheme(theme: Theme): boolean {
+  return saveAppState({ theme });
+}
+
+/**
+ * Get the main window geometry saved when it was last closed
+ */
+export function getWindowState(): WindowState | null {
+  return loadAppState().window;
+}
+
+/**
+ * Save the main window geometry so the next launch can restore it
+ */
+export function setWindowState(geometry: WindowState): boolean {
+  return saveAppState({ window: geometry });
+}
+
+/**
+ * Drop the least recently set entries beyond the cap. Entries are kept in the
+ * order they were set, so the oldest come first.
+ */
+export function pruneColumnWidths(
+  columnWidths: Record<string, Record<string, number>>,
+  maxEntries: number = MAX_COLUMN_WIDTH_ENTRIES
+): Record<string, Record<string, number>> {
+  const entries = Object.entries(columnWidths);
+  return Object.fromEntries(maxEntries > 0 ? entries.slice(-maxEntries) : []);
+}
+
+/**
+ * Save the column widths of a file/schema signature, replacing any saved
+ * before, and prune the oldest entries. Empty widths forget the signature.
+ */
+export function setColumnWidths(signature: string, widths: Record<string, number>): boolean {
+  const others = Object.entries(loadAppState().columnWidths).filter(([key]) => key !== signature);
+  const entry = sanitizeWidths(widths);
+  if (Object.keys(entry).length > 0) {
+    others.push([signature, entry]);
+  }
+  return saveAppState({ columnWidths: pruneColumnWidths(Object.fromEntries(others)) });
+}
+
+/**
+ * List the saved bookmarks, in the order they were added
+ */
+export function listBookmarks(): Bookmark[] {
+  return loadAppState().bookmarks;
+}
+
+/**
+ * Bookmark a location and save. Bookmarking a location again keeps its place
+ * in the list and takes the new label.
+ * @returns The bookmarks after the change, or null if the state could not be saved
+ */
+export function addBookmark(bookmark: Bookmark): Bookmark[] | null {
+  const existing = listBookmarks();
+  const entry: Bookmark = {
+    label: bookmark.label,
+    profile: bookmark.profile,
+    bucket: bookmark.bucket,
+    prefix: bookmark.prefix,
+  };
+  const bookmarks = existing.some(b => isSameLocation(b, entry))
+    ? existing.map(b => (isSameLocation(b, entry) ? entry : b))
+    : [...existing, entry];
+  return saveAppState({ bookmarks }) ? bookmarks : null;
+}
+
+/**
+ * Remove the bookmark of a location and save
+ * @returns The bookmarks after the change, or null if the state could not be saved
+ */
+export function removeBookmark(location: RecentLocation): Bookmark[] | null {
+  const bookmarks = listBookmarks().filter(b => !isSameLocation(b, location));
+  return saveAppState({ bookmarks }) ? bookmarks : null;
+}
+
+/** Number of recent locations remembered (mirrors the renderer's limit) */
+export const MAX_RECENT_LOCATIONS = 10;
+
+/** Identifies files written by exportShareable */
+export const SHAREABLE_FORMAT = 's3-browser-shared-settings';
+const SHAREABLE_VERSION = 1;
+
+/**
+ * The parts of the app state that can be shared with teammates. Machine-specific
+ * values (last location, viewer layouts, preferences) are left out, and the app
+ * state never holds credentials.
+ */
+export interface ShareableSettings {
+  recentLocations: RecentLocation[];
+  readOnlyProfiles: string[];
+  bookmarks: Bookmark[];
+}
+
+/**
+ * Serializes the shareable parts of the state as JSON
+ */
+export function exportShareable(state: AppState): string {
+  return JSON.stringify(
+    {
+      format: SHAREABLE_FORMAT,
+      version: SHAREABLE_VERSION,
+      recentLocations: state.recentLocations,
+      readOnlyProfiles: state.readOnlyProfiles,
+      bookmarks: state.bookmarks,
+    },
+    null,
+    2
+  );
+}
+
+/**
+ * Parses a file written by exportShareable
+ * @throws Error if the text is not a shared settings file
+ */
+export function parseShareable(json: string): ShareableSettings {
+  let data: Record<string, unknown>;
+  try {
+    data = JSON.parse(json);
+  } catch {
+    throw new Error('The file is not valid JSON');
+  }
+  if (typeof data !== 'object' || data === null || data.format !== SHAREABLE_FORMAT) {
+    throw new Error('The file is not an S3 Browser settings export');
+  }
+  if (typeof data.version !== 'number' || data.version > SHAREABLE_VERSION) {
+    throw new Error('The file was exported by a newer version of S3 Browser');
+  }
+  return {
+    recentLocations: Array.isArray(data.recentLocations) ? data.recentLocations.filter(isRecentLocation) : [],
+    readOnlyProfiles: Array.isArray(data.readOnlyProfiles)
+      ? data.readOnlyProfiles.filter((name): name is string => typeof name === 'string')
+      : [],
+    // Exports made before bookmarks were shared have none
+    bookmarks: Array.isArray(data.bookmarks) ? data.bookmarks.filter(isBookmark) : [],
+  };
+}
+
+/**
+ * Merges shared settings into the state. Imported locations go first, so they
+ * survive the MAX_RECENT_LOCATIONS limit, followed by the existing ones that
+ * still fit; read-only profiles and bookmarks of other locations are added
+ * after the existing ones.
+ * @returns The fields to save, and how many entries were new
+ */
+export function mergeShareable(
+  state: AppState,
+  shared: ShareableSettings
+): { update: ShareableSettings; added: number } {
+  const locationId = (location: RecentLocation) =>
+    JSON.stringify([location.profile, location.bucket, location.prefix]);
+  const knownLocations = new Set(state.recentLocations.map(locationId));
+  const seen = new Set<string>();
+  const recentLocations = [...shared.recentLocations, ...state.recentLocations]
+    .filter(location => {
+      const id = locationId(location);
+      if (seen.has(id)) return false;
+      seen.add(id);
+      return true;
+    })
+    .slice(0, MAX_RECENT_LOCATIONS);
+  const newLocations = recentLocations.filter(location => !knownLocations.has(locationId(location)));
+
+  const newReadOnly = [...new Set(shared.readOnlyProfiles)].filter(name => !state.readOnlyProfiles.includes(name));
+
+  // A location already bookmarked keeps its own label
+  const newBookmarks: Bookmark[] = [];
+  for (const bookmark of shared.bookmarks) {
+    if (![...state.bookmarks, ...newBookmarks].some(existing => isSameLocation(existing, bookmark))) {
+      newBookmarks.push(bookmark);
+    }
+  }
+
+  return {
+    update: {
+      recentLocations,
+      readOnlyProfiles: [...state.readOnlyProfiles, ...newReadOnly],
+      bookmarks: [...state.bookmarks, ...newBookmarks],
+    },
+    added: newLocations.length + newReadOnly.length + newBookmarks.length,
+  };
+}
+
 /**
  * Clear app state (for testing or reset)
  */
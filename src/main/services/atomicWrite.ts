@@ -0,0 +1,32 @@
+import fs from 'fs';
+
+/**
+ * Write a file by writing a temporary file next to it and renaming it over the
+ * target, so a crash mid-write leaves either the old or the new content.
+ * The temporary file is flushed to disk before the rename, so a power loss
+ * can't leave the renamed file empty. An existing file keeps its permissions
+ * (credentials files are often 0600).
+ */
+export function writeFileAtomic(filePath: string, content: string): void {
+  const tempPath = `${filePath}.${process.pid}.tmp`;
+  let mode: number | undefined;
+  try {
+    mode = fs.statSync(filePath).mode & 0o777;
+  } catch {
+    // A new file gets the default permissions
+  }
+
+  try {
+    const fd = fs.openSync(tempPath, 'w', mode);
+    try {
+      fs.writeFileSync(fd, content, 'utf-8');
+      fs.fsyncSync(fd);
+    } finally {
+      fs.closeSync(fd);
+    }
+    fs.renameSync(tempPath, filePath);
+  } catch (error) {
+    fs.rmSync(tempPath, { force: true });
+    throw error;
+  }
+}
@@ -1,6 +1,7 @@
 import fs from 'fs';
 import path from 'path';
 import os from 'os';
+import { writeFileAtomic } from './atomicWrite';
 
 // Profile type detection for UI display
 export type ProfileType =
@@ -9,7 +10,8 @@ export type ProfileType =
   | 'sso'          // SSO-based authentication
   | 'process'      // External credential process
   | 'web-identity' // Web identity token (EKS, etc.)
-  | 'config-only'; // Has region/output but no credentials
+  | 'config-only'  // Has region/output but no credentials
+  | 'anonymous';   // Unsigned requests, for publicly readable buckets
 
 export interface AwsProfile {
   name: string;
@@ -20,12 +22,22 @@ export interface AwsProfile {
   output?: string;
   sourceProfile?: string;
   roleArn?: string;
+  // MFA device the role assumption asks a code for
+  mfaSerial?: string;
+  // Role assumption settings
+  externalId?: string;
+  roleSessionName?: string;
+  durationSeconds?: number;
+  // Why the chain of source profiles can't provide credentials, for role profiles
+  roleChainError?: string;
   // SSO fields
   ssoStartUrl?: string;
   ssoRegion?: string;
   ssoAccountId?: string;
   ssoRoleName?: string;
   ssoSession?: string;
+  // Whether the [sso-session] section named by ssoSession exists
+  ssoSessionDefined?: boolean;
   // Process credentials
   credentialProcess?: string;
   // Web identity
@@ -36,6 +48,8 @@ export interface AwsProfile {
   profileType: ProfileType;
   // Whether this profile can potentially provide credentials (via SDK)
   hasCredentials: boolean;
+  // Files the profile was defined in (credentials and/or config)
+  sourceFiles?: string[];
 }
 
 export interface ParsedCredentials {
@@ -43,6 +57,27 @@ export interface ParsedCredentials {
   defaultRegion?: string;
 }
 
+/**
+ * Cleans up a raw INI value: strips surrounding quotes and drops inline comments.
+ * An inline comment starts at an unquoted # or ; preceded by whitespace, so values
+ * like ARNs or base64 that merely contain those characters are kept intact.
+ */
+function parseIniValue(raw: string): string {
+  const value = raw.trim();
+
+  const quote = value[0];
+  if (quote === '"' || quote === "'") {
+    const end = value.indexOf(quote, 1);
+    const rest = end === -1 ? '' : value.substring(end + 1).trim();
+    if (end !== -1 && (!rest || rest.startsWith('#') || rest.startsWith(';'))) {
+      return value.substring(1, end);
+    }
+  }
+
+  const commentStart = value.search(/\s[#;]/);
+  return commentStart === -1 ? value : value.substring(0, commentStart).trim();
+}
+
 /**
  * Parses an INI-style config file (like ~/.aws/credentials or ~/.aws/config)
  * Returns a map of section names to key-value pairs
@@ -71,11 +106,11 @@ export function parseIniFile(content: string): Map<string, Map<string, string>>
       continue;
     }
 
-    // Parse key = value pairs
+    // Parse key = value pairs (split on the first '=' only)
     const keyValueMatch = line.match(/^([^=]+)=(.*)$/);
     if (keyValueMatch && currentSection) {
       const key = keyValueMatch[1].trim();
-      const value = keyValueMatch[2].trim();
+      const value = parseIniValue(keyValueMatch[2]);
       sections.get(currentSection)?.set(key, value);
     }
   }
@@ -120,9 +155,12 @@ export function readCredentialsFile(filePath?: string): Map<string, Map<string,
   }
 }
 
+const SSO_SESSION_PREFIX = 'sso-session ';
+
 /**
  * Reads and parses the AWS config file
- * Note: Config file uses [profile name] format except for [default]
+ * Note: Config file uses [profile name] format except for [default].
+ * [sso-session name] sections aren't profiles and are left out; see readSsoSessions
  */
 export function readConfigFile(filePath?: string): Map<string, Map<string, string>> {
   const configPath = filePath ?? getConfigPath();
@@ -135,6 +173,9 @@ export function readConfigFile(filePath?: string): Map<string, Map<string, strin
     const normalizedSections = new Map<string, Map<string, string>>();
 
     for (const [sectionName, values] of rawSections) {
+      if (sectionName.startsWith(SSO_SESSION_PREFIX)) {
+        continue;
+      }
       // Config file uses [profile foo] for non-default profiles
       const normalizedName = sectionName.startsWith('profile ')
         ? sectionName.substring(8)
@@ -149,6 +190,29 @@ export function readConfigFile(filePath?: string): Map<string, Map<string, strin
   }
 }
 
+/**
+ * Reads the [sso-session name] sections of the AWS config file, which profiles
+ * share through sso_session = name
+ * @returns Map of session names to their settings
+ */
+export function readSsoSessions(filePath?: string): Map<string, Map<string, string>> {
+  const configPath = filePath ?? getConfigPath();
+  const sessions = new Map<string, Map<string, string>>();
+
+  try {
+    const rawSections = parseIniFile(fs.readFileSync(configPath, 'utf-8'));
+    for (const [sectionName, values] of rawSections) {
+      if (sectionName.startsWith(SSO_SESSION_PREFIX)) {
+        sessions.set(sectionName.substring(SSO_SESSION_PREFIX.length).trim(), values);
+      }
+    }
+  } catch (error) {
+    // File doesn't exist or can't be read - no sessions
+  }
+
+  return sessions;
+}
+
 /**
  * Helper to get a value from either credentials or config data
  * AWS CLI allows many settings in either file, so we check both
@@ -162,6 +226,27 @@ function getFromEither(
   return configData?.get(key) ?? credData?.get(key);
 }
 
+/**
+ * Expands a leading ~ in a configured file path to the user's home directory
+ */
+function expandHomePath(filePath: string): string {
+  if (filePath === '~' || filePath.startsWith('~/') || filePath.startsWith('~\\')) {
+    return path.join(os.homedir(), filePath.slice(1));
+  }
+  return filePath;
+}
+
+/**
+ * Checks whether a web identity token file exists (supports ~ paths)
+ */
+export function webIdentityTokenFileExists(tokenFile: string): boolean {
+  try {
+    return fs.statSync(expandHomePath(tokenFile)).isFile();
+  } catch {
+    return false;
+  }
+}
+
 /**
  * Detects the profile type based on configuration
  * Note: AWS CLI allows role_arn, source_profile, etc. in either credentials or config file
@@ -211,7 +296,8 @@ function canProvideCredentials(
   profileType: ProfileType,
   credData: Map<string, string> | undefined,
   configData: Map<string, string> | undefined,
-  credentials: Map<string, Map<string, string>>
+  credentials: Map<string, Map<string, string>>,
+  ssoSessions: Map<string, Map<string, string>>
 ): boolean {
   switch (profileType) {
     case 'static':
@@ -224,13 +310,22 @@ function canProvideCredentials(
       const ssoSession = getFromEither(credData, configData, 'sso_session');
       const ssoAccountId = getFromEither(credData, configData, 'sso_account_id');
       const ssoRoleName = getFromEither(credData, configData, 'sso_role_name');
+      if (ssoSession) {
+        // The referenced session has to exist and say where to sign in
+        const session = ssoSessions.get(ssoSession);
+        const ssoRegion = session?.get('sso_region') ?? getFromEither(credData, configData, 'sso_region');
+        if (!session || !(session.get('sso_start_url') ?? ssoStartUrl) || !ssoRegion) {
+          return false;
+        }
+      }
       return !!(ssoStartUrl || ssoSession) && !!(ssoAccountId && ssoRoleName);
 
     case 'web-identity':
-      // Web identity needs token file and role (check both files)
+      // Web identity needs role and an existing token file (check both files)
+      // The SDK exchanges the token for role credentials via AssumeRoleWithWebIdentity
       const tokenFile = getFromEither(credData, configData, 'web_identity_token_file');
       const roleArnWeb = getFromEither(credData, configData, 'role_arn');
-      return !!(tokenFile && roleArnWeb);
+      return !!(tokenFile && roleArnWeb) && webIdentityTokenFileExists(tokenFile!);
 
     case 'process':
       // Process credentials just need the command (check both files)
@@ -278,6 +373,25 @@ function createTestProfile(): AwsProfile {
   };
 }
 
+/**
+ * Name of the built-in profile that sends unsigned requests. Parentheses keep it
+ * apart from real profile names, which the AWS CLI never writes that way.
+ */
+export const ANONYMOUS_PROFILE_NAME = '(anonymous)';
+
+/**
+ * The built-in anonymous profile, for browsing public buckets without any
+ * credentials. It can only read; the main process refuses writes with it.
+ */
+export function createAnonymousProfile(): AwsProfile {
+  return {
+    name: ANONYMOUS_PROFILE_NAME,
+    region: process.env.AWS_REGION || process.env.AWS_DEFAULT_REGION || undefined,
+    profileType: 'anonymous',
+    hasCredentials: true,
+  };
+}
+
 /**
  * Merges credentials and config to build a complete list of profiles
  */
@@ -285,8 +399,11 @@ export function loadAwsProfiles(
   credentialsPath?: string,
   configPath?: string
 ): ParsedCredentials {
-  const credentials = readCredentialsFile(credentialsPath);
-  const config = readConfigFile(configPath);
+  const credPath = credentialsPath ?? getCredentialsPath();
+  const confPath = configPath ?? getConfigPath();
+  const credentials = readCredentialsFile(credPath);
+  const config = readConfigFile(confPath);
+  const ssoSessions = readSsoSessions(confPath);
 
   // Collect all unique profile names from both files
   const allProfileNames = new Set<string>();
@@ -309,7 +426,8 @@ export function loadAwsProfiles(
     const profile: AwsProfile = {
       name,
       profileType,
-      hasCredentials: canProvideCredentials(profileType, credData, configData, credentials),
+      hasCredentials: canProvideCredentials(profileType, credData, configData, credentials, ssoSessions),
+      sourceFiles: [...(credData ? [credPath] : []), ...(configData ? [confPath] : [])],
     };
 
     // Get credentials (for static profiles)
@@ -331,6 +449,11 @@ export function loadAwsProfiles(
     profile.sourceProfile = getFromEither(credData, configData, 'source_profile');
     profile.roleArn = getFromEither(credData, configData, 'role_arn');
     profile.credentialSource = getFromEither(credData, configData, 'credential_source');
+    profile.mfaSerial = getFromEither(credData, configData, 'mfa_serial');
+    profile.externalId = getFromEither(credData, configData, 'external_id');
+    profile.roleSessionName = getFromEither(credData, configData, 'role_session_name');
+    const durationSeconds = Number(getFromEither(credData, configData, 'duration_seconds'));
+    profile.durationSeconds = Number.isInteger(durationSeconds) && durationSeconds > 0 ? durationSeconds : undefined;
 
     // SSO fields (check both files)
     profile.ssoStartUrl = getFromEither(credData, configData, 'sso_start_url');
@@ -338,6 +461,13 @@ export function loadAwsProfiles(
     profile.ssoAccountId = getFromEither(credData, configData, 'sso_account_id');
     profile.ssoRoleName = getFromEither(credData, configData, 'sso_role_name');
     profile.ssoSession = getFromEither(credData, configData, 'sso_session');
+    if (profile.ssoSession) {
+      // Start URL and region come from the [sso-session] section the profile refers to
+      const session = ssoSessions.get(profile.ssoSession);
+      profile.ssoSessionDefined = !!session;
+      profile.ssoStartUrl = session?.get('sso_start_url') ?? profile.ssoStartUrl;
+      profile.ssoRegion = session?.get('sso_region') ?? profile.ssoRegion;
+    }
 
     // Process credentials (check both files)
     profile.credentialProcess = getFromEither(credData, configData, 'credential_process');
@@ -348,6 +478,16 @@ export function loadAwsProfiles(
     profiles.push(profile);
   }
 
+  // A role can take its credentials from another role; follow the whole chain
+  // rather than only checking the first source profile
+  for (const profile of profiles) {
+    if (profile.profileType === 'role' && profile.sourceProfile && !profile.credentialSource) {
+      const { error } = resolveRoleChain(profile.name, profiles);
+      profile.hasCredentials = !error;
+      profile.roleChainError = error;
+    }
+  }
+
   // Add test profile in test mode (for LocalStack)
   if (isTestMode) {
     // Only add if not already present
@@ -389,10 +529,88 @@ export function getProfile(
   credentialsPath?: string,
   configPath?: string
 ): AwsProfile | undefined {
+  if (profileName === ANONYMOUS_PROFILE_NAME) {
+    return createAnonymousProfile();
+  }
   const { profiles } = loadAwsProfiles(credentialsPath, configPath);
   return profiles.find(p => p.name === profileName);
 }
 
+/**
+ * One role assumption in a chain of source profiles
+ */
+export interface RoleHop {
+  profileName: string;
+  roleArn: string;
+  externalId?: string;
+  mfaSerial?: string;
+  roleSessionName?: string;
+  durationSeconds?: number;
+  region?: string;
+}
+
+/**
+ * Where a role chain gets its first credentials, and the roles assumed from
+ * there in order (the last one is the requested profile's)
+ */
+export interface RoleChain {
+  source: { profileName: string } | { credentialSource: string };
+  hops: RoleHop[];
+}
+
+/**
+ * Follows source_profile references from a role profile down to the profile (or
+ * credential_source) that provides the first credentials
+ * @param profileName - The role profile
+ * @param profiles - All profiles, as loaded by loadAwsProfiles
+ */
+export function resolveRoleChain(profileName: string, profiles: AwsProfile[]): { chain?: RoleChain; error?: string } {
+  const hops: RoleHop[] = [];
+  const visited: string[] = [];
+  let current = profiles.find(p => p.name === profileName);
+
+  while (current) {
+    if (current.profileType !== 'role' || !current.roleArn) {
+      if (visited.length === 0) {
+        return { error: `Profile '${profileName}' does not assume a role` };
+      }
+      if (!current.hasCredentials) {
+        return { error: `Source profile '${current.name}' has no valid credentials` };
+      }
+      return { chain: { source: { profileName: current.name }, hops } };
+    }
+
+    visited.push(current.name);
+    hops.unshift({
+      profileName: current.name,
+      roleArn: current.roleArn,
+      externalId: current.externalId,
+      mfaSerial: current.mfaSerial,
+      roleSessionName: current.roleSessionName,
+      durationSeconds: current.durationSeconds,
+      region: current.region,
+    });
+
+    if (current.credentialSource) {
+      return { chain: { source: { credentialSource: current.credentialSource }, hops } };
+    }
+    if (!current.sourceProfile) {
+      return { error: `Profile '${current.name}' requires source_profile or credential_source` };
+    }
+
+    const sourceName: string = current.sourceProfile;
+    if (visited.includes(sourceName)) {
+      return { error: `Role profiles refer to each other in a loop: ${[...visited, sourceName].join(' -> ')}` };
+    }
+    current = profiles.find(p => p.name === sourceName);
+    if (!current) {
+      return { error: `Source profile '${sourceName}' not found` };
+    }
+  }
+
+  return { error: `Profile '${profileName}' not found` };
+}
+
 /**
  * Validates that a profile has usable credentials
  */
@@ -410,9 +628,18 @@ export function validateProfile(profile: AwsProfile): { valid: boolean; reason?:
       if (!profile.sourceProfile && !profile.credentialSource) {
         return { valid: false, reason: 'Role profile requires source_profile or credential_source' };
       }
-      return { valid: false, reason: 'Source profile has no valid credentials' };
+      return { valid: false, reason: profile.roleChainError ?? 'Source profile has no valid credentials' };
 
     case 'sso':
+      if (profile.ssoSession && profile.ssoSessionDefined === false) {
+        return { valid: false, reason: `sso_session '${profile.ssoSession}' not defined` };
+      }
+      if (profile.ssoSession && (!profile.ssoStartUrl || !profile.ssoRegion)) {
+        return {
+          valid: false,
+          reason: `sso_session '${profile.ssoSession}' requires sso_start_url and sso_region`,
+        };
+      }
       if (!profile.ssoAccountId || !profile.ssoRoleName) {
         return { valid: false, reason: 'SSO profile requires sso_account_id and sso_role_name' };
       }
@@ -422,7 +649,19 @@ export function validateProfile(profile: AwsProfile): { valid: boolean; reason?:
       return { valid: false, reason: 'Process credentials command not configured' };
 
     case 'web-identity':
-      return { valid: false, reason: 'Web identity profile missing token file or role ARN' };
+      if (!profile.roleArn) {
+        return { valid: false, reason: 'Web identity profile requires role_arn' };
+      }
+      if (!profile.webIdentityTokenFile) {
+        return { valid: false, reason: 'Web identity profile requires web_identity_token_file' };
+      }
+      if (!webIdentityTokenFileExists(profile.webIdentityTokenFile)) {
+        return {
+          valid: false,
+          reason: `Web identity token file not found: ${profile.webIdentityTokenFile}`,
+        };
+      }
+      return { valid: false, reason: 'Web identity profile missing required configuration' };
 
     default:
       return { valid: false, reason: 'Profile has no credentials configured' };
@@ -446,5 +685,403 @@ export function getProfileTypeDescription(profileType: ProfileType): string {
       return 'Web Identity';
     case 'config-only':
       return 'Config Only';
+    case 'anonymous':
+      return 'Anonymous';
+  }
+}
+
+/**
+ * Shortens a path under the home directory to ~/...
+ */
+function displayPath(filePath: string): string {
+  const home = os.homedir();
+  return filePath.startsWith(home + path.sep) ? '~' + filePath.substring(home.length) : filePath;
+}
+
+/**
+ * Builds a plain-text report of all profiles for troubleshooting credential setups.
+ * Lists each profile's type, validity and the files it came from. Access keys, secrets,
+ * session tokens and credential_process commands are never included.
+ */
+export function buildProfileDiagnosticReport(
+  credentialsPath?: string,
+  configPath?: string,
+  now: Date = new Date()
+): string {
+  const credPath = credentialsPath ?? getCredentialsPath();
+  const confPath = configPath ?? getConfigPath();
+  const { profiles, defaultRegion } = loadAwsProfiles(credPath, confPath);
+
+  const lines = [
+    'S3 Browser profile diagnostics',
+    `Generated: ${now.toISOString()}`,
+    `Credentials file: ${displayPath(credPath)} (${fs.existsSync(credPath) ? 'found' : 'not found'})`,
+    `Config file: ${displayPath(confPath)} (${fs.existsSync(confPath) ? 'found' : 'not found'})`,
+    `Default region: ${defaultRegion ?? '(none)'}`,
+    '',
+    `Profiles (${profiles.length}):`,
+  ];
+
+  for (const profile of profiles) {
+    const validation = validateProfile(profile);
+    const sources = profile.sourceFiles?.length
+      ? profile.sourceFiles.map(displayPath).join(', ')
+      : 'environment';
+
+    lines.push(`- ${profile.name}`);
+    lines.push(`    Type: ${getProfileTypeDescription(profile.profileType)}`);
+    lines.push(`    Status: ${validation.valid ? 'valid' : `invalid - ${validation.reason}`}`);
+    if (profile.region) lines.push(`    Region: ${profile.region}`);
+    if (profile.sourceProfile) lines.push(`    Source profile: ${profile.sourceProfile}`);
+    if (profile.mfaSerial) lines.push('    MFA: required');
+    if (profile.ssoSession) lines.push(`    SSO session: ${profile.ssoSession}`);
+    lines.push(`    From: ${sources}`);
+  }
+
+  return lines.join('\n') + '\n';
+}
+
+/**
+ * Config file keys written for a profile, in the order they appear in the section
+ */
+const PROFILE_CONFIG_KEYS: [keyof AwsProfile, string][] = [
+  ['region', 'region'],
+  ['output', 'output'],
+  ['roleArn', 'role_arn'],
+  ['sourceProfile', 'source_profile'],
+  ['credentialSource', 'credential_source'],
+  ['mfaSerial', 'mfa_serial'],
+  ['ssoSession', 'sso_session'],
+  ['ssoStartUrl', 'sso_start_url'],
+  ['ssoRegion', 'sso_region'],
+  ['ssoAccountId', 'sso_account_id'],
+  ['ssoRoleName', 'sso_role_name'],
+  ['credentialProcess', 'credential_process'],
+  ['webIdentityTokenFile', 'web_identity_token_file'],
+];
+
+/**
+ * Config file settings of a profile, as created or edited from the app
+ */
+export type ProfileConfig = Pick<
+  AwsProfile,
+  | 'name'
+  | 'region'
+  | 'output'
+  | 'roleArn'
+  | 'sourceProfile'
+  | 'credentialSource'
+  | 'mfaSerial'
+  | 'ssoSession'
+  | 'ssoStartUrl'
+  | 'ssoRegion'
+  | 'ssoAccountId'
+  | 'ssoRoleName'
+  | 'credentialProcess'
+  | 'webIdentityTokenFile'
+>;
+
+export interface ProfileWriteResult {
+  success: boolean;
+  error?: string;
+}
+
+/**
+ * The config file section header for a profile: [default] or [profile name]
+ */
+function configSectionName(profileName: string): string {
+  return profileName === 'default' ? 'default' : `profile ${profileName}`;
+}
+
+/**
+ * Formats a profile as a config file section. Access keys aren't written: they
+ * belong in the credentials file, not the config file.
+ */
+export function formatProfileSection(profile: AwsProfile): string {
+  const lines = [`[${configSectionName(profile.name)}]`];
+  for (const [field, key] of PROFILE_CONFIG_KEYS) {
+    const value = profile[field];
+    if (typeof value === 'string' && value.trim() !== '') {
+      lines.push(`${key} = ${value.trim()}`);
+    }
+  }
+  return lines.join('\n') + '\n';
+}
+
+/**
+ * The settings a profile's config file section sets, as written there (an
+ * sso_session's start URL isn't filled in, for example)
+ * @returns The settings, or null when the config file has no section for the profile
+ */
+export function getProfileConfig(name: string, configPath?: string): ProfileConfig | null {
+  const section = readConfigFile(configPath).get(name);
+  if (!section) {
+    return null;
+  }
+
+  const config: ProfileConfig = { name };
+  for (const [field, key] of PROFILE_CONFIG_KEYS) {
+    const value = section.get(key);
+    if (value !== undefined) {
+      (config as Record<string, string>)[field] = value;
+    }
+  }
+  return config;
+}
+
+/**
+ * Checks a profile can be written to the config file without breaking it
+ */
+function checkWritableProfile(profile: AwsProfile): string | null {
+  const name = profile.name.trim();
+  if (!name || name !== profile.name) {
+    return 'Profile name must not be empty or start or end with spaces';
+  }
+  if (/[[\]\r\n]/.test(name) || name.startsWith('sso-session ')) {
+    return `Invalid profile name: ${name}`;
+  }
+  if (name === ANONYMOUS_PROFILE_NAME) {
+    return `'${ANONYMOUS_PROFILE_NAME}' is reserved for the built-in anonymous profile`;
+  }
+  for (const [field, key] of PROFILE_CONFIG_KEYS) {
+    const value = profile[field];
+    if (typeof value === 'string' && /[\r\n]/.test(value)) {
+      return `${key} must be a single line`;
+    }
+  }
+  return null;
+}
+
+function readConfigText(configPath: string): string {
+  try {
+    return fs.readFileSync(configPath, 'utf-8');
+  } catch {
+    return '';
+  }
+}
+
+function writeConfigText(configPath: string, content: string): void {
+  fs.mkdirSync(path.dirname(configPath), { recursive: true });
+  // The CLI and SDKs read these files too, so they must never see half a file
+  writeFileAtomic(configPath, content);
+}
+
+/**
+ * Finds the lines of a config file section: from its header up to the next
+ * header. Comments and blank lines just before the next header are left out, as
+ * they usually describe the section that follows.
+ * @returns [start, end) line indexes, or null when the section isn't there
+ */
+function findSectionLines(lines: string[], sectionName: string): [number, number] | null {
+  const start = lines.findIndex(line => line.trim() === `[${sectionName}]`);
+  if (start === -1) {
+    return null;
+  }
+
+  let end = start + 1;
+  while (end < lines.length && !/^\[[^\]]+\]$/.test(lines[end].trim())) {
+    end++;
+  }
+  while (end > start + 1 && /^\s*([#;].*)?$/.test(lines[end - 1])) {
+    end--;
+  }
+  return [start, end];
+}
+
+/**
+ * Adds a profile to the AWS config file as a [profile name] section. The rest of
+ * the file, comments included, is kept as it is.
+ * @param profile - The profile to write
+ * @param options.overwrite - Replace the profile's section if it already exists
+ * @param options.configPath - The config file, ~/.aws/config by default
+ */
+export function addProfile(
+  profile: AwsProfile,
+  options: { overwrite?: boolean; configPath?: string; credentialsPath?: string } = {}
+): ProfileWriteResult {
+  const invalid = checkWritableProfile(profile);
+  if (invalid) {
+    return { success: false, error: invalid };
+  }
+
+  const confPath = options.configPath ?? getConfigPath();
+  const existing = getProfile(profile.name, options.credentialsPath, confPath);
+  if (existing && !options.overwrite) {
+    return { success: false, error: `Profile '${profile.name}' already exists` };
+  }
+
+  try {
+    const content = readConfigText(confPath);
+    const lines = content.split('\n');
+    const section = formatProfileSection(profile).trimEnd().split('\n');
+    const range = findSectionLines(lines, configSectionName(profile.name));
+
+    if (range) {
+      lines.splice(range[0], range[1] - range[0], ...section);
+      writeConfigText(confPath, lines.join('\n'));
+    } else {
+      const separator = content === '' ? '' : content.endsWith('\n') ? '\n' : '\n\n';
+      writeConfigText(confPath, content + separator + section.join('\n') + '\n');
+    }
+    return { success: true };
+  } catch (error) {
+    return { success: false, error: error instanceof Error ? error.message : 'Failed to write the config file' };
+  }
+}
+
+/**
+ * Rewrites a profile's section of the AWS config file in place. Other sections,
+ * and the comments in them, are left untouched; so are the comments and the
+ * settings this app doesn't manage (s3 = ..., duration_seconds, ...) in the section itself.
+ * @param name - The profile to update
+ * @param profile - Its new settings; a different name renames the profile, in
+ *   the credentials file too
+ * @param options.configPath - The config file, ~/.aws/config by default
+ * @param options.credentialsPath - The credentials file, ~/.aws/credentials by default
+ */
+export function updateProfile(
+  name: string,
+  profile: AwsProfile,
+  options: { configPath?: string; credentialsPath?: string } = {}
+): ProfileWriteResult {
+  const invalid = checkWritableProfile(profile);
+  if (invalid) {
+    return { success: false, error: invalid };
+  }
+
+  const confPath = options.configPath ?? getConfigPath();
+  if (profile.name !== name && getProfile(profile.name, options.credentialsPath, confPath)) {
+    return { success: false, error: `Profile '${profile.name}' already exists` };
+  }
+
+  try {
+    const originalConfig = readConfigText(confPath);
+    const lines = originalConfig.split('\n');
+    const range = findSectionLines(lines, configSectionName(name));
+    if (!range) {
+      return { success: false, error: `Profile '${name}' is not defined in ${displayPath(confPath)}` };
+    }
+
+    const managedKeys = new Set(PROFILE_CONFIG_KEYS.map(([, key]) => key));
+    const kept = lines.slice(range[0] + 1, range[1]).filter(line => {
+      const key = line.match(/^([^=#;]+)=/)?.[1].trim();
+      return !key || !managedKeys.has(key);
+    });
+
+    const section = formatProfileSection(profile).trimEnd().split('\n');
+    lines.splice(range[0], range[1] - range[0], ...section, ...kept);
+
+    // A renamed profile's access keys move with it
+    const credPath = options.credentialsPath ?? getCredentialsPath();
+    const credentials =
+      profile.name !== name ? editCredentialsSection(readConfigText(credPath), name, profile.name) : null;
+    writeProfileFiles(confPath, originalConfig, lines.join('\n'), credPath, credentials);
+    return { success: true };
+  } catch (error) {
+    return { success: false, error: error instanceof Error ? error.message : 'Failed to write the config file' };
+  }
+}
+
+/**
+ * Removes a section found by findSectionLines, with the comments just above its
+ * header and one of the blank lines around it
+ */
+function removeSectionLines(lines: string[], range: [number, number]): void {
+  let [start, end] = range;
+  while (start > 0 && /^\s*[#;]/.test(lines[start - 1])) {
+    start--;
+  }
+  // Take one blank line separating the section from the next, so none pile up
+  if (lines[end]?.trim() === '' && end < lines.length - 1) {
+    end++;
+  } else if (start > 0 && lines[start - 1].trim() === '') {
+    start--;
+  }
+  lines.splice(start, end - start);
+}
+
+/**
+ * Renames or removes a profile's section in the credentials file, where the
+ * header is the bare profile name
+ * @param newName - The section's new name, or null to remove it
+ * @returns The file's new content, or null when it has no section for the profile
+ */
+function editCredentialsSection(content: string, name: string, newName: string | null): string | null {
+  const lines = content.split('\n');
+  const range = findSectionLines(lines, name);
+  if (!range) {
+    return null;
+  }
+  if (newName === null) {
+    removeSectionLines(lines, range);
+  } else {
+    lines[range[0]] = `[${newName}]`;
+  }
+  return lines.join('\n');
+}
+
+/**
+ * Writes the config file and then the credentials file. If the credentials file
+ * can't be written, the config file is put back so the two still agree.
+ * @param credentials - The credentials file's new content, or null to leave it alone
+ */
+function writeProfileFiles(
+  confPath: string,
+  originalConfig: string,
+  config: string,
+  credPath: string,
+  credentials: string | null
+): void {
+  writeConfigText(confPath, config);
+  if (credentials === null) {
+    return;
+  }
+  try {
+    writeConfigText(credPath, credentials);
+  } catch (error) {
+    writeConfigText(confPath, originalConfig);
+    throw error;
+  }
+}
+
+/**
+ * Removes a profile's sections from the AWS config and credentials files, with
+ * the comments just above their headers, leaving the other sections as they
+ * are. The default profile can't be removed.
+ * @param name - The profile to remove
+ * @param options.configPath - The config file, ~/.aws/config by default
+ * @param options.credentialsPath - The credentials file, ~/.aws/credentials by default
+ */
+export function removeProfile(
+  name: string,
+  options: { configPath?: string; credentialsPath?: string } = {}
+): ProfileWriteResult {
+  if (name === 'default') {
+    return { success: false, error: 'The default profile cannot be removed' };
+  }
+
+  const confPath = options.configPath ?? getConfigPath();
+  const credPath = options.credentialsPath ?? getCredentialsPath();
+  try {
+    const originalConfig = readConfigText(confPath);
+    const lines = originalConfig.split('\n');
+    const range = findSectionLines(lines, configSectionName(name));
+    const credentials = editCredentialsSection(readConfigText(credPath), name, null);
+    if (!range && credentials === null) {
+      return {
+        success: false,
+        error: `Profile '${name}' is not defined in ${displayPath(confPath)} or ${displayPath(credPath)}`,
+      };
+    }
+
+    if (range) {
+      removeSectionLines(lines, range);
+      writeProfileFiles(confPath, originalConfig, lines.join('\n'), credPath, credentials);
+    } else {
+      writeConfigText(credPath, credentials as string);
+    }
+    return { success: true };
+  } catch (error) {
+    return { success: false, error: error instanceof Error ? error.message : 'Failed to write the config file' };
   }
 }
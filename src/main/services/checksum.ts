@@ -0,0 +1,78 @@
+import { createHash } from 'crypto';
+
+/**
+ * Additional checksum algorithms supported for S3 uploads
+ */
+export type ChecksumAlgorithmName = 'CRC32C' | 'SHA256';
+
+// CRC32C (Castagnoli) lookup table, reflected polynomial 0x82F63B78
+const CRC32C_TABLE: Uint32Array = (() => {
+  const table = new Uint32Array(256);
+  for (let i = 0; i < 256; i++) {
+    let crc = i;
+    for (let j = 0; j < 8; j++) {
+      crc = crc & 1 ? (crc >>> 1) ^ 0x82f63b78 : crc >>> 1;
+    }
+    table[i] = crc >>> 0;
+  }
+  return table;
+})();
+
+/**
+ * Computes the CRC32C checksum of the data as an unsigned 32-bit integer
+ */
+export function crc32c(data: Uint8Array): number {
+  let crc = 0xffffffff;
+  for (let i = 0; i < data.length; i++) {
+    crc = CRC32C_TABLE[(crc ^ data[i]) & 0xff] ^ (crc >>> 8);
+  }
+  return (crc ^ 0xffffffff) >>> 0;
+}
+
+/**
+ * Computes a checksum in the format S3 uses for x-amz-checksum-* headers
+ * (base64 of the big-endian digest bytes)
+ * @param data - The object body
+ * @param algorithm - The checksum algorithm
+ */
+export function computeChecksum(data: Buffer | string, algorithm: ChecksumAlgorithmName): string {
+  const buffer = typeof data === 'string' ? Buffer.from(data, 'utf-8') : data;
+
+  if (algorithm === 'SHA256') {
+    return createHash('sha256').update(buffer).digest('base64');
+  }
+
+  const digest = Buffer.alloc(4);
+  digest.writeUInt32BE(crc32c(buffer), 0);
+  return digest.toString('base64');
+}
+
+/**
+ * Gets the part count of a multipart upload ETag ("<md5 of part md5s>-<parts>")
+ * @param etag - The ETag, with or without surrounding quotes
+ * @returns The number of parts, or null for single-part (plain MD5) ETags
+ */
+export function getEtagPartCount(etag: string): number | null {
+  const match = etag.replace(/"/g, '').match(/^[0-9a-f]{32}-(\d+)$/i);
+  if (!match) {
+    return null;
+  }
+  const parts = Number(match[1]);
+  return parts > 0 ? parts : null;
+}
+
+/**
+ * Compares an object's ETag with the MD5 of its content
+ * Multipart ETags are not an MD5 of the whole object, so they can't be compared
+ * @param etag - The ETag, with or without surrounding quotes
+ * @param data - The object body
+ * @returns Whether the content matches, or null if the ETag can't be checked this way
+ */
+export function md5MatchesEtag(etag: string, data: Buffer | string): boolean | null {
+  const normalized = etag.replace(/"/g, '').toLowerCase();
+  if (!/^[0-9a-f]{32}$/.test(normalized)) {
+    return null;
+  }
+  const buffer = typeof data === 'string' ? Buffer.from(data, 'utf-8') : data;
+  return createHash('md5').update(buffer).digest('hex') === normalized;
+}
@@ -0,0 +1,79 @@
+/**
+ * Watching the AWS config files, so profiles edited in another window or
+ * refreshed by `aws sso login` show up without restarting the app
+ */
+
+import fs from 'fs';
+import path from 'path';
+import { getConfigPath, getCredentialsPath } from './awsCredentials';
+import { getSsoCacheDir } from './ssoLogin';
+
+/** How long to wait for writes to settle before reloading */
+export const CONFIG_RELOAD_DEBOUNCE_MS = 300;
+
+export interface ConfigWatchOptions {
+  /** Files whose changes count; the credentials and config files by default */
+  files?: string[];
+  /** Directories where any change counts; the SSO token cache by default */
+  directories?: string[];
+  debounceMs?: number;
+}
+
+/**
+ * Calls onChange once writes to the watched files have settled. The parent
+ * directories are watched rather than the files themselves, since editors and
+ * the AWS CLI often replace a file instead of writing to it, and a file that
+ * doesn't exist yet can still be created. Directories that don't exist are skipped.
+ * @returns Stops watching
+ */
+export function watchAwsConfigFiles(onChange: () => void, options: ConfigWatchOptions = {}): () => void {
+  const {
+    files = [getCredentialsPath(), getConfigPath()],
+    directories = [getSsoCacheDir()],
+    debounceMs = CONFIG_RELOAD_DEBOUNCE_MS,
+  } = options;
+
+  let timer: ReturnType<typeof setTimeout> | null = null;
+  const schedule = () => {
+    if (timer) clearTimeout(timer);
+    timer = setTimeout(() => {
+      timer = null;
+      onChange();
+    }, debounceMs);
+  };
+
+  // Names to look for in each watched directory; null means any change counts
+  const watched = new Map<string, Set<string> | null>();
+  for (const file of files) {
+    const dir = path.dirname(file);
+    const names = watched.get(dir);
+    if (names === null) continue;
+    watched.set(dir, new Set([...(names ?? []), path.basename(file)]));
+  }
+  for (const dir of directories) {
+    watched.set(dir, null);
+  }
+
+  const watchers: fs.FSWatcher[] = [];
+  for (const [dir, names] of watched) {
+    try {
+      const watcher = fs.watch(dir, (_event, filename) => {
+        // Some platforms don't report the file name; treat that as a change
+        if (names === null || !filename || names.has(filename.toString())) {
+          schedule();
+        }
+      });
+      watcher.on('error', () => watcher.close());
+      watchers.push(watcher);
+    } catch {
+      // Directory doesn't exist (e.g. no SSO cache yet) - nothing to watch
+    }
+  }
+
+  return () => {
+    if (timer) clearTimeout(timer);
+    for (const watcher of watchers) {
+      watcher.close();
+    }
+  };
+}
@@ -0,0 +1,71 @@
+/**
+ * Resolving a profile to concrete credentials and formatting them as shell
+ * environment variables, for scripts run alongside the app
+ */
+
+import { fromIni } from '@aws-sdk/credential-providers';
+import { ANONYMOUS_PROFILE_NAME, getProfile } from './awsCredentials';
+import { createRoleChainProvider } from './roleChain';
+import { getCredentialProviderOptions } from './s3Service';
+
+export interface EnvExportResult {
+  success: boolean;
+  /** Variable names and values, in the order they should be set */
+  variables?: [string, string][];
+  /** When temporary credentials (assumed role, SSO) stop working */
+  expiration?: Date;
+  error?: string;
+}
+
+/**
+ * Resolves a profile the way the S3 client does (assuming roles, using the SSO
+ * cache, asking for MFA codes) and returns the credentials as AWS_* variables.
+ * The values are only returned, never logged; errors mention the profile, not secrets.
+ * @param profileName - The profile to resolve
+ */
+export async function exportCredentialsAsEnv(profileName: string): Promise<EnvExportResult> {
+  if (profileName === ANONYMOUS_PROFILE_NAME) {
+    return { success: false, error: 'The anonymous profile has no credentials to export' };
+  }
+
+  try {
+    const options = getCredentialProviderOptions();
+    const credentials =
+      getProfile(profileName)?.profileType === 'role'
+        ? await createRoleChainProvider(profileName, options)()
+        : await fromIni({ profile: profileName, ...options })();
+
+    const variables: [string, string][] = [
+      ['AWS_ACCESS_KEY_ID', credentials.accessKeyId],
+      ['AWS_SECRET_ACCESS_KEY', credentials.secretAccessKey],
+    ];
+    if (credentials.sessionToken) {
+      variables.push(['AWS_SESSION_TOKEN', credentials.sessionToken]);
+    }
+    return { success: true, variables, expiration: credentials.expiration };
+  } catch (error) {
+    return {
+      success: false,
+      error: `Could not resolve credentials for '${profileName}': ${error instanceof Error ? error.message : 'unknown error'}`,
+    };
+  }
+}
+
+/**
+ * Quotes a value for a POSIX shell
+ */
+function shellQuote(value: string): string {
+  return `'${value.replace(/'/g, `'\\''`)}'`;
+}
+
+/**
+ * Formats exported credentials as `export NAME=value` lines, with a comment
+ * saying when temporary credentials expire
+ */
+export function formatEnvExports(variables: [string, string][], expiration?: Date): string {
+  const lines = expiration ? [`# Temporary credentials, expire at ${expiration.toISOString()}`] : [];
+  for (const [name, value] of variables) {
+    lines.push(`export ${name}=${shellQuote(value)}`);
+  }
+  return lines.join('\n') + '\n';
+}
@@ -1,9 +1,39 @@
 import * as zlib from 'zlib';
 import { promisify } from 'util';
+import { compressSnappyFramed, decompressSnappyFramed, isSnappyFramed } from './snappy';
 
 const gunzipAsync = promisify(zlib.gunzip);
 const gzipAsync = promisify(zlib.gzip);
 
+/**
+ * Compression formats that are looked through when viewing and editing files
+ */
+export type CompressionCodec = 'gzip' | 'zstd' | 'snappy';
+
+/**
+ * Largest decompressed size accepted (256MB), to guard against decompression bombs
+ */
+export const MAX_DECOMPRESSED_SIZE = 256 * 1024 * 1024;
+
+const CODEC_EXTENSIONS: Record<string, CompressionCodec> = {
+  gz: 'gzip',
+  zst: 'zstd',
+  snappy: 'snappy',
+};
+
+const CODEC_LABELS: Record<CompressionCodec, string> = {
+  gzip: 'Gzip',
+  zstd: 'Zstd',
+  snappy: 'Snappy',
+};
+
+const GZIP_MAGIC = [0x1f, 0x8b];
+const ZSTD_MAGIC = [0x28, 0xb5, 0x2f, 0xfd];
+
+function startsWith(data: Uint8Array, signature: number[]): boolean {
+  return data.length >= signature.length && signature.every((b, i) => data[i] === b);
+}
+
 /**
  * Check if a file key ends with .gz extension
  */
@@ -12,16 +42,35 @@ export function isGzipFile(key: string): boolean {
 }
 
 /**
- * Get the base extension of a file (before .gz if present)
- * e.g., 'data.json.gz' -> 'json', 'data.csv.gz' -> 'csv', 'data.json' -> 'json'
+ * Get the compression codec implied by a file's extension (.gz, .zst or .snappy)
  */
-export function getBaseExtension(key: string): string {
-  const lowerKey = key.toLowerCase();
+export function getCompressionFromKey(key: string): CompressionCodec | null {
+  const dot = key.lastIndexOf('.');
+  if (dot === -1) {
+    return null;
+  }
+  return CODEC_EXTENSIONS[key.slice(dot + 1).toLowerCase()] ?? null;
+}
+
+/**
+ * Detect the compression codec from the leading magic bytes
+ */
+export function detectCompression(data: Uint8Array): CompressionCodec | null {
+  if (startsWith(data, GZIP_MAGIC)) return 'gzip';
+  if (startsWith(data, ZSTD_MAGIC)) return 'zstd';
+  if (isSnappyFramed(data)) return 'snappy';
+  return null;
+}
 
-  // If it ends with .gz, get the extension before .gz
-  if (lowerKey.endsWith('.gz')) {
-    const withoutGz = key.slice(0, -3);
-    const ext = withoutGz.split('.').pop()?.toLowerCase() ?? '';
+/**
+ * Get the base extension of a file (before .gz, .zst or .snappy if present)
+ * e.g., 'data.json.gz' -> 'json', 'data.csv.zst' -> 'csv', 'data.json' -> 'json'
+ */
+export function getBaseExtension(key: string): string {
+  // If it has a compression extension, get the extension before it
+  if (getCompressionFromKey(key)) {
+    const withoutCodec = key.slice(0, key.lastIndexOf('.'));
+    const ext = withoutCodec.split('.').pop()?.toLowerCase() ?? '';
     return ext;
   }
 
@@ -35,7 +84,7 @@ export function getBaseExtension(key: string): string {
  * @returns The decompressed string content
  */
 export async function decompressGzip(buffer: Buffer): Promise<string> {
-  const decompressed = await gunzipAsync(buffer);
+  const decompressed = await gunzipAsync(buffer, { maxOutputLength: MAX_DECOMPRESSED_SIZE });
   return decompressed.toString('utf-8');
 }
 
@@ -63,3 +112,88 @@ export async function tryDecompressGzip(buffer: Buffer): Promise<string> {
     return buffer.toString('utf-8');
   }
 }
+
+/**
+ * Decompress a buffer with the given codec
+ * @param maxSize - Largest decompressed size accepted
+ * @throws If the data is corrupt or decompresses past maxSize
+ */
+export async function decompressBuffer(
+  buffer: Buffer,
+  codec: CompressionCodec,
+  maxSize: number = MAX_DECOMPRESSED_SIZE
+): Promise<Buffer> {
+  try {
+    switch (codec) {
+      case 'gzip':
+        return await gunzipAsync(buffer, { maxOutputLength: maxSize });
+      case 'zstd':
+        // Promisified on use, since zstd only exists in newer Node runtimes
+        return await promisify(zlib.zstdDecompress)(buffer, { maxOutputLength: maxSize });
+      case 'snappy':
+        return decompressSnappyFramed(buffer, maxSize);
+    }
+  } catch (error) {
+    if (error instanceof RangeError || (error as NodeJS.ErrnoException).code === 'ERR_BUFFER_TOO_LARGE') {
+      throw new Error(`Decompressed content is larger than ${Math.round(maxSize / (1024 * 1024))}MB`);
+    }
+    throw error;
+  }
+}
+
+/**
+ * Compress a buffer with the given codec
+ */
+export async function compressBuffer(buffer: Buffer, codec: CompressionCodec): Promise<Buffer> {
+  switch (codec) {
+    case 'gzip':
+      return await gzipAsync(buffer);
+    case 'zstd':
+      return await promisify(zlib.zstdCompress)(buffer);
+    case 'snappy':
+      return compressSnappyFramed(buffer);
+  }
+}
+
+/**
+ * Decode downloaded file content as text, decompressing it first when the key
+ * has a compression extension. The codec is taken from the magic bytes when
+ * they are recognized (so a mislabelled file still opens), otherwise from the extension.
+ * @returns The text and the codec it was decompressed with, if any
+ */
+export async function decompressContent(
+  key: string,
+  buffer: Buffer,
+  maxSize: number = MAX_DECOMPRESSED_SIZE
+): Promise<{ content: string; compression?: CompressionCodec }> {
+  const byExtension = getCompressionFromKey(key);
+  if (!byExtension) {
+    return { content: buffer.toString('utf-8') };
+  }
+
+  const codec = detectCompression(buffer) ?? byExtension;
+  try {
+    const decompressed = await decompressBuffer(buffer, codec, maxSize);
+    return { content: decompressed.toString('utf-8'), compression: codec };
+  } catch (error) {
+    const message = error instanceof Error ? error.message : 'Failed to decompress file';
+    throw new Error(`${CODEC_LABELS[codec]} decompression failed: ${message}`);
+  }
+}
+
+/**
+ * Encode text for upload, compressing it with the codec implied by the key's extension
+ */
+export async function compressContent(key: string, content: string): Promise<Buffer | string> {
+  const codec = getCompressionFromKey(key);
+  if (!codec) {
+    return content;
+  }
+
+  try {
+    return await compressBuffer(Buffer.from(content, 'utf-8'), codec);
+  } catch (error) {
+    const message = error instanceof Error ? error.message : 'Failed to compress file';
+    throw new Error(`${CODEC_LABELS[codec]} compression failed: ${message}`);
+  }
+}
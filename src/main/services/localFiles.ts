@@ -0,0 +1,171 @@
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { randomUUID } from 'crypto';
+
+/**
+ * What to do when a download's destination file already exists:
+ * - 'overwrite': replace the existing file
+ * - 'skip': leave the existing file and do not download
+ * - 'rename': save as "name (1).ext", "name (2).ext", ...
+ */
+export type ConflictPolicy = 'overwrite' | 'skip' | 'rename';
+
+export const CONFLICT_POLICIES: ConflictPolicy[] = ['overwrite', 'skip', 'rename'];
+
+/**
+ * Checks whether a file exists
+ */
+export async function fileExists(filePath: string): Promise<boolean> {
+  try {
+    await fs.promises.access(filePath);
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+/**
+ * Resolves the path a local write should use according to the conflict policy
+ * @param filePath - The desired destination path
+ * @param policy - How to handle an existing file at that path
+ * @param reserved - Paths already claimed by other writes that haven't created their files yet
+ * @returns The path to write to, or null if the write should be skipped
+ */
+export async function resolveConflict(
+  filePath: string,
+  policy: ConflictPolicy,
+  reserved: ReadonlySet<string> = new Set()
+): Promise<string | null> {
+  const isTaken = async (candidate: string) => reserved.has(candidate) || (await fileExists(candidate));
+
+  if (!(await isTaken(filePath))) {
+    return filePath;
+  }
+
+  // A path another download in the same batch is writing to is never shared
+  switch (reserved.has(filePath) ? 'rename' : policy) {
+    case 'overwrite':
+      return filePath;
+    case 'skip':
+      return null;
+    case 'rename': {
+      const dir = path.dirname(filePath);
+      const ext = path.extname(filePath);
+      const baseName = path.basename(filePath, ext);
+      let counter = 1;
+      let candidate = path.join(dir, `${baseName} (${counter})${ext}`);
+      while (await isTaken(candidate)) {
+        counter++;
+        candidate = path.join(dir, `${baseName} (${counter})${ext}`);
+      }
+      return candidate;
+    }
+  }
+}
+
+/** Folder under the OS temp directory holding files opened with their default app */
+export const EXTERNAL_OPEN_DIR_NAME = 's3-browser-open';
+
+/**
+ * Gets the folder for files opened with their default app
+ */
+export function getExternalOpenDir(tempDir: string = os.tmpdir()): string {
+  return path.join(tempDir, EXTERNAL_OPEN_DIR_NAME);
+}
+
+/**
+ * Extensions the OS runs as programs or scripts when "opened", rather than
+ * showing them in a viewer. Objects with these are never opened externally,
+ * since anyone who can write to the bucket controls their content.
+ */
+const EXECUTABLE_EXTENSIONS = new Set([
+  // Windows
+  'exe', 'com', 'scr', 'pif', 'msi', 'msp', 'bat', 'cmd', 'ps1', 'psm1', 'vbs', 'vbe', 'js', 'jse',
+  'wsf', 'wsh', 'hta', 'cpl', 'lnk', 'url', 'reg', 'jar', 'appref-ms', 'msc', 'scf', 'inf',
+  // macOS
+  'app', 'command', 'tool', 'pkg', 'dmg', 'workflow', 'terminal', 'scpt',
+  // Linux and Unix
+  'sh', 'bash', 'zsh', 'csh', 'ksh', 'run', 'bin', 'appimage', 'desktop', 'deb', 'rpm',
+  // Interpreted scripts
+  'py', 'pyw', 'pl', 'rb', 'php',
+]);
+
+/**
+ * Checks whether a file would be run rather than viewed when opened with its
+ * default app
+ * @returns The lowercase extension that makes it executable, or null if it is safe to open
+ */
+export function getExecutableExtension(fileName: string): string | null {
+  const dot = fileName.lastIndexOf('.');
+  if (dot < 0) {
+    return null;
+  }
+  // Windows ignores trailing dots and spaces, so "run.exe." still runs as an .exe
+  const ext = fileName.slice(dot + 1).replace(/[. ]+$/, '').toLowerCase();
+  if (EXECUTABLE_EXTENSIONS.has(ext)) {
+    return ext;
+  }
+  const trimmed = fileName.replace(/[. ]+$/, '');
+  if (trimmed !== fileName) {
+    return getExecutableExtension(trimmed);
+  }
+  return null;
+}
+
+/**
+ * Builds the temp path a file is downloaded to before opening it with its default app.
+ * Each download gets its own subfolder so the original file name (and so the extension
+ * the OS uses to pick a handler) is kept without clashing with earlier downloads.
+ * @param fileName - The object's file name, e.g. "report.xlsx"
+ * @param baseDir - The external open folder
+ * @param uniqueId - Subfolder name; random by default
+ */
+export function buildExternalOpenPath(
+  fileName: string,
+  baseDir: string = getExternalOpenDir(),
+  uniqueId: string = randomUUID()
+): string {
+  // Keys may contain characters that are not allowed in local file names on Windows
+  const safeName = fileName.replace(/[<>:"/\\|?*\x00-\x1f]/g, '_') || 'download';
+  return path.join(baseDir, uniqueId, safeName);
+}
+
+/**
+ * Removes all files opened with their default app. Runs on quit, so it is synchronous
+ * and never throws (a file still locked by another app is left behind).
+ */
+export function cleanupExternalOpenDir(baseDir: string = getExternalOpenDir()): void {
+  try {
+    fs.rmSync(baseDir, { recursive: true, force: true });
+  } catch (error) {
+    console.warn('Failed to clean up temporary files:', error);
+  }
+}
+
+/**
+ * How to show a local file in the platform file manager:
+ * - 'select': open its folder with the file selected
+ * - 'open': open the folder only, because the file itself is gone
+ */
+export interface RevealAction {
+  action: 'select' | 'open';
+  path: string;
+}
+
+/**
+ * Works out how to reveal a file (e.g. a finished download) in the file manager.
+ * Falls back to the containing folder when the file has been moved or deleted.
+ * @param filePath - The local file
+ * @returns What to reveal, or null if neither the file nor its folder exists
+ */
+export async function getRevealAction(filePath: string): Promise<RevealAction | null> {
+  if (await fileExists(filePath)) {
+    return { action: 'select', path: filePath };
+  }
+  const dir = path.dirname(filePath);
+  if (await fileExists(dir)) {
+    return { action: 'open', path: dir };
+  }
+  return null;
+}
@@ -0,0 +1,78 @@
+/**
+ * Asking the user for an MFA code when the SDK assumes a role whose profile sets
+ * mfa_serial. The SDK calls the code provider with the device serial; the request
+ * goes to the renderer, which shows a dialog and answers with the code (or cancels).
+ */
+
+/** How long to wait for the user to enter a code */
+export const MFA_CODE_TIMEOUT_MS = 5 * 60 * 1000;
+
+export interface MfaCodeRequest {
+  requestId: number;
+  mfaSerial: string;
+}
+
+interface PendingRequest {
+  resolve: (code: string) => void;
+  reject: (error: Error) => void;
+  timer: ReturnType<typeof setTimeout>;
+}
+
+/**
+ * Tracks MFA code requests waiting on the user. Requests nobody answers within
+ * the timeout are rejected, so credential resolution doesn't hang forever.
+ * @param send - Delivers a request to the UI; returns false when there is no window to ask in
+ * @param timeoutMs - How long to wait for an answer
+ */
+export function createMfaCodeRequests(
+  send: (request: MfaCodeRequest) => boolean,
+  timeoutMs: number = MFA_CODE_TIMEOUT_MS
+): {
+  request: (mfaSerial: string) => Promise<string>;
+  submit: (requestId: number, code: string | null) => boolean;
+  /** Rejects every request still waiting, e.g. when the window asking for them goes away */
+  cancelAll: (reason: string) => void;
+} {
+  const pending = new Map<number, PendingRequest>();
+  let nextRequestId = 1;
+
+  return {
+    request: (mfaSerial: string) =>
+      new Promise<string>((resolve, reject) => {
+        const requestId = nextRequestId++;
+        const timer = setTimeout(() => {
+          pending.delete(requestId);
+          reject(new Error(`Timed out waiting for an MFA code for ${mfaSerial}`));
+        }, timeoutMs);
+        pending.set(requestId, { resolve, reject, timer });
+        if (!send({ requestId, mfaSerial })) {
+          clearTimeout(timer);
+          pending.delete(requestId);
+          reject(new Error(`An MFA code for ${mfaSerial} is required, but there is no window to ask in`));
+        }
+      }),
+
+    submit: (requestId: number, code: string | null) => {
+      const request = pending.get(requestId);
+      if (!request) {
+        return false;
+      }
+      clearTimeout(request.timer);
+      pending.delete(requestId);
+      if (code === null || code.trim() === '') {
+        request.reject(new Error('MFA code entry cancelled'));
+      } else {
+        request.resolve(code.trim());
+      }
+      return true;
+    },
+
+    cancelAll: (reason: string) => {
+      for (const request of pending.values()) {
+        clearTimeout(request.timer);
+        request.reject(new Error(reason));
+      }
+      pending.clear();
+    },
+  };
+}
@@ -0,0 +1,102 @@
+/**
+ * Routing S3 traffic through an HTTP(S) proxy, for networks without direct internet access
+ */
+
+import net from 'net';
+import tls from 'tls';
+import type http from 'http';
+import { HttpsProxyAgent } from 'https-proxy-agent';
+
+/**
+ * Checks a proxy URL can be used: http or https, with a host
+ * @returns The normalized URL, or null when it isn't a usable proxy URL
+ */
+export function parseProxyUrl(value: unknown): string | null {
+  if (typeof value !== 'string' || value.trim() === '') {
+    return null;
+  }
+  try {
+    const url = new URL(value.trim());
+    if ((url.protocol !== 'http:' && url.protocol !== 'https:') || !url.hostname) {
+      return null;
+    }
+    return url.href;
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * The proxy to use: the one set in the app, otherwise the standard environment
+ * variables (HTTPS_PROXY, then HTTP_PROXY, in either case)
+ */
+export function resolveProxyUrl(configured: string | null, env: NodeJS.ProcessEnv = process.env): string | null {
+  if (configured) {
+    return parseProxyUrl(configured);
+  }
+  const fromEnv = env.HTTPS_PROXY || env.https_proxy || env.HTTP_PROXY || env.http_proxy;
+  return parseProxyUrl(fromEnv);
+}
+
+/**
+ * Whether a host should be reached directly rather than through the proxy,
+ * following the usual NO_PROXY conventions: comma or space separated entries,
+ * "*" for every host, a domain matching itself and its subdomains (with or
+ * without a leading dot), and an optional :port restricting the entry to one port.
+ * @param hostname - The host being connected to
+ * @param port - The port being connected to
+ * @param noProxy - The NO_PROXY value
+ */
+export function bypassesProxy(hostname: string, port: number, noProxy: string | undefined): boolean {
+  const host = hostname.toLowerCase().replace(/\.$/, '');
+
+  for (const rawEntry of (noProxy ?? '').split(/[,\s]+/)) {
+    const entry = rawEntry.trim().toLowerCase();
+    if (!entry) continue;
+    if (entry === '*') return true;
+
+    const portMatch = entry.match(/^(.*):(\d+)$/);
+    const entryHost = (portMatch ? portMatch[1] : entry).replace(/^\*?\./, '');
+    if (portMatch && Number(portMatch[2]) !== port) continue;
+
+    if (host === entryHost || host.endsWith(`.${entryHost}`)) {
+      return true;
+    }
+  }
+  return false;
+}
+
+type ConnectOptions = Parameters<HttpsProxyAgent<string>['connect']>[1];
+
+/**
+ * Proxy agent that connects directly to hosts listed in NO_PROXY. S3 requests
+ * go to per-bucket hostnames, so the check is made for every connection.
+ */
+export class ProxyAgent extends HttpsProxyAgent<string> {
+  constructor(
+    proxyUrl: string,
+    private readonly noProxy: string | undefined
+  ) {
+    super(proxyUrl);
+  }
+
+  async connect(req: http.ClientRequest, opts: ConnectOptions): Promise<net.Socket> {
+    if (opts.host && bypassesProxy(opts.host, opts.port, this.noProxy)) {
+      if (opts.secureEndpoint) {
+        const secure = opts as tls.ConnectionOptions;
+        return tls.connect({ ...secure, servername: secure.servername ?? opts.host });
+      }
+      return net.connect(opts as net.TcpNetConnectOpts);
+    }
+    return super.connect(req, opts);
+  }
+}
+
+/**
+ * Builds the agent that sends S3 connections through the proxy
+ * @param proxyUrl - The proxy to use
+ * @param env - Where NO_PROXY is read from
+ */
+export function createProxyAgent(proxyUrl: string, env: NodeJS.ProcessEnv = process.env): ProxyAgent {
+  return new ProxyAgent(proxyUrl, env.NO_PROXY ?? env.no_proxy);
+}
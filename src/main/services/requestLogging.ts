@@ -0,0 +1,109 @@
+/**
+ * Wire-level logging of S3 requests and responses, for diagnosing S3-compatible endpoints
+ */
+
+/** Headers whose values are credentials and must never be logged */
+const SECRET_HEADERS = new Set([
+  'authorization',
+  'x-amz-security-token',
+  'x-amz-server-side-encryption-customer-key',
+  'x-amz-copy-source-server-side-encryption-customer-key',
+]);
+
+/** Query parameters of presigned requests that carry credentials or signatures */
+const SECRET_QUERY_PARAMS = new Set(['x-amz-signature', 'x-amz-credential', 'x-amz-security-token']);
+
+export const REDACTED = '[REDACTED]';
+
+/**
+ * The parts of an HTTP request the logger reads (matches the SDK's HttpRequest)
+ */
+export interface LoggedRequest {
+  method: string;
+  protocol?: string;
+  hostname: string;
+  port?: number;
+  path: string;
+  query?: Record<string, string | string[] | null>;
+  headers: Record<string, string>;
+}
+
+/**
+ * The parts of an HTTP response the logger reads (matches the SDK's HttpResponse)
+ */
+export interface LoggedResponse {
+  statusCode: number;
+  headers: Record<string, string>;
+}
+
+/**
+ * Copies headers with credential values replaced. The signing algorithm of an
+ * Authorization header is kept, since it helps tell SigV2 and SigV4 endpoints apart.
+ */
+export function redactHeaders(headers: Record<string, string>): Record<string, string> {
+  const redacted: Record<string, string> = {};
+  for (const [name, value] of Object.entries(headers)) {
+    if (!SECRET_HEADERS.has(name.toLowerCase())) {
+      redacted[name] = value;
+    } else if (name.toLowerCase() === 'authorization' && /^\S+\s/.test(value)) {
+      redacted[name] = `${value.split(/\s/)[0]} ${REDACTED}`;
+    } else {
+      redacted[name] = REDACTED;
+    }
+  }
+  return redacted;
+}
+
+/**
+ * Formats a request as one line plus its (redacted) headers
+ */
+export function describeRequest(request: LoggedRequest): string {
+  const query = Object.entries(request.query ?? {})
+    .flatMap(([name, value]) => {
+      const values = value === null ? [null] : Array.isArray(value) ? value : [value];
+      return values.map(v => {
+        const shown = SECRET_QUERY_PARAMS.has(name.toLowerCase()) ? REDACTED : v;
+        return shown === null ? name : `${name}=${shown}`;
+      });
+    })
+    .join('&');
+  const port = request.port ? `:${request.port}` : '';
+  const url = `${request.protocol ?? 'https:'}//${request.hostname}${port}${request.path}${query ? `?${query}` : ''}`;
+  return `${request.method} ${url} ${JSON.stringify(redactHeaders(request.headers))}`;
+}
+
+/**
+ * Formats a response status line plus its headers
+ */
+export function describeResponse(response: LoggedResponse, elapsedMs: number): string {
+  return `${response.statusCode} (${elapsedMs} ms) ${JSON.stringify(redactHeaders(response.headers))}`;
+}
+
+/**
+ * Builds SDK middleware that logs each signed request and its raw response.
+ * Added at the deserialize step, so it sees exactly what goes over the wire,
+ * once per attempt when requests are retried.
+ * @param log - Where log lines go
+ */
+export function createRequestLoggingMiddleware(log: (line: string) => void = line => console.debug(line)) {
+  return <A extends { request: unknown }, R extends { response: unknown }>(next: (args: A) => Promise<R>) =>
+    async (args: A): Promise<R> => {
+      const request = args.request as LoggedRequest;
+      log(`[S3 request] ${describeRequest(request)}`);
+      const start = Date.now();
+      try {
+        const result = await next(args);
+        log(`[S3 response] ${describeResponse(result.response as LoggedResponse, Date.now() - start)}`);
+        return result;
+      } catch (error) {
+        log(`[S3 error] ${request.method} ${request.path}: ${error instanceof Error ? error.message : String(error)}`);
+        throw error;
+      }
+    };
+}
+
+export const REQUEST_LOGGING_MIDDLEWARE_OPTIONS = {
+  step: 'deserialize',
+  priority: 'low',
+  name: 'requestLoggingMiddleware',
+} as const;
@@ -0,0 +1,179 @@
+/**
+ * Assuming the roles of a chain of source profiles ourselves, rather than leaving
+ * it to the SDK, so session names and durations are under the app's control and a
+ * failure says which role in the chain couldn't be assumed
+ */
+
+import { STSClient, AssumeRoleCommand, type STSClientConfig } from '@aws-sdk/client-sts';
+import {
+  fromIni,
+  fromEnv,
+  fromInstanceMetadata,
+  fromContainerMetadata,
+} from '@aws-sdk/credential-providers';
+import type { AwsCredentialIdentity, AwsCredentialIdentityProvider } from '@aws-sdk/types';
+import { loadAwsProfiles, resolveRoleChain, type RoleChain, type RoleHop } from './awsCredentials';
+
+/** Region used for STS when a profile doesn't set one */
+const STS_DEFAULT_REGION = 'us-east-1';
+
+/** Credentials are renewed this long before they expire */
+const REFRESH_MARGIN_MS = 5 * 60 * 1000;
+
+/**
+ * A role in the chain couldn't be assumed
+ */
+export class RoleChainError extends Error {
+  constructor(
+    message: string,
+    /** The profile whose role failed, or whose credentials couldn't be obtained */
+    readonly profileName: string,
+    /** The role that couldn't be assumed; undefined when the source credentials failed */
+    readonly roleArn?: string
+  ) {
+    super(message);
+    this.name = 'RoleChainError';
+  }
+}
+
+export interface RoleChainOptions {
+  /** Asks for the code of a role's MFA device */
+  mfaCodeProvider?: (mfaSerial: string) => Promise<string>;
+  /** Config for the STS clients, e.g. a request handler that goes through a proxy */
+  clientConfig?: { requestHandler?: STSClientConfig['requestHandler'] };
+}
+
+function describeError(error: unknown): string {
+  return error instanceof Error ? error.message : String(error);
+}
+
+function sourceProvider(source: RoleChain['source'], options: RoleChainOptions) {
+  if ('profileName' in source) {
+    return fromIni({ profile: source.profileName, ...options });
+  }
+  switch (source.credentialSource) {
+    case 'Environment':
+      return fromEnv();
+    case 'Ec2InstanceMetadata':
+      return fromInstanceMetadata();
+    case 'EcsContainer':
+      return fromContainerMetadata();
+    default:
+      throw new Error(`Unsupported credential_source: ${source.credentialSource}`);
+  }
+}
+
+/**
+ * Session name for a hop: the profile's role_session_name, or one that shows
+ * in CloudTrail that the app made the call
+ */
+function sessionName(hop: RoleHop): string {
+  return hop.roleSessionName ?? `s3-browser-${Date.now()}`;
+}
+
+/**
+ * Gets the chain's source credentials and assumes each role in turn
+ * @param chain - The chain, from resolveRoleChain
+ * @throws RoleChainError naming the hop that failed
+ */
+export async function assumeRoleChain(
+  chain: RoleChain,
+  options: RoleChainOptions = {}
+): Promise<AwsCredentialIdentity> {
+  const { mfaCodeProvider, clientConfig } = options;
+
+  const sourceName = 'profileName' in chain.source ? chain.source.profileName : chain.hops[0].profileName;
+  let credentials: AwsCredentialIdentity;
+  try {
+    credentials = await sourceProvider(chain.source, options)();
+  } catch (error) {
+    const from = 'profileName' in chain.source
+      ? `source profile '${chain.source.profileName}'`
+      : `credential_source ${chain.source.credentialSource}`;
+    throw new RoleChainError(`Could not get credentials from ${from}: ${describeError(error)}`, sourceName);
+  }
+
+  for (const [index, hop] of chain.hops.entries()) {
+    try {
+      let tokenCode: string | undefined;
+      if (hop.mfaSerial) {
+        if (!mfaCodeProvider) {
+          throw new Error(`an MFA code for ${hop.mfaSerial} is required`);
+        }
+        tokenCode = await mfaCodeProvider(hop.mfaSerial);
+      }
+
+      const client = new STSClient({ ...clientConfig, region: hop.region ?? STS_DEFAULT_REGION, credentials });
+      let response;
+      try {
+        response = await client.send(
+          new AssumeRoleCommand({
+            RoleArn: hop.roleArn,
+            RoleSessionName: sessionName(hop),
+            ExternalId: hop.externalId,
+            DurationSeconds: hop.durationSeconds,
+            SerialNumber: hop.mfaSerial,
+            TokenCode: tokenCode,
+          })
+        );
+      } finally {
+        client.destroy();
+      }
+      const { Credentials } = response;
+      if (!Credentials?.AccessKeyId || !Credentials.SecretAccessKey) {
+        throw new Error('STS returned no credentials');
+      }
+
+      credentials = {
+        accessKeyId: Credentials.AccessKeyId,
+        secretAccessKey: Credentials.SecretAccessKey,
+        sessionToken: Credentials.SessionToken,
+        expiration: Credentials.Expiration,
+      };
+    } catch (error) {
+      throw new RoleChainError(
+        `Could not assume ${hop.roleArn} for profile '${hop.profileName}' ` +
+          `(role ${index + 1} of ${chain.hops.length}): ${describeError(error)}`,
+        hop.profileName,
+        hop.roleArn
+      );
+    }
+  }
+
+  return credentials;
+}
+
+/**
+ * Credential provider for a role profile that assumes its chain of roles, and
+ * again shortly before the credentials expire
+ * @param profileName - The role profile
+ */
+export function createRoleChainProvider(
+  profileName: string,
+  options: RoleChainOptions = {}
+): AwsCredentialIdentityProvider {
+  let cached: AwsCredentialIdentity | null = null;
+  let pending: Promise<AwsCredentialIdentity> | null = null;
+
+  return async () => {
+    const expiresAt = cached?.expiration?.getTime();
+    if (cached && (expiresAt === undefined || expiresAt - Date.now() > REFRESH_MARGIN_MS)) {
+      return cached;
+    }
+
+    // Requests made while the chain is being assumed share the result (and the MFA prompt)
+    pending ??= (async () => {
+      try {
+        const { chain, error } = resolveRoleChain(profileName, loadAwsProfiles().profiles);
+        if (!chain) {
+          throw new RoleChainError(error ?? `Profile '${profileName}' not found`, profileName);
+        }
+        cached = await assumeRoleChain(chain, options);
+        return cached;
+      } finally {
+        pending = null;
+      }
+    })();
+    return pending;
+  };
+}
@@ -6,20 +6,44 @@ import {
   GetObjectCommand,
   PutObjectCommand,
   DeleteObjectCommand,
+  DeleteObjectsCommand,
   CopyObjectCommand,
   HeadObjectCommand,
   GetObjectTaggingCommand,
+  PutObjectTaggingCommand,
+  CreateBucketCommand,
+  HeadBucketCommand,
+  GetBucketLocationCommand,
+  GetPublicAccessBlockCommand,
+  GetBucketPolicyStatusCommand,
+  GetBucketAclCommand,
+  RestoreObjectCommand,
+  SelectObjectContentCommand,
+  ListMultipartUploadsCommand,
+  AbortMultipartUploadCommand,
+  type InputSerialization,
+  type BucketLocationConstraint,
+  type StorageClass,
+  type CreateBucketConfiguration,
   type S3ClientConfig,
   type Bucket,
   type _Object,
   type CommonPrefix,
 } from '@aws-sdk/client-s3';
 import { fromIni } from '@aws-sdk/credential-providers';
+import { createRoleChainProvider } from './roleChain';
+import { getSignedUrl } from '@aws-sdk/s3-request-presigner';
 import * as fs from 'fs';
 import * as path from 'path';
-import { Readable } from 'stream';
+import { Readable, Transform } from 'stream';
 import { pipeline } from 'stream/promises';
 import { getProfile, type AwsProfile } from './awsCredentials';
+import { computeChecksum, getEtagPartCount, md5MatchesEtag, type ChecksumAlgorithmName } from './checksum';
+import { createRequestLoggingMiddleware, REQUEST_LOGGING_MIDDLEWARE_OPTIONS } from './requestLogging';
+import { createProxyAgent, resolveProxyUrl } from './proxy';
+import { createContentMd5Middleware, CONTENT_MD5_MIDDLEWARE_OPTIONS } from './contentMd5';
+import { isUploadStorageClass, type UploadStorageClass } from './storageClasses';
+import { guessMimeType } from '../../shared/fileTypes';
 
 // Default page size for object listing
 const DEFAULT_PAGE_SIZE = 100;
@@ -28,6 +52,8 @@ const MAX_PAGE_SIZE = 1000;
 export interface S3Bucket {
   name: string;
   creationDate?: Date;
+  // Region the bucket lives in, when known
+  region?: string;
 }
 
 export interface S3Object {
@@ -36,6 +62,8 @@ export interface S3Object {
   lastModified?: Date;
   etag?: string;
   storageClass?: string;
+  // Listings do not return it, so it is filled in from HeadObject when the file is opened
+  contentType?: string;
   // Indicates if this is a "folder" (common prefix)
   isPrefix: boolean;
 }
@@ -57,6 +85,8 @@ export interface ListObjectsOptions {
   bucket: string;
   prefix?: string;
   delimiter?: string;
+  // List every key under the prefix as a flat list, without grouping into folders
+  recursive?: boolean;
   maxKeys?: number;
   continuationToken?: string;
 }
@@ -66,6 +96,16 @@ let s3Client: S3Client | null = null;
 let currentClientProfile: string | null = null;
 let currentEndpoint: string | null = null;
 
+// Region of each bucket, keyed by profile and bucket name
+const bucketRegions = new Map<string, string>();
+// Clients for buckets outside the profile's region, keyed by profile and region
+const regionalClients = new Map<string, S3Client>();
+// Clients for presigning uploads, keyed by profile (see presignPutUrl)
+const presignPutClients = new Map<string, S3Client>();
+
+// Region used when neither the profile nor the environment sets one
+const PROFILE_DEFAULT_REGION = 'eu-west-1';
+
 // Custom endpoint for testing with LocalStack or other S3-compatible services
 let customEndpoint: string | null = process.env.AWS_ENDPOINT_URL || null;
 
@@ -86,30 +126,267 @@ export function getCustomEndpoint(): string | null {
   return customEndpoint;
 }
 
+// Log raw requests and responses (with credentials redacted); off by default
+let requestLoggingEnabled = false;
+
 /**
- * Creates or returns cached S3 client for the given profile
+ * Turn wire-level request logging on or off
+ * Clients are rebuilt on next use so the change applies immediately
+ */
+export function setRequestLogging(enabled: boolean): void {
+  if (enabled === requestLoggingEnabled) return;
+  requestLoggingEnabled = enabled;
+  clearS3Client();
+}
+
+/**
+ * Whether wire-level request logging is on
+ */
+export function isRequestLoggingEnabled(): boolean {
+  return requestLoggingEnabled;
+}
+
+// Send x-amz-request-payer with reads, for Requester Pays buckets; off by default
+let requesterPaysEnabled = false;
+
+/**
+ * Turn Requester Pays on or off. When on, list, get and head requests accept
+ * the charges for the data they read, which Requester Pays buckets (such as
+ * many AWS Open Data datasets) require; without it they answer 403.
+ * Clients are rebuilt on next use so the change applies immediately
+ */
+export function setRequesterPays(enabled: boolean): void {
+  if (enabled === requesterPaysEnabled) return;
+  requesterPaysEnabled = enabled;
+  clearS3Client();
+}
+
+/**
+ * Whether requests are sent as the payer for Requester Pays buckets
+ */
+export function isRequesterPaysEnabled(): boolean {
+  return requesterPaysEnabled;
+}
+
+// Retries after a failed attempt; null keeps the SDK's standard policy (2 retries)
+let maxRetries: number | null = null;
+
+/**
+ * Set how many times failed requests are retried, or null for the SDK default
+ * Clients are rebuilt on next use so the change applies immediately
+ */
+export function setMaxRetries(retries: number | null): void {
+  const value = retries !== null && Number.isInteger(retries) && retries >= 0 ? retries : null;
+  if (value === maxRetries) return;
+  maxRetries = value;
+  clearS3Client();
+}
+
+/**
+ * How many times failed requests are retried, or null for the SDK default
+ */
+export function getMaxRetries(): number | null {
+  return maxRetries;
+}
+
+/**
+ * Time limits for S3 requests, in milliseconds; null keeps the SDK default (no limit)
+ */
+export interface RequestTimeouts {
+  // Time to wait for a connection to be established
+  connectTimeoutMs: number | null;
+  // Time a request may go without receiving any data
+  requestTimeoutMs: number | null;
+}
+
+let requestTimeouts: RequestTimeouts = { connectTimeoutMs: null, requestTimeoutMs: null };
+
+function validTimeout(ms: number | null): number | null {
+  return ms !== null && Number.isFinite(ms) && ms > 0 ? Math.round(ms) : null;
+}
+
+/**
+ * Set the connect and request timeouts
+ * Clients are rebuilt on next use so the change applies immediately
+ */
+export function setRequestTimeouts(timeouts: RequestTimeouts): void {
+  const next = {
+    connectTimeoutMs: validTimeout(timeouts.connectTimeoutMs),
+    requestTimeoutMs: validTimeout(timeouts.requestTimeoutMs),
+  };
+  if (
+    next.connectTimeoutMs === requestTimeouts.connectTimeoutMs &&
+    next.requestTimeoutMs === requestTimeouts.requestTimeoutMs
+  ) {
+    return;
+  }
+  requestTimeouts = next;
+  clearS3Client();
+}
+
+/**
+ * The connect and request timeouts in use
+ */
+export function getRequestTimeouts(): RequestTimeouts {
+  return { ...requestTimeouts };
+}
+
+// Proxy set in the app; null falls back to the HTTPS_PROXY / HTTP_PROXY environment variables
+let proxyUrl: string | null = null;
+
+/**
+ * Set the HTTP(S) proxy S3 requests go through, or null to use the proxy
+ * environment variables (and no proxy when they aren't set). Hosts listed in
+ * NO_PROXY are always reached directly.
+ * Clients are rebuilt on next use so the change applies immediately
+ */
+export function setProxyUrl(url: string | null): void {
+  if (url === proxyUrl) return;
+  proxyUrl = url;
+  clearS3Client();
+}
+
+/**
+ * The proxy set in the app, or null when the environment decides
+ */
+export function getProxyUrl(): string | null {
+  return proxyUrl;
+}
+
+/**
+ * Supplies the current code of an MFA device, given its serial number or ARN
+ */
+export type MfaCodeProvider = (mfaSerial: string) => Promise<string>;
+
+// Asks the user for MFA codes when assuming a role from a profile with mfa_serial
+let mfaCodeProvider: MfaCodeProvider | null = null;
+
+/**
+ * Set how MFA codes are obtained for role profiles that set mfa_serial. Without
+ * one, the SDK can't assume those roles and their requests fail.
+ * Clients are rebuilt on next use so the change applies immediately
+ */
+export function setMfaCodeProvider(provider: MfaCodeProvider | null): void {
+  mfaCodeProvider = provider;
+  clearS3Client();
+}
+
+export type ConnectionRequestHandler = NonNullable<S3ClientConfig['requestHandler']>;
+
+/**
+ * The request handler for the user's timeouts and proxy, or undefined when
+ * the SDK defaults apply
+ */
+export function getConnectionRequestHandler(): ConnectionRequestHandler | undefined {
+  const { connectTimeoutMs, requestTimeoutMs } = requestTimeouts;
+  const proxy = resolveProxyUrl(proxyUrl);
+  if (connectTimeoutMs === null && requestTimeoutMs === null && proxy === null) {
+    return undefined;
+  }
+  // One agent serves both schemes, since plain-HTTP endpoints are tunnelled the same way
+  const agent = proxy !== null ? createProxyAgent(proxy) : null;
+  return {
+    ...(connectTimeoutMs !== null ? { connectionTimeout: connectTimeoutMs } : {}),
+    ...(requestTimeoutMs !== null ? { requestTimeout: requestTimeoutMs } : {}),
+    ...(agent !== null ? { httpAgent: agent, httpsAgent: agent } : {}),
+  };
+}
+
+export interface CredentialProviderOptions {
+  mfaCodeProvider?: MfaCodeProvider;
+  /** Config for the STS and SSO clients the providers create */
+  clientConfig?: { requestHandler: ConnectionRequestHandler };
+}
+
+/**
+ * Options for fromIni and the role chain provider: the MFA prompt, and the
+ * connection settings so STS and SSO calls go through the same proxy as S3
+ * requests (role and SSO profiles can't get credentials otherwise)
+ */
+export function getCredentialProviderOptions(): CredentialProviderOptions {
+  const requestHandler = getConnectionRequestHandler();
+  return {
+    ...(mfaCodeProvider ? { mfaCodeProvider } : {}),
+    ...(requestHandler ? { clientConfig: { requestHandler } } : {}),
+  };
+}
+
+/**
+ * Adds the user's retry policy, timeouts and proxy to a client config
+ */
+function withConnectionOptions(config: S3ClientConfig): S3ClientConfig {
+  const result: S3ClientConfig = { ...config };
+  if (maxRetries !== null) {
+    // The SDK counts the first attempt, so N retries means N + 1 attempts
+    result.maxAttempts = maxRetries + 1;
+  }
+  const requestHandler = getConnectionRequestHandler();
+  if (requestHandler) {
+    result.requestHandler = requestHandler;
+  }
+  return result;
+}
+
+function withRequestLogging(client: S3Client): S3Client {
+  if (requestLoggingEnabled) {
+    client.middlewareStack.add(createRequestLoggingMiddleware(), REQUEST_LOGGING_MIDDLEWARE_OPTIONS);
+  }
+  return client;
+}
+
+// Reads that Requester Pays buckets refuse unless the requester accepts the charges
+const REQUESTER_PAYS_COMMANDS = new Set(['ListObjectsV2Command', 'GetObjectCommand', 'HeadObjectCommand']);
+
+function withRequesterPays(client: S3Client): S3Client {
+  if (requesterPaysEnabled) {
+    client.middlewareStack.add(
+      <A extends { input: object }, R>(next: (args: A) => Promise<R>, context: { commandName?: string }) =>
+        async (args: A): Promise<R> => {
+          if (context.commandName && REQUESTER_PAYS_COMMANDS.has(context.commandName)) {
+            return next({ ...args, input: { ...args.input, RequestPayer: 'requester' } });
+          }
+          return next(args);
+        },
+      { step: 'initialize', name: 'requesterPaysMiddleware' }
+    );
+  }
+  return client;
+}
+
+/**
+ * The region a profile's requests are signed for: AWS_REGION or AWS_DEFAULT_REGION
+ * when set, else the profile's region setting, as the CLI resolves it
+ * @param profile - The profile; undefined when it isn't known
+ */
+export function resolveProfileRegion(profile?: AwsProfile): string {
+  return process.env.AWS_REGION || process.env.AWS_DEFAULT_REGION || profile?.region || PROFILE_DEFAULT_REGION;
+}
+
+/**
+ * Builds a new, uncached S3 client for the given profile
  * Uses the AWS SDK's credential provider chain which supports:
  * - Static credentials (access_key_id + secret_access_key)
- * - Role assumption (role_arn with source_profile or credential_source)
+ * - Role assumption (role_arn with source_profile or credential_source), assumed
+ *   by the app one role at a time so a failure names the role in the chain
  * - SSO (sso_start_url, sso_account_id, sso_role_name)
  * - Process credentials (credential_process)
  * - Web identity (web_identity_token_file)
  * - EC2/ECS instance roles (credential_source)
  *
  * @param profileName - The AWS profile name to use
- * @param forceNew - Force creation of a new client even if one exists
+ * @param region - Region to use instead of the profile's (e.g. to create a bucket there)
+ * @param overrides - Client settings to use instead of the defaults
  */
-export function getS3Client(profileName: string, forceNew = false): S3Client {
-  // Return cached client if profile and endpoint haven't changed
-  if (s3Client && currentClientProfile === profileName && currentEndpoint === customEndpoint && !forceNew) {
-    return s3Client;
-  }
-
+export function createS3Client(
+  profileName: string,
+  region?: string,
+  overrides: Partial<S3ClientConfig> = {}
+): S3Client {
   // When using custom endpoint (e.g., LocalStack), use simple credentials
   if (customEndpoint) {
     const config: S3ClientConfig = {
       endpoint: customEndpoint,
-      region: process.env.AWS_DEFAULT_REGION || 'us-east-1',
+      region: region || process.env.AWS_DEFAULT_REGION || 'us-east-1',
       credentials: {
         accessKeyId: process.env.AWS_ACCESS_KEY_ID || 'test',
         secretAccessKey: process.env.AWS_SECRET_ACCESS_KEY || 'test',
@@ -117,11 +394,7 @@ export function getS3Client(profileName: string, forceNew = false): S3Client {
       forcePathStyle: true, // Required for LocalStack and most S3-compatible services
     };
 
-    s3Client = new S3Client(config);
-    currentClientProfile = profileName;
-    currentEndpoint = customEndpoint;
-
-    return s3Client;
+    return withRequesterPays(withRequestLogging(new S3Client(withConnectionOptions({ ...config, ...overrides }))));
   }
 
   const profile = getProfile(profileName);
@@ -133,20 +406,51 @@ export function getS3Client(profileName: string, forceNew = false): S3Client {
     throw new Error(`Profile '${profileName}' has no valid credentials`);
   }
 
+  if (profile.profileType === 'anonymous') {
+    const config: S3ClientConfig = {
+      region: region || resolveProfileRegion(profile),
+      // Requests go out unsigned, the equivalent of the CLI's --no-sign-request;
+      // the placeholder credentials only keep the SDK from searching for real ones
+      credentials: { accessKeyId: '', secretAccessKey: '' },
+      signer: { sign: async request => request },
+      followRegionRedirects: true,
+    };
+
+    return withRequesterPays(withRequestLogging(new S3Client(withConnectionOptions({ ...config, ...overrides }))));
+  }
+
   const config: S3ClientConfig = {
-    region: profile.region || 'eu-west-1',
-    // Use the fromIni credential provider which handles all profile types
-    // including static credentials, role assumption, SSO, process credentials, etc.
-    credentials: fromIni({ profile: profileName }),
+    region: region || resolveProfileRegion(profile),
+    // Use the fromIni credential provider which handles the other profile types:
+    // static credentials, SSO, process credentials, web identity, etc.
+    // The MFA code provider is called when assuming a role from a profile with mfa_serial
+    credentials:
+      profile.profileType === 'role'
+        ? createRoleChainProvider(profileName, getCredentialProviderOptions())
+        : fromIni({ profile: profileName, ...getCredentialProviderOptions() }),
     // Enable following region redirects for cross-region bucket access
     // This allows the SDK to automatically retry requests to the correct region
     // when accessing buckets in a different region than the client was configured for
     followRegionRedirects: true,
   };
 
-  s3Client = new S3Client(config);
+  return withRequesterPays(withRequestLogging(new S3Client(withConnectionOptions({ ...config, ...overrides }))));
+}
+
+/**
+ * Creates or returns cached S3 client for the given profile
+ * @param profileName - The AWS profile name to use
+ * @param forceNew - Force creation of a new client even if one exists
+ */
+export function getS3Client(profileName: string, forceNew = false): S3Client {
+  // Return cached client if profile and endpoint haven't changed
+  if (s3Client && currentClientProfile === profileName && currentEndpoint === customEndpoint && !forceNew) {
+    return s3Client;
+  }
+
+  s3Client = createS3Client(profileName);
   currentClientProfile = profileName;
-  currentEndpoint = null;
+  currentEndpoint = customEndpoint;
 
   return s3Client;
 }
@@ -158,6 +462,46 @@ export function clearS3Client(): void {
   s3Client = null;
   currentClientProfile = null;
   currentEndpoint = null;
+  regionalClients.clear();
+  presignPutClients.clear();
+  bucketRegions.clear();
+}
+
+function bucketRegionKey(profileName: string, bucket: string): string {
+  return `${profileName}\n${bucket}`;
+}
+
+/**
+ * Records a bucket's region learned elsewhere, e.g. from a regional URL the user
+ * navigated to, unless it is already known
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param region - The bucket's region
+ */
+export function rememberBucketRegion(profileName: string, bucket: string, region: string): void {
+  const key = bucketRegionKey(profileName, bucket);
+  if (!bucketRegions.has(key)) {
+    bucketRegions.set(key, region);
+  }
+}
+
+/**
+ * Returns a client in the bucket's region once that region is known, so requests
+ * go straight to the right endpoint instead of being redirected every time
+ */
+function getClientForBucket(profileName: string, bucket: string): S3Client {
+  const region = bucketRegions.get(bucketRegionKey(profileName, bucket));
+  if (!region || customEndpoint || region === resolveProfileRegion(getProfile(profileName))) {
+    return getS3Client(profileName);
+  }
+
+  const clientKey = `${profileName}\n${region}`;
+  let client = regionalClients.get(clientKey);
+  if (!client) {
+    client = createS3Client(profileName, region);
+    regionalClients.set(clientKey, client);
+  }
+  return client;
 }
 
 /**
@@ -177,10 +521,18 @@ export async function listBuckets(profileName: string): Promise<S3Bucket[]> {
   const command = new ListBucketsCommand({});
   const response = await client.send(command);
 
-  const buckets: S3Bucket[] = (response.Buckets || []).map((bucket: Bucket) => ({
-    name: bucket.Name || '',
-    creationDate: bucket.CreationDate,
-  }));
+  const buckets: S3Bucket[] = (response.Buckets || []).map((bucket: Bucket) => {
+    const name = bucket.Name || '';
+    // Newer S3 versions report each bucket's region in the listing
+    if (bucket.BucketRegion) {
+      bucketRegions.set(bucketRegionKey(profileName, name), bucket.BucketRegion);
+    }
+    return {
+      name,
+      creationDate: bucket.CreationDate,
+      region: bucketRegions.get(bucketRegionKey(profileName, name)),
+    };
+  });
 
   // Sort buckets alphabetically
   buckets.sort((a, b) => a.name.localeCompare(b.name));
@@ -188,112 +540,612 @@ export async function listBuckets(profileName: string): Promise<S3Bucket[]> {
   return buckets;
 }
 
+// Region where buckets are created without a LocationConstraint
+const BUCKET_DEFAULT_REGION = 'us-east-1';
+
 /**
- * Lists objects in an S3 bucket with support for pagination and prefix filtering
+ * Turns a GetBucketLocation answer into a region name. Buckets in us-east-1 have
+ * no location constraint, and some old eu-west-1 buckets report "EU".
+ */
+export function normalizeLocationConstraint(constraint: string | null | undefined): string {
+  if (!constraint) {
+    return BUCKET_DEFAULT_REGION;
+  }
+  return constraint === 'EU' ? 'eu-west-1' : constraint;
+}
+
+/**
+ * Finds the region a bucket lives in, remembering the answer for later requests.
+ * Tries HeadBucket first, whose response (or refusal) names the region, then
+ * falls back to GetBucketLocation.
  * @param profileName - The AWS profile name to use
- * @param options - List options including bucket, prefix, delimiter, maxKeys, continuationToken
+ * @param bucket - The S3 bucket name
  */
-export async function listObjects(
+export async function getBucketRegion(
   profileName: string,
-  options: ListObjectsOptions
-): Promise<ListObjectsResult> {
-  const client = getS3Client(profileName);
-
-  const {
-    bucket,
-    prefix = '',
-    delimiter = '/',
-    maxKeys = DEFAULT_PAGE_SIZE,
-    continuationToken,
-  } = options;
+  bucket: string
+): Promise<{ success: boolean; region?: string; error?: string }> {
+  const key = bucketRegionKey(profileName, bucket);
+  const cached = bucketRegions.get(key);
+  if (cached) {
+    return { success: true, region: cached };
+  }
 
-  // Validate and cap maxKeys
-  const effectiveMaxKeys = Math.min(Math.max(1, maxKeys), MAX_PAGE_SIZE);
+  const client = getS3Client(profileName);
+  let region: string | undefined;
 
-  const command = new ListObjectsV2Command({
-    Bucket: bucket,
-    Prefix: prefix || undefined,
-    Delimiter: delimiter || undefined,
-    MaxKeys: effectiveMaxKeys,
-    ContinuationToken: continuationToken || undefined,
-  });
+  try {
+    const response = await client.send(new HeadBucketCommand({ Bucket: bucket }));
+    region = response.BucketRegion;
+  } catch (error) {
+    // S3 names the region even when it refuses access to the bucket
+    const headers = (error as { $response?: { headers?: Record<string, string> } })?.$response?.headers;
+    region = headers?.['x-amz-bucket-region'];
+  }
 
-  const response: ListObjectsV2CommandOutput = await client.send(command);
+  if (!region) {
+    try {
+      const response = await client.send(new GetBucketLocationCommand({ Bucket: bucket }));
+      region = normalizeLocationConstraint(response.LocationConstraint);
+    } catch (error) {
+      return { success: false, error: describeS3Error(error) };
+    }
+  }
 
-  // Process objects (files)
-  const objects: S3Object[] = (response.Contents || [])
-    .filter((obj: _Object) => {
-      // Filter out the prefix itself if it appears as an object
-      return obj.Key && obj.Key !== prefix;
-    })
-    .map((obj: _Object) => ({
-      key: obj.Key || '',
-      size: obj.Size || 0,
-      lastModified: obj.LastModified,
-      etag: obj.ETag?.replace(/"/g, ''), // Remove quotes from ETag
-      storageClass: obj.StorageClass,
-      isPrefix: false,
-    }));
+  bucketRegions.set(key, region);
+  return { success: true, region };
+}
 
-  // Process common prefixes (folders)
-  const prefixes: S3Object[] = (response.CommonPrefixes || []).map((cp: CommonPrefix) => ({
-    key: cp.Prefix || '',
-    size: 0,
-    isPrefix: true,
-  }));
+/**
+ * Whether a bucket can be used with a profile, as far as HeadBucket can tell
+ */
+export type BucketAccess = 'ok' | 'not-found' | 'access-denied' | 'wrong-region';
 
-  return {
-    objects,
-    prefixes,
-    continuationToken: response.NextContinuationToken,
-    isTruncated: response.IsTruncated || false,
-    prefix: prefix,
-    keyCount: response.KeyCount || 0,
-  };
+export interface BucketStatus {
+  access: BucketAccess;
+  /** The region S3 says the bucket is in, when it says */
+  region?: string;
 }
 
 /**
- * Lists all objects in a bucket/prefix, handling pagination automatically
- * Use with caution for large buckets - prefer listObjects with pagination for lazy loading
+ * Checks whether a bucket exists and is usable before listing it, telling a
+ * missing bucket, a refused one and one outside the profile's region apart.
+ * A region S3 reports is remembered, so later requests go to the right endpoint.
  * @param profileName - The AWS profile name to use
- * @param options - List options (continuationToken is ignored)
- * @param onProgress - Optional callback for progress updates (receives objects count so far)
- * @param abortSignal - Optional signal to abort the operation
+ * @param bucket - The S3 bucket name
  */
-export async function listAllObjects(
+export async function checkBucketAccess(
   profileName: string,
-  options: Omit<ListObjectsOptions, 'continuationToken'>,
-  onProgress?: (count: number) => void,
-  abortSignal?: AbortSignal
-): Promise<ListObjectsResult> {
-  const allObjects: S3Object[] = [];
-  const allPrefixes: S3Object[] = [];
-  let continuationToken: string | undefined;
-  let totalKeyCount = 0;
+  bucket: string
+): Promise<{ success: boolean; status?: BucketStatus; error?: string }> {
+  const expectedRegion = resolveProfileRegion(getProfile(profileName));
+  const isOtherRegion = (region: string | undefined): region is string =>
+    !!region && !customEndpoint && region !== expectedRegion;
 
-  do {
-    // Check for abort
-    if (abortSignal?.aborted) {
-      throw new Error('Operation aborted');
+  try {
+    const response = await getS3Client(profileName).send(new HeadBucketCommand({ Bucket: bucket }));
+    const region = response.BucketRegion;
+    if (region) {
+      bucketRegions.set(bucketRegionKey(profileName, bucket), region);
+    }
+    return { success: true, status: isOtherRegion(region) ? { access: 'wrong-region', region } : { access: 'ok', region } };
+  } catch (error) {
+    const err = error as {
+      name?: string;
+      $metadata?: { httpStatusCode?: number };
+      $response?: { headers?: Record<string, string> };
+    };
+    const statusCode = err.$metadata?.httpStatusCode;
+    const region = err.$response?.headers?.['x-amz-bucket-region'];
+    if (region) {
+      bucketRegions.set(bucketRegionKey(profileName, bucket), region);
     }
 
-    const result = await listObjects(profileName, {
-      ...options,
-      maxKeys: MAX_PAGE_SIZE, // Use max page size for efficiency
-      continuationToken,
-    });
+    if (statusCode === 404 || err.name === 'NotFound' || err.name === 'NoSuchBucket') {
+      return { success: true, status: { access: 'not-found' } };
+    }
+    if (statusCode === 301 || err.name === 'PermanentRedirect' || (statusCode === 400 && isOtherRegion(region))) {
+      return { success: true, status: { access: 'wrong-region', region } };
+    }
+    if (statusCode === 403 || err.name === 'Forbidden' || err.name === 'AccessDenied') {
+      return { success: true, status: { access: 'access-denied', region } };
+    }
+    return { success: false, error: describeS3Error(error) };
+  }
+}
 
-    allObjects.push(...result.objects);
-    allPrefixes.push(...result.prefixes);
-    totalKeyCount += result.keyCount;
-    continuationToken = result.continuationToken;
+/**
+ * Whether a failed request was refused because it went to the wrong region:
+ * a redirect, or a signature for another region
+ */
+export function isRegionMismatchError(error: unknown): boolean {
+  const err = error as { name?: string; Code?: string; $metadata?: { httpStatusCode?: number } } | null;
+  const code = err?.Code ?? err?.name;
+  return (
+    err?.$metadata?.httpStatusCode === 301 ||
+    code === 'PermanentRedirect' ||
+    code === 'AuthorizationHeaderMalformed'
+  );
+}
 
-    // Report progress
-    onProgress?.(allObjects.length);
-  } while (continuationToken);
+/**
+ * Explains a bucket status to the user
+ * @returns The explanation, or null when the bucket is accessible
+ */
+export function describeBucketStatus(bucket: string, status: BucketStatus): string | null {
+  switch (status.access) {
+    case 'ok':
+      return null;
+    case 'not-found':
+      return `Bucket "${bucket}" does not exist`;
+    case 'access-denied':
+      return `Access to bucket "${bucket}" is denied for this profile`;
+    case 'wrong-region':
+      return status.region
+        ? `Bucket "${bucket}" is in region ${status.region}, not the profile's region`
+        : `Bucket "${bucket}" is in a different region than the profile's`;
+  }
+}
 
-  return {
-    objects: allObjects,
+// Prefixes and suffixes S3 reserves for its own bucket types
+const RESERVED_BUCKET_PREFIXES = ['xn--', 'sthree-', 'amzn-s3-demo-'];
+const RESERVED_BUCKET_SUFFIXES = ['-s3alias', '--ol-s3', '.mrap', '--x-s3', '--table-s3'];
+
+/**
+ * Checks a bucket name against the S3 general purpose bucket naming rules
+ * @returns Whether the name is valid, with the first rule it breaks otherwise
+ */
+export function validateBucketName(name: string): { valid: boolean; reason?: string } {
+  if (name.length < 3 || name.length > 63) {
+    return { valid: false, reason: 'Bucket names must be between 3 and 63 characters long' };
+  }
+  if (!/^[a-z0-9.-]+$/.test(name)) {
+    return {
+      valid: false,
+      reason: 'Bucket names can only contain lowercase letters, numbers, dots (.) and hyphens (-)',
+    };
+  }
+  if (!/^[a-z0-9]/.test(name) || !/[a-z0-9]$/.test(name)) {
+    return { valid: false, reason: 'Bucket names must begin and end with a letter or number' };
+  }
+  if (name.includes('..')) {
+    return { valid: false, reason: 'Bucket names must not contain two adjacent dots' };
+  }
+  if (/^\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}$/.test(name)) {
+    return { valid: false, reason: 'Bucket names must not be formatted as an IP address' };
+  }
+  const prefix = RESERVED_BUCKET_PREFIXES.find(p => name.startsWith(p));
+  if (prefix) {
+    return { valid: false, reason: `Bucket names must not start with "${prefix}"` };
+  }
+  const suffix = RESERVED_BUCKET_SUFFIXES.find(s => name.endsWith(s));
+  if (suffix) {
+    return { valid: false, reason: `Bucket names must not end with "${suffix}"` };
+  }
+  return { valid: true };
+}
+
+/**
+ * Builds the CreateBucket configuration for a region. us-east-1 is the default
+ * location and S3 rejects an explicit LocationConstraint for it, while every
+ * other region requires one.
+ */
+export function buildCreateBucketConfig(region: string): CreateBucketConfiguration | undefined {
+  if (!region || region === BUCKET_DEFAULT_REGION) {
+    return undefined;
+  }
+  return { LocationConstraint: region as BucketLocationConstraint };
+}
+
+/**
+ * Why a bucket could not be created, for failures the user can fix by picking another name
+ * - 'name-taken': another account owns a bucket with this name (names are global)
+ * - 'already-owned': the caller already owns a bucket with this name
+ * - 'invalid-name': the name breaks the bucket naming rules
+ */
+export type CreateBucketFailure = 'name-taken' | 'already-owned' | 'invalid-name';
+
+export interface CreateBucketResult extends FileOperationResult {
+  failure?: CreateBucketFailure;
+}
+
+/**
+ * Classifies a CreateBucket error by its S3 error code
+ */
+function getCreateBucketFailure(error: unknown): CreateBucketFailure | undefined {
+  const code = (error as { Code?: string })?.Code ?? (error as Error)?.name;
+  switch (code) {
+    case 'BucketAlreadyExists':
+      return 'name-taken';
+    case 'BucketAlreadyOwnedByYou':
+      return 'already-owned';
+    case 'InvalidBucketName':
+      return 'invalid-name';
+    default:
+      return undefined;
+  }
+}
+
+/**
+ * Creates a bucket in the given region
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The bucket name, checked with validateBucketName first
+ * @param region - The region to create the bucket in (us-east-1 when omitted)
+ */
+export async function createBucket(
+  profileName: string,
+  bucket: string,
+  region?: string
+): Promise<CreateBucketResult> {
+  const validation = validateBucketName(bucket);
+  if (!validation.valid) {
+    return { success: false, failure: 'invalid-name', error: validation.reason };
+  }
+  const bucketRegion = region || BUCKET_DEFAULT_REGION;
+
+  try {
+    // The request must go to the bucket's own region, so don't use the cached client
+    const client = createS3Client(profileName, bucketRegion);
+    const command = new CreateBucketCommand({
+      Bucket: bucket,
+      CreateBucketConfiguration: buildCreateBucketConfig(bucketRegion),
+    });
+    await client.send(command);
+    bucketRegions.set(bucketRegionKey(profileName, bucket), bucketRegion);
+    return { success: true };
+  } catch (error) {
+    const failure = getCreateBucketFailure(error);
+    if (failure === 'name-taken') {
+      return {
+        success: false,
+        failure,
+        error: `The bucket name "${bucket}" is already taken; bucket names are shared by all AWS accounts`,
+      };
+    }
+    if (failure === 'already-owned') {
+      return { success: false, failure, error: `You already own a bucket named "${bucket}"` };
+    }
+    const message = describeS3Error(error);
+    return { success: false, failure, error: message };
+  }
+}
+
+/**
+ * A bucket's Block Public Access settings
+ */
+export interface PublicAccessBlock {
+  blockPublicAcls: boolean;
+  ignorePublicAcls: boolean;
+  blockPublicPolicy: boolean;
+  restrictPublicBuckets: boolean;
+}
+
+/**
+ * Summary of whether a bucket is reachable by the public:
+ * - 'private': nothing grants public access, and Block Public Access stops object ACLs from doing so
+ * - 'public-read': the bucket policy or ACL grants access to everyone
+ * - 'mixed': the bucket itself is not public, but object ACLs can still make individual objects public
+ * - 'unknown': a setting could not be read (usually for lack of permission)
+ */
+export type PublicStatus = 'private' | 'public-read' | 'mixed' | 'unknown';
+
+/**
+ * The signals a bucket's public status is derived from. null means the signal could not be read.
+ */
+export interface PublicAccessSignals {
+  publicAccessBlock: PublicAccessBlock | null;
+  /** Whether the bucket policy grants public access (false without a policy) */
+  policyPublic: boolean | null;
+  /** Whether the bucket ACL grants access to all users or all authenticated AWS users */
+  aclPublic: boolean | null;
+}
+
+// Grantee groups that make an ACL grant public
+const PUBLIC_ACL_GROUPS = [
+  'http://acs.amazonaws.com/groups/global/AllUsers',
+  'http://acs.amazonaws.com/groups/global/AuthenticatedUsers',
+];
+
+// Block Public Access settings of a bucket that has none configured
+const NO_PUBLIC_ACCESS_BLOCK: PublicAccessBlock = {
+  blockPublicAcls: false,
+  ignorePublicAcls: false,
+  blockPublicPolicy: false,
+  restrictPublicBuckets: false,
+};
+
+function getErrorCode(error: unknown): string | undefined {
+  if (!error || typeof error !== 'object') return undefined;
+  return (error as { Code?: string }).Code ?? (error as { name?: string }).name;
+}
+
+/**
+ * Gets a bucket's Block Public Access settings. A bucket without a configuration
+ * has every setting off.
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The bucket name
+ */
+export async function getPublicAccessBlock(profileName: string, bucket: string): Promise<PublicAccessBlock> {
+  const client = getS3Client(profileName);
+  try {
+    const response = await client.send(new GetPublicAccessBlockCommand({ Bucket: bucket }));
+    const config = response.PublicAccessBlockConfiguration ?? {};
+    return {
+      blockPublicAcls: config.BlockPublicAcls === true,
+      ignorePublicAcls: config.IgnorePublicAcls === true,
+      blockPublicPolicy: config.BlockPublicPolicy === true,
+      restrictPublicBuckets: config.RestrictPublicBuckets === true,
+    };
+  } catch (error) {
+    if (getErrorCode(error) === 'NoSuchPublicAccessBlockConfiguration') {
+      return { ...NO_PUBLIC_ACCESS_BLOCK };
+    }
+    throw error;
+  }
+}
+
+async function isBucketPolicyPublic(client: S3Client, bucket: string): Promise<boolean> {
+  try {
+    const response = await client.send(new GetBucketPolicyStatusCommand({ Bucket: bucket }));
+    return response.PolicyStatus?.IsPublic === true;
+  } catch (error) {
+    if (getErrorCode(error) === 'NoSuchBucketPolicy') {
+      return false;
+    }
+    throw error;
+  }
+}
+
+async function isBucketAclPublic(client: S3Client, bucket: string): Promise<boolean> {
+  const response = await client.send(new GetBucketAclCommand({ Bucket: bucket }));
+  return (response.Grants ?? []).some(grant => PUBLIC_ACL_GROUPS.includes(grant.Grantee?.URI ?? ''));
+}
+
+/**
+ * Combines the Block Public Access settings, policy status and ACL into one status.
+ * Public policies and ACLs only count when Block Public Access doesn't neutralize them.
+ */
+export function combinePublicStatus(signals: PublicAccessSignals): PublicStatus {
+  const block = signals.publicAccessBlock;
+  if (block && Object.values(block).every(Boolean)) {
+    return 'private';
+  }
+
+  const policyPublic = signals.policyPublic === true && !block?.restrictPublicBuckets;
+  const aclPublic = signals.aclPublic === true && !block?.ignorePublicAcls;
+  if (policyPublic || aclPublic) {
+    return 'public-read';
+  }
+
+  if (!block || signals.policyPublic === null || signals.aclPublic === null) {
+    return 'unknown';
+  }
+  return block.ignorePublicAcls ? 'private' : 'mixed';
+}
+
+/**
+ * Reads the signals that decide whether a bucket is public and combines them.
+ * Signals that can't be read (e.g. access denied) are left null rather than failing.
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The bucket name
+ */
+export async function getBucketPublicStatus(
+  profileName: string,
+  bucket: string
+): Promise<PublicAccessSignals & { status: PublicStatus }> {
+  const client = getS3Client(profileName);
+  const [publicAccessBlock, policyPublic, aclPublic] = await Promise.all([
+    getPublicAccessBlock(profileName, bucket).catch(() => null),
+    isBucketPolicyPublic(client, bucket).catch(() => null),
+    isBucketAclPublic(client, bucket).catch(() => null),
+  ]);
+  const signals = { publicAccessBlock, policyPublic, aclPublic };
+  return { ...signals, status: combinePublicStatus(signals) };
+}
+
+/**
+ * Reconciles IsTruncated with NextContinuationToken, which some S3-compatible stores
+ * report inconsistently. A truncated page without a token (or with the token that
+ * was just sent, which would loop forever) ends the listing; a token on a page
+ * that claims not to be truncated is still followed, since stopping early would
+ * silently hide objects.
+ * @param isTruncated - IsTruncated from the response
+ * @param nextToken - NextContinuationToken from the response
+ * @param requestToken - The continuation token the page was requested with
+ */
+export function resolvePagination(
+  isTruncated: boolean | undefined,
+  nextToken: string | undefined,
+  requestToken?: string
+): { isTruncated: boolean; continuationToken?: string } {
+  if (nextToken && nextToken === requestToken) {
+    console.warn('S3 returned the same continuation token it was sent; treating the listing as complete');
+    return { isTruncated: false };
+  }
+  if (isTruncated && !nextToken) {
+    console.warn('S3 reported a truncated listing without a continuation token; treating it as complete');
+    return { isTruncated: false };
+  }
+  if (nextToken) {
+    return { isTruncated: true, continuationToken: nextToken };
+  }
+  return { isTruncated: false };
+}
+
+/** Error message of operations stopped through their abort signal */
+export const OPERATION_ABORTED = 'Operation aborted';
+
+/**
+ * Throws the aborted error if the signal has fired
+ */
+function throwIfAborted(abortSignal?: AbortSignal): void {
+  if (abortSignal?.aborted) {
+    throw new Error(OPERATION_ABORTED);
+  }
+}
+
+/**
+ * Whether an error reports an operation stopped through its abort signal
+ */
+export function isAbortedError(error: unknown): boolean {
+  return error instanceof Error && error.message === OPERATION_ABORTED;
+}
+
+/**
+ * Lists objects in an S3 bucket with support for pagination and prefix filtering
+ * @param profileName - The AWS profile name to use
+ * @param options - List options including bucket, prefix, delimiter, maxKeys, continuationToken
+ * @param abortSignal - Optional signal that aborts the in-flight request
+ */
+export async function listObjects(
+  profileName: string,
+  options: ListObjectsOptions,
+  abortSignal?: AbortSignal
+): Promise<ListObjectsResult> {
+  throwIfAborted(abortSignal);
+  const client = getClientForBucket(profileName, options.bucket);
+
+  const {
+    bucket,
+    prefix = '',
+    delimiter = '/',
+    recursive = false,
+    maxKeys = DEFAULT_PAGE_SIZE,
+    continuationToken,
+  } = options;
+
+  // Validate and cap maxKeys
+  const effectiveMaxKeys = Math.min(Math.max(1, maxKeys), MAX_PAGE_SIZE);
+
+  const command = new ListObjectsV2Command({
+    Bucket: bucket,
+    Prefix: prefix || undefined,
+    // Without a delimiter S3 returns nested keys instead of common prefixes
+    Delimiter: recursive ? undefined : delimiter || undefined,
+    MaxKeys: effectiveMaxKeys,
+    ContinuationToken: continuationToken || undefined,
+  });
+
+  let response: ListObjectsV2CommandOutput;
+  try {
+    response = await client.send(command, { abortSignal });
+  } catch (error) {
+    // The SDK's own AbortError varies by request handler, so report one message
+    throwIfAborted(abortSignal);
+    throw error;
+  }
+
+  // Process objects (files)
+  const objects: S3Object[] = (response.Contents || [])
+    .filter((obj: _Object) => {
+      // Filter out the prefix itself if it appears as an object
+      return obj.Key && obj.Key !== prefix;
+    })
+    .map((obj: _Object) => ({
+      key: obj.Key || '',
+      size: obj.Size || 0,
+      lastModified: obj.LastModified,
+      etag: obj.ETag?.replace(/"/g, ''), // Remove quotes from ETag
+      storageClass: obj.StorageClass,
+      isPrefix: false,
+    }));
+
+  // Process common prefixes (folders)
+  const prefixes: S3Object[] = (response.CommonPrefixes || []).map((cp: CommonPrefix) => ({
+    key: cp.Prefix || '',
+    size: 0,
+    isPrefix: true,
+  }));
+
+  return {
+    objects,
+    prefixes,
+    ...resolvePagination(response.IsTruncated, response.NextContinuationToken, continuationToken),
+    prefix: prefix,
+    keyCount: response.KeyCount || 0,
+  };
+}
+
+/**
+ * Lists a bucket/prefix one page at a time, following continuation tokens until
+ * the listing ends. Pages are only requested as they are consumed, so breaking
+ * out of the loop stops the listing early.
+ * @param profileName - The AWS profile name to use
+ * @param options - List options (continuationToken is where the first page starts)
+ * @param abortSignal - Optional signal to abort the listing
+ */
+export async function* listObjectPages(
+  profileName: string,
+  options: ListObjectsOptions,
+  abortSignal?: AbortSignal
+): AsyncGenerator<ListObjectsResult, void, undefined> {
+  let continuationToken = options.continuationToken;
+
+  do {
+    throwIfAborted(abortSignal);
+
+    const page = await listObjects(
+      profileName,
+      {
+        ...options,
+        maxKeys: options.maxKeys ?? MAX_PAGE_SIZE, // Use max page size for efficiency
+        continuationToken,
+      },
+      abortSignal
+    );
+    continuationToken = page.continuationToken;
+    yield page;
+  } while (continuationToken);
+}
+
+/**
+ * Streams every object and folder in a bucket/prefix, for listings too large to
+ * hold in memory. Each page's folders come before its objects.
+ * @param profileName - The AWS profile name to use
+ * @param options - List options (continuationToken is where the listing starts)
+ * @param abortSignal - Optional signal to abort the listing
+ */
+export async function* iterateObjects(
+  profileName: string,
+  options: ListObjectsOptions,
+  abortSignal?: AbortSignal
+): AsyncGenerator<S3Object, void, undefined> {
+  for await (const page of listObjectPages(profileName, options, abortSignal)) {
+    yield* page.prefixes;
+    yield* page.objects;
+  }
+}
+
+/**
+ * Lists all objects in a bucket/prefix, handling pagination automatically
+ * Use with caution for large buckets - prefer listObjects with pagination for lazy loading,
+ * or iterateObjects to process objects without collecting them
+ * @param profileName - The AWS profile name to use
+ * @param options - List options (continuationToken is ignored)
+ * @param onProgress - Optional callback for progress updates (receives objects count so far)
+ * @param abortSignal - Optional signal to abort the operation
+ */
+export async function listAllObjects(
+  profileName: string,
+  options: Omit<ListObjectsOptions, 'continuationToken'>,
+  onProgress?: (count: number) => void,
+  abortSignal?: AbortSignal
+): Promise<ListObjectsResult> {
+  const allObjects: S3Object[] = [];
+  const allPrefixes: S3Object[] = [];
+  let totalKeyCount = 0;
+
+  for await (const page of listObjectPages(profileName, { ...options, maxKeys: MAX_PAGE_SIZE }, abortSignal)) {
+    allObjects.push(...page.objects);
+    allPrefixes.push(...page.prefixes);
+    totalKeyCount += page.keyCount;
+
+    // Report progress
+    onProgress?.(allObjects.length);
+  }
+
+  return {
+    objects: allObjects,
     prefixes: allPrefixes,
     continuationToken: undefined,
     isTruncated: false,
@@ -302,632 +1154,1833 @@ export async function listAllObjects(
   };
 }
 
-/**
- * Extracts bucket name and key from an S3 URL
- * Supports both s3:// and https:// formats
- * @param url - The S3 URL to parse
- * @returns Object with bucket and key, or null if invalid
- */
-export function parseS3Url(url: string): { bucket: string; key: string } | null {
-  // Handle s3:// format
-  const s3Match = url.match(/^s3:\/\/([^/]+)\/?(.*)$/);
-  if (s3Match) {
-    return {
-      bucket: s3Match[1],
-      key: s3Match[2] || '',
-    };
-  }
+/** Maximum number of single-child folders followed when collapsing a chain */
+export const MAX_COLLAPSE_DEPTH = 16;
+
+/**
+ * Follows a chain of folders that each contain exactly one subfolder and nothing
+ * else (e.g. Hive partitions like year=2024/month=01/day=05/)
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param prefix - The folder to start from
+ * @param maxDepth - Maximum number of folders to descend
+ * @param abortSignal - Optional signal to cancel the walk
+ * @returns The deepest prefix reachable by following single subfolders
+ */
+export async function collapseSingleChildChain(
+  profileName: string,
+  bucket: string,
+  prefix: string,
+  maxDepth: number = MAX_COLLAPSE_DEPTH,
+  abortSignal?: AbortSignal
+): Promise<string> {
+  let current = prefix;
+
+  for (let depth = 0; depth < maxDepth; depth++) {
+    throwIfAborted(abortSignal);
+
+    // Two keys are enough to tell "exactly one child" from "more than one"
+    const result = await listObjects(profileName, { bucket, prefix: current, maxKeys: 2 }, abortSignal);
+
+    if (result.isTruncated || result.objects.length > 0 || result.prefixes.length !== 1) {
+      break;
+    }
+    current = result.prefixes[0].key;
+  }
+
+  return current;
+}
+
+/**
+ * Bucket and key of a parsed S3 URL, with the region and object version when the URL names them
+ */
+export interface ParsedS3Url {
+  bucket: string;
+  key: string;
+  region?: string;
+  versionId?: string;
+}
+
+/**
+ * The region in an S3 endpoint's host after "s3": ".us-west-2" or the legacy
+ * "-us-west-2", optionally after ".dualstack". s3-external-1 is us-east-1.
+ */
+function endpointRegion(suffix: string | undefined): string | undefined {
+  const region = suffix?.replace(/^[.-]/, '');
+  return region === 'external-1' ? 'us-east-1' : region || undefined;
+}
+
+const S3_ENDPOINT_SUFFIX = String.raw`(?:\.dualstack)?([.-][a-z]{2}(?:-[a-z]+)+-\d+|-external-1)?\.amazonaws\.com(?:\.cn)?`;
+const VIRTUAL_HOST_PATTERN = new RegExp(String.raw`^(.+)\.s3${S3_ENDPOINT_SUFFIX}$`, 'i');
+const PATH_STYLE_HOST_PATTERN = new RegExp(String.raw`^s3${S3_ENDPOINT_SUFFIX}$`, 'i');
+
+/**
+ * Decode the percent-encoded key of an HTTPS URL. "+" is a space, as in the
+ * console's object URLs (a literal "+" is sent as %2B); malformed escapes are kept as typed.
+ */
+function decodeUrlKey(path: string): string {
+  const spaced = path.replace(/\+/g, ' ');
+  try {
+    return decodeURIComponent(spaced);
+  } catch {
+    return spaced;
+  }
+}
+
+/**
+ * Extracts bucket name, key and region from an S3 URL
+ * Supports s3://bucket/key, virtual-hosted https://bucket.s3.region.amazonaws.com/key
+ * and path-style https://s3.region.amazonaws.com/bucket/key, including the legacy
+ * s3-region and dualstack endpoints. An HTTPS URL's key is percent-decoded, and its
+ * ?versionId= query names a specific object version; s3:// URIs have no query, as
+ * their keys may legitimately contain "?"
+ * @param url - The S3 URL to parse
+ * @returns Object with bucket, key and (for HTTPS URLs that name them) region and versionId, or null if invalid
+ */
+export function parseS3Url(url: string): ParsedS3Url | null {
+  // Handle s3:// format
+  const s3Match = url.match(/^s3:\/\/([^/]+)\/?(.*)$/);
+  if (s3Match) {
+    return {
+      bucket: s3Match[1],
+      key: s3Match[2] || '',
+    };
+  }
+
+  const httpMatch = url.match(/^https?:\/\/([^/?#]+)\/?([^?#]*)(?:\?([^#]*))?(?:#.*)?$/i);
+  if (!httpMatch) {
+    return null;
+  }
+  const [, host, path, query = ''] = httpMatch;
+  const versionId = new URLSearchParams(query).get('versionId') || undefined;
+
+  // Handle https://bucket.s3.region.amazonaws.com/key format
+  const virtualHostMatch = host.match(VIRTUAL_HOST_PATTERN);
+  if (virtualHostMatch) {
+    const region = endpointRegion(virtualHostMatch[2]);
+    return {
+      bucket: virtualHostMatch[1],
+      key: decodeUrlKey(path),
+      ...(region ? { region } : {}),
+      ...(versionId ? { versionId } : {}),
+    };
+  }
+
+  // Handle https://s3.region.amazonaws.com/bucket/key format
+  const pathStyleHostMatch = host.match(PATH_STYLE_HOST_PATTERN);
+  const pathStyleMatch = path.match(/^([^/]+)\/?(.*)$/);
+  if (pathStyleHostMatch && pathStyleMatch) {
+    const region = endpointRegion(pathStyleHostMatch[1]);
+    return {
+      bucket: pathStyleMatch[1],
+      key: decodeUrlKey(pathStyleMatch[2] || ''),
+      ...(region ? { region } : {}),
+      ...(versionId ? { versionId } : {}),
+    };
+  }
+
+  return null;
+}
+
+/**
+ * Formats a bucket and key as a canonical s3://bucket/key URI, the inverse of parseS3Url
+ * @param bucket - The S3 bucket name
+ * @param key - The S3 object key or prefix
+ */
+export function formatS3Uri(bucket: string, key: string): string {
+  return `s3://${bucket}/${key}`;
+}
+
+/**
+ * Formats a bucket and key as a virtual-hosted HTTPS URL in the bucket's region
+ * Each key segment is percent-encoded but slashes are kept, so the link reads like
+ * the console's and parses back to the same bucket and region
+ * @param bucket - The S3 bucket name
+ * @param key - The S3 object key or prefix
+ * @param region - The bucket's region; omitted means the global endpoint
+ */
+export function formatS3HttpsUrl(bucket: string, key: string, region?: string): string {
+  const host = region
+    ? `${bucket}.s3.${region}.amazonaws.com${region.startsWith('cn-') ? '.cn' : ''}`
+    : `${bucket}.s3.amazonaws.com`;
+  const path = key.split('/').map(encodeURIComponent).join('/');
+  return `https://${host}/${path}`;
+}
+
+/**
+ * Parses a block of text containing one S3 URL per line (e.g. a pasted list of copied URLs)
+ * Blank and unparseable lines are skipped; duplicate bucket/key pairs are kept once, in order
+ * @param text - The text to parse
+ */
+export function parseS3UrlList(text: string): Array<{ bucket: string; key: string }> {
+  const seen = new Set<string>();
+  const urls: Array<{ bucket: string; key: string }> = [];
+
+  for (const line of text.split(/\r?\n/)) {
+    const parsed = parseS3Url(line.trim());
+    if (!parsed) continue;
+
+    const id = `${parsed.bucket}/${parsed.key}`;
+    if (seen.has(id)) continue;
+    seen.add(id);
+    urls.push({ bucket: parsed.bucket, key: parsed.key });
+  }
+
+  return urls;
+}
+
+/**
+ * Finds the deepest folder prefix shared by all URLs
+ * @param urls - Parsed S3 URLs
+ * @returns The shared bucket and prefix, or null if the list is empty or spans multiple buckets
+ */
+export function getCommonPrefix(
+  urls: Array<{ bucket: string; key: string }>
+): { bucket: string; prefix: string } | null {
+  if (urls.length === 0) {
+    return null;
+  }
+
+  const bucket = urls[0].bucket;
+  if (urls.some(url => url.bucket !== bucket)) {
+    return null;
+  }
+
+  let common = urls[0].key;
+  for (const url of urls.slice(1)) {
+    let i = 0;
+    while (i < common.length && i < url.key.length && common[i] === url.key[i]) {
+      i++;
+    }
+    common = common.slice(0, i);
+  }
+
+  // Cut back to a folder boundary so partial names don't become a prefix
+  const lastSlash = common.lastIndexOf('/');
+  return { bucket, prefix: lastSlash >= 0 ? common.slice(0, lastSlash + 1) : '' };
+}
+
+/**
+ * Gets the parent prefix for a given key or prefix
+ * @param keyOrPrefix - The key or prefix to get the parent of
+ * @returns The parent prefix, or empty string if at root
+ */
+export function getParentPrefix(keyOrPrefix: string): string {
+  // Remove trailing slash if present
+  const normalized = keyOrPrefix.endsWith('/') ? keyOrPrefix.slice(0, -1) : keyOrPrefix;
+
+  const lastSlashIndex = normalized.lastIndexOf('/');
+  if (lastSlashIndex === -1) {
+    return '';
+  }
+
+  return normalized.substring(0, lastSlashIndex + 1);
+}
+
+/**
+ * Extracts the name (last segment) from a key or prefix
+ * @param keyOrPrefix - The key or prefix to extract the name from
+ */
+export function getKeyName(keyOrPrefix: string): string {
+  // Remove trailing slash if present for prefixes
+  const normalized = keyOrPrefix.endsWith('/') ? keyOrPrefix.slice(0, -1) : keyOrPrefix;
+
+  const lastSlashIndex = normalized.lastIndexOf('/');
+  if (lastSlashIndex === -1) {
+    return normalized;
+  }
+
+  return normalized.substring(lastSlashIndex + 1);
+}
+
+export interface UploadProgress {
+  loaded: number;
+  total: number;
+  percentage: number;
+}
+
+export interface DownloadProgress {
+  loaded: number;
+  total: number;
+  percentage: number;
+}
+
+// Error codes S3 returns when the local clock is too far from the server's
+const CLOCK_SKEW_ERROR_CODES = ['RequestTimeTooSkewed', 'SignatureDoesNotMatch'];
+
+// Skew below this is within normal drift and not worth reporting (S3 allows 15 minutes)
+const MIN_REPORTED_SKEW_MS = 60 * 1000;
+
+/**
+ * Estimates how far the local clock is from S3's, in milliseconds (positive when
+ * the local clock is ahead). Uses the ServerTime field of RequestTimeTooSkewed
+ * errors, falling back to the response Date header.
+ * @returns The skew, or null if the error carries no server time
+ */
+export function getClockSkewMs(error: unknown, now: number = Date.now()): number | null {
+  if (!error || typeof error !== 'object') {
+    return null;
+  }
+  const err = error as {
+    ServerTime?: string;
+    $response?: { headers?: Record<string, string | undefined> };
+  };
+  const serverTime = err.ServerTime ?? err.$response?.headers?.date ?? err.$response?.headers?.Date;
+  if (!serverTime) {
+    return null;
+  }
+  const serverMs = Date.parse(serverTime);
+  return Number.isNaN(serverMs) ? null : now - serverMs;
+}
+
+/**
+ * Checks whether an error is a connect or request timeout from the HTTP handler
+ */
+export function isTimeoutError(error: unknown): boolean {
+  return error instanceof Error && error.name === 'TimeoutError';
+}
+
+/**
+ * Builds the error message returned to the renderer. Timeouts start with "Request
+ * timed out", and reads of archived objects say a restore is needed. Clock skew
+ * errors keep their error code in the message (the SDK message alone does not
+ * mention it) along with the measured skew, so the UI can explain that the system
+ * clock is wrong.
+ */
+export function describeS3Error(error: unknown, now: number = Date.now()): string {
+  if (!(error instanceof Error)) {
+    return 'Unknown error occurred';
+  }
+
+  if (isTimeoutError(error)) {
+    return `Request timed out: ${error.message}`;
+  }
+
+  const code = (error as { Code?: string }).Code ?? error.name;
+  if (code === 'InvalidObjectState') {
+    return `${error.message} (the object is archived; restore it before downloading)`;
+  }
+  if (!CLOCK_SKEW_ERROR_CODES.includes(code)) {
+    return error.message;
+  }
+
+  const skewMs = getClockSkewMs(error, now);
+  if (skewMs === null || Math.abs(skewMs) < MIN_REPORTED_SKEW_MS) {
+    // A signature mismatch without measurable skew is most likely a wrong secret key
+    return code === 'RequestTimeTooSkewed' ? `${code}: ${error.message}` : error.message;
+  }
+
+  const minutes = Math.round(Math.abs(skewMs) / 60000);
+  const direction = skewMs > 0 ? 'ahead of' : 'behind';
+  return `RequestTimeTooSkewed: ${error.message} (clock skew: system clock is ${minutes} minute${minutes === 1 ? '' : 's'} ${direction} S3)`;
+}
+
+export interface FileOperationResult {
+  success: boolean;
+  error?: string;
+}
+
+/** Suffix of the file a download is written to until it has completed */
+export const PARTIAL_DOWNLOAD_SUFFIX = '.part';
+
+export interface DownloadFileResult extends FileOperationResult {
+  /** Bytes written to the destination */
+  bytesWritten?: number;
+}
+
+/**
+ * Downloads a file from S3 to local filesystem, streaming the body to disk
+ * rather than buffering it. The body is written next to the destination with
+ * PARTIAL_DOWNLOAD_SUFFIX and only renamed into place once complete, so a failed
+ * or cancelled download leaves neither a partial file nor a clobbered original.
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param key - The S3 object key
+ * @param destinationPath - Local path to save the file
+ * @param onProgress - Optional callback for download progress
+ * @param abortSignal - Optional signal to abort the operation
+ */
+export async function downloadFile(
+  profileName: string,
+  bucket: string,
+  key: string,
+  destinationPath: string,
+  onProgress?: (progress: DownloadProgress) => void,
+  abortSignal?: AbortSignal
+): Promise<DownloadFileResult> {
+  const client = getS3Client(profileName);
+  const partialPath = destinationPath + PARTIAL_DOWNLOAD_SUFFIX;
+
+  try {
+    // First, get the object metadata to know the total size
+    const headCommand = new HeadObjectCommand({
+      Bucket: bucket,
+      Key: key,
+    });
+    const headResponse = await client.send(headCommand);
+
+    // Download the object
+    const getCommand = new GetObjectCommand({
+      Bucket: bucket,
+      Key: key,
+    });
+
+    const response = await client.send(getCommand, {
+      abortSignal,
+    });
+
+    if (!response.Body) {
+      throw new Error('Empty response body');
+    }
+
+    // The GET's own length wins in case the object changed since the HEAD
+    const totalSize = response.ContentLength ?? headResponse.ContentLength ?? 0;
+
+    // Ensure the destination directory exists
+    const destDir = path.dirname(destinationPath);
+    await fs.promises.mkdir(destDir, { recursive: true });
+
+    const writeStream = fs.createWriteStream(partialPath);
+
+    // Count bytes as they pass through, for progress and the completeness check
+    let loaded = 0;
+    const counter = new Transform({
+      transform(chunk: Buffer, _encoding, callback) {
+        loaded += chunk.length;
+        if (onProgress && totalSize > 0) {
+          onProgress({
+            loaded,
+            total: totalSize,
+            percentage: Math.round((loaded / totalSize) * 100),
+          });
+        }
+        callback(null, chunk);
+      },
+    });
+
+    // Use pipeline to properly handle streams; the signal also stops a body already streaming
+    if (abortSignal) {
+      await pipeline(response.Body as Readable, counter, writeStream, { signal: abortSignal });
+    } else {
+      await pipeline(response.Body as Readable, counter, writeStream);
+    }
+
+    // A connection closed early can end the body without an error
+    if (totalSize > 0 && loaded !== totalSize) {
+      throw new Error(`Download incomplete: received ${loaded} of ${totalSize} bytes`);
+    }
+
+    await fs.promises.rename(partialPath, destinationPath);
+    return { success: true, bytesWritten: loaded };
+  } catch (error) {
+    // Clean up partial file on error
+    try {
+      await fs.promises.unlink(partialPath);
+    } catch {
+      // Ignore cleanup errors
+    }
+
+    const message = abortSignal?.aborted ? OPERATION_ABORTED : describeS3Error(error);
+    return { success: false, error: message };
+  }
+}
+
+/** Downloads run at once when several files are downloaded together */
+export const DEFAULT_DOWNLOAD_CONCURRENCY = 8;
+
+export interface FileDownload {
+  key: string;
+  destinationPath: string;
+}
+
+export interface FileDownloadResult extends DownloadFileResult {
+  key: string;
+}
+
+/**
+ * Downloads several files, up to maxConcurrency at a time. Every download is
+ * attempted even when others fail; once the signal aborts, downloads that have
+ * not started yet are reported as aborted without being sent.
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param downloads - The objects to download and where each goes
+ * @param maxConcurrency - The most downloads in flight at once
+ * @param abortSignal - Optional signal to abort the remaining downloads
+ * @returns One result per download, in the order given
+ */
+export async function downloadFiles(
+  profileName: string,
+  bucket: string,
+  downloads: FileDownload[],
+  maxConcurrency: number = DEFAULT_DOWNLOAD_CONCURRENCY,
+  abortSignal?: AbortSignal
+): Promise<FileDownloadResult[]> {
+  const results: FileDownloadResult[] = new Array(downloads.length);
+  let next = 0;
+
+  // Each worker takes the next download until none are left
+  const worker = async () => {
+    while (next < downloads.length) {
+      const index = next++;
+      const { key, destinationPath } = downloads[index];
+      if (abortSignal?.aborted) {
+        results[index] = { key, success: false, error: OPERATION_ABORTED };
+        continue;
+      }
+      const result = await downloadFile(profileName, bucket, key, destinationPath, undefined, abortSignal);
+      results[index] = { key, ...result };
+    }
+  };
+
+  const workerCount = Math.min(Math.max(1, Math.floor(maxConcurrency) || 1), downloads.length);
+  await Promise.all(Array.from({ length: workerCount }, worker));
+  return results;
+}
+
+export interface UploadOptions {
+  /** Content type to store instead of the one guessed from the key's extension */
+  contentType?: string;
+  /** Storage class to write the object in (S3's default is STANDARD) */
+  storageClass?: UploadStorageClass;
+  /** User metadata stored as x-amz-meta-* headers, keyed without the prefix */
+  metadata?: Record<string, string>;
+  /** Send a SHA-256 checksum that S3 verifies against the body and stores with the object */
+  checksumSha256?: boolean;
+}
+
+/**
+ * PutObject parameters sending a checksum computed locally, which S3 verifies
+ * against the body and stores with the object
+ */
+function checksumParams(
+  algorithm: ChecksumAlgorithmName,
+  checksum: string
+): { ChecksumAlgorithm: ChecksumAlgorithmName; ChecksumSHA256?: string; ChecksumCRC32C?: string } {
+  return {
+    ChecksumAlgorithm: algorithm,
+    ...(algorithm === 'SHA256' ? { ChecksumSHA256: checksum } : { ChecksumCRC32C: checksum }),
+  };
+}
+
+/**
+ * Checksum parameters for a PutObject request, when the upload options ask for one
+ */
+function uploadChecksumParams(body: Buffer, options: UploadOptions): Partial<ReturnType<typeof checksumParams>> {
+  return options.checksumSha256 ? checksumParams('SHA256', computeChecksum(body, 'SHA256')) : {};
+}
+
+/**
+ * Checks user metadata can be sent as x-amz-meta-* headers unchanged. Keys must
+ * be printable ASCII without spaces, and values can't contain line breaks,
+ * which S3 would otherwise silently mangle.
+ * @returns The problem found, or null when the metadata is valid
+ */
+export function validateUserMetadata(metadata: Record<string, string>): string | null {
+  for (const [key, value] of Object.entries(metadata)) {
+    if (key.length === 0) {
+      return 'Metadata keys cannot be empty';
+    }
+    if (!/^[\x21-\x7e]+$/.test(key)) {
+      return `Metadata key "${key}" must be printable ASCII without spaces`;
+    }
+    if (/[\r\n]/.test(value)) {
+      return `Metadata value for "${key}" cannot contain line breaks`;
+    }
+  }
+  return null;
+}
+
+/**
+ * Checks upload options before any data is sent
+ * @returns The problem found, or null when the options are usable
+ */
+function validateUploadOptions(options: UploadOptions): string | null {
+  if (options.storageClass !== undefined && !isUploadStorageClass(options.storageClass)) {
+    return `Unknown storage class: ${options.storageClass}`;
+  }
+  if (options.metadata) {
+    return validateUserMetadata(options.metadata);
+  }
+  return null;
+}
+
+/**
+ * Uploads a file from local filesystem to S3
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param key - The S3 object key
+ * @param sourcePath - Local path of the file to upload
+ * @param onProgress - Optional callback for upload progress
+ * @param abortSignal - Optional signal to abort the operation
+ * @param options - Overrides for the stored object's headers
+ */
+export async function uploadFile(
+  profileName: string,
+  bucket: string,
+  key: string,
+  sourcePath: string,
+  onProgress?: (progress: UploadProgress) => void,
+  abortSignal?: AbortSignal,
+  options: UploadOptions = {}
+): Promise<FileOperationResult> {
+  const problem = validateUploadOptions(options);
+  if (problem) {
+    return { success: false, error: problem };
+  }
+
+  const client = getS3Client(profileName);
+
+  try {
+    // Check if the source file exists
+    const stats = await fs.promises.stat(sourcePath);
+    const totalSize = stats.size;
+
+    // Read file content
+    const fileContent = await fs.promises.readFile(sourcePath);
+
+    // Determine content type based on extension unless the caller chose one
+    const contentType = options.contentType || guessMimeType(key);
+
+    // Simple upload for files
+    const putCommand = new PutObjectCommand({
+      Bucket: bucket,
+      Key: key,
+      Body: fileContent,
+      ContentType: contentType,
+      StorageClass: options.storageClass,
+      Metadata: options.metadata,
+      ...uploadChecksumParams(fileContent, options),
+    });
+
+    await client.send(putCommand, {
+      abortSignal,
+    });
+
+    // Report 100% progress
+    if (onProgress) {
+      onProgress({
+        loaded: totalSize,
+        total: totalSize,
+        percentage: 100,
+      });
+    }
+
+    return { success: true };
+  } catch (error) {
+    const message = describeS3Error(error);
+    return { success: false, error: message };
+  }
+}
+
+/**
+ * Uploads content directly to S3 (for editor save)
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param key - The S3 object key
+ * @param content - The content to upload
+ * @param options - Overrides for the stored object's headers
+ */
+export async function uploadContent(
+  profileName: string,
+  bucket: string,
+  key: string,
+  content: string | Buffer,
+  options: UploadOptions = {}
+): Promise<FileOperationResult> {
+  const problem = validateUploadOptions(options);
+  if (problem) {
+    return { success: false, error: problem };
+  }
+
+  const client = getS3Client(profileName);
+
+  try {
+    const contentType = options.contentType || guessMimeType(key);
+    const body = typeof content === 'string' ? Buffer.from(content, 'utf-8') : content;
+
+    const putCommand = new PutObjectCommand({
+      Bucket: bucket,
+      Key: key,
+      Body: body,
+      ContentType: contentType,
+      StorageClass: options.storageClass,
+      Metadata: options.metadata,
+      ...uploadChecksumParams(body, options),
+    });
+
+    await client.send(putCommand);
+
+    return { success: true };
+  } catch (error) {
+    const message = describeS3Error(error);
+    return { success: false, error: message };
+  }
+}
+
+export interface ChecksumUploadResult extends FileOperationResult {
+  // Base64 checksum acknowledged by S3
+  checksum?: string;
+}
+
+/**
+ * Uploads content with an additional checksum that S3 stores and validates
+ * The checksum is computed locally and sent with the request, so S3 rejects a
+ * body that doesn't match it (BadDigest). The checksum S3 echoes back is verified too.
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param key - The S3 object key
+ * @param content - The content to upload
+ * @param algorithm - The checksum algorithm (CRC32C or SHA256)
+ */
+export async function putObjectWithChecksum(
+  profileName: string,
+  bucket: string,
+  key: string,
+  content: string | Buffer,
+  algorithm: ChecksumAlgorithmName
+): Promise<ChecksumUploadResult> {
+  const client = getS3Client(profileName);
+
+  try {
+    const body = typeof content === 'string' ? Buffer.from(content, 'utf-8') : content;
+    const checksum = computeChecksum(body, algorithm);
+
+    const putCommand = new PutObjectCommand({
+      Bucket: bucket,
+      Key: key,
+      Body: body,
+      ContentType: guessMimeType(key),
+      ...checksumParams(algorithm, checksum),
+    });
+
+    const response = await client.send(putCommand);
+
+    const acknowledged = algorithm === 'SHA256' ? response.ChecksumSHA256 : response.ChecksumCRC32C;
+    if (!acknowledged) {
+      return { success: false, error: `S3 did not acknowledge the ${algorithm} checksum` };
+    }
+    if (acknowledged !== checksum) {
+      return {
+        success: false,
+        error: `Checksum mismatch: expected ${checksum}, S3 reported ${acknowledged}`,
+      };
+    }
+
+    return { success: true, checksum };
+  } catch (error) {
+    const message = describeS3Error(error);
+    return { success: false, error: message };
+  }
+}
+
+/**
+ * Downloads file content directly (for editor load)
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param key - The S3 object key
+ * @param versionId - Version to read instead of the latest one
+ */
+export async function downloadContent(
+  profileName: string,
+  bucket: string,
+  key: string,
+  versionId?: string
+): Promise<{ success: boolean; content?: string; error?: string }> {
+  const client = getS3Client(profileName);
+
+  try {
+    const getCommand = new GetObjectCommand({
+      Bucket: bucket,
+      Key: key,
+      VersionId: versionId,
+    });
+
+    const response = await client.send(getCommand);
+
+    if (!response.Body) {
+      throw new Error('Empty response body');
+    }
+
+    // Convert stream to string
+    const chunks: Buffer[] = [];
+    const bodyStream = response.Body as Readable;
+
+    for await (const chunk of bodyStream) {
+      chunks.push(Buffer.from(chunk));
+    }
+
+    const content = Buffer.concat(chunks).toString('utf-8');
+
+    return { success: true, content };
+  } catch (error) {
+    const message = describeS3Error(error);
+    return { success: false, error: message };
+  }
+}
+
+/**
+ * Deletes a file from S3
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param key - The S3 object key
+ */
+export async function deleteFile(
+  profileName: string,
+  bucket: string,
+  key: string
+): Promise<FileOperationResult> {
+  const client = getS3Client(profileName);
+
+  try {
+    const deleteCommand = new DeleteObjectCommand({
+      Bucket: bucket,
+      Key: key,
+    });
+
+    await client.send(deleteCommand);
+
+    return { success: true };
+  } catch (error) {
+    const message = describeS3Error(error);
+    return { success: false, error: message };
+  }
+}
+
+export interface DeleteFilesResult {
+  success: boolean;
+  results: Array<{ key: string; success: boolean; error?: string }>;
+  deletedCount: number;
+  failedCount: number;
+}
+
+// Most keys a single DeleteObjects request accepts
+const DELETE_OBJECTS_BATCH_SIZE = 1000;
+
+/**
+ * Deletes up to DELETE_OBJECTS_BATCH_SIZE keys with one DeleteObjects request
+ * @returns The outcome for each key, in the order given
+ */
+async function deleteObjectBatch(
+  client: S3Client,
+  bucket: string,
+  keys: string[]
+): Promise<Array<{ key: string; success: boolean; error?: string }>> {
+  const command = new DeleteObjectsCommand({
+    Bucket: bucket,
+    // Quiet mode only reports the keys that failed
+    Delete: { Objects: keys.map(key => ({ Key: key })), Quiet: true },
+  });
+  command.middlewareStack.add(createContentMd5Middleware(), CONTENT_MD5_MIDDLEWARE_OPTIONS);
+
+  const response = await client.send(command);
+  const errors = new Map<string, string>();
+  for (const error of response?.Errors ?? []) {
+    if (error.Key !== undefined) {
+      errors.set(error.Key, error.Message || error.Code || 'Delete failed');
+    }
+  }
+
+  return keys.map(key => (errors.has(key) ? { key, success: false, error: errors.get(key) } : { key, success: true }));
+}
+
+/**
+ * Deletes multiple files from S3
+ * Uses bulk DeleteObjects requests, falling back to deleting one by one when an
+ * endpoint rejects the bulk request, so every key still gets its own result.
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param keys - Array of S3 object keys to delete
+ */
+export async function deleteFiles(
+  profileName: string,
+  bucket: string,
+  keys: string[]
+): Promise<DeleteFilesResult> {
+  const client = getS3Client(profileName);
+  const results: Array<{ key: string; success: boolean; error?: string }> = [];
+
+  for (let start = 0; start < keys.length; start += DELETE_OBJECTS_BATCH_SIZE) {
+    const batch = keys.slice(start, start + DELETE_OBJECTS_BATCH_SIZE);
+    try {
+      results.push(...(await deleteObjectBatch(client, bucket, batch)));
+    } catch (error) {
+      console.warn(`Bulk delete failed, deleting one by one: ${describeS3Error(error)}`);
+      for (const key of batch) {
+        const result = await deleteFile(profileName, bucket, key);
+        results.push(result.success ? { key, success: true } : { key, success: false, error: result.error });
+      }
+    }
+  }
+
+  const failedCount = results.filter(result => !result.success).length;
+  return {
+    success: failedCount === 0,
+    results,
+    deletedCount: results.length - failedCount,
+    failedCount,
+  };
+}
+
+export interface DeletePrefixResult {
+  success: boolean;
+  deletedCount: number;
+  failedCount: number;
+  error?: string;
+}
+
+/**
+ * Deletes all objects under a prefix (folder) in S3
+ * This recursively lists and deletes all objects with the given prefix
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param prefix - The prefix (folder) to delete
+ * @param onProgress - Optional callback for progress updates
+ * @param abortSignal - Optional signal to abort the operation
+ */
+export async function deletePrefix(
+  profileName: string,
+  bucket: string,
+  prefix: string,
+  onProgress?: (deleted: number, total: number) => void,
+  abortSignal?: AbortSignal
+): Promise<DeletePrefixResult> {
+  try {
+    // First, list all objects under this prefix (no delimiter to get all nested objects)
+    const allObjects: S3Object[] = [];
+    let continuationToken: string | undefined;
+
+    // List all objects recursively
+    do {
+      if (abortSignal?.aborted) {
+        return { success: false, deletedCount: 0, failedCount: 0, error: OPERATION_ABORTED };
+      }
+
+      let result: ListObjectsResult;
+      try {
+        result = await listObjects(
+          profileName,
+          {
+            bucket,
+            prefix,
+            recursive: true, // We want ALL nested objects
+            maxKeys: MAX_PAGE_SIZE,
+            continuationToken,
+          },
+          abortSignal
+        );
+      } catch (error) {
+        if (isAbortedError(error)) {
+          return { success: false, deletedCount: 0, failedCount: 0, error: OPERATION_ABORTED };
+        }
+        throw error;
+      }
+
+      allObjects.push(...result.objects);
+      // Also include the prefixes as potential empty folder markers
+      allObjects.push(...result.prefixes);
+      continuationToken = result.continuationToken;
+    } while (continuationToken);
+
+    if (allObjects.length === 0) {
+      // No objects to delete, but the prefix marker itself might exist
+      // Try to delete the prefix key itself (e.g., "folder/")
+      const deleteResult = await deleteFile(profileName, bucket, prefix);
+      return {
+        success: deleteResult.success,
+        deletedCount: deleteResult.success ? 1 : 0,
+        failedCount: deleteResult.success ? 0 : 1,
+        error: deleteResult.error,
+      };
+    }
+
+    // Delete all objects
+    let deletedCount = 0;
+    let failedCount = 0;
+    const total = allObjects.length;
 
-  // Handle https://bucket.s3.region.amazonaws.com/key format
-  const virtualHostMatch = url.match(/^https?:\/\/([^.]+)\.s3\.([^.]+\.)?amazonaws\.com\/?(.*)$/);
-  if (virtualHostMatch) {
-    return {
-      bucket: virtualHostMatch[1],
-      key: virtualHostMatch[3] || '',
-    };
-  }
+    for (const obj of allObjects) {
+      if (abortSignal?.aborted) {
+        return {
+          success: false,
+          deletedCount,
+          failedCount: failedCount + (total - deletedCount - failedCount),
+          error: OPERATION_ABORTED,
+        };
+      }
+
+      const result = await deleteFile(profileName, bucket, obj.key);
+      if (result.success) {
+        deletedCount++;
+      } else {
+        failedCount++;
+      }
+      onProgress?.(deletedCount + failedCount, total);
+    }
+
+    // Also try to delete the prefix marker itself
+    await deleteFile(profileName, bucket, prefix);
 
-  // Handle https://s3.region.amazonaws.com/bucket/key format
-  const pathStyleMatch = url.match(/^https?:\/\/s3\.([^.]+\.)?amazonaws\.com\/([^/]+)\/?(.*)$/);
-  if (pathStyleMatch) {
     return {
-      bucket: pathStyleMatch[2],
-      key: pathStyleMatch[3] || '',
+      success: failedCount === 0,
+      deletedCount,
+      failedCount,
     };
+  } catch (error) {
+    const message = describeS3Error(error);
+    return { success: false, deletedCount: 0, failedCount: 0, error: message };
   }
+}
 
-  return null;
+export interface CopyOptions {
+  /**
+   * Keep the source's content type, user metadata, tags and storage class (the default).
+   * When false only the content is copied, with a content type guessed from the new key.
+   */
+  preserveMetadata?: boolean;
 }
 
 /**
- * Gets the parent prefix for a given key or prefix
- * @param keyOrPrefix - The key or prefix to get the parent of
- * @returns The parent prefix, or empty string if at root
+ * Builds the CopyObject request for a copy within one client's reach. S3 copies
+ * metadata and tags by default but always writes the copy as STANDARD, so the
+ * source's storage class is looked up and passed along.
  */
-export function getParentPrefix(keyOrPrefix: string): string {
-  // Remove trailing slash if present
-  const normalized = keyOrPrefix.endsWith('/') ? keyOrPrefix.slice(0, -1) : keyOrPrefix;
+async function buildCopyCommand(
+  client: S3Client,
+  sourceBucket: string,
+  sourceKey: string,
+  destinationBucket: string,
+  destinationKey: string,
+  { preserveMetadata = true }: CopyOptions = {}
+): Promise<CopyObjectCommand> {
+  const target = {
+    Bucket: destinationBucket,
+    Key: destinationKey,
+    CopySource: encodeURIComponent(`${sourceBucket}/${sourceKey}`),
+  };
 
-  const lastSlashIndex = normalized.lastIndexOf('/');
-  if (lastSlashIndex === -1) {
-    return '';
+  if (!preserveMetadata) {
+    return new CopyObjectCommand({
+      ...target,
+      MetadataDirective: 'REPLACE',
+      TaggingDirective: 'REPLACE',
+      ContentType: guessMimeType(destinationKey),
+    });
   }
 
-  return normalized.substring(0, lastSlashIndex + 1);
+  // STANDARD objects have no storage class header; a failed HEAD is left for the copy to report
+  let storageClass: string | undefined;
+  try {
+    const head = await client.send(new HeadObjectCommand({ Bucket: sourceBucket, Key: sourceKey }));
+    storageClass = head?.StorageClass;
+  } catch {
+    storageClass = undefined;
+  }
+
+  return new CopyObjectCommand({
+    ...target,
+    MetadataDirective: 'COPY',
+    TaggingDirective: 'COPY',
+    StorageClass: storageClass as StorageClass | undefined,
+  });
 }
 
 /**
- * Extracts the name (last segment) from a key or prefix
- * @param keyOrPrefix - The key or prefix to extract the name from
+ * Renames (copies then deletes) a file in S3
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param sourceKey - The current S3 object key
+ * @param destinationKey - The new S3 object key
+ * @param options - Whether the renamed object keeps its metadata (it does by default)
  */
-export function getKeyName(keyOrPrefix: string): string {
-  // Remove trailing slash if present for prefixes
-  const normalized = keyOrPrefix.endsWith('/') ? keyOrPrefix.slice(0, -1) : keyOrPrefix;
+export async function renameFile(
+  profileName: string,
+  bucket: string,
+  sourceKey: string,
+  destinationKey: string,
+  options: CopyOptions = {}
+): Promise<FileOperationResult> {
+  const client = getS3Client(profileName);
 
-  const lastSlashIndex = normalized.lastIndexOf('/');
-  if (lastSlashIndex === -1) {
-    return normalized;
-  }
+  try {
+    // Copy to new location
+    const copyCommand = await buildCopyCommand(client, bucket, sourceKey, bucket, destinationKey, options);
 
-  return normalized.substring(lastSlashIndex + 1);
-}
+    await client.send(copyCommand);
 
-export interface UploadProgress {
-  loaded: number;
-  total: number;
-  percentage: number;
-}
+    // Delete original
+    const deleteCommand = new DeleteObjectCommand({
+      Bucket: bucket,
+      Key: sourceKey,
+    });
 
-export interface DownloadProgress {
-  loaded: number;
-  total: number;
-  percentage: number;
+    await client.send(deleteCommand);
+
+    return { success: true };
+  } catch (error) {
+    const message = describeS3Error(error);
+    return { success: false, error: message };
+  }
 }
 
-export interface FileOperationResult {
+export interface MovePrefixResult {
   success: boolean;
+  movedCount: number;
+  /** Objects that couldn't be moved; the rest of the folder is moved regardless */
+  failures: Array<{ key: string; error: string }>;
   error?: string;
 }
 
 /**
- * Downloads a file from S3 to local filesystem
+ * Moves every object under a prefix to another prefix in the same bucket, which
+ * is what renaming a folder means in S3. A destination that already holds
+ * objects is refused, so nothing there is overwritten or mixed in. Each object
+ * is copied to its rewritten key, then the originals that copied successfully
+ * are deleted in batches. A failed copy or delete is recorded and the move
+ * carries on with the other objects; an object that copied but couldn't be
+ * deleted exists in both places.
  * @param profileName - The AWS profile name to use
  * @param bucket - The S3 bucket name
- * @param key - The S3 object key
- * @param destinationPath - Local path to save the file
- * @param onProgress - Optional callback for download progress
- * @param abortSignal - Optional signal to abort the operation
+ * @param fromPrefix - The folder to move, ending in "/"
+ * @param toPrefix - The folder to move it to, ending in "/"
+ * @param onProgress - Optional callback with the number of objects copied so far
+ * @param abortSignal - Optional signal to stop copying; objects already copied are still
+ *   deleted from the old folder, so nothing is left in both places
  */
-export async function downloadFile(
+export async function movePrefix(
   profileName: string,
   bucket: string,
-  key: string,
-  destinationPath: string,
-  onProgress?: (progress: DownloadProgress) => void,
+  fromPrefix: string,
+  toPrefix: string,
+  onProgress?: (copied: number, total: number) => void,
   abortSignal?: AbortSignal
-): Promise<FileOperationResult> {
+): Promise<MovePrefixResult> {
+  if (!fromPrefix.endsWith('/') || !toPrefix.endsWith('/')) {
+    return { success: false, movedCount: 0, failures: [], error: 'Folder prefixes must end with "/"' };
+  }
+  if (fromPrefix === toPrefix) {
+    return { success: false, movedCount: 0, failures: [], error: 'The folder already has this name' };
+  }
+  if (toPrefix.startsWith(fromPrefix)) {
+    return { success: false, movedCount: 0, failures: [], error: 'A folder cannot be moved into itself' };
+  }
+
   const client = getS3Client(profileName);
 
   try {
-    // First, get the object metadata to know the total size
-    const headCommand = new HeadObjectCommand({
-      Bucket: bucket,
-      Key: key,
-    });
-    const headResponse = await client.send(headCommand);
-    const totalSize = headResponse.ContentLength || 0;
-
-    // Download the object
-    const getCommand = new GetObjectCommand({
-      Bucket: bucket,
-      Key: key,
-    });
-
-    const response = await client.send(getCommand, {
-      abortSignal,
-    });
-
-    if (!response.Body) {
-      throw new Error('Empty response body');
+    const existing = await client.send(
+      new ListObjectsV2Command({ Bucket: bucket, Prefix: toPrefix, MaxKeys: 1 }),
+      { abortSignal }
+    );
+    if ((existing.KeyCount ?? existing.Contents?.length ?? 0) > 0) {
+      return { success: false, movedCount: 0, failures: [], error: `A folder named ${toPrefix} already exists` };
     }
 
-    // Ensure the destination directory exists
-    const destDir = path.dirname(destinationPath);
-    await fs.promises.mkdir(destDir, { recursive: true });
-
-    // Create write stream
-    const writeStream = fs.createWriteStream(destinationPath);
-
-    // Track progress
-    let loaded = 0;
-    const bodyStream = response.Body as Readable;
+    const keys: string[] = [];
+    for await (const obj of iterateObjects(profileName, { bucket, prefix: fromPrefix, recursive: true }, abortSignal)) {
+      keys.push(obj.key);
+    }
+    // The listing leaves out the folder's own marker object, which moves with it when there is one
+    const marker = await headObject(profileName, bucket, fromPrefix);
+    if (marker.success) {
+      keys.push(fromPrefix);
+    }
 
-    bodyStream.on('data', (chunk: Buffer) => {
-      loaded += chunk.length;
-      if (onProgress && totalSize > 0) {
-        onProgress({
-          loaded,
-          total: totalSize,
-          percentage: Math.round((loaded / totalSize) * 100),
-        });
+    const failures: Array<{ key: string; error: string }> = [];
+    const copied: string[] = [];
+    let aborted = false;
+    for (const key of keys) {
+      if (abortSignal?.aborted) {
+        aborted = true;
+        break;
       }
-    });
+      const destinationKey = toPrefix + key.slice(fromPrefix.length);
+      try {
+        await client.send(await buildCopyCommand(client, bucket, key, bucket, destinationKey), { abortSignal });
+        copied.push(key);
+      } catch (error) {
+        if (isAbortedError(error)) {
+          aborted = true;
+          break;
+        }
+        failures.push({ key, error: describeS3Error(error) });
+      }
+      onProgress?.(copied.length + failures.length, keys.length);
+    }
 
-    // Use pipeline to properly handle streams
-    await pipeline(bodyStream, writeStream);
+    const deleted = copied.length > 0 ? await deleteFiles(profileName, bucket, copied) : null;
+    for (const result of deleted?.results ?? []) {
+      if (!result.success) {
+        failures.push({ key: result.key, error: `Copied but not deleted: ${result.error ?? 'Delete failed'}` });
+      }
+    }
 
-    return { success: true };
+    const movedCount = deleted?.deletedCount ?? 0;
+    if (aborted) {
+      return { success: false, movedCount, failures, error: OPERATION_ABORTED };
+    }
+    return { success: failures.length === 0, movedCount, failures };
   } catch (error) {
-    // Clean up partial file on error
-    try {
-      await fs.promises.unlink(destinationPath);
-    } catch {
-      // Ignore cleanup errors
+    if (isAbortedError(error)) {
+      return { success: false, movedCount: 0, failures: [], error: OPERATION_ABORTED };
     }
-
-    const message = error instanceof Error ? error.message : 'Unknown error occurred';
-    return { success: false, error: message };
+    return { success: false, movedCount: 0, failures: [], error: describeS3Error(error) };
   }
 }
 
 /**
- * Uploads a file from local filesystem to S3
+ * Copies a file within S3
  * @param profileName - The AWS profile name to use
- * @param bucket - The S3 bucket name
- * @param key - The S3 object key
- * @param sourcePath - Local path of the file to upload
- * @param onProgress - Optional callback for upload progress
- * @param abortSignal - Optional signal to abort the operation
+ * @param sourceBucket - The source S3 bucket name
+ * @param sourceKey - The source S3 object key
+ * @param destinationBucket - The destination S3 bucket name
+ * @param destinationKey - The destination S3 object key
+ * @param options - Whether the copy keeps the source's metadata (it does by default)
  */
-export async function uploadFile(
+export async function copyFile(
   profileName: string,
-  bucket: string,
-  key: string,
-  sourcePath: string,
-  onProgress?: (progress: UploadProgress) => void,
-  abortSignal?: AbortSignal
+  sourceBucket: string,
+  sourceKey: string,
+  destinationBucket: string,
+  destinationKey: string,
+  options: CopyOptions = {}
 ): Promise<FileOperationResult> {
   const client = getS3Client(profileName);
 
   try {
-    // Check if the source file exists
-    const stats = await fs.promises.stat(sourcePath);
-    const totalSize = stats.size;
+    const copyCommand = await buildCopyCommand(
+      client,
+      sourceBucket,
+      sourceKey,
+      destinationBucket,
+      destinationKey,
+      options
+    );
 
-    // Read file content
-    const fileContent = await fs.promises.readFile(sourcePath);
+    await client.send(copyCommand);
 
-    // Determine content type based on extension
-    const contentType = getContentType(key);
+    return { success: true };
+  } catch (error) {
+    const message = describeS3Error(error);
+    return { success: false, error: message };
+  }
+}
 
-    // Simple upload for files
-    const putCommand = new PutObjectCommand({
-      Bucket: bucket,
-      Key: key,
-      Body: fileContent,
-      ContentType: contentType,
-    });
+export interface TransferResult extends FileOperationResult {
+  // How the object was transferred: server-side copy or download-reupload stream
+  method?: 'server-side' | 'stream';
+}
 
-    await client.send(putCommand, {
-      abortSignal,
-    });
+/**
+ * Checks whether an error means the caller lacks access (as opposed to e.g. a missing key)
+ */
+function isAccessDeniedError(error: unknown): boolean {
+  if (!error || typeof error !== 'object') {
+    return false;
+  }
+  const err = error as { name?: string; Code?: string; $metadata?: { httpStatusCode?: number } };
+  return (
+    err.name === 'AccessDenied' ||
+    err.Code === 'AccessDenied' ||
+    err.$metadata?.httpStatusCode === 403
+  );
+}
 
-    // Report 100% progress
-    if (onProgress) {
-      onProgress({
-        loaded: totalSize,
-        total: totalSize,
-        percentage: 100,
-      });
+/**
+ * Copies an object between two profiles (possibly different accounts)
+ * Prefers a server-side copy using the destination credentials. If the destination
+ * cannot read the source, falls back to streaming the object from the source client
+ * into the destination client without buffering it in memory.
+ * @param sourceProfile - The AWS profile used to read the source object
+ * @param sourceBucket - The source S3 bucket name
+ * @param sourceKey - The source S3 object key
+ * @param destinationProfile - The AWS profile used to write the destination object
+ * @param destinationBucket - The destination S3 bucket name
+ * @param destinationKey - The destination S3 object key
+ */
+export async function transferObject(
+  sourceProfile: string,
+  sourceBucket: string,
+  sourceKey: string,
+  destinationProfile: string,
+  destinationBucket: string,
+  destinationKey: string
+): Promise<TransferResult> {
+  // Same credentials on both sides: a plain server-side copy always works
+  if (sourceProfile === destinationProfile) {
+    const result = await copyFile(sourceProfile, sourceBucket, sourceKey, destinationBucket, destinationKey);
+    return { ...result, method: result.success ? 'server-side' : undefined };
+  }
+
+  try {
+    const sourceClient = createS3Client(sourceProfile);
+    const destinationClient = createS3Client(destinationProfile);
+
+    // Server-side copy succeeds when the destination has read access to the source
+    try {
+      await destinationClient.send(
+        await buildCopyCommand(destinationClient, sourceBucket, sourceKey, destinationBucket, destinationKey)
+      );
+      return { success: true, method: 'server-side' };
+    } catch (error) {
+      if (!isAccessDeniedError(error)) {
+        throw error;
+      }
+    }
+
+    // Fall back to stream-through: GET from source, PUT to destination
+    const getResponse = await sourceClient.send(
+      new GetObjectCommand({
+        Bucket: sourceBucket,
+        Key: sourceKey,
+      })
+    );
+
+    if (!getResponse.Body) {
+      throw new Error('Empty response body');
     }
 
-    return { success: true };
-  } catch (error) {
-    const message = error instanceof Error ? error.message : 'Unknown error occurred';
-    return { success: false, error: message };
+    await destinationClient.send(
+      new PutObjectCommand({
+        Bucket: destinationBucket,
+        Key: destinationKey,
+        Body: getResponse.Body as Readable,
+        // Streaming bodies need an explicit length to be uploaded in a single PUT
+        ContentLength: getResponse.ContentLength,
+        ContentType: getResponse.ContentType || guessMimeType(destinationKey),
+        Metadata: getResponse.Metadata,
+        StorageClass: getResponse.StorageClass,
+      })
+    );
+
+    return { success: true, method: 'stream' };
+  } catch (error) {
+    const message = describeS3Error(error);
+    return { success: false, error: message };
+  }
+}
+
+/**
+ * The headers of an object that callers commonly need, from a single HEAD request
+ */
+export interface ObjectHead {
+  contentLength: number;
+  contentType?: string;
+  etag?: string;
+  lastModified?: Date;
+  storageClass?: string;
+  /** Restore state of an archived object, absent when no restore was requested */
+  restore?: RestoreStatus;
+}
+
+/**
+ * Checks whether an error means the object doesn't exist. HEAD responses have no
+ * body, so a missing key surfaces as NotFound (or just a 404) rather than NoSuchKey.
+ */
+export function isNotFoundError(error: unknown): boolean {
+  if (!error || typeof error !== 'object') {
+    return false;
   }
+  const err = error as { name?: string; Code?: string; $metadata?: { httpStatusCode?: number } };
+  return (
+    err.name === 'NotFound' ||
+    err.name === 'NoSuchKey' ||
+    err.Code === 'NoSuchKey' ||
+    err.$metadata?.httpStatusCode === 404
+  );
 }
 
 /**
- * Uploads content directly to S3 (for editor save)
+ * Reads an object's size, type, ETag, modification time and storage class without
+ * downloading it. A missing object is reported with notFound set, so callers can
+ * tell it apart from access or network errors.
  * @param profileName - The AWS profile name to use
  * @param bucket - The S3 bucket name
  * @param key - The S3 object key
- * @param content - The content to upload
+ * @param versionId - Version to read instead of the latest one
  */
-export async function uploadContent(
+export async function headObject(
   profileName: string,
   bucket: string,
   key: string,
-  content: string | Buffer
-): Promise<FileOperationResult> {
+  versionId?: string
+): Promise<{ success: boolean; head?: ObjectHead; notFound?: boolean; error?: string }> {
   const client = getS3Client(profileName);
 
   try {
-    const contentType = getContentType(key);
+    const response = await client.send(new HeadObjectCommand({ Bucket: bucket, Key: key, VersionId: versionId }));
+    return {
+      success: true,
+      head: {
+        contentLength: response.ContentLength ?? 0,
+        contentType: response.ContentType,
+        etag: response.ETag?.replace(/"/g, ''),
+        lastModified: response.LastModified,
+        storageClass: response.StorageClass,
+        restore: parseRestoreHeader(response.Restore) ?? undefined,
+      },
+    };
+  } catch (error) {
+    if (isNotFoundError(error)) {
+      return { success: false, notFound: true, error: `Object not found: s3://${bucket}/${key}` };
+    }
+    return { success: false, error: describeS3Error(error) };
+  }
+}
 
-    const putCommand = new PutObjectCommand({
-      Bucket: bucket,
-      Key: key,
-      Body: typeof content === 'string' ? Buffer.from(content, 'utf-8') : content,
-      ContentType: contentType,
-    });
+/** Retrieval speed of a restore from Glacier Flexible Retrieval or Deep Archive */
+export type RestoreTier = 'Expedited' | 'Standard' | 'Bulk';
 
-    await client.send(putCommand);
+export const RESTORE_TIERS: RestoreTier[] = ['Expedited', 'Standard', 'Bulk'];
 
-    return { success: true };
-  } catch (error) {
-    const message = error instanceof Error ? error.message : 'Unknown error occurred';
-    return { success: false, error: message };
+/** Storage classes whose objects must be restored before they can be read */
+const ARCHIVED_STORAGE_CLASSES = new Set(['GLACIER', 'DEEP_ARCHIVE']);
+
+/**
+ * Whether objects of the storage class need a restore before they can be downloaded.
+ * Glacier Instant Retrieval is readable directly, like Standard.
+ */
+export function isArchivedStorageClass(storageClass: string | undefined): boolean {
+  return !!storageClass && ARCHIVED_STORAGE_CLASSES.has(storageClass);
+}
+
+export interface RestoreStatus {
+  /** Whether the restore is still running */
+  ongoing: boolean;
+  /** When the restored copy is removed again, once the restore has completed */
+  expiry?: Date;
+}
+
+/**
+ * Parses the x-amz-restore header of a HEAD response, e.g.
+ * `ongoing-request="false", expiry-date="Fri, 21 Dec 2012 00:00:00 GMT"`
+ * @returns The restore state, or null when the header is absent or unreadable
+ */
+export function parseRestoreHeader(value: string | undefined): RestoreStatus | null {
+  if (!value) {
+    return null;
   }
+  const ongoing = value.match(/ongoing-request\s*=\s*"(true|false)"/i);
+  if (!ongoing) {
+    return null;
+  }
+  const status: RestoreStatus = { ongoing: ongoing[1].toLowerCase() === 'true' };
+  const expiry = value.match(/expiry-date\s*=\s*"([^"]+)"/i);
+  if (expiry) {
+    const expiryMs = Date.parse(expiry[1]);
+    if (!Number.isNaN(expiryMs)) {
+      status.expiry = new Date(expiryMs);
+    }
+  }
+  return status;
 }
 
 /**
- * Downloads file content directly (for editor load)
+ * Reads whether a restore of an object is in progress or complete
  * @param profileName - The AWS profile name to use
  * @param bucket - The S3 bucket name
  * @param key - The S3 object key
+ * @returns The restore state, with restore unset when no restore was requested
  */
-export async function downloadContent(
+export async function getRestoreStatus(
   profileName: string,
   bucket: string,
   key: string
-): Promise<{ success: boolean; content?: string; error?: string }> {
-  const client = getS3Client(profileName);
-
-  try {
-    const getCommand = new GetObjectCommand({
-      Bucket: bucket,
-      Key: key,
-    });
-
-    const response = await client.send(getCommand);
-
-    if (!response.Body) {
-      throw new Error('Empty response body');
-    }
-
-    // Convert stream to string
-    const chunks: Buffer[] = [];
-    const bodyStream = response.Body as Readable;
-
-    for await (const chunk of bodyStream) {
-      chunks.push(Buffer.from(chunk));
-    }
-
-    const content = Buffer.concat(chunks).toString('utf-8');
-
-    return { success: true, content };
-  } catch (error) {
-    const message = error instanceof Error ? error.message : 'Unknown error occurred';
-    return { success: false, error: message };
+): Promise<{ success: boolean; storageClass?: string; restore?: RestoreStatus; error?: string }> {
+  const result = await headObject(profileName, bucket, key);
+  if (!result.success || !result.head) {
+    return { success: false, error: result.error };
   }
+  return { success: true, storageClass: result.head.storageClass, restore: result.head.restore };
 }
 
 /**
- * Deletes a file from S3
+ * Requests a temporary copy of an archived object, readable for the given number of days
  * @param profileName - The AWS profile name to use
  * @param bucket - The S3 bucket name
  * @param key - The S3 object key
+ * @param days - How long the restored copy is kept
+ * @param tier - Retrieval speed (faster tiers cost more)
  */
-export async function deleteFile(
+export async function restoreObject(
   profileName: string,
   bucket: string,
-  key: string
+  key: string,
+  days: number,
+  tier: RestoreTier
 ): Promise<FileOperationResult> {
+  if (!Number.isInteger(days) || days < 1) {
+    return { success: false, error: 'Restore days must be a positive whole number' };
+  }
+  if (!RESTORE_TIERS.includes(tier)) {
+    return { success: false, error: `Unknown restore tier: ${tier}` };
+  }
+
   const client = getS3Client(profileName);
 
   try {
-    const deleteCommand = new DeleteObjectCommand({
-      Bucket: bucket,
-      Key: key,
-    });
-
-    await client.send(deleteCommand);
-
+    await client.send(
+      new RestoreObjectCommand({
+        Bucket: bucket,
+        Key: key,
+        RestoreRequest: {
+          Days: days,
+          GlacierJobParameters: { Tier: tier },
+        },
+      })
+    );
     return { success: true };
   } catch (error) {
-    const message = error instanceof Error ? error.message : 'Unknown error occurred';
-    return { success: false, error: message };
+    const code = (error as { Code?: string }).Code ?? (error as Error)?.name;
+    if (code === 'RestoreAlreadyInProgress') {
+      return { success: false, error: 'A restore of this object is already in progress' };
+    }
+    return { success: false, error: describeS3Error(error) };
   }
 }
 
-export interface DeleteFilesResult {
-  success: boolean;
-  results: Array<{ key: string; success: boolean; error?: string }>;
-  deletedCount: number;
-  failedCount: number;
+/** Longest validity S3 accepts for a SigV4 presigned URL (7 days) */
+export const MAX_PRESIGN_EXPIRY_SECONDS = 7 * 24 * 60 * 60;
+
+/**
+ * Checks a presigned URL expiry, returning a message when it is not allowed
+ */
+function validatePresignExpiry(expiresInSeconds: number): string | null {
+  if (!Number.isInteger(expiresInSeconds) || expiresInSeconds <= 0) {
+    return 'Link expiry must be a positive whole number of seconds';
+  }
+  if (expiresInSeconds > MAX_PRESIGN_EXPIRY_SECONDS) {
+    return 'Presigned links can be valid for at most 7 days';
+  }
+  return null;
 }
 
 /**
- * Deletes multiple files from S3
+ * Creates a time-limited HTTPS link that downloads the object without credentials.
+ * The link is signed locally with the profile's credentials; nothing is sent to S3.
  * @param profileName - The AWS profile name to use
  * @param bucket - The S3 bucket name
- * @param keys - Array of S3 object keys to delete
+ * @param key - The S3 object key
+ * @param expiresInSeconds - How long the link stays valid, at most 7 days
  */
-export async function deleteFiles(
+export async function presignGetUrl(
   profileName: string,
   bucket: string,
-  keys: string[]
-): Promise<DeleteFilesResult> {
-  const results: Array<{ key: string; success: boolean; error?: string }> = [];
-  let deletedCount = 0;
-  let failedCount = 0;
-
-  // Delete files one by one (could use DeleteObjectsCommand for batch, but this gives per-file feedback)
-  for (const key of keys) {
-    const result = await deleteFile(profileName, bucket, key);
-    if (result.success) {
-      results.push({ key, success: true });
-      deletedCount++;
-    } else {
-      results.push({ key, success: false, error: result.error });
-      failedCount++;
-    }
+  key: string,
+  expiresInSeconds: number
+): Promise<{ success: boolean; url?: string; error?: string }> {
+  const invalid = validatePresignExpiry(expiresInSeconds);
+  if (invalid) {
+    return { success: false, error: invalid };
   }
 
-  return {
-    success: failedCount === 0,
-    results,
-    deletedCount,
-    failedCount,
-  };
-}
+  const client = getS3Client(profileName);
 
-export interface DeletePrefixResult {
-  success: boolean;
-  deletedCount: number;
-  failedCount: number;
-  error?: string;
+  try {
+    const url = await getSignedUrl(client, new GetObjectCommand({ Bucket: bucket, Key: key }), {
+      expiresIn: expiresInSeconds,
+    });
+    return { success: true, url };
+  } catch (error) {
+    return { success: false, error: describeS3Error(error) };
+  }
 }
 
 /**
- * Deletes all objects under a prefix (folder) in S3
- * This recursively lists and deletes all objects with the given prefix
+ * Creates a time-limited HTTPS link that uploads to the key with a plain HTTP PUT,
+ * for handing an upload to someone without credentials. A content type is signed
+ * into the request, so the uploader must send the same Content-Type header.
  * @param profileName - The AWS profile name to use
  * @param bucket - The S3 bucket name
- * @param prefix - The prefix (folder) to delete
- * @param onProgress - Optional callback for progress updates
- * @param abortSignal - Optional signal to abort the operation
+ * @param key - The S3 object key (not URL-encoded; the signer encodes it)
+ * @param expiresInSeconds - How long the link stays valid, at most 7 days
+ * @param contentType - Content type the upload must declare, if any
  */
-export async function deletePrefix(
+export async function presignPutUrl(
   profileName: string,
   bucket: string,
-  prefix: string,
-  onProgress?: (deleted: number, total: number) => void,
-  abortSignal?: AbortSignal
-): Promise<DeletePrefixResult> {
-  try {
-    // First, list all objects under this prefix (no delimiter to get all nested objects)
-    const allObjects: S3Object[] = [];
-    let continuationToken: string | undefined;
-
-    // List all objects recursively
-    do {
-      if (abortSignal?.aborted) {
-        return { success: false, deletedCount: 0, failedCount: 0, error: 'Operation aborted' };
-      }
-
-      const result = await listObjects(profileName, {
-        bucket,
-        prefix,
-        delimiter: '', // No delimiter - we want ALL nested objects recursively
-        maxKeys: MAX_PAGE_SIZE,
-        continuationToken,
-      });
-
-      allObjects.push(...result.objects);
-      // Also include the prefixes as potential empty folder markers
-      allObjects.push(...result.prefixes);
-      continuationToken = result.continuationToken;
-    } while (continuationToken);
-
-    if (allObjects.length === 0) {
-      // No objects to delete, but the prefix marker itself might exist
-      // Try to delete the prefix key itself (e.g., "folder/")
-      const deleteResult = await deleteFile(profileName, bucket, prefix);
-      return {
-        success: deleteResult.success,
-        deletedCount: deleteResult.success ? 1 : 0,
-        failedCount: deleteResult.success ? 0 : 1,
-        error: deleteResult.error,
-      };
-    }
-
-    // Delete all objects
-    let deletedCount = 0;
-    let failedCount = 0;
-    const total = allObjects.length;
-
-    for (const obj of allObjects) {
-      if (abortSignal?.aborted) {
-        return {
-          success: false,
-          deletedCount,
-          failedCount: failedCount + (total - deletedCount - failedCount),
-          error: 'Operation aborted',
-        };
-      }
-
-      const result = await deleteFile(profileName, bucket, obj.key);
-      if (result.success) {
-        deletedCount++;
-      } else {
-        failedCount++;
-      }
-      onProgress?.(deletedCount + failedCount, total);
-    }
+  key: string,
+  expiresInSeconds: number,
+  contentType?: string
+): Promise<{ success: boolean; url?: string; error?: string }> {
+  const invalid = validatePresignExpiry(expiresInSeconds);
+  if (invalid) {
+    return { success: false, error: invalid };
+  }
 
-    // Also try to delete the prefix marker itself
-    await deleteFile(profileName, bucket, prefix);
+  // The SDK would otherwise sign a checksum of the empty body into the URL,
+  // so S3 would reject the upload of any real content
+  let client = presignPutClients.get(profileName);
+  if (!client) {
+    client = createS3Client(profileName, undefined, { requestChecksumCalculation: 'WHEN_REQUIRED' });
+    presignPutClients.set(profileName, client);
+  }
 
-    return {
-      success: failedCount === 0,
-      deletedCount,
-      failedCount,
-    };
+  try {
+    const command = new PutObjectCommand({
+      Bucket: bucket,
+      Key: key,
+      ...(contentType ? { ContentType: contentType } : {}),
+    });
+    const url = await getSignedUrl(client, command, { expiresIn: expiresInSeconds });
+    return { success: true, url };
   } catch (error) {
-    const message = error instanceof Error ? error.message : 'Unknown error occurred';
-    return { success: false, deletedCount: 0, failedCount: 0, error: message };
+    return { success: false, error: describeS3Error(error) };
   }
 }
 
 /**
- * Renames (copies then deletes) a file in S3
+ * Gets the file size from S3 without downloading
+ */
+export async function getFileSize(
+  profileName: string,
+  bucket: string,
+  key: string,
+  versionId?: string
+): Promise<{ success: boolean; size?: number; notFound?: boolean; error?: string }> {
+  const result = await headObject(profileName, bucket, key, versionId);
+  if (!result.success || !result.head) {
+    return { success: false, notFound: result.notFound, error: result.error };
+  }
+  return { success: true, size: result.head.contentLength };
+}
+
+/**
+ * Downloads file content as raw binary buffer (for parquet/binary files)
  * @param profileName - The AWS profile name to use
  * @param bucket - The S3 bucket name
- * @param sourceKey - The current S3 object key
- * @param destinationKey - The new S3 object key
+ * @param key - The S3 object key
+ * @param versionId - Version to read instead of the latest one
  */
-export async function renameFile(
+export async function downloadBinaryContent(
   profileName: string,
   bucket: string,
-  sourceKey: string,
-  destinationKey: string
-): Promise<FileOperationResult> {
+  key: string,
+  versionId?: string
+): Promise<{ success: boolean; data?: Buffer; etag?: string; lastModified?: Date; error?: string }> {
   const client = getS3Client(profileName);
 
   try {
-    // Copy to new location
-    const copyCommand = new CopyObjectCommand({
+    const getCommand = new GetObjectCommand({
       Bucket: bucket,
-      Key: destinationKey,
-      CopySource: encodeURIComponent(`${bucket}/${sourceKey}`),
+      Key: key,
+      VersionId: versionId,
     });
 
-    await client.send(copyCommand);
+    const response = await client.send(getCommand);
 
-    // Delete original
-    const deleteCommand = new DeleteObjectCommand({
-      Bucket: bucket,
-      Key: sourceKey,
-    });
+    if (!response.Body) {
+      throw new Error('Empty response body');
+    }
+
+    // Convert stream to buffer
+    const chunks: Buffer[] = [];
+    const bodyStream = response.Body as Readable;
+
+    for await (const chunk of bodyStream) {
+      chunks.push(Buffer.from(chunk));
+    }
+
+    const buffer = Buffer.concat(chunks);
+
+    return { success: true, data: buffer, etag: response.ETag, lastModified: response.LastModified };
+  } catch (error) {
+    const message = describeS3Error(error);
+    return { success: false, error: message };
+  }
+}
+
+/**
+ * Validators from an earlier download of an object, sent with a conditional GET
+ */
+export interface DownloadConditions {
+  /** ETag of the copy already held; sent as If-None-Match */
+  etag?: string;
+  /** Last-Modified of the copy already held; sent as If-Modified-Since */
+  modifiedSince?: Date;
+}
 
-    await client.send(deleteCommand);
+export interface ConditionalDownloadResult {
+  success: boolean;
+  /** The object is unchanged since the given validators, so no body was sent */
+  notModified?: boolean;
+  data?: Buffer;
+  etag?: string;
+  lastModified?: Date;
+  error?: string;
+}
 
-    return { success: true };
-  } catch (error) {
-    const message = error instanceof Error ? error.message : 'Unknown error occurred';
-    return { success: false, error: message };
-  }
+function isNotModifiedError(error: unknown): boolean {
+  const err = error as { name?: string; $metadata?: { httpStatusCode?: number } };
+  return err?.name === 'NotModified' || err?.$metadata?.httpStatusCode === 304;
 }
 
 /**
- * Copies a file within S3
+ * Downloads an object as a binary buffer unless it is unchanged since an
+ * earlier download. The SDK surfaces S3's 304 response as an error, which is
+ * reported as `notModified` rather than a failure.
  * @param profileName - The AWS profile name to use
- * @param sourceBucket - The source S3 bucket name
- * @param sourceKey - The source S3 object key
- * @param destinationBucket - The destination S3 bucket name
- * @param destinationKey - The destination S3 object key
+ * @param bucket - The S3 bucket name
+ * @param key - The S3 object key
+ * @param conditions - Validators of the copy already held
  */
-export async function copyFile(
+export async function downloadBinaryContentIfModified(
   profileName: string,
-  sourceBucket: string,
-  sourceKey: string,
-  destinationBucket: string,
-  destinationKey: string
-): Promise<FileOperationResult> {
+  bucket: string,
+  key: string,
+  conditions: DownloadConditions
+): Promise<ConditionalDownloadResult> {
+  if (!conditions.etag && !conditions.modifiedSince) {
+    return downloadBinaryContent(profileName, bucket, key);
+  }
+
   const client = getS3Client(profileName);
 
   try {
-    const copyCommand = new CopyObjectCommand({
-      Bucket: destinationBucket,
-      Key: destinationKey,
-      CopySource: encodeURIComponent(`${sourceBucket}/${sourceKey}`),
-    });
+    const response = await client.send(
+      new GetObjectCommand({
+        Bucket: bucket,
+        Key: key,
+        // If-None-Match takes precedence over If-Modified-Since, so only one is sent
+        IfNoneMatch: conditions.etag,
+        IfModifiedSince: conditions.etag ? undefined : conditions.modifiedSince,
+      })
+    );
 
-    await client.send(copyCommand);
+    if (!response.Body) {
+      throw new Error('Empty response body');
+    }
 
-    return { success: true };
+    const chunks: Buffer[] = [];
+    for await (const chunk of response.Body as Readable) {
+      chunks.push(Buffer.from(chunk));
+    }
+
+    return { success: true, data: Buffer.concat(chunks), etag: response.ETag, lastModified: response.LastModified };
   } catch (error) {
-    const message = error instanceof Error ? error.message : 'Unknown error occurred';
-    return { success: false, error: message };
+    if (isNotModifiedError(error)) {
+      return { success: true, notModified: true };
+    }
+    return { success: false, error: describeS3Error(error) };
   }
 }
 
-/**
- * Gets the content type based on file extension
- */
-function getContentType(key: string): string {
-  const ext = key.split('.').pop()?.toLowerCase() ?? '';
-  const contentTypes: Record<string, string> = {
-    // Text
-    txt: 'text/plain',
-    html: 'text/html',
-    css: 'text/css',
-    csv: 'text/csv',
-    // Code
-    js: 'application/javascript',
-    json: 'application/json',
-    xml: 'application/xml',
-    yaml: 'text/yaml',
-    yml: 'text/yaml',
-    ts: 'text/typescript',
-    tsx: 'text/typescript',
-    py: 'text/x-python',
-    java: 'text/x-java',
-    md: 'text/markdown',
-    // Images
-    png: 'image/png',
-    jpg: 'image/jpeg',
-    jpeg: 'image/jpeg',
-    gif: 'image/gif',
-    webp: 'image/webp',
-    svg: 'image/svg+xml',
-    ico: 'image/x-icon',
-    // Binary
-    pdf: 'application/pdf',
-    zip: 'application/zip',
-    gz: 'application/gzip',
-    tar: 'application/x-tar',
-    parquet: 'application/x-parquet',
-  };
-
-  return contentTypes[ext] || 'application/octet-stream';
+export interface VerifiedDownloadResult {
+  success: boolean;
+  data?: Buffer;
+  /** Whether the body was compared with the stored checksum or the ETag */
+  verified?: boolean;
+  /** What the body was compared with, when verified */
+  verifiedAgainst?: 'SHA-256' | 'ETag';
+  /** The downloaded body doesn't match the stored checksum */
+  checksumMismatch?: boolean;
+  error?: string;
 }
 
 /**
- * Gets the file size from S3 without downloading
+ * Downloads an object and checks its SHA-256 against the checksum S3 stored
+ * when it was uploaded, which a GET in checksum mode returns with the body.
+ * Objects uploaded without a SHA-256 checksum (including most older objects)
+ * are compared with their ETag instead, which is the MD5 of the body for
+ * single-part uploads that aren't encrypted with SSE-KMS or SSE-C. When neither
+ * can be checked, verification is skipped and `verified` is false. Multipart
+ * uploads store a checksum of the part checksums ("<base64>-<parts>") and an
+ * ETag of the part MD5s, neither of which can be recomputed from the body.
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param key - The S3 object key
  */
-export async function getFileSize(
+export async function downloadBinaryContentVerified(
   profileName: string,
   bucket: string,
   key: string
-): Promise<{ success: boolean; size?: number; error?: string }> {
+): Promise<VerifiedDownloadResult> {
   const client = getS3Client(profileName);
 
+  let data: Buffer;
+  let stored: string | undefined;
+  let etag: string | undefined;
   try {
-    const headCommand = new HeadObjectCommand({
-      Bucket: bucket,
-      Key: key,
-    });
-
-    const response = await client.send(headCommand);
+    const response = await client.send(
+      new GetObjectCommand({ Bucket: bucket, Key: key, ChecksumMode: 'ENABLED' })
+    );
+    if (!response.Body) {
+      throw new Error('Empty response body');
+    }
 
-    return { success: true, size: response.ContentLength || 0 };
+    const chunks: Buffer[] = [];
+    for await (const chunk of response.Body as Readable) {
+      chunks.push(Buffer.from(chunk));
+    }
+    data = Buffer.concat(chunks);
+    stored = response.ChecksumSHA256;
+    // With SSE-KMS or SSE-C the ETag is not an MD5 of the content
+    const encrypted = response.ServerSideEncryption?.startsWith('aws:kms') || !!response.SSECustomerAlgorithm;
+    etag = encrypted ? undefined : response.ETag;
   } catch (error) {
-    const message = error instanceof Error ? error.message : 'Unknown error occurred';
-    return { success: false, error: message };
+    // The SDK validates checksums it supports as the body streams in, and fails the same way
+    const checksumMismatch = error instanceof Error && error.message.startsWith('Checksum mismatch');
+    return { success: false, error: describeS3Error(error), ...(checksumMismatch ? { checksumMismatch } : {}) };
+  }
+
+  if (!stored || stored.includes('-')) {
+    const etagMatches = etag ? md5MatchesEtag(etag, data) : null;
+    if (etagMatches === null) {
+      return { success: true, data, verified: false };
+    }
+    if (!etagMatches) {
+      return {
+        success: false,
+        checksumMismatch: true,
+        error: `Checksum mismatch: downloaded content does not match the MD5 ETag ${etag}`,
+      };
+    }
+    return { success: true, data, verified: true, verifiedAgainst: 'ETag' };
+  }
+
+  const actual = computeChecksum(data, 'SHA256');
+  if (actual !== stored) {
+    return {
+      success: false,
+      checksumMismatch: true,
+      error: `Checksum mismatch: S3 stored SHA-256 ${stored}, downloaded content has ${actual}`,
+    };
   }
+  return { success: true, data, verified: true, verifiedAgainst: 'SHA-256' };
 }
 
 /**
- * Downloads file content as raw binary buffer (for parquet/binary files)
+ * Downloads part of an object using a ranged GET (for content sniffing, hex preview
+ * and reading Parquet footers)
  * @param profileName - The AWS profile name to use
  * @param bucket - The S3 bucket name
  * @param key - The S3 object key
+ * @param length - Maximum number of bytes to fetch, or undefined for the rest of the object
+ * @param start - Offset of the first byte to fetch
+ * @param versionId - Version to read instead of the latest one
  */
-export async function downloadBinaryContent(
+export async function downloadPartialContent(
   profileName: string,
   bucket: string,
-  key: string
+  key: string,
+  length?: number,
+  start: number = 0,
+  versionId?: string
 ): Promise<{ success: boolean; data?: Buffer; error?: string }> {
+  let range: string;
+  try {
+    range = formatByteRange(start, length === undefined ? undefined : start + Math.max(length, 1) - 1);
+  } catch (error) {
+    return { success: false, error: (error as Error).message };
+  }
+
   const client = getS3Client(profileName);
 
   try {
     const getCommand = new GetObjectCommand({
       Bucket: bucket,
       Key: key,
+      Range: range,
+      VersionId: versionId,
     });
 
     const response = await client.send(getCommand);
@@ -936,7 +2989,6 @@ export async function downloadBinaryContent(
       throw new Error('Empty response body');
     }
 
-    // Convert stream to buffer
     const chunks: Buffer[] = [];
     const bodyStream = response.Body as Readable;
 
@@ -944,15 +2996,98 @@ export async function downloadBinaryContent(
       chunks.push(Buffer.from(chunk));
     }
 
-    const buffer = Buffer.concat(chunks);
+    let buffer = Buffer.concat(chunks);
+    // Servers that ignore the Range header return the whole object without a Content-Range
+    if (!response.ContentRange) {
+      buffer = buffer.subarray(start);
+    }
 
-    return { success: true, data: buffer };
+    return { success: true, data: length === undefined ? buffer : buffer.subarray(0, length) };
   } catch (error) {
-    const message = error instanceof Error ? error.message : 'Unknown error occurred';
+    const status = (error as { $metadata?: { httpStatusCode?: number } })?.$metadata?.httpStatusCode;
+    if ((error instanceof Error && error.name === 'InvalidRange') || status === 416) {
+      // Ranged GETs on empty objects fail with InvalidRange too
+      if (start === 0) {
+        return { success: true, data: Buffer.alloc(0) };
+      }
+      return {
+        success: false,
+        error: `Range not satisfiable: ${range} starts past the end of ${key}`,
+      };
+    }
+    const message = describeS3Error(error);
     return { success: false, error: message };
   }
 }
 
+/**
+ * Builds the value of a Range header for bytes start through end, inclusive
+ * @param start - First byte offset
+ * @param end - Last byte offset, or undefined for the rest of the object
+ * @throws Error if the offsets are not non-negative integers with end >= start
+ */
+export function formatByteRange(start: number, end?: number): string {
+  if (!Number.isSafeInteger(start) || start < 0) {
+    throw new Error(`Invalid range start: ${start}`);
+  }
+  if (end === undefined) {
+    return `bytes=${start}-`;
+  }
+  if (!Number.isSafeInteger(end) || end < start) {
+    throw new Error(`Invalid range end: ${end}`);
+  }
+  return `bytes=${start}-${end}`;
+}
+
+/**
+ * Resolves a website redirect location to the S3 object it points to
+ * Handles in-bucket keys ("/docs/index.html") as well as s3://, REST and website endpoint URLs
+ * @param bucket - The bucket holding the redirecting object
+ * @param location - The x-amz-website-redirect-location value
+ * @returns Bucket and key of the target, or null if it points outside S3
+ */
+export function getRedirectTarget(
+  bucket: string,
+  location: string | undefined
+): { bucket: string; key: string } | null {
+  const value = location?.trim();
+  if (!value) {
+    return null;
+  }
+
+  const decode = (path: string): string => {
+    try {
+      return decodeURIComponent(path);
+    } catch {
+      return path;
+    }
+  };
+
+  // Relative locations are keys in the same bucket
+  if (value.startsWith('/')) {
+    return { bucket, key: decode(value.replace(/^\/+/, '').split(/[?#]/)[0]) };
+  }
+
+  if (value.startsWith('s3://')) {
+    return parseS3Url(value);
+  }
+
+  if (!/^https?:\/\//i.test(value)) {
+    return null;
+  }
+
+  const url = value.split(/[?#]/)[0];
+
+  // Website endpoints: bucket.s3-website-region.amazonaws.com or bucket.s3-website.region.amazonaws.com
+  const websiteMatch = url.match(/^https?:\/\/(.+)\.s3-website[-.][a-z0-9-]+\.amazonaws\.com(?:\/(.*))?$/i);
+  if (websiteMatch) {
+    return { bucket: websiteMatch[1], key: decode(websiteMatch[2] || '') };
+  }
+
+  const parsed = parseS3Url(url);
+  return parsed ? { bucket: parsed.bucket, key: decode(parsed.key) } : null;
+}
+
 /**
  * Object metadata returned by getObjectMetadata
  */
@@ -967,13 +3102,22 @@ export interface ObjectMetadata {
   contentType?: string;
   lastModified?: Date;
   etag?: string;
+  // Number of parts when the object was uploaded in parts (the ETag is then not an MD5 of the content)
+  etagPartCount?: number;
   storageClass?: string;
+  // Restore state of an archived object (x-amz-restore), if a restore was requested
+  restore?: RestoreStatus;
   // Additional metadata
   versionId?: string;
   serverSideEncryption?: string;
   contentEncoding?: string;
   cacheControl?: string;
   expires?: Date;
+  // Website redirect (x-amz-website-redirect-location) and the S3 object it points to, if any
+  websiteRedirectLocation?: string;
+  redirectTarget?: { bucket: string; key: string };
+  // Additional checksums stored with the object (base64), keyed by algorithm
+  checksums: Record<string, string>;
   // Tags
   tags: Record<string, string>;
   // Custom metadata headers (x-amz-meta-*)
@@ -994,7 +3138,7 @@ export async function createEmptyFile(
   const client = getS3Client(profileName);
 
   try {
-    const contentType = getContentType(key);
+    const contentType = guessMimeType(key);
 
     const putCommand = new PutObjectCommand({
       Bucket: bucket,
@@ -1007,7 +3151,7 @@ export async function createEmptyFile(
 
     return { success: true };
   } catch (error) {
-    const message = error instanceof Error ? error.message : 'Unknown error occurred';
+    const message = describeS3Error(error);
     return { success: false, error: message };
   }
 }
@@ -1040,7 +3184,7 @@ export async function createFolder(
 
     return { success: true };
   } catch (error) {
-    const message = error instanceof Error ? error.message : 'Unknown error occurred';
+    const message = describeS3Error(error);
     return { success: false, error: message };
   }
 }
@@ -1055,14 +3199,15 @@ export async function getObjectMetadata(
   profileName: string,
   bucket: string,
   key: string
-): Promise<{ success: boolean; metadata?: ObjectMetadata; error?: string }> {
+): Promise<{ success: boolean; metadata?: ObjectMetadata; notFound?: boolean; error?: string }> {
   const client = getS3Client(profileName);
 
   try {
-    // Get head object metadata
+    // Get head object metadata (checksum mode returns stored additional checksums)
     const headCommand = new HeadObjectCommand({
       Bucket: bucket,
       Key: key,
+      ChecksumMode: 'ENABLED',
     });
     const headResponse = await client.send(headCommand);
 
@@ -1096,28 +3241,344 @@ export async function getObjectMetadata(
       });
     }
 
+    const checksums: Record<string, string> = {};
+    if (headResponse.ChecksumCRC32) checksums.CRC32 = headResponse.ChecksumCRC32;
+    if (headResponse.ChecksumCRC32C) checksums.CRC32C = headResponse.ChecksumCRC32C;
+    if (headResponse.ChecksumSHA1) checksums.SHA1 = headResponse.ChecksumSHA1;
+    if (headResponse.ChecksumSHA256) checksums.SHA256 = headResponse.ChecksumSHA256;
+
     const metadata: ObjectMetadata = {
       key,
       bucket,
-      s3Url: `s3://${bucket}/${key}`,
-      httpUrl: `https://${bucket}.s3.amazonaws.com/${encodeURIComponent(key)}`,
+      s3Url: formatS3Uri(bucket, key),
+      httpUrl: formatS3HttpsUrl(bucket, key, bucketRegions.get(bucketRegionKey(profileName, bucket))),
       contentLength: headResponse.ContentLength,
       contentType: headResponse.ContentType,
       lastModified: headResponse.LastModified,
       etag: headResponse.ETag?.replace(/"/g, ''),
+      etagPartCount: headResponse.ETag ? getEtagPartCount(headResponse.ETag) ?? undefined : undefined,
       storageClass: headResponse.StorageClass,
+      restore: parseRestoreHeader(headResponse.Restore) ?? undefined,
       versionId: headResponse.VersionId,
       serverSideEncryption: headResponse.ServerSideEncryption,
       contentEncoding: headResponse.ContentEncoding,
       cacheControl: headResponse.CacheControl,
       expires: headResponse.Expires,
+      websiteRedirectLocation: headResponse.WebsiteRedirectLocation,
+      redirectTarget: getRedirectTarget(bucket, headResponse.WebsiteRedirectLocation) ?? undefined,
+      checksums,
       tags,
       customMetadata,
     };
 
     return { success: true, metadata };
   } catch (error) {
-    const message = error instanceof Error ? error.message : 'Unknown error occurred';
+    if (isNotFoundError(error)) {
+      return { success: false, notFound: true, error: `Object not found: s3://${bucket}/${key}` };
+    }
+    const message = describeS3Error(error);
     return { success: false, error: message };
   }
 }
+
+/** Most tags S3 allows on one object */
+export const MAX_OBJECT_TAGS = 10;
+/** Longest tag key S3 accepts, in characters */
+export const MAX_TAG_KEY_LENGTH = 128;
+/** Longest tag value S3 accepts, in characters */
+export const MAX_TAG_VALUE_LENGTH = 256;
+
+/**
+ * Checks a tag set against the limits S3 enforces on object tags
+ * @returns The first problem found, or null when S3 will accept the tags
+ */
+export function validateObjectTags(tags: Record<string, string>): string | null {
+  const entries = Object.entries(tags);
+  if (entries.length > MAX_OBJECT_TAGS) {
+    return `Objects can have at most ${MAX_OBJECT_TAGS} tags`;
+  }
+  for (const [key, value] of entries) {
+    // Lengths count characters, not UTF-16 code units
+    if (key.trim().length === 0) {
+      return 'Tag keys must not be empty';
+    }
+    if ([...key].length > MAX_TAG_KEY_LENGTH) {
+      return `Tag key "${key.slice(0, 20)}..." is longer than ${MAX_TAG_KEY_LENGTH} characters`;
+    }
+    if ([...value].length > MAX_TAG_VALUE_LENGTH) {
+      return `The value of tag "${key}" is longer than ${MAX_TAG_VALUE_LENGTH} characters`;
+    }
+    if (key.toLowerCase().startsWith('aws:')) {
+      return `Tag "${key}" uses the reserved aws: prefix`;
+    }
+  }
+  return null;
+}
+
+/**
+ * Reads an object's tags
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param key - The S3 object key
+ */
+export async function getObjectTags(
+  profileName: string,
+  bucket: string,
+  key: string
+): Promise<{ success: boolean; tags?: Record<string, string>; error?: string }> {
+  const client = getS3Client(profileName);
+
+  try {
+    const response = await client.send(new GetObjectTaggingCommand({ Bucket: bucket, Key: key }));
+    const tags: Record<string, string> = {};
+    for (const tag of response.TagSet ?? []) {
+      if (tag.Key && tag.Value !== undefined) {
+        tags[tag.Key] = tag.Value;
+      }
+    }
+    return { success: true, tags };
+  } catch (error) {
+    return { success: false, error: describeS3Error(error) };
+  }
+}
+
+/**
+ * Replaces all of an object's tags. Tags left out are removed.
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param key - The S3 object key
+ * @param tags - The complete new tag set, checked with validateObjectTags first
+ */
+export async function putObjectTags(
+  profileName: string,
+  bucket: string,
+  key: string,
+  tags: Record<string, string>
+): Promise<FileOperationResult> {
+  const problem = validateObjectTags(tags);
+  if (problem) {
+    return { success: false, error: problem };
+  }
+
+  const client = getS3Client(profileName);
+
+  try {
+    await client.send(
+      new PutObjectTaggingCommand({
+        Bucket: bucket,
+        Key: key,
+        Tagging: {
+          TagSet: Object.entries(tags).map(([tagKey, value]) => ({ Key: tagKey, Value: value })),
+        },
+      })
+    );
+    return { success: true };
+  } catch (error) {
+    return { success: false, error: describeS3Error(error) };
+  }
+}
+
+/**
+ * Serialization of S3 Select input and output. JSON output is one record per line.
+ */
+export type SelectFormat = 'csv' | 'json';
+
+/** Query results beyond this size are cut off at the last whole record */
+export const MAX_SELECT_RESULT_BYTES = 10 * 1024 * 1024;
+
+export interface SelectResult {
+  success: boolean;
+  records?: string;
+  /** Whether the results were cut off at MAX_SELECT_RESULT_BYTES */
+  truncated?: boolean;
+  /** Bytes of the object S3 scanned to answer the query */
+  bytesScanned?: number;
+  error?: string;
+}
+
+/**
+ * Describes how S3 should read the object, from its format and its key: TSV
+ * files are tab separated, .jsonl and .ndjson have one document per line, and
+ * .gz and .bz2 objects are decompressed by S3.
+ */
+export function buildSelectInputSerialization(key: string, format: SelectFormat): InputSerialization {
+  const lowerKey = key.toLowerCase();
+  const compression = lowerKey.endsWith('.gz') ? 'GZIP' : lowerKey.endsWith('.bz2') ? 'BZIP2' : 'NONE';
+  const baseKey = lowerKey.replace(/\.(gz|bz2)$/, '');
+
+  if (format === 'csv') {
+    return {
+      CompressionType: compression,
+      CSV: { FileHeaderInfo: 'USE', FieldDelimiter: baseKey.endsWith('.tsv') ? '\t' : ',' },
+    };
+  }
+  return {
+    CompressionType: compression,
+    JSON: { Type: /\.(jsonl|ndjson)$/.test(baseKey) ? 'LINES' : 'DOCUMENT' },
+  };
+}
+
+/**
+ * Runs an S3 Select SQL expression against a CSV or JSON object, so only the
+ * matching records are transferred, e.g. `SELECT * FROM s3object s LIMIT 100`
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param key - The S3 object key
+ * @param expression - The SQL expression
+ * @param input - How the object is serialized
+ * @param output - How the results are serialized
+ * @param abortSignal - Optional signal to abort the query
+ */
+export async function selectObjectContent(
+  profileName: string,
+  bucket: string,
+  key: string,
+  expression: string,
+  input: SelectFormat,
+  output: SelectFormat,
+  abortSignal?: AbortSignal
+): Promise<SelectResult> {
+  if (!expression.trim()) {
+    return { success: false, error: 'Enter a SQL expression to run' };
+  }
+
+  const client = getS3Client(profileName);
+
+  try {
+    const response = await client.send(
+      new SelectObjectContentCommand({
+        Bucket: bucket,
+        Key: key,
+        Expression: expression,
+        ExpressionType: 'SQL',
+        InputSerialization: buildSelectInputSerialization(key, input),
+        OutputSerialization: output === 'csv' ? { CSV: {} } : { JSON: { RecordDelimiter: '\n' } },
+      }),
+      { abortSignal }
+    );
+
+    const chunks: Buffer[] = [];
+    let size = 0;
+    let truncated = false;
+    let bytesScanned: number | undefined;
+
+    for await (const event of response.Payload ?? []) {
+      if (event.Records?.Payload) {
+        chunks.push(Buffer.from(event.Records.Payload));
+        size += event.Records.Payload.length;
+        if (size > MAX_SELECT_RESULT_BYTES) {
+          truncated = true;
+          break;
+        }
+      } else if (event.Stats?.Details) {
+        bytesScanned = event.Stats.Details.BytesScanned;
+      }
+    }
+
+    let data = Buffer.concat(chunks);
+    if (truncated) {
+      // Keep whole records only
+      const limited = data.subarray(0, MAX_SELECT_RESULT_BYTES);
+      data = limited.subarray(0, limited.lastIndexOf('\n') + 1);
+    }
+
+    return { success: true, records: data.toString('utf-8'), truncated, bytesScanned };
+  } catch (error) {
+    return { success: false, error: abortSignal?.aborted ? OPERATION_ABORTED : describeS3Error(error) };
+  }
+}
+
+/**
+ * A multipart upload that was started but never completed or aborted. Its parts
+ * are stored (and billed) until the upload is aborted.
+ */
+export interface MultipartUpload {
+  key: string;
+  uploadId: string;
+  initiated?: Date;
+  storageClass?: string;
+}
+
+/**
+ * Lists the incomplete multipart uploads in a bucket, oldest first, following
+ * the key and upload ID markers across pages
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param prefix - Only list uploads of keys under this prefix
+ */
+export async function listMultipartUploads(
+  profileName: string,
+  bucket: string,
+  prefix?: string
+): Promise<{ success: boolean; uploads?: MultipartUpload[]; error?: string }> {
+  const client = getS3Client(profileName);
+  const uploads: MultipartUpload[] = [];
+  let keyMarker: string | undefined;
+  let uploadIdMarker: string | undefined;
+
+  try {
+    do {
+      const response = await client.send(
+        new ListMultipartUploadsCommand({
+          Bucket: bucket,
+          Prefix: prefix || undefined,
+          KeyMarker: keyMarker,
+          UploadIdMarker: uploadIdMarker,
+        })
+      );
+
+      for (const upload of response.Uploads ?? []) {
+        if (upload.Key && upload.UploadId) {
+          uploads.push({
+            key: upload.Key,
+            uploadId: upload.UploadId,
+            initiated: upload.Initiated,
+            storageClass: upload.StorageClass,
+          });
+        }
+      }
+
+      // Without a next marker there is no way to continue, whatever IsTruncated says,
+      // and markers that don't move on would request the same page forever
+      const nextKeyMarker = response.IsTruncated ? response.NextKeyMarker : undefined;
+      const nextUploadIdMarker = response.NextUploadIdMarker;
+      if (nextKeyMarker === keyMarker && nextUploadIdMarker === uploadIdMarker) {
+        break;
+      }
+      keyMarker = nextKeyMarker;
+      uploadIdMarker = nextUploadIdMarker;
+    } while (keyMarker);
+  } catch (error) {
+    return { success: false, error: describeS3Error(error) };
+  }
+
+  uploads.sort((a, b) => (a.initiated?.getTime() ?? 0) - (b.initiated?.getTime() ?? 0));
+  return { success: true, uploads };
+}
+
+/**
+ * Aborts an incomplete multipart upload, deleting the parts uploaded so far.
+ * An upload that no longer exists counts as aborted.
+ * @param profileName - The AWS profile name to use
+ * @param bucket - The S3 bucket name
+ * @param key - The key the upload was writing
+ * @param uploadId - The upload to abort
+ */
+export async function abortMultipartUpload(
+  profileName: string,
+  bucket: string,
+  key: string,
+  uploadId: string
+): Promise<FileOperationResult> {
+  const client = getS3Client(profileName);
+
+  try {
+    await client.send(new AbortMultipartUploadCommand({ Bucket: bucket, Key: key, UploadId: uploadId }));
+    return { success: true };
+  } catch (error) {
+    if ((error as { name?: string })?.name === 'NoSuchUpload') {
+      return { success: true };
+    }
+    return { success: false, error: describeS3Error(error) };
+  }
+}
@@ -0,0 +1,262 @@
+/**
+ * Snappy compression in the framing format used for `.snappy` files
+ * (https://github.com/google/snappy/blob/main/framing_format.txt)
+ */
+
+import { crc32c } from './checksum';
+
+const STREAM_IDENTIFIER = Buffer.from([0xff, 0x06, 0x00, 0x00, 0x73, 0x4e, 0x61, 0x50, 0x70, 0x59]); // "sNaPpY"
+
+const CHUNK_COMPRESSED = 0x00;
+const CHUNK_UNCOMPRESSED = 0x01;
+const CHUNK_STREAM_IDENTIFIER = 0xff;
+/** Chunk types 0x80-0xfe (including padding) may be skipped by readers */
+const FIRST_SKIPPABLE_CHUNK = 0x80;
+
+/** The framing format limits each chunk to 64 KiB of uncompressed data */
+const MAX_CHUNK_DATA = 65536;
+
+const HASH_TABLE_BITS = 14;
+
+/**
+ * CRC-32C of a chunk's data, masked as the framing format requires
+ */
+function maskedCrc(data: Uint8Array): number {
+  const crc = crc32c(data);
+  return (((crc >>> 15) | (crc << 17)) + 0xa282ead8) >>> 0;
+}
+
+function read32(bytes: Uint8Array, pos: number): number {
+  return (bytes[pos] | (bytes[pos + 1] << 8) | (bytes[pos + 2] << 16) | (bytes[pos + 3] << 24)) >>> 0;
+}
+
+function corrupt(): Error {
+  return new Error('Corrupt snappy data');
+}
+
+/**
+ * Decompresses one raw snappy block
+ * @param maxLength - Largest uncompressed size accepted
+ * @throws RangeError when the block would decompress past maxLength
+ */
+export function decompressSnappyBlock(input: Uint8Array, maxLength: number): Buffer {
+  // Uncompressed length as a little-endian varint
+  let pos = 0;
+  let length = 0;
+  for (let shift = 0; ; shift += 7) {
+    if (pos >= input.length || shift > 28) {
+      throw corrupt();
+    }
+    const byte = input[pos++];
+    length += (byte & 0x7f) * 2 ** shift;
+    if (!(byte & 0x80)) break;
+  }
+  if (length > maxLength) {
+    throw new RangeError('Decompressed content exceeds the size limit');
+  }
+
+  const output = Buffer.alloc(length);
+  let out = 0;
+
+  while (pos < input.length) {
+    const tag = input[pos++];
+    const type = tag & 0x03;
+
+    if (type === 0) {
+      // Literal; lengths of 61+ bytes are stored in the following 1-4 bytes
+      let literalLength = tag >>> 2;
+      if (literalLength >= 60) {
+        const lengthBytes = literalLength - 59;
+        if (pos + lengthBytes > input.length) throw corrupt();
+        literalLength = 0;
+        for (let i = 0; i < lengthBytes; i++) {
+          literalLength += input[pos + i] * 2 ** (8 * i);
+        }
+        pos += lengthBytes;
+      }
+      literalLength += 1;
+      if (pos + literalLength > input.length || out + literalLength > length) throw corrupt();
+      output.set(input.subarray(pos, pos + literalLength), out);
+      pos += literalLength;
+      out += literalLength;
+      continue;
+    }
+
+    let copyLength: number;
+    let offset: number;
+    if (type === 1) {
+      if (pos + 1 > input.length) throw corrupt();
+      copyLength = 4 + ((tag >>> 2) & 0x07);
+      offset = ((tag >>> 5) << 8) | input[pos];
+      pos += 1;
+    } else if (type === 2) {
+      if (pos + 2 > input.length) throw corrupt();
+      copyLength = 1 + (tag >>> 2);
+      offset = input[pos] | (input[pos + 1] << 8);
+      pos += 2;
+    } else {
+      if (pos + 4 > input.length) throw corrupt();
+      copyLength = 1 + (tag >>> 2);
+      offset = read32(input, pos);
+      pos += 4;
+    }
+    if (offset === 0 || offset > out || out + copyLength > length) throw corrupt();
+    // Copies may overlap their own output, so go byte by byte
+    for (let i = 0; i < copyLength; i++) {
+      output[out] = output[out - offset];
+      out++;
+    }
+  }
+
+  if (out !== length) throw corrupt();
+  return output;
+}
+
+/**
+ * Compresses one raw snappy block (at most 64 KiB, so every offset fits in two bytes)
+ */
+export function compressSnappyBlock(input: Uint8Array): Buffer {
+  const out: number[] = [];
+
+  let remaining = input.length;
+  do {
+    out.push((remaining & 0x7f) | (remaining > 0x7f ? 0x80 : 0));
+    remaining >>>= 7;
+  } while (remaining > 0);
+
+  const emitLiteral = (start: number, end: number) => {
+    const n = end - start - 1;
+    if (n < 0) return;
+    if (n < 60) {
+      out.push(n << 2);
+    } else if (n < 0x100) {
+      out.push(60 << 2, n);
+    } else {
+      out.push(61 << 2, n & 0xff, n >>> 8);
+    }
+    for (let i = start; i < end; i++) {
+      out.push(input[i]);
+    }
+  };
+
+  const emitCopy = (offset: number, length: number) => {
+    while (length > 0) {
+      const chunk = Math.min(length, 64);
+      out.push(((chunk - 1) << 2) | 2, offset & 0xff, offset >>> 8);
+      length -= chunk;
+    }
+  };
+
+  const table = new Int32Array(1 << HASH_TABLE_BITS).fill(-1);
+  let literalStart = 0;
+  let pos = 0;
+  while (pos + 4 <= input.length) {
+    const sequence = read32(input, pos);
+    const hash = Math.imul(sequence, 0x1e35a7bd) >>> (32 - HASH_TABLE_BITS);
+    const candidate = table[hash];
+    table[hash] = pos;
+
+    if (candidate >= 0 && read32(input, candidate) === sequence) {
+      let matchLength = 4;
+      while (pos + matchLength < input.length && input[candidate + matchLength] === input[pos + matchLength]) {
+        matchLength++;
+      }
+      emitLiteral(literalStart, pos);
+      emitCopy(pos - candidate, matchLength);
+      pos += matchLength;
+      literalStart = pos;
+    } else {
+      pos++;
+    }
+  }
+  emitLiteral(literalStart, input.length);
+
+  return Buffer.from(out);
+}
+
+/**
+ * Checks for the stream identifier that starts every framed snappy stream
+ */
+export function isSnappyFramed(data: Uint8Array): boolean {
+  return data.length >= STREAM_IDENTIFIER.length && STREAM_IDENTIFIER.every((b, i) => data[i] === b);
+}
+
+/**
+ * Compresses data into a framed snappy stream
+ */
+export function compressSnappyFramed(data: Uint8Array): Buffer {
+  const parts: Buffer[] = [STREAM_IDENTIFIER];
+
+  for (let start = 0; start < data.length; start += MAX_CHUNK_DATA) {
+    const chunk = data.subarray(start, start + MAX_CHUNK_DATA);
+    const compressed = compressSnappyBlock(chunk);
+    // Store incompressible chunks as they are
+    const isSmaller = compressed.length < chunk.length;
+    const payload = isSmaller ? compressed : chunk;
+
+    const header = Buffer.alloc(8);
+    const bodyLength = payload.length + 4;
+    header[0] = isSmaller ? CHUNK_COMPRESSED : CHUNK_UNCOMPRESSED;
+    header[1] = bodyLength & 0xff;
+    header[2] = (bodyLength >>> 8) & 0xff;
+    header[3] = (bodyLength >>> 16) & 0xff;
+    header.writeUInt32LE(maskedCrc(chunk), 4);
+    parts.push(header, Buffer.from(payload));
+  }
+
+  return Buffer.concat(parts);
+}
+
+/**
+ * Decompresses a framed snappy stream, verifying each chunk's checksum
+ * @param maxLength - Largest total uncompressed size accepted
+ * @throws RangeError when the stream would decompress past maxLength
+ */
+export function decompressSnappyFramed(input: Uint8Array, maxLength: number): Buffer {
+  if (!isSnappyFramed(input)) {
+    throw new Error('Not a framed snappy stream');
+  }
+
+  const parts: Buffer[] = [];
+  let total = 0;
+  let pos = 0;
+
+  while (pos < input.length) {
+    if (pos + 4 > input.length) {
+      throw new Error('Truncated snappy stream');
+    }
+    const type = input[pos];
+    const chunkLength = input[pos + 1] | (input[pos + 2] << 8) | (input[pos + 3] << 16);
+    pos += 4;
+    if (pos + chunkLength > input.length) {
+      throw new Error('Truncated snappy stream');
+    }
+    const body = input.subarray(pos, pos + chunkLength);
+    pos += chunkLength;
+
+    if (type === CHUNK_COMPRESSED || type === CHUNK_UNCOMPRESSED) {
+      if (body.length < 4) throw corrupt();
+      const expectedCrc = read32(body, 0);
+      const data =
+        type === CHUNK_COMPRESSED
+          ? decompressSnappyBlock(body.subarray(4), MAX_CHUNK_DATA)
+          : Buffer.from(body.subarray(4));
+      if (data.length > MAX_CHUNK_DATA) throw corrupt();
+      if (maskedCrc(data) !== expectedCrc) {
+        throw new Error('Snappy checksum mismatch');
+      }
+      total += data.length;
+      if (total > maxLength) {
+        throw new RangeError('Decompressed content exceeds the size limit');
+      }
+      parts.push(data);
+    } else if (type === CHUNK_STREAM_IDENTIFIER) {
+      // Streams may be concatenated, repeating the identifier
+      if (!isSnappyFramed(input.subarray(pos - chunkLength - 4, pos))) throw corrupt();
+    } else if (type < FIRST_SKIPPABLE_CHUNK) {
+      throw new Error(`Unsupported snappy chunk type 0x${type.toString(16).padStart(2, '0')}`);
+    }
+  }
+
+  return Buffer.concat(parts);
+}
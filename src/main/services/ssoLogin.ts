@@ -0,0 +1,368 @@
+/**
+ * Signing in to AWS IAM Identity Center (SSO) with the device authorization flow,
+ * the same flow as `aws sso login`, for profiles whose cached token is missing or
+ * expired, and listing the accounts and roles the signed-in user can use
+ */
+
+import crypto from 'crypto';
+import fs from 'fs';
+import path from 'path';
+import os from 'os';
+import {
+  SSOOIDCClient,
+  RegisterClientCommand,
+  StartDeviceAuthorizationCommand,
+  CreateTokenCommand,
+} from '@aws-sdk/client-sso-oidc';
+import { SSOClient, ListAccountsCommand, ListAccountRolesCommand } from '@aws-sdk/client-sso';
+import type { AwsProfile } from './awsCredentials';
+import { getConnectionRequestHandler } from './s3Service';
+
+const DEVICE_CODE_GRANT = 'urn:ietf:params:oauth:grant-type:device_code';
+const CLIENT_NAME = 's3-browser';
+const REGISTRATION_SCOPES = ['sso:account:access'];
+
+/** Seconds added to the polling interval when the service asks us to slow down */
+const SLOW_DOWN_SECONDS = 5;
+
+/**
+ * What the user needs to approve the sign-in in their browser
+ */
+export interface SsoDeviceAuthorization {
+  userCode: string;
+  verificationUri: string;
+  /** The verification URL with the code already filled in */
+  verificationUriComplete?: string;
+  expiresAt: Date;
+}
+
+/**
+ * Whether a profile has a usable SSO token in the cache
+ */
+export type SsoTokenStatus =
+  | { state: 'valid'; expiresAt: Date }
+  | { state: 'expired'; expiresAt: Date }
+  | { state: 'missing' };
+
+export interface SsoLoginResult {
+  success: boolean;
+  error?: string;
+}
+
+/**
+ * An account and role the signed-in user can get credentials for
+ */
+export interface SsoRole {
+  accountId: string;
+  accountName?: string;
+  roleName: string;
+}
+
+export interface SsoRoleListResult {
+  success: boolean;
+  roles?: SsoRole[];
+  error?: string;
+  /** The cached token is missing or expired; signing in again fixes it */
+  needsLogin?: boolean;
+}
+
+export interface SsoLoginOptions {
+  /** Called once the device code is issued, to show the code and open the URL */
+  onAuthorization: (authorization: SsoDeviceAuthorization) => void;
+  abortSignal?: AbortSignal;
+  /** Directory the token is written to, ~/.aws/sso/cache by default */
+  cacheDir?: string;
+  /** Waits between polls; replaceable so tests don't wait on real timers */
+  sleep?: (ms: number, signal?: AbortSignal) => Promise<void>;
+}
+
+/**
+ * The directory the AWS CLI and SDK keep SSO tokens in
+ */
+export function getSsoCacheDir(): string {
+  return path.join(os.homedir(), '.aws', 'sso', 'cache');
+}
+
+/**
+ * Path of the cached token for a profile. The SDK names the file after the SHA-1
+ * of the sso_session name, or of the start URL for profiles without a session.
+ */
+export function getSsoTokenCachePath(profile: AwsProfile, cacheDir: string = getSsoCacheDir()): string {
+  const cacheKey = profile.ssoSession ?? profile.ssoStartUrl ?? '';
+  const hash = crypto.createHash('sha1').update(cacheKey).digest('hex');
+  return path.join(cacheDir, `${hash}.json`);
+}
+
+interface CachedSsoToken {
+  accessToken?: string;
+  expiresAt?: string;
+  startUrl?: string;
+}
+
+function readCachedToken(filePath: string): CachedSsoToken | null {
+  try {
+    const token = JSON.parse(fs.readFileSync(filePath, 'utf-8')) as CachedSsoToken;
+    return token && typeof token === 'object' ? token : null;
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * Checks the SSO cache for a profile's token, so an expired sign-in can be
+ * flagged before a request fails. A cache file belongs to the profile when it is
+ * the file the SDK reads, or when its start URL matches (e.g. written by older CLI
+ * versions); with several, the one expiring last wins. Files that don't parse, or
+ * hold a client registration rather than a token, are ignored.
+ * @param profile - The SSO profile
+ * @param cacheDir - The SSO cache directory
+ * @param now - The current time
+ */
+export function getSsoTokenStatus(
+  profile: AwsProfile,
+  cacheDir: string = getSsoCacheDir(),
+  now: Date = new Date()
+): SsoTokenStatus {
+  const latest = findCachedToken(profile, cacheDir);
+  if (!latest) {
+    return { state: 'missing' };
+  }
+  const { expiresAt } = latest;
+  return expiresAt > now ? { state: 'valid', expiresAt } : { state: 'expired', expiresAt };
+}
+
+/**
+ * The profile's cached token that expires last, whether or not it has expired
+ */
+function findCachedToken(profile: AwsProfile, cacheDir: string): { accessToken: string; expiresAt: Date } | null {
+  const expectedPath = getSsoTokenCachePath(profile, cacheDir);
+  let fileNames: string[];
+  try {
+    fileNames = fs.readdirSync(cacheDir).filter(name => name.endsWith('.json'));
+  } catch {
+    return null;
+  }
+
+  let latest: { accessToken: string; expiresAt: Date } | null = null;
+  for (const fileName of fileNames) {
+    const filePath = path.join(cacheDir, fileName);
+    const token = readCachedToken(filePath);
+    if (!token?.accessToken || !token.expiresAt) continue;
+
+    const matches =
+      filePath === expectedPath || (!!profile.ssoStartUrl && token.startUrl === profile.ssoStartUrl);
+    if (!matches) continue;
+
+    const expiresAt = new Date(token.expiresAt);
+    if (isNaN(expiresAt.getTime())) continue;
+    if (!latest || expiresAt > latest.expiresAt) {
+      latest = { accessToken: token.accessToken, expiresAt };
+    }
+  }
+
+  return latest;
+}
+
+/**
+ * Lists every account and role the profile's SSO token can get credentials for,
+ * to pick from when creating a profile. Accounts are sorted by name, as in the
+ * AWS access portal.
+ * @param profile - A signed-in SSO profile
+ * @param cacheDir - The SSO cache directory
+ */
+export async function listSsoRoles(
+  profile: AwsProfile,
+  cacheDir: string = getSsoCacheDir()
+): Promise<SsoRoleListResult> {
+  if (profile.profileType !== 'sso') {
+    return { success: false, error: `Profile '${profile.name}' is not an SSO profile` };
+  }
+  if (!profile.ssoRegion) {
+    return { success: false, error: 'Listing SSO accounts requires sso_region' };
+  }
+
+  const token = findCachedToken(profile, cacheDir);
+  if (!token || token.expiresAt <= new Date()) {
+    return {
+      success: false,
+      needsLogin: true,
+      error: token ? 'The SSO sign-in has expired; sign in again' : 'Not signed in to SSO; sign in first',
+    };
+  }
+
+  // Through the same proxy as S3 requests, for networks without direct internet access
+  const client = new SSOClient({ region: profile.ssoRegion, requestHandler: getConnectionRequestHandler() });
+  try {
+    const accounts: { accountId: string; accountName?: string }[] = [];
+    let nextToken: string | undefined;
+    do {
+      const page = await client.send(new ListAccountsCommand({ accessToken: token.accessToken, nextToken }));
+      for (const account of page.accountList ?? []) {
+        if (account.accountId) {
+          accounts.push({ accountId: account.accountId, accountName: account.accountName });
+        }
+      }
+      nextToken = page.nextToken;
+    } while (nextToken);
+
+    accounts.sort((a, b) => (a.accountName ?? a.accountId).localeCompare(b.accountName ?? b.accountId));
+
+    const roles: SsoRole[] = [];
+    for (const account of accounts) {
+      let roleToken: string | undefined;
+      do {
+        const page = await client.send(
+          new ListAccountRolesCommand({
+            accessToken: token.accessToken,
+            accountId: account.accountId,
+            nextToken: roleToken,
+          })
+        );
+        for (const role of page.roleList ?? []) {
+          if (role.roleName) {
+            roles.push({ ...account, roleName: role.roleName });
+          }
+        }
+        roleToken = page.nextToken;
+      } while (roleToken);
+    }
+
+    return { success: true, roles };
+  } catch (error) {
+    // The token was revoked, or expired early (e.g. the session was ended in the portal)
+    if (errorName(error) === 'UnauthorizedException') {
+      return { success: false, needsLogin: true, error: 'The SSO sign-in is no longer valid; sign in again' };
+    }
+    return { success: false, error: error instanceof Error ? error.message : 'Failed to list SSO accounts' };
+  } finally {
+    client.destroy();
+  }
+}
+
+function defaultSleep(ms: number, signal?: AbortSignal): Promise<void> {
+  return new Promise((resolve, reject) => {
+    const timer = setTimeout(resolve, ms);
+    signal?.addEventListener(
+      'abort',
+      () => {
+        clearTimeout(timer);
+        reject(signal.reason);
+      },
+      { once: true }
+    );
+  });
+}
+
+function errorName(error: unknown): string | undefined {
+  return (error as { name?: string })?.name;
+}
+
+/**
+ * Signs in with the device authorization flow and writes the token to the SSO
+ * cache, where the SDK picks it up the next time the profile's credentials are resolved.
+ * @param profile - The SSO profile to sign in with
+ * @param options - Where to report the user code, and how to cancel
+ */
+export async function ssoLogin(profile: AwsProfile, options: SsoLoginOptions): Promise<SsoLoginResult> {
+  const { onAuthorization, abortSignal, cacheDir = getSsoCacheDir(), sleep = defaultSleep } = options;
+
+  if (profile.profileType !== 'sso') {
+    return { success: false, error: `Profile '${profile.name}' is not an SSO profile` };
+  }
+  if (!profile.ssoStartUrl || !profile.ssoRegion) {
+    return { success: false, error: 'SSO login requires sso_start_url and sso_region' };
+  }
+
+  const client = new SSOOIDCClient({ region: profile.ssoRegion, requestHandler: getConnectionRequestHandler() });
+
+  try {
+    const registration = await client.send(
+      new RegisterClientCommand({
+        clientName: CLIENT_NAME,
+        clientType: 'public',
+        scopes: REGISTRATION_SCOPES,
+      }),
+      { abortSignal }
+    );
+
+    const device = await client.send(
+      new StartDeviceAuthorizationCommand({
+        clientId: registration.clientId,
+        clientSecret: registration.clientSecret,
+        startUrl: profile.ssoStartUrl,
+      }),
+      { abortSignal }
+    );
+
+    const expiresAt = new Date(Date.now() + (device.expiresIn ?? 600) * 1000);
+    onAuthorization({
+      userCode: device.userCode ?? '',
+      verificationUri: device.verificationUri ?? '',
+      verificationUriComplete: device.verificationUriComplete,
+      expiresAt,
+    });
+
+    let intervalSeconds = device.interval ?? 5;
+    for (;;) {
+      if (Date.now() >= expiresAt.getTime()) {
+        return { success: false, error: 'The sign-in request expired before it was approved' };
+      }
+      await sleep(intervalSeconds * 1000, abortSignal);
+
+      try {
+        const token = await client.send(
+          new CreateTokenCommand({
+            clientId: registration.clientId,
+            clientSecret: registration.clientSecret,
+            grantType: DEVICE_CODE_GRANT,
+            deviceCode: device.deviceCode,
+          }),
+          { abortSignal }
+        );
+
+        writeSsoToken(getSsoTokenCachePath(profile, cacheDir), {
+          startUrl: profile.ssoStartUrl,
+          region: profile.ssoRegion,
+          accessToken: token.accessToken,
+          expiresAt: new Date(Date.now() + (token.expiresIn ?? 0) * 1000).toISOString(),
+          refreshToken: token.refreshToken,
+          clientId: registration.clientId,
+          clientSecret: registration.clientSecret,
+          registrationExpiresAt: registration.clientSecretExpiresAt
+            ? new Date(registration.clientSecretExpiresAt * 1000).toISOString()
+            : undefined,
+        });
+        return { success: true };
+      } catch (error) {
+        // Keep polling until the user approves the request in the browser
+        if (errorName(error) === 'AuthorizationPendingException') continue;
+        if (errorName(error) === 'SlowDownException') {
+          intervalSeconds += SLOW_DOWN_SECONDS;
+          continue;
+        }
+        throw error;
+      }
+    }
+  } catch (error) {
+    if (abortSignal?.aborted) {
+      return { success: false, error: 'SSO login cancelled' };
+    }
+    if (errorName(error) === 'AccessDeniedException') {
+      return { success: false, error: 'The sign-in request was denied' };
+    }
+    if (errorName(error) === 'ExpiredTokenException') {
+      return { success: false, error: 'The sign-in request expired before it was approved' };
+    }
+    return { success: false, error: error instanceof Error ? error.message : 'SSO login failed' };
+  } finally {
+    client.destroy();
+  }
+}
+
+/**
+ * Writes a token file readable only by the current user, as the AWS CLI does
+ */
+function writeSsoToken(filePath: string, token: Record<string, string | undefined>): void {
+  fs.mkdirSync(path.dirname(filePath), { recursive: true });
+  fs.writeFileSync(filePath, JSON.stringify(token, null, 2), { mode: 0o600 });
+}
@@ -0,0 +1,23 @@
+/**
+ * Storage classes objects can be uploaded into directly
+ */
+
+/** Storage classes offered for uploads, cheapest to retrieve first */
+export const UPLOAD_STORAGE_CLASSES = [
+  'STANDARD',
+  'STANDARD_IA',
+  'ONEZONE_IA',
+  'INTELLIGENT_TIERING',
+  'GLACIER_IR',
+  'GLACIER',
+  'DEEP_ARCHIVE',
+] as const;
+
+export type UploadStorageClass = (typeof UPLOAD_STORAGE_CLASSES)[number];
+
+/**
+ * Whether a value is a storage class uploads can be written in
+ */
+export function isUploadStorageClass(value: unknown): value is UploadStorageClass {
+  return typeof value === 'string' && (UPLOAD_STORAGE_CLASSES as readonly string[]).includes(value);
+}
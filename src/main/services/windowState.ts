@@ -0,0 +1,69 @@
+import { screen, type BrowserWindow, type Rectangle } from 'electron';
+import { getWindowState, setWindowState, type WindowState } from './appState';
+
+/** Default size of the main window when nothing (usable) was saved */
+export const DEFAULT_WINDOW_SIZE = { width: 1200, height: 800 };
+
+/** Smallest size the main window can be resized to */
+export const MIN_WINDOW_SIZE = { width: 800, height: 600 };
+
+/**
+ * How much of the window's top edge must be on a display for it to be reachable:
+ * enough width to grab, and enough height for the title bar
+ */
+const MIN_VISIBLE_WIDTH = 100;
+const MIN_VISIBLE_HEIGHT = 30;
+
+function titleBarVisibleOn(bounds: Rectangle, area: Rectangle): boolean {
+  const visibleWidth =
+    Math.min(bounds.x + bounds.width, area.x + area.width) - Math.max(bounds.x, area.x);
+  const visibleHeight =
+    Math.min(bounds.y + MIN_VISIBLE_HEIGHT, area.y + area.height) - Math.max(bounds.y, area.y);
+  return visibleWidth >= MIN_VISIBLE_WIDTH && visibleHeight >= MIN_VISIBLE_HEIGHT;
+}
+
+/**
+ * Works out the bounds to open the main window with. The saved position is kept
+ * only if the title bar would be on one of the displays, so a window last seen on
+ * a disconnected monitor opens centred instead of off-screen. The size is kept
+ * within the display it opens on.
+ * @param saved - The geometry saved when the window was last closed
+ * @param workAreas - Work areas of the connected displays, primary first
+ * @returns Bounds for the window; without x and y the window is centred
+ */
+export function restoreWindowBounds(
+  saved: WindowState | null,
+  workAreas: Rectangle[]
+): { width: number; height: number; x?: number; y?: number } {
+  if (!saved || workAreas.length === 0) {
+    return { ...DEFAULT_WINDOW_SIZE };
+  }
+
+  const savedBounds = { x: saved.x, y: saved.y, width: saved.width, height: saved.height };
+  const area = workAreas.find(a => titleBarVisibleOn(savedBounds, a));
+  const fitTo = area ?? workAreas[0];
+  const width = Math.max(MIN_WINDOW_SIZE.width, Math.min(saved.width, fitTo.width));
+  const height = Math.max(MIN_WINDOW_SIZE.height, Math.min(saved.height, fitTo.height));
+
+  return area ? { width, height, x: saved.x, y: saved.y } : { width, height };
+}
+
+/**
+ * Bounds to create the main window with, from the saved geometry and the
+ * displays connected now. Needs the app to be ready.
+ */
+export function initialWindowBounds(): { width: number; height: number; x?: number; y?: number; maximized: boolean } {
+  const saved = getWindowState();
+  const workAreas = [screen.getPrimaryDisplay(), ...screen.getAllDisplays()].map(display => display.workArea);
+  return { ...restoreWindowBounds(saved, workAreas), maximized: saved?.maximized === true };
+}
+
+/**
+ * Saves the window's geometry when it closes, so the next launch can restore it
+ */
+export function trackWindowState(window: BrowserWindow): void {
+  window.on('close', () => {
+    const bounds = window.getNormalBounds();
+    setWindowState({ ...bounds, maximized: window.isMaximized() });
+  });
+}
@@ -1,14 +1,22 @@
-import { contextBridge, ipcRenderer } from 'electron';
+import { contextBridge, ipcRenderer, type IpcRendererEvent } from 'electron';
 
 // Types for AWS credentials API
 export interface ProfileInfo {
   name: string;
   region?: string;
+  resolvedRegion: string;
   hasCredentials: boolean;
   isValid: boolean;
   validationMessage?: string;
+  ssoTokenStatus?: SsoTokenStatus;
 }
 
+// Whether an SSO profile's cached token can still be used
+export type SsoTokenStatus =
+  | { state: 'valid'; expiresAt: Date }
+  | { state: 'expired'; expiresAt: Date }
+  | { state: 'missing' };
+
 export interface CredentialsState {
   profiles: ProfileInfo[];
   currentProfile: string | null;
@@ -24,13 +32,63 @@ export interface ProfileDetails {
   output?: string;
   sourceProfile?: string;
   roleArn?: string;
+  mfaSerial?: string;
   hasCredentials: boolean;
 }
 
+// Device code to approve in the browser while an SSO sign-in is in progress
+export interface SsoAuthorizationEvent {
+  profileName: string;
+  userCode: string;
+  verificationUri: string;
+  verificationUriComplete?: string;
+  expiresAt: Date;
+}
+
+// Config file settings of a profile created from the app
+export interface ProfileConfig {
+  name: string;
+  region?: string;
+  output?: string;
+  roleArn?: string;
+  sourceProfile?: string;
+  credentialSource?: string;
+  mfaSerial?: string;
+  ssoSession?: string;
+  ssoStartUrl?: string;
+  ssoRegion?: string;
+  ssoAccountId?: string;
+  ssoRoleName?: string;
+  credentialProcess?: string;
+  webIdentityTokenFile?: string;
+}
+
+// An account and role an SSO sign-in gives access to
+export interface SsoRole {
+  accountId: string;
+  accountName?: string;
+  roleName: string;
+}
+
+export interface SsoRoleListResult {
+  success: boolean;
+  roles?: SsoRole[];
+  error?: string;
+  needsLogin?: boolean;
+}
+
+// Request for the code of an MFA device while assuming a role
+export interface MfaCodeRequestEvent {
+  requestId: number;
+  mfaSerial: string;
+  profileName: string | null;
+}
+
 // Types for S3 API
 export interface S3Bucket {
   name: string;
   creationDate?: Date;
+  region?: string;
 }
 
 export interface S3Object {
@@ -39,6 +97,7 @@ export interface S3Object {
   lastModified?: Date;
   etag?: string;
   storageClass?: string;
+  contentType?: string;
   isPrefix: boolean;
 }
 
@@ -55,6 +114,7 @@ export interface ListObjectsOptions {
   bucket: string;
   prefix?: string;
   delimiter?: string;
+  recursive?: boolean;
   maxKeys?: number;
   continuationToken?: string;
 }
@@ -65,16 +125,51 @@ export interface S3ListBucketsResult {
   error?: string;
 }
 
+export type PublicStatus = 'private' | 'public-read' | 'mixed' | 'unknown';
+
+export interface PublicAccessBlock {
+  blockPublicAcls: boolean;
+  ignorePublicAcls: boolean;
+  blockPublicPolicy: boolean;
+  restrictPublicBuckets: boolean;
+}
+
+export interface BucketPublicStatusResult {
+  success: boolean;
+  status?: PublicStatus;
+  signals?: {
+    publicAccessBlock: PublicAccessBlock | null;
+    policyPublic: boolean | null;
+    aclPublic: boolean | null;
+  };
+  error?: string;
+}
+
 export interface S3ListObjectsResult {
   success: boolean;
   result?: ListObjectsResult;
   error?: string;
 }
 
+export interface S3CollapsePrefixResult {
+  success: boolean;
+  prefix?: string;
+  error?: string;
+}
+
 export interface S3ParseUrlResult {
   success: boolean;
   bucket?: string;
   key?: string;
+  region?: string;
+  versionId?: string;
+  error?: string;
+}
+
+export interface S3ParseUrlListResult {
+  success: boolean;
+  urls?: Array<{ bucket: string; key: string }>;
+  commonPrefix?: { bucket: string; prefix: string };
   error?: string;
 }
 
@@ -83,17 +178,49 @@ export interface FileOperationResult {
   error?: string;
 }
 
+// Why a bucket could not be created, when the user can fix it by picking another name
+export interface CreateBucketResult extends FileOperationResult {
+  failure?: 'name-taken' | 'already-owned' | 'invalid-name';
+}
+
+export interface TransferResult extends FileOperationResult {
+  method?: 'server-side' | 'stream';
+}
+
 export interface UploadResult {
   path: string;
   success: boolean;
   error?: string;
 }
 
+// Storage classes objects can be uploaded into directly
+export type UploadStorageClass =
+  | 'STANDARD'
+  | 'STANDARD_IA'
+  | 'ONEZONE_IA'
+  | 'INTELLIGENT_TIERING'
+  | 'GLACIER_IR'
+  | 'GLACIER'
+  | 'DEEP_ARCHIVE';
+
+// Overrides for the headers of uploaded objects
+export interface UploadOptions {
+  contentType?: string;
+  storageClass?: UploadStorageClass;
+  metadata?: Record<string, string>;
+  checksumSha256?: boolean;
+}
+
 export interface UploadFilesResult {
   success: boolean;
   results: UploadResult[];
 }
 
+export interface DownloadFilesResult {
+  success: boolean;
+  results: Array<{ key: string; success: boolean; error?: string; localPath?: string; skipped?: boolean }>;
+}
+
 export interface DeleteFilesResult {
   success: boolean;
   results: Array<{ key: string; success: boolean; error?: string }>;
@@ -108,6 +235,75 @@ export interface DeletePrefixResult {
   error?: string;
 }
 
+export interface MovePrefixResult {
+  success: boolean;
+  movedCount: number;
+  failures: Array<{ key: string; error: string }>;
+  error?: string;
+}
+
+// Headers of an object from a single HEAD request
+export interface ObjectHead {
+  contentLength: number;
+  contentType?: string;
+  etag?: string;
+  lastModified?: Date;
+  storageClass?: string;
+  restore?: RestoreStatus;
+}
+
+// Restore state of an archived (Glacier / Deep Archive) object
+export interface RestoreStatus {
+  ongoing: boolean;
+  expiry?: Date;
+}
+
+export type RestoreTier = 'Expedited' | 'Standard' | 'Bulk';
+
+// Whole-object download; notModified is set when a conditional GET found the object unchanged
+export interface BinaryContentResult {
+  success: boolean;
+  notModified?: boolean;
+  data?: Uint8Array;
+  etag?: string;
+  lastModified?: Date;
+  error?: string;
+}
+
+// Outcome of comparing an object with its stored SHA-256; verified is false when it has none
+export interface ChecksumVerificationResult {
+  success: boolean;
+  verified?: boolean;
+  verifiedAgainst?: 'SHA-256' | 'ETag';
+  checksumMismatch?: boolean;
+  error?: string;
+}
+
+// Validators of an earlier download, sent as If-None-Match / If-Modified-Since
+export interface DownloadConditions {
+  etag?: string;
+  modifiedSince?: Date;
+}
+
+// Serialization of S3 Select input and output
+export type SelectFormat = 'csv' | 'json';
+
+// A multipart upload that was started but never completed or aborted
+export interface MultipartUpload {
+  key: string;
+  uploadId: string;
+  initiated?: Date;
+  storageClass?: string;
+}
+
+export interface SelectResult {
+  success: boolean;
+  records?: string;
+  truncated?: boolean;
+  bytesScanned?: number;
+  error?: string;
+}
+
 // Types for Object Metadata
 export interface ObjectMetadata {
   key: string;
@@ -118,21 +314,73 @@ export interface ObjectMetadata {
   contentType?: string;
   lastModified?: Date;
   etag?: string;
+  etagPartCount?: number;
   storageClass?: string;
+  restore?: RestoreStatus;
   versionId?: string;
   serverSideEncryption?: string;
   contentEncoding?: string;
   cacheControl?: string;
   expires?: Date;
+  websiteRedirectLocation?: string;
+  redirectTarget?: { bucket: string; key: string };
+  checksums?: Record<string, string>;
   tags: Record<string, string>;
   customMetadata: Record<string, string>;
 }
 
+// Progress event for a running upload or download
+export interface TransferProgressEvent {
+  operationId: string;
+  loaded: number;
+  total: number;
+}
+
+// Compression looked through when downloading file content
+export type CompressionCodec = 'gzip' | 'zstd' | 'snappy';
+
 // Types for App State API
+export type ConflictPolicy = 'overwrite' | 'skip' | 'rename';
+
+export interface RecentLocation {
+  profile: string;
+  bucket: string;
+  prefix: string;
+}
+
+export type Theme = 'system' | 'light' | 'dark';
+
+export interface Bookmark {
+  label: string;
+  profile: string;
+  bucket: string;
+  prefix: string;
+}
+
+export interface ViewerLayout {
+  hiddenColumns: string[];
+}
+
 export interface AppStateData {
   lastProfile: string | null;
   lastBucket: string | null;
   lastPrefix: string;
+  readOnlyProfiles: string[];
+  downloadConflictPolicy: ConflictPolicy;
+  collapseSingleChildFolders: boolean;
+  confirmBeforeDelete: boolean;
+  recentLocations: RecentLocation[];
+  bookmarks: Bookmark[];
+  viewerLayouts: Record<string, ViewerLayout>;
+  columnWidths: Record<string, Record<string, number>>;
+  requestLogging: boolean;
+  requesterPays: boolean;
+  maxRetries: number | null;
+  connectTimeoutSeconds: number | null;
+  requestTimeoutSeconds: number | null;
+  uploadStorageClass: UploadStorageClass | null;
+  proxyUrl: string | null;
+  theme: Theme;
 }
 
 // Expose protected methods that allow the renderer process to use
@@ -149,6 +397,16 @@ contextBridge.exposeInMainWorld('electronAPI', {
     load: (): Promise<AppStateData> => ipcRenderer.invoke('app-state:load'),
     save: (data: Partial<AppStateData>): Promise<{ success: boolean; error?: string }> =>
       ipcRenderer.invoke('app-state:save', data),
+    exportShareable: (): Promise<{ success: boolean; filePath?: string; cancelled?: boolean; error?: string }> =>
+      ipcRenderer.invoke('app-state:export-shareable'),
+    importShareable: (): Promise<{ success: boolean; added?: number; cancelled?: boolean; error?: string }> =>
+      ipcRenderer.invoke('app-state:import-shareable'),
+    addBookmark: (bookmark: Bookmark): Promise<{ success: boolean; bookmarks?: Bookmark[]; error?: string }> =>
+      ipcRenderer.invoke('app-state:add-bookmark', bookmark),
+    removeBookmark: (location: RecentLocation): Promise<{ success: boolean; bookmarks?: Bookmark[]; error?: string }> =>
+      ipcRenderer.invoke('app-state:remove-bookmark', location),
+    setColumnWidths: (signature: string, widths: Record<string, number>): Promise<{ success: boolean; error?: string }> =>
+      ipcRenderer.invoke('app-state:set-column-widths', signature, widths),
   },
 
   // AWS Credentials API
@@ -160,21 +418,81 @@ contextBridge.exposeInMainWorld('electronAPI', {
     getProfileDetails: (profileName: string): Promise<ProfileDetails | null> =>
       ipcRenderer.invoke('aws:get-profile-details', profileName),
     refreshProfiles: (): Promise<CredentialsState> => ipcRenderer.invoke('aws:refresh-profiles'),
+    getDiagnosticReport: (): Promise<string> => ipcRenderer.invoke('aws:get-diagnostic-report'),
+    onProfilesChanged: (callback: () => void): (() => void) => {
+      const listener = () => callback();
+      ipcRenderer.on('aws:profiles-changed', listener);
+      return () => {
+        ipcRenderer.removeListener('aws:profiles-changed', listener);
+      };
+    },
+    exportEnv: (profileName: string): Promise<{ success: boolean; text?: string; error?: string }> =>
+      ipcRenderer.invoke('aws:export-env', profileName),
+    ssoLogin: (profileName: string): Promise<{ success: boolean; error?: string }> =>
+      ipcRenderer.invoke('aws:sso-login', profileName),
+    cancelSsoLogin: (): Promise<boolean> => ipcRenderer.invoke('aws:cancel-sso-login'),
+    listSsoRoles: (profileName: string): Promise<SsoRoleListResult> =>
+      ipcRenderer.invoke('aws:list-sso-roles', profileName),
+    onMfaCodeRequest: (callback: (request: MfaCodeRequestEvent) => void): (() => void) => {
+      const listener = (_event: IpcRendererEvent, request: MfaCodeRequestEvent) => callback(request);
+      ipcRenderer.on('aws:mfa-code-request', listener);
+      return () => {
+        ipcRenderer.removeListener('aws:mfa-code-request', listener);
+      };
+    },
+    submitMfaCode: (requestId: number, code: string | null): Promise<boolean> =>
+      ipcRenderer.invoke('aws:submit-mfa-code', requestId, code),
+    addProfile: (config: ProfileConfig, overwrite?: boolean): Promise<{ success: boolean; error?: string }> =>
+      ipcRenderer.invoke('aws:add-profile', config, overwrite),
+    getProfileConfig: (name: string): Promise<ProfileConfig | null> =>
+      ipcRenderer.invoke('aws:get-profile-config', name),
+    updateProfile: (name: string, config: ProfileConfig): Promise<{ success: boolean; error?: string }> =>
+      ipcRenderer.invoke('aws:update-profile', name, config),
+    removeProfile: (name: string): Promise<{ success: boolean; error?: string }> =>
+      ipcRenderer.invoke('aws:remove-profile', name),
+    onSsoAuthorization: (callback: (authorization: SsoAuthorizationEvent) => void): (() => void) => {
+      const listener = (_event: IpcRendererEvent, authorization: SsoAuthorizationEvent) => callback(authorization);
+      ipcRenderer.on('aws:sso-authorization', listener);
+      return () => {
+        ipcRenderer.removeListener('aws:sso-authorization', listener);
+      };
+    },
   },
 
   // S3 API
   s3: {
     listBuckets: (): Promise<S3ListBucketsResult> => ipcRenderer.invoke('s3:list-buckets'),
-    listObjects: (options: ListObjectsOptions): Promise<S3ListObjectsResult> =>
-      ipcRenderer.invoke('s3:list-objects', options),
+    createBucket: (bucket: string, region?: string): Promise<CreateBucketResult> =>
+      ipcRenderer.invoke('s3:create-bucket', bucket, region),
+    getBucketPublicStatus: (bucket: string): Promise<BucketPublicStatusResult> =>
+      ipcRenderer.invoke('s3:get-bucket-public-status', bucket),
+    getBucketRegion: (bucket: string): Promise<{ success: boolean; region?: string; error?: string }> =>
+      ipcRenderer.invoke('s3:get-bucket-region', bucket),
+    listObjects: (options: ListObjectsOptions, operationId?: string): Promise<S3ListObjectsResult> =>
+      ipcRenderer.invoke('s3:list-objects', options, operationId),
     listAllObjects: (
       options: Omit<ListObjectsOptions, 'continuationToken'>,
       operationId: string
     ): Promise<S3ListObjectsResult> =>
       ipcRenderer.invoke('s3:list-all-objects', options, operationId),
+    collapsePrefixChain: (
+      bucket: string,
+      prefix: string,
+      operationId: string
+    ): Promise<S3CollapsePrefixResult> =>
+      ipcRenderer.invoke('s3:collapse-prefix-chain', bucket, prefix, operationId),
     cancelOperation: (operationId: string): Promise<boolean> =>
       ipcRenderer.invoke('s3:cancel-operation', operationId),
+    onTransferProgress: (callback: (progress: TransferProgressEvent) => void): (() => void) => {
+      const listener = (_event: IpcRendererEvent, progress: TransferProgressEvent) => callback(progress);
+      ipcRenderer.on('s3:transfer-progress', listener);
+      return () => {
+        ipcRenderer.removeListener('s3:transfer-progress', listener);
+      };
+    },
     parseUrl: (url: string): Promise<S3ParseUrlResult> => ipcRenderer.invoke('s3:parse-url', url),
+    parseUrlList: (text: string): Promise<S3ParseUrlListResult> =>
+      ipcRenderer.invoke('s3:parse-url-list', text),
     getParentPrefix: (keyOrPrefix: string): Promise<string> =>
       ipcRenderer.invoke('s3:get-parent-prefix', keyOrPrefix),
     getKeyName: (keyOrPrefix: string): Promise<string> =>
@@ -186,22 +504,32 @@ contextBridge.exposeInMainWorld('electronAPI', {
       bucket: string,
       key: string,
       operationId: string
-    ): Promise<FileOperationResult & { localPath?: string }> =>
+    ): Promise<FileOperationResult & { localPath?: string; skipped?: boolean }> =>
       ipcRenderer.invoke('s3:download-file', bucket, key, operationId),
+    downloadFiles: (bucket: string, keys: string[], operationId: string): Promise<DownloadFilesResult> =>
+      ipcRenderer.invoke('s3:download-files', bucket, keys, operationId),
+    openExternally: (
+      bucket: string,
+      key: string,
+      operationId: string
+    ): Promise<FileOperationResult & { localPath?: string }> =>
+      ipcRenderer.invoke('s3:open-externally', bucket, key, operationId),
     uploadFile: (
       bucket: string,
       prefix: string,
       filePath: string,
-      operationId: string
+      operationId: string,
+      options?: UploadOptions
     ): Promise<FileOperationResult> =>
-      ipcRenderer.invoke('s3:upload-file', bucket, prefix, filePath, operationId),
+      ipcRenderer.invoke('s3:upload-file', bucket, prefix, filePath, operationId, options),
     uploadFiles: (
       bucket: string,
       prefix: string,
       filePaths: string[],
-      operationId: string
+      operationId: string,
+      options?: UploadOptions
     ): Promise<UploadFilesResult> =>
-      ipcRenderer.invoke('s3:upload-files', bucket, prefix, filePaths, operationId),
+      ipcRenderer.invoke('s3:upload-files', bucket, prefix, filePaths, operationId, options),
     deleteFile: (bucket: string, key: string): Promise<FileOperationResult> =>
       ipcRenderer.invoke('s3:delete-file', bucket, key),
     deleteFiles: (bucket: string, keys: string[]): Promise<DeleteFilesResult> =>
@@ -210,6 +538,8 @@ contextBridge.exposeInMainWorld('electronAPI', {
       ipcRenderer.invoke('s3:delete-prefix', bucket, prefix),
     renameFile: (bucket: string, sourceKey: string, newName: string): Promise<FileOperationResult> =>
       ipcRenderer.invoke('s3:rename-file', bucket, sourceKey, newName),
+    renamePrefix: (bucket: string, prefix: string, newName: string, operationId: string): Promise<MovePrefixResult> =>
+      ipcRenderer.invoke('s3:rename-prefix', bucket, prefix, newName, operationId),
     copyFile: (
       sourceBucket: string,
       sourceKey: string,
@@ -217,32 +547,115 @@ contextBridge.exposeInMainWorld('electronAPI', {
       destinationKey: string
     ): Promise<FileOperationResult> =>
       ipcRenderer.invoke('s3:copy-file', sourceBucket, sourceKey, destinationBucket, destinationKey),
+    transferObject: (
+      sourceBucket: string,
+      sourceKey: string,
+      destinationProfile: string,
+      destinationBucket: string,
+      destinationKey: string
+    ): Promise<TransferResult> =>
+      ipcRenderer.invoke(
+        's3:transfer-object',
+        sourceBucket,
+        sourceKey,
+        destinationProfile,
+        destinationBucket,
+        destinationKey
+      ),
     uploadContent: (bucket: string, key: string, content: string): Promise<FileOperationResult> =>
       ipcRenderer.invoke('s3:upload-content', bucket, key, content),
     downloadContent: (
       bucket: string,
-      key: string
-    ): Promise<{ success: boolean; content?: string; error?: string }> =>
-      ipcRenderer.invoke('s3:download-content', bucket, key),
+      key: string,
+      versionId?: string
+    ): Promise<{ success: boolean; content?: string; compression?: CompressionCodec; error?: string }> =>
+      ipcRenderer.invoke('s3:download-content', bucket, key, versionId),
     getFileSize: (
       bucket: string,
-      key: string
-    ): Promise<{ success: boolean; size?: number; error?: string }> =>
-      ipcRenderer.invoke('s3:get-file-size', bucket, key),
-    downloadBinaryContent: (
+      key: string,
+      versionId?: string
+    ): Promise<{ success: boolean; size?: number; notFound?: boolean; error?: string }> =>
+      ipcRenderer.invoke('s3:get-file-size', bucket, key, versionId),
+    headObject: (
       bucket: string,
-      key: string
+      key: string,
+      versionId?: string
+    ): Promise<{ success: boolean; head?: ObjectHead; notFound?: boolean; error?: string }> =>
+      ipcRenderer.invoke('s3:head-object', bucket, key, versionId),
+    presignGetUrl: (
+      bucket: string,
+      key: string,
+      expiresInSeconds: number
+    ): Promise<{ success: boolean; url?: string; error?: string }> =>
+      ipcRenderer.invoke('s3:presign-get-url', bucket, key, expiresInSeconds),
+    presignPutUrl: (
+      bucket: string,
+      key: string,
+      expiresInSeconds: number,
+      contentType?: string
+    ): Promise<{ success: boolean; url?: string; error?: string }> =>
+      ipcRenderer.invoke('s3:presign-put-url', bucket, key, expiresInSeconds, contentType),
+    restoreObject: (
+      bucket: string,
+      key: string,
+      days: number,
+      tier: RestoreTier
+    ): Promise<FileOperationResult> => ipcRenderer.invoke('s3:restore-object', bucket, key, days, tier),
+    verifyChecksum: (bucket: string, key: string): Promise<ChecksumVerificationResult> =>
+      ipcRenderer.invoke('s3:verify-checksum', bucket, key),
+    downloadBinaryContent: (bucket: string, key: string, versionId?: string): Promise<BinaryContentResult> =>
+      ipcRenderer.invoke('s3:download-binary-content', bucket, key, versionId),
+    downloadBinaryContentIfModified: (
+      bucket: string,
+      key: string,
+      conditions: DownloadConditions
+    ): Promise<BinaryContentResult> =>
+      ipcRenderer.invoke('s3:download-binary-content-if-modified', bucket, key, conditions),
+    downloadPartialContent: (
+      bucket: string,
+      key: string,
+      length?: number,
+      start?: number,
+      versionId?: string
     ): Promise<{ success: boolean; data?: Uint8Array; error?: string }> =>
-      ipcRenderer.invoke('s3:download-binary-content', bucket, key),
+      ipcRenderer.invoke('s3:download-partial-content', bucket, key, length, start, versionId),
     showOpenDialog: (): Promise<string[] | null> => ipcRenderer.invoke('s3:show-open-dialog'),
+    saveLocalFile: (
+      defaultFileName: string,
+      content: string
+    ): Promise<{ success: boolean; filePath?: string; cancelled?: boolean; error?: string }> =>
+      ipcRenderer.invoke('s3:save-local-file', defaultFileName, content),
     openDownloadsFolder: (): Promise<void> => ipcRenderer.invoke('s3:open-downloads-folder'),
-    showFileInFolder: (filePath: string): Promise<void> =>
+    showFileInFolder: (filePath: string): Promise<{ success: boolean; error?: string }> =>
       ipcRenderer.invoke('s3:show-file-in-folder', filePath),
     getObjectMetadata: (
       bucket: string,
       key: string
-    ): Promise<{ success: boolean; metadata?: ObjectMetadata; error?: string }> =>
+    ): Promise<{ success: boolean; metadata?: ObjectMetadata; notFound?: boolean; error?: string }> =>
       ipcRenderer.invoke('s3:get-object-metadata', bucket, key),
+    getObjectTags: (
+      bucket: string,
+      key: string
+    ): Promise<{ success: boolean; tags?: Record<string, string>; error?: string }> =>
+      ipcRenderer.invoke('s3:get-object-tags', bucket, key),
+    putObjectTags: (bucket: string, key: string, tags: Record<string, string>): Promise<FileOperationResult> =>
+      ipcRenderer.invoke('s3:put-object-tags', bucket, key, tags),
+    selectObjectContent: (
+      bucket: string,
+      key: string,
+      expression: string,
+      input: SelectFormat,
+      output: SelectFormat,
+      operationId: string
+    ): Promise<SelectResult> =>
+      ipcRenderer.invoke('s3:select-object-content', bucket, key, expression, input, output, operationId),
+    listMultipartUploads: (
+      bucket: string,
+      prefix?: string
+    ): Promise<{ success: boolean; uploads?: MultipartUpload[]; error?: string }> =>
+      ipcRenderer.invoke('s3:list-multipart-uploads', bucket, prefix),
+    abortMultipartUpload: (bucket: string, key: string, uploadId: string): Promise<FileOperationResult> =>
+      ipcRenderer.invoke('s3:abort-multipart-upload', bucket, key, uploadId),
     createFile: (bucket: string, key: string): Promise<FileOperationResult> =>
       ipcRenderer.invoke('s3:create-file', bucket, key),
     createFolder: (bucket: string, prefix: string): Promise<FileOperationResult> =>
@@ -253,7 +666,7 @@ contextBridge.exposeInMainWorld('electronAPI', {
   getObjectMetadata: (
     bucket: string,
     key: string
-  ): Promise<{ success: boolean; metadata?: ObjectMetadata; error?: string }> =>
+  ): Promise<{ success: boolean; metadata?: ObjectMetadata; notFound?: boolean; error?: string }> =>
     ipcRenderer.invoke('s3:get-object-metadata', bucket, key),
 });
 
@@ -266,6 +679,11 @@ declare global {
       appState: {
         load: () => Promise<AppStateData>;
         save: (data: Partial<AppStateData>) => Promise<{ success: boolean; error?: string }>;
+        exportShareable: () => Promise<{ success: boolean; filePath?: string; cancelled?: boolean; error?: string }>;
+        importShareable: () => Promise<{ success: boolean; added?: number; cancelled?: boolean; error?: string }>;
+        addBookmark: (bookmark: Bookmark) => Promise<{ success: boolean; bookmarks?: Bookmark[]; error?: string }>;
+        removeBookmark: (location: RecentLocation) => Promise<{ success: boolean; bookmarks?: Bookmark[]; error?: string }>;
+        setColumnWidths: (signature: string, widths: Record<string, number>) => Promise<{ success: boolean; error?: string }>;
       };
       aws: {
         getProfiles: () => Promise<CredentialsState>;
@@ -273,16 +691,39 @@ declare global {
         getCurrentProfile: () => Promise<string | null>;
         getProfileDetails: (profileName: string) => Promise<ProfileDetails | null>;
         refreshProfiles: () => Promise<CredentialsState>;
+        getDiagnosticReport: () => Promise<string>;
+        onProfilesChanged: (callback: () => void) => () => void;
+        exportEnv: (profileName: string) => Promise<{ success: boolean; text?: string; error?: string }>;
+        ssoLogin: (profileName: string) => Promise<{ success: boolean; error?: string }>;
+        cancelSsoLogin: () => Promise<boolean>;
+        listSsoRoles: (profileName: string) => Promise<SsoRoleListResult>;
+        onMfaCodeRequest: (callback: (request: MfaCodeRequestEvent) => void) => () => void;
+        submitMfaCode: (requestId: number, code: string | null) => Promise<boolean>;
+        addProfile: (config: ProfileConfig, overwrite?: boolean) => Promise<{ success: boolean; error?: string }>;
+        getProfileConfig: (name: string) => Promise<ProfileConfig | null>;
+        updateProfile: (name: string, config: ProfileConfig) => Promise<{ success: boolean; error?: string }>;
+        removeProfile: (name: string) => Promise<{ success: boolean; error?: string }>;
+        onSsoAuthorization: (callback: (authorization: SsoAuthorizationEvent) => void) => () => void;
       };
       s3: {
         listBuckets: () => Promise<S3ListBucketsResult>;
-        listObjects: (options: ListObjectsOptions) => Promise<S3ListObjectsResult>;
+        createBucket: (bucket: string, region?: string) => Promise<CreateBucketResult>;
+        getBucketPublicStatus: (bucket: string) => Promise<BucketPublicStatusResult>;
+        getBucketRegion: (bucket: string) => Promise<{ success: boolean; region?: string; error?: string }>;
+        listObjects: (options: ListObjectsOptions, operationId?: string) => Promise<S3ListObjectsResult>;
         listAllObjects: (
           options: Omit<ListObjectsOptions, 'continuationToken'>,
           operationId: string
         ) => Promise<S3ListObjectsResult>;
+        collapsePrefixChain: (
+          bucket: string,
+          prefix: string,
+          operationId: string
+        ) => Promise<S3CollapsePrefixResult>;
         cancelOperation: (operationId: string) => Promise<boolean>;
+        onTransferProgress: (callback: (progress: TransferProgressEvent) => void) => () => void;
         parseUrl: (url: string) => Promise<S3ParseUrlResult>;
+        parseUrlList: (text: string) => Promise<S3ParseUrlListResult>;
         getParentPrefix: (keyOrPrefix: string) => Promise<string>;
         getKeyName: (keyOrPrefix: string) => Promise<string>;
         clearClient: () => Promise<void>;
@@ -291,55 +732,132 @@ declare global {
           bucket: string,
           key: string,
           operationId: string
+        ) => Promise<FileOperationResult & { localPath?: string; skipped?: boolean }>;
+        downloadFiles: (bucket: string, keys: string[], operationId: string) => Promise<DownloadFilesResult>;
+        openExternally: (
+          bucket: string,
+          key: string,
+          operationId: string
         ) => Promise<FileOperationResult & { localPath?: string }>;
         uploadFile: (
           bucket: string,
           prefix: string,
           filePath: string,
-          operationId: string
+          operationId: string,
+          options?: UploadOptions
         ) => Promise<FileOperationResult>;
         uploadFiles: (
           bucket: string,
           prefix: string,
           filePaths: string[],
-          operationId: string
+          operationId: string,
+          options?: UploadOptions
         ) => Promise<UploadFilesResult>;
         deleteFile: (bucket: string, key: string) => Promise<FileOperationResult>;
         deleteFiles: (bucket: string, keys: string[]) => Promise<DeleteFilesResult>;
         renameFile: (bucket: string, sourceKey: string, newName: string) => Promise<FileOperationResult>;
+        renamePrefix: (
+          bucket: string,
+          prefix: string,
+          newName: string,
+          operationId: string
+        ) => Promise<MovePrefixResult>;
         copyFile: (
           sourceBucket: string,
           sourceKey: string,
           destinationBucket: string,
           destinationKey: string
         ) => Promise<FileOperationResult>;
+        transferObject: (
+          sourceBucket: string,
+          sourceKey: string,
+          destinationProfile: string,
+          destinationBucket: string,
+          destinationKey: string
+        ) => Promise<TransferResult>;
         uploadContent: (bucket: string, key: string, content: string) => Promise<FileOperationResult>;
         downloadContent: (
           bucket: string,
-          key: string
-        ) => Promise<{ success: boolean; content?: string; error?: string }>;
+          key: string,
+          versionId?: string
+        ) => Promise<{ success: boolean; content?: string; compression?: CompressionCodec; error?: string }>;
         getFileSize: (
           bucket: string,
-          key: string
-        ) => Promise<{ success: boolean; size?: number; error?: string }>;
-        downloadBinaryContent: (
+          key: string,
+          versionId?: string
+        ) => Promise<{ success: boolean; size?: number; notFound?: boolean; error?: string }>;
+        headObject: (
           bucket: string,
-          key: string
+          key: string,
+          versionId?: string
+        ) => Promise<{ success: boolean; head?: ObjectHead; notFound?: boolean; error?: string }>;
+        presignGetUrl: (
+          bucket: string,
+          key: string,
+          expiresInSeconds: number
+        ) => Promise<{ success: boolean; url?: string; error?: string }>;
+        presignPutUrl: (
+          bucket: string,
+          key: string,
+          expiresInSeconds: number,
+          contentType?: string
+        ) => Promise<{ success: boolean; url?: string; error?: string }>;
+        restoreObject: (
+          bucket: string,
+          key: string,
+          days: number,
+          tier: RestoreTier
+        ) => Promise<FileOperationResult>;
+        verifyChecksum: (bucket: string, key: string) => Promise<ChecksumVerificationResult>;
+        downloadBinaryContent: (bucket: string, key: string, versionId?: string) => Promise<BinaryContentResult>;
+        downloadBinaryContentIfModified: (
+          bucket: string,
+          key: string,
+          conditions: DownloadConditions
+        ) => Promise<BinaryContentResult>;
+        downloadPartialContent: (
+          bucket: string,
+          key: string,
+          length?: number,
+          start?: number,
+          versionId?: string
         ) => Promise<{ success: boolean; data?: Uint8Array; error?: string }>;
         showOpenDialog: () => Promise<string[] | null>;
+        saveLocalFile: (
+          defaultFileName: string,
+          content: string
+        ) => Promise<{ success: boolean; filePath?: string; cancelled?: boolean; error?: string }>;
         openDownloadsFolder: () => Promise<void>;
-        showFileInFolder: (filePath: string) => Promise<void>;
+        showFileInFolder: (filePath: string) => Promise<{ success: boolean; error?: string }>;
         getObjectMetadata: (
           bucket: string,
           key: string
-        ) => Promise<{ success: boolean; metadata?: ObjectMetadata; error?: string }>;
+        ) => Promise<{ success: boolean; metadata?: ObjectMetadata; notFound?: boolean; error?: string }>;
+        getObjectTags: (
+          bucket: string,
+          key: string
+        ) => Promise<{ success: boolean; tags?: Record<string, string>; error?: string }>;
+        putObjectTags: (bucket: string, key: string, tags: Record<string, string>) => Promise<FileOperationResult>;
+        selectObjectContent: (
+          bucket: string,
+          key: string,
+          expression: string,
+          input: SelectFormat,
+          output: SelectFormat,
+          operationId: string
+        ) => Promise<SelectResult>;
+        listMultipartUploads: (
+          bucket: string,
+          prefix?: string
+        ) => Promise<{ success: boolean; uploads?: MultipartUpload[]; error?: string }>;
+        abortMultipartUpload: (bucket: string, key: string, uploadId: string) => Promise<FileOperationResult>;
         createFile: (bucket: string, key: string) => Promise<FileOperationResult>;
         createFolder: (bucket: string, prefix: string) => Promise<FileOperationResult>;
       };
       getObjectMetadata: (
         bucket: string,
         key: string
-      ) => Promise<{ success: boolean; metadata?: ObjectMetadata; error?: string }>;
+      ) => Promise<{ success: boolean; metadata?: ObjectMetadata; notFound?: boolean; error?: string }>;
     };
   }
 }
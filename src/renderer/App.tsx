@@ -1,4 +1,4 @@
-import React, { useState, useCallback, useEffect, useRef } from 'react';
+import React, { useState, useCallback, useEffect, useRef, useMemo } from 'react';
 import ErrorBoundary from './components/ErrorBoundary';
 import NetworkStatusBanner from './components/NetworkStatusBanner';
 import ProfileSelector from './components/ProfileSelector';
@@ -7,9 +7,13 @@ import FileList, { type S3Object } from './components/FileList';
 import FileToolbar from './components/FileToolbar';
 import NavigationBar from './components/NavigationBar';
 import RenameDialog from './components/RenameDialog';
+import CopyToProfileDialog from './components/CopyToProfileDialog';
 import DeleteConfirmDialog from './components/DeleteConfirmDialog';
 import PropertiesDialog from './components/PropertiesDialog';
+import SelectQueryDialog from './components/SelectQueryDialog';
+import IncompleteUploadsDialog from './components/IncompleteUploadsDialog';
 import NewItemDialog, { type NewItemType } from './components/NewItemDialog';
+import CreateBucketDialog from './components/CreateBucketDialog';
 import OperationStatus from './components/OperationStatus';
 import TextEditor from './components/TextEditor';
 import ParquetViewer from './components/ParquetViewer';
@@ -17,13 +21,29 @@ import CsvViewer from './components/CsvViewer';
 import JsonViewer from './components/JsonViewer';
 import YamlViewer from './components/YamlViewer';
 import ImagePreview from './components/ImagePreview';
+import HexViewer from './components/HexViewer';
+import SettingsDialog from './components/SettingsDialog';
+import MfaCodeDialog from './components/MfaCodeDialog';
+import CommandPalette from './components/CommandPalette';
 import StatusBar from './components/StatusBar';
 import { ToastContainer, useToasts } from './components/Toast';
-import { useAwsProfiles } from './context/AwsProfileContext';
+import { useAwsProfiles, ANONYMOUS_PROFILE_NAME } from './context/AwsProfileContext';
 import { useFileOperations } from './hooks/useFileOperations';
+import { useElapsedSeconds } from './hooks/useElapsedSeconds';
+import { useReadOnlyMode } from './hooks/useReadOnlyMode';
+import { consoleUrl } from './utils/consoleUrl';
+import {
+  addRecentLocation,
+  bookmarkLabel,
+  commandPaletteCandidates,
+  type Bookmark,
+  type PaletteCommand,
+  type RecentLocation,
+} from './utils/commandPalette';
+import { defaultViewerRegistry, SNIFF_BYTES, type ViewerKind } from './utils/viewerRegistry';
 
 function App(): React.ReactElement {
-  const { currentProfile, profileRestored } = useAwsProfiles();
+  const { profiles, currentProfile, defaultRegion, profileRestored } = useAwsProfiles();
   const { toasts, addToast, removeToast } = useToasts();
 
   // Callback for when download completes - shows toast with action to reveal file
@@ -36,8 +56,15 @@ function App(): React.ReactElement {
         duration: 8000, // Longer duration for user to click the action
         action: {
           label: 'Show in folder',
-          onClick: () => {
-            window.electronAPI.s3.showFileInFolder(localPath);
+          onClick: async () => {
+            const result = await window.electronAPI.s3.showFileInFolder(localPath);
+            if (!result.success) {
+              addToast({
+                type: 'error',
+                title: 'Could Not Show File',
+                message: result.error,
+              });
+            }
           },
         },
       });
@@ -49,11 +76,15 @@ function App(): React.ReactElement {
     operations,
     isLoading,
     downloadFile,
+    downloadFiles,
+    openExternally,
     uploadFiles,
     deleteFile,
     deleteFiles,
     deletePrefix,
     renameFile,
+    renamePrefix,
+    cancelOperation,
     dismissOperation,
   } = useFileOperations({ onDownloadComplete: handleDownloadComplete });
 
@@ -65,6 +96,7 @@ function App(): React.ReactElement {
 
   // Dialog state
   const [isRenameOpen, setIsRenameOpen] = useState(false);
+  const [isCopyToProfileOpen, setIsCopyToProfileOpen] = useState(false);
   const [isDeleteOpen, setIsDeleteOpen] = useState(false);
   const [isEditorOpen, setIsEditorOpen] = useState(false);
   const [isParquetViewerOpen, setIsParquetViewerOpen] = useState(false);
@@ -72,17 +104,54 @@ function App(): React.ReactElement {
   const [isJsonViewerOpen, setIsJsonViewerOpen] = useState(false);
   const [isYamlViewerOpen, setIsYamlViewerOpen] = useState(false);
   const [isImagePreviewOpen, setIsImagePreviewOpen] = useState(false);
+  const [isHexViewerOpen, setIsHexViewerOpen] = useState(false);
   const [isPropertiesOpen, setIsPropertiesOpen] = useState(false);
+  const [isQueryOpen, setIsQueryOpen] = useState(false);
+  const [isIncompleteUploadsOpen, setIsIncompleteUploadsOpen] = useState(false);
   const [isNewItemOpen, setIsNewItemOpen] = useState(false);
+  const [isSettingsOpen, setIsSettingsOpen] = useState(false);
+  const [isCreateBucketOpen, setIsCreateBucketOpen] = useState(false);
+  // Bumped to make the bucket tree reload (e.g. after creating a bucket)
+  const [bucketListVersion, setBucketListVersion] = useState(0);
+  // Opt-in: jump through folders that only contain a single subfolder
+  const [collapseFolders, setCollapseFolders] = useState(false);
+  // Show the delete confirmation dialog (on unless turned off in settings)
+  const [confirmBeforeDelete, setConfirmBeforeDelete] = useState(true);
+  // Prefixes skipped by a collapsed jump; the breadcrumb merges them into the next crumb
+  const [collapsedPrefixes, setCollapsedPrefixes] = useState<ReadonlySet<string>>(new Set());
+  const collapseOperationRef = useRef<string | null>(null);
+  const [isPaletteOpen, setIsPaletteOpen] = useState(false);
+  const [paletteBuckets, setPaletteBuckets] = useState<string[]>([]);
+  // Kept in a ref so recording a visit doesn't re-run the save effect
+  const recentLocationsRef = useRef<RecentLocation[]>([]);
+  const [bookmarks, setBookmarks] = useState<Bookmark[]>([]);
   const [newItemType, setNewItemType] = useState<NewItemType>('file');
 
   // Pending file selection (for URL navigation that points to a file)
   const [pendingFileSelection, setPendingFileSelection] = useState<string | null>(null);
+  // Pending multi-file selection (for pasted lists of URLs)
+  const [pendingMultiSelection, setPendingMultiSelection] = useState<string[] | null>(null);
+  // Object version named by the URL navigated to, opened instead of the latest one
+  const [urlVersion, setUrlVersion] = useState<{ bucket: string; key: string; versionId: string } | null>(null);
 
   // Status bar state
   const [itemCount, setItemCount] = useState(0);
   const [allItemsLoaded, setAllItemsLoaded] = useState(false);
   const [isLoadingItems, setIsLoadingItems] = useState(false);
+  const loadingElapsedSeconds = useElapsedSeconds(isLoadingItems);
+  const { isReadOnly, setReadOnly, reload: reloadReadOnly } = useReadOnlyMode(currentProfile);
+
+  // Mutating handlers call this first; shows the notice and returns true when they must stop
+  const blockIfReadOnly = useCallback((): boolean => {
+    if (!isReadOnly) return false;
+    addToast({
+      type: 'warning',
+      title: 'Read-Only Mode',
+      message: 'Read-only mode is enabled',
+      duration: 3000,
+    });
+    return true;
+  }, [isReadOnly, addToast]);
 
   // Track if initial state has been restored
   const initialStateRestored = useRef(false);
@@ -93,17 +162,50 @@ function App(): React.ReactElement {
     if (profileRestored && currentProfile && !initialStateRestored.current) {
       initialStateRestored.current = true;
       // Load saved bucket/prefix for this profile
-      window.electronAPI.appState.load().then(savedState => {
+      window.electronAPI.appState.load().then(async savedState => {
         // Only restore if the saved profile matches the current profile
-        if (savedState.lastProfile === currentProfile && savedState.lastBucket) {
-          setSelectedBucket(savedState.lastBucket);
-          setCurrentPrefix(savedState.lastPrefix || '');
+        if (savedState.lastProfile !== currentProfile || !savedState.lastBucket) {
+          return;
+        }
+
+        // Make sure the bucket still exists and is accessible. If the bucket list
+        // can't be fetched at all we can't tell, so restore and let the listing report errors.
+        const bucketsResult = await window.electronAPI.s3.listBuckets();
+        let bucketGone =
+          bucketsResult.success &&
+          !(bucketsResult.buckets ?? []).some(b => b.name === savedState.lastBucket);
+
+        // The bucket list leaves out buckets of other accounts and ones opened by URL,
+        // so only give up on the location once it can't be listed either
+        if (bucketGone) {
+          const probe = await window.electronAPI.s3.listObjects({
+            bucket: savedState.lastBucket,
+            prefix: savedState.lastPrefix || '',
+            maxKeys: 1,
+          });
+          bucketGone = !probe.success;
         }
+
+        if (bucketGone) {
+          const lastLocation = `s3://${savedState.lastBucket}/${savedState.lastPrefix || ''}`;
+          addToast({
+            type: 'warning',
+            title: 'Location Unavailable',
+            message: `Last location '${lastLocation}' is no longer accessible`,
+            duration: 8000,
+          });
+          // Forget the stale location but keep the profile
+          await window.electronAPI.appState.save({ lastBucket: null, lastPrefix: '' });
+          return;
+        }
+
+        setSelectedBucket(savedState.lastBucket);
+        setCurrentPrefix(savedState.lastPrefix || '');
       }).catch(err => {
         console.warn('Failed to restore navigation state:', err);
       });
     }
-  }, [profileRestored, currentProfile]);
+  }, [profileRestored, currentProfile, addToast]);
 
   // Reset navigation when profile changes (but not on initial restore)
   useEffect(() => {
@@ -118,6 +220,7 @@ function App(): React.ReactElement {
       setSelectedBucket(null);
       setCurrentPrefix('');
       setSelectedFile(null);
+      setUrlVersion(null);
     }
   }, [currentProfile]);
 
@@ -127,10 +230,18 @@ function App(): React.ReactElement {
     if (!profileRestored) return;
 
     const saveTimeout = setTimeout(() => {
+      if (currentProfile && selectedBucket) {
+        recentLocationsRef.current = addRecentLocation(recentLocationsRef.current, {
+          profile: currentProfile,
+          bucket: selectedBucket,
+          prefix: currentPrefix,
+        });
+      }
       window.electronAPI.appState.save({
         lastProfile: currentProfile,
         lastBucket: selectedBucket,
         lastPrefix: currentPrefix,
+        recentLocations: recentLocationsRef.current,
       }).catch(err => {
         console.warn('Failed to save app state:', err);
       });
@@ -139,31 +250,138 @@ function App(): React.ReactElement {
     return () => clearTimeout(saveTimeout);
   }, [profileRestored, currentProfile, selectedBucket, currentPrefix]);
 
+  // Load recent locations and bookmarks once so the palette can offer them
+  useEffect(() => {
+    window.electronAPI.appState.load().then(state => {
+      setBookmarks(state.bookmarks ?? []);
+      // Keep anything visited while the state was loading in front
+      recentLocationsRef.current = [...recentLocationsRef.current, ...(state.recentLocations ?? [])]
+        .reverse()
+        .reduce<RecentLocation[]>((recent, location) => addRecentLocation(recent, location), []);
+    }).catch(err => {
+      console.warn('Failed to load recent locations:', err);
+    });
+  }, []);
+
+  // Imported locations and read-only profiles were merged into the saved state
+  const handleSettingsImported = useCallback(() => {
+    reloadReadOnly();
+    window.electronAPI.appState.load().then(state => {
+      recentLocationsRef.current = state.recentLocations ?? [];
+      setBookmarks(state.bookmarks ?? []);
+    }).catch(err => {
+      console.warn('Failed to load recent locations:', err);
+    });
+  }, [reloadReadOnly]);
+
+  // Pick up the folder collapsing and delete confirmation preferences on start and whenever settings close
+  useEffect(() => {
+    if (isSettingsOpen) return;
+    window.electronAPI.appState.load().then(state => {
+      setCollapseFolders(state.collapseSingleChildFolders === true);
+      setConfirmBeforeDelete(state.confirmBeforeDelete !== false);
+    }).catch(err => {
+      console.warn('Failed to load settings:', err);
+    });
+  }, [isSettingsOpen]);
+
+  const cancelPendingCollapse = useCallback(() => {
+    if (collapseOperationRef.current) {
+      window.electronAPI.s3.cancelOperation(collapseOperationRef.current);
+      collapseOperationRef.current = null;
+    }
+  }, []);
+
   const handleSelectBucket = useCallback((bucket: string) => {
+    cancelPendingCollapse();
     setSelectedBucket(bucket);
     setCurrentPrefix('');
     setSelectedFile(null);
     setSelectedFiles([]);
-  }, []);
+    setCollapsedPrefixes(new Set());
+    setUrlVersion(null);
+  }, [cancelPendingCollapse]);
 
   const handleNavigate = useCallback((prefix: string) => {
-    setCurrentPrefix(prefix);
+    cancelPendingCollapse();
     setSelectedFile(null);
     setSelectedFiles([]);
     setPendingFileSelection(null);
-  }, []);
+    setUrlVersion(null);
+
+    // Only collapse when descending into a subfolder, so going up stays where the user asked
+    const descending = prefix !== currentPrefix && prefix.startsWith(currentPrefix);
+    if (!collapseFolders || !selectedBucket || !descending) {
+      setCurrentPrefix(prefix);
+      return;
+    }
+
+    const operationId = `collapse-${Date.now()}`;
+    collapseOperationRef.current = operationId;
+    window.electronAPI.s3.collapsePrefixChain(selectedBucket, prefix, operationId).then(result => {
+      if (collapseOperationRef.current !== operationId) return;
+      collapseOperationRef.current = null;
+
+      const target = result.success && result.prefix ? result.prefix : prefix;
+      if (target !== prefix) {
+        // Remember each folder skipped on the way so the breadcrumb shows the chain as one step
+        const skipped = new Set<string>();
+        let skippedPrefix = prefix;
+        while (skippedPrefix.length < target.length) {
+          skipped.add(skippedPrefix);
+          skippedPrefix = target.slice(0, target.indexOf('/', skippedPrefix.length) + 1);
+        }
+        setCollapsedPrefixes(prev => new Set([...prev, ...skipped]));
+      }
+      setCurrentPrefix(target);
+    }).catch(err => {
+      console.warn('Failed to collapse folder chain:', err);
+      if (collapseOperationRef.current === operationId) {
+        collapseOperationRef.current = null;
+        setCurrentPrefix(prefix);
+      }
+    });
+  }, [cancelPendingCollapse, collapseFolders, selectedBucket, currentPrefix]);
 
   // Handler for URL-based navigation (from NavigationBar)
-  const handleUrlNavigate = useCallback((bucket: string, prefix: string, selectKey?: string) => {
+  const handleUrlNavigate = useCallback((
+    bucket: string,
+    prefix: string,
+    selectKey?: string | string[],
+    versionId?: string
+  ) => {
+    cancelPendingCollapse();
     setSelectedBucket(bucket);
     setCurrentPrefix(prefix);
     setSelectedFile(null);
     setSelectedFiles([]);
-    // If a specific file key was provided, set it as pending selection
-    setPendingFileSelection(selectKey || null);
+    setCollapsedPrefixes(new Set());
+    // If specific file keys were provided, set them as pending selection
+    if (Array.isArray(selectKey)) {
+      setPendingFileSelection(null);
+      setPendingMultiSelection(selectKey.length > 0 ? selectKey : null);
+    } else {
+      setPendingFileSelection(selectKey || null);
+      setPendingMultiSelection(null);
+    }
+    setUrlVersion(versionId && typeof selectKey === 'string' ? { bucket, key: selectKey, versionId } : null);
     // Trigger refresh to load the new location
     window.dispatchEvent(new Event('s3-refresh-files'));
-  }, []);
+  }, [cancelPendingCollapse]);
+
+  // Version of a file to open, when the URL navigated to named one
+  const versionOf = useCallback((key: string): string | undefined => {
+    return urlVersion && urlVersion.bucket === selectedBucket && urlVersion.key === key
+      ? urlVersion.versionId
+      : undefined;
+  }, [urlVersion, selectedBucket]);
+  const selectedVersionId = selectedFile ? versionOf(selectedFile.key) : undefined;
+
+  // Latest selection, so async handlers can tell when the user has moved on
+  const selectedFileRef = useRef<S3Object | null>(null);
+  useEffect(() => {
+    selectedFileRef.current = selectedFile;
+  }, [selectedFile]);
 
   const handleSelectFile = useCallback((file: S3Object | null) => {
     setSelectedFile(file);
@@ -175,17 +393,27 @@ function App(): React.ReactElement {
 
   // File operation handlers
   const handleUpload = useCallback(() => {
+    if (blockIfReadOnly()) return;
     if (!selectedBucket) return;
     uploadFiles(selectedBucket, currentPrefix).then(() => {
       // Trigger refresh after upload completes
       window.dispatchEvent(new Event('s3-refresh-files'));
     });
-  }, [selectedBucket, currentPrefix, uploadFiles]);
+  }, [selectedBucket, currentPrefix, uploadFiles, blockIfReadOnly]);
 
   const handleDownload = useCallback(() => {
-    if (!selectedBucket || !selectedFile || selectedFile.isPrefix) return;
+    if (!selectedBucket) return;
+    if (selectedFiles.length > 1) {
+      // Folders in the selection are left out; only files are downloaded
+      const keys = selectedFiles.filter(f => !f.isPrefix).map(f => f.key);
+      if (keys.length > 0) {
+        downloadFiles(selectedBucket, keys);
+      }
+      return;
+    }
+    if (!selectedFile || selectedFile.isPrefix) return;
     downloadFile(selectedBucket, selectedFile.key);
-  }, [selectedBucket, selectedFile, downloadFile]);
+  }, [selectedBucket, selectedFile, selectedFiles, downloadFile, downloadFiles]);
 
   // Handler for double-click download from FileList
   const handleDownloadFile = useCallback((file: S3Object) => {
@@ -193,14 +421,9 @@ function App(): React.ReactElement {
     downloadFile(selectedBucket, file.key);
   }, [selectedBucket, downloadFile]);
 
-  const handleDelete = useCallback(() => {
-    // Allow delete if there are selected items (files or folders)
-    if (selectedFiles.length === 0) return;
-    setIsDeleteOpen(true);
-  }, [selectedFiles]);
-
   const handleConfirmDelete = useCallback(async () => {
     if (!selectedBucket || selectedFiles.length === 0) return;
+    if (blockIfReadOnly()) return;
     setIsDeleteOpen(false);
 
     // Separate folders and files
@@ -268,12 +491,25 @@ function App(): React.ReactElement {
         duration: 5000,
       });
     }
-  }, [selectedBucket, selectedFiles, deleteFile, deleteFiles, deletePrefix, addToast]);
+  }, [selectedBucket, selectedFiles, deleteFile, deleteFiles, deletePrefix, addToast, blockIfReadOnly]);
+
+  const handleDelete = useCallback(() => {
+    // Allow delete if there are selected items (files or folders)
+    if (selectedFiles.length === 0) return;
+    if (blockIfReadOnly()) return;
+    // Folders delete everything under them, so they are always confirmed
+    if (confirmBeforeDelete || selectedFiles.some(file => file.isPrefix)) {
+      setIsDeleteOpen(true);
+    } else {
+      handleConfirmDelete();
+    }
+  }, [selectedFiles, blockIfReadOnly, confirmBeforeDelete, handleConfirmDelete]);
 
   const handleRename = useCallback(() => {
     if (!selectedFile || selectedFile.isPrefix) return;
+    if (blockIfReadOnly()) return;
     setIsRenameOpen(true);
-  }, [selectedFile]);
+  }, [selectedFile, blockIfReadOnly]);
 
   const handleEdit = useCallback(() => {
     if (!selectedFile || selectedFile.isPrefix) return;
@@ -330,10 +566,93 @@ function App(): React.ReactElement {
     setIsImagePreviewOpen(true);
   }, [selectedFile]);
 
+  const handleQuery = useCallback(() => {
+    if (!selectedFile || selectedFile.isPrefix) return;
+    setIsQueryOpen(true);
+  }, [selectedFile]);
+
+  const handleOpenExternally = useCallback(() => {
+    if (!selectedBucket || !selectedFile || selectedFile.isPrefix) return;
+    openExternally(selectedBucket, selectedFile.key);
+  }, [selectedBucket, selectedFile, openExternally]);
+
   const handleImagePreviewClose = useCallback(() => {
     setIsImagePreviewOpen(false);
   }, []);
 
+  const handleHexViewerClose = useCallback(() => {
+    setIsHexViewerOpen(false);
+  }, []);
+
+  // Double-click on a file: open it in the viewer resolved from its name, then
+  // its Content-Type, sniffing the first bytes when neither is recognized
+  const handleOpenFile = useCallback(async (file: S3Object) => {
+    if (!selectedBucket || file.isPrefix) return;
+    setSelectedFile(file);
+    setSelectedFiles([file]);
+    selectedFileRef.current = file;
+    // Viewers open the selected file, so give up if another one was selected while a request was out
+    const stillSelected = () => selectedFileRef.current?.key === file.key;
+
+    const viewerOpeners: Record<ViewerKind, () => void> = {
+      parquet: () => setIsParquetViewerOpen(true),
+      csv: () => setIsCsvViewerOpen(true),
+      json: () => setIsJsonViewerOpen(true),
+      yaml: () => setIsYamlViewerOpen(true),
+      image: () => setIsImagePreviewOpen(true),
+      text: () => setIsEditorOpen(true),
+      hex: () => setIsHexViewerOpen(true),
+    };
+
+    // The listing describes the latest version, so read the size and type of the one asked for
+    const versionId = versionOf(file.key);
+    if (versionId) {
+      const head = await window.electronAPI.s3.headObject(selectedBucket, file.key, versionId);
+      if (!stillSelected()) return;
+      if (head.success && head.head) {
+        const versionFile = {
+          ...file,
+          size: head.head.contentLength,
+          contentType: head.head.contentType,
+          etag: head.head.etag,
+          lastModified: head.head.lastModified,
+        };
+        setSelectedFile(versionFile);
+        setSelectedFiles([versionFile]);
+        file = versionFile;
+      }
+    }
+
+    let kind = defaultViewerRegistry.resolveByExtension(file.key);
+    if (!kind) {
+      // Listings don't include the Content-Type, so read it now and keep it on the file
+      let contentType = file.contentType;
+      if (contentType === undefined && !versionId) {
+        const head = await window.electronAPI.s3.headObject(selectedBucket, file.key);
+        if (!stillSelected()) return;
+        contentType = head.success ? head.head?.contentType : undefined;
+        if (contentType) {
+          const typedFile = { ...file, contentType };
+          setSelectedFile(typedFile);
+          setSelectedFiles([typedFile]);
+        }
+      }
+      kind = defaultViewerRegistry.resolveByContentType(contentType);
+    }
+    if (!kind) {
+      const result = await window.electronAPI.s3.downloadPartialContent(
+        selectedBucket,
+        file.key,
+        SNIFF_BYTES,
+        0,
+        versionId
+      );
+      if (!stillSelected()) return;
+      kind = defaultViewerRegistry.resolve(file.key, result.success ? result.data : undefined);
+    }
+    viewerOpeners[kind]();
+  }, [selectedBucket, versionOf]);
+
   const handleProperties = useCallback(() => {
     if (!selectedFile) return;
     setIsPropertiesOpen(true);
@@ -343,6 +662,16 @@ function App(): React.ReactElement {
     setIsPropertiesOpen(false);
   }, []);
 
+  const handleNavigateToObject = useCallback((bucket: string, key: string) => {
+    setIsPropertiesOpen(false);
+    // Folder targets open the folder; object targets open their folder with the object selected
+    if (!key || key.endsWith('/')) {
+      handleUrlNavigate(bucket, key);
+    } else {
+      handleUrlNavigate(bucket, key.slice(0, key.lastIndexOf('/') + 1), key);
+    }
+  }, [handleUrlNavigate]);
+
   const handleCopyUrl = useCallback(async () => {
     if (!selectedBucket || !selectedFile || selectedFile.isPrefix) return;
 
@@ -366,11 +695,169 @@ function App(): React.ReactElement {
     }
   }, [selectedBucket, selectedFile, addToast]);
 
+  const handleCopyCurrentPath = useCallback(async () => {
+    if (!selectedBucket) return;
+
+    const s3Path = `s3://${selectedBucket}/${currentPrefix}`;
+    try {
+      await navigator.clipboard.writeText(s3Path);
+      addToast({
+        type: 'success',
+        title: 'Path Copied',
+        message: s3Path,
+        duration: 3000,
+      });
+    } catch (err) {
+      console.error('Failed to copy path to clipboard:', err);
+      addToast({
+        type: 'error',
+        title: 'Copy Failed',
+        message: 'Failed to copy path to clipboard',
+        duration: 5000,
+      });
+    }
+  }, [selectedBucket, currentPrefix, addToast]);
+
+  const isBookmarked = bookmarks.some(
+    b => b.profile === currentProfile && b.bucket === selectedBucket && b.prefix === currentPrefix
+  );
+
+  const handleAddBookmark = useCallback(async () => {
+    if (!currentProfile || !selectedBucket) return;
+
+    const label = bookmarkLabel(selectedBucket, currentPrefix);
+    const result = await window.electronAPI.appState.addBookmark({
+      label,
+      profile: currentProfile,
+      bucket: selectedBucket,
+      prefix: currentPrefix,
+    });
+    if (result.success && result.bookmarks) {
+      setBookmarks(result.bookmarks);
+      addToast({ type: 'success', title: 'Bookmark Added', message: label, duration: 3000 });
+    } else {
+      addToast({
+        type: 'error',
+        title: 'Bookmark Failed',
+        message: result.error || 'Failed to save bookmark',
+        duration: 5000,
+      });
+    }
+  }, [currentProfile, selectedBucket, currentPrefix, addToast]);
+
+  const handleRemoveBookmark = useCallback(async () => {
+    if (!currentProfile || !selectedBucket) return;
+
+    const result = await window.electronAPI.appState.removeBookmark({
+      profile: currentProfile,
+      bucket: selectedBucket,
+      prefix: currentPrefix,
+    });
+    if (result.success && result.bookmarks) {
+      setBookmarks(result.bookmarks);
+    } else {
+      addToast({
+        type: 'error',
+        title: 'Bookmark Failed',
+        message: result.error || 'Failed to remove bookmark',
+        duration: 5000,
+      });
+    }
+  }, [currentProfile, selectedBucket, currentPrefix, addToast]);
+
+  const currentProfileInfo = profiles.find(profile => profile.name === currentProfile);
+  const currentRegion =
+    currentProfileInfo?.resolvedRegion ?? currentProfileInfo?.region ?? defaultRegion ?? 'us-east-1';
+
+  const handleOpenInConsole = useCallback(() => {
+    if (!selectedBucket) return;
+
+    // window.open is routed to the default browser by the main window's open handler
+    window.open(consoleUrl(currentRegion, selectedBucket, currentPrefix), '_blank');
+  }, [selectedBucket, currentPrefix, currentRegion]);
+
+  const handleCreateBucket = useCallback(async (name: string, region: string): Promise<string | null> => {
+    const result = await window.electronAPI.s3.createBucket(name, region);
+    if (!result.success) {
+      return result.error ?? 'Failed to create bucket';
+    }
+
+    setIsCreateBucketOpen(false);
+    setBucketListVersion(version => version + 1);
+    addToast({
+      type: 'success',
+      title: 'Bucket Created',
+      message: `${name} (${region})`,
+      duration: 3000,
+    });
+    handleSelectBucket(name);
+    return null;
+  }, [addToast, handleSelectBucket]);
+
+  // Ctrl+Shift+C (Cmd+Shift+C on macOS) copies the current location
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if ((e.ctrlKey || e.metaKey) && e.shiftKey && e.key.toLowerCase() === 'c') {
+        e.preventDefault();
+        handleCopyCurrentPath();
+      }
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [handleCopyCurrentPath]);
+
+  const handleOpenPalette = useCallback(() => {
+    setIsPaletteOpen(true);
+    window.electronAPI.s3.listBuckets().then(result => {
+      if (result.success) {
+        setPaletteBuckets((result.buckets ?? []).map(bucket => bucket.name));
+      }
+    }).catch(err => {
+      console.warn('Failed to list buckets for the command palette:', err);
+    });
+  }, []);
+
+  // Ctrl+P (Cmd+P on macOS) opens the command palette
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if ((e.ctrlKey || e.metaKey) && !e.shiftKey && e.key.toLowerCase() === 'p') {
+        e.preventDefault();
+        handleOpenPalette();
+      }
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [handleOpenPalette]);
+
   const handleConfirmRename = useCallback(
     async (newName: string) => {
       if (!selectedBucket || !selectedFile) return;
+      if (blockIfReadOnly()) return;
       setIsRenameOpen(false);
 
+      if (selectedFile.isPrefix) {
+        const result = await renamePrefix(selectedBucket, selectedFile.key, newName);
+        if (result.movedCount > 0) {
+          setSelectedFile(null);
+          window.dispatchEvent(new Event('s3-refresh-files'));
+        }
+        if (!result.success) {
+          const failed = result.failures.length;
+          addToast({
+            type: 'warning',
+            title: 'Rename Failed',
+            message:
+              failed > 0
+                ? `Moved ${result.movedCount} item(s), ${failed} failed: ${result.failures[0].error}`
+                : result.error || 'Failed to rename folder',
+            duration: 5000,
+          });
+        }
+        return;
+      }
+
       const success = await renameFile(selectedBucket, selectedFile.key, newName);
       if (success) {
         setSelectedFile(null);
@@ -378,7 +865,46 @@ function App(): React.ReactElement {
         window.dispatchEvent(new Event('s3-refresh-files'));
       }
     },
-    [selectedBucket, selectedFile, renameFile]
+    [selectedBucket, selectedFile, renameFile, renamePrefix, blockIfReadOnly, addToast]
+  );
+
+  const handleCopyToProfile = useCallback(() => {
+    if (!selectedFile || selectedFile.isPrefix) return;
+    setIsCopyToProfileOpen(true);
+  }, [selectedFile]);
+
+  const handleConfirmCopyToProfile = useCallback(
+    async (destinationProfile: string, destinationBucket: string, destinationKey: string) => {
+      if (!selectedBucket || !selectedFile) return;
+      setIsCopyToProfileOpen(false);
+
+      const result = await window.electronAPI.s3.transferObject(
+        selectedBucket,
+        selectedFile.key,
+        destinationProfile,
+        destinationBucket,
+        destinationKey
+      );
+
+      if (result.success) {
+        addToast({
+          type: 'success',
+          title: 'File Copied',
+          message: `s3://${destinationBucket}/${destinationKey} (${destinationProfile})`,
+          duration: 3000,
+        });
+        // Refresh in case the destination is the bucket being browsed
+        window.dispatchEvent(new Event('s3-refresh-files'));
+      } else {
+        addToast({
+          type: 'error',
+          title: 'Copy Failed',
+          message: result.error || 'Failed to copy file',
+          duration: 5000,
+        });
+      }
+    },
+    [selectedBucket, selectedFile, addToast]
   );
 
   const handleRefresh = useCallback(() => {
@@ -386,18 +912,21 @@ function App(): React.ReactElement {
   }, []);
 
   const handleNewFile = useCallback(() => {
+    if (blockIfReadOnly()) return;
     setNewItemType('file');
     setIsNewItemOpen(true);
-  }, []);
+  }, [blockIfReadOnly]);
 
   const handleNewFolder = useCallback(() => {
+    if (blockIfReadOnly()) return;
     setNewItemType('folder');
     setIsNewItemOpen(true);
-  }, []);
+  }, [blockIfReadOnly]);
 
   const handleConfirmNewItem = useCallback(
     async (name: string) => {
       if (!selectedBucket) return;
+      if (blockIfReadOnly()) return;
       setIsNewItemOpen(false);
 
       const key = currentPrefix ? `${currentPrefix}${name}` : name;
@@ -430,13 +959,109 @@ function App(): React.ReactElement {
         });
       }
     },
-    [selectedBucket, currentPrefix, newItemType, addToast]
+    [selectedBucket, currentPrefix, newItemType, addToast, blockIfReadOnly]
+  );
+
+  const paletteCommands = useMemo(
+    () =>
+      isPaletteOpen
+        ? commandPaletteCandidates({
+            buckets: paletteBuckets,
+            recentLocations: recentLocationsRef.current,
+            bookmarks,
+            currentProfile,
+            selectedBucket,
+            isBookmarked,
+            hasFileSelected: !!selectedFile && !selectedFile.isPrefix && selectedFiles.length <= 1,
+            isReadOnly,
+          })
+        : [],
+    [
+      isPaletteOpen,
+      paletteBuckets,
+      bookmarks,
+      currentProfile,
+      selectedBucket,
+      isBookmarked,
+      selectedFile,
+      selectedFiles,
+      isReadOnly,
+    ]
+  );
+
+  // Route the chosen palette command to the same handlers as the toolbar and tree
+  const handlePaletteSelect = useCallback(
+    (command: PaletteCommand) => {
+      setIsPaletteOpen(false);
+      switch (command.kind) {
+        case 'bucket':
+          handleSelectBucket(command.bucket);
+          break;
+        case 'location':
+          handleUrlNavigate(command.bucket, command.prefix);
+          break;
+        case 'action':
+          switch (command.action) {
+            case 'refresh':
+              handleRefresh();
+              break;
+            case 'upload':
+              handleUpload();
+              break;
+            case 'new-file':
+              handleNewFile();
+              break;
+            case 'new-folder':
+              handleNewFolder();
+              break;
+            case 'copy-url':
+              handleCopyUrl();
+              break;
+            case 'copy-path':
+              handleCopyCurrentPath();
+              break;
+            case 'open-console':
+              handleOpenInConsole();
+              break;
+            case 'incomplete-uploads':
+              setIsIncompleteUploadsOpen(true);
+              break;
+            case 'add-bookmark':
+              handleAddBookmark();
+              break;
+            case 'remove-bookmark':
+              handleRemoveBookmark();
+              break;
+            case 'settings':
+              setIsSettingsOpen(true);
+              break;
+          }
+          break;
+      }
+    },
+    [
+      handleSelectBucket,
+      handleUrlNavigate,
+      handleRefresh,
+      handleUpload,
+      handleNewFile,
+      handleNewFolder,
+      handleCopyUrl,
+      handleCopyCurrentPath,
+      handleOpenInConsole,
+      handleAddBookmark,
+      handleRemoveBookmark,
+    ]
   );
 
   const handlePendingFileSelectionHandled = useCallback(() => {
     setPendingFileSelection(null);
   }, []);
 
+  const handlePendingMultiSelectionHandled = useCallback(() => {
+    setPendingMultiSelection(null);
+  }, []);
+
   const handleItemCountChange = useCallback(
     (count: number, allLoaded: boolean, loading: boolean) => {
       setItemCount(count);
@@ -448,15 +1073,23 @@ function App(): React.ReactElement {
 
   const handleFilesDropped = useCallback(
     (filePaths: string[]) => {
+      if (blockIfReadOnly()) return;
       if (!selectedBucket) return;
       uploadFiles(selectedBucket, currentPrefix, filePaths).then(() => {
         // Trigger refresh after upload completes
         window.dispatchEvent(new Event('s3-refresh-files'));
       });
     },
-    [selectedBucket, currentPrefix, uploadFiles]
+    [selectedBucket, currentPrefix, uploadFiles, blockIfReadOnly]
   );
 
+  // First running transfer with a speed estimate, shown in the status bar
+  const activeTransfer = useMemo(() => {
+    const op = operations.find(o => o.status === 'in-progress' && o.speedBps !== undefined);
+    if (!op || op.type === 'rename' || op.speedBps === undefined) return undefined;
+    return { type: op.type, fileName: op.fileName, speedBps: op.speedBps, etaSeconds: op.etaSeconds };
+  }, [operations]);
+
   // Build display path for header
   const getDisplayPath = (): string => {
     if (!selectedBucket) return 'Files';
@@ -473,17 +1106,54 @@ function App(): React.ReactElement {
             <h1>S3 Browser</h1>
           </div>
           <ProfileSelector />
+          {currentProfile && (
+            <label
+              className={`read-only-toggle ${isReadOnly ? 'active' : ''}`}
+              title={
+                currentProfile === ANONYMOUS_PROFILE_NAME
+                  ? 'Anonymous access can only read public buckets'
+                  : 'Refuse uploads, deletes, renames and new items for this profile'
+              }
+            >
+              <input
+                type="checkbox"
+                checked={isReadOnly}
+                onChange={(e) => setReadOnly(e.target.checked)}
+                disabled={currentProfile === ANONYMOUS_PROFILE_NAME}
+              />
+              Read-only
+            </label>
+          )}
+          <button
+            className="header-settings-btn"
+            onClick={() => setIsSettingsOpen(true)}
+            title="Settings"
+          >
+            Settings
+          </button>
         </header>
         <main className="app-main">
         <aside className="sidebar">
           <div className="sidebar-header">
             <h2>Buckets</h2>
+            {currentProfile && (
+              <button
+                className="sidebar-header-btn"
+                onClick={() => setIsCreateBucketOpen(true)}
+                disabled={isReadOnly}
+                title={isReadOnly ? 'Read-only mode is enabled' : 'New bucket'}
+                aria-label="New bucket"
+              >
+                +
+              </button>
+            )}
           </div>
           <div className="tree-view">
             <BucketTree
               currentProfile={currentProfile}
               selectedBucket={selectedBucket}
               onSelectBucket={handleSelectBucket}
+              refreshKey={bucketListVersion}
             />
           </div>
         </aside>
@@ -526,7 +1196,11 @@ function App(): React.ReactElement {
             onViewJson={handleViewJson}
             onViewYaml={handleViewYaml}
             onViewImage={handleViewImage}
+            onQuery={handleQuery}
+            onOpenExternally={handleOpenExternally}
             onCopyUrl={handleCopyUrl}
+            onCopyToProfile={handleCopyToProfile}
+            onOpenInConsole={handleOpenInConsole}
             onRefresh={handleRefresh}
             onProperties={handleProperties}
             onNewFile={handleNewFile}
@@ -539,6 +1213,7 @@ function App(): React.ReactElement {
               selectedBucket={selectedBucket}
               currentPrefix={currentPrefix}
               onNavigate={handleNavigate}
+              collapsedPrefixes={collapsedPrefixes}
               onSelectFile={handleSelectFile}
               selectedFile={selectedFile}
               selectedFiles={selectedFiles}
@@ -546,8 +1221,11 @@ function App(): React.ReactElement {
               onFilesDropped={handleFilesDropped}
               pendingFileSelection={pendingFileSelection}
               onPendingFileSelectionHandled={handlePendingFileSelectionHandled}
+              pendingMultiSelection={pendingMultiSelection}
+              onPendingMultiSelectionHandled={handlePendingMultiSelectionHandled}
               onItemCountChange={handleItemCountChange}
               onDownloadFile={handleDownloadFile}
+              onOpenFile={handleOpenFile}
             />
           </div>
           <StatusBar
@@ -555,20 +1233,33 @@ function App(): React.ReactElement {
             allLoaded={allItemsLoaded}
             selectedFiles={selectedFiles}
             loading={isLoadingItems}
+            elapsedSeconds={loadingElapsedSeconds}
+            activeTransfer={activeTransfer}
           />
         </section>
       </main>
 
       {/* Operation status */}
-      <OperationStatus operations={operations} onDismiss={dismissOperation} />
+      <OperationStatus operations={operations} onDismiss={dismissOperation} onCancel={cancelOperation} />
 
       {/* Dialogs */}
       <RenameDialog
         isOpen={isRenameOpen}
-        currentName={selectedFile?.key.split('/').pop() || ''}
+        currentName={selectedFile?.key.replace(/\/$/, '').split('/').pop() || ''}
         onConfirm={handleConfirmRename}
         onCancel={() => setIsRenameOpen(false)}
       />
+      {selectedBucket && selectedFile && (
+        <CopyToProfileDialog
+          isOpen={isCopyToProfileOpen}
+          profileNames={profiles.map(p => p.name)}
+          currentProfile={currentProfile}
+          sourceBucket={selectedBucket}
+          sourceKey={selectedFile.key}
+          onConfirm={handleConfirmCopyToProfile}
+          onCancel={() => setIsCopyToProfileOpen(false)}
+        />
+      )}
       <DeleteConfirmDialog
         isOpen={isDeleteOpen}
         fileNames={selectedFiles.map(f => {
@@ -579,6 +1270,12 @@ function App(): React.ReactElement {
         onConfirm={handleConfirmDelete}
         onCancel={() => setIsDeleteOpen(false)}
       />
+      <CreateBucketDialog
+        isOpen={isCreateBucketOpen}
+        defaultRegion={currentRegion}
+        onConfirm={handleCreateBucket}
+        onCancel={() => setIsCreateBucketOpen(false)}
+      />
       <NewItemDialog
         isOpen={isNewItemOpen}
         itemType={newItemType}
@@ -593,6 +1290,23 @@ function App(): React.ReactElement {
           fileKey={selectedFile.key}
           isFolder={selectedFile.isPrefix}
           onClose={handlePropertiesClose}
+          onNavigateToObject={handleNavigateToObject}
+        />
+      )}
+      {selectedBucket && (
+        <IncompleteUploadsDialog
+          isOpen={isIncompleteUploadsOpen}
+          bucket={selectedBucket}
+          isReadOnly={isReadOnly}
+          onClose={() => setIsIncompleteUploadsOpen(false)}
+        />
+      )}
+      {selectedBucket && selectedFile && !selectedFile.isPrefix && (
+        <SelectQueryDialog
+          isOpen={isQueryOpen}
+          bucket={selectedBucket}
+          fileKey={selectedFile.key}
+          onClose={() => setIsQueryOpen(false)}
         />
       )}
 
@@ -601,6 +1315,7 @@ function App(): React.ReactElement {
         <TextEditor
           bucket={selectedBucket}
           fileKey={selectedFile.key}
+          versionId={selectedVersionId}
           fileName={selectedFile.key.split('/').pop() || selectedFile.key}
           onClose={handleEditorClose}
           onSaved={handleEditorSaved}
@@ -612,6 +1327,7 @@ function App(): React.ReactElement {
         <ParquetViewer
           bucket={selectedBucket}
           fileKey={selectedFile.key}
+          versionId={selectedVersionId}
           fileName={selectedFile.key.split('/').pop() || selectedFile.key}
           fileSize={selectedFile.size}
           onClose={handleParquetViewerClose}
@@ -623,6 +1339,7 @@ function App(): React.ReactElement {
         <CsvViewer
           bucket={selectedBucket}
           fileKey={selectedFile.key}
+          versionId={selectedVersionId}
           fileName={selectedFile.key.split('/').pop() || selectedFile.key}
           fileSize={selectedFile.size}
           onClose={handleCsvViewerClose}
@@ -634,6 +1351,7 @@ function App(): React.ReactElement {
         <JsonViewer
           bucket={selectedBucket}
           fileKey={selectedFile.key}
+          versionId={selectedVersionId}
           fileName={selectedFile.key.split('/').pop() || selectedFile.key}
           fileSize={selectedFile.size}
           onClose={handleJsonViewerClose}
@@ -645,6 +1363,7 @@ function App(): React.ReactElement {
         <YamlViewer
           bucket={selectedBucket}
           fileKey={selectedFile.key}
+          versionId={selectedVersionId}
           fileName={selectedFile.key.split('/').pop() || selectedFile.key}
           fileSize={selectedFile.size}
           onClose={handleYamlViewerClose}
@@ -656,12 +1375,43 @@ function App(): React.ReactElement {
         <ImagePreview
           bucket={selectedBucket}
           fileKey={selectedFile.key}
+          versionId={selectedVersionId}
           fileName={selectedFile.key.split('/').pop() || selectedFile.key}
           fileSize={selectedFile.size}
           onClose={handleImagePreviewClose}
         />
       )}
 
+      {/* Hex Viewer (fallback for unrecognized files) */}
+      {isHexViewerOpen && selectedBucket && selectedFile && !selectedFile.isPrefix && (
+        <HexViewer
+          bucket={selectedBucket}
+          fileKey={selectedFile.key}
+          versionId={selectedVersionId}
+          fileName={selectedFile.key.split('/').pop() || selectedFile.key}
+          fileSize={selectedFile.size}
+          onClose={handleHexViewerClose}
+        />
+      )}
+
+      {/* Settings Dialog */}
+      <SettingsDialog
+        isOpen={isSettingsOpen}
+        onClose={() => setIsSettingsOpen(false)}
+        onImported={handleSettingsImported}
+      />
+
+      {/* Command palette */}
+      <CommandPalette
+        isOpen={isPaletteOpen}
+        commands={paletteCommands}
+        onSelect={handlePaletteSelect}
+        onClose={() => setIsPaletteOpen(false)}
+      />
+
+      {/* MFA codes asked for by the main process while assuming a role */}
+      <MfaCodeDialog />
+
       {/* Toast notifications */}
       <ToastContainer toasts={toasts} onDismiss={removeToast} />
       </div>
@@ -1,18 +1,35 @@
-import React, { useEffect, useState, useCallback, useMemo } from 'react';
+import React, { useEffect, useState, useCallback, useMemo, useRef } from 'react';
+import { getExplorerState, type ExplorerState } from '../utils/explorerState';
 
 export interface S3Bucket {
   name: string;
   creationDate?: Date;
+  region?: string;
 }
 
 export interface BucketTreeProps {
   currentProfile: string | null;
   selectedBucket: string | null;
   onSelectBucket: (bucket: string) => void;
+  /** Changing this reloads the bucket list */
+  refreshKey?: number;
 }
 
+type PublicStatus = 'private' | 'public-read' | 'mixed' | 'unknown';
+
+const PUBLIC_STATUS_BADGES: Record<PublicStatus, { label: string; title: string }> = {
+  private: { label: 'Private', title: 'Not publicly accessible' },
+  'public-read': { label: 'Public', title: 'The bucket policy or ACL grants public access' },
+  mixed: {
+    label: 'Mixed',
+    title: 'The bucket is not public, but Block Public Access allows object ACLs to make objects public',
+  },
+  unknown: { label: '?', title: 'Public access settings could not be read (check permissions)' },
+};
+
 interface TreeNode {
   name: string;
+  region?: string;
   type: 'bucket';
   expanded: boolean;
 }
@@ -21,53 +38,81 @@ function BucketTree({
   currentProfile,
   selectedBucket,
   onSelectBucket,
+  refreshKey = 0,
 }: BucketTreeProps): React.ReactElement {
-  const [buckets, setBuckets] = useState<S3Bucket[]>([]);
-  const [loading, setLoading] = useState(false);
+  const [explorerState, setExplorerState] = useState<ExplorerState>(() =>
+    getExplorerState(currentProfile, { status: 'pending' })
+  );
   const [error, setError] = useState<string | null>(null);
   const [treeNodes, setTreeNodes] = useState<TreeNode[]>([]);
   const [filterText, setFilterText] = useState('');
+  // Public access status of the buckets selected so far, checked once per bucket
+  const [publicStatuses, setPublicStatuses] = useState<Record<string, PublicStatus>>({});
+  const requestedStatusesRef = useRef(new Set<string>());
 
   const loadBuckets = useCallback(async () => {
     if (!currentProfile) {
-      setBuckets([]);
+      setExplorerState(getExplorerState(null, { status: 'pending' }));
       setTreeNodes([]);
       return;
     }
 
     try {
-      setLoading(true);
+      setExplorerState(getExplorerState(currentProfile, { status: 'pending' }));
       setError(null);
       const result = await window.electronAPI.s3.listBuckets();
 
       if (!result.success) {
         setError(result.error ?? 'Failed to list buckets');
-        setBuckets([]);
         setTreeNodes([]);
+        setExplorerState(getExplorerState(currentProfile, { status: 'failed' }));
         return;
       }
 
-      const bucketList = result.buckets ?? [];
-      setBuckets(bucketList);
+      const bucketList: S3Bucket[] = result.buckets ?? [];
       setTreeNodes(
         bucketList.map((b) => ({
           name: b.name,
+          region: b.region,
           type: 'bucket' as const,
           expanded: false,
         }))
       );
+      setExplorerState(getExplorerState(currentProfile, { status: 'loaded', bucketCount: bucketList.length }));
     } catch (err) {
       setError(err instanceof Error ? err.message : 'Failed to list buckets');
-      setBuckets([]);
       setTreeNodes([]);
-    } finally {
-      setLoading(false);
+      setExplorerState(getExplorerState(currentProfile, { status: 'failed' }));
     }
   }, [currentProfile]);
 
   useEffect(() => {
     loadBuckets();
-  }, [loadBuckets]);
+  }, [loadBuckets, refreshKey]);
+
+  // Statuses belong to the profile's view of the buckets
+  useEffect(() => {
+    requestedStatusesRef.current = new Set();
+    setPublicStatuses({});
+  }, [currentProfile, refreshKey]);
+
+  useEffect(() => {
+    if (!currentProfile || !selectedBucket || requestedStatusesRef.current.has(selectedBucket)) return;
+    requestedStatusesRef.current.add(selectedBucket);
+    const requested = requestedStatusesRef.current;
+    window.electronAPI.s3
+      .getBucketPublicStatus(selectedBucket)
+      .then(result => {
+        // Ignore answers for a previous profile
+        if (requestedStatusesRef.current !== requested) return;
+        const status: PublicStatus = result.success ? (result.status ?? 'unknown') : 'unknown';
+        setPublicStatuses(prev => ({ ...prev, [selectedBucket]: status }));
+      })
+      .catch(() => {
+        if (requestedStatusesRef.current !== requested) return;
+        setPublicStatuses(prev => ({ ...prev, [selectedBucket]: 'unknown' }));
+      });
+  }, [currentProfile, selectedBucket, refreshKey]);
 
   const handleBucketClick = (bucketName: string) => {
     onSelectBucket(bucketName);
@@ -90,7 +135,7 @@ function BucketTree({
     setFilterText('');
   };
 
-  if (!currentProfile) {
+  if (explorerState === 'no-profile') {
     return (
       <div className="bucket-tree">
         <p className="bucket-tree-placeholder">Select a profile to view buckets</p>
@@ -98,7 +143,7 @@ function BucketTree({
     );
   }
 
-  if (loading) {
+  if (explorerState === 'loading') {
     return (
       <div className="bucket-tree">
         <div className="bucket-tree-loading">
@@ -109,7 +154,7 @@ function BucketTree({
     );
   }
 
-  if (error) {
+  if (explorerState === 'error') {
     return (
       <div className="bucket-tree">
         <div className="bucket-tree-error">
@@ -123,10 +168,19 @@ function BucketTree({
     );
   }
 
-  if (buckets.length === 0) {
+  if (explorerState === 'empty') {
     return (
       <div className="bucket-tree">
-        <p className="bucket-tree-placeholder">No buckets found</p>
+        <div className="bucket-tree-empty">
+          <p className="bucket-tree-placeholder">No buckets accessible</p>
+          <p className="bucket-tree-hint">
+            This profile can't list any buckets. Check that its IAM policy allows s3:ListAllMyBuckets, or
+            paste an s3:// path into the address bar to open a bucket directly.
+          </p>
+          <button className="retry-btn" onClick={loadBuckets}>
+            Retry
+          </button>
+        </div>
       </div>
     );
   }
@@ -182,9 +236,17 @@ function BucketTree({
             tabIndex={0}
           >
             <span className="bucket-icon">📦</span>
-            <span className="bucket-name" title={node.name}>
+            <span className="bucket-name" title={node.region ? `${node.name} (${node.region})` : node.name}>
               {node.name}
             </span>
+            {publicStatuses[node.name] && (
+              <span
+                className={`bucket-public-badge bucket-public-${publicStatuses[node.name]}`}
+                title={PUBLIC_STATUS_BADGES[publicStatuses[node.name]].title}
+              >
+                {PUBLIC_STATUS_BADGES[publicStatuses[node.name]].label}
+              </span>
+            )}
           </li>
         ))}
       </ul>
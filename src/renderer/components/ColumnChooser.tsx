@@ -0,0 +1,73 @@
+import React, { useState, useEffect, useRef } from 'react';
+
+export interface ColumnChooserProps {
+  columnNames: string[];
+  visible: boolean[];
+  onToggle: (name: string, shown: boolean) => void;
+  onShowAll: () => void;
+}
+
+/**
+ * Dropdown of checkboxes for showing and hiding the columns of a table viewer
+ */
+function ColumnChooser({ columnNames, visible, onToggle, onShowAll }: ColumnChooserProps): React.ReactElement {
+  const [isOpen, setIsOpen] = useState(false);
+  const containerRef = useRef<HTMLDivElement>(null);
+
+  // Close when clicking anywhere else
+  useEffect(() => {
+    if (!isOpen) return;
+    const handleMouseDown = (e: MouseEvent) => {
+      if (containerRef.current && !containerRef.current.contains(e.target as Node)) {
+        setIsOpen(false);
+      }
+    };
+    document.addEventListener('mousedown', handleMouseDown);
+    return () => document.removeEventListener('mousedown', handleMouseDown);
+  }, [isOpen]);
+
+  const shownCount = visible.filter(Boolean).length;
+  const allShown = shownCount === columnNames.length;
+
+  return (
+    <div className="column-chooser" ref={containerRef}>
+      <button
+        type="button"
+        className="column-chooser-btn"
+        onClick={() => setIsOpen(open => !open)}
+        aria-expanded={isOpen}
+        title="Choose which columns to show"
+      >
+        {allShown ? 'Columns' : `Columns (${shownCount}/${columnNames.length})`}
+      </button>
+      {isOpen && (
+        <div className="column-chooser-menu" role="menu">
+          <button
+            type="button"
+            className="column-chooser-show-all"
+            onClick={onShowAll}
+            disabled={allShown}
+          >
+            Show all
+          </button>
+          <ul className="column-chooser-list">
+            {columnNames.map((name, idx) => (
+              <li key={idx}>
+                <label className="column-chooser-item" title={name}>
+                  <input
+                    type="checkbox"
+                    checked={visible[idx] !== false}
+                    onChange={(e) => onToggle(name, e.target.checked)}
+                  />
+                  {name || <em>(unnamed)</em>}
+                </label>
+              </li>
+            ))}
+          </ul>
+        </div>
+      )}
+    </div>
+  );
+}
+
+export default ColumnChooser;
@@ -0,0 +1,73 @@
+import React from 'react';
+import type { Histogram } from '../utils/columnHistogram';
+
+export interface ColumnHistogramProps {
+  histogram: Histogram;
+}
+
+function formatNumber(value: number): string {
+  return value.toLocaleString(undefined, { maximumFractionDigits: 2 });
+}
+
+/**
+ * Sparkline-style bars summarizing the values of one column
+ */
+function ColumnHistogram({ histogram }: ColumnHistogramProps): React.ReactElement {
+  const nullNote = histogram.nullCount > 0 ? `${histogram.nullCount.toLocaleString()} null` : null;
+
+  if (histogram.kind === 'empty') {
+    return (
+      <div className="column-histogram column-histogram-empty" title="Every value is null">
+        all null
+      </div>
+    );
+  }
+
+  const bars =
+    histogram.kind === 'numeric'
+      ? histogram.bins.map(bin => ({
+          count: bin.count,
+          label:
+            bin.start === bin.end
+              ? `${formatNumber(bin.start)}: ${bin.count.toLocaleString()}`
+              : `${formatNumber(bin.start)} – ${formatNumber(bin.end)}: ${bin.count.toLocaleString()}`,
+        }))
+      : [
+          ...histogram.categories.map(category => ({
+            count: category.count,
+            label: `${category.value}: ${category.count.toLocaleString()}`,
+          })),
+          ...(histogram.otherCount > 0
+            ? [{ count: histogram.otherCount, label: `Other values: ${histogram.otherCount.toLocaleString()}` }]
+            : []),
+        ];
+  const largest = Math.max(...bars.map(bar => bar.count), 1);
+
+  const summary =
+    histogram.kind === 'numeric'
+      ? histogram.min === histogram.max
+        ? formatNumber(histogram.min)
+        : `${formatNumber(histogram.min)} – ${formatNumber(histogram.max)}`
+      : `${histogram.distinctCount.toLocaleString()} distinct`;
+
+  return (
+    <div className={`column-histogram column-histogram-${histogram.kind}`}>
+      <div className="column-histogram-bars" role="img" aria-label={bars.map(bar => bar.label).join(', ')}>
+        {bars.map((bar, idx) => (
+          <span
+            key={idx}
+            className="column-histogram-bar"
+            style={{ height: `${Math.max((bar.count / largest) * 100, bar.count > 0 ? 4 : 0)}%` }}
+            title={bar.label}
+          />
+        ))}
+      </div>
+      <div className="column-histogram-summary">
+        {summary}
+        {nullNote && ` · ${nullNote}`}
+      </div>
+    </div>
+  );
+}
+
+export default ColumnHistogram;
@@ -0,0 +1,117 @@
+import React, { useState, useEffect, useMemo, useRef } from 'react';
+import { rankCommands, type PaletteCommand } from '../utils/commandPalette';
+
+export interface CommandPaletteProps {
+  isOpen: boolean;
+  commands: PaletteCommand[];
+  onSelect: (command: PaletteCommand) => void;
+  onClose: () => void;
+}
+
+const KIND_LABELS: Record<PaletteCommand['kind'], string> = {
+  action: 'Action',
+  bucket: 'Bucket',
+  location: 'Recent',
+};
+
+/**
+ * Renders a label with the fuzzy-matched characters highlighted
+ */
+function HighlightedLabel({ label, positions }: { label: string; positions: number[] }): React.ReactElement {
+  const matched = new Set(positions);
+  return (
+    <>
+      {label.split('').map((char, index) =>
+        matched.has(index) ? (
+          <mark key={index} className="command-palette-match">{char}</mark>
+        ) : (
+          <React.Fragment key={index}>{char}</React.Fragment>
+        )
+      )}
+    </>
+  );
+}
+
+/**
+ * Ctrl+P overlay: type to filter buckets, recent locations and actions, Enter to run
+ */
+function CommandPalette({ isOpen, commands, onSelect, onClose }: CommandPaletteProps): React.ReactElement | null {
+  const [query, setQuery] = useState('');
+  const [activeIndex, setActiveIndex] = useState(0);
+  const inputRef = useRef<HTMLInputElement>(null);
+
+  useEffect(() => {
+    if (isOpen) {
+      setQuery('');
+      setActiveIndex(0);
+      setTimeout(() => inputRef.current?.focus(), 0);
+    }
+  }, [isOpen]);
+
+  const results = useMemo(() => rankCommands(commands, query), [commands, query]);
+
+  const handleQueryChange = (value: string) => {
+    setQuery(value);
+    setActiveIndex(0);
+  };
+
+  const handleKeyDown = (e: React.KeyboardEvent) => {
+    if (e.key === 'Escape') {
+      e.preventDefault();
+      onClose();
+    } else if (e.key === 'ArrowDown') {
+      e.preventDefault();
+      setActiveIndex(index => Math.min(index + 1, results.length - 1));
+    } else if (e.key === 'ArrowUp') {
+      e.preventDefault();
+      setActiveIndex(index => Math.max(index - 1, 0));
+    } else if (e.key === 'Enter') {
+      e.preventDefault();
+      const selected = results[activeIndex];
+      if (selected) {
+        onSelect(selected.command);
+      }
+    }
+  };
+
+  if (!isOpen) {
+    return null;
+  }
+
+  return (
+    <div className="dialog-overlay command-palette-overlay" onClick={onClose}>
+      <div className="command-palette" onClick={(e) => e.stopPropagation()}>
+        <input
+          ref={inputRef}
+          type="text"
+          className="dialog-input command-palette-input"
+          placeholder="Jump to a bucket, recent location or action..."
+          value={query}
+          onChange={(e) => handleQueryChange(e.target.value)}
+          onKeyDown={handleKeyDown}
+          aria-label="Command palette"
+        />
+        <ul className="command-palette-results" role="listbox">
+          {results.map(({ command, positions }, index) => (
+            <li
+              key={command.id}
+              role="option"
+              aria-selected={index === activeIndex}
+              className={`command-palette-item ${index === activeIndex ? 'active' : ''}`}
+              onMouseEnter={() => setActiveIndex(index)}
+              onClick={() => onSelect(command)}
+            >
+              <span className="command-palette-label">
+                <HighlightedLabel label={command.label} positions={positions} />
+              </span>
+              <span className="command-palette-kind">{KIND_LABELS[command.kind]}</span>
+            </li>
+          ))}
+          {results.length === 0 && <li className="command-palette-empty">No matches</li>}
+        </ul>
+      </div>
+    </div>
+  );
+}
+
+export default CommandPalette;
@@ -0,0 +1,104 @@
+import React, { useState, useEffect, useRef } from 'react';
+import { uniqueValues } from '../utils/columnValues';
+
+export interface CopyColumnMenuProps {
+  columnNames: string[];
+  /** Text of the column at the index, from the loaded rows or from every row */
+  getValues: (columnIndex: number, allRows: boolean) => string[];
+  /** Whether rows beyond the loaded ones exist, which enables the "All rows" option */
+  hasMoreRows: boolean;
+}
+
+/**
+ * Dropdown that copies every value of one column to the clipboard, one per line
+ */
+function CopyColumnMenu({ columnNames, getValues, hasMoreRows }: CopyColumnMenuProps): React.ReactElement {
+  const [isOpen, setIsOpen] = useState(false);
+  const [columnIndex, setColumnIndex] = useState(0);
+  const [unique, setUnique] = useState(false);
+  const [allRows, setAllRows] = useState(false);
+  const [status, setStatus] = useState<string | null>(null);
+  const containerRef = useRef<HTMLDivElement>(null);
+
+  // Close when clicking anywhere else
+  useEffect(() => {
+    if (!isOpen) return;
+    const handleMouseDown = (e: MouseEvent) => {
+      if (containerRef.current && !containerRef.current.contains(e.target as Node)) {
+        setIsOpen(false);
+      }
+    };
+    document.addEventListener('mousedown', handleMouseDown);
+    return () => document.removeEventListener('mousedown', handleMouseDown);
+  }, [isOpen]);
+
+  // The chosen column may disappear when columns are hidden
+  const selected = columnIndex < columnNames.length ? columnIndex : 0;
+
+  const handleCopy = async () => {
+    const values = getValues(selected, allRows && hasMoreRows);
+    const copied = unique ? uniqueValues(values) : values;
+    try {
+      await navigator.clipboard.writeText(copied.join('\n'));
+      setStatus(`Copied ${copied.length.toLocaleString()} ${copied.length === 1 ? 'value' : 'values'}`);
+    } catch (err) {
+      console.error('Failed to copy column to clipboard:', err);
+      setStatus('Failed to copy to clipboard');
+    }
+  };
+
+  return (
+    <div className="copy-column-menu" ref={containerRef}>
+      <button
+        type="button"
+        className="column-chooser-btn"
+        onClick={() => {
+          setStatus(null);
+          setIsOpen(open => !open);
+        }}
+        aria-expanded={isOpen}
+        disabled={columnNames.length === 0}
+        title="Copy the values of a column to the clipboard"
+      >
+        Copy column
+      </button>
+      {isOpen && (
+        <div className="copy-column-menu-panel">
+          <label className="copy-column-field">
+            Column:
+            <select value={selected} onChange={(e) => setColumnIndex(Number(e.target.value))}>
+              {columnNames.map((name, idx) => (
+                <option key={idx} value={idx}>
+                  {name || '(unnamed)'}
+                </option>
+              ))}
+            </select>
+          </label>
+          <label className="copy-column-option">
+            <input type="checkbox" checked={unique} onChange={(e) => setUnique(e.target.checked)} />
+            Unique values only
+          </label>
+          {hasMoreRows && (
+            <label
+              className="copy-column-option"
+              title="Copy from every row of the file, not just the rows loaded so far"
+            >
+              <input type="checkbox" checked={allRows} onChange={(e) => setAllRows(e.target.checked)} />
+              All rows
+            </label>
+          )}
+          <button type="button" className="copy-column-btn" onClick={handleCopy}>
+            Copy
+          </button>
+          {status && (
+            <span className="copy-column-status" role="status">
+              {status}
+            </span>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
+
+export default CopyColumnMenu;
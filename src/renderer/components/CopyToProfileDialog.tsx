@@ -0,0 +1,124 @@
+import React, { useState, useEffect, useRef } from 'react';
+
+export interface CopyToProfileDialogProps {
+  isOpen: boolean;
+  /** Profiles that can be chosen as destination */
+  profileNames: string[];
+  /** Profile currently used to browse (the source side) */
+  currentProfile: string | null;
+  sourceBucket: string;
+  sourceKey: string;
+  onConfirm: (destinationProfile: string, destinationBucket: string, destinationKey: string) => void;
+  onCancel: () => void;
+}
+
+function CopyToProfileDialog({
+  isOpen,
+  profileNames,
+  currentProfile,
+  sourceBucket,
+  sourceKey,
+  onConfirm,
+  onCancel,
+}: CopyToProfileDialogProps): React.ReactElement | null {
+  const [destinationProfile, setDestinationProfile] = useState('');
+  const [destinationBucket, setDestinationBucket] = useState(sourceBucket);
+  const [destinationKey, setDestinationKey] = useState(sourceKey);
+  const bucketInputRef = useRef<HTMLInputElement>(null);
+
+  useEffect(() => {
+    if (isOpen) {
+      // Default to the first profile other than the one being browsed
+      const otherProfile = profileNames.find(name => name !== currentProfile);
+      setDestinationProfile(otherProfile ?? currentProfile ?? '');
+      setDestinationBucket(sourceBucket);
+      setDestinationKey(sourceKey);
+      setTimeout(() => {
+        bucketInputRef.current?.focus();
+        bucketInputRef.current?.select();
+      }, 0);
+    }
+  }, [isOpen, profileNames, currentProfile, sourceBucket, sourceKey]);
+
+  const canSubmit =
+    destinationProfile !== '' && destinationBucket.trim() !== '' && destinationKey.trim() !== '';
+
+  const handleSubmit = (e: React.FormEvent) => {
+    e.preventDefault();
+    if (canSubmit) {
+      onConfirm(destinationProfile, destinationBucket.trim(), destinationKey.trim());
+    }
+  };
+
+  const handleKeyDown = (e: React.KeyboardEvent) => {
+    if (e.key === 'Escape') {
+      onCancel();
+    }
+  };
+
+  if (!isOpen) {
+    return null;
+  }
+
+  return (
+    <div className="dialog-overlay" onClick={onCancel}>
+      <div className="dialog" onClick={(e) => e.stopPropagation()} onKeyDown={handleKeyDown}>
+        <div className="dialog-header">
+          <h3>Copy to Another Profile</h3>
+        </div>
+        <form onSubmit={handleSubmit}>
+          <div className="dialog-content">
+            <p>Source:</p>
+            <div className="dialog-filename">s3://{sourceBucket}/{sourceKey}</div>
+            <div className="dialog-field">
+              <label htmlFor="copy-profile-select">Destination profile:</label>
+              <select
+                id="copy-profile-select"
+                value={destinationProfile}
+                onChange={(e) => setDestinationProfile(e.target.value)}
+                className="dialog-input"
+              >
+                {profileNames.map(name => (
+                  <option key={name} value={name}>
+                    {name === currentProfile ? `${name} (current)` : name}
+                  </option>
+                ))}
+              </select>
+            </div>
+            <div className="dialog-field">
+              <label htmlFor="copy-bucket-input">Destination bucket:</label>
+              <input
+                ref={bucketInputRef}
+                id="copy-bucket-input"
+                type="text"
+                value={destinationBucket}
+                onChange={(e) => setDestinationBucket(e.target.value)}
+                className="dialog-input"
+              />
+            </div>
+            <div className="dialog-field">
+              <label htmlFor="copy-key-input">Destination key:</label>
+              <input
+                id="copy-key-input"
+                type="text"
+                value={destinationKey}
+                onChange={(e) => setDestinationKey(e.target.value)}
+                className="dialog-input"
+              />
+            </div>
+          </div>
+          <div className="dialog-actions">
+            <button type="button" className="dialog-btn dialog-btn-cancel" onClick={onCancel}>
+              Cancel
+            </button>
+            <button type="submit" className="dialog-btn dialog-btn-confirm" disabled={!canSubmit}>
+              Copy
+            </button>
+          </div>
+        </form>
+      </div>
+    </div>
+  );
+}
+
+export default CopyToProfileDialog;
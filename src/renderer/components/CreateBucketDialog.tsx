@@ -0,0 +1,154 @@
+import React, { useState, useEffect, useRef } from 'react';
+
+/**
+ * Regions offered when creating a bucket
+ */
+export const BUCKET_REGIONS = [
+  'us-east-1',
+  'us-east-2',
+  'us-west-1',
+  'us-west-2',
+  'ca-central-1',
+  'sa-east-1',
+  'eu-west-1',
+  'eu-west-2',
+  'eu-west-3',
+  'eu-central-1',
+  'eu-central-2',
+  'eu-north-1',
+  'eu-south-1',
+  'eu-south-2',
+  'ap-south-1',
+  'ap-southeast-1',
+  'ap-southeast-2',
+  'ap-northeast-1',
+  'ap-northeast-2',
+  'ap-northeast-3',
+  'ap-east-1',
+  'me-south-1',
+  'me-central-1',
+  'af-south-1',
+];
+
+export interface CreateBucketDialogProps {
+  isOpen: boolean;
+  /** Region selected when the dialog opens (usually the profile's region) */
+  defaultRegion: string;
+  /**
+   * Creates the bucket. Resolves to an error message to show in the dialog,
+   * or null once the bucket has been created.
+   */
+  onConfirm: (name: string, region: string) => Promise<string | null>;
+  onCancel: () => void;
+}
+
+function CreateBucketDialog({
+  isOpen,
+  defaultRegion,
+  onConfirm,
+  onCancel,
+}: CreateBucketDialogProps): React.ReactElement | null {
+  const [name, setName] = useState('');
+  const [region, setRegion] = useState(defaultRegion);
+  const [error, setError] = useState<string | null>(null);
+  const [creating, setCreating] = useState(false);
+  const inputRef = useRef<HTMLInputElement>(null);
+
+  useEffect(() => {
+    if (isOpen) {
+      setName('');
+      setRegion(defaultRegion);
+      setError(null);
+      setCreating(false);
+      setTimeout(() => inputRef.current?.focus(), 0);
+    }
+  }, [isOpen, defaultRegion]);
+
+  // Keep a profile region that is missing from the list selectable
+  const regions = BUCKET_REGIONS.includes(defaultRegion) ? BUCKET_REGIONS : [defaultRegion, ...BUCKET_REGIONS];
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    const trimmedName = name.trim();
+    if (!trimmedName || creating) return;
+
+    setCreating(true);
+    setError(null);
+    const failure = await onConfirm(trimmedName, region);
+    setCreating(false);
+    if (failure) {
+      setError(failure);
+    }
+  };
+
+  const handleKeyDown = (e: React.KeyboardEvent) => {
+    if (e.key === 'Escape') {
+      onCancel();
+    }
+  };
+
+  if (!isOpen) {
+    return null;
+  }
+
+  return (
+    <div className="dialog-overlay" onClick={onCancel}>
+      <div className="dialog" onClick={(e) => e.stopPropagation()} onKeyDown={handleKeyDown}>
+        <div className="dialog-header">
+          <h3>New Bucket</h3>
+        </div>
+        <form onSubmit={handleSubmit}>
+          <div className="dialog-content">
+            <div className="dialog-field">
+              <label htmlFor="create-bucket-name">Bucket name:</label>
+              <input
+                ref={inputRef}
+                id="create-bucket-name"
+                type="text"
+                value={name}
+                onChange={(e) => setName(e.target.value)}
+                className="dialog-input"
+                placeholder="my-bucket-name"
+                spellCheck={false}
+              />
+            </div>
+            <div className="dialog-field">
+              <label htmlFor="create-bucket-region">Region:</label>
+              <select
+                id="create-bucket-region"
+                value={region}
+                onChange={(e) => setRegion(e.target.value)}
+                className="dialog-input"
+              >
+                {regions.map(r => (
+                  <option key={r} value={r}>
+                    {r}
+                  </option>
+                ))}
+              </select>
+            </div>
+            {error && (
+              <p className="dialog-warning" role="alert">
+                {error}
+              </p>
+            )}
+          </div>
+          <div className="dialog-actions">
+            <button type="button" className="dialog-btn dialog-btn-cancel" onClick={onCancel}>
+              Cancel
+            </button>
+            <button
+              type="submit"
+              className="dialog-btn dialog-btn-confirm"
+              disabled={!name.trim() || creating}
+            >
+              {creating ? 'Creating...' : 'Create'}
+            </button>
+          </div>
+        </form>
+      </div>
+    </div>
+  );
+}
+
+export default CreateBucketDialog;
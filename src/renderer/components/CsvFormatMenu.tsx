@@ -0,0 +1,92 @@
+import React, { useState, useEffect, useRef } from 'react';
+import { CSV_DELIMITERS, type CsvLineTerminator, type CsvQuoteStyle } from '../utils/csvExport';
+
+export interface CsvFormat {
+  delimiter: string;
+  lineTerminator: CsvLineTerminator;
+  quoteStyle: CsvQuoteStyle;
+}
+
+export const DEFAULT_CSV_FORMAT: CsvFormat = {
+  delimiter: ',',
+  lineTerminator: '\n',
+  quoteStyle: 'minimal',
+};
+
+export interface CsvFormatMenuProps {
+  format: CsvFormat;
+  onChange: (format: CsvFormat) => void;
+}
+
+/**
+ * Dropdown for the delimiter, line endings and quoting of CSV exports
+ */
+function CsvFormatMenu({ format, onChange }: CsvFormatMenuProps): React.ReactElement {
+  const [isOpen, setIsOpen] = useState(false);
+  const containerRef = useRef<HTMLDivElement>(null);
+
+  // Close when clicking anywhere else
+  useEffect(() => {
+    if (!isOpen) return;
+    const handleMouseDown = (e: MouseEvent) => {
+      if (containerRef.current && !containerRef.current.contains(e.target as Node)) {
+        setIsOpen(false);
+      }
+    };
+    document.addEventListener('mousedown', handleMouseDown);
+    return () => document.removeEventListener('mousedown', handleMouseDown);
+  }, [isOpen]);
+
+  return (
+    <div className="csv-format-menu" ref={containerRef}>
+      <button
+        type="button"
+        className="column-chooser-btn"
+        onClick={() => setIsOpen(open => !open)}
+        aria-expanded={isOpen}
+        title="Delimiter, line endings and quoting of exported CSV"
+      >
+        CSV format
+      </button>
+      {isOpen && (
+        <div className="csv-format-menu-panel">
+          <label className="csv-format-field">
+            Delimiter:
+            <select
+              value={format.delimiter}
+              onChange={(e) => onChange({ ...format, delimiter: e.target.value })}
+            >
+              {CSV_DELIMITERS.map(({ value, label }) => (
+                <option key={label} value={value}>
+                  {label}
+                </option>
+              ))}
+            </select>
+          </label>
+          <label className="csv-format-field">
+            Line endings:
+            <select
+              value={format.lineTerminator}
+              onChange={(e) => onChange({ ...format, lineTerminator: e.target.value as CsvLineTerminator })}
+            >
+              <option value={'\n'}>LF (Unix)</option>
+              <option value={'\r\n'}>CRLF (Windows)</option>
+            </select>
+          </label>
+          <label className="csv-format-field">
+            Quoting:
+            <select
+              value={format.quoteStyle}
+              onChange={(e) => onChange({ ...format, quoteStyle: e.target.value as CsvQuoteStyle })}
+            >
+              <option value="minimal">Only when needed</option>
+              <option value="all">Every field</option>
+            </select>
+          </label>
+        </div>
+      )}
+    </div>
+  );
+}
+
+export default CsvFormatMenu;
@@ -1,10 +1,17 @@
-import React, { useState, useCallback, useEffect, useRef } from 'react';
+import React, { useState, useCallback, useEffect, useMemo, useRef } from 'react';
+import { projectVisible } from '../utils/columnVisibility';
+import { columnValues } from '../utils/columnValues';
+import { useColumnVisibility } from '../hooks/useColumnVisibility';
+import ColumnChooser from './ColumnChooser';
+import CopyColumnMenu from './CopyColumnMenu';
 
 export interface CsvViewerProps {
   bucket: string;
   fileKey: string;
   fileName: string;
   fileSize: number;
+  /** Version to show instead of the latest one */
+  versionId?: string;
   onClose: () => void;
 }
 
@@ -107,10 +114,13 @@ function CsvViewer({
   fileKey,
   fileName,
   fileSize,
+  versionId,
   onClose,
 }: CsvViewerProps): React.ReactElement {
   const [loading, setLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
+  // Codec the content was decompressed from, shown in the footer
+  const [compression, setCompression] = useState<string | null>(null);
   const [data, setData] = useState<CsvData | null>(null);
   const [displayedRows, setDisplayedRows] = useState<string[][]>([]);
   const [loadingMore, setLoadingMore] = useState(false);
@@ -119,6 +129,9 @@ function CsvViewer({
   const allRowsRef = useRef<string[][]>([]);
   const tableContainerRef = useRef<HTMLDivElement>(null);
 
+  const headers = useMemo(() => data?.headers ?? [], [data]);
+  const { visible, setColumnVisible, showAllColumns } = useColumnVisibility(bucket, fileKey, headers);
+
   // Load CSV file on mount
   useEffect(() => {
     let mounted = true;
@@ -136,7 +149,7 @@ function CsvViewer({
         }
 
         // Download the file as text
-        const result = await window.electronAPI.s3.downloadContent(bucket, fileKey);
+        const result = await window.electronAPI.s3.downloadContent(bucket, fileKey, versionId);
         if (!result.success) {
           throw new Error(result.error || 'Failed to download file');
         }
@@ -147,6 +160,8 @@ function CsvViewer({
 
         if (!mounted) return;
 
+        setCompression(result.compression ?? null);
+
         // Parse CSV
         const { headers, rows } = parseCsv(result.content);
 
@@ -173,7 +188,7 @@ function CsvViewer({
     return () => {
       mounted = false;
     };
-  }, [bucket, fileKey, fileSize]);
+  }, [bucket, fileKey, fileSize, versionId]);
 
   // Handle scroll for lazy loading
   const handleScroll = useCallback(() => {
@@ -198,15 +213,28 @@ function CsvViewer({
     }
   }, [loadingMore, data, displayedRows.length]);
 
+  // Only the visible columns are rendered and searched
+  const shown = useMemo(
+    () => projectVisible(headers, displayedRows, visible),
+    [headers, displayedRows, visible]
+  );
+
   // Filter rows based on search term
   const filteredRows = searchTerm
-    ? displayedRows.filter(row =>
+    ? shown.rows.filter(row =>
         row.some(cell => {
           const cellStr = cell.toLowerCase();
           return cellStr.includes(searchTerm.toLowerCase());
         })
       )
-    : displayedRows;
+    : shown.rows;
+
+  // Values of a visible column
+  const getColumnValues = useCallback(
+    (columnIndex: number, allRows: boolean) =>
+      columnValues(projectVisible(headers, allRows ? allRowsRef.current : displayedRows, visible).rows, columnIndex),
+    [headers, displayedRows, visible]
+  );
 
   const handleKeyDown = useCallback(
     (e: React.KeyboardEvent) => {
@@ -235,13 +263,30 @@ function CsvViewer({
                   {data.totalRows.toLocaleString()} rows
                 </span>
                 <span className="csv-viewer-meta-item">
-                  {data.headers.length} columns
+                  {shown.columns.length < data.headers.length
+                    ? `${shown.columns.length} of ${data.headers.length} columns`
+                    : `${data.headers.length} columns`}
                 </span>
               </>
             )}
             <span className="csv-viewer-meta-item">{formatSize(fileSize)}</span>
           </div>
           <div className="csv-viewer-actions">
+            {data && (
+              <>
+                <CopyColumnMenu
+                  columnNames={shown.columns}
+                  getValues={getColumnValues}
+                  hasMoreRows={displayedRows.length < data.totalRows}
+                />
+                <ColumnChooser
+                  columnNames={headers}
+                  visible={visible}
+                  onToggle={setColumnVisible}
+                  onShowAll={showAllColumns}
+                />
+              </>
+            )}
             <button
               className="csv-viewer-btn csv-viewer-btn-close"
               onClick={onClose}
@@ -301,7 +346,7 @@ function CsvViewer({
                 <thead>
                   <tr>
                     <th className="csv-col-index">#</th>
-                    {data.headers.map((header, idx) => (
+                    {shown.columns.map((header, idx) => (
                       <th key={idx} title={header}>
                         <div className="csv-col-header">
                           <span className="csv-col-name">{header}</span>
@@ -320,14 +365,19 @@ function CsvViewer({
                         </td>
                       ))}
                       {/* Pad with empty cells if row has fewer columns than headers */}
-                      {row.length < data.headers.length &&
-                        Array.from({ length: data.headers.length - row.length }).map((_, i) => (
+                      {row.length < shown.columns.length &&
+                        Array.from({ length: shown.columns.length - row.length }).map((_, i) => (
                           <td key={`empty-${i}`}></td>
                         ))}
                     </tr>
                   ))}
                 </tbody>
               </table>
+              {shown.columns.length === 0 && data.headers.length > 0 && (
+                <div className="csv-viewer-has-more">
+                  All columns are hidden
+                </div>
+              )}
               {loadingMore && (
                 <div className="csv-viewer-loading-more">
                   <span className="loading-spinner small"></span>
@@ -348,6 +398,11 @@ function CsvViewer({
           <span className="csv-viewer-path" title={`s3://${bucket}/${fileKey}`}>
             s3://{bucket}/{fileKey}
           </span>
+          {compression && (
+            <span className="viewer-compression" title="Decompressed for viewing">
+              {compression}
+            </span>
+          )}
           <span className="csv-viewer-status">
             {data
               ? `Loaded ${displayedRows.length.toLocaleString()} of ${data.totalRows.toLocaleString()} rows`
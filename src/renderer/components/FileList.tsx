@@ -4,8 +4,14 @@ import FileListControls, {
   SortField,
   sortItems,
   filterByType,
-  filterBySearch,
+  quickFilter,
+  getDisplayName,
+  filterByMetadata,
 } from './FileListControls';
+import { thumbnailCache, thumbnailCacheKey, prefetchThumbnails } from '../utils/thumbnailCache';
+import { getExtension } from '../../shared/fileTypes';
+import { getVisibleRowRange, type RowRange } from '../utils/visibleRows';
+import { formatAge } from '../utils/relativeTime';
 
 export interface S3Object {
   key: string;
@@ -13,9 +19,19 @@ export interface S3Object {
   lastModified?: Date;
   etag?: string;
   storageClass?: string;
+  /** Content-Type, filled in from HeadObject when the file is opened (listings do not return it) */
+  contentType?: string;
   isPrefix: boolean;
+  /** Tags and user metadata, fetched lazily when a metadata column is enabled */
+  metadata?: Record<string, string>;
 }
 
+/** Number of metadata requests issued in parallel */
+const METADATA_FETCH_CONCURRENCY = 4;
+
+/** Delay before the quick filter applies what was typed */
+const QUICK_FILTER_DEBOUNCE_MS = 150;
+
 /** State for tracking file search progress during URL navigation */
 interface FileSearchState {
   isSearching: boolean;
@@ -29,6 +45,8 @@ export interface FileListProps {
   selectedBucket: string | null;
   currentPrefix: string;
   onNavigate: (prefix: string) => void;
+  /** Prefixes skipped by a collapsed folder chain; merged into the following breadcrumb */
+  collapsedPrefixes?: ReadonlySet<string>;
   onSelectFile: (file: S3Object | null) => void;
   selectedFile: S3Object | null;
   /** Array of selected files for multiselect */
@@ -41,10 +59,16 @@ export interface FileListProps {
   pendingFileSelection?: string | null;
   /** Callback when pending file selection is processed */
   onPendingFileSelectionHandled?: () => void;
+  /** Keys to select together after files are loaded (for pasted URL lists) */
+  pendingMultiSelection?: string[] | null;
+  /** Callback when pending multi-selection is processed */
+  onPendingMultiSelectionHandled?: () => void;
   /** Callback to report item count changes for status bar */
   onItemCountChange?: (count: number, allLoaded: boolean, loading: boolean) => void;
-  /** Callback for double-click on a file (triggers download) */
+  /** Callback for the row download button (and double-click when onOpenFile is not set) */
   onDownloadFile?: (file: S3Object) => void;
+  /** Callback for double-click on a file (opens it in the matching viewer) */
+  onOpenFile?: (file: S3Object) => void;
 }
 
 function formatFileSize(bytes: number): string {
@@ -54,6 +78,14 @@ function formatFileSize(bytes: number): string {
   return `${(bytes / Math.pow(1024, i)).toFixed(i > 0 ? 1 : 0)} ${units[i]}`;
 }
 
+function getMetadataCellText(item: S3Object, metadataKey: string): string {
+  if (item.isPrefix) return '';
+  if (!item.metadata) return '...';
+  const lowerKey = metadataKey.trim().toLowerCase();
+  const entry = Object.entries(item.metadata).find(([k]) => k.toLowerCase() === lowerKey);
+  return entry ? entry[1] : '--';
+}
+
 function formatDate(date: Date | undefined): string {
   if (!date) return '--';
   return new Intl.DateTimeFormat('en-US', {
@@ -65,18 +97,29 @@ function formatDate(date: Date | undefined): string {
   }).format(new Date(date));
 }
 
-function getFileName(key: string, prefix: string): string {
-  // Remove the current prefix from the key to get just the filename
-  const name = key.startsWith(prefix) ? key.slice(prefix.length) : key;
-  // Remove trailing slash for folders
-  return name.endsWith('/') ? name.slice(0, -1) : name;
+/**
+ * Renders a name with the characters at the given positions highlighted
+ */
+function highlightName(name: string, positions: number[] | undefined): React.ReactNode {
+  if (!positions || positions.length === 0) return name;
+  const matched = new Set(positions);
+  const segments: React.ReactNode[] = [];
+  let start = 0;
+  while (start < name.length) {
+    const isMatch = matched.has(start);
+    let end = start + 1;
+    while (end < name.length && matched.has(end) === isMatch) end++;
+    const text = name.slice(start, end);
+    segments.push(isMatch ? <mark key={start} className="file-name-match">{text}</mark> : text);
+    start = end;
+  }
+  return segments;
 }
 
 function getFileIcon(key: string, isPrefix: boolean): string {
   if (isPrefix) return '📁';
 
-  const ext = key.split('.').pop()?.toLowerCase() ?? '';
-  switch (ext) {
+  switch (getExtension(key)) {
     case 'json':
       return '📋';
     case 'yaml':
@@ -110,6 +153,7 @@ function FileList({
   selectedBucket,
   currentPrefix,
   onNavigate,
+  collapsedPrefixes,
   onSelectFile,
   selectedFile,
   selectedFiles,
@@ -118,8 +162,11 @@ function FileList({
   onRefreshRequest,
   pendingFileSelection,
   onPendingFileSelectionHandled,
+  pendingMultiSelection,
+  onPendingMultiSelectionHandled,
   onItemCountChange,
   onDownloadFile,
+  onOpenFile,
 }: FileListProps): React.ReactElement {
   const [items, setItems] = useState<S3Object[]>([]);
   const [loading, setLoading] = useState(false);
@@ -135,6 +182,19 @@ function FileList({
   });
   const [filterType, setFilterType] = useState('all');
   const [searchQuery, setSearchQuery] = useState('');
+  const [globCaseSensitive, setGlobCaseSensitive] = useState(false);
+  // The query the list is filtered by, trailing the input while typing
+  const [appliedQuery, setAppliedQuery] = useState('');
+  const [metadataKey, setMetadataKey] = useState('');
+  const [metadataValue, setMetadataValue] = useState('');
+  // Metadata cache keyed by bucket/key@etag so changed objects are re-fetched
+  const [metadataCache, setMetadataCache] = useState<Map<string, Record<string, string>>>(
+    () => new Map()
+  );
+  // Bumped when a thumbnail lands in the shared cache so rows re-render with it
+  const [, setThumbnailVersion] = useState(0);
+  // Rows on screen plus overscan; thumbnails are only fetched for these
+  const [visibleRange, setVisibleRange] = useState<RowRange>(() => getVisibleRowRange(0, 0, 0));
 
   // State for file search during URL navigation
   const [fileSearchState, setFileSearchState] = useState<FileSearchState>({
@@ -148,16 +208,32 @@ function FileList({
   const listContainerRef = useRef<HTMLDivElement>(null);
   const dragCounterRef = useRef(0);
   const rowRefs = useRef<Map<string, HTMLTableRowElement>>(new Map());
-  // Track last clicked index for shift+click range selection
-  const lastClickedIndexRef = useRef<number>(-1);
+  // Anchor for shift+click range selection. Stored by key rather than row index so it
+  // still points at the same object after a page is appended or the list is re-sorted.
+  const lastClickedKeyRef = useRef<string | null>(null);
+  // Id of the listing in flight, so navigating away can cancel it and drop its result
+  const listOperationRef = useRef<string | null>(null);
+  const listSequenceRef = useRef(0);
 
   const loadObjects = useCallback(
     async (reset = true) => {
+      if (listOperationRef.current) {
+        // Loading more waits for the listing in flight; a fresh listing replaces it
+        if (!reset) return;
+        window.electronAPI.s3.cancelOperation(listOperationRef.current);
+        listOperationRef.current = null;
+      }
+
       if (!currentProfile || !selectedBucket) {
         setItems([]);
         return;
       }
 
+      listSequenceRef.current += 1;
+      const operationId = `list-${Date.now()}-${listSequenceRef.current}`;
+      listOperationRef.current = operationId;
+      const isStale = () => listOperationRef.current !== operationId;
+
       try {
         if (reset) {
           setLoading(true);
@@ -168,13 +244,19 @@ function FileList({
         }
         setError(null);
 
-        const result = await window.electronAPI.s3.listObjects({
-          bucket: selectedBucket,
-          prefix: currentPrefix,
-          delimiter: '/',
-          maxKeys: 100,
-          continuationToken: reset ? undefined : continuationTokenRef.current,
-        });
+        const result = await window.electronAPI.s3.listObjects(
+          {
+            bucket: selectedBucket,
+            prefix: currentPrefix,
+            delimiter: '/',
+            maxKeys: 100,
+            continuationToken: reset ? undefined : continuationTokenRef.current,
+          },
+          operationId
+        );
+
+        // A newer listing replaced this one, e.g. after navigating to another folder
+        if (isStale()) return;
 
         if (!result.success) {
           setError(result.error ?? 'Failed to list objects');
@@ -183,7 +265,8 @@ function FileList({
 
         const data = result.result!;
         continuationTokenRef.current = data.continuationToken;
-        setHasMore(data.isTruncated);
+        // Without a token there is no way to load more, whatever isTruncated says
+        setHasMore(data.isTruncated && !!data.continuationToken);
 
         // Combine prefixes and objects - prefixes first
         const combined = [...data.prefixes, ...data.objects];
@@ -203,20 +286,34 @@ function FileList({
           });
         }
       } catch (err) {
+        if (isStale()) return;
         setError(err instanceof Error ? err.message : 'Failed to list objects');
       } finally {
-        setLoading(false);
-        setLoadingMore(false);
+        if (!isStale()) {
+          listOperationRef.current = null;
+          setLoading(false);
+          setLoadingMore(false);
+        }
       }
     },
     [currentProfile, selectedBucket, currentPrefix]
   );
 
+  // Cancel a listing still in flight when the list goes away
+  useEffect(() => {
+    return () => {
+      if (listOperationRef.current) {
+        window.electronAPI.s3.cancelOperation(listOperationRef.current);
+        listOperationRef.current = null;
+      }
+    };
+  }, []);
+
   useEffect(() => {
     loadObjects(true);
     onSelectFile(null); // Clear selection on navigation
     onSelectFiles([]); // Clear multiselect on navigation
-    lastClickedIndexRef.current = -1;
+    lastClickedKeyRef.current = null;
   }, [selectedBucket, currentPrefix, loadObjects, onSelectFile, onSelectFiles]);
 
   // Cancel file search
@@ -287,14 +384,182 @@ function FileList({
     scrollToFile,
   ]);
 
+  // Handle pending multi-selection - only matches items in the loaded listing
+  useEffect(() => {
+    if (!pendingMultiSelection || loading) {
+      return;
+    }
+
+    const keys = new Set(pendingMultiSelection);
+    const matches = items.filter((item) => keys.has(item.key));
+    if (matches.length > 0) {
+      onSelectFiles(matches);
+      onSelectFile(matches[0]);
+      setTimeout(() => scrollToFile(matches[0].key), 100);
+    }
+    onPendingMultiSelectionHandled?.();
+  }, [
+    pendingMultiSelection,
+    items,
+    loading,
+    onSelectFile,
+    onSelectFiles,
+    onPendingMultiSelectionHandled,
+    scrollToFile,
+  ]);
+
+  const getMetadataCacheKey = useCallback(
+    (item: S3Object) => `${selectedBucket}/${item.key}@${item.etag ?? ''}`,
+    [selectedBucket]
+  );
+
+  // Apply the quick filter once typing pauses; clearing it applies at once
+  useEffect(() => {
+    if (!searchQuery.trim()) {
+      setAppliedQuery('');
+      return;
+    }
+    const timeout = setTimeout(() => setAppliedQuery(searchQuery), QUICK_FILTER_DEBOUNCE_MS);
+    return () => clearTimeout(timeout);
+  }, [searchQuery]);
+
+  // Items filtered by type and search - the rows that may become visible -
+  // with the quick filter's matched characters for each
+  const { candidateItems, matchPositions } = useMemo(() => {
+    const byType = filterByType(items, filterType);
+    if (!appliedQuery.trim()) {
+      return { candidateItems: byType, matchPositions: new Map<string, number[]>() };
+    }
+    const matches = quickFilter(byType, appliedQuery, currentPrefix, { caseSensitive: globCaseSensitive });
+    return {
+      candidateItems: matches.map((match) => byType[match.index]),
+      matchPositions: new Map(matches.map((match) => [byType[match.index].key, match.positions])),
+    };
+  }, [items, filterType, appliedQuery, currentPrefix, globCaseSensitive]);
+
+  // Deselect items the quick filter hides, keeping the selection of those still shown.
+  // Runs when the query changes, not when the listing itself changes.
+  useEffect(() => {
+    if (!appliedQuery.trim()) return;
+    const shownKeys = new Set(candidateItems.map((item) => item.key));
+    if (selectedFiles.some((file) => !shownKeys.has(file.key))) {
+      onSelectFiles(selectedFiles.filter((file) => shownKeys.has(file.key)));
+    }
+    if (selectedFile && !shownKeys.has(selectedFile.key)) {
+      onSelectFile(null);
+    }
+  }, [appliedQuery]);
+
   // Compute filtered and sorted items
   const displayedItems = useMemo(() => {
-    let result = items;
-    result = filterByType(result, filterType);
-    result = filterBySearch(result, searchQuery, currentPrefix);
+    let result = candidateItems;
+    if (metadataKey.trim()) {
+      result = result.map((item) => {
+        const metadata = item.isPrefix ? undefined : metadataCache.get(getMetadataCacheKey(item));
+        return metadata ? { ...item, metadata } : item;
+      });
+      result = filterByMetadata(result, metadataKey, metadataValue);
+    }
     result = sortItems(result, sortConfig);
     return result;
-  }, [items, filterType, searchQuery, currentPrefix, sortConfig]);
+  }, [candidateItems, metadataKey, metadataValue, metadataCache, getMetadataCacheKey, sortConfig]);
+
+  // Recompute which rows are on screen from the scroll position and the measured row height
+  const updateVisibleRange = useCallback(() => {
+    const container = listContainerRef.current;
+    if (!container) return;
+    const row = container.querySelector<HTMLElement>('tr.file-row');
+    const range = getVisibleRowRange(container.scrollTop, container.clientHeight, row?.offsetHeight ?? 0);
+    setVisibleRange((prev) => (prev.start === range.start && prev.end === range.end ? prev : range));
+  }, []);
+
+  // The list changes height with the window, and its rows change on navigation and filtering
+  useEffect(() => {
+    updateVisibleRange();
+  }, [displayedItems, updateVisibleRange]);
+
+  useEffect(() => {
+    window.addEventListener('resize', updateVisibleRange);
+    return () => window.removeEventListener('resize', updateVisibleRange);
+  }, [updateVisibleRange]);
+
+  const visibleItems = useMemo(
+    () => displayedItems.slice(visibleRange.start, visibleRange.end),
+    [displayedItems, visibleRange]
+  );
+
+  // Prefetch thumbnails for the image rows on screen; scrolling or navigating away aborts the batch
+  useEffect(() => {
+    if (!selectedBucket) return;
+
+    const controller = new AbortController();
+    prefetchThumbnails(selectedBucket, visibleItems, thumbnailCache, controller.signal, () =>
+      setThumbnailVersion((v) => v + 1)
+    );
+
+    return () => controller.abort();
+  }, [selectedBucket, visibleItems]);
+
+  // Files whose metadata is needed next: the rows on screen, or while a value filter hides
+  // files not fetched yet, the next candidates in display order until the screen is full
+  const metadataTargets = useMemo(() => {
+    if (!metadataKey.trim()) return [];
+    if (!metadataValue.trim() || displayedItems.length >= visibleRange.end) return visibleItems;
+    return sortItems(candidateItems, sortConfig)
+      .filter((item) => !item.isPrefix && !metadataCache.has(getMetadataCacheKey(item)))
+      .slice(0, visibleRange.end - displayedItems.length);
+  }, [
+    metadataKey,
+    metadataValue,
+    displayedItems,
+    visibleRange,
+    visibleItems,
+    candidateItems,
+    sortConfig,
+    metadataCache,
+    getMetadataCacheKey,
+  ]);
+
+  // Fetch metadata for one batch of target rows at a time; storing the batch re-runs this
+  // for the next one. Scrolling, filtering or navigating away drops the batch in flight.
+  useEffect(() => {
+    if (!selectedBucket) return;
+
+    const batch = metadataTargets
+      .filter((item) => !item.isPrefix && !metadataCache.has(getMetadataCacheKey(item)))
+      .slice(0, METADATA_FETCH_CONCURRENCY);
+    if (batch.length === 0) return;
+
+    const bucket = selectedBucket;
+    let cancelled = false;
+
+    Promise.all(
+      batch.map(async (item) => {
+        try {
+          const result = await window.electronAPI.s3.getObjectMetadata(bucket, item.key);
+          const metadata = result.success && result.metadata
+            ? { ...result.metadata.customMetadata, ...result.metadata.tags }
+            : {};
+          return [getMetadataCacheKey(item), metadata] as const;
+        } catch {
+          return [getMetadataCacheKey(item), {}] as const;
+        }
+      })
+    ).then((results) => {
+      if (cancelled) return;
+      setMetadataCache((prev) => {
+        const next = new Map(prev);
+        for (const [cacheKey, metadata] of results) {
+          next.set(cacheKey, metadata);
+        }
+        return next;
+      });
+    });
+
+    return () => {
+      cancelled = true;
+    };
+  }, [selectedBucket, metadataTargets, metadataCache, getMetadataCacheKey]);
 
   // Reset filters when navigating to new location
   useEffect(() => {
@@ -302,6 +567,7 @@ function FileList({
   }, [selectedBucket, currentPrefix]);
 
   const handleScroll = useCallback(() => {
+    updateVisibleRange();
     if (!listContainerRef.current || loadingMore || !hasMore) return;
 
     const { scrollTop, scrollHeight, clientHeight } = listContainerRef.current;
@@ -309,7 +575,7 @@ function FileList({
     if (scrollHeight - scrollTop - clientHeight < 100) {
       loadObjects(false);
     }
-  }, [loadingMore, hasMore, loadObjects]);
+  }, [loadingMore, hasMore, loadObjects, updateVisibleRange]);
 
   // Column header sorting handler
   const handleSort = useCallback((field: SortField) => {
@@ -332,10 +598,14 @@ function FileList({
     const isMac = navigator.platform.toUpperCase().indexOf('MAC') >= 0;
     const isCtrlOrCmd = isMac ? event.metaKey : event.ctrlKey;
 
-    if (event.shiftKey && lastClickedIndexRef.current >= 0) {
+    const anchorIndex = lastClickedKeyRef.current === null
+      ? -1
+      : displayedItems.findIndex(displayed => displayed.key === lastClickedKeyRef.current);
+
+    if (event.shiftKey && anchorIndex >= 0) {
       // Shift+click: range selection (includes both files and folders)
-      const start = Math.min(lastClickedIndexRef.current, index);
-      const end = Math.max(lastClickedIndexRef.current, index);
+      const start = Math.min(anchorIndex, index);
+      const end = Math.max(anchorIndex, index);
       const rangeItems = displayedItems.slice(start, end + 1);
 
       if (isCtrlOrCmd) {
@@ -365,12 +635,12 @@ function FileList({
         onSelectFiles([...selectedFiles, item]);
         onSelectFile(item);
       }
-      lastClickedIndexRef.current = index;
+      lastClickedKeyRef.current = item.key;
     } else {
       // Single click: select only this item (same behavior for files and folders)
       onSelectFile(item);
       onSelectFiles([item]);
-      lastClickedIndexRef.current = index;
+      lastClickedKeyRef.current = item.key;
     }
   };
 
@@ -380,9 +650,11 @@ function FileList({
       onNavigate(item.key);
       onSelectFile(null);
       onSelectFiles([]);
-      lastClickedIndexRef.current = -1;
+      lastClickedKeyRef.current = null;
+    } else if (onOpenFile) {
+      // Double-click on file: open in the viewer chosen by the registry
+      onOpenFile(item);
     } else {
-      // Double-click on file: trigger download
       onDownloadFile?.(item);
     }
   };
@@ -553,16 +825,26 @@ function FileList({
           {currentPrefix && (
             <>
               {currentPrefix.split('/').filter(Boolean).map((part, idx, arr) => {
-                const prefix = arr.slice(0, idx + 1).join('/') + '/';
+                const prefixAt = (i: number) => arr.slice(0, i + 1).join('/') + '/';
+                const prefix = prefixAt(idx);
+                if (idx < arr.length - 1 && collapsedPrefixes?.has(prefix)) {
+                  return null;
+                }
+                // Fold any collapsed folders just before this one into a single crumb
+                let start = idx;
+                while (start > 0 && collapsedPrefixes?.has(prefixAt(start - 1))) {
+                  start--;
+                }
+                const label = arr.slice(start, idx + 1).join('/');
                 return (
                   <React.Fragment key={prefix}>
                     <span className="breadcrumb-separator">/</span>
                     <button
                       className="breadcrumb-item"
                       onClick={() => onNavigate(prefix)}
-                      title={part}
+                      title={label}
                     >
-                      {part}
+                      {label}
                     </button>
                   </React.Fragment>
                 );
@@ -585,9 +867,15 @@ function FileList({
         onFilterTypeChange={setFilterType}
         searchQuery={searchQuery}
         onSearchChange={setSearchQuery}
+        globCaseSensitive={globCaseSensitive}
+        onGlobCaseSensitiveChange={setGlobCaseSensitive}
         totalCount={items.length}
         filteredCount={displayedItems.length}
         disabled={loading}
+        metadataKey={metadataKey}
+        onMetadataKeyChange={setMetadataKey}
+        metadataValue={metadataValue}
+        onMetadataValueChange={setMetadataValue}
       />
 
       {/* File list table */}
@@ -625,13 +913,21 @@ function FileList({
                 >
                   Last Modified{getSortIndicator('lastModified')}
                 </th>
+                {metadataKey.trim() && (
+                  <th className="col-metadata" title={`Tag or metadata: ${metadataKey.trim()}`}>
+                    {metadataKey.trim()}
+                  </th>
+                )}
               </tr>
             </thead>
             <tbody>
               {displayedItems.map((item, index) => {
-                const name = getFileName(item.key, currentPrefix);
+                const name = getDisplayName(item.key, currentPrefix);
                 const isSelected = selectedFile?.key === item.key;
                 const isInMultiselect = selectedFiles.some(f => f.key === item.key);
+                const thumbnailUrl = item.isPrefix || !selectedBucket
+                  ? undefined
+                  : thumbnailCache.get(thumbnailCacheKey(selectedBucket, item.key, item.etag));
                 return (
                   <tr
                     key={item.key}
@@ -654,7 +950,7 @@ function FileList({
                           onNavigate(item.key);
                           onSelectFile(null);
                           onSelectFiles([]);
-                          lastClickedIndexRef.current = -1;
+                          lastClickedKeyRef.current = null;
                         } else {
                           // Enter on file: trigger download
                           onDownloadFile?.(item);
@@ -663,13 +959,27 @@ function FileList({
                     }}
                   >
                     <td className="col-name">
-                      <span className="file-icon">{getFileIcon(item.key, item.isPrefix)}</span>
+                      {thumbnailUrl ? (
+                        <img className="file-thumbnail" src={thumbnailUrl} alt="" />
+                      ) : (
+                        <span className="file-icon">{getFileIcon(item.key, item.isPrefix)}</span>
+                      )}
                       <span className="file-name" title={name}>
-                        {name}
+                        {highlightName(name, matchPositions.get(item.key))}
                       </span>
+                      {!item.isPrefix && item.storageClass && item.storageClass !== 'STANDARD' && (
+                        <span className="file-storage-class" title={`Storage class: ${item.storageClass}`}>
+                          {item.storageClass}
+                        </span>
+                      )}
                     </td>
                     <td className="col-size">{formatFileSize(item.size)}</td>
-                    <td className="col-modified">{formatDate(item.lastModified)}</td>
+                    <td className="col-modified" title={item.lastModified ? formatAge(item.lastModified) : undefined}>
+                      {formatDate(item.lastModified)}
+                    </td>
+                    {metadataKey.trim() && (
+                      <td className="col-metadata">{getMetadataCellText(item, metadataKey)}</td>
+                    )}
                   </tr>
                 );
               })}
@@ -1,5 +1,8 @@
 import React, { useState, useCallback, useMemo } from 'react';
 import { S3Object } from './FileList';
+import { getExtension } from '../../shared/fileTypes';
+import { fuzzyMatch } from '../utils/fuzzyMatch';
+import { isGlobPattern, matchesGlob, type GlobOptions } from '../utils/globMatch';
 
 export type SortField = 'name' | 'size' | 'lastModified';
 export type SortDirection = 'asc' | 'desc';
@@ -31,9 +34,18 @@ export interface FileListControlsProps {
   onFilterTypeChange: (type: string) => void;
   searchQuery: string;
   onSearchChange: (query: string) => void;
+  /** Whether wildcard queries match letters case-sensitively */
+  globCaseSensitive?: boolean;
+  onGlobCaseSensitiveChange?: (caseSensitive: boolean) => void;
   totalCount: number;
   filteredCount: number;
   disabled?: boolean;
+  /** Metadata/tag key shown as an extra column (empty = column disabled) */
+  metadataKey?: string;
+  onMetadataKeyChange?: (key: string) => void;
+  /** Only show objects whose metadata key has this value (empty = no filter) */
+  metadataValue?: string;
+  onMetadataValueChange?: (value: string) => void;
 }
 
 function FileListControls({
@@ -43,9 +55,15 @@ function FileListControls({
   onFilterTypeChange,
   searchQuery,
   onSearchChange,
+  globCaseSensitive = false,
+  onGlobCaseSensitiveChange,
   totalCount,
   filteredCount,
   disabled = false,
+  metadataKey = '',
+  onMetadataKeyChange,
+  metadataValue = '',
+  onMetadataValueChange,
 }: FileListControlsProps): React.ReactElement {
   const handleSearchChange = useCallback(
     (e: React.ChangeEvent<HTMLInputElement>) => {
@@ -96,6 +114,18 @@ function FileListControls({
               x
             </button>
           )}
+          {onGlobCaseSensitiveChange && isGlobPattern(searchQuery.trim()) && (
+            <button
+              className={`file-list-search-case ${globCaseSensitive ? 'active' : ''}`}
+              onClick={() => onGlobCaseSensitiveChange(!globCaseSensitive)}
+              disabled={disabled}
+              aria-pressed={globCaseSensitive}
+              title="Match case in wildcard patterns"
+              aria-label="Match case"
+            >
+              Aa
+            </button>
+          )}
         </div>
         <select
           className="file-list-type-filter"
@@ -110,10 +140,38 @@ function FileListControls({
             </option>
           ))}
         </select>
+        {onMetadataKeyChange && (
+          <input
+            type="text"
+            className="file-list-metadata-input"
+            placeholder="Metadata key..."
+            value={metadataKey}
+            onChange={(e) => onMetadataKeyChange(e.target.value)}
+            disabled={disabled}
+            aria-label="Metadata column key"
+            title="Show a tag or user metadata value as a column"
+          />
+        )}
+        {onMetadataValueChange && metadataKey.trim() && (
+          <input
+            type="text"
+            className="file-list-metadata-input"
+            placeholder="Equals..."
+            value={metadataValue}
+            onChange={(e) => onMetadataValueChange(e.target.value)}
+            disabled={disabled}
+            aria-label="Metadata filter value"
+          />
+        )}
         {showingText && (
           <span className="file-list-showing-count">{showingText}</span>
         )}
       </div>
+      {metadataKey.trim() && (
+        <div className="file-list-metadata-warning" role="note">
+          Metadata columns need one extra request per visible file
+        </div>
+      )}
     </div>
   );
 }
@@ -147,6 +205,11 @@ export function sortItems(items: S3Object[], config: SortConfig): S3Object[] {
       }
     }
 
+    if (comparison === 0 && config.field !== 'name') {
+      // Equal sizes or dates keep a stable, readable order instead of listing order
+      return a.key.toLowerCase().localeCompare(b.key.toLowerCase());
+    }
+
     return config.direction === 'asc' ? comparison : -comparison;
   });
 }
@@ -161,23 +224,80 @@ export function filterByType(items: S3Object[], typeFilter: string): S3Object[]
     // Always show folders
     if (item.isPrefix) return true;
 
-    const ext = item.key.split('.').pop()?.toLowerCase() ?? '';
-    return filterConfig.extensions.includes(ext);
+    const ext = getExtension(item.key);
+    return ext !== null && filterConfig.extensions.includes(ext);
   });
 }
 
+export interface QuickFilterMatch {
+  /** Index of the item in the filtered list */
+  index: number;
+  /** Indices of the matched characters in the item's display name, for highlighting */
+  positions: number[];
+}
+
+/**
+ * The display name of an item: its key without the current prefix or a folder's trailing slash
+ */
+export function getDisplayName(key: string, currentPrefix: string): string {
+  const name = key.startsWith(currentPrefix) ? key.slice(currentPrefix.length) : key;
+  return name.endsWith('/') ? name.slice(0, -1) : name;
+}
+
+/**
+ * Fuzzy-matches the query against each item's display name, folders included.
+ * A query with "*" or "?" is matched as a glob instead (e.g. "*.parquet" or
+ * "2024/**\/*.csv"), with nothing to highlight. An empty query matches everything
+ * with nothing to highlight.
+ * @param globOptions - Options for queries matched as globs
+ * @returns The matching items' indices, in list order, with their match positions
+ */
+export function quickFilter(
+  items: S3Object[],
+  query: string,
+  currentPrefix: string,
+  globOptions: GlobOptions = {}
+): QuickFilterMatch[] {
+  const matches: QuickFilterMatch[] = [];
+  const pattern = query.trim();
+  if (isGlobPattern(pattern)) {
+    items.forEach((item, index) => {
+      if (matchesGlob(getDisplayName(item.key, currentPrefix), pattern, globOptions)) {
+        matches.push({ index, positions: [] });
+      }
+    });
+    return matches;
+  }
+  items.forEach((item, index) => {
+    const match = fuzzyMatch(getDisplayName(item.key, currentPrefix), query);
+    if (match) {
+      matches.push({ index, positions: match.positions });
+    }
+  });
+  return matches;
+}
+
 export function filterBySearch(items: S3Object[], query: string, currentPrefix: string): S3Object[] {
   if (!query.trim()) return items;
+  return quickFilter(items, query, currentPrefix).map(match => items[match.index]);
+}
 
-  const lowerQuery = query.toLowerCase().trim();
+/**
+ * Keeps only objects whose metadata (tags and user metadata) has key=value.
+ * Keys are compared case-insensitively since S3 lowercases user metadata keys.
+ * Folders are always shown; files whose metadata hasn't been fetched yet are hidden.
+ */
+export function filterByMetadata(items: S3Object[], key: string, value: string): S3Object[] {
+  const trimmedKey = key.trim().toLowerCase();
+  const trimmedValue = value.trim();
+  if (!trimmedKey || !trimmedValue) return items;
 
   return items.filter((item) => {
-    // Get just the filename (without the prefix)
-    const name = item.key.startsWith(currentPrefix)
-      ? item.key.slice(currentPrefix.length)
-      : item.key;
-    const displayName = name.endsWith('/') ? name.slice(0, -1) : name;
-    return displayName.toLowerCase().includes(lowerQuery);
+    if (item.isPrefix) return true;
+    if (!item.metadata) return false;
+
+    const entry = Object.entries(item.metadata).find(([k]) => k.toLowerCase() === trimmedKey);
+    return entry !== undefined && entry[1] === trimmedValue;
   });
 }
 
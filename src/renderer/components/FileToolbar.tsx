@@ -1,4 +1,5 @@
 import React from 'react';
+import { getBaseExtension, getExtension } from '../../shared/fileTypes';
 
 export interface FileToolbarProps {
   selectedBucket: string | null;
@@ -19,7 +20,15 @@ export interface FileToolbarProps {
   onViewJson: () => void;
   onViewYaml: () => void;
   onViewImage: () => void;
+  /** Run an S3 Select query against the selected CSV or JSON file */
+  onQuery?: () => void;
+  /** Download the selected file to a temp folder and open it with the OS default app */
+  onOpenExternally?: () => void;
   onCopyUrl: () => void;
+  /** Copy the selected file to a bucket reachable through another profile */
+  onCopyToProfile?: () => void;
+  /** Open the current bucket location in the AWS web console */
+  onOpenInConsole?: () => void;
   onRefresh: () => void;
   onProperties: () => void;
   onNewFile: () => void;
@@ -27,28 +36,11 @@ export interface FileToolbarProps {
   disabled?: boolean;
 }
 
-/**
- * Get the base extension of a file, looking past .gz if present
- * e.g., 'data.json.gz' -> 'json', 'data.csv' -> 'csv'
- */
-function getBaseExtension(key: string): string {
-  const lowerKey = key.toLowerCase();
-
-  // If it ends with .gz, get the extension before .gz
-  if (lowerKey.endsWith('.gz')) {
-    const withoutGz = key.slice(0, -3);
-    return withoutGz.split('.').pop()?.toLowerCase() ?? '';
-  }
-
-  // Otherwise just get the last extension
-  return key.split('.').pop()?.toLowerCase() ?? '';
-}
-
 /**
  * Check if a file is gzip compressed
  */
 function isGzipFile(key: string): boolean {
-  return key.toLowerCase().endsWith('.gz');
+  return getExtension(key) === 'gz';
 }
 
 /**
@@ -66,9 +58,8 @@ function isEditableFile(_key: string): boolean {
  * Determine if a file is a parquet file
  */
 function isParquetFile(key: string): boolean {
-  const ext = key.split('.').pop()?.toLowerCase() ?? '';
   // Parquet files are not supported with .gz compression
-  return ext === 'parquet';
+  return getExtension(key) === 'parquet';
 }
 
 /**
@@ -87,6 +78,14 @@ function isJsonFile(key: string): boolean {
   return ext === 'json';
 }
 
+/**
+ * Determine if a file holds one JSON document per line (including .jsonl.gz)
+ */
+function isJsonLinesFile(key: string): boolean {
+  const ext = getBaseExtension(key);
+  return ext === 'jsonl' || ext === 'ndjson';
+}
+
 /**
  * Determine if a file is a YAML file (including .yaml.gz, .yml.gz)
  */
@@ -99,9 +98,8 @@ function isYamlFile(key: string): boolean {
  * Determine if a file is an image that can be previewed
  */
 function isImageFile(key: string): boolean {
-  const ext = key.split('.').pop()?.toLowerCase() ?? '';
   const imageExtensions = ['png', 'jpg', 'jpeg', 'gif', 'webp', 'svg', 'ico', 'bmp'];
-  return imageExtensions.includes(ext);
+  return imageExtensions.includes(getExtension(key) ?? '');
 }
 
 // SVG Icons as components for better readability
@@ -189,6 +187,37 @@ const Icons = {
       <path d="M5 15H4a2 2 0 01-2-2V4a2 2 0 012-2h9a2 2 0 012 2v1" />
     </svg>
   ),
+  copyToProfile: (
+    <svg viewBox="0 0 24 24" width="16" height="16" fill="none" stroke="currentColor" strokeWidth="2" strokeLinecap="round" strokeLinejoin="round">
+      <path d="M16 21v-2a4 4 0 00-4-4H6a4 4 0 00-4 4v2" />
+      <circle cx="9" cy="7" r="4" />
+      <polyline points="17,8 21,12 17,16" />
+      <line x1="21" y1="12" x2="13" y2="12" />
+    </svg>
+  ),
+  query: (
+    <svg viewBox="0 0 24 24" width="16" height="16" fill="none" stroke="currentColor" strokeWidth="2" strokeLinecap="round" strokeLinejoin="round">
+      <ellipse cx="10" cy="5" rx="7" ry="3" />
+      <path d="M3 5v10c0 1.66 3.13 3 7 3" />
+      <path d="M17 5v4" />
+      <circle cx="17" cy="16" r="3" />
+      <line x1="19.5" y1="18.5" x2="22" y2="21" />
+    </svg>
+  ),
+  openExternal: (
+    <svg viewBox="0 0 24 24" width="16" height="16" fill="none" stroke="currentColor" strokeWidth="2" strokeLinecap="round" strokeLinejoin="round">
+      <rect x="2" y="3" width="20" height="14" rx="2" />
+      <line x1="8" y1="21" x2="16" y2="21" />
+      <line x1="12" y1="17" x2="12" y2="21" />
+    </svg>
+  ),
+  console: (
+    <svg viewBox="0 0 24 24" width="16" height="16" fill="none" stroke="currentColor" strokeWidth="2" strokeLinecap="round" strokeLinejoin="round">
+      <path d="M18 13v6a2 2 0 01-2 2H5a2 2 0 01-2-2V8a2 2 0 012-2h6" />
+      <polyline points="15,3 21,3 21,9" />
+      <line x1="10" y1="14" x2="21" y2="3" />
+    </svg>
+  ),
   rename: (
     <svg viewBox="0 0 24 24" width="16" height="16" fill="none" stroke="currentColor" strokeWidth="2" strokeLinecap="round" strokeLinejoin="round">
       <path d="M17 3a2.828 2.828 0 114 4L7.5 20.5 2 22l1.5-5.5L17 3z" />
@@ -257,7 +286,11 @@ function FileToolbar({
   onViewJson,
   onViewYaml,
   onViewImage,
+  onQuery,
+  onOpenExternally,
   onCopyUrl,
+  onCopyToProfile,
+  onOpenInConsole,
   onRefresh,
   onProperties,
   onNewFile,
@@ -269,11 +302,14 @@ function FileToolbar({
   const hasAnySelection = selectedFile !== null;
   const hasMultipleSelection = selectedCount > 1;
   const canEdit = hasSelection && !hasMultipleSelection;
+  // Several selected items are downloaded together (folders among them are skipped)
+  const canDownload = hasMultipleSelection || hasSelection;
   const canViewParquet = hasSelection && !hasMultipleSelection && isParquetFile(selectedFile!.key);
   const canViewCsv = hasSelection && !hasMultipleSelection && isCsvFile(selectedFile!.key);
   const canViewJson = hasSelection && !hasMultipleSelection && isJsonFile(selectedFile!.key);
   const canViewYaml = hasSelection && !hasMultipleSelection && isYamlFile(selectedFile!.key);
   const canViewImage = hasSelection && !hasMultipleSelection && isImageFile(selectedFile!.key);
+  const canQuery = canViewCsv || canViewJson || (hasSelection && !hasMultipleSelection && isJsonLinesFile(selectedFile!.key));
   // Delete is allowed for any selected items (files or folders)
   const canDelete = selectedCount > 0;
   // Properties can be shown for any single selected item (file or folder)
@@ -301,9 +337,10 @@ function FileToolbar({
       />
       <ToolbarButton
         icon={Icons.download}
-        title={hasMultipleSelection ? 'Download not available for multiple files' : 'Download selected file'}
+        title={hasMultipleSelection ? `Download ${selectedCount} selected files` : 'Download selected file'}
         onClick={onDownload}
-        disabled={disabled || !hasSelection || hasMultipleSelection}
+        disabled={disabled || !canDownload}
+        badge={hasMultipleSelection ? String(selectedCount) : undefined}
       />
       <ToolbarButton
         icon={Icons.edit}
@@ -341,6 +378,22 @@ function FileToolbar({
         onClick={onViewImage}
         disabled={disabled || !canViewImage}
       />
+      {onQuery && (
+        <ToolbarButton
+          icon={Icons.query}
+          title={canQuery ? 'Query with S3 Select' : 'Select a CSV or JSON file to query'}
+          onClick={onQuery}
+          disabled={disabled || !canQuery}
+        />
+      )}
+      {onOpenExternally && (
+        <ToolbarButton
+          icon={Icons.openExternal}
+          title={hasMultipleSelection ? 'Open with default app not available for multiple files' : (hasSelection ? 'Open with default app' : 'Select a file to open with its default app')}
+          onClick={onOpenExternally}
+          disabled={disabled || !hasSelection || hasMultipleSelection}
+        />
+      )}
       <ToolbarButton
         icon={Icons.copy}
         title={hasMultipleSelection ? 'Copy URL not available for multiple files' : (hasSelection ? 'Copy S3 URL to clipboard' : 'Select a file to copy URL')}
@@ -348,6 +401,14 @@ function FileToolbar({
         disabled={disabled || !hasSelection || hasMultipleSelection}
         className="toolbar-btn-copy"
       />
+      {onCopyToProfile && (
+        <ToolbarButton
+          icon={Icons.copyToProfile}
+          title={hasMultipleSelection ? 'Copy to profile not available for multiple files' : (hasSelection ? 'Copy file to another profile' : 'Select a file to copy to another profile')}
+          onClick={onCopyToProfile}
+          disabled={disabled || !hasSelection || hasMultipleSelection}
+        />
+      )}
       <ToolbarButton
         icon={Icons.rename}
         title={hasMultipleSelection ? 'Rename not available for multiple files' : 'Rename selected file'}
@@ -369,6 +430,14 @@ function FileToolbar({
         disabled={disabled || !canShowProperties}
       />
       <div className="toolbar-spacer" />
+      {onOpenInConsole && (
+        <ToolbarButton
+          icon={Icons.console}
+          title={selectedBucket ? 'Open current location in AWS Console' : 'Select a bucket to open in AWS Console'}
+          onClick={onOpenInConsole}
+          disabled={disabled || !selectedBucket}
+        />
+      )}
       <ToolbarButton
         icon={Icons.refresh}
         title="Refresh file list"
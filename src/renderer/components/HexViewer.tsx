@@ -0,0 +1,173 @@
+import React, { useState, useEffect, useMemo, useCallback } from 'react';
+
+export interface HexViewerProps {
+  bucket: string;
+  fileKey: string;
+  fileName: string;
+  fileSize: number;
+  /** Version to show instead of the latest one */
+  versionId?: string;
+  onClose: () => void;
+}
+
+/**
+ * Number of leading bytes shown in the hex preview (64KB)
+ */
+const HEX_PREVIEW_BYTES = 64 * 1024;
+
+const BYTES_PER_ROW = 16;
+
+export interface HexRow {
+  offset: string;
+  hex: string;
+  ascii: string;
+}
+
+/**
+ * Format file size for display
+ */
+function formatSize(bytes: number): string {
+  if (bytes === 0) return '0 B';
+  const units = ['B', 'KB', 'MB', 'GB', 'TB'];
+  const i = Math.floor(Math.log(bytes) / Math.log(1024));
+  return `${(bytes / Math.pow(1024, i)).toFixed(i > 0 ? 1 : 0)} ${units[i]}`;
+}
+
+/**
+ * Splits bytes into hex dump rows: offset, space-separated hex bytes and printable ASCII
+ */
+export function formatHexRows(bytes: Uint8Array, bytesPerRow = BYTES_PER_ROW): HexRow[] {
+  const rows: HexRow[] = [];
+  for (let start = 0; start < bytes.length; start += bytesPerRow) {
+    const slice = bytes.subarray(start, start + bytesPerRow);
+    const hex = Array.from(slice, b => b.toString(16).padStart(2, '0')).join(' ');
+    const ascii = Array.from(slice, b => (b >= 0x20 && b < 0x7f ? String.fromCharCode(b) : '.')).join('');
+    rows.push({
+      offset: start.toString(16).padStart(8, '0'),
+      hex,
+      ascii,
+    });
+  }
+  return rows;
+}
+
+function HexViewer({
+  bucket,
+  fileKey,
+  fileName,
+  fileSize,
+  versionId,
+  onClose,
+}: HexViewerProps): React.ReactElement {
+  const [loading, setLoading] = useState(true);
+  const [error, setError] = useState<string | null>(null);
+  const [data, setData] = useState<Uint8Array>(new Uint8Array());
+
+  useEffect(() => {
+    let mounted = true;
+
+    const loadBytes = async () => {
+      setLoading(true);
+      setError(null);
+
+      try {
+        const result = await window.electronAPI.s3.downloadPartialContent(bucket, fileKey, HEX_PREVIEW_BYTES, 0, versionId);
+        if (!result.success) {
+          throw new Error(result.error || 'Failed to download file');
+        }
+        if (!mounted) return;
+        setData(result.data ?? new Uint8Array());
+      } catch (err) {
+        if (!mounted) return;
+        setError(err instanceof Error ? err.message : 'Failed to load file');
+      } finally {
+        if (mounted) {
+          setLoading(false);
+        }
+      }
+    };
+
+    loadBytes();
+
+    return () => {
+      mounted = false;
+    };
+  }, [bucket, fileKey, versionId]);
+
+  const rows = useMemo(() => formatHexRows(data), [data]);
+
+  const handleKeyDown = useCallback(
+    (e: React.KeyboardEvent) => {
+      if (e.key === 'Escape') {
+        onClose();
+      }
+    },
+    [onClose]
+  );
+
+  const isTruncated = fileSize > data.length;
+
+  return (
+    <div className="hex-viewer-overlay" onKeyDown={handleKeyDown}>
+      <div className="hex-viewer">
+        <div className="hex-viewer-header">
+          <div className="hex-viewer-title">
+            <span className="hex-viewer-icon">0x</span>
+            <span className="hex-viewer-filename" title={fileKey}>
+              {fileName}
+            </span>
+          </div>
+          <span className="hex-viewer-meta-item">{formatSize(fileSize)}</span>
+          <button
+            className="hex-viewer-btn"
+            onClick={onClose}
+            title="Close (Escape)"
+          >
+            Close
+          </button>
+        </div>
+
+        {error && (
+          <div className="hex-viewer-error">
+            <span className="error-icon">!</span>
+            <span>{error}</span>
+          </div>
+        )}
+
+        <div className="hex-viewer-content">
+          {loading ? (
+            <div className="hex-viewer-loading">
+              <span className="loading-spinner"></span>
+              <span>Loading file...</span>
+            </div>
+          ) : (
+            <pre className="hex-viewer-pre">
+              {rows.map(row => (
+                <div key={row.offset} className="hex-viewer-row">
+                  <span className="hex-viewer-offset">{row.offset}</span>
+                  <span className="hex-viewer-bytes">{row.hex.padEnd(BYTES_PER_ROW * 3 - 1, ' ')}</span>
+                  <span className="hex-viewer-ascii">{row.ascii}</span>
+                </div>
+              ))}
+            </pre>
+          )}
+        </div>
+
+        <div className="hex-viewer-footer">
+          <span className="hex-viewer-path" title={`s3://${bucket}/${fileKey}`}>
+            s3://{bucket}/{fileKey}
+          </span>
+          {!loading && !error && (
+            <span className="hex-viewer-status">
+              {isTruncated
+                ? `Showing first ${formatSize(data.length)} of ${formatSize(fileSize)}`
+                : `${data.length} bytes`}
+            </span>
+          )}
+        </div>
+      </div>
+    </div>
+  );
+}
+
+export default HexViewer;
@@ -1,10 +1,13 @@
 import React, { useState, useEffect, useCallback, useRef } from 'react';
+import { guessMimeType } from '../../shared/fileTypes';
 
 export interface ImagePreviewProps {
   bucket: string;
   fileKey: string;
   fileName: string;
   fileSize?: number;
+  /** Version to show instead of the latest one */
+  versionId?: string;
   onClose: () => void;
 }
 
@@ -21,23 +24,11 @@ function formatFileSize(bytes?: number): string {
 }
 
 /**
- * Get MIME type from file extension
+ * Get the image MIME type from the file extension, assuming PNG for anything else
  */
 function getMimeType(fileName: string): string {
-  const ext = fileName.split('.').pop()?.toLowerCase() ?? '';
-  const mimeTypes: Record<string, string> = {
-    png: 'image/png',
-    jpg: 'image/jpeg',
-    jpeg: 'image/jpeg',
-    gif: 'image/gif',
-    webp: 'image/webp',
-    svg: 'image/svg+xml',
-    ico: 'image/x-icon',
-    bmp: 'image/bmp',
-    tiff: 'image/tiff',
-    tif: 'image/tiff',
-  };
-  return mimeTypes[ext] || 'image/png';
+  const mimeType = guessMimeType(fileName);
+  return mimeType.startsWith('image/') ? mimeType : 'image/png';
 }
 
 function ImagePreview({
@@ -45,6 +36,7 @@ function ImagePreview({
   fileKey,
   fileName,
   fileSize,
+  versionId,
   onClose,
 }: ImagePreviewProps): React.ReactElement {
   const [isLoading, setIsLoading] = useState(true);
@@ -73,7 +65,7 @@ function ImagePreview({
         }
 
         // Download image content
-        const result = await window.electronAPI.s3.downloadBinaryContent(bucket, fileKey);
+        const result = await window.electronAPI.s3.downloadBinaryContent(bucket, fileKey, versionId);
 
         if (!mounted) return;
 
@@ -106,7 +98,7 @@ function ImagePreview({
         URL.revokeObjectURL(objectUrl);
       }
     };
-  }, [bucket, fileKey, fileName, fileSize]);
+  }, [bucket, fileKey, fileName, fileSize, versionId]);
 
   // Handle image load to get dimensions
   const handleImageLoad = useCallback((e: React.SyntheticEvent<HTMLImageElement>) => {
@@ -0,0 +1,189 @@
+import React, { useState, useEffect, useCallback } from 'react';
+
+export interface MultipartUpload {
+  key: string;
+  uploadId: string;
+  initiated?: Date | string;
+  storageClass?: string;
+}
+
+export interface IncompleteUploadsDialogProps {
+  isOpen: boolean;
+  bucket: string;
+  /** Aborting is disabled for read-only profiles */
+  isReadOnly: boolean;
+  onClose: () => void;
+}
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+/**
+ * How long ago an upload was started, e.g. "3 days ago"
+ */
+export function formatUploadAge(initiated: Date | string | undefined, now: number = Date.now()): string {
+  if (!initiated) return 'Unknown';
+  const elapsed = now - new Date(initiated).getTime();
+  const days = Math.floor(elapsed / DAY_MS);
+  if (days >= 1) return `${days} ${days === 1 ? 'day' : 'days'} ago`;
+  const hours = Math.floor(elapsed / (60 * 60 * 1000));
+  if (hours >= 1) return `${hours} ${hours === 1 ? 'hour' : 'hours'} ago`;
+  return 'Less than an hour ago';
+}
+
+/**
+ * Lists the incomplete multipart uploads of a bucket, whose parts are billed
+ * until aborted, and aborts them one at a time or all together
+ */
+function IncompleteUploadsDialog({
+  isOpen,
+  bucket,
+  isReadOnly,
+  onClose,
+}: IncompleteUploadsDialogProps): React.ReactElement | null {
+  const [uploads, setUploads] = useState<MultipartUpload[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [aborting, setAborting] = useState<Set<string>>(new Set());
+  const [confirmAll, setConfirmAll] = useState(false);
+
+  const load = useCallback(async () => {
+    setUploads(null);
+    setError(null);
+    const result = await window.electronAPI.s3.listMultipartUploads(bucket);
+    if (result.success) {
+      setUploads(result.uploads ?? []);
+    } else {
+      setError(result.error ?? 'Failed to list incomplete uploads');
+      setUploads([]);
+    }
+  }, [bucket]);
+
+  useEffect(() => {
+    if (isOpen) {
+      setConfirmAll(false);
+      load();
+    }
+  }, [isOpen, load]);
+
+  const abortUploads = async (targets: MultipartUpload[]) => {
+    setError(null);
+    setAborting(prev => new Set([...prev, ...targets.map(u => u.uploadId)]));
+
+    const failures: string[] = [];
+    const aborted = new Set<string>();
+    for (const upload of targets) {
+      const result = await window.electronAPI.s3.abortMultipartUpload(bucket, upload.key, upload.uploadId);
+      if (result.success) {
+        aborted.add(upload.uploadId);
+      } else {
+        failures.push(`${upload.key}: ${result.error ?? 'Abort failed'}`);
+      }
+    }
+
+    setUploads(prev => (prev ?? []).filter(u => !aborted.has(u.uploadId)));
+    setAborting(prev => new Set([...prev].filter(id => !targets.some(u => u.uploadId === id))));
+    if (failures.length > 0) {
+      setError(failures.join('\n'));
+    }
+  };
+
+  const handleAbortAll = () => {
+    if (!confirmAll) {
+      setConfirmAll(true);
+      return;
+    }
+    setConfirmAll(false);
+    abortUploads(uploads ?? []);
+  };
+
+  const handleKeyDown = (e: React.KeyboardEvent) => {
+    if (e.key === 'Escape') {
+      onClose();
+    }
+  };
+
+  if (!isOpen) {
+    return null;
+  }
+
+  return (
+    <div className="dialog-overlay" onClick={onClose} onKeyDown={handleKeyDown}>
+      <div className="dialog dialog-incomplete-uploads" onClick={(e) => e.stopPropagation()}>
+        <div className="dialog-header">
+          <h3>Incomplete Uploads</h3>
+        </div>
+        <div className="dialog-content properties-content">
+          <p>
+            Multipart uploads in <strong>{bucket}</strong> that were never completed. Their parts are stored,
+            and billed, until the upload is aborted.
+          </p>
+          {error && (
+            <div className="dialog-warning incomplete-uploads-error" role="alert">
+              {error}
+            </div>
+          )}
+          {uploads === null ? (
+            <div className="properties-loading">
+              <span>Loading incomplete uploads...</span>
+            </div>
+          ) : uploads.length === 0 ? (
+            !error && <p className="incomplete-uploads-empty">No incomplete uploads</p>
+          ) : (
+            <table className="incomplete-uploads-table">
+              <thead>
+                <tr>
+                  <th>Key</th>
+                  <th>Started</th>
+                  <th />
+                </tr>
+              </thead>
+              <tbody>
+                {uploads.map(upload => (
+                  <tr key={upload.uploadId}>
+                    <td className="incomplete-uploads-key" title={upload.key}>
+                      {upload.key}
+                    </td>
+                    <td title={upload.initiated ? new Date(upload.initiated).toLocaleString() : undefined}>
+                      {formatUploadAge(upload.initiated)}
+                    </td>
+                    <td>
+                      <button
+                        type="button"
+                        className="property-copy-btn"
+                        onClick={() => abortUploads([upload])}
+                        disabled={isReadOnly || aborting.has(upload.uploadId)}
+                        aria-label={`Abort upload of ${upload.key}`}
+                        title={isReadOnly ? 'Read-only profile' : 'Abort the upload and delete its parts'}
+                      >
+                        {aborting.has(upload.uploadId) ? 'Aborting...' : 'Abort'}
+                      </button>
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          )}
+        </div>
+        <div className="dialog-actions">
+          <button type="button" className="dialog-btn dialog-btn-cancel" onClick={load} disabled={uploads === null}>
+            Refresh
+          </button>
+          {uploads && uploads.length > 0 && (
+            <button
+              type="button"
+              className="dialog-btn dialog-btn-danger"
+              onClick={handleAbortAll}
+              disabled={isReadOnly || aborting.size > 0}
+            >
+              {confirmAll ? `Confirm: abort ${uploads.length}` : 'Abort all'}
+            </button>
+          )}
+          <button type="button" className="dialog-btn dialog-btn-confirm" onClick={onClose}>
+            Close
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
+
+export default IncompleteUploadsDialog;
@@ -5,6 +5,8 @@ export interface JsonViewerProps {
   fileKey: string;
   fileName: string;
   fileSize: number;
+  /** Version to show instead of the latest one */
+  versionId?: string;
   onClose: () => void;
 }
 
@@ -210,10 +212,13 @@ function JsonViewer({
   fileKey,
   fileName,
   fileSize,
+  versionId,
   onClose,
 }: JsonViewerProps): React.ReactElement {
   const [loading, setLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
+  // Codec the content was decompressed from, shown in the footer
+  const [compression, setCompression] = useState<string | null>(null);
   const [content, setContent] = useState<string>('');
   const [parsedJson, setParsedJson] = useState<unknown>(null);
   const [viewMode, setViewMode] = useState<ViewMode>('tree');
@@ -236,7 +241,7 @@ function JsonViewer({
         }
 
         // Download the file as text
-        const result = await window.electronAPI.s3.downloadContent(bucket, fileKey);
+        const result = await window.electronAPI.s3.downloadContent(bucket, fileKey, versionId);
         if (!result.success) {
           throw new Error(result.error || 'Failed to download file');
         }
@@ -247,6 +252,7 @@ function JsonViewer({
 
         if (!mounted) return;
 
+        setCompression(result.compression ?? null);
         setContent(result.content);
 
         // Try to parse JSON
@@ -272,7 +278,7 @@ function JsonViewer({
     return () => {
       mounted = false;
     };
-  }, [bucket, fileKey, fileSize]);
+  }, [bucket, fileKey, fileSize, versionId]);
 
   // Pretty-printed JSON for text view
   const prettyJson = useMemo(() => {
@@ -460,6 +466,11 @@ function JsonViewer({
           <span className="json-viewer-path" title={`s3://${bucket}/${fileKey}`}>
             s3://{bucket}/{fileKey}
           </span>
+          {compression && (
+            <span className="viewer-compression" title="Decompressed for viewing">
+              {compression}
+            </span>
+          )}
           <span className="json-viewer-status">
             {parsedJson !== null
               ? `${viewMode === 'tree' ? 'Tree view' : 'Text view'} - ${nodeStats.keys} keys, depth ${nodeStats.depth}`
@@ -0,0 +1,87 @@
+import React, { useState, useEffect, useRef } from 'react';
+
+interface MfaCodeRequest {
+  requestId: number;
+  mfaSerial: string;
+  profileName: string | null;
+}
+
+/**
+ * Asks for the code of an MFA device when the main process assumes a role from a
+ * profile with mfa_serial. Requests arrive from the main process one at a time,
+ * while the S3 request that needs the credentials waits for the answer.
+ */
+function MfaCodeDialog(): React.ReactElement | null {
+  const [request, setRequest] = useState<MfaCodeRequest | null>(null);
+  const [code, setCode] = useState('');
+  const inputRef = useRef<HTMLInputElement>(null);
+
+  useEffect(() => {
+    return window.electronAPI.aws.onMfaCodeRequest(incoming => {
+      setRequest(incoming);
+      setCode('');
+      setTimeout(() => inputRef.current?.focus(), 0);
+    });
+  }, []);
+
+  if (!request) {
+    return null;
+  }
+
+  const answer = (value: string | null) => {
+    window.electronAPI.aws.submitMfaCode(request.requestId, value);
+    setRequest(null);
+  };
+
+  const handleSubmit = (e: React.FormEvent) => {
+    e.preventDefault();
+    if (code.trim()) {
+      answer(code.trim());
+    }
+  };
+
+  const handleKeyDown = (e: React.KeyboardEvent) => {
+    if (e.key === 'Escape') {
+      answer(null);
+    }
+  };
+
+  return (
+    <div className="dialog-overlay" onKeyDown={handleKeyDown}>
+      <div className="dialog" role="dialog" aria-labelledby="mfa-code-title">
+        <div className="dialog-header">
+          <h3 id="mfa-code-title">MFA Code Required</h3>
+        </div>
+        <form onSubmit={handleSubmit}>
+          <div className="dialog-content">
+            <p>
+              {request.profileName ? <>Profile <strong>{request.profileName}</strong> assumes</> : 'Assuming'} a role
+              that requires the code from MFA device <strong>{request.mfaSerial}</strong>.
+            </p>
+            <label htmlFor="mfa-code-input">Code:</label>
+            <input
+              ref={inputRef}
+              id="mfa-code-input"
+              type="text"
+              inputMode="numeric"
+              autoComplete="one-time-code"
+              value={code}
+              onChange={(e) => setCode(e.target.value)}
+              className="dialog-input"
+            />
+          </div>
+          <div className="dialog-actions">
+            <button type="button" className="dialog-btn dialog-btn-cancel" onClick={() => answer(null)}>
+              Cancel
+            </button>
+            <button type="submit" className="dialog-btn dialog-btn-confirm" disabled={!code.trim()}>
+              Continue
+            </button>
+          </div>
+        </form>
+      </div>
+    </div>
+  );
+}
+
+export default MfaCodeDialog;
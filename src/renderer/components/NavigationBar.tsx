@@ -3,7 +3,11 @@ import React, { useState, useCallback, useRef, useEffect } from 'react';
 export interface NavigationBarProps {
   currentBucket: string | null;
   currentPrefix: string;
-  onNavigate: (bucket: string, prefix: string, selectKey?: string) => void;
+  /**
+   * Navigate to a bucket/prefix, optionally selecting one key or a set of keys.
+   * A URL naming an object version passes it along with the selected key.
+   */
+  onNavigate: (bucket: string, prefix: string, selectKey?: string | string[], versionId?: string) => void;
 }
 
 /**
@@ -12,6 +16,9 @@ export interface NavigationBarProps {
  * - s3://bucket/key
  * - https://bucket.s3.region.amazonaws.com/key
  * - https://s3.region.amazonaws.com/bucket/key
+ * An HTTPS URL's ?versionId= query selects that version of the object.
+ * Pasting several URLs (one per line) navigates to their common folder,
+ * selecting the objects when they all live directly in that folder.
  */
 function NavigationBar({
   currentBucket,
@@ -63,6 +70,33 @@ function NavigationBar({
     }, 150);
   }, [getDisplayPath]);
 
+  // Navigate to a single parsed bucket/key, selecting it if it looks like a file
+  const navigateToLocation = useCallback(
+    (bucket: string, key: string, versionId?: string) => {
+      // Determine if key is a file or prefix
+      // If it doesn't end with '/' and has content, it might be a file
+      const isLikelyFile = key && !key.endsWith('/') && key.length > 0;
+
+      if (isLikelyFile) {
+        // Navigate to the parent prefix and select the file
+        const lastSlash = key.lastIndexOf('/');
+        const prefix = lastSlash >= 0 ? key.substring(0, lastSlash + 1) : '';
+        if (versionId) {
+          onNavigate(bucket, prefix, key, versionId);
+        } else {
+          onNavigate(bucket, prefix, key);
+        }
+      } else {
+        // Navigate to the prefix (folder)
+        onNavigate(bucket, key || '');
+      }
+
+      setError(null);
+      setIsEditing(false);
+    },
+    [onNavigate]
+  );
+
   const handleNavigate = useCallback(async () => {
     const trimmedValue = inputValue.trim();
 
@@ -79,28 +113,66 @@ function NavigationBar({
         return;
       }
 
-      const { bucket, key } = result;
-
-      // Determine if key is a file or prefix
-      // If it doesn't end with '/' and has content, it might be a file
-      const isLikelyFile = key && !key.endsWith('/') && key.length > 0;
-
-      if (isLikelyFile) {
-        // Navigate to the parent prefix and select the file
-        const lastSlash = key.lastIndexOf('/');
-        const prefix = lastSlash >= 0 ? key.substring(0, lastSlash + 1) : '';
-        onNavigate(bucket, prefix, key);
-      } else {
-        // Navigate to the prefix (folder)
-        onNavigate(bucket, key || '');
-      }
-
-      setError(null);
-      setIsEditing(false);
+      navigateToLocation(result.bucket, result.key || '', result.versionId);
     } catch (err) {
       setError('Failed to parse S3 URL');
     }
-  }, [inputValue, onNavigate]);
+  }, [inputValue, navigateToLocation]);
+
+  // Navigate to a pasted block of URLs (one per line)
+  const navigateToUrlList = useCallback(
+    async (text: string) => {
+      try {
+        const result = await window.electronAPI.s3.parseUrlList(text);
+
+        if (!result.success || !result.urls || result.urls.length === 0) {
+          setError('No valid S3 URLs found in pasted text');
+          return;
+        }
+
+        if (result.urls.length === 1) {
+          navigateToLocation(result.urls[0].bucket, result.urls[0].key);
+          return;
+        }
+
+        if (!result.commonPrefix) {
+          setError('Pasted URLs span multiple buckets and have no common location');
+          return;
+        }
+
+        const { bucket, prefix } = result.commonPrefix;
+        // Select the objects only if they are all files directly inside the common folder
+        const allDirectChildren = result.urls.every(
+          url => url.key && !url.key.endsWith('/') && url.key.lastIndexOf('/') === prefix.length - 1
+        );
+
+        if (allDirectChildren) {
+          onNavigate(bucket, prefix, result.urls.map(url => url.key));
+        } else {
+          onNavigate(bucket, prefix);
+        }
+
+        setError(null);
+        setIsEditing(false);
+      } catch (err) {
+        setError('Failed to parse S3 URLs');
+      }
+    },
+    [onNavigate, navigateToLocation]
+  );
+
+  // Text inputs drop line breaks on paste, so intercept multi-line pastes
+  const handlePaste = useCallback(
+    (e: React.ClipboardEvent<HTMLInputElement>) => {
+      const text = e.clipboardData.getData('text');
+      if (!/\r?\n/.test(text.trim())) {
+        return;
+      }
+      e.preventDefault();
+      navigateToUrlList(text);
+    },
+    [navigateToUrlList]
+  );
 
   const handleKeyDown = useCallback(
     (e: React.KeyboardEvent<HTMLInputElement>) => {
@@ -135,6 +207,7 @@ function NavigationBar({
           onFocus={handleInputFocus}
           onBlur={handleInputBlur}
           onKeyDown={handleKeyDown}
+          onPaste={handlePaste}
           placeholder="Enter S3 URL (s3://bucket/path or https://...)"
           spellCheck={false}
           autoComplete="off"
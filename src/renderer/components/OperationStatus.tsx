@@ -2,18 +2,34 @@ import React from 'react';
 
 export interface Operation {
   id: string;
-  type: 'upload' | 'download';
+  type: 'upload' | 'download' | 'rename';
   fileName: string;
   status: 'pending' | 'in-progress' | 'completed' | 'error';
   error?: string;
+  // Informational detail for a completed operation (e.g. a skipped download)
+  note?: string;
+  // Whether a running operation can be cancelled
+  cancellable?: boolean;
+  // Progress reported while the transfer runs
+  loaded?: number;
+  total?: number;
+  speedBps?: number;
+  etaSeconds?: number | null;
 }
 
 export interface OperationStatusProps {
   operations: Operation[];
   onDismiss: (id: string) => void;
+  onCancel?: (id: string) => void;
 }
 
-function OperationStatus({ operations, onDismiss }: OperationStatusProps): React.ReactElement | null {
+const OPERATION_LABELS: Record<Operation['type'], string> = {
+  upload: 'Upload',
+  download: 'Download',
+  rename: 'Rename',
+};
+
+function OperationStatus({ operations, onDismiss, onCancel }: OperationStatusProps): React.ReactElement | null {
   if (operations.length === 0) {
     return null;
   }
@@ -49,11 +65,17 @@ function OperationStatus({ operations, onDismiss }: OperationStatusProps): React
       {operations.map((op) => (
         <div key={op.id} className={`operation-status-item ${getStatusClass(op.status)}`}>
           <span className="operation-icon">{getStatusIcon(op.status)}</span>
-          <span className="operation-type">{op.type === 'upload' ? 'Upload' : 'Download'}</span>
+          <span className="operation-type">{OPERATION_LABELS[op.type]}</span>
           <span className="operation-filename" title={op.fileName}>
             {op.fileName}
           </span>
+          {op.note && <span className="operation-note">{op.note}</span>}
           {op.error && <span className="operation-error">{op.error}</span>}
+          {op.status === 'in-progress' && op.cancellable && onCancel && (
+            <button className="operation-dismiss" onClick={() => onCancel(op.id)} title="Cancel">
+              Cancel
+            </button>
+          )}
           {(op.status === 'completed' || op.status === 'error') && (
             <button className="operation-dismiss" onClick={() => onDismiss(op.id)} title="Dismiss">
               x
@@ -1,23 +1,53 @@
-import React, { useState, useCallback, useEffect, useRef } from 'react';
-import { parquetMetadataAsync, parquetRead, parquetSchema } from 'hyparquet';
+import React, { useState, useCallback, useEffect, useMemo, useRef } from 'react';
+import { parquetMetadataAsync, parquetRead } from 'hyparquet';
+import { toCsv } from '../utils/csvExport';
+import { projectVisible } from '../utils/columnVisibility';
+import { computeHistogram } from '../utils/columnHistogram';
+import { columnValues } from '../utils/columnValues';
+import { loadBinaryContent } from '../utils/binaryContentCache';
+import {
+  parquetColumnStats,
+  parquetRowGroups,
+  parquetSchemaInfo,
+  readParquetSchema,
+  s3RangeAsyncBuffer,
+  toAsyncBuffer,
+  type LogicalAnnotation,
+  type ParquetColumnStats,
+  type ParquetRowGroupInfo,
+} from '../utils/parquetMetadata';
+import { useColumnVisibility } from '../hooks/useColumnVisibility';
+import { useColumnWidths } from '../hooks/useColumnWidths';
+import ColumnChooser from './ColumnChooser';
+import ColumnHistogram from './ColumnHistogram';
+import CsvFormatMenu, { DEFAULT_CSV_FORMAT, type CsvFormat } from './CsvFormatMenu';
+import CopyColumnMenu from './CopyColumnMenu';
 
 export interface ParquetViewerProps {
   bucket: string;
   fileKey: string;
   fileName: string;
   fileSize: number;
+  /** Version to show instead of the latest one */
+  versionId?: string;
   onClose: () => void;
 }
 
 interface ParquetColumn {
   name: string;
   type: string;
+  logicalType?: LogicalAnnotation;
 }
 
 interface ParquetData {
   columns: ParquetColumn[];
   rows: unknown[][];
   totalRows: number;
+  rowGroups: ParquetRowGroupInfo[];
+  /** Statistics recorded in the file, by column name */
+  stats: Record<string, ParquetColumnStats>;
+  /** Set when the file was too large to download, so only its footer schema was read */
+  schemaOnly?: boolean;
 }
 
 /**
@@ -35,17 +65,62 @@ const INITIAL_ROWS = 100;
  */
 const ROWS_PER_BATCH = 100;
 
+/**
+ * Decode a raw BYTE_ARRAY value as UTF-8 text
+ */
+function decodeBytes(value: Uint8Array): string {
+  return new TextDecoder().decode(value);
+}
+
+/**
+ * Format a value from a JSON/BSON/ENUM annotated column
+ * JSON may arrive already parsed, as a string, or as raw bytes depending on the reader
+ */
+function formatLogicalValue(value: unknown, logicalType: LogicalAnnotation, pretty: boolean): string {
+  if (logicalType === 'BSON') {
+    if (value instanceof Uint8Array) {
+      return `[BSON ${value.length} bytes]`;
+    }
+    return formatCellValue(value);
+  }
+
+  const text = value instanceof Uint8Array ? decodeBytes(value) : value;
+
+  if (logicalType === 'ENUM') {
+    return typeof text === 'string' ? text : formatCellValue(text);
+  }
+
+  // JSON: normalize to compact (cells) or pretty (tooltips) formatting
+  let parsed: unknown = text;
+  if (typeof text === 'string') {
+    try {
+      parsed = JSON.parse(text);
+    } catch {
+      // Not valid JSON, show the text as-is
+      return text;
+    }
+  }
+  try {
+    return pretty ? JSON.stringify(parsed, null, 2) : JSON.stringify(parsed);
+  } catch {
+    return formatCellValue(parsed);
+  }
+}
+
 /**
  * Format a value for display in the table
  * Shows null explicitly for better visibility, JSONifies complex types
  */
-function formatCellValue(value: unknown): string {
+function formatCellValue(value: unknown, logicalType?: LogicalAnnotation, pretty = false): string {
   if (value === null) {
     return 'null';
   }
   if (value === undefined) {
     return '';
   }
+  if (logicalType) {
+    return formatLogicalValue(value, logicalType, pretty);
+  }
   if (typeof value === 'bigint') {
     return value.toString();
   }
@@ -72,6 +147,27 @@ function formatCellValue(value: unknown): string {
   return String(value);
 }
 
+/**
+ * Header tooltip of a column: its name and type, plus the statistics the file
+ * records for it, formatted like the column's cells
+ */
+function columnTooltip(column: ParquetColumn, stats: ParquetColumnStats | undefined): string {
+  const lines = [`${column.name} (${column.type})`];
+  if (stats) {
+    if (stats.min !== null && stats.max !== null) {
+      lines.push(`Min: ${formatCellValue(stats.min, column.logicalType)}`);
+      lines.push(`Max: ${formatCellValue(stats.max, column.logicalType)}`);
+    }
+    if (stats.nullCount !== null) {
+      lines.push(`Nulls: ${stats.nullCount.toLocaleString()}`);
+    }
+    if (stats.distinctCount !== null) {
+      lines.push(`Distinct: ${stats.distinctCount.toLocaleString()}`);
+    }
+  }
+  return lines.join('\n');
+}
+
 /**
  * Infer column type from values
  */
@@ -104,6 +200,7 @@ function ParquetViewer({
   fileKey,
   fileName,
   fileSize,
+  versionId,
   onClose,
 }: ParquetViewerProps): React.ReactElement {
   const [loading, setLoading] = useState(true);
@@ -112,10 +209,22 @@ function ParquetViewer({
   const [displayedRows, setDisplayedRows] = useState<unknown[][]>([]);
   const [loadingMore, setLoadingMore] = useState(false);
   const [searchTerm, setSearchTerm] = useState('');
+  // Neutralize formula-like cells in exports, since they usually end up in a spreadsheet
+  const [spreadsheetSafe, setSpreadsheetSafe] = useState(true);
+  const [csvFormat, setCsvFormat] = useState<CsvFormat>(DEFAULT_CSV_FORMAT);
+  const [exportStatus, setExportStatus] = useState<string | null>(null);
+  const [showProfile, setShowProfile] = useState(false);
+  // Profile every row of the file instead of only the rows loaded into the table
+  const [profileAllRows, setProfileAllRows] = useState(false);
 
   const allRowsRef = useRef<unknown[][]>([]);
   const tableContainerRef = useRef<HTMLDivElement>(null);
 
+  const columnNames = useMemo(() => data?.columns.map(c => c.name) ?? [], [data]);
+  const { visible, setColumnVisible, showAllColumns } = useColumnVisibility(bucket, fileKey, columnNames);
+  const schemaColumns = useMemo(() => data?.columns ?? [], [data]);
+  const { widths, resizeColumn, saveColumnWidths } = useColumnWidths(bucket, fileKey, schemaColumns);
+
   // Load parquet file on mount
   useEffect(() => {
     let mounted = true;
@@ -125,15 +234,29 @@ function ParquetViewer({
       setError(null);
 
       try {
-        // Check file size first
+        // Too large to download whole: read just the footer for the columns and row count
         if (fileSize > MAX_PARQUET_SIZE) {
-          throw new Error(
-            `File is too large to preview (${formatSize(fileSize)}). Maximum size is ${formatSize(MAX_PARQUET_SIZE)}.`
-          );
+          const schema = await readParquetSchema(s3RangeAsyncBuffer(bucket, fileKey, fileSize, versionId));
+          if (!mounted) return;
+          allRowsRef.current = [];
+          setData({
+            columns: schema.columns.map(column => ({
+              name: column.name,
+              type: (column.logicalType ?? column.physicalType).toLowerCase(),
+              logicalType: column.logicalType,
+            })),
+            rows: [],
+            totalRows: schema.totalRows,
+            rowGroups: [],
+            stats: {},
+            schemaOnly: true,
+          });
+          setDisplayedRows([]);
+          return;
         }
 
-        // Download the file as binary
-        const result = await window.electronAPI.s3.downloadBinaryContent(bucket, fileKey);
+        // Download the file as binary, or reuse the copy from an earlier open if it is unchanged
+        const result = await loadBinaryContent(bucket, fileKey, undefined, versionId);
         if (!result.success) {
           throw new Error(result.error || 'Failed to download file');
         }
@@ -142,32 +265,16 @@ function ParquetViewer({
           throw new Error('Empty file content');
         }
 
-        // Create a fresh ArrayBuffer from the data for hyparquet
-        // IMPORTANT: Electron IPC transfers Uint8Array data but the underlying buffer
-        // may not be directly usable for DataView operations. We explicitly create
-        // a new ArrayBuffer and copy the data to ensure it works correctly with hyparquet.
-        const dataLength = result.data.length;
-        const arrayBuffer = new ArrayBuffer(dataLength);
-        const uint8Data = new Uint8Array(arrayBuffer);
-        uint8Data.set(result.data);
-
-        // Create an AsyncBuffer wrapper for hyparquet
-        // hyparquet expects an object with byteLength and slice that returns Promise<ArrayBuffer>
-        // IMPORTANT: hyparquet's slice callback expects ArrayBuffer, not Uint8Array
-        // Using Uint8Array.slice() returns a Uint8Array which causes DataView errors
-        const asyncBuffer = {
-          byteLength: arrayBuffer.byteLength,
-          slice: (start: number, end?: number) => Promise.resolve(arrayBuffer.slice(start, end)),
-        };
+        const asyncBuffer = toAsyncBuffer(result.data);
 
         // Get metadata to understand schema structure
         const metadata = await parquetMetadataAsync(asyncBuffer);
 
-        // Get top-level column names from the schema tree
-        // parquetSchema returns a tree structure where children are the top-level columns
-        // This correctly handles nested types (arrays, structs) by returning only the parent column names
-        const schemaTree = parquetSchema(metadata);
-        const columnNames = schemaTree.children.map(c => c.element.name);
+        // Top-level column names, so nested types (arrays, structs) show under their parent column
+        const schema = parquetSchemaInfo(metadata);
+        const columnNames = schema.columns.map(c => c.name);
+        // Logical annotations come from the schema, not from the decoded values
+        const logicalTypes = schema.columns.map(c => c.logicalType);
 
         // Read all data from parquet file using rowFormat: 'object'
         // This returns an array of row objects with column names as keys
@@ -189,16 +296,24 @@ function ParquetViewer({
         if (!mounted) return;
 
         // Build columns with inferred types
-        const columns: ParquetColumn[] = columnNames.map((name, idx) => ({
-          name,
-          type: inferColumnType(rows.slice(0, 100).map(r => r[idx])),
-        }));
+        const columns: ParquetColumn[] = columnNames.map((name, idx) => {
+          const logicalType = logicalTypes[idx];
+          return {
+            name,
+            type: logicalType
+              ? logicalType.toLowerCase()
+              : inferColumnType(rows.slice(0, 100).map(r => r[idx])),
+            logicalType,
+          };
+        });
 
         allRowsRef.current = rows;
         setData({
           columns,
           rows,
           totalRows: rows.length,
+          rowGroups: parquetRowGroups(metadata),
+          stats: Object.fromEntries(parquetColumnStats(metadata).map(stats => [stats.name, stats])),
         });
         setDisplayedRows(rows.slice(0, INITIAL_ROWS));
       } catch (err) {
@@ -217,7 +332,7 @@ function ParquetViewer({
     return () => {
       mounted = false;
     };
-  }, [bucket, fileKey, fileSize]);
+  }, [bucket, fileKey, fileSize, versionId]);
 
   // Handle scroll for lazy loading
   const handleScroll = useCallback(() => {
@@ -242,15 +357,95 @@ function ParquetViewer({
     }
   }, [loadingMore, data, displayedRows.length]);
 
+  // Only the visible columns are rendered, searched and exported
+  const shown = useMemo(
+    () => projectVisible(data?.columns ?? [], displayedRows, visible),
+    [data, displayedRows, visible]
+  );
+
+  // Drag a header's right edge to resize its column; the width is saved on release
+  const handleResizeStart = useCallback(
+    (e: React.MouseEvent<HTMLElement>, name: string) => {
+      e.preventDefault();
+      e.stopPropagation();
+      const header = e.currentTarget.parentElement;
+      if (!header) return;
+      const startX = e.clientX;
+      const startWidth = header.getBoundingClientRect().width;
+
+      const onMouseMove = (moveEvent: MouseEvent) => {
+        resizeColumn(name, startWidth + moveEvent.clientX - startX);
+      };
+      const onMouseUp = () => {
+        window.removeEventListener('mousemove', onMouseMove);
+        window.removeEventListener('mouseup', onMouseUp);
+        saveColumnWidths();
+      };
+      window.addEventListener('mousemove', onMouseMove);
+      window.addEventListener('mouseup', onMouseUp);
+    },
+    [resizeColumn, saveColumnWidths]
+  );
+
+  // One histogram per visible column, over the loaded rows or the whole file
+  const histograms = useMemo(() => {
+    if (!showProfile || !data) return [];
+    const source = profileAllRows ? allRowsRef.current : displayedRows;
+    return data.columns
+      .map((column, idx) => ({ column, idx }))
+      .filter(({ idx }) => visible[idx] !== false)
+      .map(({ column, idx }) =>
+        computeHistogram(source.map(row => row[idx]), {
+          formatValue: value => formatCellValue(value, column.logicalType),
+        })
+      );
+  }, [showProfile, profileAllRows, data, displayedRows, visible]);
+
   // Filter rows based on search term
   const filteredRows = searchTerm
-    ? displayedRows.filter(row =>
-        row.some(cell => {
-          const cellStr = formatCellValue(cell).toLowerCase();
+    ? shown.rows.filter(row =>
+        row.some((cell, cellIdx) => {
+          const cellStr = formatCellValue(cell, shown.columns[cellIdx]?.logicalType).toLowerCase();
           return cellStr.includes(searchTerm.toLowerCase());
         })
       )
-    : displayedRows;
+    : shown.rows;
+
+  const handleExportCsv = useCallback(async () => {
+    if (!data) return;
+    setExportStatus(null);
+
+    // Export every row, not just the ones scrolled into view, but only the visible columns
+    const { columns, rows: cells } = projectVisible(data.columns, allRowsRef.current, visible);
+    const headers = columns.map(c => c.name);
+    // Nulls become empty cells
+    const rows = cells.map(row =>
+      row.map((cell, idx) => (cell === null ? '' : formatCellValue(cell, columns[idx]?.logicalType)))
+    );
+    const csv = toCsv(headers, rows, { ...csvFormat, safetyMode: spreadsheetSafe ? 'apostrophe' : 'none' });
+    const defaultName = fileName.replace(/\.parquet$/i, '') + '.csv';
+
+    const result = await window.electronAPI.s3.saveLocalFile(defaultName, csv);
+    if (result.success) {
+      const columnNote = columns.length < data.columns.length
+        ? ` (${columns.length} of ${data.columns.length} columns)`
+        : '';
+      setExportStatus(`Exported ${rows.length.toLocaleString()} rows${columnNote} to ${result.filePath}`);
+    } else if (!result.cancelled) {
+      setError(result.error || 'Failed to export CSV');
+    }
+  }, [data, fileName, spreadsheetSafe, csvFormat, visible]);
+
+  // Values of a visible column, formatted like exported cells
+  const getColumnValues = useCallback(
+    (columnIndex: number, allRows: boolean) => {
+      if (!data) return [];
+      const { columns, rows } = projectVisible(data.columns, allRows ? allRowsRef.current : displayedRows, visible);
+      const logicalType = columns[columnIndex]?.logicalType;
+      return columnValues(rows, columnIndex, cell => (cell === null ? '' : formatCellValue(cell, logicalType)));
+    },
+    [data, displayedRows, visible]
+  );
 
   const handleKeyDown = useCallback(
     (e: React.KeyboardEvent) => {
@@ -279,13 +474,70 @@ function ParquetViewer({
                   {data.totalRows.toLocaleString()} rows
                 </span>
                 <span className="parquet-viewer-meta-item">
-                  {data.columns.length} columns
+                  {shown.columns.length < data.columns.length
+                    ? `${shown.columns.length} of ${data.columns.length} columns`
+                    : `${data.columns.length} columns`}
                 </span>
               </>
             )}
             <span className="parquet-viewer-meta-item">{formatSize(fileSize)}</span>
           </div>
           <div className="parquet-viewer-actions">
+            {data && !data.schemaOnly && (
+              <>
+                {showProfile && displayedRows.length < data.totalRows && (
+                  <label
+                    className="parquet-viewer-export-option"
+                    title="Profile every row of the file, not just the rows loaded so far"
+                  >
+                    <input
+                      type="checkbox"
+                      checked={profileAllRows}
+                      onChange={(e) => setProfileAllRows(e.target.checked)}
+                    />
+                    All rows
+                  </label>
+                )}
+                <button
+                  className="parquet-viewer-btn"
+                  onClick={() => setShowProfile(show => !show)}
+                  aria-pressed={showProfile}
+                  title="Show a histogram of each column's values"
+                >
+                  Profile
+                </button>
+                <ColumnChooser
+                  columnNames={columnNames}
+                  visible={visible}
+                  onToggle={setColumnVisible}
+                  onShowAll={showAllColumns}
+                />
+                <CopyColumnMenu
+                  columnNames={shown.columns.map(c => c.name)}
+                  getValues={getColumnValues}
+                  hasMoreRows={displayedRows.length < data.totalRows}
+                />
+                <label
+                  className="parquet-viewer-export-option"
+                  title="Prefix cells starting with =, +, - or @ so spreadsheets don't run them as formulas"
+                >
+                  <input
+                    type="checkbox"
+                    checked={spreadsheetSafe}
+                    onChange={(e) => setSpreadsheetSafe(e.target.checked)}
+                  />
+                  Spreadsheet-safe
+                </label>
+                <CsvFormatMenu format={csvFormat} onChange={setCsvFormat} />
+                <button
+                  className="parquet-viewer-btn"
+                  onClick={handleExportCsv}
+                  title="Export all rows of the visible columns as CSV"
+                >
+                  Export CSV
+                </button>
+              </>
+            )}
             <button
               className="parquet-viewer-btn parquet-viewer-btn-close"
               onClick={onClose}
@@ -297,7 +549,7 @@ function ParquetViewer({
         </div>
 
         {/* Search bar */}
-        {data && (
+        {data && !data.schemaOnly && (
           <div className="parquet-viewer-search">
             <input
               type="text"
@@ -345,36 +597,74 @@ function ParquetViewer({
                 <thead>
                   <tr>
                     <th className="parquet-col-index">#</th>
-                    {data.columns.map((col, idx) => (
-                      <th key={idx} title={`${col.name} (${col.type})`}>
+                    {shown.columns.map((col, idx) => (
+                      <th
+                        key={idx}
+                        title={columnTooltip(col, data.stats[col.name])}
+                        style={widths[col.name] ? { width: widths[col.name], minWidth: widths[col.name] } : undefined}
+                      >
                         <div className="parquet-col-header">
                           <span className="parquet-col-name">{col.name}</span>
                           <span className="parquet-col-type">{col.type}</span>
                         </div>
+                        <span
+                          className="parquet-col-resizer"
+                          onMouseDown={(e) => handleResizeStart(e, col.name)}
+                          title="Drag to resize"
+                        />
                       </th>
                     ))}
                   </tr>
+                  {showProfile && (
+                    <tr className="parquet-histogram-row">
+                      <th className="parquet-col-index"></th>
+                      {histograms.map((histogram, idx) => (
+                        <th key={idx}>
+                          <ColumnHistogram histogram={histogram} />
+                        </th>
+                      ))}
+                    </tr>
+                  )}
                 </thead>
                 <tbody>
                   {filteredRows.map((row, rowIdx) => (
                     <tr key={rowIdx}>
                       <td className="parquet-col-index">{rowIdx + 1}</td>
                       {row.map((cell, cellIdx) => (
-                        <td key={cellIdx} title={formatCellValue(cell)}>
-                          {formatCellValue(cell)}
+                        <td
+                          key={cellIdx}
+                          title={formatCellValue(cell, shown.columns[cellIdx]?.logicalType, true)}
+                          style={
+                            widths[shown.columns[cellIdx]?.name]
+                              ? { maxWidth: widths[shown.columns[cellIdx].name] }
+                              : undefined
+                          }
+                        >
+                          {formatCellValue(cell, shown.columns[cellIdx]?.logicalType)}
                         </td>
                       ))}
                     </tr>
                   ))}
                 </tbody>
               </table>
+              {shown.columns.length === 0 && data.columns.length > 0 && (
+                <div className="parquet-viewer-has-more">
+                  All columns are hidden
+                </div>
+              )}
               {loadingMore && (
                 <div className="parquet-viewer-loading-more">
                   <span className="loading-spinner small"></span>
                   <span>Loading more rows...</span>
                 </div>
               )}
-              {displayedRows.length < data.totalRows && !loadingMore && (
+              {data.schemaOnly && (
+                <div className="parquet-viewer-has-more">
+                  File is too large to preview rows ({formatSize(fileSize)}). Maximum size is {formatSize(MAX_PARQUET_SIZE)};
+                  showing the schema read from its footer.
+                </div>
+              )}
+              {!data.schemaOnly && displayedRows.length < data.totalRows && !loadingMore && (
                 <div className="parquet-viewer-has-more">
                   Showing {displayedRows.length.toLocaleString()} of {data.totalRows.toLocaleString()} rows
                 </div>
@@ -389,10 +679,26 @@ function ParquetViewer({
             s3://{bucket}/{fileKey}
           </span>
           <span className="parquet-viewer-status">
-            {data
-              ? `Loaded ${displayedRows.length.toLocaleString()} of ${data.totalRows.toLocaleString()} rows`
-              : 'Loading...'}
+            {exportStatus
+              ?? (data?.schemaOnly
+                ? 'Schema only'
+                : data
+                ? `Loaded ${displayedRows.length.toLocaleString()} of ${data.totalRows.toLocaleString()} rows`
+                : 'Loading...')}
           </span>
+          {data && data.rowGroups.length > 0 && (
+            <span
+              className="parquet-viewer-row-groups"
+              title={data.rowGroups
+                .map((group, idx) =>
+                  `Row group ${idx + 1}: ${group.numRows.toLocaleString()} rows, ` +
+                  `${formatSize(group.compressedSize)} stored, ${formatSize(group.totalByteSize)} uncompressed`
+                )
+                .join('\n')}
+            >
+              {data.rowGroups.length === 1 ? '1 row group' : `${data.rowGroups.length} row groups`}
+            </span>
+          )}
         </div>
       </div>
     </div>
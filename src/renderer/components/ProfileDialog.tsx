@@ -0,0 +1,204 @@
+import React, { useState, useEffect, useRef } from 'react';
+
+/**
+ * Config file settings of a profile, as written to ~/.aws/config
+ */
+export interface ProfileConfig {
+  name: string;
+  region?: string;
+  roleArn?: string;
+  sourceProfile?: string;
+  mfaSerial?: string;
+  ssoStartUrl?: string;
+  ssoRegion?: string;
+  ssoAccountId?: string;
+  ssoRoleName?: string;
+  credentialProcess?: string;
+  // Not offered in the dialog, but kept when editing a profile that sets them
+  output?: string;
+  credentialSource?: string;
+  ssoSession?: string;
+  webIdentityTokenFile?: string;
+}
+
+type ProfileField =
+  | 'region'
+  | 'roleArn'
+  | 'sourceProfile'
+  | 'mfaSerial'
+  | 'ssoStartUrl'
+  | 'ssoRegion'
+  | 'ssoAccountId'
+  | 'ssoRoleName'
+  | 'credentialProcess';
+
+/**
+ * Settings offered in the dialog, with the config file key shown as a placeholder
+ */
+export const PROFILE_FIELDS: { field: ProfileField; label: string; placeholder: string }[] = [
+  { field: 'region', label: 'Region', placeholder: 'region, e.g. eu-west-1' },
+  { field: 'roleArn', label: 'Role ARN', placeholder: 'role_arn' },
+  { field: 'sourceProfile', label: 'Source profile', placeholder: 'source_profile' },
+  { field: 'mfaSerial', label: 'MFA device', placeholder: 'mfa_serial' },
+  { field: 'ssoStartUrl', label: 'SSO start URL', placeholder: 'sso_start_url' },
+  { field: 'ssoRegion', label: 'SSO region', placeholder: 'sso_region' },
+  { field: 'ssoAccountId', label: 'SSO account ID', placeholder: 'sso_account_id' },
+  { field: 'ssoRoleName', label: 'SSO role name', placeholder: 'sso_role_name' },
+  { field: 'credentialProcess', label: 'Credential process', placeholder: 'credential_process' },
+];
+
+export interface ProfileDialogProps {
+  isOpen: boolean;
+  /** The profile being edited; a new profile is created when not set */
+  initial?: ProfileConfig | null;
+  /** Settings a new profile starts with, e.g. from an SSO account picked from a list */
+  defaults?: ProfileConfig | null;
+  /**
+   * Saves the profile. Resolves to an error message to show in the dialog,
+   * or null once the profile has been written.
+   */
+  onSave: (config: ProfileConfig) => Promise<string | null>;
+  onCancel: () => void;
+  /**
+   * Removes the profile being edited. Resolves to an error message to show in
+   * the dialog, or null once removed. Not offered for the default profile.
+   */
+  onDelete?: (name: string) => Promise<string | null>;
+}
+
+/**
+ * Creates or edits a profile in ~/.aws/config. Access keys aren't asked for:
+ * they belong in the credentials file, which the app doesn't write.
+ */
+function ProfileDialog({
+  isOpen,
+  initial,
+  defaults,
+  onSave,
+  onCancel,
+  onDelete,
+}: ProfileDialogProps): React.ReactElement | null {
+  const [config, setConfig] = useState<ProfileConfig>({ name: '' });
+  const [error, setError] = useState<string | null>(null);
+  const [saving, setSaving] = useState(false);
+  const [confirmDelete, setConfirmDelete] = useState(false);
+  const inputRef = useRef<HTMLInputElement>(null);
+
+  useEffect(() => {
+    if (isOpen) {
+      setConfig({ name: '', ...(initial ?? defaults) });
+      setError(null);
+      setSaving(false);
+      setConfirmDelete(false);
+      setTimeout(() => inputRef.current?.focus(), 0);
+    }
+  }, [isOpen, initial, defaults]);
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!config.name.trim() || saving) return;
+
+    setSaving(true);
+    setError(null);
+    const trimmed: ProfileConfig = { ...(initial ?? defaults), name: config.name.trim() };
+    for (const { field } of PROFILE_FIELDS) {
+      const value = config[field]?.trim();
+      if (value) {
+        trimmed[field] = value;
+      } else {
+        delete trimmed[field];
+      }
+    }
+    const failure = await onSave(trimmed);
+    setSaving(false);
+    if (failure) {
+      setError(failure);
+    }
+  };
+
+  const handleDelete = async () => {
+    if (!initial || !onDelete || saving) return;
+    if (!confirmDelete) {
+      setConfirmDelete(true);
+      return;
+    }
+    setSaving(true);
+    setError(null);
+    const failure = await onDelete(initial.name);
+    setSaving(false);
+    setConfirmDelete(false);
+    if (failure) {
+      setError(failure);
+    }
+  };
+
+  const handleKeyDown = (e: React.KeyboardEvent) => {
+    if (e.key === 'Escape') {
+      onCancel();
+    }
+  };
+
+  if (!isOpen) {
+    return null;
+  }
+
+  return (
+    <div className="dialog-overlay" onClick={onCancel}>
+      <div className="dialog dialog-profile" onClick={(e) => e.stopPropagation()} onKeyDown={handleKeyDown}>
+        <div className="dialog-header">
+          <h3>{initial ? 'Edit Profile' : 'New Profile'}</h3>
+        </div>
+        <form onSubmit={handleSubmit}>
+          <div className="dialog-content">
+            <div className="dialog-field">
+              <label htmlFor="profile-name">Profile name:</label>
+              <input
+                ref={inputRef}
+                id="profile-name"
+                type="text"
+                value={config.name}
+                onChange={(e) => setConfig({ ...config, name: e.target.value })}
+                className="dialog-input"
+                spellCheck={false}
+              />
+            </div>
+            {PROFILE_FIELDS.map(({ field, label, placeholder }) => (
+              <div className="dialog-field" key={field}>
+                <label htmlFor={`profile-${field}`}>{label}:</label>
+                <input
+                  id={`profile-${field}`}
+                  type="text"
+                  value={config[field] ?? ''}
+                  onChange={(e) => setConfig({ ...config, [field]: e.target.value })}
+                  className="dialog-input"
+                  placeholder={placeholder}
+                  spellCheck={false}
+                />
+              </div>
+            ))}
+            {error && (
+              <p className="dialog-warning" role="alert">
+                {error}
+              </p>
+            )}
+          </div>
+          <div className="dialog-actions">
+            {initial && onDelete && initial.name !== 'default' && (
+              <button type="button" className="dialog-btn dialog-btn-danger" onClick={handleDelete} disabled={saving}>
+                {confirmDelete ? `Confirm: delete ${initial.name}` : 'Delete'}
+              </button>
+            )}
+            <button type="button" className="dialog-btn dialog-btn-cancel" onClick={onCancel}>
+              Cancel
+            </button>
+            <button type="submit" className="dialog-btn dialog-btn-confirm" disabled={!config.name.trim() || saving}>
+              {saving ? 'Saving...' : 'Save'}
+            </button>
+          </div>
+        </form>
+      </div>
+    </div>
+  );
+}
+
+export default ProfileDialog;
@@ -1,8 +1,29 @@
-import React from 'react';
+import React, { useState, useEffect } from 'react';
 import { useAwsProfiles, type ProfileInfo } from '../context/AwsProfileContext';
+import ProfileDialog, { type ProfileConfig } from './ProfileDialog';
+import SsoRolePicker, { type SsoRole } from './SsoRolePicker';
 
 function ProfileSelector(): React.ReactElement {
   const { profiles, currentProfile, loading, error, selectProfile, refreshProfiles } = useAwsProfiles();
+  const [reportCopied, setReportCopied] = useState(false);
+  const [envCopied, setEnvCopied] = useState(false);
+  const [envError, setEnvError] = useState<string | null>(null);
+  const [ssoLoggingIn, setSsoLoggingIn] = useState(false);
+  const [ssoCode, setSsoCode] = useState<{ userCode: string; verificationUri: string } | null>(null);
+  const [ssoError, setSsoError] = useState<string | null>(null);
+  const [isProfileDialogOpen, setIsProfileDialogOpen] = useState(false);
+  const [editedProfile, setEditedProfile] = useState<ProfileConfig | null>(null);
+  const [profileDefaults, setProfileDefaults] = useState<ProfileConfig | null>(null);
+  const [isSsoRolePickerOpen, setIsSsoRolePickerOpen] = useState(false);
+  const [editError, setEditError] = useState<string | null>(null);
+
+  const current = profiles.find(p => p.name === currentProfile);
+
+  useEffect(() => {
+    return window.electronAPI.aws.onSsoAuthorization(({ userCode, verificationUri }) => {
+      setSsoCode({ userCode, verificationUri });
+    });
+  }, []);
 
   const handleProfileChange = (event: React.ChangeEvent<HTMLSelectElement>) => {
     const profileName = event.target.value;
@@ -11,6 +32,114 @@ function ProfileSelector(): React.ReactElement {
     }
   };
 
+  const handleCopyDiagnostics = async () => {
+    try {
+      const report = await window.electronAPI.aws.getDiagnosticReport();
+      await navigator.clipboard.writeText(report);
+      setReportCopied(true);
+      setTimeout(() => setReportCopied(false), 2000);
+    } catch (err) {
+      console.error('Failed to copy profile diagnostics:', err);
+    }
+  };
+
+  const handleCopyEnv = async () => {
+    if (!currentProfile) return;
+    setEnvError(null);
+    const result = await window.electronAPI.aws.exportEnv(currentProfile);
+    if (!result.success || result.text === undefined) {
+      setEnvError(result.error ?? 'Failed to resolve credentials');
+      return;
+    }
+    await navigator.clipboard.writeText(result.text);
+    setEnvCopied(true);
+    setTimeout(() => setEnvCopied(false), 2000);
+  };
+
+  const openNewProfile = () => {
+    setEditedProfile(null);
+    setProfileDefaults(null);
+    setIsProfileDialogOpen(true);
+  };
+
+  // Start a new profile for the picked account and role, signing in the same way
+  // as the current profile
+  const handlePickSsoRole = async (role: SsoRole) => {
+    setIsSsoRolePickerOpen(false);
+    const source = currentProfile ? await window.electronAPI.aws.getProfileConfig(currentProfile) : null;
+    const name = `${role.accountName ?? role.accountId}-${role.roleName}`.replace(/[\s\[\]]+/g, '-');
+    setEditedProfile(null);
+    setProfileDefaults({
+      name,
+      region: source?.region,
+      ...(source?.ssoSession
+        ? { ssoSession: source.ssoSession }
+        : { ssoStartUrl: source?.ssoStartUrl, ssoRegion: source?.ssoRegion }),
+      ssoAccountId: role.accountId,
+      ssoRoleName: role.roleName,
+    });
+    setIsProfileDialogOpen(true);
+  };
+
+  const openEditProfile = async () => {
+    if (!currentProfile) return;
+    setEditError(null);
+    const config = await window.electronAPI.aws.getProfileConfig(currentProfile);
+    if (!config) {
+      setEditError(`Profile '${currentProfile}' is not defined in ~/.aws/config`);
+      return;
+    }
+    setEditedProfile(config);
+    setIsProfileDialogOpen(true);
+  };
+
+  const handleSaveProfile = async (config: ProfileConfig): Promise<string | null> => {
+    const result = editedProfile
+      ? await window.electronAPI.aws.updateProfile(editedProfile.name, config)
+      : await window.electronAPI.aws.addProfile(config);
+    if (!result.success) {
+      return result.error ?? 'Failed to save the profile';
+    }
+    setIsProfileDialogOpen(false);
+    await refreshProfiles();
+    return null;
+  };
+
+  const handleDeleteProfile = async (name: string): Promise<string | null> => {
+    const result = await window.electronAPI.aws.removeProfile(name);
+    if (!result.success) {
+      return result.error ?? 'Failed to delete the profile';
+    }
+    setIsProfileDialogOpen(false);
+    await refreshProfiles();
+    return null;
+  };
+
+  const handleSsoLogin = async () => {
+    if (!currentProfile) return;
+    setSsoLoggingIn(true);
+    setSsoError(null);
+    setSsoCode(null);
+    try {
+      const result = await window.electronAPI.aws.ssoLogin(currentProfile);
+      if (result.success) {
+        // Reselecting drops the S3 client, so the next request picks up the new token
+        await selectProfile(currentProfile);
+        await refreshProfiles();
+      } else {
+        setSsoError(result.error ?? 'SSO login failed');
+      }
+    } finally {
+      setSsoLoggingIn(false);
+      setSsoCode(null);
+    }
+  };
+
+  // Prompt for a sign-in before requests start failing on a missing or expired token
+  const ssoState = current?.ssoTokenStatus?.state;
+  const ssoSignInNeeded = ssoState === 'expired' || ssoState === 'missing';
+  const ssoLoginLabel = ssoState === 'expired' ? 'SSO expired: sign in' : ssoState === 'missing' ? 'SSO sign in' : 'SSO login';
+
   if (loading) {
     return (
       <div className="profile-selector">
@@ -30,20 +159,29 @@ function ProfileSelector(): React.ReactElement {
         disabled={profiles.length === 0}
       >
         <option value="">Select a profile</option>
-        {profiles.map((profile: ProfileInfo) => (
-          <option
-            key={profile.name}
-            value={profile.name}
-            disabled={!profile.isValid}
-            title={profile.isValid
-              ? `${profile.profileTypeDescription}${profile.region ? ` - ${profile.region}` : ''}`
-              : profile.validationMessage}
-          >
-            {profile.name}
-            {profile.region ? ` (${profile.region})` : ''}
-            {profile.isValid ? ` [${profile.profileTypeDescription}]` : ' [invalid]'}
-          </option>
-        ))}
+        {profiles.map((profile: ProfileInfo) => {
+          // AWS_REGION overrides the profile's region setting, and profiles without one use the default
+          const region = profile.resolvedRegion ?? profile.region;
+          const regionNote = !profile.region && region
+            ? ' (not set in the profile)'
+            : region !== profile.region
+              ? ' (set by the environment)'
+              : '';
+          return (
+            <option
+              key={profile.name}
+              value={profile.name}
+              disabled={!profile.isValid}
+              title={profile.isValid
+                ? `${profile.profileTypeDescription}${region ? ` - ${region}${regionNote}` : ''}`
+                : profile.validationMessage}
+            >
+              {profile.name}
+              {region ? ` (${region})` : ''}
+              {profile.isValid ? ` [${profile.profileTypeDescription}]` : ' [invalid]'}
+            </option>
+          );
+        })}
       </select>
       <button
         className="profile-refresh-btn"
@@ -53,12 +191,101 @@ function ProfileSelector(): React.ReactElement {
       >
         ↻
       </button>
+      <button
+        className="profile-refresh-btn"
+        onClick={openNewProfile}
+        title="Add a profile to ~/.aws/config"
+        aria-label="New profile"
+      >
+        +
+      </button>
+      <button
+        className="profile-refresh-btn"
+        onClick={openEditProfile}
+        disabled={!currentProfile || current?.profileType === 'anonymous'}
+        title="Edit the selected profile in ~/.aws/config"
+        aria-label="Edit profile"
+      >
+        ✎
+      </button>
+      <button
+        className="profile-refresh-btn"
+        onClick={handleCopyEnv}
+        disabled={!currentProfile || current?.profileType === 'anonymous'}
+        title="Copy the selected profile's credentials as environment variables for a terminal"
+        aria-label="Copy credentials as environment variables"
+      >
+        {envCopied ? '✓' : '$'}
+      </button>
+      <button
+        className="profile-refresh-btn profile-diagnostics-btn"
+        onClick={handleCopyDiagnostics}
+        title="Copy a profile diagnostic report (no secrets) to the clipboard"
+        aria-label="Copy profile diagnostics"
+      >
+        {reportCopied ? '✓' : 'ⓘ'}
+      </button>
+      {current?.profileType === 'sso' && (
+        ssoLoggingIn ? (
+          <>
+            <span className="profile-sso-code" role="status">
+              {ssoCode
+                ? <>Enter code <strong>{ssoCode.userCode}</strong> at {ssoCode.verificationUri}</>
+                : 'Starting SSO login...'}
+            </span>
+            <button
+              className="profile-refresh-btn"
+              onClick={() => window.electronAPI.aws.cancelSsoLogin()}
+              title="Cancel the SSO login"
+            >
+              Cancel
+            </button>
+          </>
+        ) : (
+          <>
+            <button
+              className={`profile-refresh-btn profile-sso-login-btn${ssoSignInNeeded ? ' needs-login' : ''}`}
+              onClick={handleSsoLogin}
+              title="Sign in to AWS SSO in the browser"
+            >
+              {ssoLoginLabel}
+            </button>
+            <button
+              className="profile-refresh-btn"
+              onClick={() => setIsSsoRolePickerOpen(true)}
+              disabled={ssoSignInNeeded}
+              title={ssoSignInNeeded
+                ? 'Sign in to SSO to list the accounts and roles it gives access to'
+                : 'List the accounts and roles this SSO sign-in gives access to'}
+            >
+              Accounts
+            </button>
+          </>
+        )
+      )}
+      {ssoError && <span className="profile-error">{ssoError}</span>}
+      {editError && <span className="profile-error">{editError}</span>}
+      {envError && <span className="profile-error">{envError}</span>}
       {error && <span className="profile-error">{error}</span>}
       {profiles.length === 0 && !error && (
         <span className="profile-hint">
           No profiles found. Configure ~/.aws/credentials
         </span>
       )}
+      <ProfileDialog
+        isOpen={isProfileDialogOpen}
+        initial={editedProfile}
+        defaults={profileDefaults}
+        onSave={handleSaveProfile}
+        onCancel={() => setIsProfileDialogOpen(false)}
+        onDelete={handleDeleteProfile}
+      />
+      <SsoRolePicker
+        isOpen={isSsoRolePickerOpen}
+        profileName={currentProfile}
+        onPick={handlePickSsoRole}
+        onCancel={() => setIsSsoRolePickerOpen(false)}
+      />
     </div>
   );
 }
@@ -9,12 +9,17 @@ export interface ObjectMetadata {
   contentType?: string;
   lastModified?: Date | string;
   etag?: string;
+  etagPartCount?: number;
   storageClass?: string;
+  restore?: { ongoing: boolean; expiry?: Date | string };
   versionId?: string;
   serverSideEncryption?: string;
   contentEncoding?: string;
   cacheControl?: string;
   expires?: Date | string;
+  websiteRedirectLocation?: string;
+  redirectTarget?: { bucket: string; key: string };
+  checksums?: Record<string, string>;
   tags: Record<string, string>;
   customMetadata: Record<string, string>;
 }
@@ -25,8 +30,35 @@ export interface PropertiesDialogProps {
   fileKey: string;
   isFolder: boolean;
   onClose: () => void;
+  /** Opens the object a website redirect points to */
+  onNavigateToObject?: (bucket: string, key: string) => void;
 }
 
+/** How long a copied shareable link stays valid (S3 allows at most 7 days) */
+const SHARE_LINK_EXPIRIES = [
+  { label: '1 hour', seconds: 60 * 60 },
+  { label: '1 day', seconds: 24 * 60 * 60 },
+  { label: '7 days', seconds: 7 * 24 * 60 * 60 },
+];
+
+/** Storage classes whose objects must be restored before they can be downloaded */
+const ARCHIVED_STORAGE_CLASSES = ['GLACIER', 'DEEP_ARCHIVE'];
+
+type RestoreTier = 'Expedited' | 'Standard' | 'Bulk';
+
+/** Retrieval tiers, fastest and most expensive first (Deep Archive has no Expedited tier) */
+const RESTORE_TIERS: { tier: RestoreTier; label: string }[] = [
+  { tier: 'Expedited', label: 'Expedited (minutes)' },
+  { tier: 'Standard', label: 'Standard (hours)' },
+  { tier: 'Bulk', label: 'Bulk (cheapest)' },
+];
+
+/** Most tags S3 allows on one object */
+const MAX_OBJECT_TAGS = 10;
+
+/** Single-part ETags are the hex MD5 of the content, with or without quotes */
+const MD5_ETAG_PATTERN = /^"?[0-9a-f]{32}"?$/i;
+
 /**
  * Format bytes into human readable size
  */
@@ -71,16 +103,33 @@ function PropertiesDialog({
   fileKey,
   isFolder,
   onClose,
+  onNavigateToObject,
 }: PropertiesDialogProps): React.ReactElement | null {
   const [loading, setLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
   const [metadata, setMetadata] = useState<ObjectMetadata | null>(null);
+  const [shareExpiry, setShareExpiry] = useState(SHARE_LINK_EXPIRIES[0].seconds);
+  const [shareStatus, setShareStatus] = useState<string | null>(null);
+  const [restoreDays, setRestoreDays] = useState(7);
+  const [restoreTier, setRestoreTier] = useState<RestoreTier>('Standard');
+  const [restoreMessage, setRestoreMessage] = useState<string | null>(null);
+  const [verifyStatus, setVerifyStatus] = useState<string | null>(null);
+  const [verifying, setVerifying] = useState(false);
+  // Tag rows being edited, or null when not editing
+  const [tagDraft, setTagDraft] = useState<{ key: string; value: string }[] | null>(null);
+  const [tagError, setTagError] = useState<string | null>(null);
+  const [savingTags, setSavingTags] = useState(false);
 
   useEffect(() => {
     if (isOpen && !isFolder) {
       setLoading(true);
       setError(null);
       setMetadata(null);
+      setShareStatus(null);
+      setRestoreMessage(null);
+      setVerifyStatus(null);
+      setTagDraft(null);
+      setTagError(null);
 
       // Fetch metadata from main process
       window.electronAPI
@@ -125,11 +174,111 @@ function PropertiesDialog({
     }
   };
 
+  const handleCopyShareLink = async (kind: 'download' | 'upload') => {
+    setShareStatus(null);
+    const result =
+      kind === 'download'
+        ? await window.electronAPI.s3.presignGetUrl(bucket, fileKey, shareExpiry)
+        : await window.electronAPI.s3.presignPutUrl(bucket, fileKey, shareExpiry, metadata?.contentType);
+    if (!result.success || !result.url) {
+      setShareStatus(result.error || 'Failed to create link');
+      return;
+    }
+    try {
+      await navigator.clipboard.writeText(result.url);
+      const expiry = SHARE_LINK_EXPIRIES.find(option => option.seconds === shareExpiry);
+      const label = kind === 'download' ? 'Link' : 'Upload link';
+      setShareStatus(`${label} copied, valid for ${expiry?.label ?? `${shareExpiry} seconds`}`);
+    } catch {
+      setShareStatus('Failed to copy to clipboard');
+    }
+  };
+
+  const handleRestore = async () => {
+    setRestoreMessage(null);
+    const result = await window.electronAPI.s3.restoreObject(bucket, fileKey, restoreDays, restoreTier);
+    if (!result.success) {
+      setRestoreMessage(result.error || 'Failed to request restore');
+      return;
+    }
+    setMetadata(prev => (prev ? { ...prev, restore: { ongoing: true } } : prev));
+    setRestoreMessage(`Restore requested for ${restoreDays} ${restoreDays === 1 ? 'day' : 'days'}`);
+  };
+
+  const handleVerifyChecksum = async () => {
+    setVerifyStatus(null);
+    setVerifying(true);
+    try {
+      const result = await window.electronAPI.s3.verifyChecksum(bucket, fileKey);
+      if (result.success) {
+        if (!result.verified) {
+          setVerifyStatus('No SHA-256 checksum or MD5 ETag to compare with');
+        } else if (result.verifiedAgainst === 'ETag') {
+          setVerifyStatus('Content matches the MD5 ETag');
+        } else {
+          setVerifyStatus('Content matches the SHA-256 checksum');
+        }
+      } else {
+        setVerifyStatus(result.error || 'Failed to verify the content');
+      }
+    } finally {
+      setVerifying(false);
+    }
+  };
+
+  const startEditingTags = () => {
+    const entries = Object.entries(metadata?.tags ?? {}).map(([key, value]) => ({ key, value }));
+    setTagDraft(entries.length > 0 ? entries : [{ key: '', value: '' }]);
+    setTagError(null);
+  };
+
+  const updateTagDraft = (index: number, field: 'key' | 'value', text: string) => {
+    setTagDraft(prev => prev && prev.map((row, i) => (i === index ? { ...row, [field]: text } : row)));
+  };
+
+  const handleSaveTags = async () => {
+    if (!tagDraft) return;
+    // Rows left completely blank are dropped rather than rejected
+    const rows = tagDraft.filter(row => row.key.trim() !== '' || row.value !== '');
+    const tags: Record<string, string> = {};
+    for (const row of rows) {
+      if (row.key in tags) {
+        setTagError(`Duplicate tag key: ${row.key}`);
+        return;
+      }
+      tags[row.key] = row.value;
+    }
+
+    setSavingTags(true);
+    setTagError(null);
+    try {
+      const result = await window.electronAPI.s3.putObjectTags(bucket, fileKey, tags);
+      if (!result.success) {
+        setTagError(result.error || 'Failed to save tags');
+        return;
+      }
+      setMetadata(prev => (prev ? { ...prev, tags } : prev));
+      setTagDraft(null);
+    } catch (err) {
+      setTagError(err instanceof Error ? err.message : 'Failed to save tags');
+    } finally {
+      setSavingTags(false);
+    }
+  };
+
   if (!isOpen) {
     return null;
   }
 
   const name = getName(fileKey);
+  const sha256 = metadata?.checksums?.SHA256;
+  const canVerifySha256 = !!sha256 && !sha256.includes('-');
+  // Without a SHA-256 the content can still be compared with a single-part ETag,
+  // which isn't an MD5 of the content under SSE-KMS
+  const canVerifyEtag =
+    !canVerifySha256 &&
+    MD5_ETAG_PATTERN.test(metadata?.etag ?? '') &&
+    !metadata?.serverSideEncryption?.startsWith('aws:kms');
 
   return (
     <div className="dialog-overlay" onClick={onClose} onKeyDown={handleKeyDown}>
@@ -200,6 +349,42 @@ function PropertiesDialog({
                     Copy
                   </button>
                 </div>
+                {!isFolder && (
+                  <div className="property-row property-row-url">
+                    <span className="property-label">Shareable link:</span>
+                    <select
+                      className="property-share-expiry"
+                      value={shareExpiry}
+                      onChange={(e) => setShareExpiry(Number(e.target.value))}
+                      aria-label="Link expiry"
+                    >
+                      {SHARE_LINK_EXPIRIES.map(option => (
+                        <option key={option.seconds} value={option.seconds}>
+                          {option.label}
+                        </option>
+                      ))}
+                    </select>
+                    <button
+                      className="property-copy-btn"
+                      onClick={() => handleCopyShareLink('download')}
+                      title="Copy a presigned link that downloads the file without credentials until it expires"
+                    >
+                      Copy link
+                    </button>
+                    <button
+                      className="property-copy-btn"
+                      onClick={() => handleCopyShareLink('upload')}
+                      title="Copy a presigned link that replaces the file with an HTTP PUT until it expires"
+                    >
+                      Copy upload link
+                    </button>
+                    {shareStatus && (
+                      <span className="property-share-status" role="status">
+                        {shareStatus}
+                      </span>
+                    )}
+                  </div>
+                )}
               </div>
 
               {!isFolder && (
@@ -215,6 +400,32 @@ function PropertiesDialog({
                     <div className="property-row">
                       <span className="property-label">ETag:</span>
                       <span className="property-value property-value-mono">{metadata.etag}</span>
+                      {canVerifyEtag && (
+                        <button
+                          className="property-copy-btn"
+                          onClick={handleVerifyChecksum}
+                          disabled={verifying}
+                          title="Download the object and compare its MD5 with this ETag"
+                        >
+                          {verifying ? 'Verifying...' : 'Verify'}
+                        </button>
+                      )}
+                    </div>
+                  )}
+                  {canVerifyEtag && verifyStatus && (
+                    <span className="property-share-status" role="status">
+                      {verifyStatus}
+                    </span>
+                  )}
+                  {metadata.etagPartCount && (
+                    <div className="property-row">
+                      <span className="property-label">Upload:</span>
+                      <span
+                        className="property-value"
+                        title="The ETag of a multipart upload is not an MD5 of the content"
+                      >
+                        Multipart ({metadata.etagPartCount} {metadata.etagPartCount === 1 ? 'part' : 'parts'})
+                      </span>
                     </div>
                   )}
                   {metadata.storageClass && (
@@ -223,6 +434,55 @@ function PropertiesDialog({
                       <span className="property-value">{metadata.storageClass}</span>
                     </div>
                   )}
+                  {metadata.storageClass && ARCHIVED_STORAGE_CLASSES.includes(metadata.storageClass) && (
+                    <div className="property-row">
+                      <span className="property-label">Restore:</span>
+                      <span className="property-value">
+                        {metadata.restore?.ongoing
+                          ? 'In progress'
+                          : metadata.restore?.expiry
+                            ? `Restored until ${formatDate(metadata.restore.expiry)}`
+                            : 'Not restored (download needs a restore first)'}
+                      </span>
+                      {!metadata.restore?.ongoing && (
+                        <>
+                          <input
+                            type="number"
+                            className="property-restore-days"
+                            min={1}
+                            value={restoreDays}
+                            onChange={(e) => setRestoreDays(Math.max(1, Math.floor(Number(e.target.value)) || 1))}
+                            aria-label="Restore days"
+                            title="Days the restored copy is kept"
+                          />
+                          <select
+                            className="property-restore-tier"
+                            value={restoreTier}
+                            onChange={(e) => setRestoreTier(e.target.value as RestoreTier)}
+                            aria-label="Restore tier"
+                          >
+                            {RESTORE_TIERS.map(option => (
+                              <option key={option.tier} value={option.tier}>
+                                {option.label}
+                              </option>
+                            ))}
+                          </select>
+                          <button
+                            className="property-copy-btn"
+                            onClick={handleRestore}
+                            title="Request a temporary copy of the archived object that can be downloaded"
+                          >
+                            {metadata.restore?.expiry ? 'Extend' : 'Restore'}
+                          </button>
+                        </>
+                      )}
+                      {restoreMessage && (
+                        <span className="property-share-status" role="status">
+                          {restoreMessage}
+                        </span>
+                      )}
+                    </div>
+                  )}
                   {metadata.versionId && (
                     <div className="property-row">
                       <span className="property-label">Version ID:</span>
@@ -253,10 +513,120 @@ function PropertiesDialog({
                       <span className="property-value">{formatDate(metadata.expires)}</span>
                     </div>
                   )}
+                  {metadata.websiteRedirectLocation && (
+                    <div className="property-row property-row-url">
+                      <span className="property-label">Redirects To:</span>
+                      <span className="property-value property-value-mono property-value-wrap">
+                        {metadata.websiteRedirectLocation}
+                      </span>
+                      {metadata.redirectTarget && onNavigateToObject && (
+                        <button
+                          className="property-copy-btn"
+                          onClick={() => onNavigateToObject(metadata.redirectTarget!.bucket, metadata.redirectTarget!.key)}
+                          title={`Go to s3://${metadata.redirectTarget.bucket}/${metadata.redirectTarget.key}`}
+                        >
+                          Go to
+                        </button>
+                      )}
+                    </div>
+                  )}
                 </div>
               )}
 
-              {Object.keys(metadata.tags).length > 0 && (
+              {metadata.checksums && Object.keys(metadata.checksums).length > 0 && (
+                <div className="properties-section">
+                  <h4>Checksums</h4>
+                  {Object.entries(metadata.checksums).map(([algorithm, value]) => (
+                    <div key={algorithm} className="property-row">
+                      <span className="property-label">{algorithm}:</span>
+                      <span className="property-value property-value-mono">{value}</span>
+                      {algorithm === 'SHA256' && canVerifySha256 && (
+                        <button
+                          className="property-copy-btn"
+                          onClick={handleVerifyChecksum}
+                          disabled={verifying}
+                          title="Download the object and compare it with this checksum"
+                        >
+                          {verifying ? 'Verifying...' : 'Verify'}
+                        </button>
+                      )}
+                    </div>
+                  ))}
+                  {canVerifySha256 && verifyStatus && (
+                    <span className="property-share-status" role="status">
+                      {verifyStatus}
+                    </span>
+                  )}
+                </div>
+              )}
+
+              {!isFolder && !tagDraft && Object.keys(metadata.tags).length === 0 && (
+                <div className="properties-section">
+                  <div className="property-row">
+                    <span className="property-label">Tags:</span>
+                    <span className="property-value">None</span>
+                    <button className="property-copy-btn" onClick={startEditingTags}>
+                      Add tags
+                    </button>
+                  </div>
+                </div>
+              )}
+
+              {tagDraft && (
+                <div className="properties-section">
+                  <h4>Tags</h4>
+                  {tagDraft.map((row, index) => (
+                    <div key={index} className="property-row">
+                      <input
+                        type="text"
+                        className="property-tag-input"
+                        value={row.key}
+                        onChange={(e) => updateTagDraft(index, 'key', e.target.value)}
+                        placeholder="Key"
+                        aria-label={`Tag key ${index + 1}`}
+                      />
+                      <input
+                        type="text"
+                        className="property-tag-input"
+                        value={row.value}
+                        onChange={(e) => updateTagDraft(index, 'value', e.target.value)}
+                        placeholder="Value"
+                        aria-label={`Tag value ${index + 1}`}
+                      />
+                      <button
+                        className="property-copy-btn"
+                        onClick={() => setTagDraft(prev => prev && prev.filter((_, i) => i !== index))}
+                        aria-label={`Remove tag ${index + 1}`}
+                      >
+                        Remove
+                      </button>
+                    </div>
+                  ))}
+                  <div className="property-row">
+                    <button
+                      className="property-copy-btn"
+                      onClick={() => setTagDraft(prev => prev && [...prev, { key: '', value: '' }])}
+                      disabled={tagDraft.length >= MAX_OBJECT_TAGS}
+                      title={`Objects can have at most ${MAX_OBJECT_TAGS} tags`}
+                    >
+                      Add tag
+                    </button>
+                    <button className="property-copy-btn" onClick={handleSaveTags} disabled={savingTags}>
+                      {savingTags ? 'Saving...' : 'Save tags'}
+                    </button>
+                    <button className="property-copy-btn" onClick={() => setTagDraft(null)} disabled={savingTags}>
+                      Cancel
+                    </button>
+                  </div>
+                  {tagError && (
+                    <div className="properties-error property-tag-error" role="alert">
+                      {tagError}
+                    </div>
+                  )}
+                </div>
+              )}
+
+              {!tagDraft && Object.keys(metadata.tags).length > 0 && (
                 <div className="properties-section">
                   <h4>Tags</h4>
                   {Object.entries(metadata.tags).map(([key, value]) => (
@@ -265,6 +635,11 @@ function PropertiesDialog({
                       <span className="property-value">{value}</span>
                     </div>
                   ))}
+                  {!isFolder && (
+                    <button className="property-copy-btn" onClick={startEditingTags}>
+                      Edit tags
+                    </button>
+                  )}
                 </div>
               )}
 
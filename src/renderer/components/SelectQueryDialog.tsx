@@ -0,0 +1,203 @@
+import React, { useState, useEffect, useRef } from 'react';
+
+/**
+ * Serialization of S3 Select input and output (mirrors the main process type)
+ */
+export type SelectFormat = 'csv' | 'json';
+
+export interface SelectQueryDialogProps {
+  isOpen: boolean;
+  bucket: string;
+  fileKey: string;
+  onClose: () => void;
+}
+
+export const DEFAULT_SELECT_EXPRESSION = 'SELECT * FROM s3object s LIMIT 100';
+
+/**
+ * Whether S3 Select should read the object as CSV or JSON, from its extension
+ */
+export function getSelectInputFormat(key: string): SelectFormat {
+  const baseKey = key.toLowerCase().replace(/\.(gz|bz2)$/, '');
+  return baseKey.endsWith('.csv') || baseKey.endsWith('.tsv') ? 'csv' : 'json';
+}
+
+/**
+ * Format file size for display
+ */
+function formatSize(bytes: number): string {
+  if (bytes === 0) return '0 B';
+  const units = ['B', 'KB', 'MB', 'GB', 'TB'];
+  const i = Math.floor(Math.log(bytes) / Math.log(1024));
+  return `${(bytes / Math.pow(1024, i)).toFixed(i > 0 ? 1 : 0)} ${units[i]}`;
+}
+
+let queryCounter = 0;
+
+/**
+ * Runs an S3 Select SQL query against a CSV or JSON object and shows the
+ * matching records, without downloading the whole object
+ */
+function SelectQueryDialog({ isOpen, bucket, fileKey, onClose }: SelectQueryDialogProps): React.ReactElement | null {
+  const [expression, setExpression] = useState(DEFAULT_SELECT_EXPRESSION);
+  const [outputFormat, setOutputFormat] = useState<SelectFormat>('csv');
+  const [running, setRunning] = useState(false);
+  const [records, setRecords] = useState<string | null>(null);
+  const [summary, setSummary] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const operationRef = useRef<string | null>(null);
+
+  const inputFormat = getSelectInputFormat(fileKey);
+
+  useEffect(() => {
+    if (isOpen) {
+      setRecords(null);
+      setSummary(null);
+      setError(null);
+      setOutputFormat(inputFormat);
+    }
+  }, [isOpen, fileKey, inputFormat]);
+
+  // Stop a running query when the dialog is closed
+  useEffect(() => {
+    if (!isOpen) return;
+    return () => {
+      if (operationRef.current) {
+        window.electronAPI.s3.cancelOperation(operationRef.current);
+        operationRef.current = null;
+        setRunning(false);
+      }
+    };
+  }, [isOpen]);
+
+  const handleRun = async () => {
+    const operationId = `select-${Date.now()}-${++queryCounter}`;
+    operationRef.current = operationId;
+    setRunning(true);
+    setError(null);
+
+    try {
+      const result = await window.electronAPI.s3.selectObjectContent(
+        bucket,
+        fileKey,
+        expression,
+        inputFormat,
+        outputFormat,
+        operationId
+      );
+      if (operationRef.current !== operationId) return;
+
+      if (result.success) {
+        const text = result.records ?? '';
+        const count = text.split('\n').filter(line => line !== '').length;
+        const parts = [`${count.toLocaleString()} ${count === 1 ? 'record' : 'records'}`];
+        if (result.bytesScanned !== undefined) {
+          parts.push(`${formatSize(result.bytesScanned)} scanned`);
+        }
+        if (result.truncated) {
+          parts.push('results cut off, add a LIMIT to see them all');
+        }
+        setRecords(text);
+        setSummary(parts.join(' · '));
+      } else {
+        setRecords(null);
+        setSummary(null);
+        setError(result.error ?? 'Query failed');
+      }
+    } finally {
+      if (operationRef.current === operationId) {
+        operationRef.current = null;
+        setRunning(false);
+      }
+    }
+  };
+
+  const handleCancel = () => {
+    if (operationRef.current) {
+      window.electronAPI.s3.cancelOperation(operationRef.current);
+    }
+  };
+
+  const handleKeyDown = (e: React.KeyboardEvent) => {
+    if (e.key === 'Escape') {
+      onClose();
+    } else if (e.key === 'Enter' && (e.ctrlKey || e.metaKey) && !running) {
+      e.preventDefault();
+      handleRun();
+    }
+  };
+
+  if (!isOpen) {
+    return null;
+  }
+
+  return (
+    <div className="dialog-overlay" onClick={onClose}>
+      <div className="dialog dialog-select-query" onClick={(e) => e.stopPropagation()} onKeyDown={handleKeyDown}>
+        <div className="dialog-header">
+          <h3>Query with S3 Select</h3>
+        </div>
+        <div className="dialog-content">
+          <div className="dialog-filename">s3://{bucket}/{fileKey}</div>
+          <div className="dialog-field">
+            <label htmlFor="select-expression">SQL expression (read as {inputFormat.toUpperCase()}):</label>
+            <textarea
+              id="select-expression"
+              value={expression}
+              onChange={(e) => setExpression(e.target.value)}
+              className="dialog-input select-query-expression"
+              rows={3}
+              spellCheck={false}
+              autoFocus
+            />
+          </div>
+          <div className="dialog-field">
+            <label htmlFor="select-output-format">Results as:</label>
+            <select
+              id="select-output-format"
+              value={outputFormat}
+              onChange={(e) => setOutputFormat(e.target.value as SelectFormat)}
+              className="dialog-input"
+            >
+              <option value="csv">CSV</option>
+              <option value="json">JSON (one record per line)</option>
+            </select>
+          </div>
+          {error && (
+            <div className="dialog-warning" role="alert">
+              {error}
+            </div>
+          )}
+          {summary && (
+            <div className="select-query-summary" role="status">
+              {summary}
+            </div>
+          )}
+          {records !== null && <pre className="select-query-results">{records}</pre>}
+        </div>
+        <div className="dialog-actions">
+          <button type="button" className="dialog-btn dialog-btn-cancel" onClick={onClose}>
+            Close
+          </button>
+          {running ? (
+            <button type="button" className="dialog-btn dialog-btn-cancel" onClick={handleCancel}>
+              Stop
+            </button>
+          ) : (
+            <button
+              type="button"
+              className="dialog-btn dialog-btn-confirm"
+              onClick={handleRun}
+              disabled={!expression.trim()}
+              title="Run the query (Ctrl+Enter)"
+            >
+              Run
+            </button>
+          )}
+        </div>
+      </div>
+    </div>
+  );
+}
+
+export default SelectQueryDialog;
@@ -0,0 +1,435 @@
+import React, { useState, useEffect } from 'react';
+import type { Theme } from '../../preload';
+
+/**
+ * What to do when a download's destination file already exists
+ */
+export type ConflictPolicy = 'overwrite' | 'skip' | 'rename';
+
+export interface SettingsDialogProps {
+  isOpen: boolean;
+  onClose: () => void;
+  /** Called after shared locations have been merged into the app state */
+  onImported?: () => void;
+}
+
+const CONFLICT_POLICY_LABELS: Record<ConflictPolicy, string> = {
+  rename: 'Keep both (add a number to the new file)',
+  overwrite: 'Overwrite the existing file',
+  skip: 'Skip the download',
+};
+
+/**
+ * Storage classes objects can be uploaded into (mirrors the main process list)
+ */
+export type UploadStorageClass =
+  | 'STANDARD'
+  | 'STANDARD_IA'
+  | 'ONEZONE_IA'
+  | 'INTELLIGENT_TIERING'
+  | 'GLACIER_IR'
+  | 'GLACIER'
+  | 'DEEP_ARCHIVE';
+
+const UPLOAD_STORAGE_CLASS_LABELS: Record<UploadStorageClass, string> = {
+  STANDARD: 'Standard',
+  STANDARD_IA: 'Standard-IA (infrequent access)',
+  ONEZONE_IA: 'One Zone-IA',
+  INTELLIGENT_TIERING: 'Intelligent-Tiering',
+  GLACIER_IR: 'Glacier Instant Retrieval',
+  GLACIER: 'Glacier Flexible Retrieval',
+  DEEP_ARCHIVE: 'Glacier Deep Archive',
+};
+
+const THEME_LABELS: Record<Theme, string> = {
+  system: 'Same as the system',
+  light: 'Light',
+  dark: 'Dark',
+};
+
+/** Retry counts offered in the settings; null keeps the SDK's standard policy */
+const RETRY_OPTIONS: Array<{ value: number | null; label: string }> = [
+  { value: null, label: 'Default (2 retries)' },
+  { value: 0, label: 'No retries' },
+  { value: 1, label: '1 retry' },
+  { value: 3, label: '3 retries' },
+  { value: 5, label: '5 retries' },
+  { value: 10, label: '10 retries' },
+];
+
+/** Timeouts offered in the settings, in seconds; null waits as long as the SDK does */
+const CONNECT_TIMEOUT_OPTIONS: Array<number | null> = [null, 5, 10, 30, 60];
+const REQUEST_TIMEOUT_OPTIONS: Array<number | null> = [null, 30, 60, 120, 300];
+
+/**
+ * Whether a proxy URL is one the main process accepts: http or https with a host
+ */
+export function isValidProxyUrl(value: string): boolean {
+  try {
+    const url = new URL(value);
+    return (url.protocol === 'http:' || url.protocol === 'https:') && url.hostname !== '';
+  } catch {
+    return false;
+  }
+}
+
+function timeoutLabel(seconds: number | null): string {
+  return seconds === null ? 'No limit' : `${seconds} seconds`;
+}
+
+/**
+ * Application settings. Changes are saved to the app state as soon as they are made.
+ */
+function SettingsDialog({ isOpen, onClose, onImported }: SettingsDialogProps): React.ReactElement | null {
+  const [conflictPolicy, setConflictPolicy] = useState<ConflictPolicy>('rename');
+  const [collapseFolders, setCollapseFolders] = useState(false);
+  const [confirmBeforeDelete, setConfirmBeforeDelete] = useState(true);
+  const [theme, setTheme] = useState<Theme>('system');
+  const [requestLogging, setRequestLogging] = useState(false);
+  const [requesterPays, setRequesterPays] = useState(false);
+  const [maxRetries, setMaxRetries] = useState<number | null>(null);
+  const [connectTimeout, setConnectTimeout] = useState<number | null>(null);
+  const [requestTimeout, setRequestTimeout] = useState<number | null>(null);
+  const [uploadStorageClass, setUploadStorageClass] = useState<UploadStorageClass | null>(null);
+  const [proxyUrl, setProxyUrl] = useState('');
+  const [proxyError, setProxyError] = useState<string | null>(null);
+  const [shareStatus, setShareStatus] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    setShareStatus(null);
+    let mounted = true;
+    window.electronAPI.appState
+      .load()
+      .then(state => {
+        if (mounted) {
+          setConflictPolicy(state.downloadConflictPolicy ?? 'rename');
+          setCollapseFolders(state.collapseSingleChildFolders === true);
+          setConfirmBeforeDelete(state.confirmBeforeDelete !== false);
+          setTheme(state.theme ?? 'system');
+          setRequestLogging(state.requestLogging === true);
+          setRequesterPays(state.requesterPays === true);
+          setMaxRetries(state.maxRetries ?? null);
+          setConnectTimeout(state.connectTimeoutSeconds ?? null);
+          setRequestTimeout(state.requestTimeoutSeconds ?? null);
+          setUploadStorageClass(state.uploadStorageClass ?? null);
+          setProxyUrl(state.proxyUrl ?? '');
+          setProxyError(null);
+        }
+      })
+      .catch(err => {
+        console.error('Failed to load settings:', err);
+      });
+    return () => {
+      mounted = false;
+    };
+  }, [isOpen]);
+
+  const handleConflictPolicyChange = (policy: ConflictPolicy) => {
+    setConflictPolicy(policy);
+    window.electronAPI.appState.save({ downloadConflictPolicy: policy }).catch(err => {
+      console.error('Failed to save settings:', err);
+    });
+  };
+
+  const handleCollapseFoldersChange = (enabled: boolean) => {
+    setCollapseFolders(enabled);
+    window.electronAPI.appState.save({ collapseSingleChildFolders: enabled }).catch(err => {
+      console.error('Failed to save settings:', err);
+    });
+  };
+
+  const handleConfirmBeforeDeleteChange = (enabled: boolean) => {
+    setConfirmBeforeDelete(enabled);
+    window.electronAPI.appState.save({ confirmBeforeDelete: enabled }).catch(err => {
+      console.error('Failed to save settings:', err);
+    });
+  };
+
+  const handleThemeChange = (value: Theme) => {
+    setTheme(value);
+    window.electronAPI.appState.save({ theme: value }).catch(err => {
+      console.error('Failed to save settings:', err);
+    });
+  };
+
+  const handleRequestLoggingChange = (enabled: boolean) => {
+    setRequestLogging(enabled);
+    window.electronAPI.appState.save({ requestLogging: enabled }).catch(err => {
+      console.error('Failed to save settings:', err);
+    });
+  };
+
+  const handleRequesterPaysChange = (enabled: boolean) => {
+    setRequesterPays(enabled);
+    window.electronAPI.appState.save({ requesterPays: enabled }).catch(err => {
+      console.error('Failed to save settings:', err);
+    });
+  };
+
+  const handleMaxRetriesChange = (retries: number | null) => {
+    setMaxRetries(retries);
+    window.electronAPI.appState.save({ maxRetries: retries }).catch(err => {
+      console.error('Failed to save settings:', err);
+    });
+  };
+
+  const handleConnectTimeoutChange = (seconds: number | null) => {
+    setConnectTimeout(seconds);
+    window.electronAPI.appState.save({ connectTimeoutSeconds: seconds }).catch(err => {
+      console.error('Failed to save settings:', err);
+    });
+  };
+
+  const handleRequestTimeoutChange = (seconds: number | null) => {
+    setRequestTimeout(seconds);
+    window.electronAPI.appState.save({ requestTimeoutSeconds: seconds }).catch(err => {
+      console.error('Failed to save settings:', err);
+    });
+  };
+
+  const handleUploadStorageClassChange = (storageClass: UploadStorageClass | null) => {
+    setUploadStorageClass(storageClass);
+    window.electronAPI.appState.save({ uploadStorageClass: storageClass }).catch(err => {
+      console.error('Failed to save settings:', err);
+    });
+  };
+
+  const handleProxyUrlCommit = () => {
+    const value = proxyUrl.trim();
+    if (value !== '' && !isValidProxyUrl(value)) {
+      setProxyError('Enter an http:// or https:// proxy URL, e.g. http://proxy.example.com:8080');
+      return;
+    }
+    setProxyError(null);
+    window.electronAPI.appState.save({ proxyUrl: value === '' ? null : value }).catch(err => {
+      console.error('Failed to save settings:', err);
+    });
+  };
+
+  const handleExportLocations = async () => {
+    const result = await window.electronAPI.appState.exportShareable();
+    if (result.success) {
+      setShareStatus(`Exported to ${result.filePath}`);
+    } else if (!result.cancelled) {
+      setShareStatus(`Export failed: ${result.error}`);
+    }
+  };
+
+  const handleImportLocations = async () => {
+    const result = await window.electronAPI.appState.importShareable();
+    if (result.success) {
+      const added = result.added ?? 0;
+      setShareStatus(added === 0 ? 'Nothing new to import' : `Imported ${added} new ${added === 1 ? 'entry' : 'entries'}`);
+      onImported?.();
+    } else if (!result.cancelled) {
+      setShareStatus(`Import failed: ${result.error}`);
+    }
+  };
+
+  const handleKeyDown = (e: React.KeyboardEvent) => {
+    if (e.key === 'Escape') {
+      onClose();
+    }
+  };
+
+  if (!isOpen) {
+    return null;
+  }
+
+  return (
+    <div className="dialog-overlay" onClick={onClose} onKeyDown={handleKeyDown}>
+      <div className="dialog" onClick={(e) => e.stopPropagation()}>
+        <div className="dialog-header">
+          <h3>Settings</h3>
+        </div>
+        <div className="dialog-content">
+          <div className="dialog-field">
+            <label htmlFor="settings-theme">Theme:</label>
+            <select
+              id="settings-theme"
+              value={theme}
+              onChange={(e) => handleThemeChange(e.target.value as Theme)}
+              className="dialog-input"
+            >
+              {(Object.keys(THEME_LABELS) as Theme[]).map(value => (
+                <option key={value} value={value}>
+                  {THEME_LABELS[value]}
+                </option>
+              ))}
+            </select>
+          </div>
+          <div className="dialog-field">
+            <label htmlFor="settings-conflict-policy">When a downloaded file already exists:</label>
+            <select
+              id="settings-conflict-policy"
+              value={conflictPolicy}
+              onChange={(e) => handleConflictPolicyChange(e.target.value as ConflictPolicy)}
+              className="dialog-input"
+            >
+              {(Object.keys(CONFLICT_POLICY_LABELS) as ConflictPolicy[]).map(policy => (
+                <option key={policy} value={policy}>
+                  {CONFLICT_POLICY_LABELS[policy]}
+                </option>
+              ))}
+            </select>
+          </div>
+          <div className="dialog-field">
+            <label title="Useful for partitioned data such as year=2024/month=01/day=05/">
+              <input
+                type="checkbox"
+                checked={collapseFolders}
+                onChange={(e) => handleCollapseFoldersChange(e.target.checked)}
+              />
+              Skip through folders that only contain a single subfolder
+            </label>
+          </div>
+          <div className="dialog-field">
+            <label>
+              <input
+                type="checkbox"
+                checked={confirmBeforeDelete}
+                onChange={(e) => handleConfirmBeforeDeleteChange(e.target.checked)}
+              />
+              Ask for confirmation before deleting files (folders are always confirmed)
+            </label>
+          </div>
+          <div className="dialog-field">
+            <label htmlFor="settings-max-retries">Retry failed S3 requests:</label>
+            <select
+              id="settings-max-retries"
+              value={maxRetries === null ? '' : String(maxRetries)}
+              onChange={(e) => handleMaxRetriesChange(e.target.value === '' ? null : Number(e.target.value))}
+              className="dialog-input"
+              title="Throttling, timeouts and network errors are retried with backoff"
+            >
+              {RETRY_OPTIONS.map(option => (
+                <option key={option.label} value={option.value === null ? '' : String(option.value)}>
+                  {option.label}
+                </option>
+              ))}
+            </select>
+          </div>
+          <div className="dialog-field">
+            <label htmlFor="settings-connect-timeout">Connection timeout:</label>
+            <select
+              id="settings-connect-timeout"
+              value={connectTimeout === null ? '' : String(connectTimeout)}
+              onChange={(e) => handleConnectTimeoutChange(e.target.value === '' ? null : Number(e.target.value))}
+              className="dialog-input"
+              title="How long to wait for the S3 endpoint to accept a connection"
+            >
+              {CONNECT_TIMEOUT_OPTIONS.map(seconds => (
+                <option key={String(seconds)} value={seconds === null ? '' : String(seconds)}>
+                  {timeoutLabel(seconds)}
+                </option>
+              ))}
+            </select>
+          </div>
+          <div className="dialog-field">
+            <label htmlFor="settings-request-timeout">Request timeout:</label>
+            <select
+              id="settings-request-timeout"
+              value={requestTimeout === null ? '' : String(requestTimeout)}
+              onChange={(e) => handleRequestTimeoutChange(e.target.value === '' ? null : Number(e.target.value))}
+              className="dialog-input"
+              title="How long a request may go without receiving any data"
+            >
+              {REQUEST_TIMEOUT_OPTIONS.map(seconds => (
+                <option key={String(seconds)} value={seconds === null ? '' : String(seconds)}>
+                  {timeoutLabel(seconds)}
+                </option>
+              ))}
+            </select>
+          </div>
+          <div className="dialog-field">
+            <label htmlFor="settings-proxy-url">HTTP(S) proxy:</label>
+            <input
+              id="settings-proxy-url"
+              type="text"
+              value={proxyUrl}
+              onChange={(e) => setProxyUrl(e.target.value)}
+              onBlur={handleProxyUrlCommit}
+              onKeyDown={(e) => {
+                if (e.key === 'Enter') handleProxyUrlCommit();
+              }}
+              className="dialog-input"
+              placeholder="From HTTPS_PROXY / HTTP_PROXY when empty"
+              title="Hosts listed in NO_PROXY are always reached directly"
+              spellCheck={false}
+            />
+            {proxyError && (
+              <p className="dialog-warning" role="alert">
+                {proxyError}
+              </p>
+            )}
+          </div>
+          <div className="dialog-field">
+            <label htmlFor="settings-upload-storage-class">Storage class for uploads:</label>
+            <select
+              id="settings-upload-storage-class"
+              value={uploadStorageClass ?? ''}
+              onChange={(e) =>
+                handleUploadStorageClassChange(e.target.value === '' ? null : (e.target.value as UploadStorageClass))
+              }
+              className="dialog-input"
+              title="Archive classes are cheaper to store but must be restored before downloading"
+            >
+              <option value="">Default (Standard)</option>
+              {(Object.keys(UPLOAD_STORAGE_CLASS_LABELS) as UploadStorageClass[])
+                .filter(storageClass => storageClass !== 'STANDARD')
+                .map(storageClass => (
+                  <option key={storageClass} value={storageClass}>
+                    {UPLOAD_STORAGE_CLASS_LABELS[storageClass]}
+                  </option>
+                ))}
+            </select>
+          </div>
+          <div className="dialog-field">
+            <label title="Needed for Requester Pays buckets, such as some AWS Open Data datasets. Your account is charged for the requests and data transfer.">
+              <input
+                type="checkbox"
+                checked={requesterPays}
+                onChange={(e) => handleRequesterPaysChange(e.target.checked)}
+              />
+              Pay for requests to Requester Pays buckets
+            </label>
+          </div>
+          <div className="dialog-field">
+            <label title="Written to the main process console at debug level, with credentials and signatures redacted">
+              <input
+                type="checkbox"
+                checked={requestLogging}
+                onChange={(e) => handleRequestLoggingChange(e.target.checked)}
+              />
+              Log raw S3 requests and responses (for debugging endpoints)
+            </label>
+          </div>
+          <div className="dialog-field">
+            <label>Share recent locations, bookmarks and read-only profiles with your team:</label>
+            <div className="settings-share-actions">
+              <button type="button" className="dialog-btn dialog-btn-cancel" onClick={handleExportLocations}>
+                Export...
+              </button>
+              <button type="button" className="dialog-btn dialog-btn-cancel" onClick={handleImportLocations}>
+                Import...
+              </button>
+            </div>
+            {shareStatus && (
+              <p className="settings-share-status" role="status">
+                {shareStatus}
+              </p>
+            )}
+          </div>
+        </div>
+        <div className="dialog-actions">
+          <button type="button" className="dialog-btn dialog-btn-confirm" onClick={onClose}>
+            Done
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
+
+export default SettingsDialog;
@@ -0,0 +1,117 @@
+import React, { useState, useEffect } from 'react';
+
+export interface SsoRole {
+  accountId: string;
+  accountName?: string;
+  roleName: string;
+}
+
+export interface SsoRolePickerProps {
+  isOpen: boolean;
+  /** The signed-in SSO profile whose accounts are listed */
+  profileName: string | null;
+  onPick: (role: SsoRole) => void;
+  onCancel: () => void;
+}
+
+/**
+ * Lists the accounts and roles an SSO sign-in gives access to, to create a
+ * profile for one of them
+ */
+function SsoRolePicker({ isOpen, profileName, onPick, onCancel }: SsoRolePickerProps): React.ReactElement | null {
+  const [roles, setRoles] = useState<SsoRole[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [filter, setFilter] = useState('');
+
+  useEffect(() => {
+    if (!isOpen || !profileName) return;
+
+    let cancelled = false;
+    setRoles(null);
+    setError(null);
+    setFilter('');
+    window.electronAPI.aws.listSsoRoles(profileName).then(result => {
+      if (cancelled) return;
+      if (result.success) {
+        setRoles(result.roles ?? []);
+      } else {
+        setError(result.error ?? 'Failed to list SSO accounts');
+      }
+    });
+    return () => {
+      cancelled = true;
+    };
+  }, [isOpen, profileName]);
+
+  const handleKeyDown = (e: React.KeyboardEvent) => {
+    if (e.key === 'Escape') {
+      onCancel();
+    }
+  };
+
+  if (!isOpen) {
+    return null;
+  }
+
+  const needle = filter.trim().toLowerCase();
+  const shown = (roles ?? []).filter(
+    role =>
+      !needle ||
+      role.accountId.includes(needle) ||
+      role.roleName.toLowerCase().includes(needle) ||
+      !!role.accountName?.toLowerCase().includes(needle)
+  );
+
+  return (
+    <div className="dialog-overlay" onClick={onCancel}>
+      <div className="dialog dialog-profile" onClick={(e) => e.stopPropagation()} onKeyDown={handleKeyDown}>
+        <div className="dialog-header">
+          <h3>SSO Accounts and Roles</h3>
+        </div>
+        <div className="dialog-content">
+          {error ? (
+            <p className="dialog-warning" role="alert">
+              {error}
+            </p>
+          ) : roles === null ? (
+            <p>Loading accounts...</p>
+          ) : roles.length === 0 ? (
+            <p>This sign-in doesn't give access to any accounts.</p>
+          ) : (
+            <>
+              <input
+                type="text"
+                value={filter}
+                onChange={(e) => setFilter(e.target.value)}
+                className="dialog-input"
+                placeholder="Filter by account or role"
+                aria-label="Filter accounts"
+                autoFocus
+              />
+              <ul className="sso-role-list">
+                {shown.map(role => (
+                  <li key={`${role.accountId}/${role.roleName}`}>
+                    <button type="button" className="sso-role-item" onClick={() => onPick(role)}>
+                      <span className="sso-role-account">
+                        {role.accountName ?? role.accountId}
+                        {role.accountName && <span className="sso-role-account-id"> {role.accountId}</span>}
+                      </span>
+                      <span className="sso-role-name">{role.roleName}</span>
+                    </button>
+                  </li>
+                ))}
+              </ul>
+            </>
+          )}
+        </div>
+        <div className="dialog-actions">
+          <button type="button" className="dialog-btn dialog-btn-cancel" onClick={onCancel}>
+            Cancel
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
+
+export default SsoRolePicker;
@@ -55,6 +55,34 @@ describe('StatusBar', () => {
       expect(screen.getByTestId('status-bar-items')).toHaveTextContent('100 items loaded...');
     });
 
+    it('shows elapsed seconds while loading', () => {
+      render(
+        <StatusBar
+          loadedCount={100}
+          allLoaded={false}
+          selectedFiles={[]}
+          loading={true}
+          elapsedSeconds={12}
+        />
+      );
+
+      expect(screen.getByTestId('status-bar-items')).toHaveTextContent('100 items loaded... (12s)');
+    });
+
+    it('hides elapsed seconds when not loading', () => {
+      render(
+        <StatusBar
+          loadedCount={100}
+          allLoaded={true}
+          selectedFiles={[]}
+          loading={false}
+          elapsedSeconds={12}
+        />
+      );
+
+      expect(screen.getByTestId('status-bar-items')).toHaveTextContent(/^100 items$/);
+    });
+
     it('shows 0 items correctly', () => {
       render(
         <StatusBar
@@ -167,6 +195,33 @@ describe('StatusBar', () => {
     });
   });
 
+  describe('transfer display', () => {
+    it('shows speed and ETA of the active transfer', () => {
+      render(
+        <StatusBar
+          loadedCount={10}
+          allLoaded={true}
+          selectedFiles={[]}
+          activeTransfer={{ type: 'download', fileName: 'large.bin', speedBps: 45.2 * 1024 * 1024, etaSeconds: 38 }}
+        />
+      );
+
+      expect(screen.getByTestId('status-bar-transfer')).toHaveTextContent('Downloading large.bin — 45.2 MB/s, ETA 0:38');
+    });
+
+    it('hides the transfer area when nothing is running', () => {
+      render(
+        <StatusBar
+          loadedCount={10}
+          allLoaded={true}
+          selectedFiles={[]}
+        />
+      );
+
+      expect(screen.queryByTestId('status-bar-transfer')).not.toBeInTheDocument();
+    });
+  });
+
   describe('rendering', () => {
     it('renders status bar container with data-testid', () => {
       render(
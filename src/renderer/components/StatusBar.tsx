@@ -1,5 +1,6 @@
 import React from 'react';
 import type { S3Object } from './FileList';
+import { describeTransfer } from '../utils/transferStats';
 
 export interface StatusBarProps {
   /** Total items loaded so far */
@@ -10,6 +11,15 @@ export interface StatusBarProps {
   selectedFiles: S3Object[];
   /** Whether items are currently loading */
   loading?: boolean;
+  /** Seconds the current load has been running (shown while loading) */
+  elapsedSeconds?: number;
+  /** Running transfer whose speed and ETA are shown */
+  activeTransfer?: {
+    type: 'upload' | 'download';
+    fileName: string;
+    speedBps: number;
+    etaSeconds?: number | null;
+  };
 }
 
 function formatFileSize(bytes: number): string {
@@ -24,6 +34,8 @@ function StatusBar({
   allLoaded,
   selectedFiles,
   loading = false,
+  elapsedSeconds = 0,
+  activeTransfer,
 }: StatusBarProps): React.ReactElement {
   // Calculate total size of selected files (excluding folders)
   const selectedSize = selectedFiles
@@ -37,6 +49,9 @@ function StatusBar({
     ? `${loadedCount} items`
     : `${loadedCount} items loaded${loading ? '...' : ' (more available)'}`;
 
+  // Reassure users during slow listings by showing how long the load has been running
+  const elapsedText = loading && elapsedSeconds > 0 ? ` (${elapsedSeconds}s)` : '';
+
   // Build selection text
   const selectionText =
     selectedCount > 0
@@ -48,8 +63,16 @@ function StatusBar({
       <div className="status-bar-left">
         <span className="status-bar-items" data-testid="status-bar-items">
           {itemCountText}
+          {elapsedText}
         </span>
       </div>
+      {activeTransfer && (
+        <div className="status-bar-center">
+          <span className="status-bar-transfer" data-testid="status-bar-transfer">
+            {describeTransfer(activeTransfer)}
+          </span>
+        </div>
+      )}
       <div className="status-bar-right">
         <span className="status-bar-selection" data-testid="status-bar-selection">
           {selectionText}
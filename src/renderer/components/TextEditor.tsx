@@ -1,35 +1,23 @@
-import React, { useState, useCallback, useRef, useEffect } from 'react';
+import React, { useState, useCallback, useRef, useEffect, useMemo } from 'react';
 import Editor, { OnMount, OnChange } from '@monaco-editor/react';
+import { getLongestLineLength, LONG_LINE_THRESHOLD, LONG_LINE_WRAP_WIDTH, wrapLongLines } from '../utils/longLines';
 import type { editor } from 'monaco-editor';
+import { useColorScheme } from '../hooks/useColorScheme';
+import { getBaseExtension } from '../../shared/fileTypes';
 
 export interface TextEditorProps {
   bucket: string;
   fileKey: string;
   fileName: string;
+  /** Version to show instead of the latest one */
+  versionId?: string;
   onClose: () => void;
   onSaved?: () => void;
 }
 
-/**
- * Get the base extension of a file, looking past .gz if present
- * e.g., 'data.json.gz' -> 'json', 'data.csv' -> 'csv'
- */
-function getBaseExtension(key: string): string {
-  const lowerKey = key.toLowerCase();
-
-  // If it ends with .gz, get the extension before .gz
-  if (lowerKey.endsWith('.gz')) {
-    const withoutGz = key.slice(0, -3);
-    return withoutGz.split('.').pop()?.toLowerCase() ?? '';
-  }
-
-  // Otherwise just get the last extension
-  return key.split('.').pop()?.toLowerCase() ?? '';
-}
-
 /**
  * Determine Monaco language from file extension
- * Handles .gz files by looking at the extension before .gz
+ * Handles compressed files by looking at the extension before .gz, .zst or .snappy
  */
 function getLanguageFromKey(key: string): string {
   const ext = getBaseExtension(key);
@@ -86,7 +74,7 @@ function getLanguageFromKey(key: string): string {
     gql: 'graphql',
   };
 
-  return languageMap[ext] || 'plaintext';
+  return (ext && languageMap[ext]) || 'plaintext';
 }
 
 /**
@@ -98,15 +86,22 @@ function TextEditor({
   bucket,
   fileKey,
   fileName,
+  versionId,
   onClose,
   onSaved,
 }: TextEditorProps): React.ReactElement {
+  const colorScheme = useColorScheme();
   const [content, setContent] = useState<string>('');
   const [originalContent, setOriginalContent] = useState<string>('');
   const [loading, setLoading] = useState(true);
   const [saving, setSaving] = useState(false);
   const [error, setError] = useState<string | null>(null);
+  // Codec the content was decompressed from, shown in the footer
+  const [compression, setCompression] = useState<string | null>(null);
   const [hasChanges, setHasChanges] = useState(false);
+  // Files with huge lines open wrapped and read-only, since editing them as-is freezes the editor
+  const [longLineMode, setLongLineMode] = useState<'wrapped' | 'edit' | null>(null);
+  const [longestLine, setLongestLine] = useState(0);
 
   const editorRef = useRef<editor.IStandaloneCodeEditor | null>(null);
   const language = getLanguageFromKey(fileKey);
@@ -121,7 +116,7 @@ function TextEditor({
 
       try {
         // First check file size
-        const sizeResult = await window.electronAPI.s3.getFileSize(bucket, fileKey);
+        const sizeResult = await window.electronAPI.s3.getFileSize(bucket, fileKey, versionId);
         if (!sizeResult.success) {
           throw new Error(sizeResult.error || 'Failed to get file size');
         }
@@ -133,15 +128,20 @@ function TextEditor({
         }
 
         // Download content
-        const result = await window.electronAPI.s3.downloadContent(bucket, fileKey);
+        const result = await window.electronAPI.s3.downloadContent(bucket, fileKey, versionId);
         if (!result.success) {
           throw new Error(result.error || 'Failed to load file content');
         }
 
         if (mounted) {
-          setContent(result.content || '');
-          setOriginalContent(result.content || '');
+          const loaded = result.content || '';
+          const longest = getLongestLineLength(loaded);
+          setCompression(result.compression ?? null);
+          setContent(loaded);
+          setOriginalContent(loaded);
           setHasChanges(false);
+          setLongestLine(longest);
+          setLongLineMode(longest > LONG_LINE_THRESHOLD ? 'wrapped' : null);
         }
       } catch (err) {
         if (mounted) {
@@ -159,7 +159,31 @@ function TextEditor({
     return () => {
       mounted = false;
     };
-  }, [bucket, fileKey]);
+  }, [bucket, fileKey, versionId]);
+
+  const wrappedContent = useMemo(
+    () => (longLineMode === 'wrapped' ? wrapLongLines(content).join('\n') : null),
+    [longLineMode, content]
+  );
+
+  // Only offered when the content parses, so invalid JSON is never rewritten
+  const prettyJson = useMemo(() => {
+    if (longLineMode !== 'wrapped' || language !== 'json') return null;
+    try {
+      return JSON.stringify(JSON.parse(content), null, 2);
+    } catch {
+      return null;
+    }
+  }, [longLineMode, language, content]);
+
+  const handlePrettyPrint = useCallback(() => {
+    if (prettyJson === null) return;
+    const longest = getLongestLineLength(prettyJson);
+    setContent(prettyJson);
+    setHasChanges(prettyJson !== originalContent);
+    setLongestLine(longest);
+    setLongLineMode(longest > LONG_LINE_THRESHOLD ? 'wrapped' : null);
+  }, [prettyJson, originalContent]);
 
   const handleEditorMount: OnMount = useCallback((editor) => {
     editorRef.current = editor;
@@ -169,11 +193,13 @@ function TextEditor({
 
   const handleEditorChange: OnChange = useCallback(
     (value) => {
+      // The wrapped view is read-only; its text is not the file's content
+      if (longLineMode === 'wrapped') return;
       const newContent = value || '';
       setContent(newContent);
       setHasChanges(newContent !== originalContent);
     },
-    [originalContent]
+    [originalContent, longLineMode]
   );
 
   const handleSave = useCallback(async () => {
@@ -269,6 +295,29 @@ function TextEditor({
           </div>
         )}
 
+        {longLineMode === 'wrapped' && (
+          <div className="text-editor-notice">
+            <span>
+              The longest line has {longestLine.toLocaleString()} characters, so the file is shown wrapped
+              every {LONG_LINE_WRAP_WIDTH.toLocaleString()} characters and read-only.
+            </span>
+            <div className="text-editor-notice-actions">
+              {prettyJson !== null && (
+                <button className="text-editor-btn" onClick={handlePrettyPrint}>
+                  Pretty-print JSON
+                </button>
+              )}
+              <button
+                className="text-editor-btn"
+                onClick={() => setLongLineMode('edit')}
+                title="Editing very long lines can make the editor unresponsive"
+              >
+                Edit anyway
+              </button>
+            </div>
+          </div>
+        )}
+
         {/* Editor content */}
         <div className="text-editor-content">
           {loading ? (
@@ -280,8 +329,8 @@ function TextEditor({
             <Editor
               height="100%"
               language={language}
-              value={content}
-              theme="vs-dark"
+              value={wrappedContent ?? content}
+              theme={colorScheme === 'light' ? 'vs' : 'vs-dark'}
               onMount={handleEditorMount}
               onChange={handleEditorChange}
               loading={
@@ -291,12 +340,14 @@ function TextEditor({
                 </div>
               }
               options={{
-                minimap: { enabled: true },
+                readOnly: longLineMode === 'wrapped',
+                // The minimap and soft wrapping are slow on huge lines
+                minimap: { enabled: longLineMode === null },
                 fontSize: 13,
                 fontFamily: "'SF Mono', Monaco, 'Cascadia Code', 'Consolas', monospace",
                 lineNumbers: 'on',
                 scrollBeyondLastLine: false,
-                wordWrap: 'on',
+                wordWrap: longLineMode === 'edit' ? 'off' : 'on',
                 wrappingIndent: 'indent',
                 automaticLayout: true,
                 tabSize: 2,
@@ -317,8 +368,13 @@ function TextEditor({
           <span className="text-editor-path" title={`s3://${bucket}/${fileKey}`}>
             s3://{bucket}/{fileKey}
           </span>
+          {compression && (
+            <span className="viewer-compression" title="Decompressed for viewing">
+              {compression}
+            </span>
+          )}
           <span className="text-editor-status">
-            {hasChanges ? 'Modified' : 'Saved'}
+            {longLineMode === 'wrapped' ? 'Read-only (wrapped)' : hasChanges ? 'Modified' : 'Saved'}
           </span>
         </div>
       </div>
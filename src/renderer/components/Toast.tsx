@@ -69,17 +69,31 @@ function Toast({ toast, onDismiss }: ToastProps): React.ReactElement {
   );
 }
 
+/**
+ * Toasts shown at once; later ones wait in order for a slot
+ */
+export const MAX_VISIBLE_TOASTS = 4;
+
 interface ToastContainerProps {
   toasts: ToastMessage[];
   onDismiss: (id: string) => void;
 }
 
 export function ToastContainer({ toasts, onDismiss }: ToastContainerProps): React.ReactElement {
+  // A toast's timer starts when it mounts, so queued toasts keep their full duration
+  const visible = toasts.slice(0, MAX_VISIBLE_TOASTS);
+  const queued = toasts.length - visible.length;
+
   return (
     <div className="toast-container">
-      {toasts.map((toast) => (
+      {visible.map((toast) => (
         <Toast key={toast.id} toast={toast} onDismiss={onDismiss} />
       ))}
+      {queued > 0 && (
+        <div className="toast-queue-count">
+          +{queued} more
+        </div>
+      )}
     </div>
   );
 }
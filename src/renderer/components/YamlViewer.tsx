@@ -1,10 +1,13 @@
 import React, { useState, useCallback, useEffect, useMemo } from 'react';
+import { parseYamlDocuments, type YamlNode, type YamlScalar } from '../utils/yamlParser';
 
 export interface YamlViewerProps {
   bucket: string;
   fileKey: string;
   fileName: string;
   fileSize: number;
+  /** Version to show instead of the latest one */
+  versionId?: string;
   onClose: () => void;
 }
 
@@ -13,6 +16,11 @@ export interface YamlViewerProps {
  */
 const MAX_YAML_SIZE = 10 * 1024 * 1024;
 
+/**
+ * View modes for the YAML viewer
+ */
+type ViewMode = 'text' | 'tree';
+
 /**
  * Format file size for display
  */
@@ -266,17 +274,123 @@ function getTokenClass(type: TokenType): string {
   }
 }
 
+/**
+ * CSS class for a parsed scalar value
+ */
+function getScalarClass(value: YamlScalar): string {
+  if (value === null) return 'yaml-null';
+  if (typeof value === 'boolean') return 'yaml-boolean';
+  if (typeof value === 'number') return 'yaml-number';
+  return 'yaml-string';
+}
+
+function formatScalar(value: YamlScalar): string {
+  if (value === null) return 'null';
+  if (typeof value === 'string') return JSON.stringify(value);
+  return String(value);
+}
+
+/**
+ * Tag, anchor and alias markers shown next to a tree node's name
+ */
+function YamlNodeAnnotations({ node }: { node: YamlNode }): React.ReactElement {
+  return (
+    <>
+      {node.tag && <span className="yaml-tag yaml-tree-annotation" title="Tag">{node.tag}</span>}
+      {node.anchor && <span className="yaml-anchor yaml-tree-annotation" title="Anchor">&amp;{node.anchor}</span>}
+      {node.alias && (
+        <span className="yaml-alias yaml-tree-annotation" title={`Resolved from alias *${node.alias}`}>
+          *{node.alias}
+        </span>
+      )}
+    </>
+  );
+}
+
+/**
+ * A collapsible tree node for a parsed YAML value
+ */
+function YamlTreeNode({
+  name,
+  node,
+  level,
+  defaultExpanded = false,
+}: {
+  name: string;
+  node: YamlNode;
+  level: number;
+  defaultExpanded?: boolean;
+}): React.ReactElement {
+  const [isExpanded, setIsExpanded] = useState(defaultExpanded);
+  const indent = level * 16;
+
+  if (node.kind === 'scalar') {
+    return (
+      <div className="yaml-tree-line" style={{ paddingLeft: `${indent + 20}px` }}>
+        <span className="yaml-key">{name}</span>
+        <span className="yaml-tree-colon">: </span>
+        <YamlNodeAnnotations node={node} />
+        <span className={getScalarClass(node.value)}>{formatScalar(node.value)}</span>
+      </div>
+    );
+  }
+
+  const children: Array<[string, YamlNode]> = node.kind === 'map'
+    ? node.entries.map(entry => [entry.key, entry.value])
+    : node.items.map((item, i) => [`[${i}]`, item]);
+  const summary = node.kind === 'map' ? `${children.length} keys` : `${children.length} items`;
+
+  return (
+    <div className="yaml-tree-node">
+      <div
+        className="yaml-tree-line yaml-tree-collapsible"
+        style={{ paddingLeft: `${indent}px` }}
+        onClick={() => setIsExpanded(!isExpanded)}
+        role="button"
+        tabIndex={0}
+        onKeyDown={(e) => {
+          if (e.key === 'Enter' || e.key === ' ') {
+            setIsExpanded(!isExpanded);
+          }
+        }}
+      >
+        <span className={`yaml-tree-toggle ${isExpanded ? 'expanded' : ''}`}>
+          {children.length > 0 ? (isExpanded ? '▼' : '▶') : ''}
+        </span>
+        <span className="yaml-key">{name}</span>
+        <span className="yaml-tree-colon">: </span>
+        <YamlNodeAnnotations node={node} />
+        <span className="yaml-tree-hint">{summary}</span>
+      </div>
+      {isExpanded &&
+        children.map(([key, child], idx) => (
+          <YamlTreeNode
+            key={`${idx}-${key}`}
+            name={key}
+            node={child}
+            level={level + 1}
+            defaultExpanded={level < 1}
+          />
+        ))}
+    </div>
+  );
+}
+
 function YamlViewer({
   bucket,
   fileKey,
   fileName,
   fileSize,
+  versionId,
   onClose,
 }: YamlViewerProps): React.ReactElement {
   const [loading, setLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
+  // Codec the content was decompressed from, shown in the footer
+  const [compression, setCompression] = useState<string | null>(null);
   const [content, setContent] = useState<string>('');
   const [searchTerm, setSearchTerm] = useState('');
+  const [viewMode, setViewMode] = useState<ViewMode>('text');
 
   // Load YAML file on mount
   useEffect(() => {
@@ -295,7 +409,7 @@ function YamlViewer({
         }
 
         // Download the file as text
-        const result = await window.electronAPI.s3.downloadContent(bucket, fileKey);
+        const result = await window.electronAPI.s3.downloadContent(bucket, fileKey, versionId);
         if (!result.success) {
           throw new Error(result.error || 'Failed to download file');
         }
@@ -306,6 +420,7 @@ function YamlViewer({
 
         if (!mounted) return;
 
+        setCompression(result.compression ?? null);
         setContent(result.content);
       } catch (err) {
         if (mounted) {
@@ -323,7 +438,7 @@ function YamlViewer({
     return () => {
       mounted = false;
     };
-  }, [bucket, fileKey, fileSize]);
+  }, [bucket, fileKey, fileSize, versionId]);
 
   // Stats about the YAML content
   const yamlStats = useMemo(() => {
@@ -342,6 +457,16 @@ function YamlViewer({
     return { lines: lines.length, keys: keyCount };
   }, [content]);
 
+  // Parsed documents for the tree view; parse errors are shown in place of the tree
+  const parsed = useMemo(() => {
+    if (!content) return { documents: [] as YamlNode[], error: null };
+    try {
+      return { documents: parseYamlDocuments(content), error: null };
+    } catch (err) {
+      return { documents: [] as YamlNode[], error: err instanceof Error ? err.message : 'Invalid YAML' };
+    }
+  }, [content]);
+
   const handleKeyDown = useCallback(
     (e: React.KeyboardEvent) => {
       if (e.key === 'Escape') {
@@ -428,10 +553,31 @@ function YamlViewer({
                 <span className="yaml-viewer-meta-item">
                   {yamlStats.keys} keys
                 </span>
+                {parsed.documents.length > 1 && (
+                  <span className="yaml-viewer-meta-item">
+                    {parsed.documents.length} documents
+                  </span>
+                )}
               </>
             )}
             <span className="yaml-viewer-meta-item">{formatSize(fileSize)}</span>
           </div>
+          <div className="yaml-viewer-view-toggle">
+            <button
+              className={`yaml-viewer-toggle-btn ${viewMode === 'text' ? 'active' : ''}`}
+              onClick={() => setViewMode('text')}
+              title="Text view"
+            >
+              Text
+            </button>
+            <button
+              className={`yaml-viewer-toggle-btn ${viewMode === 'tree' ? 'active' : ''}`}
+              onClick={() => setViewMode('tree')}
+              title="Tree view"
+            >
+              Tree
+            </button>
+          </div>
           <div className="yaml-viewer-actions">
             <button
               className="yaml-viewer-btn yaml-viewer-btn-close"
@@ -444,7 +590,7 @@ function YamlViewer({
         </div>
 
         {/* Search bar */}
-        {content && (
+        {content && viewMode === 'text' && (
           <div className="yaml-viewer-search">
             <input
               type="text"
@@ -491,6 +637,18 @@ function YamlViewer({
               <span className="loading-spinner"></span>
               <span>Loading YAML file...</span>
             </div>
+          ) : content && viewMode === 'tree' ? (
+            <div className="yaml-viewer-tree">
+              {parsed.error ? (
+                <div className="yaml-tree-error">Cannot show tree: {parsed.error}</div>
+              ) : parsed.documents.length === 1 ? (
+                <YamlTreeNode name="root" node={parsed.documents[0]} level={0} defaultExpanded={true} />
+              ) : (
+                parsed.documents.map((doc, i) => (
+                  <YamlTreeNode key={i} name={`Document ${i + 1}`} node={doc} level={0} defaultExpanded={true} />
+                ))
+              )}
+            </div>
           ) : content ? (
             <div className="yaml-viewer-text">
               <pre className="yaml-viewer-pre">
@@ -505,6 +663,11 @@ function YamlViewer({
           <span className="yaml-viewer-path" title={`s3://${bucket}/${fileKey}`}>
             s3://{bucket}/{fileKey}
           </span>
+          {compression && (
+            <span className="viewer-compression" title="Decompressed for viewing">
+              {compression}
+            </span>
+          )}
           <span className="yaml-viewer-status">
             {content
               ? `${yamlStats.lines} lines, ${yamlStats.keys} keys`
@@ -6,16 +6,29 @@ export type ProfileType =
   | 'sso'          // SSO-based authentication
   | 'process'      // External credential process
   | 'web-identity' // Web identity token (EKS, etc.)
-  | 'config-only'; // Has region/output but no credentials
+  | 'config-only'  // Has region/output but no credentials
+  | 'anonymous';   // Unsigned requests, for publicly readable buckets
+
+/** Whether an SSO profile's cached token can still be used */
+export type SsoTokenStatus =
+  | { state: 'valid'; expiresAt: Date }
+  | { state: 'expired'; expiresAt: Date }
+  | { state: 'missing' };
+
+/** Name of the built-in profile that browses public buckets without credentials */
+export const ANONYMOUS_PROFILE_NAME = '(anonymous)';
 
 export interface ProfileInfo {
   name: string;
   region?: string;
+  /** The region requests are made in, which AWS_REGION overrides the profile's setting for */
+  resolvedRegion?: string;
   hasCredentials: boolean;
   isValid: boolean;
   validationMessage?: string;
   profileType: ProfileType;
   profileTypeDescription: string;
+  ssoTokenStatus?: SsoTokenStatus;
 }
 
 export interface AwsProfileContextValue {
@@ -117,6 +130,13 @@ export function AwsProfileProvider({ children }: { children: ReactNode }): React
     loadProfiles();
   }, [loadProfiles]);
 
+  // Pick up profiles edited in another window, or a token refreshed by `aws sso login`
+  useEffect(() => {
+    return window.electronAPI.aws.onProfilesChanged(() => {
+      refreshProfiles();
+    });
+  }, [refreshProfiles]);
+
   const value: AwsProfileContextValue = {
     profiles,
     currentProfile,
@@ -0,0 +1,34 @@
+import { useState, useEffect } from 'react';
+
+export type ColorScheme = 'light' | 'dark';
+
+// The main process applies the theme setting as the native theme, which this query follows
+const LIGHT_SCHEME_QUERY = '(prefers-color-scheme: light)';
+
+function currentColorScheme(): ColorScheme {
+  if (typeof window === 'undefined' || typeof window.matchMedia !== 'function') {
+    return 'dark';
+  }
+  return window.matchMedia(LIGHT_SCHEME_QUERY).matches ? 'light' : 'dark';
+}
+
+/**
+ * Whether the app is showing its light or dark colours, for components that
+ * can't be styled from the stylesheet (e.g. the Monaco editor)
+ */
+export function useColorScheme(): ColorScheme {
+  const [scheme, setScheme] = useState<ColorScheme>(currentColorScheme);
+
+  useEffect(() => {
+    if (typeof window.matchMedia !== 'function') return;
+
+    const query = window.matchMedia(LIGHT_SCHEME_QUERY);
+    const handleChange = () => setScheme(query.matches ? 'light' : 'dark');
+    query.addEventListener('change', handleChange);
+    return () => query.removeEventListener('change', handleChange);
+  }, []);
+
+  return scheme;
+}
+
+export default useColorScheme;
@@ -0,0 +1,74 @@
+import { useState, useEffect, useCallback, useMemo, useRef } from 'react';
+import {
+  updateViewerLayouts,
+  viewerLayoutKey,
+  visibleColumns,
+  type ViewerLayout,
+} from '../utils/columnVisibility';
+
+export interface ColumnVisibility {
+  /** One flag per column name, in column order */
+  visible: boolean[];
+  /** Show or hide a column by name (persisted for this object) */
+  setColumnVisible: (name: string, shown: boolean) => void;
+  /** Show every column again */
+  showAllColumns: () => void;
+}
+
+/**
+ * Tracks which columns of an object are hidden in the viewer, remembered per
+ * object in the app state's viewer layouts.
+ */
+export function useColumnVisibility(bucket: string, fileKey: string, columnNames: string[]): ColumnVisibility {
+  const objectId = viewerLayoutKey(bucket, fileKey);
+  const [hiddenColumns, setHiddenColumns] = useState<string[]>([]);
+  const layoutsRef = useRef<Record<string, ViewerLayout>>({});
+
+  useEffect(() => {
+    let mounted = true;
+    setHiddenColumns([]);
+    window.electronAPI.appState
+      .load()
+      .then(state => {
+        if (mounted) {
+          layoutsRef.current = state.viewerLayouts ?? {};
+          setHiddenColumns(layoutsRef.current[objectId]?.hiddenColumns ?? []);
+        }
+      })
+      .catch(err => {
+        console.warn('Failed to load viewer layout:', err);
+      });
+    return () => {
+      mounted = false;
+    };
+  }, [objectId]);
+
+  const saveHiddenColumns = useCallback(
+    (next: string[]) => {
+      setHiddenColumns(next);
+      layoutsRef.current = updateViewerLayouts(layoutsRef.current, objectId, next);
+      window.electronAPI.appState.save({ viewerLayouts: layoutsRef.current }).catch(err => {
+        console.warn('Failed to save viewer layout:', err);
+      });
+    },
+    [objectId]
+  );
+
+  const setColumnVisible = useCallback(
+    (name: string, shown: boolean) => {
+      const others = hiddenColumns.filter(hidden => hidden !== name);
+      saveHiddenColumns(shown ? others : [...others, name]);
+    },
+    [hiddenColumns, saveHiddenColumns]
+  );
+
+  const showAllColumns = useCallback(() => {
+    saveHiddenColumns([]);
+  }, [saveHiddenColumns]);
+
+  const visible = useMemo(() => visibleColumns(columnNames, hiddenColumns), [columnNames, hiddenColumns]);
+
+  return { visible, setColumnVisible, showAllColumns };
+}
+
+export default useColumnVisibility;
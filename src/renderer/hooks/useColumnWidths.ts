@@ -0,0 +1,56 @@
+import { useState, useEffect, useCallback, useMemo, useRef } from 'react';
+import { clampColumnWidth, columnWidthsKey, type SchemaColumn } from '../utils/columnWidths';
+
+export interface ColumnWidths {
+  /** Width in pixels of each column the user has resized, by column name */
+  widths: Record<string, number>;
+  /** Set a column's width while it is being dragged (not persisted) */
+  resizeColumn: (name: string, width: number) => void;
+  /** Persist the current widths, once a drag ends */
+  saveColumnWidths: () => void;
+}
+
+/**
+ * Tracks the column widths the user has dragged in the viewer, remembered per
+ * file and schema in the app state's column widths.
+ */
+export function useColumnWidths(bucket: string, fileKey: string, columns: SchemaColumn[]): ColumnWidths {
+  const signature = useMemo(() => columnWidthsKey(bucket, fileKey, columns), [bucket, fileKey, columns]);
+  const [widths, setWidths] = useState<Record<string, number>>({});
+  const widthsRef = useRef<Record<string, number>>({});
+
+  useEffect(() => {
+    let mounted = true;
+    widthsRef.current = {};
+    setWidths({});
+    window.electronAPI.appState
+      .load()
+      .then(state => {
+        if (mounted) {
+          widthsRef.current = state.columnWidths?.[signature] ?? {};
+          setWidths(widthsRef.current);
+        }
+      })
+      .catch(err => {
+        console.warn('Failed to load column widths:', err);
+      });
+    return () => {
+      mounted = false;
+    };
+  }, [signature]);
+
+  const resizeColumn = useCallback((name: string, width: number) => {
+    widthsRef.current = { ...widthsRef.current, [name]: clampColumnWidth(width) };
+    setWidths(widthsRef.current);
+  }, []);
+
+  const saveColumnWidths = useCallback(() => {
+    window.electronAPI.appState.setColumnWidths(signature, widthsRef.current).catch(err => {
+      console.warn('Failed to save column widths:', err);
+    });
+  }, [signature]);
+
+  return { widths, resizeColumn, saveColumnWidths };
+}
+
+export default useColumnWidths;
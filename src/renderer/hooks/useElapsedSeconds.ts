@@ -0,0 +1,31 @@
+import { useState, useEffect } from 'react';
+
+/**
+ * Counts whole seconds while `active` is true.
+ * Resets to 0 and stops ticking as soon as `active` becomes false.
+ * A single interval is kept per hook instance; restarting replaces the previous one.
+ */
+export function useElapsedSeconds(active: boolean): number {
+  const [elapsedSeconds, setElapsedSeconds] = useState(0);
+
+  useEffect(() => {
+    setElapsedSeconds(0);
+    if (!active) {
+      return;
+    }
+
+    const startedAt = Date.now();
+    const intervalId = setInterval(() => {
+      // Derive from the start time so a delayed tick doesn't drift the counter
+      setElapsedSeconds(Math.floor((Date.now() - startedAt) / 1000));
+    }, 1000);
+
+    return () => {
+      clearInterval(intervalId);
+    };
+  }, [active]);
+
+  return elapsedSeconds;
+}
+
+export default useElapsedSeconds;
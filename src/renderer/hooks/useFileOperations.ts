@@ -1,5 +1,6 @@
-import { useState, useCallback } from 'react';
+import { useState, useCallback, useEffect, useRef } from 'react';
 import type { Operation } from '../components/OperationStatus';
+import { TransferStats } from '../utils/transferStats';
 
 let operationCounter = 0;
 
@@ -29,15 +30,26 @@ export interface DeletePrefixResult {
   error?: string;
 }
 
+export interface MovePrefixResult {
+  success: boolean;
+  movedCount: number;
+  failures: Array<{ key: string; error: string }>;
+  error?: string;
+}
+
 export interface UseFileOperationsResult {
   operations: Operation[];
   isLoading: boolean;
   downloadFile: (bucket: string, key: string) => Promise<void>;
+  downloadFiles: (bucket: string, keys: string[]) => Promise<void>;
+  openExternally: (bucket: string, key: string) => Promise<void>;
   uploadFiles: (bucket: string, prefix: string, filePaths?: string[]) => Promise<void>;
   deleteFile: (bucket: string, key: string) => Promise<boolean>;
   deleteFiles: (bucket: string, keys: string[]) => Promise<DeleteFilesResult>;
   deletePrefix: (bucket: string, prefix: string) => Promise<DeletePrefixResult>;
   renameFile: (bucket: string, sourceKey: string, newName: string) => Promise<boolean>;
+  renamePrefix: (bucket: string, prefix: string, newName: string) => Promise<MovePrefixResult>;
+  cancelOperation: (id: string) => void;
   dismissOperation: (id: string) => void;
   clearCompleted: () => void;
 }
@@ -47,7 +59,7 @@ export function useFileOperations(options: UseFileOperationsOptions = {}): UseFi
   const [operations, setOperations] = useState<Operation[]>([]);
   const [isLoading, setIsLoading] = useState(false);
 
-  const addOperation = useCallback((type: 'upload' | 'download', fileName: string): string => {
+  const addOperation = useCallback((type: Operation['type'], fileName: string): string => {
     const id = generateOperationId();
     setOperations((prev) => [
       ...prev,
@@ -65,6 +77,28 @@ export function useFileOperations(options: UseFileOperationsOptions = {}): UseFi
     []
   );
 
+  // Speed/ETA accumulators for transfers that have reported progress
+  const transferStats = useRef(new Map<string, TransferStats>());
+  // Operations whose progress counts objects rather than bytes
+  const objectCountOperations = useRef(new Set<string>());
+
+  useEffect(() => {
+    return window.electronAPI.s3.onTransferProgress(({ operationId, loaded, total }) => {
+      if (objectCountOperations.current.has(operationId)) {
+        updateOperation(operationId, { loaded, total, note: `${loaded} of ${total} objects` });
+        return;
+      }
+      const now = Date.now();
+      let stats = transferStats.current.get(operationId);
+      if (!stats) {
+        stats = new TransferStats(total, now);
+        transferStats.current.set(operationId, stats);
+      }
+      const { speedBps, etaSeconds } = stats.update(loaded, now);
+      updateOperation(operationId, { loaded, total, speedBps, etaSeconds });
+    });
+  }, [updateOperation]);
+
   const dismissOperation = useCallback((id: string) => {
     setOperations((prev) => prev.filter((op) => op.id !== id));
   }, []);
@@ -83,8 +117,13 @@ export function useFileOperations(options: UseFileOperationsOptions = {}): UseFi
 
     try {
       const result = await window.electronAPI.s3.downloadFile(bucket, key, opId);
+      transferStats.current.delete(opId);
 
-      if (result.success) {
+      if (result.success && result.skipped) {
+        // The conflict policy kept an existing local file
+        updateOperation(opId, { status: 'completed', note: 'Skipped: file already exists' });
+        setTimeout(() => dismissOperation(opId), 3000);
+      } else if (result.success) {
         updateOperation(opId, { status: 'completed' });
         // Auto-dismiss after 3 seconds
         setTimeout(() => dismissOperation(opId), 3000);
@@ -103,6 +142,75 @@ export function useFileOperations(options: UseFileOperationsOptions = {}): UseFi
     }
   }, [addOperation, updateOperation, dismissOperation, onDownloadComplete]);
 
+  const downloadFiles = useCallback(async (bucket: string, keys: string[]) => {
+    const ops = keys.map((key) => {
+      const fileName = key.split('/').pop() || key;
+      return { fileName, id: addOperation('download', fileName) };
+    });
+    for (const { id } of ops) {
+      updateOperation(id, { status: 'in-progress' });
+    }
+
+    try {
+      const result = await window.electronAPI.s3.downloadFiles(bucket, keys, generateOperationId());
+      const downloaded: DownloadCompleteInfo[] = [];
+
+      result.results.forEach((r, idx) => {
+        const op = ops[idx];
+        if (r.success && r.skipped) {
+          updateOperation(op.id, { status: 'completed', note: 'Skipped: file already exists' });
+          setTimeout(() => dismissOperation(op.id), 3000);
+        } else if (r.success) {
+          updateOperation(op.id, { status: 'completed' });
+          setTimeout(() => dismissOperation(op.id), 3000);
+          if (r.localPath) {
+            downloaded.push({ fileName: op.fileName, localPath: r.localPath });
+          }
+        } else {
+          updateOperation(op.id, { status: 'error', error: r.error });
+        }
+      });
+
+      // One notification for the batch; the files all land in the same folder
+      if (downloaded.length > 0 && onDownloadComplete) {
+        onDownloadComplete(
+          downloaded.length === 1
+            ? downloaded[0]
+            : { fileName: `${downloaded.length} files`, localPath: downloaded[0].localPath }
+        );
+      }
+    } catch (error) {
+      const message = error instanceof Error ? error.message : 'Download failed';
+      for (const { id } of ops) {
+        updateOperation(id, { status: 'error', error: message });
+      }
+    }
+  }, [addOperation, updateOperation, dismissOperation, onDownloadComplete]);
+
+  const openExternally = useCallback(async (bucket: string, key: string) => {
+    const fileName = key.split('/').pop() || key;
+    const opId = addOperation('download', fileName);
+
+    updateOperation(opId, { status: 'in-progress' });
+
+    try {
+      const result = await window.electronAPI.s3.openExternally(bucket, key, opId);
+      transferStats.current.delete(opId);
+
+      if (result.success) {
+        updateOperation(opId, { status: 'completed', note: 'Opened with default app' });
+        setTimeout(() => dismissOperation(opId), 3000);
+      } else {
+        updateOperation(opId, { status: 'error', error: result.error });
+      }
+    } catch (error) {
+      updateOperation(opId, {
+        status: 'error',
+        error: error instanceof Error ? error.message : 'Open failed',
+      });
+    }
+  }, [addOperation, updateOperation, dismissOperation]);
+
   const uploadFiles = useCallback(
     async (bucket: string, prefix: string, filePaths?: string[]) => {
       setIsLoading(true);
@@ -224,15 +332,52 @@ export function useFileOperations(options: UseFileOperationsOptions = {}): UseFi
     []
   );
 
+  const renamePrefix = useCallback(
+    async (bucket: string, prefix: string, newName: string): Promise<MovePrefixResult> => {
+      const folderName = prefix.replace(/\/$/, '').split('/').pop() || prefix;
+      const opId = addOperation('rename', `${folderName}/`);
+      objectCountOperations.current.add(opId);
+      updateOperation(opId, { status: 'in-progress', cancellable: true });
+      setIsLoading(true);
+
+      try {
+        const result = await window.electronAPI.s3.renamePrefix(bucket, prefix, newName, opId);
+        if (result.success) {
+          updateOperation(opId, { status: 'completed', note: `Moved ${result.movedCount} item(s)` });
+          setTimeout(() => dismissOperation(opId), 3000);
+        } else {
+          updateOperation(opId, { status: 'error', error: result.error ?? `${result.failures.length} item(s) failed` });
+        }
+        return result;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : 'Rename failed';
+        updateOperation(opId, { status: 'error', error: message });
+        return { success: false, movedCount: 0, failures: [], error: message };
+      } finally {
+        objectCountOperations.current.delete(opId);
+        setIsLoading(false);
+      }
+    },
+    [addOperation, updateOperation, dismissOperation]
+  );
+
+  const cancelOperation = useCallback((id: string) => {
+    window.electronAPI.s3.cancelOperation(id);
+  }, []);
+
   return {
     operations,
     isLoading,
     downloadFile,
+    downloadFiles,
+    openExternally,
     uploadFiles,
     deleteFile,
     deleteFiles,
     deletePrefix,
     renameFile,
+    renamePrefix,
+    cancelOperation,
     dismissOperation,
     clearCompleted,
   };
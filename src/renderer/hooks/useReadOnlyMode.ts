@@ -0,0 +1,66 @@
+import { useState, useEffect, useCallback } from 'react';
+import { ANONYMOUS_PROFILE_NAME } from '../context/AwsProfileContext';
+
+export interface ReadOnlyMode {
+  /** Whether read-only mode is enabled for the current profile */
+  isReadOnly: boolean;
+  /** Enable or disable read-only mode for the current profile (persisted) */
+  setReadOnly: (enabled: boolean) => void;
+  /** Re-read the setting after the app state was changed elsewhere (e.g. by an import) */
+  reload: () => void;
+}
+
+/**
+ * Tracks the per-profile read-only setting stored in app state.
+ * The main process enforces the same setting on every mutating IPC call.
+ * The anonymous profile can't write at all, so it is always read-only.
+ */
+export function useReadOnlyMode(currentProfile: string | null): ReadOnlyMode {
+  const [readOnlyProfiles, setReadOnlyProfiles] = useState<string[]>([]);
+
+  const [loadCount, setLoadCount] = useState(0);
+
+  useEffect(() => {
+    let mounted = true;
+    window.electronAPI.appState
+      .load()
+      .then(state => {
+        if (mounted) {
+          setReadOnlyProfiles(state.readOnlyProfiles ?? []);
+        }
+      })
+      .catch(err => {
+        console.warn('Failed to load read-only settings:', err);
+      });
+    return () => {
+      mounted = false;
+    };
+  }, [loadCount]);
+
+  const reload = useCallback(() => setLoadCount(count => count + 1), []);
+
+  const setReadOnly = useCallback(
+    (enabled: boolean) => {
+      if (!currentProfile) return;
+      setReadOnlyProfiles(prev => {
+        const others = prev.filter(name => name !== currentProfile);
+        const next = enabled ? [...others, currentProfile] : others;
+        window.electronAPI.appState.save({ readOnlyProfiles: next }).catch(err => {
+          console.warn('Failed to save read-only settings:', err);
+        });
+        return next;
+      });
+    },
+    [currentProfile]
+  );
+
+  return {
+    isReadOnly:
+      currentProfile !== null &&
+      (currentProfile === ANONYMOUS_PROFILE_NAME || readOnlyProfiles.includes(currentProfile)),
+    setReadOnly,
+    reload,
+  };
+}
+
+export default useReadOnlyMode;
@@ -11,6 +11,7 @@ export interface S3Object {
   lastModified?: Date;
   etag?: string;
   storageClass?: string;
+  contentType?: string;
   isPrefix: boolean;
 }
 
@@ -114,7 +115,8 @@ export function useS3(currentProfile: string | null): UseS3Result {
 
         const data = result.result!;
         continuationTokenRef.current = data.continuationToken;
-        setHasMore(data.isTruncated);
+        // Without a token there is no way to load more, whatever isTruncated says
+        setHasMore(data.isTruncated && !!data.continuationToken);
 
         if (reset) {
           setPrefixes(data.prefixes);
@@ -0,0 +1,55 @@
+/**
+ * The parts of js-yaml used by the YAML viewer (the package ships no types)
+ */
+declare module 'js-yaml' {
+  export type TypeKind = 'scalar' | 'sequence' | 'mapping';
+
+  export interface TypeOptions {
+    kind: TypeKind;
+    /** Match every tag starting with the type's tag instead of only the tag itself */
+    multi?: boolean;
+    resolve?: (data: unknown) => boolean;
+    construct?: (data: unknown, tag?: string) => unknown;
+  }
+
+  export class Type {
+    constructor(tag: string, options: TypeOptions);
+    tag: string;
+    kind: TypeKind;
+  }
+
+  export class Schema {
+    extend(types: Type | Type[]): Schema;
+  }
+
+  export const FAILSAFE_SCHEMA: Schema;
+  export const JSON_SCHEMA: Schema;
+  export const CORE_SCHEMA: Schema;
+  export const DEFAULT_SCHEMA: Schema;
+
+  /** Loader state passed to a listener as each node is opened and closed */
+  export interface State {
+    position: number;
+    line: number;
+    kind: string | null;
+    tag: string | null;
+    anchor: string | null;
+    result: unknown;
+  }
+
+  export interface LoadOptions {
+    filename?: string;
+    schema?: Schema;
+    json?: boolean;
+    onWarning?: (warning: YAMLException) => void;
+    listener?: (eventType: 'open' | 'close', state: State) => void;
+  }
+
+  export class YAMLException extends Error {
+    reason: string;
+    mark?: { name: string | null; position: number; line: number; column: number };
+  }
+
+  export function load(str: string, options?: LoadOptions): unknown;
+  export function loadAll(str: string, iterator?: null, options?: LoadOptions): unknown[];
+}
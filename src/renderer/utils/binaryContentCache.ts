@@ -0,0 +1,82 @@
+/**
+ * In-memory cache of downloaded objects, revalidated with a conditional GET so
+ * reopening an unchanged file doesn't download it again
+ */
+
+import { LruCache } from './thumbnailCache';
+
+/** Number of downloaded objects kept in memory */
+export const BINARY_CACHE_SIZE = 4;
+
+/** Largest object kept in memory (32MB); bigger ones are downloaded every time */
+export const BINARY_CACHE_MAX_BYTES = 32 * 1024 * 1024;
+
+export interface CachedBinary {
+  data: Uint8Array;
+  etag?: string;
+  lastModified?: Date;
+}
+
+/**
+ * Shared cache of downloaded objects keyed by bucket and key
+ */
+export const binaryContentCache = new LruCache<CachedBinary>(BINARY_CACHE_SIZE);
+
+function cacheKey(bucket: string, key: string, versionId?: string): string {
+  return versionId ? `${bucket}/${key}?versionId=${versionId}` : `${bucket}/${key}`;
+}
+
+/**
+ * Downloads an object's content, reusing the cached copy when S3 reports it
+ * unchanged. Only responses that carry a validator (ETag or Last-Modified) are
+ * cached, since without one there is nothing to revalidate against. A specific
+ * version never changes, so its cached copy is reused without asking S3.
+ * @param bucket - The S3 bucket name
+ * @param key - The S3 object key
+ * @param cache - Cache holding earlier downloads
+ * @param versionId - Version to load instead of the latest one
+ */
+export async function loadBinaryContent(
+  bucket: string,
+  key: string,
+  cache: LruCache<CachedBinary> = binaryContentCache,
+  versionId?: string
+): Promise<{ success: boolean; data?: Uint8Array; fromCache?: boolean; error?: string }> {
+  const id = cacheKey(bucket, key, versionId);
+  const cached = cache.get(id);
+
+  if (versionId) {
+    if (cached) {
+      return { success: true, data: cached.data, fromCache: true };
+    }
+    const result = await window.electronAPI.s3.downloadBinaryContent(bucket, key, versionId);
+    if (!result.success) {
+      return { success: false, error: result.error };
+    }
+    if (result.data && result.data.length <= BINARY_CACHE_MAX_BYTES) {
+      cache.set(id, { data: result.data, etag: result.etag, lastModified: result.lastModified });
+    }
+    return { success: true, data: result.data };
+  }
+
+  const result = cached
+    ? await window.electronAPI.s3.downloadBinaryContentIfModified(bucket, key, {
+        etag: cached.etag,
+        modifiedSince: cached.lastModified,
+      })
+    : await window.electronAPI.s3.downloadBinaryContent(bucket, key);
+
+  if (!result.success) {
+    return { success: false, error: result.error };
+  }
+  if (result.notModified && cached) {
+    return { success: true, data: cached.data, fromCache: true };
+  }
+
+  if (result.data && (result.etag || result.lastModified) && result.data.length <= BINARY_CACHE_MAX_BYTES) {
+    cache.set(id, { data: result.data, etag: result.etag, lastModified: result.lastModified });
+  } else {
+    cache.delete(id);
+  }
+  return { success: true, data: result.data };
+}
@@ -0,0 +1,119 @@
+/**
+ * Value histograms for quick column profiling in the Parquet viewer
+ */
+
+/** Default number of bins for numeric columns */
+export const DEFAULT_HISTOGRAM_BINS = 10;
+
+/** Default number of categories shown for string and other columns */
+export const DEFAULT_TOP_CATEGORIES = 5;
+
+export interface HistogramBin {
+  start: number;
+  end: number;
+  count: number;
+}
+
+export interface HistogramCategory {
+  value: string;
+  count: number;
+}
+
+export type Histogram =
+  | {
+      kind: 'numeric';
+      bins: HistogramBin[];
+      min: number;
+      max: number;
+      nullCount: number;
+    }
+  | {
+      kind: 'categorical';
+      categories: HistogramCategory[];
+      /** Values outside the top categories */
+      otherCount: number;
+      distinctCount: number;
+      nullCount: number;
+    }
+  | {
+      /** Every value is null */
+      kind: 'empty';
+      nullCount: number;
+    };
+
+export interface HistogramOptions {
+  bins?: number;
+  topCategories?: number;
+  /** Turns a non-numeric value into its category label */
+  formatValue?: (value: unknown) => string;
+}
+
+function toNumber(value: unknown): number | null {
+  if (typeof value === 'number') return Number.isFinite(value) ? value : null;
+  if (typeof value === 'bigint') return Number(value);
+  return null;
+}
+
+/**
+ * Computes a histogram of one column's values. Columns whose non-null values are
+ * all numbers (or bigints) get equal-width bins from min to max; anything else
+ * gets its most frequent values. A numeric column with a single distinct value
+ * gets one bin.
+ * @param values - The column's values, in row order
+ */
+export function computeHistogram(values: unknown[], options: HistogramOptions = {}): Histogram {
+  const binCount = Math.max(1, options.bins ?? DEFAULT_HISTOGRAM_BINS);
+  const topCategories = Math.max(1, options.topCategories ?? DEFAULT_TOP_CATEGORIES);
+  const formatValue = options.formatValue ?? String;
+
+  const present = values.filter(value => value !== null && value !== undefined);
+  const nullCount = values.length - present.length;
+  if (present.length === 0) {
+    return { kind: 'empty', nullCount };
+  }
+
+  const numbers = present.map(toNumber);
+  if (numbers.every(n => n !== null)) {
+    const nums = numbers as number[];
+    let min = nums[0];
+    let max = nums[0];
+    for (const n of nums) {
+      if (n < min) min = n;
+      if (n > max) max = n;
+    }
+
+    if (min === max) {
+      return { kind: 'numeric', bins: [{ start: min, end: max, count: nums.length }], min, max, nullCount };
+    }
+
+    const width = (max - min) / binCount;
+    const bins: HistogramBin[] = Array.from({ length: binCount }, (_, i) => ({
+      start: min + i * width,
+      end: i === binCount - 1 ? max : min + (i + 1) * width,
+      count: 0,
+    }));
+    for (const n of nums) {
+      // The maximum belongs to the last bin rather than starting a new one
+      bins[Math.min(Math.floor((n - min) / width), binCount - 1)].count++;
+    }
+    return { kind: 'numeric', bins, min, max, nullCount };
+  }
+
+  const counts = new Map<string, number>();
+  for (const value of present) {
+    const label = formatValue(value);
+    counts.set(label, (counts.get(label) ?? 0) + 1);
+  }
+  // Most frequent first; ties keep first-seen order
+  const sorted = [...counts.entries()].sort((a, b) => b[1] - a[1]);
+  const categories = sorted.slice(0, topCategories).map(([value, count]) => ({ value, count }));
+  const shownCount = categories.reduce((sum, category) => sum + category.count, 0);
+
+  return {
+    kind: 'categorical',
+    categories,
+    otherCount: present.length - shownCount,
+    distinctCount: counts.size,
+    nullCount,
+  };
+}
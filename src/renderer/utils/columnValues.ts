@@ -0,0 +1,29 @@
+/**
+ * Extracting a single column's values from the tabular viewers, e.g. to copy a list of IDs
+ */
+
+/**
+ * The text of one column in every row. Rows too short to have the column give
+ * an empty string, so the result always has one entry per row; a column index
+ * outside every row gives no values at all.
+ * @param rows - The rows, in display order
+ * @param columnIndex - Index of the column in each row
+ * @param formatValue - Turns a cell into text
+ */
+export function columnValues<T>(
+  rows: T[][],
+  columnIndex: number,
+  formatValue: (value: T) => string = String
+): string[] {
+  if (!Number.isInteger(columnIndex) || columnIndex < 0 || !rows.some(row => columnIndex < row.length)) {
+    return [];
+  }
+  return rows.map(row => (columnIndex < row.length ? formatValue(row[columnIndex]) : ''));
+}
+
+/**
+ * Drops repeated values, keeping the first occurrence of each in its original position
+ */
+export function uniqueValues(values: string[]): string[] {
+  return [...new Set(values)];
+}
@@ -0,0 +1,61 @@
+/**
+ * Column visibility for the tabular viewers (CSV and Parquet)
+ */
+
+/** Number of objects whose viewer layout is remembered */
+export const MAX_VIEWER_LAYOUTS = 200;
+
+export interface ViewerLayout {
+  hiddenColumns: string[];
+}
+
+/**
+ * Key under which an object's layout is stored
+ */
+export function viewerLayoutKey(bucket: string, key: string): string {
+  return `${bucket}/${key}`;
+}
+
+/**
+ * Visibility flags for each column, from the names the user has hidden
+ */
+export function visibleColumns(columnNames: string[], hiddenColumns: string[]): boolean[] {
+  const hidden = new Set(hiddenColumns);
+  return columnNames.map(name => !hidden.has(name));
+}
+
+/**
+ * Keeps only the visible columns, and the matching cells of each row, without
+ * touching the loaded data. A column with no visibility flag is shown, and cells
+ * past the end of a short row stay missing rather than being padded.
+ */
+export function projectVisible<C, R>(
+  columns: C[],
+  rows: R[][],
+  visible: boolean[]
+): { columns: C[]; rows: R[][] } {
+  if (visible.every(flag => flag)) {
+    return { columns, rows };
+  }
+  const isVisible = (_: unknown, index: number) => visible[index] !== false;
+  return {
+    columns: columns.filter(isVisible),
+    rows: rows.map(row => row.filter(isVisible)),
+  };
+}
+
+/**
+ * Stores an object's hidden columns, most recently changed last. Objects with
+ * nothing hidden are dropped, and the oldest are trimmed past MAX_VIEWER_LAYOUTS.
+ */
+export function updateViewerLayouts(
+  layouts: Record<string, ViewerLayout>,
+  objectId: string,
+  hiddenColumns: string[]
+): Record<string, ViewerLayout> {
+  const entries = Object.entries(layouts).filter(([id]) => id !== objectId);
+  if (hiddenColumns.length > 0) {
+    entries.push([objectId, { hiddenColumns }]);
+  }
+  return Object.fromEntries(entries.slice(-MAX_VIEWER_LAYOUTS));
+}
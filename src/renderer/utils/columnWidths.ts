@@ -0,0 +1,43 @@
+/**
+ * User-adjusted column widths for the Parquet viewer
+ */
+
+/** Narrowest a column can be dragged, in pixels */
+export const MIN_COLUMN_WIDTH = 60;
+
+/** Widest a column can be dragged, in pixels */
+export const MAX_COLUMN_WIDTH = 2000;
+
+export interface SchemaColumn {
+  name: string;
+  type: string;
+}
+
+/**
+ * 32-bit FNV-1a hash of a string, as 8 hex digits
+ */
+function hashString(text: string): string {
+  let hash = 0x811c9dc5;
+  for (let i = 0; i < text.length; i++) {
+    hash ^= text.charCodeAt(i);
+    hash = Math.imul(hash, 0x01000193);
+  }
+  return (hash >>> 0).toString(16).padStart(8, '0');
+}
+
+/**
+ * Key under which a file's column widths are stored. It includes a hash of the
+ * schema, so widths saved for an object are not applied once it is overwritten
+ * with different columns.
+ */
+export function columnWidthsKey(bucket: string, key: string, columns: SchemaColumn[]): string {
+  const schema = columns.map(column => `${column.name}:${column.type}`).join('\n');
+  return `${bucket}/${key}#${hashString(schema)}`;
+}
+
+/**
+ * Limits a dragged width to MIN_COLUMN_WIDTH..MAX_COLUMN_WIDTH, in whole pixels
+ */
+export function clampColumnWidth(width: number): number {
+  return Math.round(Math.min(MAX_COLUMN_WIDTH, Math.max(MIN_COLUMN_WIDTH, width)));
+}
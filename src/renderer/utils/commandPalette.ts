@@ -0,0 +1,179 @@
+/**
+ * Candidate commands and ranking for the command palette (Ctrl+P)
+ */
+
+import { fuzzyMatch } from './fuzzyMatch';
+
+/** Number of recent locations remembered per app state */
+export const MAX_RECENT_LOCATIONS = 10;
+
+/** Number of ranked results shown in the palette */
+export const MAX_PALETTE_RESULTS = 50;
+
+export interface RecentLocation {
+  profile: string;
+  bucket: string;
+  prefix: string;
+}
+
+export interface Bookmark extends RecentLocation {
+  label: string;
+}
+
+export type PaletteAction =
+  | 'refresh'
+  | 'upload'
+  | 'new-file'
+  | 'new-folder'
+  | 'copy-url'
+  | 'copy-path'
+  | 'open-console'
+  | 'incomplete-uploads'
+  | 'add-bookmark'
+  | 'remove-bookmark'
+  | 'settings';
+
+export type PaletteCommand =
+  | { kind: 'action'; id: string; label: string; action: PaletteAction }
+  | { kind: 'bucket'; id: string; label: string; bucket: string }
+  | { kind: 'location'; id: string; label: string; bucket: string; prefix: string };
+
+export interface PaletteState {
+  buckets: string[];
+  recentLocations: RecentLocation[];
+  bookmarks: Bookmark[];
+  currentProfile: string | null;
+  selectedBucket: string | null;
+  /** Whether the current folder is bookmarked */
+  isBookmarked: boolean;
+  /** Whether exactly one file (not a folder) is selected */
+  hasFileSelected: boolean;
+  isReadOnly: boolean;
+}
+
+export interface RankedCommand {
+  command: PaletteCommand;
+  /** Indices in the label of the matched characters */
+  positions: number[];
+}
+
+const ACTION_LABELS: Record<PaletteAction, string> = {
+  refresh: 'Refresh file list',
+  upload: 'Upload files',
+  'new-file': 'New file',
+  'new-folder': 'New folder',
+  'copy-url': 'Copy S3 URL of selected file',
+  'copy-path': 'Copy current path',
+  'open-console': 'Open in AWS Console',
+  'incomplete-uploads': 'Incomplete multipart uploads',
+  'add-bookmark': 'Bookmark current location',
+  'remove-bookmark': 'Remove bookmark of current location',
+  settings: 'Settings',
+};
+
+function isActionAvailable(action: PaletteAction, state: PaletteState): boolean {
+  switch (action) {
+    case 'upload':
+    case 'new-file':
+    case 'new-folder':
+      return !!state.selectedBucket && !state.isReadOnly;
+    case 'copy-url':
+      return !!state.selectedBucket && state.hasFileSelected;
+    case 'refresh':
+    case 'copy-path':
+    case 'open-console':
+    case 'incomplete-uploads':
+      return !!state.selectedBucket;
+    case 'add-bookmark':
+      return !!state.selectedBucket && !state.isBookmarked;
+    case 'remove-bookmark':
+      return state.isBookmarked;
+    case 'settings':
+      return true;
+  }
+}
+
+/**
+ * Builds everything the palette can act on right now: bookmarks and recent
+ * locations for the current profile, actions that apply to the current view,
+ * and bucket jumps
+ */
+export function commandPaletteCandidates(state: PaletteState): PaletteCommand[] {
+  const commands: PaletteCommand[] = [];
+
+  for (const bookmark of state.bookmarks) {
+    if (bookmark.profile !== state.currentProfile) continue;
+    commands.push({
+      kind: 'location',
+      id: `bookmark:${bookmark.bucket}/${bookmark.prefix}`,
+      label: `${bookmark.label} (s3://${bookmark.bucket}/${bookmark.prefix})`,
+      bucket: bookmark.bucket,
+      prefix: bookmark.prefix,
+    });
+  }
+
+  for (const location of state.recentLocations) {
+    if (location.profile !== state.currentProfile) continue;
+    commands.push({
+      kind: 'location',
+      id: `location:${location.bucket}/${location.prefix}`,
+      label: `s3://${location.bucket}/${location.prefix}`,
+      bucket: location.bucket,
+      prefix: location.prefix,
+    });
+  }
+
+  for (const action of Object.keys(ACTION_LABELS) as PaletteAction[]) {
+    if (isActionAvailable(action, state)) {
+      commands.push({ kind: 'action', id: `action:${action}`, label: ACTION_LABELS[action], action });
+    }
+  }
+
+  for (const bucket of state.buckets) {
+    commands.push({ kind: 'bucket', id: `bucket:${bucket}`, label: bucket, bucket });
+  }
+
+  return commands;
+}
+
+/**
+ * Ranks commands against the typed query. An empty query keeps the candidate
+ * order; otherwise non-matching commands are dropped and the rest sorted by
+ * match quality (ties keep candidate order).
+ */
+export function rankCommands(
+  commands: PaletteCommand[],
+  query: string,
+  limit: number = MAX_PALETTE_RESULTS
+): RankedCommand[] {
+  const ranked: Array<RankedCommand & { score: number; order: number }> = [];
+
+  commands.forEach((command, order) => {
+    const match = fuzzyMatch(command.label, query);
+    if (match) {
+      ranked.push({ command, positions: match.positions, score: match.score, order });
+    }
+  });
+
+  ranked.sort((a, b) => b.score - a.score || a.order - b.order);
+  return ranked.slice(0, limit).map(({ command, positions }) => ({ command, positions }));
+}
+
+/**
+ * Default label for a bookmark: the folder name, or the bucket name at its root
+ */
+export function bookmarkLabel(bucket: string, prefix: string): string {
+  const folders = prefix.split('/').filter(Boolean);
+  return folders.length > 0 ? folders[folders.length - 1] : bucket;
+}
+
+/**
+ * Moves a location to the front of the recent list, dropping duplicates and
+ * trimming to MAX_RECENT_LOCATIONS
+ */
+export function addRecentLocation(recent: RecentLocation[], location: RecentLocation): RecentLocation[] {
+  const others = recent.filter(
+    r => r.profile !== location.profile || r.bucket !== location.bucket || r.prefix !== location.prefix
+  );
+  return [location, ...others].slice(0, MAX_RECENT_LOCATIONS);
+}
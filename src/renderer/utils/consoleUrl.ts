@@ -0,0 +1,31 @@
+/**
+ * Utility functions for building links to the AWS web console
+ */
+
+/**
+ * Returns the S3 console host for a region.
+ * China and GovCloud partitions are served from their own console domains.
+ */
+export function getConsoleHost(region: string): string {
+  if (region.startsWith('cn-')) {
+    return 'https://console.amazonaws.cn';
+  }
+  if (region.startsWith('us-gov-')) {
+    return 'https://console.amazonaws-us-gov.com';
+  }
+  return 'https://s3.console.aws.amazon.com';
+}
+
+/**
+ * Builds the AWS console URL showing a bucket at the given prefix
+ * @param region - The bucket region (e.g., 'eu-west-1')
+ * @param bucket - The bucket name
+ * @param prefix - The folder prefix, empty for the bucket root
+ */
+export function consoleUrl(region: string, bucket: string, prefix: string): string {
+  let url = `${getConsoleHost(region)}/s3/buckets/${encodeURIComponent(bucket)}?region=${encodeURIComponent(region)}`;
+  if (prefix) {
+    url += `&prefix=${encodeURIComponent(prefix)}&showversions=false`;
+  }
+  return url;
+}
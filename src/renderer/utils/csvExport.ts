@@ -0,0 +1,91 @@
+/**
+ * Utility functions for writing tabular data as CSV
+ */
+
+/**
+ * How cells that a spreadsheet could evaluate as a formula are neutralized:
+ * - 'none': written as-is
+ * - 'apostrophe': prefixed with a single quote (hidden by most spreadsheets)
+ * - 'space': prefixed with a space
+ */
+export type CsvSafetyMode = 'none' | 'apostrophe' | 'space';
+
+/**
+ * Which fields are quoted:
+ * - 'minimal': only fields containing the delimiter, a quote or a line break
+ * - 'all': every field
+ */
+export type CsvQuoteStyle = 'minimal' | 'all';
+
+export type CsvLineTerminator = '\r\n' | '\n';
+
+export interface CsvWriteOptions {
+  delimiter?: string;
+  /** Defaults to LF; CRLF (as in RFC 4180) is offered for Windows tools that need it */
+  lineTerminator?: CsvLineTerminator;
+  quoteStyle?: CsvQuoteStyle;
+  /** Defaults to 'apostrophe', since exports usually end up in a spreadsheet */
+  safetyMode?: CsvSafetyMode;
+}
+
+/** Delimiters offered for exports */
+export const CSV_DELIMITERS: Array<{ value: string; label: string }> = [
+  { value: ',', label: 'Comma' },
+  { value: ';', label: 'Semicolon' },
+  { value: '\t', label: 'Tab' },
+  { value: '|', label: 'Pipe' },
+];
+
+// Leading characters that make spreadsheets treat a cell as a formula (CSV injection).
+// Tab and carriage return are included because some spreadsheets strip them before evaluating.
+const FORMULA_PREFIXES = ['=', '+', '-', '@', '\t', '\r'];
+
+// A plain signed number such as -5 or +1.2e3, which spreadsheets read as a number, not a formula
+const SIGNED_NUMBER = /^[+-](\d+(\.\d*)?|\.\d+)(e[+-]?\d+)?$/i;
+
+/**
+ * Neutralizes a cell that would be interpreted as a formula by spreadsheets
+ * Signed numbers are left as they are so they still export as numbers.
+ * @param value - The cell text
+ * @param mode - How to neutralize dangerous cells
+ */
+export function sanitizeCsvCell(value: string, mode: CsvSafetyMode): string {
+  if (mode === 'none' || value === '' || !FORMULA_PREFIXES.includes(value[0]) || SIGNED_NUMBER.test(value)) {
+    return value;
+  }
+  return (mode === 'apostrophe' ? "'" : ' ') + value;
+}
+
+/**
+ * Quotes a field if it contains the delimiter, a quote or a line break, or always with the 'all' style
+ */
+export function escapeCsvField(value: string, delimiter = ',', quoteStyle: CsvQuoteStyle = 'minimal'): string {
+  if (
+    quoteStyle === 'all' ||
+    value.includes(delimiter) ||
+    value.includes('"') ||
+    value.includes('\n') ||
+    value.includes('\r')
+  ) {
+    return `"${value.replace(/"/g, '""')}"`;
+  }
+  return value;
+}
+
+/**
+ * Builds CSV text from a header row and data rows
+ * @param headers - Column names
+ * @param rows - Cell text for each row, in header order
+ * @param options - Delimiter, line terminator, quoting and formula-neutralization mode
+ */
+export function toCsv(headers: string[], rows: string[][], options: CsvWriteOptions = {}): string {
+  const delimiter = options.delimiter ?? ',';
+  const lineTerminator = options.lineTerminator ?? '\n';
+  const quoteStyle = options.quoteStyle ?? 'minimal';
+  const safetyMode = options.safetyMode ?? 'apostrophe';
+
+  const formatRow = (cells: string[]) =>
+    cells.map(cell => escapeCsvField(sanitizeCsvCell(cell, safetyMode), delimiter, quoteStyle)).join(delimiter);
+
+  return [formatRow(headers), ...rows.map(formatRow)].join(lineTerminator) + lineTerminator;
+}
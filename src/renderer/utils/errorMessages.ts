@@ -31,6 +31,22 @@ export function parseError(error: unknown): ParsedError {
     };
   }
 
+  // Clock skew: S3 rejects signatures from machines whose clock is too far off
+  if (
+    lowerMessage.includes('requesttimetooskewed') ||
+    lowerMessage.includes('clock skew')
+  ) {
+    const skewDetail = message.match(/clock skew: ([^)]+)\)/i);
+    return {
+      title: 'Clock Skew',
+      message: 'Your system clock appears to be off — S3 rejected the request signature.',
+      suggestion: skewDetail
+        ? `The ${skewDetail[1]}. Sync your system clock and try again.`
+        : 'Sync your system clock (enable automatic time) and try again.',
+      isRetryable: false,
+    };
+  }
+
   // Invalid credentials
   if (
     lowerMessage.includes('invalidsignature') ||
@@ -0,0 +1,35 @@
+/**
+ * What the bucket explorer shows while a profile's buckets are loaded
+ */
+
+/**
+ * - 'no-profile': no profile is selected yet
+ * - 'loading': the bucket list is being fetched
+ * - 'error': listing buckets failed
+ * - 'empty': listing succeeded but returned no buckets, usually a permissions issue
+ * - 'ready': there are buckets to show
+ */
+export type ExplorerState = 'no-profile' | 'loading' | 'error' | 'empty' | 'ready';
+
+/**
+ * The outcome of loading a profile's buckets
+ */
+export type BucketListOutcome =
+  | { status: 'pending' }
+  | { status: 'failed' }
+  | { status: 'loaded'; bucketCount: number };
+
+/**
+ * Picks the explorer state for the selected profile and the outcome of listing its buckets
+ */
+export function getExplorerState(profile: string | null, outcome: BucketListOutcome): ExplorerState {
+  if (!profile) return 'no-profile';
+  switch (outcome.status) {
+    case 'pending':
+      return 'loading';
+    case 'failed':
+      return 'error';
+    case 'loaded':
+      return outcome.bucketCount > 0 ? 'ready' : 'empty';
+  }
+}
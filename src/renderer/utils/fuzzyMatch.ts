@@ -0,0 +1,58 @@
+/**
+ * Forgiving subsequence matching for typed filters
+ */
+
+export interface FuzzyMatch {
+  /** Higher is better */
+  score: number;
+  /** Indices in the text of the matched characters, for highlighting */
+  positions: number[];
+}
+
+const WORD_SEPARATORS = new Set(['/', '-', '_', '.', ' ', '=', ':']);
+
+/**
+ * Matches the query's characters in order (not necessarily adjacent) against
+ * the text, ignoring case. Runs of consecutive characters and matches at the
+ * start of a word score higher, so "prdlog" ranks "prod-logs" above "product-catalog".
+ * @returns The match, or null if the text does not contain the query as a subsequence
+ */
+export function fuzzyMatch(text: string, query: string): FuzzyMatch | null {
+  const needle = query.trim().toLowerCase();
+  if (!needle) {
+    return { score: 0, positions: [] };
+  }
+
+  const haystack = text.toLowerCase();
+  const positions: number[] = [];
+  let score = 0;
+  let searchFrom = 0;
+
+  for (const char of needle) {
+    if (char === ' ') continue;
+    const index = haystack.indexOf(char, searchFrom);
+    if (index === -1) {
+      return null;
+    }
+
+    const previous = positions[positions.length - 1];
+    if (previous !== undefined && index === previous + 1) {
+      score += 5;
+    } else if (previous !== undefined) {
+      // Penalise gaps, but cap it so a long text is not ruled out
+      score -= Math.min(index - previous - 1, 3);
+    }
+    if (index === 0 || WORD_SEPARATORS.has(haystack[index - 1])) {
+      score += 3;
+    }
+    score += 1;
+
+    positions.push(index);
+    searchFrom = index + 1;
+  }
+
+  // Prefer shorter texts when the match is otherwise equal
+  score -= haystack.length / 100;
+
+  return { score, positions };
+}
@@ -0,0 +1,52 @@
+/**
+ * Shell-style wildcard matching for typed filters such as "*.parquet"
+ */
+
+export interface GlobOptions {
+  /** Match letters case-sensitively (default false, like the quick filter) */
+  caseSensitive?: boolean;
+}
+
+/**
+ * Whether the text uses any wildcard, so it should be matched as a glob
+ */
+export function isGlobPattern(text: string): boolean {
+  return /[*?]/.test(text);
+}
+
+/**
+ * Converts a glob to an anchored regular expression. "*" and "?" stay within one
+ * path segment, "**" crosses slashes and a "**\/" segment may also match no folders.
+ */
+function globToRegExp(pattern: string, caseSensitive: boolean): RegExp {
+  let source = '';
+  for (let i = 0; i < pattern.length; i++) {
+    const char = pattern[i];
+    if (char === '*' && pattern[i + 1] === '*') {
+      i++;
+      if (pattern[i + 1] === '/') {
+        i++;
+        source += '(?:.*/)?';
+      } else {
+        source += '.*';
+      }
+    } else if (char === '*') {
+      source += '[^/]*';
+    } else if (char === '?') {
+      source += '[^/]';
+    } else {
+      source += char.replace(/[.+^${}()|[\]\\]/g, '\\$&');
+    }
+  }
+  return new RegExp(`^${source}$`, caseSensitive ? '' : 'i');
+}
+
+/**
+ * Matches a whole key against a glob pattern
+ * @param key - The key or name to test
+ * @param pattern - Pattern using "*", "?" and "**"; other characters match literally
+ * @param options - Matching options
+ */
+export function matchesGlob(key: string, pattern: string, options: GlobOptions = {}): boolean {
+  return globToRegExp(pattern, options.caseSensitive ?? false).test(key);
+}
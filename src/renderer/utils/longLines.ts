@@ -0,0 +1,59 @@
+/**
+ * Helpers for files with extremely long lines (minified JSON, single-line CSV),
+ * which freeze the editor when rendered as they are
+ */
+
+/** Lines longer than this are treated as too long to edit comfortably */
+export const LONG_LINE_THRESHOLD = 10000;
+
+/** Column at which long lines are wrapped for viewing */
+export const LONG_LINE_WRAP_WIDTH = 1000;
+
+/**
+ * Length of the longest line, without splitting the content into an array of lines
+ */
+export function getLongestLineLength(content: string): number {
+  let longest = 0;
+  let start = 0;
+  while (start <= content.length) {
+    let end = content.indexOf('\n', start);
+    if (end === -1) end = content.length;
+    // Don't count the \r of CRLF line endings
+    const length = end - start - (end > start && content[end - 1] === '\r' ? 1 : 0);
+    if (length > longest) longest = length;
+    start = end + 1;
+  }
+  return longest;
+}
+
+/**
+ * Splits every line longer than the width into segments of at most that many
+ * characters. Shorter lines are kept whole, and a segment never ends between
+ * the two halves of a surrogate pair, so characters outside the BMP stay intact.
+ * @param content - The text to wrap
+ * @param width - The largest segment length, in UTF-16 code units
+ * @returns One entry per displayed line
+ */
+export function wrapLongLines(content: string, width = LONG_LINE_WRAP_WIDTH): string[] {
+  const segmentWidth = Math.max(2, Math.floor(width));
+  const wrapped: string[] = [];
+
+  for (const line of content.split(/\r?\n/)) {
+    if (line.length <= segmentWidth) {
+      wrapped.push(line);
+      continue;
+    }
+    let start = 0;
+    while (start < line.length) {
+      let end = Math.min(start + segmentWidth, line.length);
+      const last = line.charCodeAt(end - 1);
+      if (end < line.length && last >= 0xd800 && last <= 0xdbff) {
+        end--;
+      }
+      wrapped.push(line.slice(start, end));
+      start = end;
+    }
+  }
+
+  return wrapped;
+}
@@ -0,0 +1,220 @@
+/**
+ * Parquet file structure read from the footer metadata, without decoding any rows
+ */
+
+import { parquetMetadataAsync, parquetSchema } from 'hyparquet';
+import type { AsyncBuffer, ColumnMetaData, FileMetaData } from 'hyparquet';
+
+type ColumnStatistics = NonNullable<ColumnMetaData['statistics']>;
+
+/**
+ * Logical annotations on physical BYTE_ARRAY columns that need special rendering
+ */
+export type LogicalAnnotation = 'JSON' | 'BSON' | 'ENUM';
+
+export interface ParquetColumnInfo {
+  name: string;
+  /** Physical type (e.g. INT64, BYTE_ARRAY), or 'GROUP' for structs, lists and maps */
+  physicalType: string;
+  logicalType?: LogicalAnnotation;
+  /** Whether the column is OPTIONAL rather than REQUIRED or REPEATED */
+  nullable: boolean;
+}
+
+export interface ParquetSchemaInfo {
+  /** Top-level columns, in file order */
+  columns: ParquetColumnInfo[];
+  totalRows: number;
+}
+
+export interface ParquetRowGroupInfo {
+  numRows: number;
+  /** Uncompressed size of the row group's column data, in bytes */
+  totalByteSize: number;
+  /** Size of the row group's column chunks as stored in the file, in bytes */
+  compressedSize: number;
+}
+
+export interface ParquetColumnStats {
+  /** Column path, with nested fields joined by dots */
+  name: string;
+  /** Smallest and largest values across all row groups, null when not recorded */
+  min: unknown;
+  max: unknown;
+  nullCount: number | null;
+  /** Only known for files with a single row group, since counts can't be combined */
+  distinctCount: number | null;
+}
+
+/**
+ * Get the JSON/BSON/ENUM annotation of a schema element
+ * Checks the logical type first and falls back to the legacy converted type
+ */
+export function getLogicalAnnotation(element: {
+  converted_type?: string;
+  logical_type?: { type: string };
+}): LogicalAnnotation | undefined {
+  const annotation = element.logical_type?.type ?? element.converted_type;
+  if (annotation === 'JSON' || annotation === 'BSON' || annotation === 'ENUM') {
+    return annotation;
+  }
+  return undefined;
+}
+
+/**
+ * Wrap downloaded bytes in the AsyncBuffer hyparquet reads from
+ * IMPORTANT: Electron IPC transfers Uint8Array data but the underlying buffer
+ * may not be directly usable for DataView operations, and hyparquet's slice
+ * callback expects an ArrayBuffer, not a Uint8Array. The data is copied into a
+ * fresh ArrayBuffer so both work.
+ */
+export function toAsyncBuffer(data: Uint8Array): AsyncBuffer {
+  const arrayBuffer = new ArrayBuffer(data.length);
+  new Uint8Array(arrayBuffer).set(data);
+  return {
+    byteLength: arrayBuffer.byteLength,
+    slice: (start: number, end?: number) => Promise.resolve(arrayBuffer.slice(start, end)),
+  };
+}
+
+/**
+ * An AsyncBuffer over an S3 object that fetches each slice with a ranged GET,
+ * so reading the footer metadata doesn't download the rest of the file
+ * @param byteLength - Size of the object, from its listing
+ * @param versionId - Version to read instead of the latest one
+ */
+export function s3RangeAsyncBuffer(bucket: string, key: string, byteLength: number, versionId?: string): AsyncBuffer {
+  return {
+    byteLength,
+    slice: async (start: number, end: number = byteLength) => {
+      const result = await window.electronAPI.s3.downloadPartialContent(bucket, key, end - start, start, versionId);
+      if (!result.success || !result.data) {
+        throw new Error(result.error || 'Failed to read file range');
+      }
+      return toAsyncBuffer(result.data).slice(0);
+    },
+  };
+}
+
+/**
+ * Describe the top-level columns and row count recorded in a file's metadata
+ * Nested types (arrays, structs) are reported once, by their parent column name.
+ */
+export function parquetSchemaInfo(metadata: FileMetaData): ParquetSchemaInfo {
+  const schemaTree = parquetSchema(metadata);
+  return {
+    columns: schemaTree.children.map(({ element }) => ({
+      name: element.name,
+      physicalType: element.type ?? 'GROUP',
+      logicalType: getLogicalAnnotation(element),
+      nullable: element.repetition_type === 'OPTIONAL',
+    })),
+    totalRows: Number(metadata.num_rows ?? 0),
+  };
+}
+
+/**
+ * Read only the schema and row count of a Parquet file. Just the footer is
+ * parsed, so with s3RangeAsyncBuffer only the footer is downloaded.
+ */
+export async function readParquetSchema(file: AsyncBuffer): Promise<ParquetSchemaInfo> {
+  return parquetSchemaInfo(await parquetMetadataAsync(file));
+}
+
+/**
+ * Describe how the rows of a file are split into row groups, in file order
+ * Writers may omit a row group's compressed size, in which case it is summed
+ * from its column chunks.
+ */
+export function parquetRowGroups(metadata: FileMetaData): ParquetRowGroupInfo[] {
+  return (metadata.row_groups ?? []).map(rowGroup => ({
+    numRows: Number(rowGroup.num_rows),
+    totalByteSize: Number(rowGroup.total_byte_size),
+    compressedSize: Number(
+      rowGroup.total_compressed_size
+        ?? rowGroup.columns.reduce((sum, column) => sum + Number(column.meta_data?.total_compressed_size ?? 0), 0)
+    ),
+  }));
+}
+
+/**
+ * Order two statistics values of the same column, or undefined if they can't
+ * be compared (e.g. raw bytes)
+ */
+function compareStatValues(a: unknown, b: unknown): number | undefined {
+  const isNumeric = (value: unknown) => typeof value === 'number' || typeof value === 'bigint';
+  if ((isNumeric(a) && isNumeric(b)) || (typeof a === 'string' && typeof b === 'string')) {
+    const x = a as number | string;
+    const y = b as number | string;
+    return x < y ? -1 : x > y ? 1 : 0;
+  }
+  if (typeof a === 'boolean' && typeof b === 'boolean') {
+    return Number(a) - Number(b);
+  }
+  if (a instanceof Date && b instanceof Date) {
+    return a.getTime() - b.getTime();
+  }
+  return undefined;
+}
+
+/**
+ * Combine one statistic across row groups. Missing from any row group, or not
+ * comparable, means it isn't known for the file.
+ */
+function combineStatValues(values: unknown[], pick: 'min' | 'max'): unknown {
+  if (values.length === 0 || values.some(value => value === undefined || value === null)) {
+    return null;
+  }
+  let result = values[0];
+  for (const value of values.slice(1)) {
+    const order = compareStatValues(value, result);
+    if (order === undefined) {
+      return null;
+    }
+    if (pick === 'min' ? order < 0 : order > 0) {
+      result = value;
+    }
+  }
+  return result;
+}
+
+/**
+ * Aggregate the min/max, null and distinct counts the writer recorded for each
+ * column chunk, without scanning the data. Statistics a writer left out are null.
+ */
+export function parquetColumnStats(metadata: FileMetaData): ParquetColumnStats[] {
+  const rowGroups = metadata.row_groups ?? [];
+  const byColumn = new Map<string, ColumnStatistics[]>();
+  const names: string[] = [];
+  for (const rowGroup of rowGroups) {
+    for (const column of rowGroup.columns) {
+      if (!column.meta_data) continue;
+      const name = column.meta_data.path_in_schema.join('.');
+      if (!byColumn.has(name)) {
+        byColumn.set(name, []);
+        names.push(name);
+      }
+      byColumn.get(name)!.push(column.meta_data.statistics ?? {});
+    }
+  }
+
+  return names.map(name => {
+    const chunks = byColumn.get(name)!;
+    const complete = chunks.length === rowGroups.length;
+    // min_value/max_value replace the deprecated min/max, which some older writers still set alone
+    const mins = chunks.map(stats => stats.min_value ?? stats.min);
+    const maxes = chunks.map(stats => stats.max_value ?? stats.max);
+    const nullCounts = chunks.map(stats => stats.null_count);
+    const distinctCount = chunks.length === 1 ? chunks[0].distinct_count : undefined;
+    return {
+      name,
+      min: complete ? combineStatValues(mins, 'min') : null,
+      max: complete ? combineStatValues(maxes, 'max') : null,
+      nullCount:
+        complete && nullCounts.every(count => count !== undefined)
+          ? nullCounts.reduce<number>((sum, count) => sum + Number(count), 0)
+          : null,
+      distinctCount: complete && distinctCount !== undefined ? Number(distinctCount) : null,
+    };
+  });
+}
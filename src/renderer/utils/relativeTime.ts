@@ -0,0 +1,38 @@
+/**
+ * Relative ages ("3 days ago") for timestamps shown in the file list
+ */
+
+const MINUTE_MS = 60 * 1000;
+const HOUR_MS = 60 * MINUTE_MS;
+const DAY_MS = 24 * HOUR_MS;
+
+const UNITS: Array<{ name: string; ms: number }> = [
+  { name: 'year', ms: 365 * DAY_MS },
+  { name: 'month', ms: 30 * DAY_MS },
+  { name: 'week', ms: 7 * DAY_MS },
+  { name: 'day', ms: DAY_MS },
+  { name: 'hour', ms: HOUR_MS },
+  { name: 'minute', ms: MINUTE_MS },
+];
+
+/**
+ * How long ago a timestamp was, in its largest whole unit, e.g. "3 days ago"
+ * Anything under a minute is "just now", and so are timestamps ahead of this
+ * machine's clock, which are clock skew rather than real future times.
+ * @param date - The timestamp; strings are what dates become after IPC or JSON
+ * @param now - The current time in milliseconds
+ * @returns The age, or "--" when there is no valid timestamp
+ */
+export function formatAge(date: Date | string | undefined, now: number = Date.now()): string {
+  const time = date ? new Date(date).getTime() : NaN;
+  if (Number.isNaN(time)) return '--';
+
+  const elapsed = now - time;
+  for (const unit of UNITS) {
+    const count = Math.floor(elapsed / unit.ms);
+    if (count >= 1) {
+      return `${count} ${unit.name}${count === 1 ? '' : 's'} ago`;
+    }
+  }
+  return 'just now';
+}
@@ -0,0 +1,151 @@
+/**
+ * Thumbnail cache and background prefetching for image files in the file list
+ */
+
+import { guessMimeType } from '../../shared/fileTypes';
+import { defaultViewerRegistry } from './viewerRegistry';
+
+/**
+ * Largest image fetched for a thumbnail (256KB); bigger images keep the generic icon
+ */
+export const THUMBNAIL_MAX_BYTES = 256 * 1024;
+
+/** Number of thumbnail downloads issued in parallel */
+export const THUMBNAIL_PREFETCH_CONCURRENCY = 4;
+
+/** Number of thumbnails kept in memory */
+export const THUMBNAIL_CACHE_SIZE = 200;
+
+/**
+ * Least-recently-used cache. Reads refresh an entry's recency; inserting past
+ * capacity evicts the oldest entry and passes its value to `onEvict`.
+ */
+export class LruCache<V> {
+  private entries = new Map<string, V>();
+
+  constructor(
+    private readonly maxEntries: number,
+    private readonly onEvict?: (value: V, key: string) => void
+  ) {}
+
+  get size(): number {
+    return this.entries.size;
+  }
+
+  has(key: string): boolean {
+    return this.entries.has(key);
+  }
+
+  get(key: string): V | undefined {
+    if (!this.entries.has(key)) {
+      return undefined;
+    }
+    // Map keeps insertion order, so re-inserting marks the entry as most recent
+    const value = this.entries.get(key) as V;
+    this.entries.delete(key);
+    this.entries.set(key, value);
+    return value;
+  }
+
+  set(key: string, value: V): void {
+    const existing = this.entries.get(key);
+    if (existing !== undefined) {
+      this.entries.delete(key);
+      if (existing !== value) {
+        this.onEvict?.(existing, key);
+      }
+    }
+    this.entries.set(key, value);
+
+    while (this.entries.size > this.maxEntries) {
+      const [oldestKey, oldestValue] = this.entries.entries().next().value as [string, V];
+      this.entries.delete(oldestKey);
+      this.onEvict?.(oldestValue, oldestKey);
+    }
+  }
+
+  delete(key: string): void {
+    const value = this.entries.get(key);
+    if (value !== undefined) {
+      this.entries.delete(key);
+      this.onEvict?.(value, key);
+    }
+  }
+
+  clear(): void {
+    for (const [key, value] of this.entries) {
+      this.onEvict?.(value, key);
+    }
+    this.entries.clear();
+  }
+}
+
+/**
+ * Cache key for an object's thumbnail. Including the ETag means an overwritten
+ * object misses the cache instead of showing the old image.
+ */
+export function thumbnailCacheKey(bucket: string, key: string, etag?: string): string {
+  return `${bucket}/${key}@${etag ?? ''}`;
+}
+
+/**
+ * Whether a file gets a thumbnail: an image small enough to fetch whole
+ */
+export function isThumbnailCandidate(item: { key: string; size: number; isPrefix: boolean }): boolean {
+  return (
+    !item.isPrefix &&
+    item.size > 0 &&
+    item.size <= THUMBNAIL_MAX_BYTES &&
+    defaultViewerRegistry.resolveByExtension(item.key) === 'image' &&
+    !item.key.toLowerCase().endsWith('.gz')
+  );
+}
+
+/**
+ * Shared thumbnail cache; evicted object URLs are revoked to free the image data
+ */
+export const thumbnailCache = new LruCache<string>(THUMBNAIL_CACHE_SIZE, url => URL.revokeObjectURL(url));
+
+/**
+ * Downloads thumbnails for the given files with bounded parallelism and stores
+ * object URLs in the cache. Files already cached or not eligible are skipped.
+ * Aborting the signal stops issuing new downloads and discards in-flight results.
+ * @param bucket - The S3 bucket name
+ * @param items - Files in display order (visible and upcoming rows first)
+ * @param cache - Cache receiving object URLs keyed by thumbnailCacheKey
+ * @param signal - Aborted when the user navigates away
+ * @param onThumbnail - Called after each thumbnail is cached
+ */
+export async function prefetchThumbnails(
+  bucket: string,
+  items: Array<{ key: string; size: number; isPrefix: boolean; etag?: string }>,
+  cache: LruCache<string>,
+  signal: AbortSignal,
+  onThumbnail?: (key: string) => void
+): Promise<void> {
+  const queue = items.filter(
+    item => isThumbnailCandidate(item) && !cache.has(thumbnailCacheKey(bucket, item.key, item.etag))
+  );
+
+  const worker = async () => {
+    while (queue.length > 0 && !signal.aborted) {
+      const item = queue.shift()!;
+      try {
+        const result = await window.electronAPI.s3.downloadBinaryContent(bucket, item.key);
+        if (signal.aborted || !result.success || !result.data || result.data.length === 0) {
+          continue;
+        }
+        const blob = new Blob([result.data], { type: guessMimeType(item.key) });
+        cache.set(thumbnailCacheKey(bucket, item.key, item.etag), URL.createObjectURL(blob));
+        onThumbnail?.(item.key);
+      } catch (err) {
+        // A failed thumbnail just keeps the generic icon
+        console.warn(`Failed to load thumbnail for ${item.key}:`, err);
+      }
+    }
+  };
+
+  await Promise.all(
+    Array.from({ length: Math.min(THUMBNAIL_PREFETCH_CONCURRENCY, queue.length) }, worker)
+  );
+}
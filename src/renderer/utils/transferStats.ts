@@ -0,0 +1,101 @@
+/**
+ * Transfer speed and ETA estimation from progress updates
+ */
+
+/** Speed is averaged over this trailing window to smooth out bursty chunks */
+export const SPEED_WINDOW_MS = 3000;
+
+export interface TransferEstimate {
+  /** Speed over the smoothing window, in bytes per second */
+  speedBps: number;
+  /** Average speed since the transfer started, in bytes per second */
+  averageBps: number;
+  /** Seconds remaining, or null when the total size is unknown or nothing has moved yet */
+  etaSeconds: number | null;
+}
+
+/**
+ * Accumulates progress samples for one transfer
+ */
+export class TransferStats {
+  private samples: Array<{ bytes: number; time: number }>;
+
+  /**
+   * @param total - Total bytes, or 0/null when unknown
+   * @param startTime - When the transfer started (ms)
+   * @param windowMs - Smoothing window for the current speed
+   */
+  constructor(
+    private readonly total: number | null,
+    private readonly startTime: number = Date.now(),
+    private readonly windowMs: number = SPEED_WINDOW_MS
+  ) {
+    this.samples = [{ bytes: 0, time: startTime }];
+  }
+
+  /**
+   * Records the bytes transferred so far and returns the updated estimate
+   * @param loaded - Total bytes transferred so far
+   * @param now - Current time (ms)
+   */
+  update(loaded: number, now: number): TransferEstimate {
+    this.samples.push({ bytes: loaded, time: now });
+    // Keep the newest sample at or before the window start as the baseline
+    while (this.samples.length > 2 && this.samples[1].time <= now - this.windowMs) {
+      this.samples.shift();
+    }
+
+    const baseline = this.samples[0];
+    const windowSeconds = (now - baseline.time) / 1000;
+    const speedBps = windowSeconds > 0 ? Math.max(0, (loaded - baseline.bytes) / windowSeconds) : 0;
+
+    const elapsedSeconds = (now - this.startTime) / 1000;
+    const averageBps = elapsedSeconds > 0 ? loaded / elapsedSeconds : 0;
+
+    let etaSeconds: number | null = null;
+    if (this.total && this.total > 0) {
+      if (loaded >= this.total) {
+        etaSeconds = 0;
+      } else if (speedBps > 0) {
+        etaSeconds = (this.total - loaded) / speedBps;
+      }
+    }
+
+    return { speedBps, averageBps, etaSeconds };
+  }
+}
+
+/**
+ * Formats a speed for display, e.g. "45.2 MB/s"
+ */
+export function formatSpeed(bytesPerSecond: number): string {
+  if (bytesPerSecond < 1) return '0 B/s';
+  const units = ['B', 'KB', 'MB', 'GB', 'TB'];
+  const i = Math.min(Math.floor(Math.log(bytesPerSecond) / Math.log(1024)), units.length - 1);
+  return `${(bytesPerSecond / Math.pow(1024, i)).toFixed(i > 0 ? 1 : 0)} ${units[i]}/s`;
+}
+
+/**
+ * Formats remaining seconds as m:ss or h:mm:ss, e.g. "0:38"
+ */
+export function formatEta(seconds: number): string {
+  const total = Math.ceil(seconds);
+  const h = Math.floor(total / 3600);
+  const m = Math.floor((total % 3600) / 60);
+  const s = String(total % 60).padStart(2, '0');
+  return h > 0 ? `${h}:${String(m).padStart(2, '0')}:${s}` : `${m}:${s}`;
+}
+
+/**
+ * One-line description of a running transfer, e.g. "Downloading large.bin — 45.2 MB/s, ETA 0:38"
+ */
+export function describeTransfer(transfer: {
+  type: 'upload' | 'download';
+  fileName: string;
+  speedBps: number;
+  etaSeconds?: number | null;
+}): string {
+  const verb = transfer.type === 'download' ? 'Downloading' : 'Uploading';
+  const eta = transfer.etaSeconds != null ? `, ETA ${formatEta(transfer.etaSeconds)}` : '';
+  return `${verb} ${transfer.fileName} — ${formatSpeed(transfer.speedBps)}${eta}`;
+}
@@ -0,0 +1,204 @@
+/**
+ * Registry mapping file types to the viewer used to open them.
+ * Viewers are matched by extension first, then by the object's Content-Type,
+ * then by sniffing the leading bytes, falling back to the hex viewer for anything unrecognized.
+ */
+
+import { COMPRESSION_EXTENSIONS, getBaseExtension, getExtension } from '../../shared/fileTypes';
+
+export type ViewerKind = 'parquet' | 'csv' | 'json' | 'yaml' | 'image' | 'text' | 'hex';
+
+/**
+ * Number of leading bytes needed for content sniffing
+ */
+export const SNIFF_BYTES = 512;
+
+export interface ViewerRegistration {
+  kind: ViewerKind;
+  /** Lowercase extensions without the leading dot */
+  extensions: string[];
+  /** Whether compressed variants of the extensions are supported (e.g. data.json.gz, data.csv.zst) */
+  allowCompressed?: boolean;
+  /** Lowercase MIME types, or "type/*" for a whole top-level type */
+  contentTypes?: string[];
+  /** Content check used when the extension is not recognized */
+  matchesContent?: (firstBytes: Uint8Array) => boolean;
+}
+
+function startsWith(bytes: Uint8Array, signature: number[]): boolean {
+  return signature.length <= bytes.length && signature.every((b, i) => bytes[i] === b);
+}
+
+/**
+ * Checks whether the bytes look like UTF-8 text (no NUL bytes, valid encoding)
+ */
+export function looksLikeText(bytes: Uint8Array): boolean {
+  if (bytes.length === 0 || bytes.includes(0)) {
+    return false;
+  }
+  try {
+    // A multi-byte character may be cut off at the end of the sample
+    new TextDecoder('utf-8', { fatal: true }).decode(bytes, { stream: true });
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+function firstNonWhitespaceChar(bytes: Uint8Array): string {
+  for (const b of bytes) {
+    if (b !== 0x20 && b !== 0x09 && b !== 0x0a && b !== 0x0d) {
+      return String.fromCharCode(b);
+    }
+  }
+  return '';
+}
+
+const IMAGE_SIGNATURES: number[][] = [
+  [0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a], // PNG
+  [0xff, 0xd8, 0xff], // JPEG
+  [0x47, 0x49, 0x46, 0x38], // GIF8
+  [0x42, 0x4d], // BMP
+];
+
+function isWebp(bytes: Uint8Array): boolean {
+  // RIFF....WEBP
+  return startsWith(bytes, [0x52, 0x49, 0x46, 0x46]) && startsWith(bytes.subarray(8), [0x57, 0x45, 0x42, 0x50]);
+}
+
+/**
+ * Ordered collection of viewer registrations
+ */
+export class ViewerRegistry {
+  private registrations: ViewerRegistration[] = [];
+
+  register(registration: ViewerRegistration): this {
+    this.registrations.push(registration);
+    return this;
+  }
+
+  /**
+   * Resolves a viewer from the file extension alone (compression extensions are
+   * looked through for viewers that support it). Returns null when no viewer claims the extension.
+   */
+  resolveByExtension(filename: string): ViewerKind | null {
+    const ext = getBaseExtension(filename);
+    if (!ext) {
+      return null;
+    }
+    const isCompressed = COMPRESSION_EXTENSIONS.includes(getExtension(filename) ?? '');
+
+    const match = this.registrations.find(
+      r => r.extensions.includes(ext) && (!isCompressed || r.allowCompressed)
+    );
+    return match ? match.kind : null;
+  }
+
+  /**
+   * Resolves a viewer from an object's Content-Type, ignoring parameters such as
+   * charset. Returns null when the type is missing or no viewer claims it.
+   */
+  resolveByContentType(contentType?: string): ViewerKind | null {
+    const mime = contentType?.split(';')[0].trim().toLowerCase();
+    if (!mime) {
+      return null;
+    }
+    const wildcard = `${mime.split('/')[0]}/*`;
+
+    const match = this.registrations.find(
+      r => r.contentTypes?.includes(mime) || r.contentTypes?.includes(wildcard)
+    );
+    return match ? match.kind : null;
+  }
+
+  /**
+   * Resolves the viewer for a file, sniffing `firstBytes` when the extension
+   * is not recognized. Unrecognized content opens in the hex viewer.
+   */
+  resolve(filename: string, firstBytes?: Uint8Array): ViewerKind {
+    const byExtension = this.resolveByExtension(filename);
+    if (byExtension) {
+      return byExtension;
+    }
+
+    if (firstBytes) {
+      const match = this.registrations.find(r => r.matchesContent?.(firstBytes));
+      if (match) {
+        return match.kind;
+      }
+    }
+
+    return 'hex';
+  }
+}
+
+/**
+ * Registry with the built-in viewers. Content checks run in registration
+ * order, so more specific formats are registered before plain text.
+ */
+export const defaultViewerRegistry = new ViewerRegistry()
+  .register({
+    kind: 'parquet',
+    extensions: ['parquet'],
+    contentTypes: ['application/vnd.apache.parquet', 'application/x-parquet'],
+    matchesContent: bytes => startsWith(bytes, [0x50, 0x41, 0x52, 0x31]), // PAR1
+  })
+  .register({
+    kind: 'image',
+    extensions: ['png', 'jpg', 'jpeg', 'gif', 'webp', 'svg', 'ico', 'bmp'],
+    // Only formats Chromium can draw; TIFF, HEIC and other image types fall through to sniffing
+    contentTypes: [
+      'image/png',
+      'image/apng',
+      'image/jpeg',
+      'image/gif',
+      'image/webp',
+      'image/avif',
+      'image/svg+xml',
+      'image/x-icon',
+      'image/vnd.microsoft.icon',
+      'image/bmp',
+    ],
+    matchesContent: bytes => IMAGE_SIGNATURES.some(sig => startsWith(bytes, sig)) || isWebp(bytes),
+  })
+  .register({
+    kind: 'csv',
+    extensions: ['csv', 'tsv'],
+    contentTypes: ['text/csv', 'text/tab-separated-values'],
+    allowCompressed: true,
+  })
+  .register({
+    kind: 'json',
+    extensions: ['json'],
+    contentTypes: ['application/json'],
+    allowCompressed: true,
+    matchesContent: bytes => {
+      const first = firstNonWhitespaceChar(bytes);
+      return (first === '{' || first === '[') && looksLikeText(bytes);
+    },
+  })
+  .register({
+    kind: 'yaml',
+    extensions: ['yaml', 'yml'],
+    contentTypes: ['application/yaml', 'application/x-yaml', 'text/yaml', 'text/x-yaml'],
+    allowCompressed: true,
+  })
+  .register({
+    kind: 'text',
+    extensions: [
+      'txt', 'md', 'log', 'xml', 'html', 'htm', 'css', 'js', 'ts', 'py', 'sh',
+      'sql', 'ini', 'cfg', 'conf', 'toml', 'env', 'properties',
+    ],
+    contentTypes: ['text/*', 'application/xml', 'application/javascript', 'application/x-sh', 'application/sql'],
+    allowCompressed: true,
+    matchesContent: looksLikeText,
+  });
+
+/**
+ * Resolves the viewer for a file using the built-in registry
+ * @param filename - The file name or key
+ * @param firstBytes - Leading bytes of the file, used when the extension is not recognized
+ */
+export function resolveViewer(filename: string, firstBytes?: Uint8Array): ViewerKind {
+  return defaultViewerRegistry.resolve(filename, firstBytes);
+}
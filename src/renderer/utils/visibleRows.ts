@@ -0,0 +1,39 @@
+/**
+ * Which rows of a scrolling list are on screen, for work that should only
+ * happen for rows the user can see (thumbnails, metadata)
+ */
+
+/** Row height assumed before a row has been measured, in pixels */
+export const DEFAULT_ROW_HEIGHT = 32;
+
+/** Rows beyond each edge of the viewport that are treated as visible */
+export const ROW_OVERSCAN = 10;
+
+export interface RowRange {
+  /** Index of the first row, inclusive */
+  start: number;
+  /** Index after the last row, exclusive */
+  end: number;
+}
+
+/**
+ * Get the rows within the viewport plus `overscan` rows above and below it
+ * @param scrollTop - Scroll offset of the list, in pixels
+ * @param viewportHeight - Visible height of the list, in pixels
+ * @param rowHeight - Height of one row, in pixels; unmeasured (0) falls back to the default
+ * @param overscan - Extra rows on each side, so a short scroll doesn't wait on them
+ */
+export function getVisibleRowRange(
+  scrollTop: number,
+  viewportHeight: number,
+  rowHeight: number,
+  overscan: number = ROW_OVERSCAN
+): RowRange {
+  const height = rowHeight > 0 ? rowHeight : DEFAULT_ROW_HEIGHT;
+  const first = Math.floor(Math.max(0, scrollTop) / height);
+  const last = Math.ceil((Math.max(0, scrollTop) + Math.max(0, viewportHeight)) / height);
+  return {
+    start: Math.max(0, first - overscan),
+    end: last + overscan,
+  };
+}
@@ -0,0 +1,151 @@
+/**
+ * YAML parsing for the YAML viewer's tree view, built on js-yaml's core schema.
+ * Multiple documents, anchors/aliases and explicit tags are supported. Tags the
+ * schema doesn't know (e.g. `!Ref`, `!!binary`) are kept as annotations instead
+ * of failing the document, and aliased collections remember the alias name so
+ * the tree can mark them.
+ */
+
+import { CORE_SCHEMA, Type, YAMLException, loadAll, type State } from 'js-yaml';
+
+export type YamlScalar = string | number | boolean | null;
+
+interface YamlNodeBase {
+  /** Explicit tag, e.g. `!Ref` or `!!binary` */
+  tag?: string;
+  /** Anchor defined on this node (`&name`) */
+  anchor?: string;
+  /** Set when the node was produced by resolving an alias (`*name`) */
+  alias?: string;
+}
+
+export interface YamlScalarNode extends YamlNodeBase {
+  kind: 'scalar';
+  value: YamlScalar;
+}
+
+export interface YamlMapNode extends YamlNodeBase {
+  kind: 'map';
+  entries: Array<{ key: string; value: YamlNode }>;
+}
+
+export interface YamlSeqNode extends YamlNodeBase {
+  kind: 'seq';
+  items: YamlNode[];
+}
+
+export type YamlNode = YamlScalarNode | YamlMapNode | YamlSeqNode;
+
+export class YamlParseError extends Error {
+  constructor(message: string, public readonly line: number) {
+    super(`${message} (line ${line})`);
+    this.name = 'YamlParseError';
+  }
+}
+
+/**
+ * Content loaded under a tag the core schema has no type for
+ */
+class TaggedValue {
+  constructor(public readonly tag: string, public readonly value: unknown) {}
+}
+
+const STANDARD_TAG_PREFIX = 'tag:yaml.org,2002:';
+
+// An empty multi-type prefix matches every tag not constructed by the core schema
+const SCHEMA = CORE_SCHEMA.extend(
+  (['scalar', 'sequence', 'mapping'] as const).map(
+    kind => new Type('', { kind, multi: true, construct: (data, tag) => new TaggedValue(tag ?? '', data) })
+  )
+);
+
+/**
+ * Shortens a resolved tag back to the form it is usually written in (`!!binary`)
+ */
+function displayTag(tag: string): string {
+  return tag.startsWith(STANDARD_TAG_PREFIX) ? `!!${tag.slice(STANDARD_TAG_PREFIX.length)}` : tag;
+}
+
+/**
+ * Converts a loaded document to nodes. js-yaml hands every alias the anchored
+ * collection itself, so reaching the same collection again means an alias.
+ * Scalar anchors and aliases can't be told apart this way and load as plain values.
+ */
+function toNode(document: unknown, anchors: Map<object, string>): YamlNode {
+  const seen = new Set<object>();
+
+  const build = (value: unknown, insideAlias: boolean): YamlNode => {
+    if (typeof value !== 'object' || value === null) {
+      return { kind: 'scalar', value: value as YamlScalar };
+    }
+
+    const anchor = anchors.get(value);
+    const isAlias = !insideAlias && seen.has(value);
+    seen.add(value);
+    const nested = insideAlias || isAlias;
+
+    let node: YamlNode;
+    if (value instanceof TaggedValue) {
+      node = { ...build(value.value, nested), tag: displayTag(value.tag) };
+    } else if (Array.isArray(value)) {
+      node = { kind: 'seq', items: value.map(item => build(item, nested)) };
+    } else {
+      node = {
+        kind: 'map',
+        entries: Object.entries(value).map(([key, item]) => ({ key, value: build(item, nested) })),
+      };
+    }
+
+    if (anchor && isAlias) {
+      node.alias = anchor;
+    } else if (anchor && !insideAlias) {
+      node.anchor = anchor;
+    }
+    return node;
+  };
+
+  return build(document, false);
+}
+
+/**
+ * Parses YAML source into one node per document (`---` separated).
+ * Anchors are scoped to their document, as in the YAML spec. Documents without
+ * any content (or whose content is just null) are left out.
+ * @throws YamlParseError with the offending line number
+ */
+export function parseYamlDocuments(source: string): YamlNode[] {
+  const anchors = new Map<object, string>();
+  const listener = (event: 'open' | 'close', state: State) => {
+    if (event === 'close' && state.anchor !== null && typeof state.result === 'object' && state.result !== null) {
+      anchors.set(state.result, state.anchor);
+    }
+  };
+
+  let documents: unknown[];
+  try {
+    documents = loadAll(source, null, { schema: SCHEMA, listener });
+  } catch (err) {
+    if (err instanceof YAMLException) {
+      throw new YamlParseError(err.reason, (err.mark?.line ?? 0) + 1);
+    }
+    throw err;
+  }
+
+  return documents
+    .filter(document => document !== null && document !== undefined)
+    .map(document => toNode(document, anchors));
+}
+
+/**
+ * Converts a node to plain JavaScript values (maps become objects)
+ */
+export function yamlNodeToValue(node: YamlNode): unknown {
+  switch (node.kind) {
+    case 'scalar':
+      return node.value;
+    case 'seq':
+      return node.items.map(yamlNodeToValue);
+    case 'map':
+      return Object.fromEntries(node.entries.map(e => [e.key, yamlNodeToValue(e.value)]));
+  }
+}
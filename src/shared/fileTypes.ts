@@ -0,0 +1,101 @@
+/**
+ * File-type helpers shared by the main process (upload Content-Types) and the
+ * renderer's file list, toolbar and viewers, derived from an object's key alone.
+ */
+
+/**
+ * Extensions of compressed files whose content is decompressed before viewing
+ */
+export const COMPRESSION_EXTENSIONS = ['gz', 'zst', 'snappy'];
+
+/**
+ * MIME types by lowercase extension. Anything missing is application/octet-stream.
+ */
+const MIME_TYPES: Record<string, string> = {
+  // Text
+  txt: 'text/plain',
+  log: 'text/plain',
+  md: 'text/markdown',
+  html: 'text/html',
+  htm: 'text/html',
+  css: 'text/css',
+  csv: 'text/csv',
+  tsv: 'text/tab-separated-values',
+  yaml: 'text/yaml',
+  yml: 'text/yaml',
+  ts: 'text/typescript',
+  tsx: 'text/typescript',
+  py: 'text/x-python',
+  java: 'text/x-java',
+  // Code and data
+  js: 'application/javascript',
+  json: 'application/json',
+  jsonl: 'application/x-ndjson',
+  ndjson: 'application/x-ndjson',
+  xml: 'application/xml',
+  sql: 'application/sql',
+  sh: 'application/x-sh',
+  parquet: 'application/vnd.apache.parquet',
+  // Images
+  png: 'image/png',
+  jpg: 'image/jpeg',
+  jpeg: 'image/jpeg',
+  gif: 'image/gif',
+  webp: 'image/webp',
+  svg: 'image/svg+xml',
+  ico: 'image/x-icon',
+  avif: 'image/avif',
+  bmp: 'image/bmp',
+  tiff: 'image/tiff',
+  tif: 'image/tiff',
+  // Audio and video
+  mp3: 'audio/mpeg',
+  wav: 'audio/wav',
+  mp4: 'video/mp4',
+  webm: 'video/webm',
+  // Archives
+  pdf: 'application/pdf',
+  zip: 'application/zip',
+  gz: 'application/gzip',
+  zst: 'application/zstd',
+  tar: 'application/x-tar',
+};
+
+/**
+ * Gets the lowercase extension of an object key, without the dot
+ * Only the last path segment counts (folders may contain dots); folder keys,
+ * names without a dot and dotfiles such as ".env" have no extension.
+ * @param key - The S3 object key
+ * @returns The extension, e.g. 'gz' for 'logs/app.log.gz', or null if there is none
+ */
+export function getExtension(key: string): string | null {
+  if (key.endsWith('/')) {
+    return null;
+  }
+  const name = key.slice(key.lastIndexOf('/') + 1);
+  const dot = name.lastIndexOf('.');
+  return dot > 0 && dot < name.length - 1 ? name.slice(dot + 1).toLowerCase() : null;
+}
+
+/**
+ * Gets the extension of a file's content, looking past a .gz, .zst or .snappy extension
+ * @param key - The S3 object key
+ * @returns The extension, e.g. 'json' for 'data.json.gz', or null if there is none
+ */
+export function getBaseExtension(key: string): string | null {
+  const ext = getExtension(key);
+  if (ext && COMPRESSION_EXTENSIONS.includes(ext)) {
+    return getExtension(key.slice(0, key.lastIndexOf('.')));
+  }
+  return ext;
+}
+
+/**
+ * Guesses an object's MIME type from its extension
+ * @param key - The S3 object key
+ * @returns The MIME type, or application/octet-stream when the extension is unknown
+ */
+export function guessMimeType(key: string): string {
+  const ext = getExtension(key);
+  return (ext && MIME_TYPES[ext]) || 'application/octet-stream';
+}